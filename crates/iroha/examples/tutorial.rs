@@ -60,7 +60,7 @@ fn domain_registration_test(config: Config) -> Result<(), Error> {
     // Prepare a transaction
     let metadata = Metadata::default();
     let instructions: Vec<InstructionBox> = vec![create_looking_glass.into()];
-    let tx = client.build_transaction(instructions, metadata);
+    let tx = client.build_transaction(instructions, metadata)?;
     // #endregion domain_register_example_prepare_tx
 
     // #region domain_register_example_submit_tx
@@ -130,7 +130,7 @@ fn account_registration_test(config: Config) -> Result<(), Error> {
     // Account's RegisterBox
     let metadata = Metadata::default();
     let instructions: Vec<InstructionBox> = vec![create_account.into()];
-    let tx = client.build_transaction(instructions, metadata);
+    let tx = client.build_transaction(instructions, metadata)?;
     // #endregion register_account_prepare_tx
 
     // #region register_account_submit_tx
@@ -0,0 +1,589 @@
+//! Client-side cache of selected entities, kept current by the events stream.
+//!
+//! [`StateCache`] mirrors the accounts and the balances an application is interested in,
+//! so that UI threads can read them synchronously instead of querying the peer.
+//! Entries are refetched whenever an event touches them and versioned with the block height
+//! they are known to be current at, so that a late refetch never overwrites a newer value.
+//!
+//! [`QueryCache`] instead keeps the results of arbitrary iterable queries, keyed by the query
+//! along with its filter, and drops them once an event touches the kind of entities they were read from.
+//! It suits explorers and wallets repeating the same queries between the changes of the state.
+
+use std::{
+    collections::{HashMap, HashSet},
+    hash::Hash,
+    sync::{Arc, RwLock, RwLockReadGuard, RwLockWriteGuard},
+    thread,
+};
+
+use eyre::Result;
+use iroha_logger::prelude::*;
+use parity_scale_codec::Encode;
+
+use crate::{
+    client::{events_api::EventStreamItem, Client},
+    data_model::{
+        prelude::*,
+        query::{
+            builder::{QueryBuilder, QueryExecutor},
+            Query, QueryBox, QueryOutputBatchBoxTuple, QueryWithParams, SingularQueryBox,
+            SingularQueryOutputBox,
+        },
+    },
+    query::{QueryCursor, QueryError},
+};
+
+/// Value mirrored by [`StateCache`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cached<T> {
+    /// Value of the entity
+    pub value: T,
+    /// Height of the block the value is known to be current at
+    pub height: u64,
+}
+
+#[derive(Debug, Default)]
+struct Entries {
+    watched_accounts: HashSet<AccountId>,
+    watched_balances: HashSet<AssetId>,
+    accounts: HashMap<AccountId, Cached<Option<Account>>>,
+    balances: HashMap<AssetId, Cached<Numeric>>,
+    height: u64,
+}
+
+impl Entries {
+    /// Store `value` unless a value current at a greater height is already stored
+    fn store<K: Eq + Hash, T>(map: &mut HashMap<K, Cached<T>>, key: K, value: T, height: u64) {
+        if !map.get(&key).is_some_and(|cached| cached.height > height) {
+            map.insert(key, Cached { value, height });
+        }
+    }
+}
+
+/// Cache of the selected accounts and balances, see the [module-level documentation](self).
+///
+/// Entities are selected with [`Self::watch_account`] and [`Self::watch_balance`]
+/// and kept current once [`Self::start`] is called.
+/// Cloning is cheap: the clones share the entries.
+#[derive(Debug, Clone)]
+pub struct StateCache {
+    client: Client,
+    entries: Arc<RwLock<Entries>>,
+}
+
+impl StateCache {
+    /// Empty cache fetching the entities through `client`
+    pub fn new(client: Client) -> Self {
+        Self {
+            client,
+            entries: Arc::default(),
+        }
+    }
+
+    /// Keep the watched entities current by consuming the events stream on a separate thread.
+    ///
+    /// The entities are refetched after the connection is restored, as the events may have been missed.
+    /// The thread runs until the stream gives up reconnecting, see [`Client::listen_for_events_resilient`].
+    ///
+    /// # Errors
+    /// Fails if the events stream can't be connected
+    pub fn start(&self) -> Result<thread::JoinHandle<()>> {
+        let events = self.client.listen_for_events_resilient([
+            DataEventFilter::Account(AccountEventFilter::new()),
+            DataEventFilter::Domain(DomainEventFilter::new().for_events(DomainEventSet::Deleted)),
+        ])?;
+        let cache = self.clone();
+
+        Ok(thread::spawn(move || {
+            for item in events {
+                let result = match item {
+                    Ok(EventStreamItem::Event(EventMessage {
+                        sequence: Some(sequence),
+                        event,
+                    })) => cache.apply(&event, sequence.block_height),
+                    // NOTE: data events are unsequenced only if the peer lost some of them
+                    Ok(EventStreamItem::Event(EventMessage { event, .. })) => {
+                        let height = cache.read().height;
+                        cache.apply(&event, height)
+                    }
+                    Ok(EventStreamItem::Gap(gap)) => cache.refresh_all(gap.resumed_at),
+                    Err(error) => Err(error),
+                };
+                if let Err(error) = result {
+                    warn!(%error, "Failed to update state cache");
+                }
+            }
+        }))
+    }
+
+    /// Fetch the account with `id` and keep it current from now on.
+    ///
+    /// # Errors
+    /// Fails if the account can't be fetched
+    pub fn watch_account(&self, id: AccountId) -> Result<()> {
+        // NOTE: Watched before fetching, so that the changes made meanwhile are refetched
+        self.write().watched_accounts.insert(id.clone());
+        let height = self.client.get_status()?.blocks;
+        self.refresh_account(id, height)
+    }
+
+    /// Fetch the balance of the asset with `id` and keep it current from now on.
+    ///
+    /// # Errors
+    /// Fails if the balance can't be fetched
+    pub fn watch_balance(&self, id: AssetId) -> Result<()> {
+        self.write().watched_balances.insert(id.clone());
+        let height = self.client.get_status()?.blocks;
+        self.refresh_balance(id, height)
+    }
+
+    /// Cached account with `id`, `None` if it isn't watched or doesn't exist.
+    pub fn account(&self, id: &AccountId) -> Option<Cached<Account>> {
+        let cached = self.read().accounts.get(id)?.clone();
+        cached.value.map(|value| Cached {
+            value,
+            height: cached.height,
+        })
+    }
+
+    /// Cached balance of the asset with `id`, `None` if it isn't watched.
+    pub fn balance(&self, id: &AssetId) -> Option<Cached<Numeric>> {
+        self.read().balances.get(id).cloned()
+    }
+
+    /// Height of the block of the latest event applied to the cache.
+    pub fn height(&self) -> u64 {
+        self.read().height
+    }
+
+    fn read(&self) -> RwLockReadGuard<'_, Entries> {
+        self.entries.read().expect("state cache lock is poisoned")
+    }
+
+    fn write(&self) -> RwLockWriteGuard<'_, Entries> {
+        self.entries.write().expect("state cache lock is poisoned")
+    }
+
+    fn apply(&self, event: &EventBox, height: u64) -> Result<()> {
+        if let EventBox::Data(event) = event {
+            self.apply_data(event.untagged(), height)?;
+        }
+
+        let mut entries = self.write();
+        entries.height = entries.height.max(height);
+        Ok(())
+    }
+
+    fn apply_data(&self, event: &DataEvent, height: u64) -> Result<()> {
+        match event {
+            DataEvent::Domain(DomainEvent::Account(AccountEvent::Asset(event))) => {
+                let id = event.origin();
+                if self.read().watched_balances.contains(id) {
+                    self.refresh_balance(id.clone(), height)?;
+                }
+            }
+            DataEvent::Domain(DomainEvent::Account(event)) => {
+                let id = event.origin();
+                if self.read().watched_accounts.contains(id) {
+                    self.refresh_account(id.clone(), height)?;
+                }
+            }
+            DataEvent::Domain(DomainEvent::Deleted(domain)) => {
+                let (accounts, balances) = {
+                    let entries = self.read();
+                    (
+                        entries
+                            .watched_accounts
+                            .iter()
+                            .filter(|id| id.domain() == domain)
+                            .cloned()
+                            .collect::<Vec<_>>(),
+                        entries
+                            .watched_balances
+                            .iter()
+                            .filter(|id| {
+                                id.definition().domain() == domain
+                                    || id.account().domain() == domain
+                            })
+                            .cloned()
+                            .collect::<Vec<_>>(),
+                    )
+                };
+                for id in accounts {
+                    self.refresh_account(id, height)?;
+                }
+                for id in balances {
+                    self.refresh_balance(id, height)?;
+                }
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    fn refresh_all(&self, height: u64) -> Result<()> {
+        let (accounts, balances) = {
+            let entries = self.read();
+            (
+                entries.watched_accounts.iter().cloned().collect::<Vec<_>>(),
+                entries.watched_balances.iter().cloned().collect::<Vec<_>>(),
+            )
+        };
+        debug!(height, "Refetching the whole state cache");
+        for id in accounts {
+            self.refresh_account(id, height)?;
+        }
+        for id in balances {
+            self.refresh_balance(id, height)?;
+        }
+
+        let mut entries = self.write();
+        entries.height = entries.height.max(height);
+        Ok(())
+    }
+
+    fn refresh_account(&self, id: AccountId, height: u64) -> Result<()> {
+        let account = self
+            .client
+            .query(FindAccounts::new())
+            .filter_with(|account| account.id.eq(id.clone()))
+            .execute_single_opt()?;
+        Entries::store(&mut self.write().accounts, id, account, height);
+        Ok(())
+    }
+
+    fn refresh_balance(&self, id: AssetId, height: u64) -> Result<()> {
+        let balance = self
+            .client
+            .query(FindAssets::new())
+            .filter_with(|asset| asset.id.eq(id.clone()))
+            .execute_single_opt()?
+            .map_or(Numeric::ZERO, |asset| asset.value);
+        Entries::store(&mut self.write().balances, id, balance, height);
+        Ok(())
+    }
+}
+
+/// Kind of the entities a query reads and an event touches
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Scope {
+    Domains,
+    Accounts,
+    Assets,
+    AssetDefinitions,
+    Nfts,
+    Roles,
+    Peers,
+    Triggers,
+    Parameters,
+}
+
+impl Scope {
+    const ALL: &'static [Self] = &[
+        Self::Domains,
+        Self::Accounts,
+        Self::Assets,
+        Self::AssetDefinitions,
+        Self::Nfts,
+        Self::Roles,
+        Self::Peers,
+        Self::Triggers,
+        Self::Parameters,
+    ];
+
+    /// Entities read by `query`, `None` if its results change with every block
+    fn of_query(query: &QueryBox) -> Option<&'static [Self]> {
+        Some(match query {
+            QueryBox::FindDomains(_) => &[Self::Domains],
+            QueryBox::FindAccounts(_)
+            | QueryBox::FindPermissionsByAccountId(_)
+            | QueryBox::FindRolesByAccountId(_)
+            | QueryBox::FindAccountsWithRole(_)
+            | QueryBox::FindAccountsWithTag(_) => &[Self::Accounts],
+            QueryBox::FindAssets(_) | QueryBox::FindAssetsWithDefinition(_) => &[Self::Assets],
+            QueryBox::FindAccountsWithAsset(_) => &[Self::Accounts, Self::Assets],
+            QueryBox::FindAssetsDefinitions(_) => &[Self::AssetDefinitions],
+            QueryBox::FindNfts(_) => &[Self::Nfts],
+            QueryBox::FindRoles(_) | QueryBox::FindRoleIds(_) => &[Self::Roles],
+            QueryBox::FindPeers(_) => &[Self::Peers],
+            QueryBox::FindActiveTriggerIds(_) | QueryBox::FindTriggers(_) => &[Self::Triggers],
+            QueryBox::FindAllParameters(_) => &[Self::Parameters],
+            QueryBox::FindTransactions(_)
+            | QueryBox::FindBlocks(_)
+            | QueryBox::FindBlockHeaders(_) => return None,
+        })
+    }
+
+    /// Entities touched by `event`, including the ones removed along with its origin
+    fn of_event(event: &DataEvent) -> &'static [Self] {
+        match event {
+            DataEvent::Domain(DomainEvent::Account(AccountEvent::Asset(_))) => &[Self::Assets],
+            DataEvent::Domain(DomainEvent::Account(_)) => &[Self::Accounts, Self::Assets],
+            DataEvent::Domain(DomainEvent::AssetDefinition(_)) => {
+                &[Self::AssetDefinitions, Self::Assets]
+            }
+            DataEvent::Domain(DomainEvent::Nft(_)) => &[Self::Nfts],
+            DataEvent::Domain(DomainEvent::Deleted(_) | DomainEvent::Purged(_))
+            | DataEvent::Executor(_) => Self::ALL,
+            DataEvent::Domain(_) => &[Self::Domains],
+            DataEvent::Role(_) => &[Self::Roles, Self::Accounts],
+            DataEvent::Peer(_) => &[Self::Peers],
+            DataEvent::Trigger(_) => &[Self::Triggers],
+            DataEvent::Configuration(_) => &[Self::Parameters],
+            DataEvent::Tagged(event) => Self::of_event(event.event()),
+        }
+    }
+}
+
+/// First batch of the results, followed by the remaining ones, along with the number of results left after each
+type Batches = Vec<(QueryOutputBatchBoxTuple, u64)>;
+
+#[derive(Debug)]
+struct CachedQuery {
+    scopes: &'static [Scope],
+    batches: Batches,
+}
+
+#[derive(Debug, Default)]
+struct Queries {
+    entries: HashMap<Vec<u8>, CachedQuery>,
+    /// Bumped on every invalidation, so that the results fetched meanwhile are not stored
+    generation: u64,
+}
+
+impl Queries {
+    fn invalidate(&mut self, scopes: &[Scope]) {
+        self.generation += 1;
+        self.entries
+            .retain(|_, query| !query.scopes.iter().any(|scope| scopes.contains(scope)));
+    }
+
+    fn clear(&mut self) {
+        self.generation += 1;
+        self.entries.clear();
+    }
+}
+
+/// Cache of the results of the iterable queries, see the [module-level documentation](self).
+///
+/// Queries are built with [`Self::query`] the same way as with [`Client::query`].
+/// The results are fetched as a whole on the first execution, and served from the cache
+/// until [`Self::start`]ed invalidation drops them. Queries of blocks and transactions aren't cached.
+/// Cloning is cheap: the clones share the results.
+#[derive(Debug, Clone)]
+pub struct QueryCache {
+    client: Client,
+    queries: Arc<RwLock<Queries>>,
+}
+
+/// Cursor of a query executed with [`QueryCache`]
+#[derive(Debug)]
+pub struct QueryCacheCursor(CursorInner);
+
+#[derive(Debug)]
+enum CursorInner {
+    Cached(std::vec::IntoIter<(QueryOutputBatchBoxTuple, u64)>),
+    Live(QueryCursor),
+}
+
+impl QueryCache {
+    /// Empty cache executing the queries through `client`
+    pub fn new(client: Client) -> Self {
+        Self {
+            client,
+            queries: Arc::default(),
+        }
+    }
+
+    /// Invalidate the results by consuming the events stream on a separate thread.
+    ///
+    /// The whole cache is dropped after the connection is restored, as the events may have been missed.
+    /// The thread runs until the stream gives up reconnecting, see [`Client::listen_for_events_resilient`].
+    ///
+    /// # Errors
+    /// Fails if the events stream can't be connected
+    pub fn start(&self) -> Result<thread::JoinHandle<()>> {
+        let events = self
+            .client
+            .listen_for_events_resilient([DataEventFilter::Any])?;
+        let cache = self.clone();
+
+        Ok(thread::spawn(move || {
+            for item in events {
+                match item {
+                    Ok(EventStreamItem::Event(message)) => {
+                        if let EventBox::Data(event) = &message.event {
+                            cache.write().invalidate(Scope::of_event(event.untagged()));
+                        }
+                    }
+                    Ok(EventStreamItem::Gap(_)) => cache.clear(),
+                    Err(error) => warn!(%error, "Failed to invalidate query cache"),
+                }
+            }
+        }))
+    }
+
+    /// Build an iterable query executed through the cache
+    pub fn query<Q>(&self, query: Q) -> QueryBuilder<Self, Q, Q::Item>
+    where
+        Q: Query,
+    {
+        QueryBuilder::new(self, query)
+    }
+
+    /// Drop all the cached results
+    pub fn clear(&self) {
+        self.write().clear();
+    }
+
+    fn read(&self) -> RwLockReadGuard<'_, Queries> {
+        self.queries.read().expect("query cache lock is poisoned")
+    }
+
+    fn write(&self) -> RwLockWriteGuard<'_, Queries> {
+        self.queries.write().expect("query cache lock is poisoned")
+    }
+
+    fn fetch(&self, query: QueryWithParams) -> Result<Batches, QueryError> {
+        let (batch, remaining_items, mut cursor) = self.client.start_query(query)?;
+        let mut batches = vec![(batch, remaining_items)];
+        while let Some(next) = cursor {
+            let (batch, remaining_items, next) = Client::continue_query(next)?;
+            batches.push((batch, remaining_items));
+            cursor = next;
+        }
+        Ok(batches)
+    }
+}
+
+impl QueryCacheCursor {
+    fn next_batch(
+        mut batches: std::vec::IntoIter<(QueryOutputBatchBoxTuple, u64)>,
+    ) -> (QueryOutputBatchBoxTuple, u64, Option<Self>) {
+        let (batch, remaining_items) = batches
+            .next()
+            .expect("cached query has a batch left for the cursor");
+        let cursor = (!batches.as_slice().is_empty()).then(|| Self(CursorInner::Cached(batches)));
+        (batch, remaining_items, cursor)
+    }
+
+    fn live(
+        (batch, remaining_items, cursor): (QueryOutputBatchBoxTuple, u64, Option<QueryCursor>),
+    ) -> (QueryOutputBatchBoxTuple, u64, Option<Self>) {
+        (
+            batch,
+            remaining_items,
+            cursor.map(|cursor| Self(CursorInner::Live(cursor))),
+        )
+    }
+}
+
+impl QueryExecutor for QueryCache {
+    type Cursor = QueryCacheCursor;
+    type Error = QueryError;
+
+    fn execute_singular_query(
+        &self,
+        query: SingularQueryBox,
+    ) -> Result<SingularQueryOutputBox, Self::Error> {
+        self.client.execute_singular_query(query)
+    }
+
+    fn start_query(
+        &self,
+        query: QueryWithParams,
+    ) -> Result<(QueryOutputBatchBoxTuple, u64, Option<Self::Cursor>), Self::Error> {
+        let Some(scopes) = Scope::of_query(&query.query) else {
+            return self.client.start_query(query).map(QueryCacheCursor::live);
+        };
+        let key = query.encode();
+
+        let (cached, generation) = {
+            let queries = self.read();
+            (
+                queries
+                    .entries
+                    .get(&key)
+                    .map(|cached| cached.batches.clone()),
+                queries.generation,
+            )
+        };
+        let batches = match cached {
+            Some(batches) => batches,
+            None => {
+                let batches = self.fetch(query)?;
+                let mut queries = self.write();
+                if queries.generation == generation {
+                    queries.entries.insert(
+                        key,
+                        CachedQuery {
+                            scopes,
+                            batches: batches.clone(),
+                        },
+                    );
+                }
+                batches
+            }
+        };
+
+        Ok(QueryCacheCursor::next_batch(batches.into_iter()))
+    }
+
+    fn continue_query(
+        cursor: Self::Cursor,
+    ) -> Result<(QueryOutputBatchBoxTuple, u64, Option<Self::Cursor>), Self::Error> {
+        match cursor.0 {
+            CursorInner::Cached(batches) => Ok(QueryCacheCursor::next_batch(batches)),
+            CursorInner::Live(cursor) => Client::continue_query(cursor).map(QueryCacheCursor::live),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stale_values_are_not_stored() {
+        let mut balances = HashMap::new();
+
+        Entries::store(&mut balances, "rose", Numeric::from(10_u32), 5);
+        Entries::store(&mut balances, "rose", Numeric::from(7_u32), 4);
+        assert_eq!(
+            balances["rose"],
+            Cached {
+                value: Numeric::from(10_u32),
+                height: 5
+            }
+        );
+
+        Entries::store(&mut balances, "rose", Numeric::from(3_u32), 5);
+        assert_eq!(balances["rose"].value, Numeric::from(3_u32));
+    }
+
+    #[test]
+    fn queries_are_invalidated_by_events_of_their_scope() {
+        let query = |scopes: &'static [Scope]| CachedQuery {
+            scopes,
+            batches: Vec::new(),
+        };
+        let mut queries = Queries::default();
+        queries
+            .entries
+            .insert(b"domains".to_vec(), query(&[Scope::Domains]));
+        queries
+            .entries
+            .insert(b"assets".to_vec(), query(&[Scope::Assets]));
+
+        let account: AccountId =
+            "ed0120CE7FA46C9DCE7EA4B125E2E36BDB63EA33073E7590AC92816AE1E861B7048B03@wonderland"
+                .parse()
+                .unwrap();
+        let event = DataEvent::Domain(DomainEvent::Account(AccountEvent::Deleted(account)));
+        queries.invalidate(Scope::of_event(&event));
+
+        assert!(queries.entries.contains_key(b"domains".as_slice()));
+        assert!(!queries.entries.contains_key(b"assets".as_slice()));
+        assert_eq!(queries.generation, 1);
+    }
+}
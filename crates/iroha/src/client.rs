@@ -1,13 +1,7 @@
 //! Contains the end-point querying logic.  This is where you need to
 //! add any custom end-point related logic.
 
-use std::{
-    collections::HashMap,
-    fmt::Debug,
-    num::{NonZeroU32, NonZeroU64},
-    thread,
-    time::Duration,
-};
+use std::{collections::HashMap, fmt::Debug, num::NonZeroU64, sync::Arc, thread, time::Duration};
 
 use derive_more::{DebugCustom, Display};
 use eyre::{eyre, Result, WrapErr};
@@ -15,34 +9,69 @@ use futures_util::StreamExt;
 use http_default::{AsyncWebSocketStream, WebSocketStream};
 pub use iroha_config::client_api::ConfigGetDTO;
 use iroha_config::client_api::ConfigUpdateDTO;
+use iroha_executor_data_model::isi::multisig::{
+    MultisigApprove, MultisigProposalValue, MultisigPropose,
+};
 use iroha_logger::prelude::*;
-pub use iroha_telemetry::metrics::{Status, Uptime};
-use iroha_torii_shared::{uri as torii_uri, Version};
+pub use iroha_telemetry::metrics::{InstructionStats, Status, Uptime};
+pub use iroha_torii_shared::MultisigApprovals;
+use iroha_torii_shared::{uri as torii_uri, QueueFull, Version};
 use iroha_version::prelude::*;
 use parity_scale_codec::DecodeAll;
-use rand::Rng;
 use url::Url;
 
-use self::{blocks_api::AsyncBlockStream, events_api::AsyncEventStream};
-pub use crate::query::QueryError;
+pub use self::{
+    assets::Assets,
+    async_client::AsyncClient,
+    blocks_api::ResumingBlockIterator,
+    builder::ClientBuilder,
+    events_api::{ResilientEventIterator, TransactionProgress, TransactionProgressStream},
+    network_status::{NetworkStatus, OnlinePeerStatus, PeerStatus},
+    wasm::{WasmPreflightError, WasmUpload},
+};
+use self::{
+    blocks_api::{AsyncBlockHeaderStream, AsyncBlockStream},
+    events_api::{AsyncEventStream, AsyncSequencedEventStream},
+};
 use crate::{
-    config::Config,
-    crypto::{HashOf, KeyPair},
+    config::{Config, Proxy},
+    crypto::HashOf,
     data_model::{
-        block::SignedBlock,
+        block::{stream::BlockHeaderMessage, SignedBlock},
         events::pipeline::{
             BlockEventFilter, BlockStatus, PipelineEventBox, PipelineEventFilterBox,
             TransactionEventFilter, TransactionStatus,
         },
         isi::Instruction,
         prelude::*,
-        transaction::TransactionBuilder,
+        transaction::{
+            error::TransactionRejectionReason,
+            inspection::TransactionInspection,
+            receipt::TransactionReceipt,
+            simulation::{TransactionEstimate, TransactionSimulation},
+            TransactionBuilder, TransactionSignature,
+        },
         ChainId,
     },
     http::{Method as HttpMethod, RequestBuilder, Response, StatusCode},
     http_default::{self, DefaultRequestBuilder, WebSocketError, WebSocketMessage},
+    nonce::NonceManager,
+    retry::RetryPolicy,
+    signer::Signer,
+};
+pub use crate::{
+    http_default::HttpPool,
+    query::{AsyncQueryBuilderExt, QueryError},
 };
 
+mod assets;
+mod async_client;
+mod builder;
+#[cfg(feature = "mock-client")]
+pub mod mock;
+mod network_status;
+mod wasm;
+
 const APPLICATION_JSON: &str = "application/json";
 
 /// `Result` with [`QueryError`] as an error
@@ -53,9 +82,25 @@ pub type QueryResult<T> = core::result::Result<T, QueryError>;
 struct TransactionResponseHandler;
 
 impl TransactionResponseHandler {
+    /// Same as [`Self::handle`], but for the response to the last of `attempts` submissions.
+    ///
+    /// A duplicate of the transaction is rejected with `409 Conflict`,
+    /// so the conflict on a retry means that one of the previous attempts has been accepted.
+    fn handle_attempts(resp: &Response<Vec<u8>>, attempts: u32) -> Result<()> {
+        if attempts > 1 && resp.status() == StatusCode::CONFLICT {
+            return Ok(());
+        }
+        Self::handle(resp)
+    }
+
     fn handle(resp: &Response<Vec<u8>>) -> Result<()> {
         if resp.status() == StatusCode::OK {
             Ok(())
+        } else if let Some(hint) = (resp.status() == StatusCode::SERVICE_UNAVAILABLE)
+            .then(|| serde_json::from_slice::<QueueFull>(resp.body()).ok())
+            .flatten()
+        {
+            Err(QueueFullError::from(hint).into())
         } else {
             Err(
                 ResponseReport::with_msg("Unexpected transaction response", resp)
@@ -66,6 +111,70 @@ impl TransactionResponseHandler {
     }
 }
 
+/// Transaction was rejected because the transaction queue of the peer is saturated
+///
+/// Can be recovered from the [`eyre::Report`] returned by the submit methods with
+/// [`eyre::Report::downcast_ref`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, thiserror::Error)]
+#[error("Transaction queue is full ({queue_depth}/{capacity}), retry after {retry_after:?}")]
+pub struct QueueFullError {
+    /// Number of transactions waiting in the queue when the transaction was rejected
+    pub queue_depth: u64,
+    /// Maximum number of transactions the queue can hold
+    pub capacity: u64,
+    /// Delay suggested by the peer before resubmitting the transaction
+    pub retry_after: Duration,
+}
+
+impl From<QueueFull> for QueueFullError {
+    fn from(
+        QueueFull {
+            queue_depth,
+            capacity,
+            retry_after_ms,
+        }: QueueFull,
+    ) -> Self {
+        Self {
+            queue_depth,
+            capacity,
+            retry_after: Duration::from_millis(retry_after_ms),
+        }
+    }
+}
+
+/// No matching event arrived within the timeout given to [`Client::wait_for_event`]
+///
+/// Can be recovered from the returned [`eyre::Report`] with [`eyre::Report::downcast_ref`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, thiserror::Error)]
+#[error("No matching event arrived within {timeout:?}")]
+pub struct EventTimeoutError {
+    /// Timeout which has elapsed
+    pub timeout: Duration,
+}
+
+/// Filter of the transactions waiting in the queue of the peer, see [`Client::pending_transactions`]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PendingTransactionsFilter {
+    authority: Option<AccountId>,
+    min_age: Option<Duration>,
+}
+
+impl PendingTransactionsFilter {
+    /// Only the transactions of the `authority`
+    #[must_use]
+    pub fn for_authority(mut self, authority: AccountId) -> Self {
+        self.authority = Some(authority);
+        self
+    }
+
+    /// Only the transactions created at least `age` ago
+    #[must_use]
+    pub fn older_than(mut self, age: Duration) -> Self {
+        self.min_age = Some(age);
+        self
+    }
+}
+
 /// Phantom struct that handles status check HTTP response
 #[derive(Clone, Copy)]
 pub struct StatusResponseHandler;
@@ -117,16 +226,16 @@ impl From<ResponseReport> for eyre::Report {
 #[derive(Clone, DebugCustom, Display)]
 #[debug(
     fmt = "Client {{ torii: {torii_url}, public_key: {} }}",
-    "key_pair.public_key()"
+    "signer.public_key()"
 )]
-#[display(fmt = "{}@{torii_url}", "key_pair.public_key()")]
+#[display(fmt = "{}@{torii_url}", "signer.public_key()")]
 pub struct Client {
     /// Unique id of the blockchain. Used for simple replay attack protection.
     pub chain: ChainId,
     /// Url for accessing Iroha node
     pub torii_url: Url,
-    /// Accounts keypair
-    pub key_pair: KeyPair,
+    /// Signer of the transactions and the queries of the account
+    pub signer: Arc<dyn Signer>,
     /// Transaction time to live in milliseconds
     pub transaction_ttl: Option<Duration>,
     /// Transaction status timeout
@@ -138,6 +247,12 @@ pub struct Client {
     /// If `true` add nonce, which makes different hashes for
     /// transactions which occur repeatedly and/or simultaneously
     pub add_transaction_nonce: bool,
+    /// Nonces and in-flight transactions of the accounts, shared by the clones of the client
+    pub nonces: Arc<NonceManager>,
+    /// How the submissions and the queries are retried after transient failures
+    pub retry_policy: RetryPolicy,
+    /// Pool of the connections the requests are sent through
+    pub http: HttpPool,
 }
 
 /// Representation of `Iroha` client.
@@ -150,42 +265,17 @@ impl Client {
 
     /// Constructor for client from configuration and headers
     ///
-    /// *Authorization* header will be added if `basic_auth` is presented
+    /// *Authorization* header will be added if `basic_auth` is presented.
+    /// See [`Client::builder`] to configure the client further.
+    ///
+    /// # Panics
+    /// If the TLS or proxy settings can't be applied, which is checked when the configuration is loaded
     #[inline]
-    pub fn with_headers(
-        Config {
-            chain,
-            account,
-            torii_api_url,
-            key_pair,
-            basic_auth,
-            transaction_add_nonce,
-            transaction_ttl,
-            transaction_status_timeout,
-        }: Config,
-        mut headers: HashMap<String, String>,
-    ) -> Self {
-        if let Some(basic_auth) = basic_auth {
-            let credentials = format!(
-                "{}:{}",
-                basic_auth.web_login,
-                basic_auth.password.expose_secret()
-            );
-            let engine = base64::engine::general_purpose::STANDARD;
-            let encoded = base64::engine::Engine::encode(&engine, credentials);
-            headers.insert(String::from("Authorization"), format!("Basic {encoded}"));
-        }
-
-        Self {
-            chain,
-            torii_url: torii_api_url,
-            key_pair,
-            transaction_ttl: Some(transaction_ttl),
-            transaction_status_timeout,
-            account,
-            headers,
-            add_transaction_nonce: transaction_add_nonce,
-        }
+    pub fn with_headers(configuration: Config, headers: HashMap<String, String>) -> Self {
+        Self::builder(configuration)
+            .headers(headers)
+            .build()
+            .expect("TLS and proxy settings should be checked when the configuration is loaded")
     }
 
     /// Builds transaction out of supplied instructions or wasm.
@@ -196,7 +286,29 @@ impl Client {
         &self,
         instructions: Exec,
         metadata: Metadata,
-    ) -> SignedTransaction {
+    ) -> Result<SignedTransaction> {
+        self.sign_transaction(self.transaction_builder(instructions, metadata))
+    }
+
+    /// Builds transaction out of supplied instructions or wasm, leaving it to be signed elsewhere.
+    ///
+    /// Meant for custody setups where the private key of the authority never leaves an air-gapped machine:
+    /// the [`UnsignedTransaction`] is exported as SCALE or JSON, signed there with [`UnsignedTransaction::sign_payload`]
+    /// and submitted along with the detached signature by [`Self::submit_with_signature`].
+    pub fn build_unsigned_transaction<Exec: Into<Executable>>(
+        &self,
+        instructions: Exec,
+        metadata: Metadata,
+    ) -> UnsignedTransaction {
+        self.transaction_builder(instructions, metadata)
+            .into_unsigned()
+    }
+
+    fn transaction_builder<Exec: Into<Executable>>(
+        &self,
+        instructions: Exec,
+        metadata: Metadata,
+    ) -> TransactionBuilder {
         let tx_builder = TransactionBuilder::new(self.chain.clone(), self.account.clone());
 
         let mut tx_builder = match instructions.into() {
@@ -208,21 +320,38 @@ impl Client {
             tx_builder.set_ttl(transaction_ttl);
         }
         if self.add_transaction_nonce {
-            let nonce = rand::thread_rng().gen::<NonZeroU32>();
-            tx_builder.set_nonce(nonce);
+            let reservation = self.nonces.reserve(&self.account);
+            tx_builder
+                .set_nonce(reservation.nonce)
+                .set_creation_time(reservation.creation_time);
         }
 
-        tx_builder
-            .with_metadata(metadata)
-            .sign(self.key_pair.private_key())
+        tx_builder.with_metadata(metadata)
     }
 
     /// Signs transaction
     ///
     /// # Errors
     /// Fails if signature generation fails
-    pub fn sign_transaction(&self, transaction: TransactionBuilder) -> SignedTransaction {
-        transaction.sign(self.key_pair.private_key())
+    pub fn sign_transaction(&self, transaction: TransactionBuilder) -> Result<SignedTransaction> {
+        transaction
+            .sign_with(|payload| self.signer.sign(payload))
+            .wrap_err("Failed to sign transaction")
+    }
+
+    /// Decode a SCALE-encoded signed transaction, e.g. captured from logs or received from a partner,
+    /// into a human-readable [`TransactionInspection`].
+    ///
+    /// Both versioned (as submitted to Torii) and bare encodings are accepted.
+    ///
+    /// # Errors
+    /// Fails if `bytes` don't hold a transaction
+    pub fn decode_transaction(bytes: &[u8]) -> Result<TransactionInspection> {
+        let transaction = SignedTransaction::decode_all_versioned(bytes)
+            .or_else(|_| SignedTransaction::decode_all(&mut &bytes[..]))
+            .wrap_err("Failed to decode signed transaction")?;
+
+        Ok(TransactionInspection::from(&transaction))
     }
 
     /// Instructions API entry point. Submits one Iroha Special Instruction to `Iroha` peers.
@@ -271,26 +400,105 @@ impl Client {
         instructions: impl IntoIterator<Item = I>,
         metadata: Metadata,
     ) -> Result<HashOf<SignedTransaction>> {
-        self.submit_transaction(&self.build_transaction(instructions, metadata))
+        self.submit_transaction(&self.build_transaction(instructions, metadata)?)
     }
 
     /// Submit a prebuilt transaction.
     /// Returns submitted transaction's hash or error string.
     ///
+    /// Transient failures, e.g. the saturated queue of the peer, are retried according to [`Client::retry_policy`].
+    ///
     /// # Errors
-    /// Fails if sending transaction to peer fails or if it response with error
+    /// Fails if sending transaction to peer fails or if it response with error.
+    /// Saturated queue is reported as [`QueueFullError`] once the retries are exhausted
     pub fn submit_transaction(
         &self,
         transaction: &SignedTransaction,
     ) -> Result<HashOf<SignedTransaction>> {
         iroha_logger::trace!(tx=?transaction, "Submitting");
-        let (req, hash) = self.prepare_transaction_request::<DefaultRequestBuilder>(transaction);
-        let response = req
+        let hash = transaction.hash();
+        let (response, attempts) = self.retry_policy.retry(|| {
+            self.prepare_transaction_request::<DefaultRequestBuilder>(transaction)
+                .0
+                .build()?
+                .send(&self.http)
+        });
+        let response =
+            response.wrap_err_with(|| format!("Failed to send transaction with hash {hash:?}"))?;
+        TransactionResponseHandler::handle_attempts(&response, attempts)?;
+        self.nonces.track(transaction);
+        Ok(hash)
+    }
+
+    /// Submit the transaction signed elsewhere, see [`Self::build_unsigned_transaction`].
+    /// Returns submitted transaction's hash or error string.
+    ///
+    /// # Errors
+    /// Fails if `signature` wasn't made over `transaction` by its authority
+    /// or if sending transaction to peer fails or if it response with error
+    pub fn submit_with_signature(
+        &self,
+        transaction: UnsignedTransaction,
+        signature: TransactionSignature,
+    ) -> Result<HashOf<SignedTransaction>> {
+        let transaction = transaction
+            .with_signature(signature)
+            .wrap_err("Signature doesn't match the transaction")?;
+        self.submit_transaction(&transaction)
+    }
+
+    /// Submit the prebuilt transaction and return the receipt the peer acknowledges it with.
+    ///
+    /// The receipt can be stored as the proof of the submission, see [`Self::verify_receipt`].
+    /// Unlike [`Self::submit_transaction`], the submission isn't retried,
+    /// since the peer rejects a resubmitted transaction without a receipt.
+    ///
+    /// # Errors
+    /// Fails if sending the transaction fails, the peer rejects it or doesn't respond with a valid receipt
+    pub fn submit_transaction_with_receipt(
+        &self,
+        transaction: &SignedTransaction,
+    ) -> Result<TransactionReceipt> {
+        let hash = transaction.hash();
+        let response = self
+            .prepare_transaction_request::<DefaultRequestBuilder>(transaction)
+            .0
             .build()?
-            .send()
+            .send(&self.http)
             .wrap_err_with(|| format!("Failed to send transaction with hash {hash:?}"))?;
         TransactionResponseHandler::handle(&response)?;
-        Ok(hash)
+        self.nonces.track(transaction);
+
+        let receipt: TransactionReceipt = serde_json::from_slice(response.body())
+            .wrap_err("Peer didn't respond with a transaction receipt")?;
+        if receipt.payload.tx_hash != hash {
+            return Err(eyre!(
+                "Receipt acknowledges transaction {:?} instead of {hash:?}",
+                receipt.payload.tx_hash
+            ));
+        }
+        receipt
+            .verify()
+            .wrap_err("Receipt isn't signed by the peer it names")?;
+        Ok(receipt)
+    }
+
+    /// Check that the `receipt` is signed by one of the peers of the chain.
+    ///
+    /// # Errors
+    /// Fails if the signature is invalid, the signing peer isn't in the topology or the peers can't be queried
+    pub fn verify_receipt(&self, receipt: &TransactionReceipt) -> Result<()> {
+        receipt
+            .verify()
+            .wrap_err("Receipt isn't signed by the peer it names")?;
+        let peers = self.query(FindPeers).execute_all()?;
+        if !peers.contains(&receipt.payload.peer) {
+            return Err(eyre!(
+                "Receipt is signed by {}, which isn't a peer of the chain",
+                receipt.payload.peer
+            ));
+        }
+        Ok(())
     }
 
     /// Submit the prebuilt transaction and wait until it is either rejected or committed.
@@ -319,6 +527,7 @@ impl Client {
             });
 
             let confirmation_res = self.listen_for_tx_confirmation(init_sender, hash);
+            self.nonces.complete(transaction.authority(), &hash);
 
             match submitter_handle.join() {
                 Ok(Ok(())) => confirmation_res,
@@ -328,6 +537,42 @@ impl Client {
         })
     }
 
+    /// Submit the prebuilt transaction and watch its progress through the pipeline.
+    ///
+    /// Unlike [`Self::submit_transaction_blocking`], every stage of the transaction is reported,
+    /// so that it can be shown to the user. The stream ends once the transaction is either committed or rejected.
+    /// No timeout is applied: it's up to the caller to stop waiting.
+    ///
+    /// # Errors
+    /// Fails if the event listener can't be connected or if sending the transaction fails
+    pub async fn submit_and_watch(
+        &self,
+        transaction: &SignedTransaction,
+    ) -> Result<TransactionProgressStream> {
+        let hash = transaction.hash();
+        tracing::debug!(%hash, ?transaction, "Submitting transaction");
+
+        // Subscribe before submitting, otherwise the events might be missed
+        let events = self
+            .listen_for_events_async(TransactionProgressStream::filters(hash))
+            .await
+            .wrap_err("Failed to establish event listener connection")?;
+
+        let client = self.clone();
+        let transaction = transaction.clone();
+        let submitted =
+            tokio::task::spawn_blocking(move || client.submit_transaction(&transaction))
+                .await
+                .wrap_err("Transaction submitter task panicked")
+                .and_then(std::convert::identity);
+        if let Err(error) = submitted {
+            events.close().await;
+            return Err(error);
+        }
+
+        Ok(TransactionProgressStream::new(events, hash))
+    }
+
     fn listen_for_tx_confirmation(
         &self,
         init_sender: tokio::sync::oneshot::Sender<bool>,
@@ -435,6 +680,217 @@ impl Client {
         )
     }
 
+    /// Propose `instructions` on behalf of the multisig `account` through the peer,
+    /// which checks and submits the proposal for the signatories to approve it with [`Self::approve_multisig`].
+    /// The proposal is executed within the transaction of the approval reaching the quorum.
+    ///
+    /// Returns the hash of the proposal the signatories fetch it by and the approvals once the proposal is committed.
+    ///
+    /// # Errors
+    /// Fails if the client account isn't a signatory of `account`, or if sending the proposal fails
+    pub fn propose_multisig(
+        &self,
+        account: AccountId,
+        instructions: impl IntoIterator<Item = impl Into<InstructionBox>>,
+    ) -> Result<(HashOf<Vec<InstructionBox>>, MultisigApprovals)> {
+        let instructions: Vec<InstructionBox> = instructions.into_iter().map(Into::into).collect();
+        let hash = HashOf::new(&instructions);
+        let transaction = self.build_transaction(
+            [InstructionBox::from(MultisigPropose::new(
+                account,
+                instructions,
+                None,
+            ))],
+            Metadata::default(),
+        )?;
+
+        let approvals = self.send_multisig(torii_uri::MULTISIG_PENDING.to_owned(), &transaction)?;
+        Ok((hash, approvals))
+    }
+
+    /// Get the transactions waiting in the queue of the peer to be included in a block, the oldest first.
+    ///
+    /// A transaction lingering in the queue is either not valid in the current state yet
+    /// or stays behind because the blocks are full.
+    ///
+    /// # Errors
+    /// Fails if sending the request or decoding the response fails
+    pub fn pending_transactions(
+        &self,
+        filter: &PendingTransactionsFilter,
+    ) -> Result<Vec<SignedTransaction>> {
+        let mut request = DefaultRequestBuilder::new(
+            HttpMethod::GET,
+            join_torii_url(&self.torii_url, torii_uri::PENDING_TRANSACTIONS),
+        )
+        .headers(&self.headers);
+        if let Some(authority) = &filter.authority {
+            request = request.param("authority", authority);
+        }
+        if let Some(min_age) = filter.min_age {
+            request = request.param("min_age_ms", &min_age.as_millis());
+        }
+        let resp = request
+            .build()?
+            .send(&self.http)
+            .wrap_err("Failed to fetch pending transactions")?;
+
+        if resp.status() != StatusCode::OK {
+            return Err(ResponseReport::with_msg(
+                "Unexpected pending transactions response",
+                &resp,
+            )
+            .unwrap_or_else(core::convert::identity)
+            .into());
+        }
+        Vec::<SignedTransaction>::decode_all(&mut resp.body().as_slice())
+            .wrap_err("Failed to decode pending transactions")
+    }
+
+    /// Fetch the proposal with the given `hash` pending approvals of the signatories of the multisig `account`,
+    /// e.g. to review its instructions before approving it
+    ///
+    /// # Errors
+    /// Fails if the proposal isn't pending, or if sending the request fails
+    pub fn pending_multisig_proposal(
+        &self,
+        account: &AccountId,
+        hash: HashOf<Vec<InstructionBox>>,
+    ) -> Result<MultisigProposalValue> {
+        let resp = DefaultRequestBuilder::new(
+            HttpMethod::GET,
+            join_torii_url(
+                &self.torii_url,
+                &format!("{}/{account}/{hash}", torii_uri::MULTISIG_PENDING),
+            ),
+        )
+        .headers(&self.headers)
+        .build()?
+        .send(&self.http)
+        .wrap_err_with(|| format!("Failed to fetch multisig proposal {hash}"))?;
+
+        if resp.status() != StatusCode::OK {
+            return Err(
+                ResponseReport::with_msg("Unexpected multisig proposal response", &resp)
+                    .unwrap_or_else(core::convert::identity)
+                    .into(),
+            );
+        }
+        serde_json::from_slice(resp.body()).wrap_err("Failed to decode multisig proposal")
+    }
+
+    /// Approve the pending proposal with the given `hash` of the multisig `account` through the peer,
+    /// which checks and submits the approval.
+    ///
+    /// # Errors
+    /// Fails if the proposal isn't pending, the client account isn't a signatory of `account`,
+    /// or if sending the approval fails
+    pub fn approve_multisig(
+        &self,
+        account: AccountId,
+        hash: HashOf<Vec<InstructionBox>>,
+    ) -> Result<MultisigApprovals> {
+        let path = format!("{}/{account}/{hash}/approvals", torii_uri::MULTISIG_PENDING);
+        let transaction = self.build_transaction(
+            [InstructionBox::from(MultisigApprove::new(account, hash))],
+            Metadata::default(),
+        )?;
+
+        self.send_multisig(path, &transaction)
+    }
+
+    fn send_multisig(
+        &self,
+        path: String,
+        transaction: &SignedTransaction,
+    ) -> Result<MultisigApprovals> {
+        let resp =
+            DefaultRequestBuilder::new(HttpMethod::POST, join_torii_url(&self.torii_url, &path))
+                .headers(&self.headers)
+                .body(transaction.encode_versioned())
+                .build()?
+                .send(&self.http)
+                .wrap_err_with(|| format!("Failed to send multisig transaction to {path}"))?;
+
+        if resp.status() != StatusCode::OK {
+            return Err(ResponseReport::with_msg(
+                "Unexpected multisig transaction response",
+                &resp,
+            )
+            .unwrap_or_else(core::convert::identity)
+            .into());
+        }
+        Ok(serde_json::from_slice(resp.body())?)
+    }
+
+    /// Execute the transaction against the current state of the peer without committing it.
+    ///
+    /// Reports whether the transaction would be committed, the decision of the executor on each of its instructions
+    /// up to the first rejected one and the events it would emit. The state may change by the time the transaction
+    /// is actually submitted.
+    ///
+    /// # Errors
+    /// Fails if sending the transaction fails or the peer doesn't accept it, e.g. because of an invalid signature,
+    /// an unregistered authority, a WASM smart contract or too many transactions being simulated at once
+    pub fn simulate(&self, transaction: &SignedTransaction) -> Result<TransactionSimulation> {
+        let resp = DefaultRequestBuilder::new(
+            HttpMethod::POST,
+            join_torii_url(&self.torii_url, torii_uri::TRANSACTION_SIMULATION),
+        )
+        .headers(&self.headers)
+        .body(transaction.encode_versioned())
+        .build()?
+        .send(&self.http)
+        .wrap_err_with(|| {
+            format!(
+                "Failed to simulate transaction with hash {:?}",
+                transaction.hash()
+            )
+        })?;
+
+        if resp.status() != StatusCode::OK {
+            return Err(eyre!(
+                "Failed to simulate transaction with HTTP status: {}. {}",
+                resp.status(),
+                std::str::from_utf8(resp.body()).unwrap_or(""),
+            ));
+        }
+        Ok(serde_json::from_slice(resp.body())?)
+    }
+
+    /// Estimate the resources the transaction consumes against the limits set by the current chain parameters,
+    /// e.g. to warn the user before submitting a transaction which would be rejected.
+    ///
+    /// Smart contracts are executed against the current state of the peer to meter the fuel they consume.
+    ///
+    /// # Errors
+    /// Fails if sending the transaction fails or the peer doesn't accept it, e.g. because of an invalid signature
+    pub fn estimate(&self, transaction: &SignedTransaction) -> Result<TransactionEstimate> {
+        let resp = DefaultRequestBuilder::new(
+            HttpMethod::POST,
+            join_torii_url(&self.torii_url, torii_uri::TRANSACTION_ESTIMATE),
+        )
+        .headers(&self.headers)
+        .body(transaction.encode_versioned())
+        .build()?
+        .send(&self.http)
+        .wrap_err_with(|| {
+            format!(
+                "Failed to estimate transaction with hash {:?}",
+                transaction.hash()
+            )
+        })?;
+
+        if resp.status() != StatusCode::OK {
+            return Err(eyre!(
+                "Failed to estimate transaction with HTTP status: {}. {}",
+                resp.status(),
+                std::str::from_utf8(resp.body()).unwrap_or(""),
+            ));
+        }
+        Ok(serde_json::from_slice(resp.body())?)
+    }
+
     /// Submits and waits until the transaction is either rejected or committed.
     /// Returns rejection reason if transaction was rejected.
     ///
@@ -484,7 +940,7 @@ impl Client {
         instructions: impl IntoIterator<Item = I>,
         metadata: Metadata,
     ) -> Result<HashOf<SignedTransaction>> {
-        let transaction = self.build_transaction(instructions, metadata);
+        let transaction = self.build_transaction(instructions, metadata)?;
         self.submit_transaction_blocking(&transaction)
     }
 
@@ -512,84 +968,316 @@ impl Client {
         events_api::AsyncEventStream::new(self.events_handler(event_filters)?).await
     }
 
-    /// Constructs an Events API handler. With it, you can use any WS client you want.
+    /// Wait until an event passing the `event_filter` and satisfying the `predicate` arrives.
+    ///
+    /// Only the events emitted after the connection is established are considered.
     ///
     /// # Errors
-    /// Fails if handler construction fails
-    #[inline]
-    pub fn events_handler(
+    /// - [`EventTimeoutError`] if no matching event arrives within the `timeout`
+    /// - Forwards from [`Self::wait_for_event_async`]
+    pub fn wait_for_event(
         &self,
-        event_filters: impl IntoIterator<Item = impl Into<EventFilterBox>>,
-    ) -> Result<events_api::flow::Init> {
-        events_api::flow::Init::new(
-            event_filters.into_iter().map(Into::into).collect(),
-            self.headers.clone(),
-            join_torii_url(&self.torii_url, torii_uri::SUBSCRIPTION),
-        )
+        event_filter: impl Into<EventFilterBox>,
+        predicate: impl FnMut(&EventBox) -> bool,
+        timeout: Duration,
+    ) -> Result<EventBox> {
+        let event_filter = event_filter.into();
+        tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()?
+            .block_on(self.wait_for_event_async(event_filter, predicate, timeout))
     }
 
-    /// Connect (through `WebSocket`) to listen for `Iroha` blocks
+    /// Wait asynchronously until an event passing the `event_filter` and satisfying the `predicate` arrives.
     ///
     /// # Errors
-    /// - Forwards from [`Self::events_handler`]
-    /// - Forwards from `blocks_api::BlockIterator::new`
-    pub fn listen_for_blocks(
+    /// - [`EventTimeoutError`] if no matching event arrives within the `timeout`
+    /// - Forwards from [`Self::listen_for_events_async`]
+    /// - If the connection reports an error or is closed by the peer
+    pub async fn wait_for_event_async(
         &self,
-        height: NonZeroU64,
-    ) -> Result<impl Iterator<Item = Result<SignedBlock>>> {
-        blocks_api::BlockIterator::new(self.blocks_handler(height)?)
+        event_filter: impl Into<EventFilterBox>,
+        mut predicate: impl FnMut(&EventBox) -> bool,
+        timeout: Duration,
+    ) -> Result<EventBox> {
+        let deadline = tokio::time::Instant::now() + timeout;
+        let mut events = tokio::time::timeout_at(
+            deadline,
+            self.listen_for_events_async([event_filter.into()]),
+        )
+        .await
+        .map_err(|_| EventTimeoutError { timeout })??;
+
+        let result = tokio::time::timeout_at(deadline, async {
+            while let Some(event) = events.next().await {
+                let event = event?;
+                if predicate(&event) {
+                    return Ok(event);
+                }
+            }
+            Err(eyre!("Connection dropped before a matching event arrived"))
+        })
+        .await
+        .map_err(|_| EventTimeoutError { timeout }.into())
+        .and_then(std::convert::identity);
+        events.close().await;
+        result
     }
 
-    /// Connect asynchronously (through `WebSocket`) to listen for `Iroha` blocks
+    /// Connect (through `WebSocket`) to listen for `Iroha` events along with their [`EventSequence`]s.
+    ///
+    /// Use [`events_api::EventConsumer`] to process the events exactly once across reconnects.
     ///
     /// # Errors
-    /// - Forwards from [`Self::events_handler`]
-    /// - Forwards from `blocks_api::BlockIterator::new`
-    pub async fn listen_for_blocks_async(&self, height: NonZeroU64) -> Result<AsyncBlockStream> {
-        blocks_api::AsyncBlockStream::new(self.blocks_handler(height)?).await
+    /// - Forwards from [`Self::sequenced_events_handler`]
+    /// - Forwards from `events_api::SequencedEventIterator::new`
+    pub fn listen_for_sequenced_events(
+        &self,
+        event_filters: impl IntoIterator<Item = impl Into<EventFilterBox>>,
+    ) -> Result<impl Iterator<Item = Result<EventMessage>>> {
+        let events = events_api::SequencedEventIterator::new(
+            self.sequenced_events_handler(event_filters, None)?,
+        )?;
+        Ok(events.map(|message| match message? {
+            SequencedEventMessage::Event(message) => Ok(message),
+            SequencedEventMessage::Lost => Err(eyre!("Peer reported lost events of a new stream")),
+        }))
     }
 
-    /// Construct a handler for Blocks API. With this handler you can use any WS client you want.
+    /// Connect (through `WebSocket`) to listen for `Iroha` events surviving the loss of the connection.
+    ///
+    /// When the connection is lost, the iterator reconnects and replays the events emitted in the meantime.
+    /// If the peer doesn't keep some of them anymore, an [`events_api::EventGap`] telling which part
+    /// of the stream was missed is yielded before the replayed events.
+    /// Errors reported by the connection are yielded before reconnecting.
+    /// The iterator ends once [`stream_api::RECONNECT_ATTEMPTS`] reconnects in a row fail.
     ///
     /// # Errors
-    /// - if handler construction fails
-    #[inline]
-    pub fn blocks_handler(&self, height: NonZeroU64) -> Result<blocks_api::flow::Init> {
-        blocks_api::flow::Init::new(
-            height,
-            self.headers.clone(),
-            join_torii_url(&self.torii_url, torii_uri::BLOCKS_STREAM),
+    /// - Forwards from [`Self::sequenced_events_handler`]
+    /// - Forwards from `events_api::SequencedEventIterator::new`
+    pub fn listen_for_events_resilient(
+        &self,
+        event_filters: impl IntoIterator<Item = impl Into<EventFilterBox>>,
+    ) -> Result<ResilientEventIterator> {
+        ResilientEventIterator::new(
+            self.clone(),
+            event_filters.into_iter().map(Into::into).collect(),
         )
     }
 
-    /// Get value of config on peer
+    /// Connect asynchronously (through `WebSocket`) to listen for `Iroha` events along with their [`EventSequence`]s.
+    /// The peer replays the events following the one with sequence `after` first, if given,
+    /// reporting [`SequencedEventMessage::Lost`] if some of them aren't kept anymore.
     ///
     /// # Errors
-    /// Fails if sending request or decoding fails
-    pub fn get_config(&self) -> Result<ConfigGetDTO> {
-        let resp = DefaultRequestBuilder::new(
-            HttpMethod::GET,
-            join_torii_url(&self.torii_url, torii_uri::CONFIGURATION),
+    /// - Forwards from [`Self::sequenced_events_handler`]
+    /// - Forwards from `events_api::AsyncSequencedEventStream::new`
+    pub async fn listen_for_sequenced_events_async(
+        &self,
+        event_filters: impl IntoIterator<Item = impl Into<EventFilterBox>> + Send,
+        after: Option<EventSequence>,
+    ) -> Result<AsyncSequencedEventStream> {
+        events_api::AsyncSequencedEventStream::new(
+            self.sequenced_events_handler(event_filters, after)?,
         )
-        .headers(&self.headers)
-        .header(http::header::CONTENT_TYPE, APPLICATION_JSON)
-        .build()?
-        .send()?;
+        .await
+    }
 
-        if resp.status() != StatusCode::OK {
-            return Err(eyre!(
-                "Failed to get configuration with HTTP status: {}. {}",
-                resp.status(),
-                std::str::from_utf8(resp.body()).unwrap_or(""),
-            ));
-        }
-        serde_json::from_slice(resp.body()).wrap_err("Failed to decode body")
+    /// Connect (through `WebSocket`) to listen for the events of the `domain` only.
+    ///
+    /// The peer doesn't send the events of the other domains, unlike [`Self::listen_for_events`]
+    /// with a domain filter. Events which don't belong to a single domain (e.g. pipeline events) are never sent.
+    ///
+    /// # Errors
+    /// - Forwards from [`Self::domain_events_handler`]
+    /// - Forwards from `events_api::EventIterator::new`
+    pub fn listen_for_domain_events(
+        &self,
+        domain: &DomainId,
+        event_filters: impl IntoIterator<Item = impl Into<EventFilterBox>>,
+    ) -> Result<impl Iterator<Item = Result<EventBox>>> {
+        events_api::EventIterator::new(self.domain_events_handler(domain, event_filters)?)
     }
 
-    /// Send a request to change the configuration of a specified field.
+    /// Connect asynchronously (through `WebSocket`) to listen for the events of the `domain` only.
     ///
     /// # Errors
-    /// If sending request or decoding fails
+    /// - Forwards from [`Self::domain_events_handler`]
+    /// - Forwards from `events_api::AsyncEventStream::new`
+    pub async fn listen_for_domain_events_async(
+        &self,
+        domain: &DomainId,
+        event_filters: impl IntoIterator<Item = impl Into<EventFilterBox>> + Send,
+    ) -> Result<AsyncEventStream> {
+        events_api::AsyncEventStream::new(self.domain_events_handler(domain, event_filters)?).await
+    }
+
+    /// Constructs an Events API handler for the events of the `domain`.
+    ///
+    /// # Errors
+    /// Fails if handler construction fails
+    #[inline]
+    pub fn domain_events_handler(
+        &self,
+        domain: &DomainId,
+        event_filters: impl IntoIterator<Item = impl Into<EventFilterBox>>,
+    ) -> Result<events_api::flow::Init> {
+        events_api::flow::Init::new(
+            event_filters.into_iter().map(Into::into).collect(),
+            self.headers.clone(),
+            join_torii_url(
+                &self.torii_url,
+                &format!("{}/{domain}", torii_uri::SUBSCRIPTION_DOMAIN),
+            ),
+            self.http.proxy().cloned(),
+        )
+    }
+
+    /// Constructs an Events API handler. With it, you can use any WS client you want.
+    ///
+    /// # Errors
+    /// Fails if handler construction fails
+    #[inline]
+    pub fn events_handler(
+        &self,
+        event_filters: impl IntoIterator<Item = impl Into<EventFilterBox>>,
+    ) -> Result<events_api::flow::Init> {
+        events_api::flow::Init::new(
+            event_filters.into_iter().map(Into::into).collect(),
+            self.headers.clone(),
+            join_torii_url(&self.torii_url, torii_uri::SUBSCRIPTION),
+            self.http.proxy().cloned(),
+        )
+    }
+
+    /// Constructs an Events API handler yielding the events along with their [`EventSequence`]s.
+    /// The peer replays the events following the one with sequence `after` first, if given.
+    ///
+    /// # Errors
+    /// Fails if handler construction fails
+    pub fn sequenced_events_handler(
+        &self,
+        event_filters: impl IntoIterator<Item = impl Into<EventFilterBox>>,
+        after: Option<EventSequence>,
+    ) -> Result<events_api::flow::SequencedInit> {
+        events_api::flow::Init::new(
+            event_filters.into_iter().map(Into::into).collect(),
+            self.headers.clone(),
+            join_torii_url(&self.torii_url, torii_uri::SUBSCRIPTION_SEQUENCED),
+            self.http.proxy().cloned(),
+        )
+        .map(|init| events_api::flow::SequencedInit::new(init, after))
+    }
+
+    /// Connect (through `WebSocket`) to listen for `Iroha` blocks starting from `height`
+    ///
+    /// When the connection is lost, the iterator reconnects and resumes from the block following
+    /// the last received one, so every block is yielded exactly once.
+    /// Errors reported by the connection are yielded before reconnecting.
+    /// The iterator ends once [`stream_api::RECONNECT_ATTEMPTS`] reconnects in a row fail.
+    ///
+    /// # Errors
+    /// - Forwards from [`Self::blocks_handler`]
+    /// - Forwards from `blocks_api::BlockIterator::new`
+    pub fn listen_for_blocks(&self, height: NonZeroU64) -> Result<ResumingBlockIterator> {
+        ResumingBlockIterator::new(self.clone(), height)
+    }
+
+    /// Connect asynchronously (through `WebSocket`) to listen for `Iroha` blocks
+    ///
+    /// # Errors
+    /// - Forwards from [`Self::events_handler`]
+    /// - Forwards from `blocks_api::BlockIterator::new`
+    pub async fn listen_for_blocks_async(&self, height: NonZeroU64) -> Result<AsyncBlockStream> {
+        blocks_api::AsyncBlockStream::new(self.blocks_handler(height)?).await
+    }
+
+    /// Construct a handler for Blocks API. With this handler you can use any WS client you want.
+    ///
+    /// # Errors
+    /// - if handler construction fails
+    #[inline]
+    pub fn blocks_handler(&self, height: NonZeroU64) -> Result<blocks_api::flow::Init> {
+        blocks_api::flow::Init::new(
+            height,
+            self.headers.clone(),
+            join_torii_url(&self.torii_url, torii_uri::BLOCKS_STREAM),
+            self.http.proxy().cloned(),
+        )
+    }
+
+    /// Connect (through `WebSocket`) to listen for headers of `Iroha` blocks.
+    ///
+    /// Transactions are not transferred, which makes it suitable for watch-only clients
+    /// following the chain progress on a tight bandwidth budget.
+    ///
+    /// # Errors
+    /// - Forwards from [`Self::block_headers_handler`]
+    /// - Forwards from `blocks_api::BlockHeaderIterator::new`
+    pub fn listen_for_block_headers(
+        &self,
+        height: NonZeroU64,
+    ) -> Result<impl Iterator<Item = Result<BlockHeaderMessage>>> {
+        blocks_api::BlockHeaderIterator::new(self.block_headers_handler(height)?)
+    }
+
+    /// Connect asynchronously (through `WebSocket`) to listen for headers of `Iroha` blocks
+    ///
+    /// # Errors
+    /// - Forwards from [`Self::block_headers_handler`]
+    /// - Forwards from `blocks_api::AsyncBlockHeaderStream::new`
+    pub async fn listen_for_block_headers_async(
+        &self,
+        height: NonZeroU64,
+    ) -> Result<AsyncBlockHeaderStream> {
+        blocks_api::AsyncBlockHeaderStream::new(self.block_headers_handler(height)?).await
+    }
+
+    /// Construct a handler for the block headers stream. With this handler you can use any WS client you want.
+    ///
+    /// # Errors
+    /// - if handler construction fails
+    #[inline]
+    pub fn block_headers_handler(
+        &self,
+        height: NonZeroU64,
+    ) -> Result<blocks_api::flow::HeadersInit> {
+        blocks_api::flow::HeadersInit::new(
+            height,
+            self.headers.clone(),
+            join_torii_url(&self.torii_url, torii_uri::BLOCK_HEADERS_STREAM),
+            self.http.proxy().cloned(),
+        )
+    }
+
+    /// Get value of config on peer
+    ///
+    /// # Errors
+    /// Fails if sending request or decoding fails
+    pub fn get_config(&self) -> Result<ConfigGetDTO> {
+        let resp = DefaultRequestBuilder::new(
+            HttpMethod::GET,
+            join_torii_url(&self.torii_url, torii_uri::CONFIGURATION),
+        )
+        .headers(&self.headers)
+        .header(http::header::CONTENT_TYPE, APPLICATION_JSON)
+        .build()?
+        .send(&self.http)?;
+
+        if resp.status() != StatusCode::OK {
+            return Err(eyre!(
+                "Failed to get configuration with HTTP status: {}. {}",
+                resp.status(),
+                std::str::from_utf8(resp.body()).unwrap_or(""),
+            ));
+        }
+        serde_json::from_slice(resp.body()).wrap_err("Failed to decode body")
+    }
+
+    /// Send a request to change the configuration of a specified field.
+    ///
+    /// # Errors
+    /// If sending request or decoding fails
     pub fn set_config(&self, dto: &ConfigUpdateDTO) -> Result<()> {
         let body = serde_json::to_vec(&dto).wrap_err(format!("Failed to serialize {dto:?}"))?;
         let url = join_torii_url(&self.torii_url, torii_uri::CONFIGURATION);
@@ -598,7 +1286,7 @@ impl Client {
             .header(http::header::CONTENT_TYPE, APPLICATION_JSON)
             .body(body)
             .build()?
-            .send()?;
+            .send(&self.http)?;
 
         if resp.status() != StatusCode::ACCEPTED {
             return Err(eyre!(
@@ -619,7 +1307,28 @@ impl Client {
         let req = self
             .prepare_status_request::<DefaultRequestBuilder>()
             .header(http::header::ACCEPT, "application/x-parity-scale");
-        let resp = req.build()?.send()?;
+        let resp = req.build()?.send(&self.http)?;
+        let scaled_resp = StatusResponseHandler::handle(&resp).cloned()?;
+        DecodeAll::decode_all(&mut scaled_resp.as_slice()).map_err(|err| eyre!("{err}"))
+    }
+
+    /// Gets the instructions executed within the `window` ending now,
+    /// counted by type and by the domain of the transaction authority.
+    ///
+    /// The peer keeps the counts of the last 24 hours only.
+    ///
+    /// # Errors
+    /// Fails if sending request or decoding fails
+    pub fn get_instruction_stats(&self, window: Duration) -> Result<InstructionStats> {
+        let resp = DefaultRequestBuilder::new(
+            HttpMethod::GET,
+            join_torii_url(&self.torii_url, torii_uri::INSTRUCTION_STATS),
+        )
+        .headers(self.headers.clone())
+        .header(http::header::ACCEPT, "application/x-parity-scale")
+        .param("window_ms", &window.as_millis())
+        .build()?
+        .send(&self.http)?;
         let scaled_resp = StatusResponseHandler::handle(&resp).cloned()?;
         DecodeAll::decode_all(&mut scaled_resp.as_slice()).map_err(|err| eyre!("{err}"))
     }
@@ -648,7 +1357,7 @@ impl Client {
         .headers(&self.headers)
         .header(http::header::CONTENT_TYPE, APPLICATION_JSON)
         .build()?
-        .send()?;
+        .send(&self.http)?;
 
         if resp.status() != StatusCode::OK {
             return Err(eyre!(
@@ -704,13 +1413,17 @@ pub mod stream_api {
             handler: I,
         ) -> Result<SyncIterator<I::Next>> {
             trace!("Creating `SyncIterator`");
+            let proxy = Init::<DefaultWebSocketRequestBuilder>::proxy(&handler).cloned();
             let InitData {
                 first_message,
                 req,
                 next: next_handler,
             } = Init::<http_default::DefaultWebSocketRequestBuilder>::init(handler);
 
-            let mut stream = req.build()?.connect()?;
+            let mut stream = match &proxy {
+                Some(proxy) => req.build()?.connect_through(proxy)?,
+                None => req.build()?.connect()?,
+            };
             stream.send(WebSocketMessage::Binary(first_message))?;
 
             trace!("`SyncIterator` created successfully");
@@ -788,13 +1501,17 @@ pub mod stream_api {
             handler: I,
         ) -> Result<AsyncStream<I::Next>> {
             trace!("Creating `AsyncStream`");
+            let proxy = Init::<DefaultWebSocketRequestBuilder>::proxy(&handler).cloned();
             let InitData {
                 first_message,
                 req,
                 next: next_handler,
             } = Init::<http_default::DefaultWebSocketRequestBuilder>::init(handler);
 
-            let mut stream = req.build()?.connect_async().await?;
+            let mut stream = match &proxy {
+                Some(proxy) => req.build()?.connect_through_async(proxy).await?,
+                None => req.build()?.connect_async().await?,
+            };
             stream.send(WebSocketMessage::Binary(first_message)).await?;
 
             trace!("`AsyncStream` created successfully");
@@ -841,6 +1558,35 @@ pub mod stream_api {
             }
         }
     }
+
+    /// Number of reconnects attempted in a row before a resumable stream gives up
+    pub const RECONNECT_ATTEMPTS: u32 = 5;
+    /// Delay before the first reconnect, doubled with every failed attempt
+    pub const RECONNECT_DELAY: Duration = Duration::from_millis(500);
+
+    /// Call `connect` until it succeeds, backing off exponentially before every attempt.
+    ///
+    /// # Errors
+    /// Forwards the error of the last attempt once [`RECONNECT_ATTEMPTS`] attempts fail
+    pub(super) fn reconnect<T>(mut connect: impl FnMut() -> Result<T>) -> Result<T> {
+        let mut delay = RECONNECT_DELAY;
+        let mut attempt = 1;
+        loop {
+            std::thread::sleep(delay);
+            match connect() {
+                Ok(connection) => {
+                    debug!(attempt, "Reconnected");
+                    return Ok(connection);
+                }
+                Err(error) if attempt < RECONNECT_ATTEMPTS => {
+                    warn!(?error, attempt, ?delay, "Failed to reconnect");
+                    delay *= 2;
+                    attempt += 1;
+                }
+                Err(error) => return Err(error),
+            }
+        }
+    }
 }
 
 /// Logic related to Events API client implementation.
@@ -864,10 +1610,12 @@ pub mod events_api {
             headers: HashMap<String, String>,
             /// Event filter
             filters: Vec<EventFilterBox>,
+            /// Proxy the connection is tunneled through
+            proxy: Option<Proxy>,
         }
 
         impl Init {
-            /// Construct new item with provided filter, headers and url.
+            /// Construct new item with provided filter, headers, url and proxy.
             ///
             /// # Errors
             /// Fails if [`transform_ws_url`] fails.
@@ -876,11 +1624,13 @@ pub mod events_api {
                 filters: Vec<EventFilterBox>,
                 headers: HashMap<String, String>,
                 url: Url,
+                proxy: Option<Proxy>,
             ) -> Result<Self> {
                 Ok(Self {
                     url: transform_ws_url(url)?,
                     headers,
                     filters,
+                    proxy,
                 })
             }
         }
@@ -893,11 +1643,16 @@ pub mod events_api {
                     url,
                     headers,
                     filters,
+                    proxy: _,
                 } = self;
 
                 let msg = EventSubscriptionRequest::new(filters).encode();
                 InitData::new(R::new(HttpMethod::GET, url).headers(headers), msg, Events)
             }
+
+            fn proxy(&self) -> Option<&Proxy> {
+                self.proxy.as_ref()
+            }
         }
 
         /// Events handler for Events API flow
@@ -908,8 +1663,57 @@ pub mod events_api {
             type Event = crate::data_model::prelude::EventBox;
 
             fn message(&self, message: Vec<u8>) -> Result<Self::Event> {
-                let event_socket_message = EventMessage::decode_all(&mut message.as_slice())?;
-                Ok(event_socket_message.into())
+                Ok(Self::Event::decode_all(&mut message.as_slice())?)
+            }
+        }
+
+        /// Initialization struct for Events API flow yielding events along with their sequences.
+        pub struct SequencedInit {
+            init: Init,
+            /// Sequence of the event to resume the stream after
+            after: Option<EventSequence>,
+        }
+
+        impl SequencedInit {
+            /// Construct new item resuming the stream of `init` after the event with sequence `after`, if any.
+            pub(in super::super) fn new(init: Init, after: Option<EventSequence>) -> Self {
+                Self { init, after }
+            }
+        }
+
+        impl<R: RequestBuilder> FlowInit<R> for SequencedInit {
+            type Next = SequencedEvents;
+
+            fn init(self) -> InitData<R, Self::Next> {
+                let Init {
+                    url,
+                    headers,
+                    filters,
+                    proxy: _,
+                } = self.init;
+
+                let msg = SequencedEventSubscriptionRequest::new(filters, self.after).encode();
+                InitData::new(
+                    R::new(HttpMethod::GET, url).headers(headers),
+                    msg,
+                    SequencedEvents,
+                )
+            }
+
+            fn proxy(&self) -> Option<&Proxy> {
+                FlowInit::<R>::proxy(&self.init)
+            }
+        }
+
+        /// Events handler for Events API flow yielding events along with their sequences
+        #[derive(Debug, Copy, Clone)]
+        pub struct SequencedEvents;
+
+        impl FlowEvents for SequencedEvents {
+            type Event = SequencedEventMessage;
+
+            fn message(&self, message: Vec<u8>) -> Result<Self::Event> {
+                Ok(SequencedEventMessage::decode_all(&mut message.as_slice())?)
             }
         }
     }
@@ -919,6 +1723,412 @@ pub mod events_api {
 
     /// Async stream for getting events from the `WebSocket` stream.
     pub type AsyncEventStream = stream_api::AsyncStream<flow::Events>;
+
+    /// Iterator for getting sequenced events from the `WebSocket` stream.
+    pub(super) type SequencedEventIterator = stream_api::SyncIterator<flow::SequencedEvents>;
+
+    /// Async stream for getting sequenced events from the `WebSocket` stream.
+    pub type AsyncSequencedEventStream = stream_api::AsyncStream<flow::SequencedEvents>;
+
+    /// Item yielded by [`ResilientEventIterator`].
+    #[derive(Debug, Clone)]
+    pub enum EventStreamItem {
+        /// Event received from the peer
+        Event(EventMessage),
+        /// Connection was lost and some of the events emitted until it was restored are missing
+        Gap(EventGap),
+    }
+
+    /// Span of the event stream which was missed while reconnecting.
+    ///
+    /// Peers keep the recent events only, the ones emitted by the blocks committed while
+    /// the connection was lost are replayed as long as the peer still keeps them.
+    /// Otherwise, the changes they report can be recovered from the blocks
+    /// committed since `last_seen.block_height` up to `resumed_at`,
+    /// see [`Client::listen_for_blocks`].
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub struct EventGap {
+        /// Sequence of the last event received before the connection was lost, if any
+        pub last_seen: Option<EventSequence>,
+        /// Blockchain height once the connection was restored
+        pub resumed_at: u64,
+    }
+
+    /// Iterator for getting events which survives the loss of the connection, see [`Client::listen_for_events_resilient`].
+    pub struct ResilientEventIterator {
+        client: Client,
+        filters: Vec<EventFilterBox>,
+        last_seen: Option<EventSequence>,
+        connection: Option<SequencedEventIterator>,
+        exhausted: bool,
+    }
+
+    impl ResilientEventIterator {
+        /// Subscribe to the events matching `filters`.
+        ///
+        /// # Errors
+        /// Fails if the first connection can't be established
+        pub(super) fn new(client: Client, filters: Vec<EventFilterBox>) -> Result<Self> {
+            let connection = SequencedEventIterator::new(
+                client.sequenced_events_handler(filters.clone(), None)?,
+            )?;
+            Ok(Self {
+                client,
+                filters,
+                last_seen: None,
+                connection: Some(connection),
+                exhausted: false,
+            })
+        }
+
+        /// Sequence of the last yielded event, i.e. the point the stream resumes from.
+        pub fn last_seen(&self) -> Option<EventSequence> {
+            self.last_seen
+        }
+
+        /// Subscribe again, replaying the events following the last seen one.
+        fn reconnect(&self) -> Result<SequencedEventIterator> {
+            debug!(last_seen = ?self.last_seen, "Resuming events stream");
+            stream_api::reconnect(|| {
+                SequencedEventIterator::new(
+                    self.client
+                        .sequenced_events_handler(self.filters.clone(), self.last_seen)?,
+                )
+            })
+        }
+    }
+
+    impl Iterator for ResilientEventIterator {
+        type Item = Result<EventStreamItem>;
+
+        fn next(&mut self) -> Option<Self::Item> {
+            loop {
+                if self.exhausted {
+                    return None;
+                }
+                let Some(connection) = self.connection.as_mut() else {
+                    match self.reconnect() {
+                        Ok(connection) => {
+                            self.connection = Some(connection);
+                            continue;
+                        }
+                        Err(error) => {
+                            self.exhausted = true;
+                            return Some(
+                                Err(error).wrap_err("Failed to resume events stream, giving up"),
+                            );
+                        }
+                    }
+                };
+
+                match connection.next() {
+                    Some(Ok(SequencedEventMessage::Lost)) => {
+                        let gap = self.client.get_status().map(|status| EventGap {
+                            last_seen: self.last_seen,
+                            resumed_at: status.blocks,
+                        });
+                        if gap.is_err() {
+                            // NOTE: the peer reports the loss again once reconnected
+                            self.connection = None;
+                        }
+                        return Some(gap.map(EventStreamItem::Gap));
+                    }
+                    Some(Ok(SequencedEventMessage::Event(message))) => {
+                        if let Some(sequence) = message.sequence {
+                            if self.last_seen >= Some(sequence) {
+                                continue;
+                            }
+                            self.last_seen = Some(sequence);
+                        }
+                        return Some(Ok(EventStreamItem::Event(message)));
+                    }
+                    Some(Err(error)) => {
+                        self.connection = None;
+                        return Some(Err(error));
+                    }
+                    None => {
+                        debug!(last_seen = ?self.last_seen, "Events stream closed, reconnecting");
+                        self.connection = None;
+                    }
+                }
+            }
+        }
+    }
+
+    /// Stage of a submitted transaction in the pipeline, see [`Client::submit_and_watch`].
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub enum TransactionProgress {
+        /// Transaction was accepted into the queue of the peer
+        Queued,
+        /// Transaction was included in a block, which is being validated by the peers
+        Validating {
+            /// Height of the block including the transaction
+            block_height: NonZeroU64,
+        },
+        /// Block including the transaction was committed
+        Committed {
+            /// Height of the block including the transaction
+            block_height: NonZeroU64,
+        },
+        /// Transaction was rejected
+        Rejected(TransactionRejectionReason),
+        /// Transaction wasn't included in a block in time and was dropped from the queue
+        Expired,
+    }
+
+    impl TransactionProgress {
+        /// Whether the transaction won't make any further progress.
+        pub fn is_final(&self) -> bool {
+            matches!(
+                self,
+                Self::Committed { .. } | Self::Rejected(_) | Self::Expired
+            )
+        }
+    }
+
+    /// Async stream of the [`TransactionProgress`] of a submitted transaction, see [`Client::submit_and_watch`].
+    ///
+    /// Ends after the final stage.
+    pub struct TransactionProgressStream {
+        events: AsyncEventStream,
+        hash: HashOf<SignedTransaction>,
+        block_height: Option<NonZeroU64>,
+        finished: bool,
+    }
+
+    impl TransactionProgressStream {
+        pub(super) fn new(events: AsyncEventStream, hash: HashOf<SignedTransaction>) -> Self {
+            Self {
+                events,
+                hash,
+                block_height: None,
+                finished: false,
+            }
+        }
+
+        /// Filters of the events reporting the progress of the transaction with `hash`.
+        pub(super) fn filters(hash: HashOf<SignedTransaction>) -> Vec<PipelineEventFilterBox> {
+            vec![
+                TransactionEventFilter::default().for_hash(hash).into(),
+                BlockEventFilter::default()
+                    .for_status(BlockStatus::Applied)
+                    .into(),
+            ]
+        }
+
+        /// Hash of the watched transaction
+        pub fn hash(&self) -> HashOf<SignedTransaction> {
+            self.hash
+        }
+
+        fn progress(&mut self, event: EventBox) -> Option<TransactionProgress> {
+            let EventBox::Pipeline(event) = event else {
+                return None;
+            };
+            match event {
+                PipelineEventBox::Transaction(event) => match event.status() {
+                    TransactionStatus::Queued => Some(TransactionProgress::Queued),
+                    TransactionStatus::Approved => {
+                        self.block_height = event.block_height();
+                        self.block_height
+                            .map(|block_height| TransactionProgress::Validating { block_height })
+                    }
+                    TransactionStatus::Rejected(reason) => {
+                        Some(TransactionProgress::Rejected((**reason).clone()))
+                    }
+                    TransactionStatus::Expired => Some(TransactionProgress::Expired),
+                },
+                PipelineEventBox::Block(event) => {
+                    let block_height = event.header().height();
+                    (Some(block_height) == self.block_height
+                        && matches!(event.status(), BlockStatus::Applied))
+                    .then_some(TransactionProgress::Committed { block_height })
+                }
+            }
+        }
+    }
+
+    impl futures_util::Stream for TransactionProgressStream {
+        type Item = Result<TransactionProgress>;
+
+        fn poll_next(
+            mut self: std::pin::Pin<&mut Self>,
+            cx: &mut std::task::Context<'_>,
+        ) -> std::task::Poll<Option<Self::Item>> {
+            loop {
+                if self.finished {
+                    return std::task::Poll::Ready(None);
+                }
+                let item = match futures_util::ready!(self.events.poll_next_unpin(cx)) {
+                    Some(Ok(event)) => match self.progress(event) {
+                        Some(progress) => Ok(progress),
+                        None => continue,
+                    },
+                    Some(Err(error)) => Err(error),
+                    None => Err(eyre!(
+                        "Connection dropped without `Committed` or `Rejected` event"
+                    )),
+                };
+                self.finished = item.as_ref().map_or(true, TransactionProgress::is_final);
+                return std::task::Poll::Ready(Some(item));
+            }
+        }
+    }
+
+    /// Storage of the [`EventSequence`] of the last event processed by [`EventConsumer`].
+    pub trait Checkpoint {
+        /// Load sequence of the last processed event, if there is any.
+        ///
+        /// # Errors
+        /// Implementation specific
+        fn load(&self) -> Result<Option<EventSequence>>;
+
+        /// Store sequence of the last processed event.
+        ///
+        /// # Errors
+        /// Implementation specific
+        fn store(&mut self, sequence: EventSequence) -> Result<()>;
+    }
+
+    /// [`Checkpoint`] kept in memory, i.e. lost when the process exits.
+    #[derive(Debug, Default, Clone, Copy)]
+    pub struct MemoryCheckpoint(Option<EventSequence>);
+
+    impl Checkpoint for MemoryCheckpoint {
+        fn load(&self) -> Result<Option<EventSequence>> {
+            Ok(self.0)
+        }
+
+        fn store(&mut self, sequence: EventSequence) -> Result<()> {
+            self.0 = Some(sequence);
+            Ok(())
+        }
+    }
+
+    /// [`Checkpoint`] persisted in a JSON file.
+    #[derive(Debug, Clone)]
+    pub struct FileCheckpoint {
+        path: std::path::PathBuf,
+    }
+
+    impl FileCheckpoint {
+        /// Construct [`FileCheckpoint`] stored at `path`. The file is created on the first store.
+        pub fn new(path: impl Into<std::path::PathBuf>) -> Self {
+            Self { path: path.into() }
+        }
+    }
+
+    impl Checkpoint for FileCheckpoint {
+        fn load(&self) -> Result<Option<EventSequence>> {
+            match std::fs::read(&self.path) {
+                Ok(content) => serde_json::from_slice(&content)
+                    .map(Some)
+                    .wrap_err_with(|| eyre!("Failed to parse checkpoint {}", self.path.display())),
+                Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(None),
+                Err(err) => Err(err)
+                    .wrap_err_with(|| eyre!("Failed to read checkpoint {}", self.path.display())),
+            }
+        }
+
+        fn store(&mut self, sequence: EventSequence) -> Result<()> {
+            // NOTE: write to a temporary file first so that the checkpoint is never left half-written
+            let tmp = self.path.with_extension("tmp");
+            std::fs::write(&tmp, serde_json::to_vec(&sequence)?)
+                .and_then(|()| std::fs::rename(&tmp, &self.path))
+                .wrap_err_with(|| eyre!("Failed to store checkpoint {}", self.path.display()))
+        }
+    }
+
+    /// Processes sequenced events exactly once.
+    ///
+    /// Progress is saved to a [`Checkpoint`] after every successfully handled event,
+    /// and events with sequences not greater than the checkpoint are skipped. This way
+    /// events redelivered after a reconnect or a restart of the consumer are not handled twice.
+    /// The events without a sequence, i.e. the ones emitted by the peer on its own, are handled as they arrive.
+    #[derive(Debug)]
+    pub struct EventConsumer<C> {
+        checkpoint: C,
+        last_processed: Option<EventSequence>,
+    }
+
+    impl<C: Checkpoint> EventConsumer<C> {
+        /// Construct [`EventConsumer`] resuming from the `checkpoint`.
+        ///
+        /// # Errors
+        /// Fails if checkpoint can't be loaded
+        pub fn new(checkpoint: C) -> Result<Self> {
+            let last_processed = checkpoint.load()?;
+            Ok(Self {
+                checkpoint,
+                last_processed,
+            })
+        }
+
+        /// Sequence of the last successfully processed event.
+        pub fn last_processed(&self) -> Option<EventSequence> {
+            self.last_processed
+        }
+
+        /// Pass the event from `message` to `handler` unless it was already processed.
+        ///
+        /// Returns `true` if the event was handled and `false` if it was skipped as a duplicate.
+        ///
+        /// # Errors
+        /// Fails if `handler` fails or checkpoint can't be stored.
+        /// In both cases the checkpoint is not advanced.
+        pub fn process(
+            &mut self,
+            message: EventMessage,
+            handler: impl FnOnce(EventBox) -> Result<()>,
+        ) -> Result<bool> {
+            let EventMessage { sequence, event } = message;
+            let Some(sequence) = sequence else {
+                handler(event)?;
+                return Ok(true);
+            };
+            if self.last_processed.is_some_and(|last| sequence <= last) {
+                return Ok(false);
+            }
+
+            handler(event)?;
+            self.checkpoint.store(sequence)?;
+            self.last_processed = Some(sequence);
+            Ok(true)
+        }
+
+        /// Subscribe to events matching `event_filters` and process them with `handler`
+        /// until the connection is closed. Call again to resume after a disconnect:
+        /// the events following the last processed one are replayed first.
+        ///
+        /// # Errors
+        /// - Forwards from [`Client::sequenced_events_handler`]
+        /// - Forwards from [`Self::process`]
+        /// - Fails if the peer doesn't keep some of the events to replay anymore
+        pub fn run(
+            &mut self,
+            client: &Client,
+            event_filters: impl IntoIterator<Item = impl Into<EventFilterBox>>,
+            mut handler: impl FnMut(EventBox) -> Result<()>,
+        ) -> Result<()> {
+            let events = SequencedEventIterator::new(
+                client.sequenced_events_handler(event_filters, self.last_processed)?,
+            )?;
+            for message in events {
+                match message? {
+                    SequencedEventMessage::Event(message) => {
+                        self.process(message, &mut handler)?;
+                    }
+                    SequencedEventMessage::Lost => {
+                        return Err(eyre!(
+                            "Events following {:?} are lost, they can't be processed exactly once",
+                            self.last_processed
+                        ))
+                    }
+                }
+            }
+            Ok(())
+        }
+    }
 }
 
 mod blocks_api {
@@ -943,10 +2153,12 @@ mod blocks_api {
             headers: HashMap<String, String>,
             /// TORII URL
             url: Url,
+            /// Proxy the connection is tunneled through
+            proxy: Option<Proxy>,
         }
 
         impl Init {
-            /// Construct new item with provided headers and url.
+            /// Construct new item with provided headers, url and proxy.
             ///
             /// # Errors
             /// If [`transform_ws_url`] fails.
@@ -955,11 +2167,13 @@ mod blocks_api {
                 height: NonZeroU64,
                 headers: HashMap<String, String>,
                 url: Url,
+                proxy: Option<Proxy>,
             ) -> Result<Self> {
                 Ok(Self {
                     height,
                     headers,
                     url: transform_ws_url(url)?,
+                    proxy,
                 })
             }
         }
@@ -972,11 +2186,16 @@ mod blocks_api {
                     height,
                     headers,
                     url,
+                    proxy: _,
                 } = self;
 
                 let msg = BlockSubscriptionRequest::new(height).encode();
                 InitData::new(R::new(HttpMethod::GET, url).headers(headers), msg, Events)
             }
+
+            fn proxy(&self) -> Option<&Proxy> {
+                self.proxy.as_ref()
+            }
         }
 
         /// Events handler for Blocks API flow
@@ -990,13 +2209,155 @@ mod blocks_api {
                 Ok(BlockMessage::decode_all(&mut message.as_slice()).map(Into::into)?)
             }
         }
+
+        /// Initialization struct for the block headers flow of Blocks API.
+        pub struct HeadersInit(Init);
+
+        impl HeadersInit {
+            /// Construct new item with provided headers, url and proxy.
+            ///
+            /// # Errors
+            /// If [`transform_ws_url`] fails.
+            #[inline]
+            pub(in super::super) fn new(
+                height: NonZeroU64,
+                headers: HashMap<String, String>,
+                url: Url,
+                proxy: Option<Proxy>,
+            ) -> Result<Self> {
+                Init::new(height, headers, url, proxy).map(Self)
+            }
+        }
+
+        impl<R: RequestBuilder> FlowInit<R> for HeadersInit {
+            type Next = HeaderEvents;
+
+            fn init(self) -> InitData<R, Self::Next> {
+                let Init {
+                    height,
+                    headers,
+                    url,
+                    proxy: _,
+                } = self.0;
+
+                let msg = BlockSubscriptionRequest::new(height).encode();
+                InitData::new(
+                    R::new(HttpMethod::GET, url).headers(headers),
+                    msg,
+                    HeaderEvents,
+                )
+            }
+
+            fn proxy(&self) -> Option<&Proxy> {
+                self.0.proxy.as_ref()
+            }
+        }
+
+        /// Events handler for the block headers flow of Blocks API
+        #[derive(Debug, Copy, Clone)]
+        pub struct HeaderEvents;
+
+        impl FlowEvents for HeaderEvents {
+            type Event = BlockHeaderMessage;
+
+            fn message(&self, message: Vec<u8>) -> Result<Self::Event> {
+                Ok(BlockHeaderMessage::decode_all(&mut message.as_slice())?)
+            }
+        }
     }
 
     /// Iterator for getting blocks from the `WebSocket` stream.
     pub(super) type BlockIterator = stream_api::SyncIterator<flow::Events>;
 
+    /// Iterator for getting blocks which survives the loss of the connection, see [`Client::listen_for_blocks`].
+    pub struct ResumingBlockIterator {
+        client: Client,
+        next_height: NonZeroU64,
+        connection: Option<BlockIterator>,
+        exhausted: bool,
+    }
+
+    impl ResumingBlockIterator {
+        /// Connect to stream the blocks starting from `height`.
+        ///
+        /// # Errors
+        /// Fails if the first connection can't be established
+        pub(super) fn new(client: Client, height: NonZeroU64) -> Result<Self> {
+            let connection = BlockIterator::new(client.blocks_handler(height)?)?;
+            Ok(Self {
+                client,
+                next_height: height,
+                connection: Some(connection),
+                exhausted: false,
+            })
+        }
+
+        /// Height of the block which will be yielded next.
+        pub fn next_height(&self) -> NonZeroU64 {
+            self.next_height
+        }
+
+        fn reconnect(&self) -> Result<BlockIterator> {
+            debug!(height = %self.next_height, "Resuming blocks stream");
+            stream_api::reconnect(|| {
+                self.client
+                    .blocks_handler(self.next_height)
+                    .and_then(BlockIterator::new)
+            })
+        }
+    }
+
+    impl Iterator for ResumingBlockIterator {
+        type Item = Result<SignedBlock>;
+
+        fn next(&mut self) -> Option<Self::Item> {
+            loop {
+                if self.exhausted {
+                    return None;
+                }
+                let connection = match self.connection.as_mut() {
+                    Some(connection) => connection,
+                    None => match self.reconnect() {
+                        Ok(connection) => self.connection.insert(connection),
+                        Err(error) => {
+                            self.exhausted = true;
+                            return Some(
+                                Err(error).wrap_err("Failed to resume blocks stream, giving up"),
+                            );
+                        }
+                    },
+                };
+
+                match connection.next() {
+                    Some(Ok(block)) => {
+                        self.next_height = block
+                            .header()
+                            .height()
+                            .checked_add(1)
+                            .expect("INTERNAL BUG: Blockchain height exceeds u64::MAX");
+                        return Some(Ok(block));
+                    }
+                    Some(Err(error)) => {
+                        self.connection = None;
+                        return Some(Err(error));
+                    }
+                    None => {
+                        debug!(height = %self.next_height, "Blocks stream closed, reconnecting");
+                        self.connection = None;
+                    }
+                }
+            }
+        }
+    }
+
     /// Async stream for getting blocks from the `WebSocket` stream.
     pub type AsyncBlockStream = stream_api::AsyncStream<flow::Events>;
+
+    /// Iterator for getting block headers from the `WebSocket` stream.
+    pub(super) type BlockHeaderIterator = stream_api::SyncIterator<flow::HeaderEvents>;
+
+    /// Async stream for getting block headers from the `WebSocket` stream.
+    pub type AsyncBlockHeaderStream = stream_api::AsyncStream<flow::HeaderEvents>;
 }
 
 #[cfg(test)]
@@ -1005,7 +2366,7 @@ mod tests {
 
     use super::*;
     use crate::{
-        config::{BasicAuth, Config},
+        config::{BasicAuth, Config, Http, Tls},
         secrecy::SecretString,
     };
 
@@ -1018,13 +2379,16 @@ mod tests {
         let (account_id, key_pair) = gen_account_in("wonderland");
         Config {
             chain: ChainId::from("00000000-0000-0000-0000-000000000000"),
-            key_pair,
+            signer: Arc::new(key_pair),
             account: account_id,
             torii_api_url: "http://127.0.0.1:8080".parse().unwrap(),
             basic_auth: None,
             transaction_add_nonce: false,
             transaction_ttl: Duration::from_secs(5),
             transaction_status_timeout: Duration::from_secs(10),
+            http: Http::default(),
+            tls: Tls::default(),
+            proxy: None,
         }
     }
 
@@ -1035,8 +2399,11 @@ mod tests {
             ..config_factory()
         });
 
-        let build_transaction =
-            || client.build_transaction(Vec::<InstructionBox>::new(), Metadata::default());
+        let build_transaction = || {
+            client
+                .build_transaction(Vec::<InstructionBox>::new(), Metadata::default())
+                .unwrap()
+        };
         let tx1 = build_transaction();
         let tx2 = build_transaction();
         assert_ne!(tx1.hash(), tx2.hash());
@@ -1054,7 +2421,7 @@ mod tests {
                 tx.set_ttl(transaction_ttl);
             }
 
-            client.sign_transaction(tx)
+            client.sign_transaction(tx).unwrap()
         };
         assert_eq!(tx1.hash(), tx2.hash());
     }
@@ -1077,7 +2444,98 @@ mod tests {
         assert_eq!(value, &expected_value);
     }
 
+    #[test]
+    fn builder_configures_both_flavors_alike() {
+        let builder = Client::builder(config_factory())
+            .header("X-Request-Source", "test")
+            .request_timeout(Duration::from_secs(3))
+            .transaction_status_timeout(Duration::from_secs(30))
+            .retry_policy(RetryPolicy::exponential(5));
+
+        let blocking = builder.clone().build().unwrap();
+        let async_client = builder.build_async().unwrap();
+        for client in [&blocking, async_client.blocking()] {
+            assert_eq!(client.headers["X-Request-Source"], "test");
+            assert_eq!(client.http.config().request_timeout, Duration::from_secs(3));
+            assert_eq!(client.transaction_status_timeout, Duration::from_secs(30));
+            assert_eq!(client.retry_policy, RetryPolicy::exponential(5));
+        }
+    }
+
+    #[test]
+    fn queue_full_response_is_typed_error() {
+        let hint = QueueFull {
+            queue_depth: 65536,
+            capacity: 65536,
+            retry_after_ms: 2_000,
+        };
+        let response = Response::builder()
+            .status(StatusCode::SERVICE_UNAVAILABLE)
+            .body(serde_json::to_vec(&hint).unwrap())
+            .unwrap();
+
+        let err = TransactionResponseHandler::handle(&response).unwrap_err();
+        assert_eq!(
+            err.downcast_ref::<QueueFullError>(),
+            Some(&QueueFullError {
+                queue_depth: 65536,
+                capacity: 65536,
+                retry_after: Duration::from_secs(2),
+            })
+        );
+
+        let response = Response::builder()
+            .status(StatusCode::SERVICE_UNAVAILABLE)
+            .body(b"not a hint".to_vec())
+            .unwrap();
+        let err = TransactionResponseHandler::handle(&response).unwrap_err();
+        assert!(err.downcast_ref::<QueueFullError>().is_none());
+    }
+
     #[cfg(test)]
+    #[test]
+    fn event_consumer_skips_processed_events() {
+        use events_api::{EventConsumer, MemoryCheckpoint};
+
+        let message = |block_height, index| {
+            let domain_id: DomainId = "wonderland".parse().unwrap();
+            EventMessage::new(
+                Some(EventSequence::new(block_height, index)),
+                DataEvent::Domain(DomainEvent::Deleted(domain_id)).into(),
+            )
+        };
+
+        let mut consumer = EventConsumer::new(MemoryCheckpoint::default()).unwrap();
+        let mut handled = 0;
+
+        assert!(consumer
+            .process(message(2, 0), |_| Err(eyre!("handler failed")))
+            .is_err());
+        assert_eq!(consumer.last_processed(), None);
+
+        for (block_height, index) in [(2, 0), (2, 1), (2, 1), (2, 0), (3, 0)] {
+            consumer
+                .process(message(block_height, index), |_| {
+                    handled += 1;
+                    Ok(())
+                })
+                .unwrap();
+        }
+
+        assert_eq!(handled, 3);
+        assert_eq!(consumer.last_processed(), Some(EventSequence::new(3, 0)));
+
+        let unsequenced = EventMessage::new(None, message(3, 0).event);
+        assert!(consumer
+            .process(unsequenced, |_| {
+                handled += 1;
+                Ok(())
+            })
+            .unwrap());
+        assert_eq!(handled, 4);
+        assert_eq!(consumer.last_processed(), Some(EventSequence::new(3, 0)));
+    }
+
     mod join_torii_url {
         use url::Url;
 
@@ -0,0 +1,78 @@
+//! Shortcuts for the most common queries of the assets.
+//!
+//! ```ignore
+//! let rose: AssetDefinitionId = "rose#wonderland".parse()?;
+//! let balance = client.assets().balance_of(&alice, &rose)?;
+//! let holders = client.assets().by_definition(&rose)?;
+//! ```
+
+use crate::{
+    client::Client,
+    data_model::{
+        prelude::*,
+        query::builder::{QueryBuilder, QueryBuilderExt, QueryExecutor},
+    },
+};
+
+/// Queries of the assets made with [`Client::assets`]
+#[derive(Debug, Clone, Copy)]
+pub struct Assets<'a, E> {
+    executor: &'a E,
+}
+
+impl<'a, E: QueryExecutor> Assets<'a, E> {
+    /// Construct the shortcuts for the queries executed by `executor`
+    pub fn new(executor: &'a E) -> Self {
+        Self { executor }
+    }
+
+    /// Assets held by the `account`
+    ///
+    /// # Errors
+    /// Fails if the query execution fails
+    pub fn by_account_id(&self, account: &AccountId) -> Result<Vec<Asset>, E::Error> {
+        let account = account.clone();
+        QueryBuilder::new(self.executor, FindAssets::new())
+            .filter_with(|asset| asset.id.account.eq(account))
+            .execute_all()
+    }
+
+    /// Assets of the `definition` held by all the accounts
+    ///
+    /// # Errors
+    /// Fails if the query execution fails
+    pub fn by_definition(&self, definition: &AssetDefinitionId) -> Result<Vec<Asset>, E::Error> {
+        let definition = definition.clone();
+        QueryBuilder::new(self.executor, FindAssets::new())
+            .filter_with(|asset| asset.id.definition.eq(definition))
+            .execute_all()
+    }
+
+    /// Quantity of the `definition` held by the `account`, zero if the account doesn't hold it
+    ///
+    /// # Errors
+    /// Fails if the query execution fails
+    pub fn balance_of(
+        &self,
+        account: &AccountId,
+        definition: &AssetDefinitionId,
+    ) -> Result<Numeric, E::Error> {
+        let id = AssetId::new(definition.clone(), account.clone());
+        let balance = QueryBuilder::new(self.executor, FindAssets::new())
+            .filter_with(|asset| asset.id.eq(id))
+            .select_with(|asset| asset.value)
+            .execute_all()?
+            .into_iter()
+            .next()
+            .unwrap_or(Numeric::ZERO);
+
+        Ok(balance)
+    }
+}
+
+impl Client {
+    /// Shortcuts for the common queries of the assets, see [`Assets`]
+    pub fn assets(&self) -> Assets<'_, Self> {
+        Assets::new(self)
+    }
+}
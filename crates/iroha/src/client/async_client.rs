@@ -0,0 +1,328 @@
+//! Asynchronous counterpart of [`Client`].
+
+use super::*;
+use crate::{http_default::AsyncRequestBuilder, observer::RequestObserver};
+
+/// Asynchronous Iroha client built on top of `reqwest` and `tokio`.
+///
+/// Shares the configuration and the transaction building logic with [`Client`],
+/// but never blocks the calling thread, so it can be used inside async services directly.
+/// Cloning is cheap: the clones share the pool of HTTP connections,
+/// which is configured the same way as the [`HttpPool`] of the client.
+///
+/// Iterable queries built with [`AsyncClient::query`] are executed with [`AsyncQueryBuilderExt`].
+#[derive(Clone, DebugCustom, Display)]
+#[debug(fmt = "Async{client:?}")]
+#[display(fmt = "{client}")]
+pub struct AsyncClient {
+    client: Client,
+    http: reqwest::Client,
+    observer: Option<Arc<dyn RequestObserver>>,
+}
+
+impl From<Client> for AsyncClient {
+    fn from(client: Client) -> Self {
+        Self {
+            http: client.http.async_client(),
+            observer: client.http.observer().cloned(),
+            client,
+        }
+    }
+}
+
+impl AsyncClient {
+    /// Constructor for client from configuration
+    #[inline]
+    pub fn new(configuration: Config) -> Self {
+        Client::new(configuration).into()
+    }
+
+    /// Constructor for client from configuration and headers
+    ///
+    /// *Authorization* header will be added if `basic_auth` is presented
+    #[inline]
+    pub fn with_headers(configuration: Config, headers: HashMap<String, String>) -> Self {
+        Client::with_headers(configuration, headers).into()
+    }
+
+    /// The underlying blocking [`Client`].
+    ///
+    /// Useful for building and signing transactions, which doesn't involve any I/O.
+    pub fn blocking(&self) -> &Client {
+        &self.client
+    }
+
+    /// Builds transaction out of supplied instructions or wasm.
+    ///
+    /// # Errors
+    /// Fails if signing transaction fails
+    pub fn build_transaction<Exec: Into<Executable>>(
+        &self,
+        instructions: Exec,
+        metadata: Metadata,
+    ) -> Result<SignedTransaction> {
+        self.client.build_transaction(instructions, metadata)
+    }
+
+    /// Send the request through the pool of connections of the client
+    pub(crate) async fn send(&self, request: AsyncRequestBuilder) -> Result<Response<Vec<u8>>> {
+        request.send(&self.http, self.observer.as_deref()).await
+    }
+
+    /// Instructions API entry point. Submits one Iroha Special Instruction to `Iroha` peers.
+    /// Returns submitted transaction's hash or error string.
+    ///
+    /// # Errors
+    /// Fails if sending transaction to peer fails or if it response with error
+    pub async fn submit<I: Instruction>(&self, isi: I) -> Result<HashOf<SignedTransaction>> {
+        self.submit_all([isi]).await
+    }
+
+    /// Instructions API entry point. Submits several Iroha Special Instructions to `Iroha` peers.
+    /// Returns submitted transaction's hash or error string.
+    ///
+    /// # Errors
+    /// Fails if sending transaction to peer fails or if it response with error
+    pub async fn submit_all<I: Instruction>(
+        &self,
+        instructions: impl IntoIterator<Item = I>,
+    ) -> Result<HashOf<SignedTransaction>> {
+        self.submit_all_with_metadata(instructions, Metadata::default())
+            .await
+    }
+
+    /// Instructions API entry point. Submits several Iroha Special Instructions to `Iroha` peers.
+    /// Allows to specify [`Metadata`] of [`TransactionBuilder`].
+    /// Returns submitted transaction's hash or error string.
+    ///
+    /// # Errors
+    /// Fails if sending transaction to peer fails or if it response with error
+    pub async fn submit_all_with_metadata<I: Instruction>(
+        &self,
+        instructions: impl IntoIterator<Item = I>,
+        metadata: Metadata,
+    ) -> Result<HashOf<SignedTransaction>> {
+        self.submit_transaction(&self.build_transaction(instructions, metadata)?)
+            .await
+    }
+
+    /// Submit a prebuilt transaction.
+    /// Returns submitted transaction's hash or error string.
+    ///
+    /// Transient failures, e.g. the saturated queue of the peer, are retried according to [`Client::retry_policy`].
+    ///
+    /// # Errors
+    /// Fails if sending transaction to peer fails or if it response with error.
+    /// Saturated queue is reported as [`QueueFullError`] once the retries are exhausted
+    pub async fn submit_transaction(
+        &self,
+        transaction: &SignedTransaction,
+    ) -> Result<HashOf<SignedTransaction>> {
+        iroha_logger::trace!(tx=?transaction, "Submitting");
+        let hash = transaction.hash();
+        let (response, attempts) = self
+            .client
+            .retry_policy
+            .retry_async(|| {
+                self.send(
+                    self.client
+                        .prepare_transaction_request::<AsyncRequestBuilder>(transaction)
+                        .0,
+                )
+            })
+            .await;
+        let response =
+            response.wrap_err_with(|| format!("Failed to send transaction with hash {hash:?}"))?;
+        TransactionResponseHandler::handle_attempts(&response, attempts)?;
+        self.client.nonces.track(transaction);
+        Ok(hash)
+    }
+
+    /// Submits and waits until the transaction is either rejected or committed.
+    /// Returns rejection reason if transaction was rejected.
+    ///
+    /// # Errors
+    /// Fails if sending transaction to peer fails or if it response with error
+    pub async fn submit_blocking<I: Instruction>(
+        &self,
+        instruction: I,
+    ) -> Result<HashOf<SignedTransaction>> {
+        self.submit_all_blocking([instruction]).await
+    }
+
+    /// Submits and waits until the transaction is either rejected or committed.
+    /// Returns rejection reason if transaction was rejected.
+    ///
+    /// # Errors
+    /// Fails if sending transaction to peer fails or if it response with error
+    pub async fn submit_all_blocking<I: Instruction>(
+        &self,
+        instructions: impl IntoIterator<Item = I>,
+    ) -> Result<HashOf<SignedTransaction>> {
+        let transaction = self.build_transaction(instructions, Metadata::default())?;
+        self.submit_transaction_blocking(&transaction).await
+    }
+
+    /// Submit the prebuilt transaction and wait until it is either rejected or committed.
+    /// If rejected, return the rejection reason.
+    ///
+    /// Only the current task waits, the thread remains free to run other tasks.
+    ///
+    /// # Errors
+    /// Fails if sending a transaction to a peer fails or there is an error in the response
+    pub async fn submit_transaction_blocking(
+        &self,
+        transaction: &SignedTransaction,
+    ) -> Result<HashOf<SignedTransaction>> {
+        let hash = transaction.hash();
+        let deadline = tokio::time::Instant::now() + self.client.transaction_status_timeout;
+        tracing::debug!(%hash, ?transaction, "Submitting transaction");
+
+        // Subscribe before submitting, otherwise the events might be missed
+        let filters = vec![
+            TransactionEventFilter::default().for_hash(hash).into(),
+            PipelineEventFilterBox::from(
+                BlockEventFilter::default().for_status(BlockStatus::Applied),
+            ),
+        ];
+        let mut event_iterator = tokio::time::timeout_at(deadline, self.listen_for_events(filters))
+            .await
+            .map_err(Into::into)
+            .and_then(std::convert::identity)
+            .wrap_err("Failed to establish event listener connection")?;
+
+        let result = async {
+            self.submit_transaction(transaction).await?;
+
+            tokio::time::timeout_at(
+                deadline,
+                Client::listen_for_tx_confirmation_loop(&mut event_iterator, hash),
+            )
+            .await
+            .wrap_err_with(|| {
+                eyre!(
+                    "haven't got tx confirmation within {:?} (configured with `transaction.status_timeout_ms`)",
+                    self.client.transaction_status_timeout
+                )
+            })
+            .and_then(std::convert::identity)
+        }
+        .await;
+        event_iterator.close().await;
+        self.client.nonces.complete(transaction.authority(), &hash);
+        result
+    }
+
+    /// Submit the prebuilt transaction and watch its progress through the pipeline.
+    ///
+    /// Asynchronous counterpart of [`Client::submit_and_watch`].
+    ///
+    /// # Errors
+    /// Fails if the event listener can't be connected or if sending the transaction fails
+    pub async fn submit_and_watch(
+        &self,
+        transaction: &SignedTransaction,
+    ) -> Result<TransactionProgressStream> {
+        let hash = transaction.hash();
+        tracing::debug!(%hash, ?transaction, "Submitting transaction");
+
+        // Subscribe before submitting, otherwise the events might be missed
+        let events = self
+            .listen_for_events(TransactionProgressStream::filters(hash))
+            .await
+            .wrap_err("Failed to establish event listener connection")?;
+        if let Err(error) = self.submit_transaction(transaction).await {
+            events.close().await;
+            return Err(error);
+        }
+
+        Ok(TransactionProgressStream::new(events, hash))
+    }
+
+    /// Connect (through `WebSocket`) to listen for `Iroha` `pipeline` and `data` events.
+    ///
+    /// # Errors
+    /// - Forwards from [`Client::events_handler`]
+    /// - Forwards from `events_api::AsyncEventStream::new`
+    pub async fn listen_for_events(
+        &self,
+        event_filters: impl IntoIterator<Item = impl Into<EventFilterBox>> + Send,
+    ) -> Result<AsyncEventStream> {
+        self.client.listen_for_events_async(event_filters).await
+    }
+
+    /// Connect (through `WebSocket`) to listen for `Iroha` events along with their [`EventSequence`]s,
+    /// replaying the ones following the event with sequence `after` first, if given.
+    ///
+    /// # Errors
+    /// - Forwards from [`Client::sequenced_events_handler`]
+    /// - Forwards from `events_api::AsyncSequencedEventStream::new`
+    pub async fn listen_for_sequenced_events(
+        &self,
+        event_filters: impl IntoIterator<Item = impl Into<EventFilterBox>> + Send,
+        after: Option<EventSequence>,
+    ) -> Result<AsyncSequencedEventStream> {
+        self.client
+            .listen_for_sequenced_events_async(event_filters, after)
+            .await
+    }
+
+    /// Connect (through `WebSocket`) to listen for `Iroha` blocks
+    ///
+    /// # Errors
+    /// - Forwards from [`Client::blocks_handler`]
+    /// - Forwards from `blocks_api::AsyncBlockStream::new`
+    pub async fn listen_for_blocks(&self, height: NonZeroU64) -> Result<AsyncBlockStream> {
+        self.client.listen_for_blocks_async(height).await
+    }
+
+    /// Gets network status seen from the peer
+    ///
+    /// # Errors
+    /// Fails if sending request or decoding fails
+    pub async fn get_status(&self) -> Result<Status> {
+        let req = self
+            .client
+            .prepare_status_request::<AsyncRequestBuilder>()
+            .header(http::header::ACCEPT, "application/x-parity-scale");
+        let resp = self.send(req).await?;
+        let scaled_resp = StatusResponseHandler::handle(&resp).cloned()?;
+        DecodeAll::decode_all(&mut scaled_resp.as_slice()).map_err(|err| eyre!("{err}"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use iroha_test_samples::gen_account_in;
+
+    use super::*;
+
+    #[test]
+    fn clones_share_configuration() {
+        let (account, key_pair) = gen_account_in("wonderland");
+        let client = AsyncClient::with_headers(
+            Config {
+                chain: ChainId::from("00000000-0000-0000-0000-000000000000"),
+                signer: Arc::new(key_pair),
+                account: account.clone(),
+                torii_api_url: "http://127.0.0.1:8080".parse().unwrap(),
+                basic_auth: None,
+                transaction_add_nonce: false,
+                transaction_ttl: Duration::from_secs(5),
+                transaction_status_timeout: Duration::from_secs(10),
+                http: crate::config::Http::default(),
+                tls: crate::config::Tls::default(),
+                proxy: None,
+            },
+            HashMap::from([("X-Custom".to_owned(), "value".to_owned())]),
+        );
+        let clone = client.clone();
+
+        assert_eq!(clone.blocking().account, account);
+        assert_eq!(
+            clone.blocking().headers.get("X-Custom").map(String::as_str),
+            Some("value")
+        );
+        assert_eq!(client.to_string(), clone.to_string());
+    }
+}
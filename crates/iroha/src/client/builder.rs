@@ -0,0 +1,167 @@
+//! Construction of [`Client`] and [`AsyncClient`], see [`Client::builder`].
+
+use super::*;
+use crate::{
+    config::{Http, Tls},
+    observer::RequestObserver,
+};
+
+/// Builder of [`Client`] and [`AsyncClient`].
+///
+/// Both flavors of the client are built from the same settings:
+/// the transport is chosen by [`Self::build`] (blocking, `ureq`) or [`Self::build_async`] (`reqwest`),
+/// while the timeouts, TLS, proxy, retry policy and request observer apply to either of them.
+#[derive(Debug, Clone)]
+#[must_use]
+pub struct ClientBuilder {
+    config: Config,
+    headers: HashMap<String, String>,
+    retry_policy: RetryPolicy,
+    observer: Option<Arc<dyn RequestObserver>>,
+}
+
+impl Client {
+    /// Builder of the client starting from the `configuration`
+    pub fn builder(configuration: Config) -> ClientBuilder {
+        ClientBuilder {
+            config: configuration,
+            headers: HashMap::new(),
+            retry_policy: RetryPolicy::default(),
+            observer: None,
+        }
+    }
+}
+
+impl AsyncClient {
+    /// Builder of the client starting from the `configuration`, same as [`Client::builder`]
+    pub fn builder(configuration: Config) -> ClientBuilder {
+        Client::builder(configuration)
+    }
+}
+
+impl ClientBuilder {
+    /// Http headers appended to each request, replacing the previously set ones
+    pub fn headers(mut self, headers: HashMap<String, String>) -> Self {
+        self.headers = headers;
+        self
+    }
+
+    /// Http header appended to each request
+    pub fn header(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.headers.insert(name.into(), value.into());
+        self
+    }
+
+    /// Settings of the connections to the peer
+    pub fn http(mut self, http: Http) -> Self {
+        self.config.http = http;
+        self
+    }
+
+    /// Timeout of a single request, from connecting to reading the whole response
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.config.http.request_timeout = timeout;
+        self
+    }
+
+    /// How long the client waits for the transaction to be committed, see [`Client::submit_transaction_blocking`]
+    pub fn transaction_status_timeout(mut self, timeout: Duration) -> Self {
+        self.config.transaction_status_timeout = timeout;
+        self
+    }
+
+    /// TLS settings of the connections to the peer
+    pub fn tls(mut self, tls: Tls) -> Self {
+        self.config.tls = tls;
+        self
+    }
+
+    /// Proxy the connections to the peer are made through, if any
+    pub fn proxy(mut self, proxy: Option<Proxy>) -> Self {
+        self.config.proxy = proxy;
+        self
+    }
+
+    /// How the submissions and the queries are retried after transient failures
+    pub fn retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+
+    /// Report every request sent by the client to the `observer`
+    pub fn observer(mut self, observer: Arc<dyn RequestObserver>) -> Self {
+        self.observer = Some(observer);
+        self
+    }
+
+    /// Blocking client, sending the requests with `ureq`.
+    ///
+    /// *Authorization* header will be added if `basic_auth` is presented
+    ///
+    /// # Errors
+    /// Fails if the certificates can't be loaded, the TLS backend doesn't support the settings
+    /// or the proxy URL is invalid
+    pub fn build(self) -> Result<Client> {
+        let Self {
+            config:
+                Config {
+                    chain,
+                    account,
+                    torii_api_url,
+                    signer,
+                    basic_auth,
+                    transaction_add_nonce,
+                    transaction_ttl,
+                    transaction_status_timeout,
+                    http,
+                    tls,
+                    proxy,
+                },
+            mut headers,
+            retry_policy,
+            observer,
+        } = self;
+
+        if let Some(basic_auth) = basic_auth {
+            let credentials = format!(
+                "{}:{}",
+                basic_auth.web_login,
+                basic_auth.password.expose_secret()
+            );
+            let engine = base64::engine::general_purpose::STANDARD;
+            let encoded = base64::engine::Engine::encode(&engine, credentials);
+            headers.insert(String::from("Authorization"), format!("Basic {encoded}"));
+        }
+        let http = HttpPool::with_proxy(
+            http,
+            &tls,
+            proxy.filter(|proxy| !proxy.bypasses(&torii_api_url)),
+        )?;
+        let http = match observer {
+            Some(observer) => http.with_observer(observer),
+            None => http,
+        };
+
+        Ok(Client {
+            chain,
+            torii_url: torii_api_url,
+            signer,
+            transaction_ttl: Some(transaction_ttl),
+            transaction_status_timeout,
+            account,
+            headers,
+            add_transaction_nonce: transaction_add_nonce,
+            nonces: Arc::new(NonceManager::new()),
+            retry_policy,
+            http,
+        })
+    }
+
+    /// Asynchronous client, sending the requests with `reqwest`.
+    ///
+    /// # Errors
+    /// See [`Self::build`]
+    pub fn build_async(self) -> Result<AsyncClient> {
+        self.build().map(AsyncClient::from)
+    }
+}
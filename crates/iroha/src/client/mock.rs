@@ -0,0 +1,414 @@
+//! In-memory backend of the client for unit testing applications built on top of [`Client`](super::Client).
+//!
+//! [`MockClient`] offers the transaction and query API of the client,
+//! but executes the transactions against a [`World`] held in the current process instead of sending them to a peer.
+//! Every transaction is committed in a block of its own, so there is no need to spin up `iroha_test_network` peers.
+//! The world is governed by the initial executor, so no permissions are checked.
+//!
+//! Unlike [`MockPeer`](crate::mock::MockPeer), which replies with scripted responses,
+//! the state of [`MockClient`] evolves with the submitted instructions.
+
+use std::sync::{Arc, Mutex};
+
+use eyre::{eyre, Result, WrapErr};
+use iroha_core::{
+    block::BlockBuilder,
+    kura::Kura,
+    query::store::{LiveQueryStore, LiveQueryStoreHandle},
+    smartcontracts::query::ValidQueryRequest,
+    state::{State, StateReadOnly, World, WorldReadOnly},
+    sumeragi::network_topology::Topology,
+    tx::AcceptedTransaction,
+};
+
+use crate::{
+    crypto::{HashOf, KeyPair},
+    data_model::{
+        isi::Instruction,
+        prelude::*,
+        query::{
+            builder::{QueryBuilder, QueryExecutor},
+            parameters::ForwardCursor,
+            Query, QueryOutputBatchBoxTuple, QueryRequest, QueryResponse, QueryWithParams,
+            SingularQuery, SingularQueryBox, SingularQueryOutputBox,
+        },
+        transaction::{error::TransactionRejectionReason, TransactionBuilder},
+        ChainId, ValidationFail,
+    },
+    nonce::NonceManager,
+    query::QueryError,
+};
+
+/// Client executing the transactions and queries against an in-process [`World`],
+/// see the [module-level documentation](self).
+///
+/// Cloning is cheap: the clones share the world.
+#[derive(Debug, Clone)]
+pub struct MockClient {
+    inner: Arc<Inner>,
+}
+
+struct Inner {
+    chain: ChainId,
+    account: AccountId,
+    key_pair: KeyPair,
+    peer_key_pair: KeyPair,
+    kura: Arc<Kura>,
+    state: State,
+    query_store: LiveQueryStoreHandle,
+    nonces: NonceManager,
+    /// Blocks are committed one at a time
+    commit_lock: Mutex<()>,
+    /// Hosts the pruning task of the live query store
+    _runtime: tokio::runtime::Runtime,
+}
+
+impl core::fmt::Debug for Inner {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Inner")
+            .field("chain", &self.chain)
+            .field("account", &self.account)
+            .finish_non_exhaustive()
+    }
+}
+
+impl MockClient {
+    /// Construct [`MockClient`] acting on behalf of the `account`,
+    /// which is registered along with its domain in an otherwise empty world.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the runtime of the live query store can't be started.
+    pub fn new(account: AccountId, key_pair: KeyPair) -> Self {
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_time()
+            .build()
+            .expect("failed to build the runtime of the live query store");
+        let query_store = {
+            let _guard = runtime.enter();
+            LiveQueryStore::start_test()
+        };
+
+        let domain = Domain::new(account.domain().clone()).build(&account);
+        let account_entry = Account::new(account.clone()).build(&account);
+        let world = World::with([domain], [account_entry], []);
+        let kura = Kura::blank_kura_for_testing();
+        let state = State::new(
+            world,
+            Arc::clone(&kura),
+            query_store.clone(),
+            <_>::default(),
+        );
+
+        Self {
+            inner: Arc::new(Inner {
+                chain: ChainId::from("00000000-0000-0000-0000-000000000000"),
+                account,
+                key_pair,
+                peer_key_pair: KeyPair::random(),
+                kura,
+                state,
+                query_store,
+                nonces: NonceManager::new(),
+                commit_lock: Mutex::new(()),
+                _runtime: runtime,
+            }),
+        }
+    }
+
+    /// Account on behalf of which the transactions and queries are made
+    pub fn account(&self) -> &AccountId {
+        &self.inner.account
+    }
+
+    /// Builds transaction out of supplied instructions.
+    pub fn build_transaction<I: Instruction>(
+        &self,
+        instructions: impl IntoIterator<Item = I>,
+        metadata: Metadata,
+    ) -> SignedTransaction {
+        let reservation = self.inner.nonces.reserve(&self.inner.account);
+        let mut tx_builder =
+            TransactionBuilder::new(self.inner.chain.clone(), self.inner.account.clone())
+                .with_instructions(instructions);
+        tx_builder
+            .set_nonce(reservation.nonce)
+            .set_creation_time(reservation.creation_time);
+
+        tx_builder
+            .with_metadata(metadata)
+            .sign(self.inner.key_pair.private_key())
+    }
+
+    /// Submits one instruction and commits it in a new block.
+    /// Returns the transaction hash, even if the transaction was rejected.
+    ///
+    /// # Errors
+    /// Fails if the transaction isn't acceptable, e.g. exceeds the transaction limits
+    pub fn submit<I: Instruction>(&self, isi: I) -> Result<HashOf<SignedTransaction>> {
+        self.submit_all([isi])
+    }
+
+    /// Submits several instructions and commits them in a new block.
+    /// Returns the transaction hash, even if the transaction was rejected.
+    ///
+    /// # Errors
+    /// Fails if the transaction isn't acceptable, e.g. exceeds the transaction limits
+    pub fn submit_all<I: Instruction>(
+        &self,
+        instructions: impl IntoIterator<Item = I>,
+    ) -> Result<HashOf<SignedTransaction>> {
+        let transaction = self.build_transaction(instructions, Metadata::default());
+        let hash = transaction.hash();
+        self.commit(transaction)?;
+        Ok(hash)
+    }
+
+    /// Submits one instruction and commits it in a new block.
+    /// Returns rejection reason if transaction was rejected.
+    ///
+    /// # Errors
+    /// Fails if the transaction isn't acceptable or was rejected
+    pub fn submit_blocking<I: Instruction>(
+        &self,
+        instruction: I,
+    ) -> Result<HashOf<SignedTransaction>> {
+        self.submit_all_blocking([instruction])
+    }
+
+    /// Submits several instructions and commits them in a new block.
+    /// Returns rejection reason if transaction was rejected.
+    ///
+    /// # Errors
+    /// Fails if the transaction isn't acceptable or was rejected
+    pub fn submit_all_blocking<I: Instruction>(
+        &self,
+        instructions: impl IntoIterator<Item = I>,
+    ) -> Result<HashOf<SignedTransaction>> {
+        self.submit_transaction_blocking(self.build_transaction(instructions, Metadata::default()))
+    }
+
+    /// Submit the prebuilt transaction and commit it in a new block.
+    /// If rejected, return the rejection reason.
+    ///
+    /// # Errors
+    /// Fails if the transaction isn't acceptable or was rejected
+    pub fn submit_transaction_blocking(
+        &self,
+        transaction: SignedTransaction,
+    ) -> Result<HashOf<SignedTransaction>> {
+        let hash = transaction.hash();
+        match self.commit(transaction)? {
+            None => Ok(hash),
+            Some(reason) => Err(reason.into()),
+        }
+    }
+
+    /// Execute a singular query and return the result
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query execution fails.
+    pub fn query_single<Q>(&self, query: Q) -> Result<Q::Output, QueryError>
+    where
+        Q: SingularQuery,
+        SingularQueryBox: From<Q>,
+        Q::Output: TryFrom<SingularQueryOutputBox>,
+        <Q::Output as TryFrom<SingularQueryOutputBox>>::Error: core::fmt::Debug,
+    {
+        let output = self.execute_singular_query(SingularQueryBox::from(query))?;
+
+        Ok(output
+            .try_into()
+            .expect("BUG: unexpected type of singular query output"))
+    }
+
+    /// Build an iterable query and return a builder object
+    pub fn query<Q>(&self, query: Q) -> QueryBuilder<Self, Q, Q::Item>
+    where
+        Q: Query,
+    {
+        QueryBuilder::new(self, query)
+    }
+
+    /// Shortcuts for the common queries of the assets, see [`Assets`](super::Assets)
+    pub fn assets(&self) -> super::Assets<'_, Self> {
+        super::Assets::new(self)
+    }
+
+    /// Validate the transaction and commit it in a new block signed by the mock peer.
+    ///
+    /// Returns the rejection reason if the transaction was rejected.
+    fn commit(&self, transaction: SignedTransaction) -> Result<Option<TransactionRejectionReason>> {
+        let inner = &*self.inner;
+        let (max_clock_drift, tx_limits) = {
+            let state_view = inner.state.view();
+            let params = state_view.world().parameters();
+            (params.sumeragi().max_clock_drift(), params.transaction())
+        };
+        let transaction =
+            AcceptedTransaction::accept(transaction, &inner.chain, max_clock_drift, tx_limits)
+                .wrap_err("Transaction is not acceptable")?;
+
+        let _commit_guard = inner
+            .commit_lock
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+        let peer_id = PeerId::new(inner.peer_key_pair.public_key().clone());
+        let topology = Topology::new(vec![peer_id]);
+        let new_block = BlockBuilder::new(vec![transaction])
+            .chain(0, inner.state.view().latest_block().as_deref())
+            .sign(inner.peer_key_pair.private_key())
+            .unpack(|_| {});
+
+        let mut state_block = inner.state.block(new_block.header());
+        let block = new_block
+            .validate_and_record_transactions(&mut state_block)
+            .unpack(|_| {})
+            .commit_unchecked()
+            .unpack(|_| {});
+        let rejection = block
+            .as_ref()
+            .errors()
+            .next()
+            .map(|(_, reason)| reason.clone());
+
+        let _events = state_block.apply_without_execution(&block, topology.as_ref().to_owned());
+        state_block.commit();
+        inner.kura.store_block(block);
+
+        Ok(rejection)
+    }
+
+    fn execute(inner: &Inner, request: QueryRequest) -> Result<QueryResponse, QueryError> {
+        let state_view = inner.state.view();
+        let request = ValidQueryRequest::validate_for_client(
+            request.with_authority(inner.account.clone()),
+            &state_view,
+        )?;
+
+        request
+            .execute(&inner.query_store, &state_view, &inner.account)
+            .map_err(|error| QueryError::Validation(ValidationFail::QueryFailed(error)))
+    }
+
+    fn execute_iterable(
+        inner: Arc<Inner>,
+        request: QueryRequest,
+    ) -> Result<(QueryOutputBatchBoxTuple, u64, Option<MockQueryCursor>), QueryError> {
+        let QueryResponse::Iterable(output) = Self::execute(&inner, request)? else {
+            return Err(eyre!("Got unexpected type of query response (expected iterable)").into());
+        };
+        let (batch, remaining_items, cursor) = output.into_parts();
+        let cursor = cursor.map(|cursor| MockQueryCursor { inner, cursor });
+
+        Ok((batch, remaining_items, cursor))
+    }
+}
+
+/// An iterable query cursor of [`MockClient`]
+#[derive(Debug)]
+pub struct MockQueryCursor {
+    inner: Arc<Inner>,
+    cursor: ForwardCursor,
+}
+
+impl QueryExecutor for MockClient {
+    type Cursor = MockQueryCursor;
+    type Error = QueryError;
+
+    fn execute_singular_query(
+        &self,
+        query: SingularQueryBox,
+    ) -> Result<SingularQueryOutputBox, Self::Error> {
+        let QueryResponse::Singular(output) =
+            Self::execute(&self.inner, QueryRequest::Singular(query))?
+        else {
+            return Err(eyre!("Got unexpected type of query response (expected singular)").into());
+        };
+
+        Ok(output)
+    }
+
+    fn start_query(
+        &self,
+        query: QueryWithParams,
+    ) -> Result<(QueryOutputBatchBoxTuple, u64, Option<Self::Cursor>), Self::Error> {
+        Self::execute_iterable(Arc::clone(&self.inner), QueryRequest::Start(query))
+    }
+
+    fn continue_query(
+        cursor: Self::Cursor,
+    ) -> Result<(QueryOutputBatchBoxTuple, u64, Option<Self::Cursor>), Self::Error> {
+        let MockQueryCursor { inner, cursor } = cursor;
+
+        Self::execute_iterable(inner, QueryRequest::Continue(cursor))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use iroha_test_samples::{ALICE_ID, ALICE_KEYPAIR};
+
+    use super::*;
+
+    #[test]
+    fn instructions_are_applied_to_the_world() {
+        let client = MockClient::new(ALICE_ID.clone(), ALICE_KEYPAIR.clone());
+        let rose: AssetDefinitionId = "rose#wonderland".parse().unwrap();
+        let asset_id = AssetId::new(rose.clone(), ALICE_ID.clone());
+
+        client
+            .submit_blocking(Register::asset_definition(AssetDefinition::numeric(
+                rose.clone(),
+            )))
+            .unwrap();
+        client
+            .submit_blocking(Mint::asset_numeric(13_u32, asset_id.clone()))
+            .unwrap();
+
+        let asset = client
+            .query(FindAssets::new())
+            .filter_with(|asset| asset.id.eq(asset_id))
+            .execute_single()
+            .unwrap();
+        assert_eq!(*asset.value(), Numeric::from(13_u32));
+
+        let burn_too_much = Burn::asset_numeric(14_u32, AssetId::new(rose, ALICE_ID.clone()));
+        assert!(client.submit_blocking(burn_too_much).is_err());
+    }
+
+    #[test]
+    fn assets_shortcuts_find_holdings() {
+        let client = MockClient::new(ALICE_ID.clone(), ALICE_KEYPAIR.clone());
+        let [rose, tulip]: [AssetDefinitionId; 2] = [
+            "rose#wonderland".parse().unwrap(),
+            "tulip#wonderland".parse().unwrap(),
+        ];
+        for definition in [&rose, &tulip] {
+            client
+                .submit_blocking(Register::asset_definition(AssetDefinition::numeric(
+                    definition.clone(),
+                )))
+                .unwrap();
+        }
+        client
+            .submit_blocking(Mint::asset_numeric(
+                7_u32,
+                AssetId::new(rose.clone(), ALICE_ID.clone()),
+            ))
+            .unwrap();
+
+        let assets = client.assets();
+        let held = assets.by_account_id(&ALICE_ID).unwrap();
+        assert_eq!(held.len(), 1);
+        assert_eq!(held[0].id().definition(), &rose);
+        assert_eq!(assets.by_definition(&rose).unwrap(), held);
+        assert!(assets.by_definition(&tulip).unwrap().is_empty());
+        assert_eq!(
+            assets.balance_of(&ALICE_ID, &rose).unwrap(),
+            Numeric::from(7_u32)
+        );
+        assert_eq!(assets.balance_of(&ALICE_ID, &tulip).unwrap(), Numeric::ZERO);
+    }
+}
@@ -0,0 +1,128 @@
+//! Status of all the peers of the network at once, see [`Client::network_status`].
+
+use std::{
+    collections::{BTreeMap, HashMap},
+    thread,
+    time::Duration,
+};
+
+use eyre::Result;
+use url::Url;
+
+use crate::{
+    client::{Client, Status},
+    data_model::prelude::*,
+};
+
+/// Status of the peers in the topology of the network
+#[derive(Debug, Clone, Default)]
+pub struct NetworkStatus {
+    /// Status of every peer in the topology
+    pub peers: BTreeMap<PeerId, PeerStatus>,
+}
+
+impl NetworkStatus {
+    /// Height of the blockchain at the peer furthest ahead, `None` if no peer is reachable
+    pub fn height(&self) -> Option<u64> {
+        self.online().map(|(_, status)| status.height).max()
+    }
+
+    /// Peers which reported their status
+    pub fn online(&self) -> impl Iterator<Item = (&PeerId, &OnlinePeerStatus)> {
+        self.peers.iter().filter_map(|(id, status)| match status {
+            PeerStatus::Online(status) => Some((id, status)),
+            PeerStatus::UnknownUrl | PeerStatus::Unreachable(_) => None,
+        })
+    }
+}
+
+/// Status of a single peer of [`NetworkStatus`]
+#[derive(Debug, Clone)]
+pub enum PeerStatus {
+    /// Peer reported its status
+    Online(OnlinePeerStatus),
+    /// Torii URL of the peer isn't known to the client
+    UnknownUrl,
+    /// Peer didn't report its status, with the reason
+    Unreachable(String),
+}
+
+/// Status reported by a peer of the network
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OnlinePeerStatus {
+    /// Number of the committed blocks
+    pub height: u64,
+    /// Time since the genesis block creation
+    pub uptime: Duration,
+    /// Number of the blocks the peer is behind the peer furthest ahead
+    pub sync_lag: u64,
+}
+
+impl Client {
+    /// Gets the status of every peer in the topology of the network.
+    ///
+    /// The topology is discovered from the peer of the client.
+    /// Peers are requested concurrently at their Torii URLs from `torii_urls`,
+    /// since they aren't stored on-chain. Peers missing in `torii_urls` are reported as
+    /// [`PeerStatus::UnknownUrl`], the peers failing to respond as [`PeerStatus::Unreachable`].
+    ///
+    /// # Errors
+    /// Fails if the topology can't be queried
+    pub fn network_status(&self, torii_urls: &HashMap<PeerId, Url>) -> Result<NetworkStatus> {
+        let topology = self.query(FindPeers).execute_all()?;
+
+        let statuses: Vec<_> = thread::scope(|scope| {
+            let requests: Vec<_> = topology
+                .iter()
+                .map(|peer| {
+                    let client = torii_urls.get(peer).map(|url| self.with_torii_url(url));
+                    scope.spawn(move || client.map(|client| client.get_status()))
+                })
+                .collect();
+            requests
+                .into_iter()
+                .map(|request| request.join().expect("status request shouldn't panic"))
+                .collect()
+        });
+
+        let height = statuses
+            .iter()
+            .filter_map(|status| status.as_ref()?.as_ref().ok())
+            .map(|status| status.blocks)
+            .max()
+            .unwrap_or_default();
+        let peers = topology
+            .into_iter()
+            .zip(statuses)
+            .map(|(peer, status)| {
+                let status = match status {
+                    None => PeerStatus::UnknownUrl,
+                    Some(Err(error)) => PeerStatus::Unreachable(format!("{error:#}")),
+                    Some(Ok(Status { blocks, uptime, .. })) => {
+                        PeerStatus::Online(OnlinePeerStatus {
+                            height: blocks,
+                            uptime: uptime.0,
+                            sync_lag: height.saturating_sub(blocks),
+                        })
+                    }
+                };
+                (peer, status)
+            })
+            .collect();
+
+        Ok(NetworkStatus { peers })
+    }
+
+    fn with_torii_url(&self, url: &Url) -> Self {
+        let mut torii_url = url.clone();
+        // Same as at the config user layer, see `join_torii_url`
+        if !torii_url.path().ends_with('/') {
+            let path = torii_url.path().to_owned() + "/";
+            torii_url.set_path(&path);
+        }
+        Self {
+            torii_url,
+            ..self.clone()
+        }
+    }
+}
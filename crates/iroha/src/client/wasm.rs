@@ -0,0 +1,179 @@
+//! Upload of compiled WASM with pre-flight validation, see [`Client::upload_wasm`].
+//!
+//! The size of the WASM and its entrypoints are checked before the transaction is submitted,
+//! so that a wrong binary is reported by the client instead of being rejected by the peer.
+
+use std::{collections::BTreeSet, path::Path};
+
+use eyre::{Result, WrapErr};
+
+use crate::{
+    client::Client,
+    data_model::{
+        prelude::*,
+        query::parameter::prelude::FindParameters,
+        transaction::{Executable, WasmSmartContract},
+    },
+};
+
+/// What the uploaded WASM is, and so which instruction it's wrapped in
+#[derive(Debug, Clone)]
+pub enum WasmUpload {
+    /// Smart contract executed as the transaction itself
+    SmartContract,
+    /// Trigger registered with the authority of the client
+    Trigger {
+        /// Id of the registered trigger
+        id: TriggerId,
+        /// How many times the trigger is executed
+        repeats: Repeats,
+        /// Events the trigger is executed on
+        filter: EventFilterBox,
+    },
+    /// Executor the current executor is upgraded to
+    Executor,
+}
+
+impl WasmUpload {
+    /// Entrypoints the WASM has to export
+    pub fn entrypoints(&self) -> &'static [&'static str] {
+        match self {
+            Self::SmartContract => &["_iroha_smart_contract_main"],
+            Self::Trigger { .. } => &["_iroha_trigger_main"],
+            Self::Executor => &[
+                "_iroha_executor_execute_transaction",
+                "_iroha_executor_execute_instruction",
+                "_iroha_executor_validate_query",
+                "_iroha_executor_migrate",
+            ],
+        }
+    }
+}
+
+/// WASM failed the pre-flight validation of [`Client::upload_wasm`]
+#[derive(Debug, displaydoc::Display, thiserror::Error)]
+pub enum WasmPreflightError {
+    /// WASM of {size} bytes exceeds the limit of {limit} bytes set by the `SmartContractSize` parameter
+    TooLarge {
+        /// Size of the WASM in bytes
+        size: u64,
+        /// Maximum size allowed by the chain
+        limit: u64,
+    },
+    /// WASM doesn't export the `{0}` entrypoint
+    MissingEntrypoint(&'static str),
+    /// Not a valid WASM module: {0}
+    Invalid(String),
+}
+
+/// Check that `wasm` exports the entrypoints required for the `upload` and fits into `limit` bytes
+///
+/// # Errors
+/// See [`WasmPreflightError`]
+fn preflight(
+    wasm: &[u8],
+    upload: &WasmUpload,
+    limit: u64,
+) -> core::result::Result<(), WasmPreflightError> {
+    let size = wasm.len() as u64;
+    if size > limit {
+        return Err(WasmPreflightError::TooLarge { size, limit });
+    }
+
+    let mut exports = BTreeSet::new();
+    for payload in wasmparser::Parser::new(0).parse_all(wasm) {
+        let payload = payload.map_err(|error| WasmPreflightError::Invalid(error.to_string()))?;
+        if let wasmparser::Payload::ExportSection(section) = payload {
+            for export in section {
+                let export =
+                    export.map_err(|error| WasmPreflightError::Invalid(error.to_string()))?;
+                if export.kind == wasmparser::ExternalKind::Func {
+                    exports.insert(export.name);
+                }
+            }
+        }
+    }
+    if let Some(missing) = upload
+        .entrypoints()
+        .iter()
+        .find(|entrypoint| !exports.contains(*entrypoint))
+    {
+        return Err(WasmPreflightError::MissingEntrypoint(missing));
+    }
+
+    Ok(())
+}
+
+impl Client {
+    /// Read the compiled WASM at `path`, validate it and submit it as the `upload` says.
+    ///
+    /// The WASM is validated against the `SmartContractSize` parameter of the chain
+    /// and the entrypoints required by [`WasmUpload::entrypoints`].
+    ///
+    /// # Errors
+    /// Fails if the file can't be read, the WASM fails the validation with [`WasmPreflightError`],
+    /// or the transaction can't be submitted
+    pub fn upload_wasm(
+        &self,
+        path: impl AsRef<Path>,
+        upload: WasmUpload,
+    ) -> Result<HashOf<SignedTransaction>> {
+        let path = path.as_ref();
+        let wasm = std::fs::read(path)
+            .wrap_err_with(|| format!("Failed to read WASM from {}", path.display()))?;
+        let limit = self
+            .query_single(FindParameters)?
+            .transaction()
+            .smart_contract_size()
+            .get();
+        preflight(&wasm, &upload, limit)?;
+
+        let wasm = WasmSmartContract::from_compiled(wasm);
+        let executable = match upload {
+            WasmUpload::SmartContract => Executable::from(wasm),
+            WasmUpload::Trigger {
+                id,
+                repeats,
+                filter,
+            } => Executable::from([Register::trigger(Trigger::new(
+                id,
+                Action::new(wasm, repeats, self.account.clone(), filter),
+            ))]),
+            WasmUpload::Executor => Executable::from([Upgrade::new(Executor::new(wasm))]),
+        };
+        let transaction = self.build_transaction(executable, Metadata::default())?;
+        self.submit_transaction(&transaction)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // (module (func (export "_iroha_trigger_main")))
+    const TRIGGER: &[u8] = &[
+        0x00, 0x61, 0x73, 0x6d, 0x01, 0x00, 0x00, 0x00, 0x01, 0x04, 0x01, 0x60, 0x00, 0x00, 0x03,
+        0x02, 0x01, 0x00, 0x07, 0x17, 0x01, 0x13, 0x5f, 0x69, 0x72, 0x6f, 0x68, 0x61, 0x5f, 0x74,
+        0x72, 0x69, 0x67, 0x67, 0x65, 0x72, 0x5f, 0x6d, 0x61, 0x69, 0x6e, 0x00, 0x00, 0x0a, 0x04,
+        0x01, 0x02, 0x00, 0x0b,
+    ];
+
+    #[test]
+    fn preflight_checks_size_and_entrypoints() {
+        preflight(TRIGGER, &WasmUpload::Executor, 1024).expect_err("not an executor");
+        assert!(matches!(
+            preflight(TRIGGER, &WasmUpload::SmartContract, 1024),
+            Err(WasmPreflightError::MissingEntrypoint(
+                "_iroha_smart_contract_main"
+            ))
+        ));
+        assert!(matches!(
+            preflight(TRIGGER, &WasmUpload::SmartContract, 8),
+            Err(WasmPreflightError::TooLarge { limit: 8, .. })
+        ));
+        assert!(matches!(
+            preflight(&TRIGGER[..20], &WasmUpload::SmartContract, 1024),
+            Err(WasmPreflightError::Invalid(_))
+        ));
+    }
+}
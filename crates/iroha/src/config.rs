@@ -1,10 +1,10 @@
 //! Module for client-related configuration and structs
 
 use core::str::FromStr;
-use std::{path::Path, time::Duration};
+use std::{borrow::Cow, path::Path, sync::Arc, time::Duration};
 
 use derive_more::Display;
-use error_stack::ResultExt;
+use error_stack::{Report, ResultExt};
 use eyre::Result;
 use iroha_config_base::{env::ReadEnv, read::ConfigReader, toml::TomlSource};
 use iroha_primitives::small::SmallStr;
@@ -13,12 +13,16 @@ use serde_with::{DeserializeFromStr, SerializeDisplay};
 use url::Url;
 
 use crate::{
-    crypto::KeyPair,
     data_model::{prelude::*, ChainId},
+    signer::Signer,
 };
 
+mod proxy;
+mod tls;
 mod user;
 
+pub use proxy::Proxy;
+pub use tls::{CertificateFingerprint, Tls, TlsIdentity};
 pub use user::Root as UserConfig;
 
 use crate::secrecy::SecretString;
@@ -29,6 +33,19 @@ pub const DEFAULT_TRANSACTION_TIME_TO_LIVE: Duration = Duration::from_secs(100);
 pub const DEFAULT_TRANSACTION_STATUS_TIMEOUT: Duration = Duration::from_secs(15);
 #[allow(missing_docs)]
 pub const DEFAULT_TRANSACTION_NONCE: bool = false;
+#[allow(missing_docs)]
+pub const DEFAULT_HTTP_MAX_CONNECTIONS: usize = 8;
+#[allow(missing_docs)]
+pub const DEFAULT_HTTP_KEEP_ALIVE: bool = true;
+#[allow(missing_docs)]
+pub const DEFAULT_HTTP_REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+#[allow(missing_docs)]
+pub const DEFAULT_HTTP_COMPRESSION: bool = true;
+
+/// Environment variable selecting the profile loaded by [`Config::load`]
+pub const PROFILE_ENV: &str = "PROFILE";
+/// Table of the configuration file with the profiles, e.g. `[profile.dev]`
+const PROFILES: &str = "profile";
 
 /// Valid web auth login string. See [`WebLogin::from_str`]
 #[derive(Debug, Display, Clone, PartialEq, Eq, DeserializeFromStr, SerializeDisplay)]
@@ -59,18 +76,58 @@ pub struct BasicAuth {
     pub password: SecretString,
 }
 
+/// Settings of the connections to the peer, see [`crate::client::HttpPool`]
+#[derive(Clone, Copy, Debug, Serialize)]
+pub struct Http {
+    /// Maximum number of idle connections kept open for reuse
+    pub max_connections: usize,
+    /// If `false`, every request opens a new connection
+    pub keep_alive: bool,
+    /// Timeout of a single request, from connecting to reading the whole response
+    pub request_timeout: Duration,
+    /// If `true`, the peer is asked to compress the responses
+    pub compression: bool,
+}
+
+impl Http {
+    pub(crate) fn max_idle_connections(&self) -> usize {
+        if self.keep_alive {
+            self.max_connections
+        } else {
+            0
+        }
+    }
+}
+
+impl Default for Http {
+    fn default() -> Self {
+        Self {
+            max_connections: DEFAULT_HTTP_MAX_CONNECTIONS,
+            keep_alive: DEFAULT_HTTP_KEEP_ALIVE,
+            request_timeout: DEFAULT_HTTP_REQUEST_TIMEOUT,
+            compression: DEFAULT_HTTP_COMPRESSION,
+        }
+    }
+}
+
 /// Complete client configuration
 #[derive(Clone, Debug, Serialize)]
 #[allow(missing_docs)]
 pub struct Config {
     pub chain: ChainId,
     pub account: AccountId,
-    pub key_pair: KeyPair,
+    /// Signer of the transactions and the queries of the account.
+    /// Loaded configuration holds the key pair of the account, see [`crate::signer`] for the alternatives.
+    #[serde(skip)]
+    pub signer: Arc<dyn Signer>,
     pub basic_auth: Option<BasicAuth>,
     pub torii_api_url: Url,
     pub transaction_ttl: Duration,
     pub transaction_status_timeout: Duration,
     pub transaction_add_nonce: bool,
+    pub http: Http,
+    pub tls: Tls,
+    pub proxy: Option<Proxy>,
 }
 
 /// An error type for [`Config::load`]
@@ -89,17 +146,57 @@ pub enum LoadPath<P> {
 impl Config {
     /// Loads configuration from a file
     ///
+    /// If the [`PROFILE_ENV`] variable is set, the profile it names is loaded,
+    /// see [`Self::load_profile`].
+    ///
     /// # Errors
     /// - unable to load config from a TOML file
+    /// - the selected profile is not defined
     /// - the config is invalid
     pub fn load(path: LoadPath<impl AsRef<Path>>) -> error_stack::Result<Self, LoadError> {
-        Self::load_with_env(path, Box::new(iroha_config_base::env::std_env))
+        Self::load_with_env(path, None, Box::new(iroha_config_base::env::std_env))
+    }
+
+    /// Loads the named `profile` from a file.
+    ///
+    /// Profiles are the tables under `[profile]`, e.g. `[profile.dev]`, with the same parameters
+    /// as the root of the file. Parameters of the profile override the ones set at the root,
+    /// so that the settings shared by the environments are set once:
+    ///
+    /// ```toml
+    /// chain = "00000000-0000-0000-0000-000000000000"
+    ///
+    /// [profile.dev]
+    /// torii_url = "http://127.0.0.1:8080/"
+    ///
+    /// [profile.mainnet]
+    /// chain = "mainnet"
+    /// torii_url = "https://iroha.example.com/"
+    /// ```
+    ///
+    /// # Errors
+    /// - unable to load config from a TOML file
+    /// - the profile is not defined
+    /// - the config is invalid
+    pub fn load_profile(
+        path: LoadPath<impl AsRef<Path>>,
+        profile: &str,
+    ) -> error_stack::Result<Self, LoadError> {
+        Self::load_with_env(
+            path,
+            Some(profile),
+            Box::new(iroha_config_base::env::std_env),
+        )
     }
 
     fn load_with_env(
         path: LoadPath<impl AsRef<Path>>,
+        profile: Option<&str>,
         env: impl ReadEnv + 'static,
     ) -> error_stack::Result<Self, LoadError> {
+        let profile = profile
+            .map(ToOwned::to_owned)
+            .or_else(|| env.read_env(PROFILE_ENV).map(Cow::into_owned));
         let toml_source = match path {
             LoadPath::Explicit(path) => {
                 Some(TomlSource::from_file(path).change_context(LoadError)?)
@@ -117,6 +214,15 @@ impl Config {
                 Err(err) => Err(err).change_context(LoadError)?,
             },
         };
+        let toml_source = match (toml_source, profile) {
+            (Some(toml_source), profile) => Some(select_profile(toml_source, profile.as_deref())?),
+            (None, Some(profile)) => {
+                return Err(Report::new(LoadError).attach_printable(format!(
+                    "profile `{profile}` is selected, but the configuration file is not found"
+                )))
+            }
+            (None, None) => None,
+        };
 
         let config = toml_source
             .map_or_else(ConfigReader::new, |x| {
@@ -131,13 +237,52 @@ impl Config {
     }
 }
 
+/// Merge the parameters of the `profile` into the root of the file.
+/// The other profiles are dropped, so that they are not reported as unknown parameters.
+fn select_profile(
+    mut toml_source: TomlSource,
+    profile: Option<&str>,
+) -> error_stack::Result<TomlSource, LoadError> {
+    let profiles = toml_source.table_mut().remove(PROFILES);
+    let Some(profile) = profile else {
+        return Ok(toml_source);
+    };
+
+    match profiles.and_then(|profiles| match profiles {
+        toml::Value::Table(mut profiles) => profiles.remove(profile),
+        _ => None,
+    }) {
+        Some(toml::Value::Table(parameters)) => {
+            merge(toml_source.table_mut(), parameters);
+            Ok(toml_source)
+        }
+        _ => Err(Report::new(LoadError).attach_printable(format!(
+            "profile `{profile}` is not defined in `{}`",
+            toml_source.path().display()
+        ))),
+    }
+}
+
+fn merge(table: &mut toml::Table, overrides: toml::Table) {
+    for (key, value) in overrides {
+        match (table.get_mut(&key), value) {
+            (Some(toml::Value::Table(nested)), toml::Value::Table(overrides)) => {
+                merge(nested, overrides);
+            }
+            (_, value) => {
+                table.insert(key, value);
+            }
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::{collections::HashSet, io::Write};
 
     use assertables::{assert_contains, assert_contains_as_result};
     use iroha_config_base::env::MockEnv;
-    use iroha_crypto::ExposedPrivateKey;
+    use iroha_crypto::{ExposedPrivateKey, KeyPair};
 
     use super::*;
 
@@ -171,6 +316,23 @@ mod tests {
             time_to_live_ms = 100_000
             status_timeout_ms = 100_000
             nonce = false
+
+            [http]
+            max_connections = 4
+            keep_alive = true
+            request_timeout_ms = 10_000
+            compression = true
+
+            [tls]
+            pinned_certificates = ["9f86d081884c7d659a2feaa0c55ad015a3bf4f1b2b0b822cd15d6c15b0f00a08"]
+
+            [proxy]
+            url = "http://proxy.example.com:3128"
+            no_proxy = ["localhost", ".internal.example.com"]
+
+            [proxy.credentials]
+            web_login = "mad_hatter"
+            password = "ilovetea"
         }
     }
 
@@ -244,6 +406,44 @@ mod tests {
         );
     }
 
+    #[test]
+    fn selected_profile_overrides_root() {
+        let mut sample = config_sample();
+        sample.insert(
+            "profile".to_owned(),
+            toml::toml! {
+                [dev]
+                torii_url = "http://127.0.0.1:8081/"
+
+                [dev.http]
+                max_connections = 1
+
+                [mainnet]
+                chain = "mainnet"
+            }
+            .into(),
+        );
+        let mut file = tempfile::NamedTempFile::new().unwrap();
+        file.write_all(toml::to_string(&sample).unwrap().as_bytes())
+            .unwrap();
+        let load = |env: MockEnv| Config::load_with_env(LoadPath::Explicit(file.path()), None, env);
+
+        let config = load(MockEnv::new()).unwrap();
+        assert_eq!(config.torii_api_url.as_str(), "http://127.0.0.1:8080/");
+
+        let config = load(MockEnv::from([("PROFILE", "dev")])).unwrap();
+        assert_eq!(config.torii_api_url.as_str(), "http://127.0.0.1:8081/");
+        assert_eq!(config.http.max_connections, 1);
+        assert!(config.http.keep_alive);
+        assert_eq!(
+            config.chain,
+            ChainId::from("00000000-0000-0000-0000-000000000000")
+        );
+
+        let _err =
+            load(MockEnv::from([("PROFILE", "staging")])).expect_err("profile is not defined");
+    }
+
     #[test]
     fn full_env_fallback() {
         let key = KeyPair::random();
@@ -258,9 +458,21 @@ mod tests {
             .set("ACCOUNT_PUBLIC_KEY", key.public_key().to_string());
 
         let _config =
-            Config::load_with_env(LoadPath::Default("non_existing_path"), env.clone()).unwrap();
+            Config::load_with_env(LoadPath::Default("non_existing_path"), None, env.clone())
+                .unwrap();
 
         assert_eq!(env.unvisited(), HashSet::new());
-        assert_eq!(env.unknown(), HashSet::new());
+        assert_eq!(
+            env.unknown(),
+            [
+                "PROFILE",
+                "TLS_CA_CERTIFICATES",
+                "TLS_CLIENT_CERTIFICATE",
+                "TLS_CLIENT_PRIVATE_KEY"
+            ]
+            .into_iter()
+            .map(ToOwned::to_owned)
+            .collect()
+        );
     }
 }
@@ -0,0 +1,91 @@
+//! HTTP proxy of the connections to the peer.
+
+use serde::{Deserialize, Serialize};
+use url::Url;
+
+use super::BasicAuth;
+
+/// HTTP proxy the connections to the peer are made through, e.g. for the egress of corporate networks.
+///
+/// `WebSocket` streams (events and blocks) are tunneled through the proxy with `CONNECT`.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct Proxy {
+    /// URL of the proxy, e.g. `http://proxy.example.com:3128`
+    pub url: Url,
+    /// Credentials sent to the proxy in the `Proxy-Authorization` header
+    pub credentials: Option<BasicAuth>,
+    /// Hosts connected to directly, in the format of the `NO_PROXY` environment variable:
+    /// `example.com` and `.example.com` match the domain and its subdomains, `*` matches any host
+    #[serde(default)]
+    pub no_proxy: Vec<String>,
+}
+
+impl Proxy {
+    /// Whether the connections to the host of `url` bypass the proxy
+    pub fn bypasses(&self, url: &Url) -> bool {
+        let Some(host) = url.host_str() else {
+            return false;
+        };
+        let host = host
+            .trim_start_matches('[')
+            .trim_end_matches(']')
+            .to_ascii_lowercase();
+        self.no_proxy.iter().any(|pattern| {
+            let pattern = pattern.trim().trim_start_matches('.').to_ascii_lowercase();
+            pattern == "*"
+                || host == pattern
+                || host
+                    .strip_suffix(&pattern)
+                    .is_some_and(|subdomain| subdomain.ends_with('.'))
+        })
+    }
+
+    /// URL of the proxy along with the credentials, in the format expected by `ureq`
+    pub(crate) fn url_with_credentials(&self) -> Url {
+        let mut url = self.url.clone();
+        if let Some(credentials) = &self.credentials {
+            url.set_username(&credentials.web_login.to_string())
+                .and_then(|()| url.set_password(Some(credentials.password.expose_secret())))
+                .expect("proxy URL has a host, since its scheme is `http`");
+        }
+        url
+    }
+
+    /// Value of the `Proxy-Authorization` header, if the credentials are set
+    pub(crate) fn authorization(&self) -> Option<String> {
+        self.credentials.as_ref().map(|credentials| {
+            let engine = base64::engine::general_purpose::STANDARD;
+            let encoded = base64::engine::Engine::encode(
+                &engine,
+                format!(
+                    "{}:{}",
+                    credentials.web_login,
+                    credentials.password.expose_secret()
+                ),
+            );
+            format!("Basic {encoded}")
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn no_proxy_matches_domain_and_subdomains() {
+        let proxy = Proxy {
+            url: "http://proxy.example.com:3128".parse().unwrap(),
+            credentials: None,
+            no_proxy: vec![".internal.example.com".to_owned(), "127.0.0.1".to_owned()],
+        };
+        let bypasses = |url: &str| proxy.bypasses(&url.parse().unwrap());
+
+        assert!(bypasses("http://internal.example.com:8080"));
+        assert!(bypasses("https://peer.Internal.example.com"));
+        assert!(bypasses("http://127.0.0.1:8080"));
+        assert!(!bypasses("http://notinternal.example.com"));
+        assert!(!bypasses("http://peer.example.com"));
+    }
+}
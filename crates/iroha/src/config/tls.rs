@@ -0,0 +1,269 @@
+//! TLS settings of the connections to the peer.
+
+use core::str::FromStr;
+use std::path::PathBuf;
+
+use derive_more::Display;
+use eyre::Result;
+use serde::Serialize;
+use serde_with::{DeserializeFromStr, SerializeDisplay};
+
+/// TLS settings of the HTTP requests to the peer, e.g. for deployments where Torii is behind a TLS-terminating proxy
+/// signed by a private CA.
+///
+/// Only supported by the `rustls` backends. `WebSocket` streams (events and blocks) are verified against the default roots.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize)]
+pub struct Tls {
+    /// PEM bundle of the root certificates which replace the default ones
+    pub ca_certificates: Option<PathBuf>,
+    /// Certificate presented to the peer for mutual TLS
+    pub client_identity: Option<TlsIdentity>,
+    /// If not empty, the certificate of the peer must match one of the fingerprints in addition to being trusted
+    pub pinned_certificates: Vec<CertificateFingerprint>,
+}
+
+/// Certificate chain and private key of the client, both PEM-encoded
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct TlsIdentity {
+    /// Certificate chain, starting with the certificate of the client
+    pub certificate: PathBuf,
+    /// Private key of the certificate
+    pub private_key: PathBuf,
+}
+
+/// SHA-256 fingerprint of a DER-encoded certificate, written as 64 hexadecimal digits
+#[derive(
+    Debug, Display, Clone, Copy, PartialEq, Eq, Hash, DeserializeFromStr, SerializeDisplay,
+)]
+#[display(fmt = "{}", "hex::encode(_0)")]
+pub struct CertificateFingerprint([u8; 32]);
+
+impl CertificateFingerprint {
+    /// Fingerprint of the DER-encoded `certificate`
+    #[cfg(feature = "__rustls")]
+    pub fn of(certificate: &[u8]) -> Self {
+        use sha2::Digest as _;
+
+        Self(sha2::Sha256::digest(certificate).into())
+    }
+}
+
+impl FromStr for CertificateFingerprint {
+    type Err = eyre::ErrReport;
+
+    fn from_str(fingerprint: &str) -> Result<Self> {
+        let mut bytes = [0; 32];
+        // `openssl x509 -fingerprint` separates the bytes with colons
+        hex::decode_to_slice(fingerprint.replace(':', ""), &mut bytes).map_err(|err| {
+            eyre::eyre!("`{fingerprint}` is not a SHA-256 fingerprint of a certificate: {err}")
+        })?;
+        Ok(Self(bytes))
+    }
+}
+
+impl Tls {
+    /// Whether the default TLS settings of the backend are used
+    pub fn is_default(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// Check that the TLS backend supports the settings.
+    ///
+    /// # Errors
+    /// Fails unless the default settings are used
+    #[cfg(not(feature = "__rustls"))]
+    pub(crate) fn client_config(&self) -> Result<()> {
+        if self.is_default() {
+            return Ok(());
+        }
+        Err(eyre::eyre!(
+            "Custom TLS settings are only supported by the `rustls` backends"
+        ))
+    }
+
+    /// Read the certificates and build the configuration of `rustls`,
+    /// or `None` if the default settings of the backend are used.
+    ///
+    /// # Errors
+    /// Fails if the files can't be read or don't hold the certificates or the key
+    #[cfg(feature = "__rustls")]
+    pub(crate) fn client_config(&self) -> Result<Option<std::sync::Arc<rustls::ClientConfig>>> {
+        if self.is_default() {
+            return Ok(None);
+        }
+        rustls_config::build(self).map(|config| Some(std::sync::Arc::new(config)))
+    }
+}
+
+#[cfg(feature = "__rustls")]
+mod rustls_config {
+    use std::{fs::File, io::BufReader, path::Path, sync::Arc};
+
+    use eyre::{eyre, Result, WrapErr};
+    use rustls::{
+        client::{
+            danger::{HandshakeSignatureValid, ServerCertVerified, ServerCertVerifier},
+            WebPkiServerVerifier,
+        },
+        pki_types::{CertificateDer, PrivateKeyDer, ServerName, UnixTime},
+        ClientConfig, DigitallySignedStruct, RootCertStore, SignatureScheme,
+    };
+
+    use super::{CertificateFingerprint, Tls};
+
+    pub fn build(tls: &Tls) -> Result<ClientConfig> {
+        let provider = Arc::new(rustls::crypto::ring::default_provider());
+        let roots = match &tls.ca_certificates {
+            Some(path) => {
+                let mut roots = RootCertStore::empty();
+                for certificate in read_certificates(path)? {
+                    roots.add(certificate).wrap_err_with(|| {
+                        format!("Invalid root certificate in {}", path.display())
+                    })?;
+                }
+                roots
+            }
+            None => default_roots()?,
+        };
+        let verifier =
+            WebPkiServerVerifier::builder_with_provider(Arc::new(roots), Arc::clone(&provider))
+                .build()
+                .wrap_err("Failed to build the verifier of the peer certificates")?;
+
+        let builder = ClientConfig::builder_with_provider(Arc::clone(&provider))
+            .with_safe_default_protocol_versions()
+            .wrap_err("Failed to configure TLS protocol versions")?;
+        let builder = if tls.pinned_certificates.is_empty() {
+            builder.with_webpki_verifier(verifier)
+        } else {
+            builder
+                .dangerous()
+                .with_custom_certificate_verifier(Arc::new(PinningVerifier {
+                    inner: verifier,
+                    pins: tls.pinned_certificates.clone(),
+                }))
+        };
+
+        match &tls.client_identity {
+            Some(identity) => {
+                let chain = read_certificates(&identity.certificate)?;
+                let key = read_private_key(&identity.private_key)?;
+                builder
+                    .with_client_auth_cert(chain, key)
+                    .wrap_err("Client certificate doesn't match its private key")
+            }
+            None => Ok(builder.with_no_client_auth()),
+        }
+    }
+
+    fn default_roots() -> Result<RootCertStore> {
+        let mut roots = RootCertStore::empty();
+        #[cfg(feature = "tls-rustls-native-roots")]
+        {
+            let certificates = rustls_native_certs::load_native_certs()
+                .wrap_err("Failed to load the root certificates of the platform")?;
+            roots.add_parsable_certificates(certificates);
+        }
+        #[cfg(feature = "tls-rustls-webpki-roots")]
+        roots.extend(webpki_roots::TLS_SERVER_ROOTS.iter().cloned());
+        Ok(roots)
+    }
+
+    fn open(path: &Path) -> Result<BufReader<File>> {
+        File::open(path)
+            .map(BufReader::new)
+            .wrap_err_with(|| format!("Failed to open {}", path.display()))
+    }
+
+    fn read_certificates(path: &Path) -> Result<Vec<CertificateDer<'static>>> {
+        let certificates = rustls_pemfile::certs(&mut open(path)?)
+            .collect::<Result<Vec<_>, _>>()
+            .wrap_err_with(|| format!("Failed to read certificates from {}", path.display()))?;
+        if certificates.is_empty() {
+            return Err(eyre!("No certificates found in {}", path.display()));
+        }
+        Ok(certificates)
+    }
+
+    fn read_private_key(path: &Path) -> Result<PrivateKeyDer<'static>> {
+        rustls_pemfile::private_key(&mut open(path)?)
+            .wrap_err_with(|| format!("Failed to read private key from {}", path.display()))?
+            .ok_or_else(|| eyre!("No private key found in {}", path.display()))
+    }
+
+    /// Verifies the certificate of the peer as usual, then checks that it is pinned
+    #[derive(Debug)]
+    struct PinningVerifier {
+        inner: Arc<WebPkiServerVerifier>,
+        pins: Vec<CertificateFingerprint>,
+    }
+
+    impl ServerCertVerifier for PinningVerifier {
+        fn verify_server_cert(
+            &self,
+            end_entity: &CertificateDer<'_>,
+            intermediates: &[CertificateDer<'_>],
+            server_name: &ServerName<'_>,
+            ocsp_response: &[u8],
+            now: UnixTime,
+        ) -> Result<ServerCertVerified, rustls::Error> {
+            let verified = self.inner.verify_server_cert(
+                end_entity,
+                intermediates,
+                server_name,
+                ocsp_response,
+                now,
+            )?;
+            let fingerprint = CertificateFingerprint::of(end_entity);
+            if !self.pins.contains(&fingerprint) {
+                return Err(rustls::Error::General(format!(
+                    "Certificate of the peer with fingerprint {fingerprint} is not pinned"
+                )));
+            }
+            Ok(verified)
+        }
+
+        fn verify_tls12_signature(
+            &self,
+            message: &[u8],
+            cert: &CertificateDer<'_>,
+            dss: &DigitallySignedStruct,
+        ) -> Result<HandshakeSignatureValid, rustls::Error> {
+            self.inner.verify_tls12_signature(message, cert, dss)
+        }
+
+        fn verify_tls13_signature(
+            &self,
+            message: &[u8],
+            cert: &CertificateDer<'_>,
+            dss: &DigitallySignedStruct,
+        ) -> Result<HandshakeSignatureValid, rustls::Error> {
+            self.inner.verify_tls13_signature(message, cert, dss)
+        }
+
+        fn supported_verify_schemes(&self) -> Vec<SignatureScheme> {
+            self.inner.supported_verify_schemes()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fingerprint_with_colons_is_parsed() {
+        let hex = "9f86d081884c7d659a2feaa0c55ad015a3bf4f1b2b0b822cd15d6c15b0f00a08";
+        let with_colons = hex
+            .as_bytes()
+            .chunks(2)
+            .map(|byte| std::str::from_utf8(byte).unwrap())
+            .collect::<Vec<_>>()
+            .join(":")
+            .to_uppercase();
+
+        let fingerprint: CertificateFingerprint = with_colons.parse().unwrap();
+        assert_eq!(fingerprint.to_string(), hex);
+        assert!("9f86".parse::<CertificateFingerprint>().is_err());
+    }
+}
@@ -1,5 +1,7 @@
 //! User configuration view.
 
+use std::{path::PathBuf, sync::Arc};
+
 use error_stack::{Report, ResultExt};
 use iroha_config_base::{
     attach::ConfigValueAndOrigin,
@@ -9,7 +11,7 @@ use iroha_config_base::{
 use url::Url;
 
 use crate::{
-    config::BasicAuth,
+    config::{BasicAuth, CertificateFingerprint, Proxy},
     crypto::{KeyPair, PrivateKey, PublicKey},
     data_model::prelude::{AccountId, ChainId, DomainId},
 };
@@ -27,6 +29,11 @@ pub struct Root {
     pub account: Account,
     #[config(nested)]
     pub transaction: Transaction,
+    #[config(nested)]
+    pub http: Http,
+    #[config(nested)]
+    pub tls: Tls,
+    pub proxy: Option<Proxy>,
 }
 
 #[derive(thiserror::Error, Debug)]
@@ -37,6 +44,12 @@ pub enum ParseError {
     KeyPair,
     #[error("Unsupported URL scheme: `{scheme}`")]
     UnsupportedUrlScheme { scheme: String },
+    #[error("Both the client certificate and its private key should be set for mutual TLS")]
+    TlsIdentity,
+    #[error("Invalid TLS settings")]
+    Tls,
+    #[error("Unsupported proxy URL scheme: `{scheme}`")]
+    UnsupportedProxyScheme { scheme: String },
 }
 
 impl Root {
@@ -62,6 +75,15 @@ impl Root {
                     status_timeout_ms: tx_timeout,
                     nonce: tx_add_nonce,
                 },
+            http:
+                Http {
+                    max_connections,
+                    keep_alive,
+                    request_timeout_ms,
+                    compression,
+                },
+            tls,
+            proxy,
         } = self;
 
         let mut emitter = Emitter::new();
@@ -88,6 +110,17 @@ impl Root {
                 .attach_printable("Note: only `http` and `https` protocols are supported"),
             ),
         }
+        if let Some(proxy) = &proxy {
+            if proxy.url.scheme() != "http" {
+                emitter.emit(
+                    Report::new(ParseError::UnsupportedProxyScheme {
+                        scheme: proxy.url.scheme().to_string(),
+                    })
+                    .attach_printable(format!("proxy.url = {}", proxy.url))
+                    .attach_printable("Note: only `http` proxies are supported"),
+                )
+            }
+        }
         let torii_api_url = {
             let mut url = torii_url.into_value();
             let path = url.path();
@@ -108,17 +141,27 @@ impl Root {
             .change_context(ParseError::KeyPair)
             .ok_or_emit(&mut emitter);
 
+        let tls = tls.parse().ok_or_emit(&mut emitter);
+
         emitter.into_result()?;
 
         Ok(super::Config {
             chain: chain_id,
             account: account_id,
-            key_pair: key_pair.unwrap(),
+            signer: Arc::new(key_pair.unwrap()),
             torii_api_url,
             basic_auth,
             transaction_ttl: tx_ttl.into_value().get(),
             transaction_status_timeout: tx_timeout.into_value().get(),
             transaction_add_nonce: tx_add_nonce,
+            http: super::Http {
+                max_connections,
+                keep_alive,
+                request_timeout: request_timeout_ms.into_value().get(),
+                compression,
+            },
+            tls: tls.unwrap(),
+            proxy,
         })
     }
 }
@@ -144,3 +187,59 @@ pub struct Transaction {
     #[config(default = "super::DEFAULT_TRANSACTION_NONCE")]
     pub nonce: bool,
 }
+
+#[derive(Debug, Clone, ReadConfig)]
+#[allow(missing_docs)]
+pub struct Http {
+    #[config(default = "super::DEFAULT_HTTP_MAX_CONNECTIONS")]
+    pub max_connections: usize,
+    #[config(default = "super::DEFAULT_HTTP_KEEP_ALIVE")]
+    pub keep_alive: bool,
+    #[config(default = "super::DEFAULT_HTTP_REQUEST_TIMEOUT.into()")]
+    pub request_timeout_ms: WithOrigin<DurationMs>,
+    #[config(default = "super::DEFAULT_HTTP_COMPRESSION")]
+    pub compression: bool,
+}
+
+#[derive(Debug, Clone, ReadConfig)]
+#[allow(missing_docs)]
+pub struct Tls {
+    #[config(env = "TLS_CA_CERTIFICATES")]
+    pub ca_certificates: Option<WithOrigin<PathBuf>>,
+    #[config(env = "TLS_CLIENT_CERTIFICATE")]
+    pub client_certificate: Option<WithOrigin<PathBuf>>,
+    #[config(env = "TLS_CLIENT_PRIVATE_KEY")]
+    pub client_private_key: Option<WithOrigin<PathBuf>>,
+    #[config(default)]
+    pub pinned_certificates: Vec<CertificateFingerprint>,
+}
+
+impl Tls {
+    /// Resolve the paths relative to the configuration file and check that the certificates can be loaded
+    fn parse(self) -> error_stack::Result<super::Tls, ParseError> {
+        let client_identity = match (self.client_certificate, self.client_private_key) {
+            (None, None) => None,
+            (Some(certificate), Some(private_key)) => Some(super::TlsIdentity {
+                certificate: certificate.resolve_relative_path(),
+                private_key: private_key.resolve_relative_path(),
+            }),
+            (Some(path), None) | (None, Some(path)) => {
+                return Err(Report::new(ParseError::TlsIdentity)
+                    .attach_printable(path.into_attachment().display_path()))
+            }
+        };
+        let tls = super::Tls {
+            ca_certificates: self
+                .ca_certificates
+                .as_ref()
+                .map(WithOrigin::resolve_relative_path),
+            client_identity,
+            pinned_certificates: self.pinned_certificates,
+        };
+
+        tls.client_config()
+            .map_err(|err| Report::new(ParseError::Tls).attach_printable(format!("{err:#}")))?;
+
+        Ok(tls)
+    }
+}
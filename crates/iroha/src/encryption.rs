@@ -0,0 +1,102 @@
+//! Encryption of the metadata values to the accounts.
+//!
+//! Sensitive attributes are stored on-chain as [`EncryptedValue`]s, which only the holder
+//! of the private key of the recipient can decrypt. The value is encrypted to a signatory of the
+//! recipient account, so no keys have to be exchanged upfront.
+//!
+//! ```
+//! use iroha::{
+//!     crypto::KeyPair,
+//!     data_model::prelude::*,
+//!     encryption::{decrypt, encrypt},
+//! };
+//!
+//! let alice = KeyPair::random();
+//! let card: Name = "card".parse().unwrap();
+//! let mut metadata = Metadata::default();
+//! let value = encrypt(&Json::new("4111 1111 1111 1111"), alice.public_key()).unwrap();
+//! metadata.insert(card.clone(), Json::new(value));
+//!
+//! let stored: EncryptedValue = metadata.get(&card).unwrap().try_into_any().unwrap();
+//! assert_eq!(
+//!     decrypt(&stored, &alice).unwrap(),
+//!     Json::new("4111 1111 1111 1111")
+//! );
+//! ```
+
+use core::num::NonZeroU64;
+
+use eyre::{eyre, Result, WrapErr};
+
+use crate::{
+    crypto::{encryption::sealed, KeyPair, PublicKey},
+    data_model::prelude::*,
+};
+
+/// Encrypt `value` so that only the holder of the private key of `recipient` can [`decrypt`] it
+///
+/// # Errors
+/// Fails if `recipient` is not an Ed25519 key or encryption fails
+pub fn encrypt(value: &Json, recipient: &PublicKey) -> Result<EncryptedValue> {
+    let ciphertext = sealed::seal(recipient, value.as_ref().as_bytes())
+        .wrap_err("Failed to encrypt metadata value")?;
+
+    Ok(EncryptedValue {
+        algorithm: EncryptionAlgorithm::X25519ChaCha20Poly1305,
+        recipient: recipient.clone(),
+        ciphertext,
+    })
+}
+
+/// Build a [`Message`] to `recipient` carrying `value` encrypted to its signatory.
+///
+/// The default executor accepts the message only if `recipient` granted the sender
+/// [`CanMessageAccount`](iroha_executor_data_model::permission::account::CanMessageAccount).
+///
+/// # Errors
+/// Fails if the signatory of `recipient` is not an Ed25519 key or encryption fails
+pub fn message(recipient: AccountId, value: &Json, ttl: NonZeroU64) -> Result<Message> {
+    let payload = encrypt(value, recipient.signatory())?;
+    Ok(Message::new(recipient, payload, ttl))
+}
+
+/// Decrypt `value` encrypted to the public key of `key_pair`
+///
+/// # Errors
+/// Fails if `value` is encrypted to another key or has been tampered with
+pub fn decrypt(value: &EncryptedValue, key_pair: &KeyPair) -> Result<Json> {
+    if value.recipient != *key_pair.public_key() {
+        return Err(eyre!(
+            "Metadata value is encrypted to {}, not to {}",
+            value.recipient,
+            key_pair.public_key()
+        ));
+    }
+    let plaintext = match value.algorithm {
+        EncryptionAlgorithm::X25519ChaCha20Poly1305 => {
+            sealed::open(key_pair.private_key(), &value.ciphertext)
+                .wrap_err("Failed to decrypt metadata value")?
+        }
+    };
+
+    serde_json::from_slice::<serde_json::Value>(&plaintext)
+        .map(Json::from)
+        .wrap_err("Decrypted metadata value isn't valid JSON")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn value_encrypted_to_another_key_is_not_decrypted() {
+        let (alice, bob) = (KeyPair::random(), KeyPair::random());
+        let value = encrypt(&Json::new(42_u32), alice.public_key()).unwrap();
+
+        let _err = decrypt(&value, &bob).expect_err("value is encrypted to alice");
+
+        let mut tampered = value.clone();
+        tampered.recipient = bob.public_key().clone();
+        let _err = decrypt(&tampered, &bob).expect_err("value is encrypted to alice");
+    }
+}
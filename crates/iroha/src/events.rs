@@ -0,0 +1,192 @@
+//! Handling of the events received from the peer without matching on the nested event enums.
+//!
+//! Implement [`EventVisitor`] overriding only the handlers of the events of interest,
+//! and pass every received event to [`EventVisitor::visit_event`].
+//! The default handlers descend into the nested events and ignore the rest,
+//! so that the implementations keep compiling when new kinds of events are introduced.
+//!
+//! ```
+//! use iroha::events::{AssetEvent, EventVisitor};
+//!
+//! #[derive(Default)]
+//! struct AssetsChanged(usize);
+//!
+//! impl EventVisitor for AssetsChanged {
+//!     fn visit_asset_event(&mut self, _event: &AssetEvent) {
+//!         self.0 += 1;
+//!     }
+//! }
+//! ```
+
+pub use crate::data_model::events::prelude::*;
+
+/// Visitor of the events, see the [module-level documentation](self).
+///
+/// The handlers of the events containing other events descend into them, the other handlers do nothing.
+/// Data events emitted by the [`Tagged`](crate::data_model::isi::Tagged) instructions are visited without the tag.
+#[allow(unused_variables)]
+pub trait EventVisitor {
+    /// Visit any event
+    fn visit_event(&mut self, event: &EventBox) {
+        visit_event(self, event);
+    }
+
+    /// Visit pipeline event
+    fn visit_pipeline_event(&mut self, event: &PipelineEventBox) {
+        visit_pipeline_event(self, event);
+    }
+
+    /// Visit status change of a transaction
+    fn visit_transaction_event(&mut self, event: &TransactionEvent) {}
+
+    /// Visit status change of a block
+    fn visit_block_event(&mut self, event: &BlockEvent) {}
+
+    /// Visit data event
+    fn visit_data_event(&mut self, event: &DataEvent) {
+        visit_data_event(self, event);
+    }
+
+    /// Visit peer event
+    fn visit_peer_event(&mut self, event: &PeerEvent) {}
+
+    /// Visit domain event
+    fn visit_domain_event(&mut self, event: &DomainEvent) {
+        visit_domain_event(self, event);
+    }
+
+    /// Visit account event
+    fn visit_account_event(&mut self, event: &AccountEvent) {
+        visit_account_event(self, event);
+    }
+
+    /// Visit asset event
+    fn visit_asset_event(&mut self, event: &AssetEvent) {}
+
+    /// Visit asset definition event
+    fn visit_asset_definition_event(&mut self, event: &AssetDefinitionEvent) {}
+
+    /// Visit NFT event
+    fn visit_nft_event(&mut self, event: &NftEvent) {}
+
+    /// Visit trigger event
+    fn visit_trigger_event(&mut self, event: &TriggerEvent) {}
+
+    /// Visit role event
+    fn visit_role_event(&mut self, event: &RoleEvent) {}
+
+    /// Visit configuration event
+    fn visit_configuration_event(&mut self, event: &ConfigurationEvent) {}
+
+    /// Visit executor event
+    fn visit_executor_event(&mut self, event: &ExecutorEvent) {}
+
+    /// Visit time event
+    fn visit_time_event(&mut self, event: &TimeEvent) {}
+
+    /// Visit trigger execution event
+    fn visit_execute_trigger_event(&mut self, event: &ExecuteTriggerEvent) {}
+
+    /// Visit trigger completion event
+    fn visit_trigger_completed_event(&mut self, event: &TriggerCompletedEvent) {}
+}
+
+/// Default handling of [`EventBox`]
+pub fn visit_event<V: EventVisitor + ?Sized>(visitor: &mut V, event: &EventBox) {
+    match event {
+        EventBox::Pipeline(event) => visitor.visit_pipeline_event(event),
+        EventBox::Data(event) => visitor.visit_data_event(event),
+        EventBox::Time(event) => visitor.visit_time_event(event),
+        EventBox::ExecuteTrigger(event) => visitor.visit_execute_trigger_event(event),
+        EventBox::TriggerCompleted(event) => visitor.visit_trigger_completed_event(event),
+    }
+}
+
+/// Default handling of [`PipelineEventBox`]
+pub fn visit_pipeline_event<V: EventVisitor + ?Sized>(visitor: &mut V, event: &PipelineEventBox) {
+    match event {
+        PipelineEventBox::Transaction(event) => visitor.visit_transaction_event(event),
+        PipelineEventBox::Block(event) => visitor.visit_block_event(event),
+    }
+}
+
+/// Default handling of [`DataEvent`]
+pub fn visit_data_event<V: EventVisitor + ?Sized>(visitor: &mut V, event: &DataEvent) {
+    match event.untagged() {
+        DataEvent::Peer(event) => visitor.visit_peer_event(event),
+        DataEvent::Domain(event) => visitor.visit_domain_event(event),
+        DataEvent::Trigger(event) => visitor.visit_trigger_event(event),
+        DataEvent::Role(event) => visitor.visit_role_event(event),
+        DataEvent::Configuration(event) => visitor.visit_configuration_event(event),
+        DataEvent::Executor(event) => visitor.visit_executor_event(event),
+        // Tagged events are never nested
+        DataEvent::Tagged(_) => {}
+    }
+}
+
+/// Default handling of [`DomainEvent`]
+pub fn visit_domain_event<V: EventVisitor + ?Sized>(visitor: &mut V, event: &DomainEvent) {
+    match event {
+        DomainEvent::Account(event) => visitor.visit_account_event(event),
+        DomainEvent::AssetDefinition(event) => visitor.visit_asset_definition_event(event),
+        DomainEvent::Nft(event) => visitor.visit_nft_event(event),
+        _ => {}
+    }
+}
+
+/// Default handling of [`AccountEvent`]
+pub fn visit_account_event<V: EventVisitor + ?Sized>(visitor: &mut V, event: &AccountEvent) {
+    if let AccountEvent::Asset(event) = event {
+        visitor.visit_asset_event(event);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use iroha_test_samples::ALICE_ID;
+
+    use super::*;
+    use crate::data_model::prelude::*;
+
+    #[derive(Default)]
+    struct Counter {
+        assets: usize,
+        accounts: usize,
+    }
+
+    impl EventVisitor for Counter {
+        fn visit_account_event(&mut self, event: &AccountEvent) {
+            self.accounts += 1;
+            visit_account_event(self, event);
+        }
+
+        fn visit_asset_event(&mut self, _event: &AssetEvent) {
+            self.assets += 1;
+        }
+    }
+
+    #[test]
+    fn only_overridden_handlers_are_called() {
+        let asset_id = AssetId::new("rose#wonderland".parse().unwrap(), ALICE_ID.clone());
+        let asset_created = EventBox::from(DataEvent::from(DomainEvent::Account(
+            AccountEvent::Asset(AssetEvent::Created(Asset::new(asset_id, 1_u32))),
+        )));
+        let tagged = EventBox::Data(
+            DataEvent::from(DomainEvent::Account(AccountEvent::Deleted(
+                ALICE_ID.clone(),
+            )))
+            .with_tag("offboarding".parse().unwrap()),
+        );
+        let domain_deleted = EventBox::from(DataEvent::from(DomainEvent::Deleted(
+            "wonderland".parse().unwrap(),
+        )));
+
+        let mut counter = Counter::default();
+        for event in [asset_created, tagged, domain_deleted] {
+            counter.visit_event(&event);
+        }
+
+        assert_eq!(counter.accounts, 2);
+        assert_eq!(counter.assets, 1);
+    }
+}
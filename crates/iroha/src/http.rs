@@ -265,6 +265,11 @@ pub mod ws {
             ///
             /// It doesn't return a `Result` because it doesn't accept any parameters except of itself.
             fn init(self) -> InitData<R, Self::Next>;
+
+            /// Proxy the WS connection should be tunneled through, if any
+            fn proxy(&self) -> Option<&crate::config::Proxy> {
+                None
+            }
         }
 
         /// Events flow stage.
@@ -1,98 +1,443 @@
 //! Defaults for various items used in communication over http(s).
-use std::net::TcpStream;
-
-use attohttpc::{
-    body as atto_body, RequestBuilder as AttoHttpRequestBuilder, Response as AttoHttpResponse,
+use std::{
+    io::{Read, Write},
+    net::TcpStream,
+    sync::Arc,
+    time::Instant,
 };
+
 use eyre::{eyre, Error, Result, WrapErr};
-use http::header::HeaderName;
+use http::header::{
+    HeaderMap, HeaderName, HeaderValue, ACCEPT_ENCODING, CONTENT_ENCODING, CONTENT_LENGTH,
+};
 use tungstenite::{client::IntoClientRequest, stream::MaybeTlsStream, WebSocket};
 pub use tungstenite::{Error as WebSocketError, Message as WebSocketMessage};
 use url::Url;
 
-use crate::http::{Method, RequestBuilder, Response};
+use crate::{
+    config::{Http as HttpConfig, Proxy, Tls},
+    http::{Method, RequestBuilder, Response},
+    observer::{RequestObserver, RequestRecord},
+};
 
 type Bytes = Vec<u8>;
-type AttoHttpRequestBuilderWithBytes = AttoHttpRequestBuilder<atto_body::Bytes<Bytes>>;
+
+/// Encodings of the responses the client can decompress, most preferred first
+const ACCEPTED_ENCODINGS: &str = "zstd, gzip";
 
 fn header_name_from_str(str: &str) -> Result<HeaderName> {
     str.parse::<HeaderName>()
         .wrap_err_with(|| format!("Failed to parse header name {str}"))
 }
 
-/// Default request builder implemented on top of `attohttpc` crate.
-#[derive(Debug)]
-pub struct DefaultRequestBuilder {
-    inner: Result<AttoHttpRequestBuilder>,
-    body: Option<Vec<u8>>,
+/// Whether the request failed with `error` before any response was received
+pub(crate) fn is_transport_error(error: &Error) -> bool {
+    error.chain().any(|source| {
+        source.is::<ureq::Error>()
+            || source.is::<std::io::Error>()
+            || source
+                .downcast_ref::<reqwest::Error>()
+                .is_some_and(|error| error.is_connect() || error.is_timeout() || error.is_request())
+    })
+}
+
+/// Decompress the body of `response` according to its `Content-Encoding`
+fn decompress(response: Response<Bytes>) -> Result<Response<Bytes>> {
+    let (mut parts, body) = response.into_parts();
+    let Some(encoding) = parts.headers.remove(CONTENT_ENCODING) else {
+        return Ok(Response::from_parts(parts, body));
+    };
+    let body = match encoding.as_bytes() {
+        b"identity" => body,
+        b"gzip" => {
+            let mut decompressed = Vec::new();
+            flate2::read::GzDecoder::new(body.as_slice())
+                .read_to_end(&mut decompressed)
+                .wrap_err("Failed to decompress gzip response")?;
+            decompressed
+        }
+        b"zstd" => zstd::stream::decode_all(body.as_slice())
+            .wrap_err("Failed to decompress zstd response")?,
+        _ => return Err(eyre!("Unsupported response encoding {encoding:?}")),
+    };
+    parts.headers.remove(CONTENT_LENGTH);
+    Ok(Response::from_parts(parts, body))
+}
+
+/// Report the request started at `started_at` to the `observer`, if any
+fn observe(
+    observer: Option<&dyn RequestObserver>,
+    method: &Method,
+    url: &Url,
+    started_at: Instant,
+    bytes_sent: usize,
+    response: Option<&Response<Bytes>>,
+) {
+    if let Some(observer) = observer {
+        observer.on_request(&RequestRecord {
+            method: method.clone(),
+            endpoint: url.path().to_owned(),
+            duration: started_at.elapsed(),
+            status: response.map(Response::status),
+            bytes_sent,
+            bytes_received: response.map_or(0, |response| response.body().len()),
+        });
+    }
 }
 
+/// Pool of HTTP connections to the peer.
+///
+/// Idle connections are kept alive and reused by the subsequent requests,
+/// which saves a TCP (and TLS) handshake per request for high-frequency submitters.
+/// Cloning is cheap: the clones share the connections.
+#[derive(Debug, Clone)]
+pub struct HttpPool {
+    agent: ureq::Agent,
+    config: HttpConfig,
+    #[cfg(feature = "__rustls")]
+    tls: Option<Arc<rustls::ClientConfig>>,
+    proxy: Option<Proxy>,
+    observer: Option<Arc<dyn RequestObserver>>,
+}
+
+impl HttpPool {
+    /// Pool configured with `config`
+    pub fn new(config: HttpConfig) -> Self {
+        Self::with_tls(config, &Tls::default()).expect("default TLS settings are always supported")
+    }
+
+    /// Pool configured with `config`, connecting to the peer with the `tls` settings
+    ///
+    /// # Errors
+    /// Fails if the certificates can't be loaded or the TLS backend doesn't support the settings
+    pub fn with_tls(config: HttpConfig, tls: &Tls) -> Result<Self> {
+        Self::with_proxy(config, tls, None)
+    }
+
+    /// Pool configured with `config`, connecting to the peer with the `tls` settings through the `proxy`, if any
+    ///
+    /// # Errors
+    /// Fails if the certificates can't be loaded, the TLS backend doesn't support the settings
+    /// or the proxy URL is invalid
+    pub fn with_proxy(config: HttpConfig, tls: &Tls, proxy: Option<Proxy>) -> Result<Self> {
+        let builder = ureq::AgentBuilder::new()
+            .max_idle_connections(config.max_idle_connections())
+            .max_idle_connections_per_host(config.max_idle_connections())
+            .timeout(config.request_timeout);
+        let builder = match &proxy {
+            Some(proxy) => builder.proxy(
+                ureq::Proxy::new(proxy.url_with_credentials())
+                    .wrap_err_with(|| format!("Invalid proxy URL {}", proxy.url))?,
+            ),
+            None => builder,
+        };
+        // Unlike `rustls`, `ureq` has no default connector for `native-tls`
+        #[cfg(feature = "__native_tls")]
+        let builder = builder.tls_connector(Arc::new(
+            native_tls::TlsConnector::new().wrap_err("Failed to create native TLS connector")?,
+        ));
+
+        #[cfg(not(feature = "__rustls"))]
+        {
+            tls.client_config()?;
+            Ok(Self {
+                agent: builder.build(),
+                config,
+                proxy,
+                observer: None,
+            })
+        }
+        #[cfg(feature = "__rustls")]
+        {
+            let tls = tls.client_config()?;
+            let builder = match &tls {
+                Some(tls) => builder.tls_config(Arc::clone(tls)),
+                None => builder,
+            };
+            Ok(Self {
+                agent: builder.build(),
+                config,
+                tls,
+                proxy,
+                observer: None,
+            })
+        }
+    }
+
+    /// Configuration of the pool
+    pub fn config(&self) -> &HttpConfig {
+        &self.config
+    }
+
+    /// Proxy the connections are made through, if any
+    pub fn proxy(&self) -> Option<&Proxy> {
+        self.proxy.as_ref()
+    }
+
+    /// Report every request sent through the pool, or the asynchronous clients created from it, to the `observer`
+    #[must_use]
+    pub fn with_observer(mut self, observer: Arc<dyn RequestObserver>) -> Self {
+        self.observer = Some(observer);
+        self
+    }
+
+    /// Observer of the requests sent through the pool, if any
+    pub fn observer(&self) -> Option<&Arc<dyn RequestObserver>> {
+        self.observer.as_ref()
+    }
+
+    /// `reqwest` client configured the same way, for the asynchronous requests
+    pub(crate) fn async_client(&self) -> reqwest::Client {
+        let builder = reqwest::Client::builder()
+            .pool_max_idle_per_host(self.config.max_idle_connections())
+            .timeout(self.config.request_timeout);
+        let builder = if self.config.compression {
+            builder.default_headers(HeaderMap::from_iter([(
+                ACCEPT_ENCODING,
+                HeaderValue::from_static(ACCEPTED_ENCODINGS),
+            )]))
+        } else {
+            builder
+        };
+        #[cfg(feature = "__rustls")]
+        let builder = match &self.tls {
+            Some(tls) => builder.use_preconfigured_tls(rustls::ClientConfig::clone(tls)),
+            None => builder,
+        };
+        let builder = match &self.proxy {
+            Some(proxy) => {
+                let mut async_proxy = reqwest::Proxy::all(proxy.url.clone())
+                    .expect("proxy URL is checked when the pool is created");
+                if let Some(credentials) = &proxy.credentials {
+                    async_proxy = async_proxy.basic_auth(
+                        &credentials.web_login.to_string(),
+                        credentials.password.expose_secret(),
+                    );
+                }
+                builder.proxy(async_proxy)
+            }
+            None => builder,
+        };
+
+        builder.build().expect("TLS backend should be initialized")
+    }
+}
+
+impl Default for HttpPool {
+    fn default() -> Self {
+        Self::new(HttpConfig::default())
+    }
+}
+
+/// Default request builder, sent through the [`HttpPool`] of the client.
+#[derive(Debug)]
+pub struct DefaultRequestBuilder(Result<DefaultRequest>);
+
 impl DefaultRequestBuilder {
-    /// Apply `.and_then()` semantics to the inner `Result` with underlying request builder.
-    fn and_then<F>(self, fun: F) -> Self
+    /// Apply `.and_then()` semantics to the inner `Result` with underlying request.
+    fn and_then<F>(self, func: F) -> Self
     where
-        F: FnOnce(AttoHttpRequestBuilder) -> Result<AttoHttpRequestBuilder>,
+        F: FnOnce(DefaultRequest) -> Result<DefaultRequest>,
     {
-        Self {
-            inner: self.inner.and_then(fun),
-            ..self
-        }
+        Self(self.0.and_then(func))
     }
 
     /// Build request by consuming self.
     pub fn build(self) -> Result<DefaultRequest> {
-        self.inner
-            .map(|b| DefaultRequest(b.bytes(self.body.map_or_else(Vec::new, |vec| vec))))
+        self.0
     }
 }
 
 /// Request built by [`DefaultRequestBuilder`].
 #[derive(Debug)]
-pub struct DefaultRequest(AttoHttpRequestBuilderWithBytes);
+pub struct DefaultRequest {
+    method: Method,
+    url: Url,
+    headers: Vec<(HeaderName, String)>,
+    body: Bytes,
+}
 
 impl DefaultRequest {
-    /// Sends itself and returns byte response
+    /// Sends itself through the connections of `pool` and returns byte response
     ///
     /// # Errors
-    /// Fails if request building and sending fails or response transformation fails
-    pub fn send(mut self) -> Result<Response<Bytes>> {
-        let (method, url) = {
-            let inspect = self.0.inspect();
-            (inspect.method().clone(), inspect.url().clone())
-        };
+    /// Fails if request sending fails or response transformation fails
+    pub fn send(self, pool: &HttpPool) -> Result<Response<Bytes>> {
+        let Self {
+            method,
+            url,
+            headers,
+            body,
+        } = self;
 
-        let response = self
-            .0
-            .send()
-            .wrap_err_with(|| format!("Failed to send http {method} request to {url}"))?;
+        let mut request = headers.iter().fold(
+            pool.agent.request_url(method.as_str(), &url),
+            |request, (name, value)| request.set(name.as_str(), value),
+        );
+        if pool.config.compression && !headers.iter().any(|(name, _)| *name == ACCEPT_ENCODING) {
+            request = request.set(ACCEPT_ENCODING.as_str(), ACCEPTED_ENCODINGS);
+        }
+        let started_at = Instant::now();
+        let response = match request.send_bytes(&body) {
+            // Error statuses are handled by the callers
+            Ok(response) | Err(ureq::Error::Status(_, response)) => {
+                Response::try_from(ClientResponse(response))
+            }
+            Err(error) => Err(error)
+                .wrap_err_with(|| format!("Failed to send http {method} request to {url}")),
+        };
+        observe(
+            pool.observer.as_deref(),
+            &method,
+            &url,
+            started_at,
+            body.len(),
+            response.as_ref().ok(),
+        );
 
-        ClientResponse(response).try_into()
+        response.and_then(decompress)
     }
 }
 
 impl RequestBuilder for DefaultRequestBuilder {
     fn new(method: Method, url: Url) -> Self {
-        Self {
-            inner: Ok(AttoHttpRequestBuilder::new(method, url)),
-            body: None,
-        }
+        Self(Ok(DefaultRequest {
+            method,
+            url,
+            headers: Vec::new(),
+            body: Vec::new(),
+        }))
     }
 
     fn header<K: AsRef<str>, V: ToString + ?Sized>(self, key: K, value: &V) -> Self {
-        self.and_then(|builder| {
-            Ok(builder.header(header_name_from_str(key.as_ref())?, value.to_string()))
+        self.and_then(|mut request| {
+            let name = header_name_from_str(key.as_ref())?;
+            request.headers.retain(|(other, _)| *other != name);
+            request.headers.push((name, value.to_string()));
+            Ok(request)
         })
     }
 
     fn param<K: AsRef<str>, V: ToString + ?Sized>(self, key: K, value: &V) -> Self {
-        self.and_then(|b| Ok(b.param(key, value.to_string())))
+        self.and_then(|mut request| {
+            request
+                .url
+                .query_pairs_mut()
+                .append_pair(key.as_ref(), &value.to_string());
+            Ok(request)
+        })
     }
 
     fn body(self, data: Vec<u8>) -> Self {
-        Self {
-            body: Some(data),
-            ..self
+        self.and_then(|mut request| {
+            request.body = data;
+            Ok(request)
+        })
+    }
+}
+
+/// Asynchronous request builder implemented on top of `reqwest` crate.
+#[derive(Debug)]
+pub struct AsyncRequestBuilder(Result<reqwest::Request>);
+
+impl AsyncRequestBuilder {
+    /// Same as [`DefaultRequestBuilder::and_then`].
+    fn and_then<F>(self, func: F) -> Self
+    where
+        F: FnOnce(reqwest::Request) -> Result<reqwest::Request>,
+    {
+        Self(self.0.and_then(func))
+    }
+
+    /// Sends itself with the given `client`, reusing its connections, and returns byte response.
+    /// The request is reported to the `observer`, if any.
+    ///
+    /// # Errors
+    /// Fails if request building and sending fails or response transformation fails
+    pub async fn send(
+        self,
+        client: &reqwest::Client,
+        observer: Option<&dyn RequestObserver>,
+    ) -> Result<Response<Bytes>> {
+        let request = self.0?;
+        let (method, url) = (request.method().clone(), request.url().clone());
+        let bytes_sent = request
+            .body()
+            .and_then(reqwest::Body::as_bytes)
+            .map_or(0, <[u8]>::len);
+
+        let started_at = Instant::now();
+        let response = Self::execute(client, request).await;
+        observe(
+            observer,
+            &method,
+            &url,
+            started_at,
+            bytes_sent,
+            response.as_ref().ok(),
+        );
+
+        response.and_then(decompress)
+    }
+
+    async fn execute(
+        client: &reqwest::Client,
+        request: reqwest::Request,
+    ) -> Result<Response<Bytes>> {
+        let (method, url) = (request.method().clone(), request.url().clone());
+        let response = client
+            .execute(request)
+            .await
+            .wrap_err_with(|| format!("Failed to send http {method} request to {url}"))?;
+
+        let mut builder = Response::builder().status(response.status());
+        let headers = builder
+            .headers_mut()
+            .ok_or_else(|| eyre!("Failed to get headers map reference."))?;
+        for (key, value) in response.headers() {
+            headers.insert(key, value.clone());
         }
+        let bytes = response
+            .bytes()
+            .await
+            .wrap_err("Failed to get response as bytes")?;
+        builder
+            .body(bytes.to_vec())
+            .wrap_err("Failed to construct response bytes body")
+    }
+}
+
+impl RequestBuilder for AsyncRequestBuilder {
+    fn new(method: Method, url: Url) -> Self {
+        Self(Ok(reqwest::Request::new(method, url)))
+    }
+
+    fn param<K: AsRef<str>, V: ToString + ?Sized>(self, key: K, value: &V) -> Self {
+        self.and_then(|mut request| {
+            request
+                .url_mut()
+                .query_pairs_mut()
+                .append_pair(key.as_ref(), &value.to_string());
+            Ok(request)
+        })
+    }
+
+    fn header<N: AsRef<str>, V: ToString + ?Sized>(self, name: N, value: &V) -> Self {
+        self.and_then(|mut request| {
+            let value = HeaderValue::try_from(value.to_string())
+                .wrap_err_with(|| format!("Failed to parse value of header {}", name.as_ref()))?;
+            request
+                .headers_mut()
+                .insert(header_name_from_str(name.as_ref())?, value);
+            Ok(request)
+        })
+    }
+
+    fn body(self, data: Vec<u8>) -> Self {
+        self.and_then(|mut request| {
+            *request.body_mut() = Some(data.into());
+            Ok(request)
+        })
     }
 }
 
@@ -140,6 +485,100 @@ impl DefaultWebSocketStreamRequest {
         let (stream, _) = tokio_tungstenite::connect_async(self.0).await?;
         Ok(stream)
     }
+
+    /// Open [`WebSocketStream`] synchronously through a tunnel of the `proxy`.
+    pub fn connect_through(self, proxy: &Proxy) -> Result<WebSocketStream> {
+        let (host, port) = proxy_address(proxy)?;
+        let mut stream = TcpStream::connect((host, port))
+            .wrap_err_with(|| format!("Failed to connect to proxy {}", proxy.url))?;
+        stream.write_all(tunnel_request(proxy, self.0.uri())?.as_bytes())?;
+        let mut response = Vec::new();
+        let mut byte = [0];
+        while !response.ends_with(b"\r\n\r\n") {
+            ensure_tunnel_response_fits(&response)?;
+            stream.read_exact(&mut byte)?;
+            response.push(byte[0]);
+        }
+        check_tunnel_response(&response)?;
+
+        let (stream, _) = tungstenite::client_tls(self.0, stream)
+            .map_err(|err| eyre!("WebSocket handshake through proxy failed: {err}"))?;
+        Ok(stream)
+    }
+
+    /// Open [`AsyncWebSocketStream`] through a tunnel of the `proxy`.
+    pub async fn connect_through_async(self, proxy: &Proxy) -> Result<AsyncWebSocketStream> {
+        use tokio::io::{AsyncReadExt as _, AsyncWriteExt as _};
+
+        let (host, port) = proxy_address(proxy)?;
+        let mut stream = tokio::net::TcpStream::connect((host, port))
+            .await
+            .wrap_err_with(|| format!("Failed to connect to proxy {}", proxy.url))?;
+        stream
+            .write_all(tunnel_request(proxy, self.0.uri())?.as_bytes())
+            .await?;
+        let mut response = Vec::new();
+        while !response.ends_with(b"\r\n\r\n") {
+            ensure_tunnel_response_fits(&response)?;
+            response.push(stream.read_u8().await?);
+        }
+        check_tunnel_response(&response)?;
+
+        let (stream, _) = tokio_tungstenite::client_async_tls(self.0, stream).await?;
+        Ok(stream)
+    }
+}
+
+/// Longest response to `CONNECT` accepted from the proxy
+const MAX_TUNNEL_RESPONSE_LEN: usize = 8 * 1024;
+
+fn proxy_address(proxy: &Proxy) -> Result<(&str, u16)> {
+    let host = proxy
+        .url
+        .host_str()
+        .ok_or_else(|| eyre!("Proxy URL {} has no host", proxy.url))?;
+    let port = proxy
+        .url
+        .port_or_known_default()
+        .ok_or_else(|| eyre!("Proxy URL {} has no port", proxy.url))?;
+    Ok((host, port))
+}
+
+/// `CONNECT` request asking the `proxy` to open a tunnel to the host of `uri`
+fn tunnel_request(proxy: &Proxy, uri: &http::Uri) -> Result<String> {
+    let host = uri.host().ok_or_else(|| eyre!("Missing host in {uri}"))?;
+    let port = uri
+        .port_u16()
+        .unwrap_or(if uri.scheme_str() == Some("wss") {
+            443
+        } else {
+            80
+        });
+    let mut request = format!("CONNECT {host}:{port} HTTP/1.1\r\nHost: {host}:{port}\r\n");
+    if let Some(authorization) = proxy.authorization() {
+        request.push_str(&format!("Proxy-Authorization: {authorization}\r\n"));
+    }
+    request.push_str("\r\n");
+    Ok(request)
+}
+
+fn ensure_tunnel_response_fits(response: &[u8]) -> Result<()> {
+    if response.len() >= MAX_TUNNEL_RESPONSE_LEN {
+        return Err(eyre!(
+            "Response of the proxy to `CONNECT` exceeds {MAX_TUNNEL_RESPONSE_LEN} bytes"
+        ));
+    }
+    Ok(())
+}
+
+/// Check that the proxy opened the tunnel, judging by the status line of its `response` to `CONNECT`
+fn check_tunnel_response(response: &[u8]) -> Result<()> {
+    let response = String::from_utf8_lossy(response);
+    let status_line = response.lines().next().unwrap_or_default();
+    match status_line.split_whitespace().nth(1) {
+        Some(status) if status.starts_with('2') => Ok(()),
+        _ => Err(eyre!("Proxy refused to open a tunnel: {status_line}")),
+    }
 }
 
 impl RequestBuilder for DefaultWebSocketRequestBuilder {
@@ -172,7 +611,7 @@ pub type WebSocketStream = WebSocket<MaybeTlsStream<TcpStream>>;
 pub type AsyncWebSocketStream =
     tokio_tungstenite::WebSocketStream<tokio_tungstenite::MaybeTlsStream<tokio::net::TcpStream>>;
 
-struct ClientResponse(AttoHttpResponse);
+struct ClientResponse(ureq::Response);
 
 impl TryFrom<ClientResponse> for Response<Bytes> {
     type Error = Error;
@@ -183,16 +622,91 @@ impl TryFrom<ClientResponse> for Response<Bytes> {
         let headers = builder
             .headers_mut()
             .ok_or_else(|| eyre!("Failed to get headers map reference."))?;
-        for (key, value) in response.headers() {
-            headers.insert(key, value.clone());
+        for name in response.headers_names() {
+            let key = header_name_from_str(&name)?;
+            for value in response.all(&name) {
+                let value = HeaderValue::from_str(value)
+                    .wrap_err_with(|| format!("Failed to parse value of header {name}"))?;
+                headers.append(key.clone(), value);
+            }
         }
+        let mut bytes = Vec::new();
         response
-            .bytes()
-            .wrap_err("Failed to get response as bytes")
-            .and_then(|bytes| {
-                builder
-                    .body(bytes)
-                    .wrap_err("Failed to construct response bytes body")
-            })
+            .into_reader()
+            .read_to_end(&mut bytes)
+            .wrap_err("Failed to get response as bytes")?;
+        builder
+            .body(bytes)
+            .wrap_err("Failed to construct response bytes body")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+
+    use super::*;
+
+    #[derive(Debug, Default)]
+    struct Recorder(Mutex<Vec<RequestRecord>>);
+
+    impl RequestObserver for Recorder {
+        fn on_request(&self, request: &RequestRecord) {
+            self.0.lock().unwrap().push(request.clone());
+        }
+    }
+
+    #[test]
+    fn failed_requests_are_observed() {
+        let recorder = Arc::new(Recorder::default());
+        let pool = HttpPool::default().with_observer(recorder.clone());
+
+        let _err = DefaultRequestBuilder::new(
+            Method::POST,
+            "http://127.0.0.1:0/transaction?x=1".parse().unwrap(),
+        )
+        .body(vec![0; 42])
+        .build()
+        .unwrap()
+        .send(&pool)
+        .expect_err("nothing listens on port 0");
+
+        let records = recorder.0.lock().unwrap();
+        let [record] = records.as_slice() else {
+            panic!("expected a single record, got {records:?}");
+        };
+        assert_eq!(record.method, Method::POST);
+        assert_eq!(record.endpoint, "/transaction");
+        assert_eq!(record.status, None);
+        assert_eq!(record.bytes_sent, 42);
+        assert_eq!(record.bytes_received, 0);
+    }
+
+    fn response(encoding: &str, body: Bytes) -> Response<Bytes> {
+        Response::builder()
+            .header(CONTENT_ENCODING, encoding)
+            .header(CONTENT_LENGTH, body.len())
+            .body(body)
+            .unwrap()
+    }
+
+    #[test]
+    fn compressed_responses_are_decompressed() {
+        let listing = "wonderland#alice, ".repeat(1_000).into_bytes();
+
+        let mut gzip = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::default());
+        gzip.write_all(&listing).unwrap();
+        let gzip = gzip.finish().unwrap();
+        let zstd = zstd::stream::encode_all(listing.as_slice(), 0).unwrap();
+
+        for (encoding, compressed) in [("gzip", gzip), ("zstd", zstd)] {
+            assert!(compressed.len() * 10 < listing.len());
+            let decompressed = decompress(response(encoding, compressed)).unwrap();
+            assert!(!decompressed.headers().contains_key(CONTENT_ENCODING));
+            assert!(!decompressed.headers().contains_key(CONTENT_LENGTH));
+            assert_eq!(*decompressed.body(), listing);
+        }
+
+        let _err = decompress(response("br", listing)).expect_err("brotli isn't accepted");
     }
 }
@@ -1,11 +1,21 @@
 //! Crate contains client which talks to Iroha network via http
 
+pub mod cache;
 pub mod client;
 pub mod config;
+pub mod encryption;
+pub mod events;
 pub mod http;
 mod http_default;
+pub mod mock;
+pub mod nonce;
+pub mod observer;
 pub mod query;
+pub mod retry;
 pub mod secrecy;
+pub mod signer;
+#[cfg(feature = "sql")]
+pub mod sql;
 
 pub use iroha_crypto as crypto;
 pub use iroha_data_model as data_model;
@@ -0,0 +1,606 @@
+//! In-process mock of an Iroha peer for unit testing applications built on top of [`Client`].
+//!
+//! [`MockPeer`] serves the Torii HTTP and `WebSocket` contract on a local port,
+//! replies with scripted responses and records every request it receives.
+//! Unlike a peer started by `iroha_test_network`, it starts instantly and runs no consensus.
+
+use std::{
+    collections::{HashMap, VecDeque},
+    io::{BufRead, BufReader, Read, Write},
+    net::{SocketAddr, TcpListener, TcpStream},
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc, Mutex, MutexGuard,
+    },
+    thread,
+};
+
+use eyre::{eyre, Result, WrapErr};
+use parity_scale_codec::Encode;
+use serde::Serialize;
+use tungstenite::{
+    handshake::server::{
+        ErrorResponse, Request as HandshakeRequest, Response as HandshakeResponse,
+    },
+    Message as WebSocketMessage,
+};
+use url::Url;
+
+use crate::{
+    client::Client,
+    config::{
+        Config, Http, Tls, DEFAULT_TRANSACTION_NONCE, DEFAULT_TRANSACTION_STATUS_TIMEOUT,
+        DEFAULT_TRANSACTION_TIME_TO_LIVE,
+    },
+    crypto::KeyPair,
+    data_model::{account::AccountId, ChainId},
+    http::{Method, StatusCode},
+};
+
+/// Response scripted for an endpoint of [`MockPeer`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MockResponse {
+    /// Status code of the response
+    pub status: StatusCode,
+    /// Additional headers of the response
+    pub headers: Vec<(String, String)>,
+    /// Raw body of the response
+    pub body: Vec<u8>,
+}
+
+impl MockResponse {
+    /// Response with the given status and raw body
+    pub fn new(status: StatusCode, body: Vec<u8>) -> Self {
+        Self {
+            status,
+            headers: Vec::new(),
+            body,
+        }
+    }
+
+    /// `200 OK` with an empty body, as returned by Torii for an accepted transaction
+    pub fn ok() -> Self {
+        Self::new(StatusCode::OK, Vec::new())
+    }
+
+    /// `200 OK` with a SCALE-encoded body, as returned by Torii for queries
+    pub fn scale(value: &impl Encode) -> Self {
+        Self::new(StatusCode::OK, value.encode())
+    }
+
+    /// `200 OK` with a JSON body, as returned by Torii for status and configuration
+    ///
+    /// # Errors
+    /// Fails if `value` can't be serialized
+    pub fn json(value: &impl Serialize) -> Result<Self> {
+        let body = serde_json::to_vec(value).wrap_err("Failed to serialize mock response")?;
+        Ok(Self::new(StatusCode::OK, body).with_header("Content-Type", "application/json"))
+    }
+
+    /// Replace the status code of the response
+    #[must_use]
+    pub fn with_status(mut self, status: StatusCode) -> Self {
+        self.status = status;
+        self
+    }
+
+    /// Append a header to the response
+    #[must_use]
+    pub fn with_header(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.headers.push((name.into(), value.into()));
+        self
+    }
+}
+
+/// Request received by [`MockPeer`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RecordedRequest {
+    /// HTTP method, `GET` for `WebSocket` subscriptions
+    pub method: Method,
+    /// Path of the request, e.g. [`iroha_torii_shared::uri::TRANSACTION`]
+    pub path: String,
+    /// Query string of the request, if any
+    pub query: Option<String>,
+    /// Headers of the request with lowercase names
+    pub headers: HashMap<String, String>,
+    /// Raw body of the request, the subscription message for `WebSocket` subscriptions
+    pub body: Vec<u8>,
+}
+
+#[derive(Default)]
+struct Script {
+    responses: HashMap<(Method, String), VecDeque<MockResponse>>,
+    streams: HashMap<String, Vec<Vec<u8>>>,
+    requests: Vec<RecordedRequest>,
+}
+
+impl Script {
+    fn respond(&mut self, request: RecordedRequest) -> MockResponse {
+        let key = (request.method.clone(), request.path.clone());
+        self.requests.push(request);
+
+        match self.responses.get_mut(&key) {
+            Some(responses) if responses.len() > 1 => {
+                responses.pop_front().expect("queue is not empty")
+            }
+            Some(responses) => responses
+                .front()
+                .cloned()
+                .expect("responses are never emptied"),
+            None => MockResponse::new(StatusCode::NOT_FOUND, Vec::new()),
+        }
+    }
+
+    fn subscribe(&mut self, request: RecordedRequest) -> Vec<Vec<u8>> {
+        let messages = self.streams.get(&request.path).cloned().unwrap_or_default();
+        self.requests.push(request);
+        messages
+    }
+}
+
+/// In-process peer which replies to [`Client`] with scripted responses
+///
+/// Every scripted response is served once in the order of scripting,
+/// except for the last one which keeps being served for all subsequent requests.
+/// Requests to unscripted endpoints are replied with `404 Not Found`.
+///
+/// The peer is stopped when dropped.
+pub struct MockPeer {
+    address: SocketAddr,
+    script: Arc<Mutex<Script>>,
+    shutdown: Arc<AtomicBool>,
+}
+
+impl MockPeer {
+    /// Start serving on a random local port
+    ///
+    /// # Errors
+    /// Fails if the port can't be bound
+    pub fn start() -> Result<Self> {
+        let listener =
+            TcpListener::bind("127.0.0.1:0").wrap_err("Failed to bind mock peer listener")?;
+        let address = listener.local_addr()?;
+        let script = Arc::new(Mutex::new(Script::default()));
+        let shutdown = Arc::new(AtomicBool::new(false));
+
+        {
+            let script = Arc::clone(&script);
+            let shutdown = Arc::clone(&shutdown);
+            thread::spawn(move || serve(&listener, &script, &shutdown));
+        }
+
+        Ok(Self {
+            address,
+            script,
+            shutdown,
+        })
+    }
+
+    /// URL of the Torii API served by the peer
+    pub fn torii_url(&self) -> Url {
+        format!("http://{}/", self.address)
+            .parse()
+            .expect("socket address forms a valid URL")
+    }
+
+    /// Client connected to the peer on behalf of `account`
+    pub fn client(&self, chain: ChainId, account: AccountId, key_pair: KeyPair) -> Client {
+        Client::new(Config {
+            chain,
+            account,
+            signer: Arc::new(key_pair),
+            basic_auth: None,
+            torii_api_url: self.torii_url(),
+            transaction_ttl: DEFAULT_TRANSACTION_TIME_TO_LIVE,
+            transaction_status_timeout: DEFAULT_TRANSACTION_STATUS_TIMEOUT,
+            transaction_add_nonce: DEFAULT_TRANSACTION_NONCE,
+            http: Http::default(),
+            tls: Tls::default(),
+            proxy: None,
+        })
+    }
+
+    /// Script a `response` to requests with `method` to `path`
+    pub fn respond(&self, method: Method, path: &str, response: MockResponse) {
+        self.script()
+            .responses
+            .entry((method, path.to_owned()))
+            .or_default()
+            .push_back(response);
+    }
+
+    /// Script binary `messages` sent to every `WebSocket` subscription to `path`
+    ///
+    /// The messages are sent after the subscription request of the client is received,
+    /// then the connection is closed.
+    pub fn stream(&self, path: &str, messages: impl IntoIterator<Item = impl Encode>) {
+        self.script()
+            .streams
+            .entry(path.to_owned())
+            .or_default()
+            .extend(messages.into_iter().map(|message| message.encode()));
+    }
+
+    /// Requests received by the peer so far, in the order of arrival
+    pub fn requests(&self) -> Vec<RecordedRequest> {
+        self.script().requests.clone()
+    }
+
+    fn script(&self) -> MutexGuard<'_, Script> {
+        self.script
+            .lock()
+            .expect("mock peer script is never poisoned")
+    }
+}
+
+impl Drop for MockPeer {
+    fn drop(&mut self) {
+        self.shutdown.store(true, Ordering::Relaxed);
+        // NOTE: wake up the listener blocked on `accept`
+        let _ = TcpStream::connect(self.address);
+    }
+}
+
+fn serve(listener: &TcpListener, script: &Arc<Mutex<Script>>, shutdown: &AtomicBool) {
+    for stream in listener.incoming() {
+        if shutdown.load(Ordering::Relaxed) {
+            break;
+        }
+        let Ok(stream) = stream else { continue };
+        let script = Arc::clone(script);
+        thread::spawn(move || {
+            if let Err(error) = handle_connection(stream, &script) {
+                iroha_logger::warn!(?error, "Mock peer failed to handle connection");
+            }
+        });
+    }
+}
+
+fn handle_connection(stream: TcpStream, script: &Mutex<Script>) -> Result<()> {
+    if is_websocket_upgrade(&stream)? {
+        return handle_subscription(stream, script);
+    }
+
+    let mut reader = BufReader::new(stream);
+    let mut request_line = String::new();
+    reader.read_line(&mut request_line)?;
+    let mut parts = request_line.split_whitespace();
+    let (Some(method), Some(target)) = (parts.next(), parts.next()) else {
+        return Err(eyre!("Malformed request line: {request_line:?}"));
+    };
+    let method: Method = method.parse()?;
+    let (path, query) = split_target(target);
+
+    let mut headers = HashMap::new();
+    loop {
+        let mut line = String::new();
+        reader.read_line(&mut line)?;
+        let Some((name, value)) = line.trim_end().split_once(':') else {
+            break;
+        };
+        headers.insert(name.trim().to_ascii_lowercase(), value.trim().to_owned());
+    }
+    let content_length = headers
+        .get("content-length")
+        .map(|length| length.parse::<usize>())
+        .transpose()?
+        .unwrap_or(0);
+    let mut body = vec![0; content_length];
+    reader.read_exact(&mut body)?;
+
+    let response = script
+        .lock()
+        .expect("mock peer script is never poisoned")
+        .respond(RecordedRequest {
+            method,
+            path,
+            query,
+            headers,
+            body,
+        });
+
+    let mut stream = reader.into_inner();
+    let mut head = format!(
+        "HTTP/1.1 {}\r\nContent-Length: {}\r\nConnection: close\r\n",
+        response.status,
+        response.body.len()
+    );
+    for (name, value) in &response.headers {
+        head.push_str(&format!("{name}: {value}\r\n"));
+    }
+    head.push_str("\r\n");
+    stream.write_all(head.as_bytes())?;
+    stream.write_all(&response.body)?;
+    stream.flush()?;
+
+    Ok(())
+}
+
+fn handle_subscription(stream: TcpStream, script: &Mutex<Script>) -> Result<()> {
+    let mut target = String::new();
+    let mut headers = HashMap::new();
+    let callback = |request: &HandshakeRequest,
+                    response: HandshakeResponse|
+     -> Result<HandshakeResponse, ErrorResponse> {
+        target = request.uri().to_string();
+        headers = request
+            .headers()
+            .iter()
+            .filter_map(|(name, value)| Some((name.to_string(), value.to_str().ok()?.to_owned())))
+            .collect();
+        Ok(response)
+    };
+    let mut socket = tungstenite::accept_hdr(stream, callback)
+        .map_err(|error| eyre!("WebSocket handshake failed: {error}"))?;
+
+    let body = loop {
+        match socket.read()? {
+            WebSocketMessage::Binary(message) => break message,
+            WebSocketMessage::Close(_) => return Ok(()),
+            _ => {}
+        }
+    };
+    let (path, query) = split_target(&target);
+    let messages = script
+        .lock()
+        .expect("mock peer script is never poisoned")
+        .subscribe(RecordedRequest {
+            method: Method::GET,
+            path,
+            query,
+            headers,
+            body,
+        });
+
+    for message in messages {
+        socket.send(WebSocketMessage::Binary(message))?;
+    }
+    socket.close(None)?;
+    // NOTE: drive close handshake to completion
+    while socket.read().is_ok() {}
+
+    Ok(())
+}
+
+/// Check the request head without consuming it, so that the `WebSocket` handshake can read it
+fn is_websocket_upgrade(stream: &TcpStream) -> Result<bool> {
+    let mut buf = [0; 4096];
+    loop {
+        let len = stream.peek(&mut buf)?;
+        let head = &buf[..len];
+        if len == 0 || len == buf.len() || head.windows(4).any(|window| window == b"\r\n\r\n") {
+            return Ok(String::from_utf8_lossy(head)
+                .to_ascii_lowercase()
+                .contains("upgrade: websocket"));
+        }
+        thread::yield_now();
+    }
+}
+
+fn split_target(target: &str) -> (String, Option<String>) {
+    match target.split_once('?') {
+        Some((path, query)) => (path.to_owned(), Some(query.to_owned())),
+        None => (target.to_owned(), None),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use iroha_test_samples::gen_account_in;
+    use iroha_torii_shared::{uri as torii_uri, QueueFull};
+    use iroha_version::prelude::*;
+    use parity_scale_codec::DecodeAll;
+
+    use super::*;
+    use crate::{client::QueueFullError, data_model::prelude::*, retry::RetryPolicy};
+
+    fn client(peer: &MockPeer) -> Client {
+        let (account, key_pair) = gen_account_in("wonderland");
+        peer.client(
+            ChainId::from("00000000-0000-0000-0000-000000000000"),
+            account,
+            key_pair,
+        )
+    }
+
+    #[test]
+    fn submitted_transaction_is_recorded() {
+        let peer = MockPeer::start().unwrap();
+        let client = client(&peer);
+        peer.respond(Method::POST, torii_uri::TRANSACTION, MockResponse::ok());
+
+        let hash = client.submit_all(Vec::<InstructionBox>::new()).unwrap();
+
+        let requests = peer.requests();
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0].method, Method::POST);
+        assert_eq!(requests[0].path, torii_uri::TRANSACTION);
+        let transaction = SignedTransaction::decode_all_versioned(&requests[0].body).unwrap();
+        assert_eq!(transaction.hash(), hash);
+    }
+
+    #[test]
+    fn submission_receipt_is_verified() {
+        use crate::data_model::transaction::receipt::{
+            TransactionReceipt, TransactionReceiptPayload,
+        };
+
+        let peer = MockPeer::start().unwrap();
+        let client = client(&peer);
+        let transaction = client
+            .build_transaction(Vec::<InstructionBox>::new(), Metadata::default())
+            .unwrap();
+        let peer_key_pair = KeyPair::random();
+        let receipt = TransactionReceipt::sign(
+            TransactionReceiptPayload {
+                tx_hash: transaction.hash(),
+                received_at_ms: 0,
+                queue_len: 1,
+                peer: PeerId::new(peer_key_pair.public_key().clone()),
+            },
+            &peer_key_pair,
+        );
+        let mut forged = receipt.clone();
+        forged.payload.queue_len = 0;
+        peer.respond(
+            Method::POST,
+            torii_uri::TRANSACTION,
+            MockResponse::json(&receipt).unwrap(),
+        );
+        peer.respond(
+            Method::POST,
+            torii_uri::TRANSACTION,
+            MockResponse::json(&forged).unwrap(),
+        );
+
+        assert_eq!(
+            client
+                .submit_transaction_with_receipt(&transaction)
+                .unwrap(),
+            receipt
+        );
+        assert!(client
+            .submit_transaction_with_receipt(&transaction)
+            .is_err());
+    }
+
+    #[test]
+    fn scripted_responses_are_served_in_order() {
+        let peer = MockPeer::start().unwrap();
+        let client = client(&peer);
+        let hint = QueueFull {
+            queue_depth: 1,
+            capacity: 1,
+            retry_after_ms: 10,
+        };
+        peer.respond(
+            Method::POST,
+            torii_uri::TRANSACTION,
+            MockResponse::json(&hint)
+                .unwrap()
+                .with_status(StatusCode::SERVICE_UNAVAILABLE),
+        );
+        peer.respond(Method::POST, torii_uri::TRANSACTION, MockResponse::ok());
+
+        let err = client.submit_all(Vec::<InstructionBox>::new()).unwrap_err();
+        assert!(err.downcast_ref::<QueueFullError>().is_some());
+        client.submit_all(Vec::<InstructionBox>::new()).unwrap();
+        client.submit_all(Vec::<InstructionBox>::new()).unwrap();
+        assert_eq!(peer.requests().len(), 3);
+    }
+
+    #[test]
+    fn transient_failures_are_retried() {
+        let peer = MockPeer::start().unwrap();
+        let mut client = client(&peer);
+        client.retry_policy = RetryPolicy {
+            initial_backoff: Duration::from_millis(1),
+            ..RetryPolicy::exponential(3)
+        };
+        let hint = QueueFull {
+            queue_depth: 1,
+            capacity: 1,
+            retry_after_ms: 10,
+        };
+        peer.respond(
+            Method::POST,
+            torii_uri::TRANSACTION,
+            MockResponse::json(&hint)
+                .unwrap()
+                .with_status(StatusCode::SERVICE_UNAVAILABLE),
+        );
+        peer.respond(
+            Method::POST,
+            torii_uri::TRANSACTION,
+            MockResponse::ok().with_status(StatusCode::TOO_MANY_REQUESTS),
+        );
+        // The transaction has been accepted by one of the previous attempts
+        peer.respond(
+            Method::POST,
+            torii_uri::TRANSACTION,
+            MockResponse::ok().with_status(StatusCode::CONFLICT),
+        );
+
+        let hash = client.submit_all(Vec::<InstructionBox>::new()).unwrap();
+
+        let requests = peer.requests();
+        assert_eq!(requests.len(), 3);
+        for request in requests {
+            let transaction = SignedTransaction::decode_all_versioned(&request.body).unwrap();
+            assert_eq!(transaction.hash(), hash);
+        }
+    }
+
+    #[test]
+    fn unscripted_endpoint_is_not_found() {
+        let peer = MockPeer::start().unwrap();
+        let client = client(&peer);
+
+        assert!(client.submit_all(Vec::<InstructionBox>::new()).is_err());
+    }
+
+    #[test]
+    fn scripted_events_are_streamed() {
+        let peer = MockPeer::start().unwrap();
+        let client = client(&peer);
+        let domain_id: DomainId = "wonderland".parse().unwrap();
+        let event: EventBox = DataEvent::Domain(DomainEvent::Deleted(domain_id)).into();
+        peer.stream(torii_uri::SUBSCRIPTION, [event.clone()]);
+
+        let events = client
+            .listen_for_events([DataEventFilter::Any])
+            .unwrap()
+            .collect::<Result<Vec<_>>>()
+            .unwrap();
+
+        assert_eq!(events, vec![event]);
+        assert_eq!(peer.requests()[0].path, torii_uri::SUBSCRIPTION);
+    }
+
+    #[test]
+    fn resilient_events_resume_after_last_seen() {
+        use crate::client::events_api::EventStreamItem;
+
+        let peer = MockPeer::start().unwrap();
+        let client = client(&peer);
+        let domain_id: DomainId = "wonderland".parse().unwrap();
+        let message = |index| {
+            SequencedEventMessage::Event(EventMessage::new(
+                Some(EventSequence::new(2, index)),
+                DataEvent::Domain(DomainEvent::Deleted(domain_id.clone())).into(),
+            ))
+        };
+        peer.stream(
+            torii_uri::SUBSCRIPTION_SEQUENCED,
+            [message(0), message(1), SequencedEventMessage::Lost],
+        );
+
+        let mut events = client
+            .listen_for_events_resilient([DataEventFilter::Any])
+            .unwrap();
+        let mut sequence = || match events.next().unwrap() {
+            Ok(EventStreamItem::Event(message)) => Ok(message.sequence),
+            Ok(EventStreamItem::Gap(_)) => panic!("the status isn't scripted"),
+            Err(error) => Err(error),
+        };
+        assert_eq!(sequence().unwrap(), Some(EventSequence::new(2, 0)));
+        assert_eq!(sequence().unwrap(), Some(EventSequence::new(2, 1)));
+        // NOTE: the replayed events are skipped, the loss can't be reported without the status
+        assert!(sequence().is_err());
+        assert!(sequence().is_err());
+
+        let resumed_after = peer
+            .requests()
+            .into_iter()
+            .filter(|request| request.path == torii_uri::SUBSCRIPTION_SEQUENCED)
+            .map(|request| {
+                SequencedEventSubscriptionRequest::decode_all(&mut request.body.as_slice())
+                    .unwrap()
+                    .after
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(resumed_after, [None, Some(EventSequence::new(2, 1))]);
+    }
+}
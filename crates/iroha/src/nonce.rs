@@ -0,0 +1,232 @@
+//! Allocation of nonces for the transactions built concurrently on behalf of the same account.
+//!
+//! Transactions built at the same millisecond from the same instructions share the hash, unless their nonces differ,
+//! so that all but one of them are rejected as duplicates. Picking the nonces at random makes the collisions unlikely,
+//! but doesn't rule them out and leaves the order of such transactions ambiguous.
+//! [`NonceManager`] hands out distinct nonces per account instead, along with the creation times
+//! which never go back, and tracks the transactions of the account which are submitted but neither committed nor expired yet.
+//! The creation times follow the clock regardless of the rate of the transactions,
+//! so that they never drift beyond the clock drift tolerated by the peers.
+
+use std::{
+    collections::HashMap,
+    num::NonZeroU32,
+    sync::{Mutex, MutexGuard},
+    time::{Duration, SystemTime},
+};
+
+use iroha_config::parameters::defaults::queue::TRANSACTION_TIME_TO_LIVE;
+use rand::Rng;
+
+use crate::{crypto::HashOf, data_model::prelude::*};
+
+/// Nonce and creation time reserved for a transaction by [`NonceManager::reserve`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Reservation {
+    /// Nonce distinct from the nonces of the other transactions of the account
+    pub nonce: NonZeroU32,
+    /// Creation time, since the Unix epoch, not earlier than the creation times of the previous transactions of the account
+    pub creation_time: Duration,
+}
+
+/// Hands out the nonces and the creation times to the transactions of the accounts and tracks the in-flight ones.
+///
+/// Safe to share between the threads submitting the transactions, e.g. behind an [`Arc`](std::sync::Arc).
+#[derive(Debug, Default)]
+pub struct NonceManager {
+    accounts: Mutex<HashMap<AccountId, AccountNonces>>,
+}
+
+#[derive(Debug)]
+struct AccountNonces {
+    next_nonce: NonZeroU32,
+    last_creation_time: Duration,
+    /// In-flight transactions along with the time they expire at
+    in_flight: HashMap<HashOf<SignedTransaction>, Duration>,
+}
+
+impl AccountNonces {
+    fn new() -> Self {
+        Self {
+            // Starting at random keeps the nonces of the independent clients of the account apart
+            next_nonce: rand::thread_rng().gen(),
+            last_creation_time: Duration::ZERO,
+            in_flight: HashMap::new(),
+        }
+    }
+
+    fn prune_expired(&mut self, now: Duration) {
+        self.in_flight.retain(|_, expires_at| now < *expires_at);
+    }
+}
+
+impl NonceManager {
+    /// Construct [`NonceManager`] without any reservations
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn accounts(&self) -> MutexGuard<'_, HashMap<AccountId, AccountNonces>> {
+        // The map stays consistent even if a holder of the lock panicked
+        self.accounts
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+    }
+
+    /// Reserve a nonce and a creation time for the next transaction of the `account`
+    pub fn reserve(&self, account: &AccountId) -> Reservation {
+        let mut accounts = self.accounts();
+        let nonces = accounts
+            .entry(account.clone())
+            .or_insert_with(AccountNonces::new);
+
+        let nonce = nonces.next_nonce;
+        nonces.next_nonce = nonce.checked_add(1).unwrap_or(NonZeroU32::MIN);
+        // Nonces tell the transactions apart, so the creation time only has to keep them in order
+        nonces.last_creation_time = now().max(nonces.last_creation_time);
+
+        Reservation {
+            nonce,
+            creation_time: nonces.last_creation_time,
+        }
+    }
+
+    /// Track the submitted `transaction` as in-flight until it is [completed](Self::complete) or expires.
+    ///
+    /// The transactions without the time to live expire once dropped from the queue of a peer by default.
+    /// The expired transactions of the account are dropped on each submission,
+    /// so that the transactions which are never completed don't pile up.
+    pub fn track(&self, transaction: &SignedTransaction) {
+        let expires_at = transaction.creation_time().saturating_add(
+            transaction
+                .time_to_live()
+                .unwrap_or(TRANSACTION_TIME_TO_LIVE),
+        );
+
+        let mut accounts = self.accounts();
+        let nonces = accounts
+            .entry(transaction.authority().clone())
+            .or_insert_with(AccountNonces::new);
+        nonces.prune_expired(now());
+        nonces.in_flight.insert(transaction.hash(), expires_at);
+    }
+
+    /// Stop tracking the transaction of the `account`, e.g. once it is committed or rejected
+    pub fn complete(&self, account: &AccountId, transaction: &HashOf<SignedTransaction>) {
+        if let Some(nonces) = self.accounts().get_mut(account) {
+            nonces.in_flight.remove(transaction);
+        }
+    }
+
+    /// Transactions of the `account` which are submitted but neither completed nor expired yet
+    pub fn in_flight(&self, account: &AccountId) -> Vec<HashOf<SignedTransaction>> {
+        let mut accounts = self.accounts();
+        let Some(nonces) = accounts.get_mut(account) else {
+            return Vec::new();
+        };
+        nonces.prune_expired(now());
+        nonces.in_flight.keys().copied().collect()
+    }
+}
+
+fn now() -> Duration {
+    SystemTime::now()
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{collections::HashSet, sync::Arc, thread};
+
+    use iroha_test_samples::{ALICE_ID, ALICE_KEYPAIR};
+
+    use super::*;
+
+    #[test]
+    fn concurrent_reservations_are_distinct() {
+        let manager = Arc::new(NonceManager::new());
+
+        let reservations = (0..4)
+            .map(|_| {
+                let manager = Arc::clone(&manager);
+                thread::spawn(move || {
+                    (0..100)
+                        .map(|_| manager.reserve(&ALICE_ID))
+                        .collect::<Vec<_>>()
+                })
+            })
+            .collect::<Vec<_>>()
+            .into_iter()
+            .map(|handle| handle.join().unwrap())
+            .collect::<Vec<_>>();
+
+        for reservations in &reservations {
+            assert!(reservations
+                .windows(2)
+                .all(|pair| pair[0].creation_time <= pair[1].creation_time));
+        }
+        let nonces = reservations
+            .iter()
+            .flatten()
+            .map(|reservation| reservation.nonce)
+            .collect::<HashSet<_>>();
+        assert_eq!(nonces.len(), 400);
+    }
+
+    #[test]
+    fn creation_time_does_not_drift_ahead_of_clock() {
+        let manager = NonceManager::new();
+
+        let last = (0..10_000)
+            .map(|_| manager.reserve(&ALICE_ID))
+            .last()
+            .unwrap();
+
+        assert!(last.creation_time <= now());
+    }
+
+    #[test]
+    fn completed_transactions_are_not_in_flight() {
+        let manager = NonceManager::new();
+        let reservation = manager.reserve(&ALICE_ID);
+        let mut builder = TransactionBuilder::new(ChainId::from("00000000"), ALICE_ID.clone());
+        builder
+            .set_nonce(reservation.nonce)
+            .set_creation_time(reservation.creation_time)
+            .set_ttl(Duration::from_secs(60));
+        let transaction = builder
+            .with_instructions([Log::new(Level::INFO, "in flight".to_owned())])
+            .sign(ALICE_KEYPAIR.private_key());
+
+        manager.track(&transaction);
+        assert_eq!(manager.in_flight(&ALICE_ID), [transaction.hash()]);
+
+        manager.complete(&ALICE_ID, &transaction.hash());
+        assert!(manager.in_flight(&ALICE_ID).is_empty());
+    }
+
+    #[test]
+    fn expired_transactions_are_dropped_on_submission() {
+        let manager = NonceManager::new();
+        let transaction = |creation_time: Duration, message: &str| {
+            let mut builder = TransactionBuilder::new(ChainId::from("00000000"), ALICE_ID.clone());
+            builder
+                .set_creation_time(creation_time)
+                .set_ttl(Duration::from_secs(60));
+            builder
+                .with_instructions([Log::new(Level::INFO, message.to_owned())])
+                .sign(ALICE_KEYPAIR.private_key())
+        };
+        let expired = transaction(now() - Duration::from_secs(120), "expired");
+        let submitted = transaction(now(), "submitted");
+
+        manager.track(&expired);
+        manager.track(&submitted);
+
+        let accounts = manager.accounts();
+        let in_flight = &accounts[&*ALICE_ID].in_flight;
+        assert_eq!(in_flight.len(), 1);
+        assert!(in_flight.contains_key(&submitted.hash()));
+    }
+}
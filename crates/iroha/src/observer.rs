@@ -0,0 +1,34 @@
+//! Instrumentation of the requests sent to the peer.
+//!
+//! A [`RequestObserver`] set on the [`HttpPool`](crate::client::HttpPool) of the client is notified
+//! of every completed request, so that the applications can export the latency, the errors and the traffic
+//! of the client to their metrics without wrapping each call.
+
+use std::{fmt::Debug, time::Duration};
+
+use crate::http::{Method, StatusCode};
+
+/// Request sent to the peer, as reported to the [`RequestObserver`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RequestRecord {
+    /// Method of the request
+    pub method: Method,
+    /// Path of the requested endpoint, without the query, e.g. `/transaction`
+    pub endpoint: String,
+    /// Time from sending the request to receiving the whole response
+    pub duration: Duration,
+    /// Status of the response, or `None` if no response was received, e.g. the peer is unreachable
+    pub status: Option<StatusCode>,
+    /// Size of the request body
+    pub bytes_sent: usize,
+    /// Size of the response body as received, i.e. before it's decompressed
+    pub bytes_received: usize,
+}
+
+/// Receives the [`RequestRecord`] of each request sent by the client
+pub trait RequestObserver: Debug + Send + Sync {
+    /// Called once the `request` is complete, successfully or not.
+    ///
+    /// Runs on the thread (or the task) sending the request, so it should return quickly.
+    fn on_request(&self, request: &RequestRecord);
+}
@@ -1,6 +1,6 @@
 //! Functions and types to make queries to the Iroha peer.
 
-use std::{collections::HashMap, fmt::Debug};
+use std::{collections::HashMap, fmt::Debug, sync::Arc, time::Duration};
 
 use eyre::{eyre, Context, Result};
 use http::StatusCode;
@@ -10,20 +10,23 @@ use parity_scale_codec::{DecodeAll, Encode};
 use url::Url;
 
 use crate::{
-    client::{join_torii_url, Client, QueryResult, ResponseReport},
-    crypto::KeyPair,
+    client::{join_torii_url, AsyncClient, Client, QueryResult, ResponseReport},
     data_model::{
         account::AccountId,
         query::{
-            builder::{QueryBuilder, QueryExecutor},
+            builder::{
+                HasTypedBatchIter, QueryBuilder, QueryExecutor, QueryIterator, SingleQueryError,
+            },
             parameters::ForwardCursor,
-            Query, QueryOutput, QueryRequest, QueryResponse, QueryWithParams, SingularQuery,
-            SingularQueryBox, SingularQueryOutputBox,
+            Query, QueryBox, QueryOutput, QueryRequest, QueryResponse, QueryWithFilter,
+            QueryWithParams, SignedQuery, SingularQuery, SingularQueryBox, SingularQueryOutputBox,
         },
         ValidationFail,
     },
     http::{Method as HttpMethod, RequestBuilder},
-    http_default::DefaultRequestBuilder,
+    http_default::{AsyncRequestBuilder, DefaultRequestBuilder, HttpPool},
+    retry::RetryPolicy,
+    signer::Signer,
 };
 
 #[derive(Debug)]
@@ -31,22 +34,39 @@ struct ClientQueryRequestHead {
     torii_url: Url,
     headers: HashMap<String, String>,
     account_id: AccountId,
-    key_pair: KeyPair,
+    signer: Arc<dyn Signer>,
+    http: HttpPool,
+    retry_policy: RetryPolicy,
 }
 
 impl ClientQueryRequestHead {
-    fn assemble(&self, query: QueryRequest) -> DefaultRequestBuilder {
-        // authorize and sign the query
-        let query = query
+    /// Authorize and sign the `query`
+    fn sign(&self, query: QueryRequest) -> Result<SignedQuery> {
+        query
             .with_authority(self.account_id.clone())
-            .sign(&self.key_pair);
+            .sign_with(|payload| self.signer.sign(payload))
+            .wrap_err("Failed to sign query")
+    }
+
+    fn assemble<B: RequestBuilder>(&self, query: QueryRequest) -> Result<B> {
+        let query = self.sign(query)?;
 
-        DefaultRequestBuilder::new(
+        Ok(B::new(
             HttpMethod::POST,
             join_torii_url(&self.torii_url, torii_uri::QUERY),
         )
         .headers(self.headers.clone())
-        .body(query.encode())
+        .body(query.encode()))
+    }
+
+    /// Sign and send the `query`, retrying it according to the retry policy of the client
+    fn send(&self, query: &QueryRequest) -> Result<http::Response<Vec<u8>>> {
+        let (response, _attempts) = self.retry_policy.retry(|| {
+            self.assemble::<DefaultRequestBuilder>(query.clone())?
+                .build()?
+                .send(&self.http)
+        });
+        response
     }
 }
 
@@ -66,7 +86,8 @@ fn decode_query_response(resp: &http::Response<Vec<u8>>) -> QueryResult<QueryRes
         | StatusCode::UNAUTHORIZED
         | StatusCode::FORBIDDEN
         | StatusCode::NOT_FOUND
-        | StatusCode::UNPROCESSABLE_ENTITY => Err(ValidationFail::decode_all(
+        | StatusCode::UNPROCESSABLE_ENTITY
+        | StatusCode::SERVICE_UNAVAILABLE => Err(ValidationFail::decode_all(
             &mut resp.body().as_ref(),
         )
             .map_or_else(
@@ -101,16 +122,124 @@ fn decode_singular_query_response(
     Ok(resp)
 }
 
-fn decode_iterable_query_response(resp: &http::Response<Vec<u8>>) -> QueryResult<QueryOutput> {
-    let QueryResponse::Iterable(resp) = decode_query_response(resp)? else {
-        return Err(eyre!(
+/// Returns the output along with whether its batch was shed by the peer under load
+fn decode_iterable_query_response(
+    resp: &http::Response<Vec<u8>>,
+) -> QueryResult<(QueryOutput, bool)> {
+    iterable_query_output(decode_query_response(resp)?)
+}
+
+fn iterable_query_output(response: QueryResponse) -> QueryResult<(QueryOutput, bool)> {
+    match response {
+        QueryResponse::Iterable(output) => Ok((output, false)),
+        QueryResponse::Shed(output) => Ok((output, true)),
+        QueryResponse::Singular(_) => Err(eyre!(
             "Got unexpected type of query response from the node (expected iterable)"
         )
-        .into());
+        .into()),
+    }
+}
+
+/// Decode a raw response from the node's query batch endpoint
+fn decode_query_batch_response(
+    resp: &http::Response<Vec<u8>>,
+) -> QueryResult<Vec<QueryResult<QueryOutput>>> {
+    if resp.status() != StatusCode::OK {
+        return Err(
+            ResponseReport::with_msg("Unexpected query batch response", resp)
+                .unwrap_or_else(core::convert::identity)
+                .into(),
+        );
+    }
+
+    let responses =
+        Vec::<Result<QueryResponse, ValidationFail>>::decode_all(&mut resp.body().as_slice())
+            .wrap_err(
+                "Failed to decode response from Iroha. \
+         You are likely using a version of the client library \
+         that is incompatible with the version of the peer software",
+            )?;
+
+    Ok(responses
+        .into_iter()
+        // NOTE: the first batches are never shed, the load is shed before the queries are started
+        .map(|response| iterable_query_output(response?).map(|(output, _shed)| output))
+        .collect())
+}
+
+/// Several iterable queries executed in a single request by [`Client::query_batch`].
+///
+/// Implemented for the tuples of up to 8 [`QueryBuilder`]s, e.g. `(client.query(FindAccounts::new()), client.query(FindAssets::new()))`.
+/// The results are returned in a tuple of the same arity, each typed according to its query.
+pub trait QueryBatch {
+    /// Results of the queries, in the order of the queries
+    type Output;
+
+    /// Assemble the queries of the batch
+    fn into_queries(self) -> Vec<QueryWithParams>;
+
+    /// Collect the results of the queries from their first `responses`, fetching the remaining batches through `client`
+    fn collect(client: &Client, responses: Vec<QueryResult<QueryOutput>>) -> Self::Output;
+}
+
+/// Collect all results of a batched query from its first `response`
+fn collect_batched<T: HasTypedBatchIter>(
+    client: &Client,
+    response: Option<QueryResult<QueryOutput>>,
+) -> QueryResult<Vec<T>> {
+    let response = response.ok_or_else(|| {
+        eyre!("Iroha returned fewer responses than there are queries in the batch")
+    })??;
+    let (batch, remaining_items, cursor) = response.into_parts();
+    let cursor = cursor.map(|cursor| QueryCursor {
+        request_head: client.get_query_request_head(),
+        cursor,
+        shed: false,
+    });
+
+    QueryIterator::<Client, T>::new(batch, remaining_items, cursor)
+        .map_err(|err| eyre!("Iroha returned unexpected type in iterable query: {err}"))?
+        .collect()
+}
+
+macro_rules! impl_query_batch {
+    ($($query:ident => $item:ident),+) => {
+        impl<'e, $($query, $item),+> QueryBatch for ($(QueryBuilder<'e, Client, $query, $item>,)+)
+        where
+            $(
+                $query: Query,
+                QueryBox: From<QueryWithFilter<$query>>,
+                $item: HasTypedBatchIter,
+            )+
+        {
+            type Output = ($(QueryResult<Vec<$item>>,)+);
+
+            #[allow(non_snake_case)]
+            fn into_queries(self) -> Vec<QueryWithParams> {
+                let ($($query,)+) = self;
+                vec![$($query.into_parts().1),+]
+            }
+
+            fn collect(client: &Client, responses: Vec<QueryResult<QueryOutput>>) -> Self::Output {
+                let mut responses = responses.into_iter();
+                ($(collect_batched::<$item>(client, responses.next()),)+)
+            }
+        }
     };
-    Ok(resp)
 }
 
+impl_query_batch!(Q1 => T1);
+impl_query_batch!(Q1 => T1, Q2 => T2);
+impl_query_batch!(Q1 => T1, Q2 => T2, Q3 => T3);
+impl_query_batch!(Q1 => T1, Q2 => T2, Q3 => T3, Q4 => T4);
+impl_query_batch!(Q1 => T1, Q2 => T2, Q3 => T3, Q4 => T4, Q5 => T5);
+impl_query_batch!(Q1 => T1, Q2 => T2, Q3 => T3, Q4 => T4, Q5 => T5, Q6 => T6);
+impl_query_batch!(Q1 => T1, Q2 => T2, Q3 => T3, Q4 => T4, Q5 => T5, Q6 => T6, Q7 => T7);
+impl_query_batch!(Q1 => T1, Q2 => T2, Q3 => T3, Q4 => T4, Q5 => T5, Q6 => T6, Q7 => T7, Q8 => T8);
+
+/// Pause before continuing a query whose last batch was shed by the peer under load
+const SHED_BACKOFF: Duration = Duration::from_millis(100);
+
 /// An iterable query cursor for use in the client
 #[derive(Debug)]
 pub struct QueryCursor {
@@ -119,6 +248,8 @@ pub struct QueryCursor {
     // this removes the need to either keep a reference or use an Arc, but breaks abstraction a little
     request_head: ClientQueryRequestHead,
     cursor: ForwardCursor,
+    // whether the batch the cursor came with was shed
+    shed: bool,
 }
 
 /// Different errors as a result of query response handling
@@ -149,7 +280,7 @@ impl QueryExecutor for Client {
 
         let request = QueryRequest::Singular(query);
 
-        let response = request_head.assemble(request).build()?.send()?;
+        let response = request_head.send(&request)?;
         let response = decode_singular_query_response(&response)?;
 
         Ok(response)
@@ -163,14 +294,15 @@ impl QueryExecutor for Client {
 
         let request = QueryRequest::Start(query);
 
-        let response = request_head.assemble(request).build()?.send()?;
-        let response = decode_iterable_query_response(&response)?;
+        let response = request_head.send(&request)?;
+        let (response, shed) = decode_iterable_query_response(&response)?;
 
         let (batch, remaining_items, cursor) = response.into_parts();
 
         let cursor = cursor.map(|cursor| QueryCursor {
             request_head,
             cursor,
+            shed,
         });
 
         Ok((batch, remaining_items, cursor))
@@ -182,18 +314,23 @@ impl QueryExecutor for Client {
         let QueryCursor {
             request_head,
             cursor,
+            shed,
         } = cursor;
 
+        if shed {
+            std::thread::sleep(SHED_BACKOFF);
+        }
         let request = QueryRequest::Continue(cursor);
 
-        let response = request_head.assemble(request).build()?.send()?;
-        let response = decode_iterable_query_response(&response)?;
+        let response = request_head.send(&request)?;
+        let (response, shed) = decode_iterable_query_response(&response)?;
 
         let (batch, remaining_items, cursor) = response.into_parts();
 
         let cursor = cursor.map(|cursor| QueryCursor {
             request_head,
             cursor,
+            shed,
         });
 
         Ok((batch, remaining_items, cursor))
@@ -209,7 +346,9 @@ impl Client {
             torii_url: self.torii_url.clone(),
             headers: self.headers.clone(),
             account_id: self.account.clone(),
-            key_pair: self.key_pair.clone(),
+            signer: Arc::clone(&self.signer),
+            http: self.http.clone(),
+            retry_policy: self.retry_policy,
         }
     }
 
@@ -242,6 +381,54 @@ impl Client {
         QueryBuilder::new(self, query)
     }
 
+    /// Execute several iterable queries in a single round trip, e.g. to refresh many views of a dashboard at once.
+    ///
+    /// The queries are executed independently against the same state of the peer,
+    /// so each of them succeeds or fails on its own. Results which don't fit into the first batch of their query
+    /// are fetched with additional requests.
+    ///
+    /// ```ignore
+    /// let (accounts, assets) = client.query_batch((
+    ///     client.query(FindAccounts::new()),
+    ///     client.query(FindAssets::new()).with_fetch_size(fetch_size),
+    /// ))?;
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the batch can't be signed, sent or decoded.
+    pub fn query_batch<B: QueryBatch>(&self, batch: B) -> Result<B::Output, QueryError> {
+        let request_head = self.get_query_request_head();
+        let queries = batch
+            .into_queries()
+            .into_iter()
+            .map(|query| request_head.sign(QueryRequest::Start(query)))
+            .collect::<Result<Vec<_>>>()?;
+        let query_count = queries.len();
+        let body = queries.encode();
+
+        let (response, _attempts) = request_head.retry_policy.retry(|| {
+            DefaultRequestBuilder::new(
+                HttpMethod::POST,
+                join_torii_url(&request_head.torii_url, torii_uri::QUERY_BATCH),
+            )
+            .headers(request_head.headers.clone())
+            .body(body.clone())
+            .build()?
+            .send(&request_head.http)
+        });
+        let responses = decode_query_batch_response(&response?)?;
+        if responses.len() != query_count {
+            return Err(eyre!(
+                "Iroha returned {} responses to the batch of {query_count} queries",
+                responses.len()
+            )
+            .into());
+        }
+
+        Ok(B::collect(self, responses))
+    }
+
     /// Make a request to continue an iterable query with the provided raw [`ForwardCursor`]
     ///
     /// You probably do not want to use this function, but rather use the [`Self::query`] method to make a query and iterate over its results.
@@ -257,13 +444,153 @@ impl Client {
 
         let request = QueryRequest::Continue(cursor);
 
-        let response = request_head.assemble(request).build()?.send()?;
+        let response = request_head.send(&request)?;
         let response = decode_query_response(&response)?;
 
         Ok(response)
     }
 }
 
+impl AsyncClient {
+    async fn send_query_request(&self, request: QueryRequest) -> QueryResult<QueryResponse> {
+        let request_head = self.blocking().get_query_request_head();
+        let (response, _attempts) = request_head
+            .retry_policy
+            .retry_async(|| async {
+                self.send(request_head.assemble::<AsyncRequestBuilder>(request.clone())?)
+                    .await
+            })
+            .await;
+        let response = response?;
+
+        decode_query_response(&response)
+    }
+
+    async fn start_query(&self, query: QueryWithParams) -> QueryResult<(QueryOutput, bool)> {
+        iterable_query_output(self.send_query_request(QueryRequest::Start(query)).await?)
+    }
+
+    async fn continue_query(&self, cursor: ForwardCursor) -> QueryResult<(QueryOutput, bool)> {
+        iterable_query_output(
+            self.send_query_request(QueryRequest::Continue(cursor))
+                .await?,
+        )
+    }
+
+    /// Execute a singular query and return the result
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query execution fails.
+    pub async fn query_single<Q>(&self, query: Q) -> Result<Q::Output, QueryError>
+    where
+        Q: SingularQuery,
+        SingularQueryBox: From<Q>,
+        Q::Output: TryFrom<SingularQueryOutputBox>,
+        <Q::Output as TryFrom<SingularQueryOutputBox>>::Error: Debug,
+    {
+        let request = QueryRequest::Singular(SingularQueryBox::from(query));
+        let QueryResponse::Singular(result) = self.send_query_request(request).await? else {
+            return Err(eyre!(
+                "Got unexpected type of query response from the node (expected singular)"
+            )
+            .into());
+        };
+
+        Ok(result
+            .try_into()
+            .expect("BUG: iroha returned unexpected type in singular query"))
+    }
+
+    /// Build an iterable query and return a builder object.
+    ///
+    /// Execute it with the methods of [`AsyncQueryBuilderExt`].
+    pub fn query<Q>(&self, query: Q) -> QueryBuilder<Self, Q, Q::Item>
+    where
+        Q: Query,
+    {
+        QueryBuilder::new(self, query)
+    }
+}
+
+/// An extension trait executing query builders of [`AsyncClient`] without blocking the current thread.
+#[allow(async_fn_in_trait)]
+pub trait AsyncQueryBuilderExt<T> {
+    /// Execute the query, returning all the results collected into a vector.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query execution fails.
+    async fn execute_all(self) -> Result<Vec<T>, QueryError>;
+
+    /// Execute the query, constraining the number of results to zero or one.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query execution fails or if more than one result is returned.
+    async fn execute_single_opt(self) -> Result<Option<T>, SingleQueryError<QueryError>>;
+
+    /// Execute the query, constraining the number of results to exactly one.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query execution fails or if zero or more than one result is returned.
+    async fn execute_single(self) -> Result<T, SingleQueryError<QueryError>>;
+}
+
+impl<Q, T> AsyncQueryBuilderExt<T> for QueryBuilder<'_, AsyncClient, Q, T>
+where
+    Q: Query,
+    QueryBox: From<QueryWithFilter<Q>>,
+    T: HasTypedBatchIter,
+{
+    async fn execute_all(self) -> Result<Vec<T>, QueryError> {
+        let (client, query) = self.into_parts();
+
+        let mut results = Vec::new();
+        let (mut response, mut shed) = client.start_query(query).await?;
+        loop {
+            let (batch, remaining_items, cursor) = response.into_parts();
+            let batch = T::downcast(batch)
+                .map_err(|err| eyre!("Iroha returned unexpected type in iterable query: {err}"))?;
+            results.reserve(
+                batch
+                    .len()
+                    .saturating_add(remaining_items.try_into().unwrap_or(0)),
+            );
+            results.extend(batch);
+
+            let Some(cursor) = cursor else {
+                return Ok(results);
+            };
+            if shed {
+                tokio::time::sleep(SHED_BACKOFF).await;
+            }
+            (response, shed) = client.continue_query(cursor).await?;
+        }
+    }
+
+    async fn execute_single_opt(self) -> Result<Option<T>, SingleQueryError<QueryError>> {
+        let mut results = self.execute_all().await?.into_iter();
+
+        match (results.next(), results.next()) {
+            (None, _) => Ok(None),
+            (Some(result), None) => Ok(Some(result)),
+            (Some(_), Some(_)) => Err(SingleQueryError::ExpectedOneOrZeroGotMany),
+        }
+    }
+
+    async fn execute_single(self) -> Result<T, SingleQueryError<QueryError>> {
+        let mut results = self.execute_all().await?.into_iter();
+
+        match (results.next(), results.next()) {
+            (None, _) => Err(SingleQueryError::ExpectedOneGotNone),
+            (Some(result), None) => Ok(result),
+            (Some(_), Some(_)) => Err(SingleQueryError::ExpectedOneGotMany),
+        }
+    }
+}
+
 #[cfg(test)]
 mod query_errors_handling {
     use http::Response;
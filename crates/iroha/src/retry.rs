@@ -0,0 +1,225 @@
+//! Retrying of the requests to the peer which failed transiently.
+//!
+//! Resubmitting a transaction is idempotent: the signed transaction keeps its hash,
+//! so the peer rejects the copies of a transaction it has already accepted as duplicates.
+//! Such a rejection of a retried submission means that one of the previous attempts has reached the peer,
+//! and is reported as a success.
+
+use std::{future::Future, thread, time::Duration};
+
+use eyre::Result;
+use iroha_torii_shared::QueueFull;
+
+use crate::{
+    http::{Response, StatusCode},
+    http_default,
+};
+
+/// Classes of the transient failures retried by [`RetryPolicy`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetryOn {
+    /// No response was received, e.g. the connection was refused, reset or timed out
+    pub connection: bool,
+    /// `429 Too Many Requests`, e.g. the capacity of the live query store is reached
+    pub too_many_requests: bool,
+    /// `503 Service Unavailable`, e.g. the transaction queue is full or the peer sheds the load of the queries
+    pub service_unavailable: bool,
+}
+
+impl RetryOn {
+    /// No failures are retried
+    pub const NONE: Self = Self {
+        connection: false,
+        too_many_requests: false,
+        service_unavailable: false,
+    };
+
+    /// All transient failures are retried
+    pub const ALL: Self = Self {
+        connection: true,
+        too_many_requests: true,
+        service_unavailable: true,
+    };
+}
+
+/// How the submissions and the queries are retried after transient failures.
+///
+/// The delay between the attempts starts at [`Self::initial_backoff`] and doubles after every attempt
+/// up to [`Self::max_backoff`]. When the peer suggests a longer delay, e.g. with `Retry-After`, it is respected.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetryPolicy {
+    /// Maximum number of attempts, including the first one. `1` disables retrying
+    pub max_attempts: u32,
+    /// Delay before the first retry
+    pub initial_backoff: Duration,
+    /// Upper bound of the delay between the attempts
+    pub max_backoff: Duration,
+    /// Failures which are retried
+    pub retry_on: RetryOn,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self::NONE
+    }
+}
+
+impl RetryPolicy {
+    /// Policy which never retries
+    pub const NONE: Self = Self {
+        max_attempts: 1,
+        initial_backoff: Duration::ZERO,
+        max_backoff: Duration::ZERO,
+        retry_on: RetryOn::NONE,
+    };
+
+    /// Policy retrying all transient failures with the delay doubling from 100 ms up to 10 s,
+    /// until `max_attempts` attempts are made in total.
+    pub fn exponential(max_attempts: u32) -> Self {
+        Self {
+            max_attempts,
+            initial_backoff: Duration::from_millis(100),
+            max_backoff: Duration::from_secs(10),
+            retry_on: RetryOn::ALL,
+        }
+    }
+
+    /// Delay before the attempt following the `attempt`-th one (counting from 1), not accounting for the hints of the peer
+    pub fn backoff(&self, attempt: u32) -> Duration {
+        let factor = 2_u32.saturating_pow(attempt.saturating_sub(1));
+        self.initial_backoff
+            .saturating_mul(factor)
+            .min(self.max_backoff)
+    }
+
+    /// Delay before retrying the `outcome` of the `attempt`-th attempt, `None` if it isn't retried
+    fn delay(&self, attempt: u32, outcome: &Result<Response<Vec<u8>>>) -> Option<Duration> {
+        if attempt >= self.max_attempts {
+            return None;
+        }
+
+        let hint = match outcome {
+            Ok(response) => match response.status() {
+                StatusCode::TOO_MANY_REQUESTS if self.retry_on.too_many_requests => {
+                    retry_after(response)
+                }
+                StatusCode::SERVICE_UNAVAILABLE if self.retry_on.service_unavailable => {
+                    retry_after(response)
+                }
+                _ => return None,
+            },
+            Err(error) if self.retry_on.connection && http_default::is_transport_error(error) => {
+                None
+            }
+            Err(_) => return None,
+        };
+
+        let backoff = self.backoff(attempt);
+        Some(hint.map_or(backoff, |hint| hint.max(backoff)))
+    }
+
+    /// Make the request with `send` until it succeeds or fails permanently.
+    /// Returns the outcome of the last attempt along with the number of attempts made.
+    pub(crate) fn retry(
+        &self,
+        mut send: impl FnMut() -> Result<Response<Vec<u8>>>,
+    ) -> (Result<Response<Vec<u8>>>, u32) {
+        let mut attempt = 1;
+        loop {
+            let outcome = send();
+            let Some(delay) = self.delay(attempt, &outcome) else {
+                return (outcome, attempt);
+            };
+            iroha_logger::debug!(attempt, ?delay, "Request failed transiently, retrying");
+            thread::sleep(delay);
+            attempt += 1;
+        }
+    }
+
+    /// Asynchronous counterpart of [`Self::retry`]
+    pub(crate) async fn retry_async<F>(
+        &self,
+        mut send: impl FnMut() -> F,
+    ) -> (Result<Response<Vec<u8>>>, u32)
+    where
+        F: Future<Output = Result<Response<Vec<u8>>>>,
+    {
+        let mut attempt = 1;
+        loop {
+            let outcome = send().await;
+            let Some(delay) = self.delay(attempt, &outcome) else {
+                return (outcome, attempt);
+            };
+            iroha_logger::debug!(attempt, ?delay, "Request failed transiently, retrying");
+            tokio::time::sleep(delay).await;
+            attempt += 1;
+        }
+    }
+}
+
+/// Delay suggested by the peer, either by the [`QueueFull`] hint or the `Retry-After` header
+fn retry_after(response: &Response<Vec<u8>>) -> Option<Duration> {
+    if let Ok(hint) = serde_json::from_slice::<QueueFull>(response.body()) {
+        return Some(Duration::from_millis(hint.retry_after_ms));
+    }
+
+    response
+        .headers()
+        .get(http::header::RETRY_AFTER)?
+        .to_str()
+        .ok()?
+        .parse()
+        .ok()
+        .map(Duration::from_secs)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn response(status: StatusCode) -> Result<Response<Vec<u8>>> {
+        Ok(Response::builder()
+            .status(status)
+            .header(http::header::RETRY_AFTER, "1")
+            .body(Vec::new())
+            .unwrap())
+    }
+
+    #[test]
+    fn backoff_doubles_up_to_limit() {
+        let policy = RetryPolicy::exponential(10);
+
+        assert_eq!(policy.backoff(1), Duration::from_millis(100));
+        assert_eq!(policy.backoff(2), Duration::from_millis(200));
+        assert_eq!(policy.backoff(4), Duration::from_millis(800));
+        assert_eq!(policy.backoff(9), Duration::from_secs(10));
+        assert_eq!(policy.backoff(u32::MAX), Duration::from_secs(10));
+    }
+
+    #[test]
+    fn only_transient_failures_are_retried() {
+        let policy = RetryPolicy::exponential(3);
+
+        assert_eq!(
+            policy.delay(1, &response(StatusCode::SERVICE_UNAVAILABLE)),
+            Some(Duration::from_secs(1))
+        );
+        assert_eq!(
+            policy.delay(2, &response(StatusCode::TOO_MANY_REQUESTS)),
+            Some(Duration::from_secs(1))
+        );
+        assert_eq!(
+            policy.delay(3, &response(StatusCode::SERVICE_UNAVAILABLE)),
+            None
+        );
+        assert_eq!(policy.delay(1, &response(StatusCode::BAD_REQUEST)), None);
+        assert_eq!(
+            policy.delay(1, &Err(eyre::eyre!("not a transport error"))),
+            None
+        );
+        assert_eq!(
+            RetryPolicy::NONE.delay(1, &response(StatusCode::SERVICE_UNAVAILABLE)),
+            None
+        );
+    }
+}
@@ -0,0 +1,52 @@
+//! Signing of the transactions and the queries submitted by the client.
+//!
+//! The client doesn't need the private key of its account: any [`Signer`] can sign on its behalf,
+//! so that the key can live in a key management service, a hardware security module or a signing service.
+//! [`KeyPair`] is the in-memory implementation used by default.
+
+use std::fmt::Debug;
+
+use eyre::Result;
+
+use crate::crypto::{KeyPair, PublicKey, Signature};
+
+/// Produces signatures on behalf of the client account
+pub trait Signer: Debug + Send + Sync {
+    /// Public key the signatures are verified with, i.e. the signatory of the client account
+    fn public_key(&self) -> &PublicKey;
+
+    /// Sign `payload`, which is the hash of the transaction or the query being signed.
+    ///
+    /// # Errors
+    /// Fails if the signature can't be produced, e.g. the signing service is unavailable
+    fn sign(&self, payload: &[u8]) -> Result<Signature>;
+}
+
+impl Signer for KeyPair {
+    fn public_key(&self) -> &PublicKey {
+        KeyPair::public_key(self)
+    }
+
+    fn sign(&self, payload: &[u8]) -> Result<Signature> {
+        Ok(Signature::new(self.private_key(), payload))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data_model::prelude::*;
+
+    #[test]
+    fn transaction_signed_by_signer_is_verified() {
+        let signer: Box<dyn Signer> = Box::new(KeyPair::random());
+        let authority = AccountId::new("wonderland".parse().unwrap(), signer.public_key().clone());
+
+        let transaction = TransactionBuilder::new(ChainId::from("00000000"), authority)
+            .with_instructions([Log::new(Level::INFO, "signed remotely".to_owned())])
+            .sign_with(|payload| signer.sign(payload))
+            .unwrap();
+
+        transaction.verify_signature().unwrap();
+    }
+}
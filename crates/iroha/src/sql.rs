@@ -0,0 +1,521 @@
+//! Translation of a restricted SQL dialect into iterable queries.
+//!
+//! Lets analysts explore the chain data with a familiar syntax, e.g.
+//! `SELECT * FROM assets WHERE definition = 'rose#wonderland' ORDER BY metadata.rank LIMIT 10`.
+//! The statement is translated on the client side, so that the resulting query is signed
+//! and validated by the peer like any other.
+//!
+//! The supported statements are:
+//!
+//! ```text
+//! SELECT * FROM <table> [WHERE <column> {= | !=} '<value>' [AND ...]] [ORDER BY metadata.<key>] [LIMIT <n>] [OFFSET <n>]
+//! ```
+//!
+//! | table          | columns                                     |
+//! |----------------|---------------------------------------------|
+//! | `accounts`     | `id`, `domain`, `signatory`                 |
+//! | `assets`       | `id`, `account`, `definition`               |
+//! | `domains`      | `id`                                        |
+//! | `transactions` | `authority`, `hash`, `block`, `status`      |
+//!
+//! The `status` of a transaction is either `'committed'` or `'rejected'`.
+//! Keywords are case-insensitive.
+
+use std::{fmt::Display, num::NonZeroU64, str::FromStr};
+
+use crate::data_model::{
+    block::BlockHeader,
+    prelude::*,
+    query::{
+        dsl::{CompoundPredicate, HasProjection, PredicateMarker},
+        parameters::{FetchSize, Pagination, QueryParams, Sorting},
+        CommittedTransaction, QueryBox, QueryWithFilter, QueryWithParams,
+    },
+};
+
+/// Error of translating an SQL statement
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error, displaydoc::Display)]
+pub enum Error {
+    /// Unexpected end of the statement, expected {0}
+    UnexpectedEnd(&'static str),
+    /// Unexpected `{found}`, expected {expected}
+    Unexpected {
+        /// Token found in the statement
+        found: String,
+        /// Description of the expected token
+        expected: &'static str,
+    },
+    /// String literal isn't terminated
+    UnterminatedString,
+    /// Unknown table `{0}`, expected one of `accounts`, `assets`, `domains` or `transactions`
+    UnknownTable(String),
+    /// Table `{table}` has no column `{column}`
+    UnknownColumn {
+        /// Table selected from
+        table: &'static str,
+        /// Column which isn't supported
+        column: String,
+    },
+    /// Invalid value `{value}` of column `{column}`: {reason}
+    InvalidValue {
+        /// Column compared to the value
+        column: String,
+        /// Value which can't be parsed
+        value: String,
+        /// Why the value can't be parsed
+        reason: String,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Token {
+    Word(String),
+    String(String),
+    Star,
+    Eq,
+    NotEq,
+    Dot,
+}
+
+impl Display for Token {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Word(word) => write!(f, "{word}"),
+            Self::String(string) => write!(f, "'{string}'"),
+            Self::Star => write!(f, "*"),
+            Self::Eq => write!(f, "="),
+            Self::NotEq => write!(f, "!="),
+            Self::Dot => write!(f, "."),
+        }
+    }
+}
+
+fn tokenize(statement: &str) -> Result<Vec<Token>, Error> {
+    let mut tokens = Vec::new();
+    let mut chars = statement.chars().peekable();
+
+    while let Some(char) = chars.next() {
+        match char {
+            char if char.is_whitespace() => {}
+            '*' => tokens.push(Token::Star),
+            '=' => tokens.push(Token::Eq),
+            '.' => tokens.push(Token::Dot),
+            '!' if chars.next_if_eq(&'=').is_some() => tokens.push(Token::NotEq),
+            '<' if chars.next_if_eq(&'>').is_some() => tokens.push(Token::NotEq),
+            '\'' => {
+                let mut string = String::new();
+                loop {
+                    match chars.next() {
+                        // Quotes are escaped by doubling them
+                        Some('\'') if chars.next_if_eq(&'\'').is_some() => string.push('\''),
+                        Some('\'') => break,
+                        Some(char) => string.push(char),
+                        None => return Err(Error::UnterminatedString),
+                    }
+                }
+                tokens.push(Token::String(string));
+            }
+            char if is_word_char(char) => {
+                let mut word = String::from(char);
+                while let Some(char) = chars.next_if(|char| is_word_char(*char)) {
+                    word.push(char);
+                }
+                tokens.push(Token::Word(word));
+            }
+            char => {
+                return Err(Error::Unexpected {
+                    found: char.to_string(),
+                    expected: "a keyword, an identifier or a string literal",
+                })
+            }
+        }
+    }
+
+    Ok(tokens)
+}
+
+fn is_word_char(char: char) -> bool {
+    char.is_alphanumeric() || char == '_'
+}
+
+struct Parser {
+    tokens: std::iter::Peekable<std::vec::IntoIter<Token>>,
+}
+
+impl Parser {
+    fn next(&mut self, expected: &'static str) -> Result<Token, Error> {
+        self.tokens.next().ok_or(Error::UnexpectedEnd(expected))
+    }
+
+    fn expect(&mut self, token: &Token, expected: &'static str) -> Result<(), Error> {
+        match self.next(expected)? {
+            found if found == *token => Ok(()),
+            found => Err(Error::Unexpected {
+                found: found.to_string(),
+                expected,
+            }),
+        }
+    }
+
+    fn expect_keyword(&mut self, keyword: &'static str) -> Result<(), Error> {
+        match self.next(keyword)? {
+            Token::Word(word) if word.eq_ignore_ascii_case(keyword) => Ok(()),
+            found => Err(Error::Unexpected {
+                found: found.to_string(),
+                expected: keyword,
+            }),
+        }
+    }
+
+    /// Consume the `keyword` if it is the next token
+    fn next_is_keyword(&mut self, keyword: &str) -> bool {
+        self.tokens
+            .next_if(
+                |token| matches!(token, Token::Word(word) if word.eq_ignore_ascii_case(keyword)),
+            )
+            .is_some()
+    }
+
+    fn word(&mut self, expected: &'static str) -> Result<String, Error> {
+        match self.next(expected)? {
+            Token::Word(word) => Ok(word),
+            found => Err(Error::Unexpected {
+                found: found.to_string(),
+                expected,
+            }),
+        }
+    }
+
+    fn string(&mut self, expected: &'static str) -> Result<String, Error> {
+        match self.next(expected)? {
+            Token::String(string) => Ok(string),
+            found => Err(Error::Unexpected {
+                found: found.to_string(),
+                expected,
+            }),
+        }
+    }
+
+    fn number(&mut self, expected: &'static str) -> Result<u64, Error> {
+        let word = self.word(expected)?;
+        word.parse().map_err(|_| Error::Unexpected {
+            found: word,
+            expected,
+        })
+    }
+
+    /// Parse `<column> {= | !=} '<value>' [AND ...]`
+    fn conditions(&mut self) -> Result<Vec<Condition>, Error> {
+        let mut conditions = Vec::new();
+        loop {
+            let column = self.word("a column")?;
+            let negated = match self.next("`=` or `!=`")? {
+                Token::Eq => false,
+                Token::NotEq => true,
+                found => {
+                    return Err(Error::Unexpected {
+                        found: found.to_string(),
+                        expected: "`=` or `!=`",
+                    })
+                }
+            };
+            let value = self.string("a string literal")?;
+            conditions.push(Condition {
+                column,
+                value,
+                negated,
+            });
+
+            if !self.next_is_keyword("AND") {
+                return Ok(conditions);
+            }
+        }
+    }
+}
+
+struct Condition {
+    column: String,
+    value: String,
+    negated: bool,
+}
+
+impl Condition {
+    fn parse_value<T>(&self) -> Result<T, Error>
+    where
+        T: FromStr<Err: Display>,
+    {
+        self.value
+            .parse()
+            .map_err(|err: T::Err| Error::InvalidValue {
+                column: self.column.clone(),
+                value: self.value.clone(),
+                reason: err.to_string(),
+            })
+    }
+
+    fn unknown_column(&self, table: &'static str) -> Error {
+        Error::UnknownColumn {
+            table,
+            column: self.column.clone(),
+        }
+    }
+}
+
+/// Combine the predicates of the `conditions` with "and"
+fn predicate<T>(
+    conditions: &[Condition],
+    mut atom: impl FnMut(&Condition) -> Result<CompoundPredicate<T>, Error>,
+) -> Result<CompoundPredicate<T>, Error>
+where
+    T: HasProjection<PredicateMarker>,
+{
+    conditions
+        .iter()
+        .try_fold(CompoundPredicate::PASS, |predicate, condition| {
+            let atom = atom(condition)?;
+            Ok(predicate.and(if condition.negated { atom.not() } else { atom }))
+        })
+}
+
+/// Translate the SQL `statement` into an iterable query, see the [module-level documentation](self).
+///
+/// # Errors
+/// Fails if the statement isn't supported or is malformed
+pub fn translate(statement: &str) -> Result<QueryWithParams, Error> {
+    let mut parser = Parser {
+        tokens: tokenize(statement)?.into_iter().peekable(),
+    };
+
+    parser.expect_keyword("SELECT")?;
+    parser.expect(&Token::Star, "`*`")?;
+    parser.expect_keyword("FROM")?;
+    let table = parser.word("a table")?;
+    let conditions = if parser.next_is_keyword("WHERE") {
+        parser.conditions()?
+    } else {
+        Vec::new()
+    };
+
+    let mut sorting = Sorting::default();
+    if parser.next_is_keyword("ORDER") {
+        parser.expect_keyword("BY")?;
+        let field = parser.word("`metadata`")?;
+        if !field.eq_ignore_ascii_case("metadata") {
+            return Err(Error::Unexpected {
+                found: field,
+                expected: "`metadata`, only sorting by metadata keys is supported",
+            });
+        }
+        parser.expect(&Token::Dot, "`.`")?;
+        let key = parser.word("a metadata key")?;
+        let key = key.parse::<Name>().map_err(|err| Error::InvalidValue {
+            column: "metadata".to_owned(),
+            value: key.clone(),
+            reason: err.to_string(),
+        })?;
+        sorting.sort_by_metadata_key = Some(key);
+    }
+
+    let mut pagination = Pagination::default();
+    if parser.next_is_keyword("LIMIT") {
+        pagination.limit = Some(NonZeroU64::new(parser.number("a number of rows")?).ok_or(
+            Error::Unexpected {
+                found: "0".to_owned(),
+                expected: "a positive number of rows",
+            },
+        )?);
+    }
+    if parser.next_is_keyword("OFFSET") {
+        pagination.offset = parser.number("a number of rows")?;
+    }
+
+    if let Some(token) = parser.tokens.next() {
+        return Err(Error::Unexpected {
+            found: token.to_string(),
+            expected: "the end of the statement",
+        });
+    }
+
+    let query = match table.to_ascii_lowercase().as_str() {
+        "accounts" => QueryBox::from(QueryWithFilter::new(
+            FindAccounts,
+            predicate(&conditions, |condition| {
+                Ok(match condition.column.as_str() {
+                    "id" => {
+                        let id: AccountId = condition.parse_value()?;
+                        CompoundPredicate::<Account>::build(|account| account.id.eq(id))
+                    }
+                    "domain" => {
+                        let domain: DomainId = condition.parse_value()?;
+                        CompoundPredicate::<Account>::build(|account| account.id.domain.eq(domain))
+                    }
+                    "signatory" => {
+                        let signatory: PublicKey = condition.parse_value()?;
+                        CompoundPredicate::<Account>::build(|account| {
+                            account.id.signatory.eq(signatory)
+                        })
+                    }
+                    _ => return Err(condition.unknown_column("accounts")),
+                })
+            })?,
+            SelectorTuple::default(),
+        )),
+        "assets" => QueryBox::from(QueryWithFilter::new(
+            FindAssets,
+            predicate(&conditions, |condition| {
+                Ok(match condition.column.as_str() {
+                    "id" => {
+                        let id: AssetId = condition.parse_value()?;
+                        CompoundPredicate::<Asset>::build(|asset| asset.id.eq(id))
+                    }
+                    "account" => {
+                        let account: AccountId = condition.parse_value()?;
+                        CompoundPredicate::<Asset>::build(|asset| asset.id.account.eq(account))
+                    }
+                    "definition" => {
+                        let definition: AssetDefinitionId = condition.parse_value()?;
+                        CompoundPredicate::<Asset>::build(|asset| {
+                            asset.id.definition.eq(definition)
+                        })
+                    }
+                    _ => return Err(condition.unknown_column("assets")),
+                })
+            })?,
+            SelectorTuple::default(),
+        )),
+        "domains" => QueryBox::from(QueryWithFilter::new(
+            FindDomains,
+            predicate(&conditions, |condition| {
+                Ok(match condition.column.as_str() {
+                    "id" => {
+                        let id: DomainId = condition.parse_value()?;
+                        CompoundPredicate::<Domain>::build(|domain| domain.id.eq(id))
+                    }
+                    _ => return Err(condition.unknown_column("domains")),
+                })
+            })?,
+            SelectorTuple::default(),
+        )),
+        "transactions" => QueryBox::from(QueryWithFilter::new(
+            FindTransactions,
+            predicate(&conditions, |condition| {
+                Ok(match condition.column.as_str() {
+                    "authority" => {
+                        let authority: AccountId = condition.parse_value()?;
+                        CompoundPredicate::<CommittedTransaction>::build(|tx| {
+                            tx.entrypoint.authority.eq(authority)
+                        })
+                    }
+                    "hash" => {
+                        let hash: HashOf<TransactionEntrypoint> = condition.parse_value()?;
+                        CompoundPredicate::<CommittedTransaction>::build(|tx| {
+                            tx.entrypoint_hash.eq(hash)
+                        })
+                    }
+                    "block" => {
+                        let hash: HashOf<BlockHeader> = condition.parse_value()?;
+                        CompoundPredicate::<CommittedTransaction>::build(|tx| {
+                            tx.block_hash.eq(hash)
+                        })
+                    }
+                    "status" => {
+                        let is_ok = CompoundPredicate::<CommittedTransaction>::build(|tx| {
+                            tx.result.is_ok()
+                        });
+                        match condition.value.as_str() {
+                            "committed" => is_ok,
+                            "rejected" => is_ok.not(),
+                            _ => {
+                                return Err(Error::InvalidValue {
+                                    column: condition.column.clone(),
+                                    value: condition.value.clone(),
+                                    reason: "expected `committed` or `rejected`".to_owned(),
+                                })
+                            }
+                        }
+                    }
+                    _ => return Err(condition.unknown_column("transactions")),
+                })
+            })?,
+            SelectorTuple::default(),
+        )),
+        _ => return Err(Error::UnknownTable(table)),
+    };
+
+    Ok(QueryWithParams {
+        query,
+        params: QueryParams {
+            pagination,
+            sorting,
+            fetch_size: FetchSize::default(),
+            aggregation: None,
+        },
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use iroha_test_samples::ALICE_ID;
+
+    use super::*;
+
+    #[test]
+    fn select_is_translated() {
+        let statement = format!(
+            "select * from ASSETS where account = '{}' and definition != 'rose#wonderland' \
+             order by metadata.rank limit 10 offset 5",
+            *ALICE_ID
+        );
+        let definition: AssetDefinitionId = "rose#wonderland".parse().unwrap();
+        let account = ALICE_ID.clone();
+
+        let query = translate(&statement).unwrap();
+
+        assert_eq!(
+            query.query,
+            QueryBox::from(QueryWithFilter::new(
+                FindAssets,
+                CompoundPredicate::<Asset>::build(|asset| asset.id.account.eq(account)).and(
+                    CompoundPredicate::<Asset>::build(|asset| asset.id.definition.eq(definition))
+                        .not()
+                ),
+                SelectorTuple::default(),
+            ))
+        );
+        assert_eq!(
+            query.params.sorting.sort_by_metadata_key,
+            Some("rank".parse().unwrap())
+        );
+        assert_eq!(
+            query.params.pagination,
+            Pagination::new(NonZeroU64::new(10), 5)
+        );
+    }
+
+    #[test]
+    fn unsupported_statements_are_rejected() {
+        assert_eq!(
+            translate("SELECT * FROM peers").unwrap_err(),
+            Error::UnknownTable("peers".to_owned())
+        );
+        assert_eq!(
+            translate("SELECT * FROM domains WHERE owner = 'alice'").unwrap_err(),
+            Error::UnknownColumn {
+                table: "domains",
+                column: "owner".to_owned()
+            }
+        );
+        assert_eq!(
+            translate("SELECT * FROM domains WHERE id = 'wonderland").unwrap_err(),
+            Error::UnterminatedString
+        );
+        assert!(matches!(
+            translate("DELETE FROM domains").unwrap_err(),
+            Error::Unexpected { .. }
+        ));
+        assert!(matches!(
+            translate("SELECT * FROM domains LIMIT 1 DROP").unwrap_err(),
+            Error::Unexpected { .. }
+        ));
+    }
+}
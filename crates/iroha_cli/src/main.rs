@@ -31,6 +31,11 @@ struct Args {
     /// By default, `iroha` will try to read `client.toml` file, but would proceed if it is not found.
     #[arg(short, long, value_name("PATH"))]
     config: Option<PathBuf>,
+    /// Profile of the configuration file to use, e.g. `dev` for the `[profile.dev]` table.
+    ///
+    /// Might be set via `PROFILE` env var.
+    #[arg(short, long, value_name("NAME"))]
+    profile: Option<String>,
     /// Print configuration details to stderr
     #[arg(short, long)]
     verbose: bool,
@@ -159,7 +164,7 @@ trait RunContext {
         let transaction = client.build_transaction(
             instructions,
             self.transaction_metadata().cloned().unwrap_or_default(),
-        );
+        )?;
 
         #[cfg(not(debug_assertions))]
         let err_msg = "Failed to submit transaction";
@@ -291,10 +296,14 @@ fn main() -> error_stack::Result<(), MainError> {
 
     error_stack::Report::set_color_mode(color_mode());
 
-    let config = Config::load(args.config.map_or_else(
+    let path = args.config.map_or_else(
         || LoadPath::Default(PathBuf::from("client.toml")),
         LoadPath::Explicit,
-    ))
+    );
+    let config = match &args.profile {
+        Some(profile) => Config::load_profile(path, profile),
+        None => Config::load(path),
+    }
     // FIXME: would be nice to NOT change the context, it's unnecessary
     .change_context(MainError::Config)
     .attach_printable("config path was set by `--config` argument")?;
@@ -475,13 +484,24 @@ mod blocks {
         /// Example: "1y 6M 2w 3d 12h 30m 30s"
         #[arg(short, long)]
         timeout: Option<humantime::Duration>,
+        /// Stream only block headers and signatures, omitting transactions
+        #[arg(long)]
+        headers_only: bool,
     }
 
     impl Run for Args {
         fn run<C: RunContext>(self, context: &mut C) -> Result<()> {
-            let Args { height, timeout } = self;
+            let Args {
+                height,
+                timeout,
+                headers_only,
+            } = self;
             let timeout: Option<Duration> = timeout.map(Into::into);
-            listen(height, context, timeout)
+            if headers_only {
+                listen_headers(height, context, timeout)
+            } else {
+                listen(height, context, timeout)
+            }
         }
     }
 
@@ -514,6 +534,36 @@ mod blocks {
         }
         Ok(())
     }
+
+    fn listen_headers(
+        height: NonZeroU64,
+        context: &mut impl RunContext,
+        timeout: Option<Duration>,
+    ) -> Result<()> {
+        let client = context.client_from_config();
+        if let Some(timeout) = timeout {
+            eprintln!("Listening to block headers from height: {height} and timeout: {timeout:?}");
+            let rt = Runtime::new().wrap_err("Failed to create runtime")?;
+            rt.block_on(async {
+                let mut stream = client
+                    .listen_for_block_headers_async(height)
+                    .await
+                    .expect("Failed to listen for block headers");
+                while let Ok(header) = tokio::time::timeout(timeout, stream.try_next()).await {
+                    context.print_data(&header?)?;
+                }
+                eprintln!("Timeout period has expired.");
+                Result::<()>::Ok(())
+            })?;
+        } else {
+            eprintln!("Listening to block headers from height: {height}");
+            client
+                .listen_for_block_headers(height)
+                .wrap_err("Failed to listen for block headers")?
+                .try_for_each(|header| context.print_data(&header?))?;
+        }
+        Ok(())
+    }
 }
 
 macro_rules! impl_list {
@@ -905,6 +955,12 @@ mod asset {
                         if args.mint_once {
                             entry = entry.mintable_once();
                         }
+                        if args.store {
+                            entry.kind = AssetKind::Store;
+                        }
+                        if let Some(max_supply) = args.max_supply {
+                            entry = entry.with_max_supply(max_supply);
+                        }
                         let instruction = iroha::data_model::isi::Register::asset_definition(entry);
                         context
                             .finish([instruction])
@@ -941,6 +997,12 @@ mod asset {
             /// Numeric scale of the asset. No value means unconstrained.
             #[arg(short, long)]
             pub scale: Option<u32>,
+            /// Holdings are key-value stores instead of quantities
+            #[arg(long)]
+            pub store: bool,
+            /// Total quantity the asset can't be minted above. No value means uncapped.
+            #[arg(long)]
+            pub max_supply: Option<Numeric>,
         }
 
         #[derive(clap::Args, Debug)]
@@ -1010,7 +1072,7 @@ mod nft {
         #[clap(subcommand)]
         List(List),
         /// Register NFT with content provided from stdin in JSON5 format
-        Register(Id),
+        Register(Register),
         /// Unregister NFT
         Unregister(Id),
         /// Transfer ownership of NFT
@@ -1042,8 +1104,11 @@ mod nft {
                 List(cmd) => cmd.run(context),
                 Register(args) => {
                     let metadata: Metadata = parse_json5_stdin(context)?;
-                    let instruction =
-                        iroha::data_model::isi::Register::nft(Nft::new(args.id, metadata));
+                    let mut nft = Nft::new(args.id, metadata);
+                    if args.non_transferable {
+                        nft = nft.non_transferable();
+                    }
+                    let instruction = iroha::data_model::isi::Register::nft(nft);
                     context
                         .finish([instruction])
                         .wrap_err("Failed to register NFT")
@@ -1086,6 +1151,16 @@ mod nft {
         }
     }
 
+    #[derive(clap::Args, Debug)]
+    pub struct Register {
+        /// NFT in the format "name$domain"
+        #[arg(short, long)]
+        pub id: NftId,
+        /// Only the owner of the domain can transfer the NFT, e.g. to issue it to a holder
+        #[arg(long)]
+        pub non_transferable: bool,
+    }
+
     #[derive(clap::Args, Debug)]
     pub struct Transfer {
         /// NFT in the format "name$domain"
@@ -1119,6 +1194,10 @@ mod nft {
 }
 
 mod peer {
+    use std::num::NonZeroU64;
+
+    use iroha::executor_data_model::isi::governance::GovernancePropose;
+
     use super::*;
 
     #[derive(clap::Subcommand, Debug)]
@@ -1126,10 +1205,10 @@ mod peer {
         /// List registered peers expected to connect with each other
         #[command(subcommand)]
         List(List),
-        /// Register a peer
-        Register(Id),
-        /// Unregister a peer
-        Unregister(Id),
+        /// Propose to register a peer via governance
+        Register(Change),
+        /// Propose to unregister a peer via governance
+        Unregister(Change),
     }
 
     impl Run for Command {
@@ -1139,20 +1218,40 @@ mod peer {
                 List(cmd) => cmd.run(context),
                 Register(args) => {
                     let instruction = iroha::data_model::isi::Register::peer(args.key.into());
+                    let proposal = propose(context, instruction, args.voting_period)?;
                     context
-                        .finish([instruction])
-                        .wrap_err("Failed to register peer")
+                        .finish([proposal])
+                        .wrap_err("Failed to propose peer registration")
                 }
                 Unregister(args) => {
                     let instruction = iroha::data_model::isi::Unregister::peer(args.key.into());
+                    let proposal = propose(context, instruction, args.voting_period)?;
                     context
-                        .finish([instruction])
-                        .wrap_err("Failed to unregister peer")
+                        .finish([proposal])
+                        .wrap_err("Failed to propose peer unregistration")
                 }
             }
         }
     }
 
+    /// Wrap the peer set change into a proposal to the governance council, printing its hash to vote on
+    fn propose<C: RunContext>(
+        context: &mut C,
+        instruction: impl Into<InstructionBox>,
+        voting_period: humantime::Duration,
+    ) -> Result<GovernancePropose> {
+        let voting_period_ms = voting_period
+            .as_millis()
+            .try_into()
+            .ok()
+            .and_then(NonZeroU64::new)
+            .ok_or_else(|| eyre!("voting period should be between 1 ms and 584942417 years"))?;
+        let instructions: Vec<InstructionBox> = vec![instruction.into()];
+        context.println(HashOf::new(&instructions))?;
+
+        Ok(GovernancePropose::new(instructions, voting_period_ms))
+    }
+
     #[derive(clap::Subcommand, Debug)]
     pub enum List {
         /// List all registered peers
@@ -1168,10 +1267,18 @@ mod peer {
     }
 
     #[derive(clap::Args, Debug)]
-    pub struct Id {
+    pub struct Change {
         /// Peer's public key in multihash format
         #[arg(short, long)]
         pub key: PublicKey,
+        /// Time the governance council has to approve the change.
+        /// Example: "1y 6M 2w 3d 12h 30m 30s"
+        #[arg(short, long, default_value_t = default_voting_period())]
+        pub voting_period: humantime::Duration,
+    }
+
+    fn default_voting_period() -> humantime::Duration {
+        std::time::Duration::from_secs(60 * 60).into()
     }
 }
 
@@ -1508,7 +1615,7 @@ mod multisig {
 }
 
 mod query {
-    use iroha::data_model::query::AnyQueryBox;
+    use iroha::data_model::query::{AnyQueryBox, QueryWithParams};
 
     use super::*;
 
@@ -1516,12 +1623,14 @@ mod query {
     pub enum Command {
         /// Query using JSON5 input from stdin
         Stdin(Stdin),
+        /// Query using a read-only SQL statement, e.g. `SELECT * FROM accounts WHERE domain = 'wonderland' LIMIT 10`
+        Sql(Sql),
     }
 
     impl Run for Command {
         fn run<C: RunContext>(self, context: &mut C) -> Result<()> {
             use self::Command::*;
-            match_all!((self, context), { Stdin })
+            match_all!((self, context), { Stdin, Sql })
         }
     }
 
@@ -1541,47 +1650,69 @@ mod query {
 
                     context.print_data(&result)
                 }
-                AnyQueryBox::Iterable(query) => {
-                    // we can't really do type-erased iterable queries in a nice way right now...
-                    use iroha::data_model::query::builder::QueryExecutor;
+                AnyQueryBox::Iterable(query) => print_iterable(context, &client, query),
+            }
+        }
+    }
 
-                    let (mut accumulated_batch, _remaining_items, mut continue_cursor) =
-                        client.start_query(query)?;
+    #[derive(clap::Args, Debug)]
+    pub struct Sql {
+        /// SQL statement, see `iroha::sql` for the supported dialect
+        statement: String,
+    }
 
-                    while let Some(cursor) = continue_cursor {
-                        let (next_batch, _remaining_items, next_continue_cursor) =
-                            <Client as QueryExecutor>::continue_query(cursor)?;
+    impl Run for Sql {
+        fn run<C: RunContext>(self, context: &mut C) -> Result<()> {
+            let client = Client::new(context.config().clone());
+            let query = iroha::sql::translate(&self.statement)
+                .wrap_err("Failed to translate the SQL statement")?;
 
-                        accumulated_batch.extend(next_batch);
-                        continue_cursor = next_continue_cursor;
-                    }
+            print_iterable(context, &client, query)
+        }
+    }
 
-                    // for efficiency reasons iroha encodes query results in a columnar format,
-                    // so we need to transpose the batch to get the format that is more natural for humans
-                    let mut batches = vec![Vec::new(); accumulated_batch.len()];
-                    for batch in accumulated_batch {
-                        // downcast to json and extract the actual array
-                        // dynamic typing is just easier to use here than introducing a bunch of new types only for iroha_cli
-                        let batch = serde_json::to_value(batch)?;
-                        let serde_json::Value::Object(batch) = batch else {
-                            panic!("Expected the batch serialization to be a JSON object");
-                        };
-                        let (_ty, batch) = batch
-                            .into_iter()
-                            .next()
-                            .expect("Expected the batch to have exactly one key");
-                        let serde_json::Value::Array(batch_vec) = batch else {
-                            panic!("Expected the batch payload to be a JSON array");
-                        };
-                        for (target, value) in batches.iter_mut().zip(batch_vec) {
-                            target.push(value);
-                        }
-                    }
+    fn print_iterable<C: RunContext>(
+        context: &mut C,
+        client: &Client,
+        query: QueryWithParams,
+    ) -> Result<()> {
+        // we can't really do type-erased iterable queries in a nice way right now...
+        use iroha::data_model::query::builder::QueryExecutor;
 
-                    context.print_data(&batches)
-                }
+        let (mut accumulated_batch, _remaining_items, mut continue_cursor) =
+            client.start_query(query)?;
+
+        while let Some(cursor) = continue_cursor {
+            let (next_batch, _remaining_items, next_continue_cursor) =
+                <Client as QueryExecutor>::continue_query(cursor)?;
+
+            accumulated_batch.extend(next_batch);
+            continue_cursor = next_continue_cursor;
+        }
+
+        // for efficiency reasons iroha encodes query results in a columnar format,
+        // so we need to transpose the batch to get the format that is more natural for humans
+        let mut batches = vec![Vec::new(); accumulated_batch.len()];
+        for batch in accumulated_batch {
+            // downcast to json and extract the actual array
+            // dynamic typing is just easier to use here than introducing a bunch of new types only for iroha_cli
+            let batch = serde_json::to_value(batch)?;
+            let serde_json::Value::Object(batch) = batch else {
+                panic!("Expected the batch serialization to be a JSON object");
+            };
+            let (_ty, batch) = batch
+                .into_iter()
+                .next()
+                .expect("Expected the batch to have exactly one key");
+            let serde_json::Value::Array(batch_vec) = batch else {
+                panic!("Expected the batch payload to be a JSON array");
+            };
+            for (target, value) in batches.iter_mut().zip(batch_vec) {
+                target.push(value);
             }
         }
+
+        context.print_data(&batches)
     }
 }
 
@@ -1594,6 +1725,8 @@ mod transaction {
     pub enum Command {
         /// Retrieve details of a specific transaction
         Get(Get),
+        /// List transactions waiting in the queue of the peer, the oldest first
+        Pending(Pending),
         /// Send an empty transaction that logs a message
         Ping(Ping),
         /// Send a transaction using Wasm input
@@ -1605,7 +1738,7 @@ mod transaction {
     impl Run for Command {
         fn run<C: RunContext>(self, context: &mut C) -> Result<()> {
             use self::Command::*;
-            match_all!((self, context), { Get, Ping, Wasm, Stdin })
+            match_all!((self, context), { Get, Pending, Ping, Wasm, Stdin })
         }
     }
 
@@ -1627,6 +1760,32 @@ mod transaction {
         }
     }
 
+    #[derive(clap::Args, Debug)]
+    pub struct Pending {
+        /// Only the transactions of this authority
+        #[arg(short, long)]
+        pub authority: Option<AccountId>,
+        /// Only the transactions created at least this long ago.
+        /// Example: "5m 30s"
+        #[arg(short, long)]
+        pub min_age: Option<humantime::Duration>,
+    }
+
+    impl Run for Pending {
+        fn run<C: RunContext>(self, context: &mut C) -> Result<()> {
+            let client = context.client_from_config();
+            let mut filter = iroha::client::PendingTransactionsFilter::default();
+            if let Some(authority) = self.authority {
+                filter = filter.for_authority(authority);
+            }
+            if let Some(min_age) = self.min_age {
+                filter = filter.older_than(min_age.into());
+            }
+            let transactions = client.pending_transactions(&filter)?;
+            context.print_data(&transactions)
+        }
+    }
+
     #[derive(clap::Args, Debug)]
     pub struct Ping {
         /// Log levels: TRACE, DEBUG, INFO, WARN, ERROR (in increasing order of visibility)
@@ -1689,6 +1848,8 @@ mod role {
         /// List role IDs
         #[command(subcommand)]
         List(List),
+        /// List accounts holding a role
+        Holders(Id),
         /// Register a role and grant it to the registrant
         Register(Id),
         /// Unregister a role
@@ -1701,6 +1862,14 @@ mod role {
             match self {
                 Permission(cmd) => cmd.run(context),
                 List(cmd) => cmd.run(context),
+                Holders(args) => {
+                    let client = context.client_from_config();
+                    let accounts = client
+                        .query(FindAccountsWithRole::new(args.id))
+                        .select_with(|account| account.id)
+                        .execute_all()?;
+                    context.print_data(&accounts)
+                }
                 Register(args) => {
                     let instruction = iroha::data_model::isi::Register::role(Role::new(
                         args.id,
@@ -26,9 +26,69 @@ pub enum InitMode {
     Fast,
 }
 
+/// Where Kura keeps the blocks.
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Default,
+    strum::EnumString,
+    strum::Display,
+    DeserializeFromStr,
+    SerializeDisplay,
+)]
+#[strum(serialize_all = "snake_case")]
+pub enum StoreMode {
+    /// Blocks are written to the files in the store directory and loaded from them on restart.
+    #[default]
+    Disk,
+    /// Blocks are kept in memory only and are lost once the peer stops.
+    ///
+    /// Nothing is read from or written to the store directory, which suits the ephemeral
+    /// networks of the tests and the CI.
+    Memory,
+}
+
+/// When Kura flushes the written blocks from the OS page cache to the disk.
+///
+/// Blocks are written to the files as soon as they are committed, but stay in the page cache
+/// until flushed. A power loss or a kernel crash loses the blocks which weren't flushed yet
+/// (a crash of the peer process alone doesn't), and the peer has to fetch them from the network again.
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Default,
+    strum::EnumString,
+    strum::Display,
+    DeserializeFromStr,
+    SerializeDisplay,
+)]
+#[strum(serialize_all = "snake_case")]
+pub enum FsyncMode {
+    /// Flush after every write, so that no committed block is ever lost.
+    ///
+    /// The strictest and the slowest mode, suitable for the archival nodes.
+    Block,
+    /// Flush at most once per period, so that at most the blocks committed within the period are lost.
+    ///
+    /// Trades a bounded window of the blocks to refetch for a throughput close to [`Self::Never`].
+    #[default]
+    Periodic,
+    /// Never flush explicitly, the OS writes the page cache back at its discretion.
+    ///
+    /// The fastest mode, suitable for the validators which can always catch up with their peers.
+    /// An unbounded number of the latest blocks may be lost.
+    Never,
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::kura::InitMode;
+    use crate::kura::{FsyncMode, InitMode, StoreMode};
 
     #[test]
     fn init_mode_display_reprs() {
@@ -37,4 +97,22 @@ mod tests {
         assert_eq!("strict".parse::<InitMode>().unwrap(), InitMode::Strict);
         assert_eq!("fast".parse::<InitMode>().unwrap(), InitMode::Fast);
     }
+
+    #[test]
+    fn store_mode_display_reprs() {
+        assert_eq!(format!("{}", StoreMode::Disk), "disk");
+        assert_eq!(format!("{}", StoreMode::Memory), "memory");
+        assert_eq!("memory".parse::<StoreMode>().unwrap(), StoreMode::Memory);
+    }
+
+    #[test]
+    fn fsync_mode_display_reprs() {
+        assert_eq!(format!("{}", FsyncMode::Block), "block");
+        assert_eq!(format!("{}", FsyncMode::Periodic), "periodic");
+        assert_eq!(format!("{}", FsyncMode::Never), "never");
+        assert_eq!(
+            "periodic".parse::<FsyncMode>().unwrap(),
+            FsyncMode::Periodic
+        );
+    }
 }
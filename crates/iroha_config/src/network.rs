@@ -0,0 +1,43 @@
+//! Configuration related to the peer-to-peer network specifically
+
+/// Transport the peer prefers to connect to the other peers over
+///
+/// Negotiated per connection: every peer accepts TCP connections, and the peers preferring QUIC
+/// connect to each other over QUIC, so the peers of a network can prefer different transports.
+#[derive(
+    Copy,
+    Clone,
+    Debug,
+    Default,
+    PartialEq,
+    Eq,
+    strum::Display,
+    strum::EnumString,
+    serde_with::SerializeDisplay,
+    serde_with::DeserializeFromStr,
+)]
+#[strum(serialize_all = "snake_case")]
+pub enum Transport {
+    /// Plain TCP connections
+    #[default]
+    Tcp,
+    /// QUIC connections over UDP.
+    ///
+    /// Recovers from packet loss without stalling the other messages and survives
+    /// the change of the peer address, which suits lossy links between distant peers.
+    Quic,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::network::Transport;
+
+    #[test]
+    fn transport_display_form() {
+        assert_eq!(
+            format!("{} {}", Transport::Tcp, Transport::Quic),
+            "tcp quic"
+        );
+        assert_eq!("quic".parse::<Transport>().unwrap(), Transport::Quic);
+    }
+}
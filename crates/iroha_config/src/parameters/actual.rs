@@ -2,7 +2,8 @@
 //! structures in a way that is efficient for Iroha internally.
 
 use std::{
-    num::{NonZeroU32, NonZeroUsize},
+    collections::BTreeSet,
+    num::{NonZeroU32, NonZeroU64, NonZeroUsize},
     path::PathBuf,
     time::Duration,
 };
@@ -11,15 +12,18 @@ use error_stack::{Result, ResultExt};
 use iroha_config_base::{read::ConfigReader, toml::TomlSource, util::Bytes, WithOrigin};
 use iroha_crypto::{KeyPair, PublicKey};
 use iroha_data_model::{
+    domain::DomainId,
+    isi::InstructionType,
     peer::{Peer, PeerId},
     ChainId,
 };
 use iroha_primitives::{addr::SocketAddr, unique_vec::UniqueVec};
 use url::Url;
-pub use user::{DevTelemetry, Logger, Snapshot};
+pub use user::{DevTelemetry, Logger, Retention, Snapshot, Tiering};
 
 use crate::{
-    kura::InitMode,
+    kura::{FsyncMode, InitMode, StoreMode},
+    network::Transport,
     parameters::{defaults, user},
 };
 
@@ -39,7 +43,11 @@ pub struct Root {
     pub logger: Logger,
     pub queue: Queue,
     pub snapshot: Snapshot,
+    pub retention: Retention,
+    pub tiering: Tiering,
     pub telemetry: Option<Telemetry>,
+    pub network_stats: Option<NetworkStats>,
+    pub archive: Option<Archive>,
     pub dev_telemetry: DevTelemetry,
 }
 
@@ -79,7 +87,10 @@ pub struct Common {
 pub struct Network {
     pub address: WithOrigin<SocketAddr>,
     pub public_address: WithOrigin<SocketAddr>,
+    pub transport: Transport,
     pub idle_timeout: Duration,
+    pub observers: BTreeSet<PeerId>,
+    pub log_rejected_peers: bool,
 }
 
 /// Parsed genesis configuration
@@ -105,8 +116,12 @@ pub struct Queue {
 #[derive(Debug, Clone)]
 pub struct Kura {
     pub init_mode: InitMode,
+    pub store_mode: StoreMode,
     pub store_dir: WithOrigin<PathBuf>,
     pub blocks_in_memory: NonZeroUsize,
+    pub fsync: FsyncMode,
+    pub fsync_period: Duration,
+    pub write_buffer_size: Bytes<u64>,
     pub debug_output_new_blocks: bool,
 }
 
@@ -123,7 +138,9 @@ impl Default for Queue {
 #[derive(Debug, Copy, Clone)]
 #[allow(missing_docs)]
 pub struct Sumeragi {
+    pub proposal_max_transactions: Option<NonZeroUsize>,
     pub debug_force_soft_fork: bool,
+    pub debug_verify_determinism: bool,
 }
 
 #[derive(Debug, Clone)]
@@ -155,6 +172,8 @@ pub struct LiveQueryStore {
     pub idle_time: Duration,
     pub capacity: NonZeroUsize,
     pub capacity_per_user: NonZeroUsize,
+    pub shed_threshold: Option<NonZeroUsize>,
+    pub shed_fetch_size: NonZeroU64,
 }
 
 impl Default for LiveQueryStore {
@@ -163,6 +182,8 @@ impl Default for LiveQueryStore {
             idle_time: defaults::torii::QUERY_IDLE_TIME,
             capacity: defaults::torii::QUERY_STORE_CAPACITY,
             capacity_per_user: defaults::torii::QUERY_STORE_CAPACITY_PER_USER,
+            shed_threshold: None,
+            shed_fetch_size: defaults::torii::QUERY_SHED_FETCH_SIZE,
         }
     }
 }
@@ -186,6 +207,43 @@ pub struct TransactionGossiper {
 pub struct Torii {
     pub address: WithOrigin<SocketAddr>,
     pub max_content_len: Bytes<u64>,
+    pub compression: bool,
+    pub virtual_hosts: Vec<ToriiVirtualHost>,
+    pub instructions: InstructionFilter,
+    pub webhook_allowed_hosts: BTreeSet<String>,
+}
+
+/// Instruction types accepted in the transactions submitted to Torii
+#[derive(Debug, Clone, Default)]
+pub struct InstructionFilter {
+    /// If set, only the instructions of these types are accepted
+    pub allowed: Option<BTreeSet<InstructionType>>,
+    /// Instructions of these types are refused
+    pub denied: BTreeSet<InstructionType>,
+}
+
+impl InstructionFilter {
+    /// Whether the instructions of type `instruction` are accepted
+    pub fn accepts(&self, instruction: InstructionType) -> bool {
+        self.allowed
+            .as_ref()
+            .is_none_or(|allowed| allowed.contains(&instruction))
+            && !self.denied.contains(&instruction)
+    }
+
+    /// Whether any instruction type is refused
+    pub fn is_restrictive(&self) -> bool {
+        self.allowed.is_some() || !self.denied.is_empty()
+    }
+}
+
+/// Torii endpoint restricted to the data of a subset of domains
+#[derive(Debug, Clone)]
+pub struct ToriiVirtualHost {
+    /// Address the endpoint is bound to
+    pub address: SocketAddr,
+    /// Domains visible through the endpoint
+    pub domains: BTreeSet<DomainId>,
 }
 
 /// Complete configuration needed to start regular telemetry.
@@ -198,6 +256,41 @@ pub struct Telemetry {
     pub max_retry_delay_exponent: u8,
 }
 
+/// Complete configuration needed to report anonymized network statistics.
+#[derive(Debug, Clone)]
+#[allow(missing_docs)]
+pub struct NetworkStats {
+    pub url: Url,
+    pub period: Duration,
+}
+
+/// Complete configuration needed to mirror the blocks and the snapshots to S3-compatible storage.
+#[derive(Clone)]
+#[allow(missing_docs)]
+pub struct Archive {
+    pub endpoint: Url,
+    pub bucket: String,
+    pub region: String,
+    pub prefix: String,
+    pub access_key_id: String,
+    pub secret_access_key: String,
+    pub period: Duration,
+}
+
+impl core::fmt::Debug for Archive {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Archive")
+            .field("endpoint", &self.endpoint)
+            .field("bucket", &self.bucket)
+            .field("region", &self.region)
+            .field("prefix", &self.prefix)
+            .field("access_key_id", &self.access_key_id)
+            .field("secret_access_key", &"[REDACTED]")
+            .field("period", &self.period)
+            .finish()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use iroha_primitives::{addr::socket_addr, unique_vec};
@@ -237,4 +330,16 @@ mod tests {
         };
         assert!(value.contains_other_trusted_peers());
     }
+
+    #[test]
+    fn denied_instructions_are_refused_even_if_allowed() {
+        let filter = InstructionFilter {
+            allowed: Some([InstructionType::Register, InstructionType::Upgrade].into()),
+            denied: [InstructionType::Upgrade].into(),
+        };
+        assert!(filter.accepts(InstructionType::Register));
+        assert!(!filter.accepts(InstructionType::Upgrade));
+        assert!(!filter.accepts(InstructionType::Transfer));
+        assert!(!InstructionFilter::default().is_restrictive());
+    }
 }
@@ -4,7 +4,7 @@
 #![allow(missing_docs)]
 
 use std::{
-    num::{NonZeroU32, NonZeroUsize},
+    num::{NonZeroU32, NonZeroU64, NonZeroUsize},
     time::Duration,
 };
 
@@ -20,12 +20,21 @@ pub mod queue {
 }
 
 pub mod kura {
-    use std::num::NonZeroUsize;
+    use iroha_config_base::util::Bytes;
 
-    use nonzero_ext::nonzero;
+    use super::*;
 
     pub const STORE_DIR: &str = "./storage";
     pub const BLOCKS_IN_MEMORY: NonZeroUsize = nonzero!(128_usize);
+    pub const FSYNC_PERIOD: Duration = Duration::from_secs(1);
+    pub const WRITE_BUFFER_SIZE: Bytes<u64> = Bytes(2_u64.pow(20) * 4);
+}
+
+pub mod archive {
+    use super::*;
+
+    pub const PERIOD: Duration = Duration::from_secs(60);
+    pub const REGION: &str = "us-east-1";
 }
 
 pub mod network {
@@ -38,6 +47,19 @@ pub mod network {
     pub const BLOCK_GOSSIP_SIZE: NonZeroU32 = nonzero!(4u32);
 
     pub const IDLE_TIMEOUT: Duration = Duration::from_secs(60);
+
+    pub const LOG_REJECTED_PEERS: bool = true;
+}
+
+pub mod retention {
+    use super::*;
+
+    pub const COMPACT_EVERY_BLOCKS: NonZeroU64 = nonzero!(100_u64);
+    pub const EVENTS_MAX_ENTRIES: NonZeroUsize = nonzero!(10_000_usize);
+}
+
+pub mod tiering {
+    pub const STORE_DIR: &str = "./storage/cold";
 }
 
 pub mod snapshot {
@@ -49,7 +71,10 @@ pub mod snapshot {
 }
 
 pub mod torii {
-    use std::{num::NonZeroUsize, time::Duration};
+    use std::{
+        num::{NonZeroU64, NonZeroUsize},
+        time::Duration,
+    };
 
     use iroha_config_base::util::Bytes;
     use nonzero_ext::nonzero;
@@ -58,6 +83,8 @@ pub mod torii {
     pub const QUERY_IDLE_TIME: Duration = Duration::from_secs(10);
     pub const QUERY_STORE_CAPACITY: NonZeroUsize = nonzero!(128usize);
     pub const QUERY_STORE_CAPACITY_PER_USER: NonZeroUsize = nonzero!(128usize);
+    pub const QUERY_SHED_FETCH_SIZE: NonZeroU64 = nonzero!(10_u64);
+    pub const COMPRESSION: bool = true;
 }
 
 pub mod telemetry {
@@ -67,4 +94,6 @@ pub mod telemetry {
     pub const MIN_RETRY_PERIOD: Duration = Duration::from_secs(1);
     /// Default maximum exponent for the retry delay
     pub const MAX_RETRY_DELAY_EXPONENT: u8 = 4;
+    /// Default period of reporting network statistics
+    pub const NETWORK_STATS_PERIOD: Duration = Duration::from_secs(60 * 60);
 }
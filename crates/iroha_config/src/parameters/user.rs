@@ -13,7 +13,7 @@ use std::{
     borrow::Cow,
     convert::Infallible,
     fmt::Debug,
-    num::{NonZeroU32, NonZeroUsize},
+    num::{NonZeroU32, NonZeroU64, NonZeroUsize},
     path::PathBuf,
 };
 
@@ -25,14 +25,20 @@ use iroha_config_base::{
     ReadConfig, WithOrigin,
 };
 use iroha_crypto::{PrivateKey, PublicKey};
-use iroha_data_model::{peer::Peer, ChainId, Level};
+use iroha_data_model::{
+    domain::DomainId,
+    isi::InstructionType,
+    peer::{Peer, PeerId},
+    ChainId, Level,
+};
 use iroha_primitives::{addr::SocketAddr, unique_vec::UniqueVec};
 use serde::Deserialize;
 use url::Url;
 
 use crate::{
-    kura::InitMode as KuraInitMode,
+    kura::{FsyncMode as KuraFsyncMode, InitMode as KuraInitMode, StoreMode as KuraStoreMode},
     logger::{Directives, Format as LoggerFormat},
+    network::Transport as NetworkTransport,
     parameters::{actual, defaults},
     snapshot::Mode as SnapshotMode,
 };
@@ -75,7 +81,13 @@ pub struct Root {
     queue: Queue,
     #[config(nested)]
     snapshot: Snapshot,
+    #[config(nested)]
+    retention: Retention,
+    #[config(nested)]
+    tiering: Tiering,
     telemetry: Option<Telemetry>,
+    network_stats: Option<NetworkStats>,
+    archive: Option<Archive>,
     #[config(nested)]
     dev_telemetry: DevTelemetry,
     #[config(nested)]
@@ -130,9 +142,13 @@ impl Root {
         let logger = self.logger;
         let queue = self.queue;
         let snapshot = self.snapshot;
+        let retention = self.retention;
+        let tiering = self.tiering;
         let dev_telemetry = self.dev_telemetry;
         let (torii, live_query_store) = self.torii.parse();
         let telemetry = self.telemetry.map(actual::Telemetry::from);
+        let network_stats = self.network_stats.map(actual::NetworkStats::from);
+        let archive = self.archive.map(actual::Archive::from);
 
         let sumeragi = self.sumeragi.parse();
 
@@ -159,7 +175,11 @@ impl Root {
             logger,
             queue: queue.parse(),
             snapshot,
+            retention,
+            tiering,
             telemetry,
+            network_stats,
+            archive,
             dev_telemetry,
         })
     }
@@ -186,6 +206,9 @@ impl From<Genesis> for actual::Genesis {
 pub struct Kura {
     #[config(env = "KURA_INIT_MODE", default)]
     pub init_mode: KuraInitMode,
+    /// Whether the blocks are stored on the disk or in memory only, see [`KuraStoreMode`].
+    #[config(env = "KURA_STORE_MODE", default)]
+    pub store_mode: KuraStoreMode,
     #[config(
         env = "KURA_STORE_DIR",
         default = "PathBuf::from(defaults::kura::STORE_DIR)"
@@ -196,6 +219,21 @@ pub struct Kura {
         default = "defaults::kura::BLOCKS_IN_MEMORY"
     )]
     pub blocks_in_memory: NonZeroUsize,
+    /// When the written blocks are flushed to the disk, see [`KuraFsyncMode`] for the trade-offs.
+    #[config(env = "KURA_FSYNC", default)]
+    pub fsync: KuraFsyncMode,
+    /// Period of flushing in the [`KuraFsyncMode::Periodic`] mode.
+    #[config(
+        env = "KURA_FSYNC_PERIOD_MS",
+        default = "defaults::kura::FSYNC_PERIOD.into()"
+    )]
+    pub fsync_period_ms: DurationMs,
+    /// Up to how many bytes of the consecutive blocks are written to the data file at once.
+    #[config(
+        env = "KURA_WRITE_BUFFER_SIZE",
+        default = "defaults::kura::WRITE_BUFFER_SIZE"
+    )]
+    pub write_buffer_size: Bytes<u64>,
     #[config(nested)]
     pub debug: KuraDebug,
 }
@@ -204,8 +242,12 @@ impl Kura {
     fn parse(self) -> actual::Kura {
         let Self {
             init_mode,
+            store_mode,
             store_dir,
             blocks_in_memory,
+            fsync,
+            fsync_period_ms: fsync_period,
+            write_buffer_size,
             debug:
                 KuraDebug {
                     output_new_blocks: debug_output_new_blocks,
@@ -214,8 +256,12 @@ impl Kura {
 
         actual::Kura {
             init_mode,
+            store_mode,
             store_dir,
             blocks_in_memory,
+            fsync,
+            fsync_period: fsync_period.get(),
+            write_buffer_size,
             debug_output_new_blocks,
         }
     }
@@ -229,6 +275,8 @@ pub struct KuraDebug {
 
 #[derive(Debug, Clone, Copy, ReadConfig)]
 pub struct Sumeragi {
+    #[config(nested)]
+    pub proposal_checks: SumeragiProposalChecks,
     #[config(nested)]
     pub debug: SumeragiDebug,
 }
@@ -256,19 +304,38 @@ impl Default for TrustedPeers {
 impl Sumeragi {
     fn parse(self) -> actual::Sumeragi {
         let Self {
-            debug: SumeragiDebug { force_soft_fork },
+            proposal_checks: SumeragiProposalChecks { max_transactions },
+            debug:
+                SumeragiDebug {
+                    force_soft_fork,
+                    verify_determinism,
+                },
         } = self;
 
         actual::Sumeragi {
+            proposal_max_transactions: max_transactions,
             debug_force_soft_fork: force_soft_fork,
+            debug_verify_determinism: verify_determinism,
         }
     }
 }
 
+/// Peer-local checks of the block proposals this peer abstains from voting for,
+/// see `iroha_core::sumeragi::proposal_check`
+#[derive(Debug, Copy, Clone, ReadConfig)]
+pub struct SumeragiProposalChecks {
+    /// Abstain from voting for blocks with more transactions
+    pub max_transactions: Option<NonZeroUsize>,
+}
+
 #[derive(Debug, Copy, Clone, ReadConfig)]
 pub struct SumeragiDebug {
     #[config(default)]
     pub force_soft_fork: bool,
+    /// Execute every block twice and compare the emitted events and transaction results
+    /// to detect nondeterministic execution. Doubles the execution time of blocks.
+    #[config(default)]
+    pub verify_determinism: bool,
 }
 
 #[derive(Debug, Clone, ReadConfig)]
@@ -280,6 +347,9 @@ pub struct Network {
     /// Will be gossiped to connected peers so that they can gossip it to other peers.
     #[config(env = "P2P_PUBLIC_ADDRESS")]
     pub public_address: WithOrigin<SocketAddr>,
+    /// Transport the peer prefers to connect over, see [`NetworkTransport`].
+    #[config(env = "P2P_TRANSPORT", default)]
+    pub transport: NetworkTransport,
     #[config(default = "defaults::network::BLOCK_GOSSIP_SIZE")]
     pub block_gossip_size: NonZeroU32,
     #[config(default = "defaults::network::BLOCK_GOSSIP_PERIOD.into()")]
@@ -291,6 +361,30 @@ pub struct Network {
     /// Duration of time after which connection with peer is terminated if peer is idle
     #[config(default = "defaults::network::IDLE_TIMEOUT.into()")]
     pub idle_timeout_ms: DurationMs,
+    /// Peers allowed to connect despite not being in the topology, e.g. the ones following the chain.
+    /// Connections of any other peer outside of the topology are rejected.
+    #[config(env = "P2P_OBSERVERS", default)]
+    pub observers: ObserverPeers,
+    /// Whether to log the rejected connections of the peers outside of the topology
+    #[config(
+        env = "P2P_LOG_REJECTED_PEERS",
+        default = "defaults::network::LOG_REJECTED_PEERS"
+    )]
+    pub log_rejected_peers: bool,
+}
+
+#[derive(Debug, Default, Deserialize)]
+pub struct ObserverPeers(Vec<PeerId>);
+
+impl FromEnvStr for ObserverPeers {
+    type Error = json5::Error;
+
+    fn from_env_str(value: Cow<'_, str>) -> std::result::Result<Self, Self::Error>
+    where
+        Self: Sized,
+    {
+        Ok(Self(json5::from_str(value.as_ref())?))
+    }
 }
 
 impl Network {
@@ -304,18 +398,24 @@ impl Network {
         let Self {
             address,
             public_address,
+            transport,
             block_gossip_size,
             block_gossip_period_ms: block_gossip_period,
             transaction_gossip_size,
             transaction_gossip_period_ms: transaction_gossip_period,
             idle_timeout_ms: idle_timeout,
+            observers,
+            log_rejected_peers,
         } = self;
 
         (
             actual::Network {
                 address,
                 public_address,
+                transport,
                 idle_timeout: idle_timeout.get(),
+                observers: observers.0.into_iter().collect(),
+                log_rejected_peers,
             },
             actual::BlockSync {
                 gossip_period: block_gossip_period.get(),
@@ -372,6 +472,10 @@ pub struct Logger {
     /// Output format
     #[config(env = "LOG_FORMAT", default)]
     pub format: LoggerFormat,
+    /// Report spans (e.g. of transaction execution or block commit) which took at least this long.
+    ///
+    /// Spans are reported regardless of the log level, as long as it's not above `INFO`.
+    pub slow_span_threshold_ms: Option<DurationMs>,
 }
 
 impl Logger {
@@ -384,6 +488,7 @@ impl Logger {
     ///     level: Level::DEBUG,
     ///     filter: Some("iroha_core=trace".parse().unwrap()),
     ///     format: <_>::default(),
+    ///     slow_span_threshold_ms: None,
     /// };
     ///
     /// assert_eq!(
@@ -449,6 +554,93 @@ impl From<Telemetry> for actual::Telemetry {
     }
 }
 
+/// Opt-in reporting of anonymized aggregate statistics, see `iroha_telemetry::stats`
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct NetworkStats {
+    url: Url,
+    #[serde(default)]
+    period_ms: NetworkStatsPeriod,
+}
+
+#[derive(Deserialize, Debug, Copy, Clone)]
+struct NetworkStatsPeriod(DurationMs);
+
+impl Default for NetworkStatsPeriod {
+    fn default() -> Self {
+        Self(DurationMs(defaults::telemetry::NETWORK_STATS_PERIOD))
+    }
+}
+
+impl From<NetworkStats> for actual::NetworkStats {
+    fn from(
+        NetworkStats {
+            url,
+            period_ms: NetworkStatsPeriod(DurationMs(period)),
+        }: NetworkStats,
+    ) -> Self {
+        Self { url, period }
+    }
+}
+
+/// Opt-in mirroring of the blocks and the snapshots to S3-compatible storage, see `iroha_core::archive`
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Archive {
+    endpoint: Url,
+    bucket: String,
+    #[serde(default)]
+    region: ArchiveRegion,
+    #[serde(default)]
+    prefix: String,
+    access_key_id: String,
+    secret_access_key: String,
+    #[serde(default)]
+    period_ms: ArchivePeriod,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+struct ArchiveRegion(String);
+
+impl Default for ArchiveRegion {
+    fn default() -> Self {
+        Self(defaults::archive::REGION.to_owned())
+    }
+}
+
+#[derive(Deserialize, Debug, Copy, Clone)]
+struct ArchivePeriod(DurationMs);
+
+impl Default for ArchivePeriod {
+    fn default() -> Self {
+        Self(DurationMs(defaults::archive::PERIOD))
+    }
+}
+
+impl From<Archive> for actual::Archive {
+    fn from(
+        Archive {
+            endpoint,
+            bucket,
+            region: ArchiveRegion(region),
+            prefix,
+            access_key_id,
+            secret_access_key,
+            period_ms: ArchivePeriod(DurationMs(period)),
+        }: Archive,
+    ) -> Self {
+        Self {
+            endpoint,
+            bucket,
+            region,
+            prefix,
+            access_key_id,
+            secret_access_key,
+            period,
+        }
+    }
+}
+
 #[derive(Debug, Clone, ReadConfig)]
 pub struct DevTelemetry {
     pub out_file: Option<WithOrigin<PathBuf>>,
@@ -467,6 +659,44 @@ pub struct Snapshot {
     pub store_dir: WithOrigin<PathBuf>,
 }
 
+/// Bounds of the indices the peer keeps to serve the queries, independent of the block storage.
+#[derive(Debug, Clone, Copy, ReadConfig)]
+pub struct Retention {
+    /// Changes of the asset quantities made more than this many blocks ago are dropped,
+    /// except for the latest one of every asset
+    pub asset_history_max_age_blocks: Option<NonZeroU64>,
+    /// Number of the latest changes kept for every asset
+    pub asset_history_max_entries: Option<NonZeroUsize>,
+    /// Hashes of the committed transactions are dropped from the index once their block is
+    /// this many blocks old and their time to live is over, so that they can't be resubmitted.
+    /// The transactions without a time to live are kept.
+    pub transactions_max_age_blocks: Option<NonZeroU64>,
+    /// Number of the latest events kept to resume the event streams
+    #[config(default = "defaults::retention::EVENTS_MAX_ENTRIES")]
+    pub events_max_entries: NonZeroUsize,
+    /// The indices are compacted in the background once per this many blocks,
+    /// the history of the assets changed by a block is bounded by its size right away
+    #[config(default = "defaults::retention::COMPACT_EVERY_BLOCKS")]
+    pub compact_every_blocks: NonZeroU64,
+}
+
+/// Tiering of the world state between memory and disk.
+///
+/// Only the metadata and tags of the accounts are tiered, as they make up most of the state
+/// of the chains with many dormant accounts.
+#[derive(Debug, Clone, ReadConfig)]
+pub struct Tiering {
+    /// The accounts unchanged for this many blocks are moved to the disk and read back on access,
+    /// the whole state is kept in memory unless set
+    pub dormant_after_blocks: Option<NonZeroU64>,
+    /// Directory of the accounts moved to the disk, cleared on start
+    #[config(
+        default = "PathBuf::from(defaults::tiering::STORE_DIR)",
+        env = "TIERING_STORE_DIR"
+    )]
+    pub store_dir: WithOrigin<PathBuf>,
+}
+
 #[derive(Debug, ReadConfig)]
 pub struct Torii {
     #[config(env = "API_ADDRESS")]
@@ -481,6 +711,35 @@ pub struct Torii {
     /// The upper limit of the number of live queries for a single user.
     #[config(default = "defaults::torii::QUERY_STORE_CAPACITY_PER_USER")]
     pub query_store_capacity_per_user: NonZeroUsize,
+    /// Once this many live queries are stored, no new queries are started, failing with `503 Service Unavailable`,
+    /// and the batches of the live ones are cut to `query_shed_fetch_size` and marked as shed,
+    /// so that the queries in flight are served first and the store drains under load.
+    pub query_shed_threshold: Option<NonZeroUsize>,
+    /// The size of the batches returned while the queries are shed.
+    #[config(default = "defaults::torii::QUERY_SHED_FETCH_SIZE")]
+    pub query_shed_fetch_size: NonZeroU64,
+    /// Compress the responses with `gzip` or `zstd` if the client accepts them.
+    #[config(default = "defaults::torii::COMPRESSION")]
+    pub compression: bool,
+    /// Additional endpoints restricted to subsets of domains, e.g. one per tenant of a shared peer.
+    #[config(default)]
+    pub virtual_hosts: Vec<ToriiVirtualHost>,
+    /// If set, the submitted transactions may only contain the instructions of these types.
+    pub allowed_instructions: Option<Vec<InstructionType>>,
+    /// The submitted transactions may not contain the instructions of these types.
+    #[config(default)]
+    pub denied_instructions: Vec<InstructionType>,
+    /// Hosts the webhooks of the on-chain subscriptions are delivered to; none unless set.
+    #[config(default)]
+    pub webhook_allowed_hosts: Vec<String>,
+}
+
+/// Endpoint exposing only the data of the `domains`
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ToriiVirtualHost {
+    pub address: SocketAddr,
+    pub domains: Vec<DomainId>,
 }
 
 impl Torii {
@@ -488,12 +747,30 @@ impl Torii {
         let torii = actual::Torii {
             address: self.address,
             max_content_len: self.max_content_len,
+            compression: self.compression,
+            virtual_hosts: self
+                .virtual_hosts
+                .into_iter()
+                .map(|virtual_host| actual::ToriiVirtualHost {
+                    address: virtual_host.address,
+                    domains: virtual_host.domains.into_iter().collect(),
+                })
+                .collect(),
+            instructions: actual::InstructionFilter {
+                allowed: self
+                    .allowed_instructions
+                    .map(|allowed| allowed.into_iter().collect()),
+                denied: self.denied_instructions.into_iter().collect(),
+            },
+            webhook_allowed_hosts: self.webhook_allowed_hosts.into_iter().collect(),
         };
 
         let query = actual::LiveQueryStore {
             idle_time: self.query_idle_time_ms.get(),
             capacity: self.query_store_capacity,
             capacity_per_user: self.query_store_capacity_per_user,
+            shed_threshold: self.query_shed_threshold,
+            shed_fetch_size: self.query_shed_fetch_size,
         };
 
         (torii, query)
@@ -510,6 +787,7 @@ mod tests {
             level: Level::INFO,
             filter: None,
             format: <_>::default(),
+            slow_span_threshold_ms: None,
         };
         assert_eq!(format!("{}", cfg.resolve_filter()), "info");
 
@@ -122,7 +122,10 @@ fn minimal_config_snapshot() {
                         path: "tests/fixtures/base.toml",
                     },
                 },
+                transport: Tcp,
                 idle_timeout: 60s,
+                observers: {},
+                log_rejected_peers: true,
             },
             genesis: Genesis {
                 public_key: PublicKey(
@@ -143,9 +146,17 @@ fn minimal_config_snapshot() {
                 max_content_len: Bytes(
                     16777216,
                 ),
+                compression: true,
+                virtual_hosts: [],
+                instructions: InstructionFilter {
+                    allowed: None,
+                    denied: {},
+                },
+                webhook_allowed_hosts: {},
             },
             kura: Kura {
                 init_mode: Strict,
+                store_mode: Disk,
                 store_dir: WithOrigin {
                     value: "./storage",
                     origin: Default {
@@ -153,10 +164,17 @@ fn minimal_config_snapshot() {
                     },
                 },
                 blocks_in_memory: 128,
+                fsync: Periodic,
+                fsync_period: 1s,
+                write_buffer_size: Bytes(
+                    4194304,
+                ),
                 debug_output_new_blocks: false,
             },
             sumeragi: Sumeragi {
+                proposal_max_transactions: None,
                 debug_force_soft_fork: false,
+                debug_verify_determinism: false,
             },
             block_sync: BlockSync {
                 gossip_period: 10s,
@@ -170,11 +188,14 @@ fn minimal_config_snapshot() {
                 idle_time: 10s,
                 capacity: 128,
                 capacity_per_user: 128,
+                shed_threshold: None,
+                shed_fetch_size: 10,
             },
             logger: Logger {
                 level: INFO,
                 filter: None,
                 format: Full,
+                slow_span_threshold_ms: None,
             },
             queue: Queue {
                 capacity: 65536,
@@ -193,7 +214,25 @@ fn minimal_config_snapshot() {
                     },
                 },
             },
+            retention: Retention {
+                asset_history_max_age_blocks: None,
+                asset_history_max_entries: None,
+                transactions_max_age_blocks: None,
+                events_max_entries: 10000,
+                compact_every_blocks: 100,
+            },
+            tiering: Tiering {
+                dormant_after_blocks: None,
+                store_dir: WithOrigin {
+                    value: "./storage/cold",
+                    origin: Default {
+                        id: ParameterId(tiering.store_dir),
+                    },
+                },
+            },
             telemetry: None,
+            network_stats: None,
+            archive: None,
             dev_telemetry: DevTelemetry {
                 out_file: None,
             },
@@ -208,8 +208,12 @@ pub fn build_state(rt: &tokio::runtime::Handle, account_id: &AccountId) -> State
     {
         let mut state_block = state.block();
 
-        state_block.world.parameters.transaction =
-            TransactionParameters::new(NonZeroU64::MAX, NonZeroU64::MAX);
+        state_block.world.parameters.transaction = TransactionParameters::new(
+            NonZeroU64::MAX,
+            NonZeroU64::MAX,
+            NonZeroU64::MAX,
+            NonZeroU64::MAX,
+        );
         state_block.world.parameters.executor.fuel = NonZeroU64::MAX;
         state_block.world.parameters.executor.memory = NonZeroU64::MAX;
 
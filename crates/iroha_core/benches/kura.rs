@@ -3,7 +3,10 @@
 use criterion::{criterion_group, criterion_main, Criterion};
 use iroha_config::{
     base::WithOrigin,
-    parameters::{actual::Kura as Config, defaults::kura::BLOCKS_IN_MEMORY},
+    parameters::{
+        actual::Kura as Config,
+        defaults::kura::{BLOCKS_IN_MEMORY, FSYNC_PERIOD, WRITE_BUFFER_SIZE},
+    },
 };
 use iroha_core::{
     block::*,
@@ -23,8 +26,12 @@ async fn measure_block_size_for_n_executors(n_executors: u32) {
     let dir = tempfile::tempdir().expect("Could not create tempfile.");
     let cfg = Config {
         init_mode: iroha_config::kura::InitMode::Strict,
+        store_mode: iroha_config::kura::StoreMode::Disk,
         debug_output_new_blocks: false,
         blocks_in_memory: BLOCKS_IN_MEMORY,
+        fsync: iroha_config::kura::FsyncMode::Periodic,
+        fsync_period: FSYNC_PERIOD,
+        write_buffer_size: WRITE_BUFFER_SIZE,
         store_dir: WithOrigin::inline(dir.path().to_path_buf()),
     };
     let chain_id = ChainId::from("00000000-0000-0000-0000-000000000000");
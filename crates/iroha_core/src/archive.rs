@@ -0,0 +1,803 @@
+//! Mirroring of the finalized blocks and the state snapshots to S3-compatible object storage.
+//!
+//! [`Archiver`] uploads every finalized block as a separate object `{prefix}blocks/{height}.scale`
+//! and every new snapshot as `{prefix}snapshots/{sha256}.data`. Once they are uploaded, it appends
+//! a manifest segment `{prefix}manifest/{index}.json` listing the digests of the new objects, so a
+//! segment never refers to an object which isn't uploaded yet. Segments are never overwritten:
+//! the archive only grows, and a peer whose chain doesn't match the archived one stops archiving.
+//!
+//! [`restore`] downloads the objects listed in the manifest, verifies them against it and writes
+//! them into an empty block store, so that a peer can be brought back without the disks of another one.
+
+use std::{
+    fs::{self, File},
+    io::{self, Read, Seek, Write},
+    num::NonZeroUsize,
+    path::{Path, PathBuf},
+    sync::Arc,
+    time::{Duration, SystemTime},
+};
+
+use hmac::{Hmac, Mac};
+use iroha_config::parameters::actual::{Archive as Config, Snapshot as SnapshotConfig};
+use iroha_crypto::HashOf;
+use iroha_data_model::block::{BlockHeader, SignedBlock};
+use iroha_futures::supervisor::{Child, OnShutdown, ShutdownSignal};
+use iroha_logger::prelude::*;
+use iroha_version::scale::{DecodeVersioned, EncodeVersioned};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use crate::{
+    kura::{self, BlockStore, Kura},
+    snapshot::{SNAPSHOT_FILE_NAME, SNAPSHOT_TMP_FILE_NAME},
+};
+
+/// Timeout of a single request to the storage
+const TIMEOUT: Duration = Duration::from_secs(60);
+/// Payload hash of the requests whose body isn't signed, as it's streamed
+const UNSIGNED_PAYLOAD: &str = "UNSIGNED-PAYLOAD";
+
+/// Errors of archiving and restoring.
+#[derive(Debug, displaydoc::Display, thiserror::Error)]
+pub enum Error {
+    /// Request to the storage failed
+    Http(#[from] ureq::Transport),
+    /// Storage responded with status {status} to the request of `{key}`
+    Status {
+        /// Key of the requested object
+        key: String,
+        /// HTTP status of the response
+        status: u16,
+    },
+    /// Failed to read or write the local files
+    Io(#[from] io::Error),
+    /// Failed to access the block store
+    Kura(#[from] kura::Error),
+    /// Blocking task panicked or was cancelled
+    Join(#[from] tokio::task::JoinError),
+    /// Object key `{0}` isn't a valid URL path, check the prefix
+    InvalidKey(String),
+    /// Manifest is malformed
+    Manifest(#[from] serde_json::Error),
+    /// Object `{0}` listed in the manifest is missing
+    Missing(String),
+    /// Object `{0}` doesn't match the manifest
+    Corrupted(String),
+    /// Archived block at height {0} doesn't match the local one
+    Diverged(NonZeroUsize),
+    /// Manifest segment `{0}` was appended by another peer in the meantime
+    Conflict(String),
+    /// Block store to restore into already contains {0} blocks
+    NotEmpty(u64),
+}
+
+/// Part of the manifest appended by a single upload
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+struct Segment {
+    /// Blocks archived by the upload, following the blocks of the previous segments
+    blocks: Vec<ArchivedBlock>,
+    /// Snapshot archived by the upload, superseding the one of the previous segments
+    snapshot: Option<Sha256Digest>,
+}
+
+/// Entry of an archived block in [`Segment`]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+struct ArchivedBlock {
+    /// Hash of the block
+    hash: HashOf<BlockHeader>,
+    /// Digest of the object the block is stored in
+    sha256: Sha256Digest,
+}
+
+/// Hex-encoded SHA-256 digest of an object
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+struct Sha256Digest(String);
+
+impl Sha256Digest {
+    /// Digest of `data`
+    fn of(data: &[u8]) -> Self {
+        Self(hex::encode(Sha256::digest(data)))
+    }
+
+    /// Digest of everything `reader` yields
+    fn of_reader(mut reader: impl Read) -> io::Result<Self> {
+        let mut hasher = Sha256::new();
+        io::copy(&mut reader, &mut hasher)?;
+        Ok(Self(hex::encode(hasher.finalize())))
+    }
+}
+
+/// Key of the object the block at `height` is stored in
+fn block_key(height: NonZeroUsize) -> String {
+    format!("blocks/{height:020}.scale")
+}
+
+/// Key of the object the snapshot with the `sha256` digest is stored in
+fn snapshot_key(sha256: &Sha256Digest) -> String {
+    format!("snapshots/{}.data", sha256.0)
+}
+
+/// Key of the manifest segment number `index`
+fn segment_key(index: u64) -> String {
+    format!("manifest/{index:020}.json")
+}
+
+/// Manifest assembled from all of its segments
+#[derive(Debug, Default)]
+struct Manifest {
+    /// Number of the segments
+    segments: u64,
+    /// Archived blocks, in the order of their heights starting from 1
+    blocks: Vec<ArchivedBlock>,
+    /// Latest archived snapshot
+    snapshot: Option<Sha256Digest>,
+}
+
+impl Manifest {
+    /// Download all the segments of the manifest
+    fn fetch(store: &impl ObjectStore) -> Result<Self, Error> {
+        let mut manifest = Self::default();
+        while let Some(data) = store.get_bytes(&segment_key(manifest.segments))? {
+            let segment: Segment = serde_json::from_slice(&data)?;
+            manifest.segments += 1;
+            manifest.blocks.extend(segment.blocks);
+            if segment.snapshot.is_some() {
+                manifest.snapshot = segment.snapshot;
+            }
+        }
+        Ok(manifest)
+    }
+
+    /// Check that `data` is the block archived at `height` which follows `previous`
+    fn verify_block(
+        &self,
+        height: NonZeroUsize,
+        data: &[u8],
+        previous: Option<HashOf<BlockHeader>>,
+    ) -> Result<SignedBlock, Error> {
+        let key = block_key(height);
+        let entry = &self.blocks[height.get() - 1];
+        if Sha256Digest::of(data) != entry.sha256 {
+            return Err(Error::Corrupted(key));
+        }
+        let block =
+            SignedBlock::decode_all_versioned(data).map_err(|_| Error::Corrupted(key.clone()))?;
+        if block.hash() != entry.hash || block.header().prev_block_hash != previous {
+            return Err(Error::Corrupted(key));
+        }
+        Ok(block)
+    }
+}
+
+/// Storage of the archived objects
+trait ObjectStore {
+    /// Upload the object of `length` bytes read from `body`.
+    ///
+    /// Unless `overwrite` is set, fails with [`Error::Conflict`] if the object already exists.
+    fn put(
+        &self,
+        key: &str,
+        body: &mut dyn Read,
+        length: u64,
+        overwrite: bool,
+    ) -> Result<(), Error>;
+
+    /// Download the object into `body`, `false` if there is no such object
+    fn get(&self, key: &str, body: &mut dyn Write) -> Result<bool, Error>;
+
+    /// Get the object, `None` if there is no such object
+    fn get_bytes(&self, key: &str) -> Result<Option<Vec<u8>>, Error> {
+        let mut data = Vec::new();
+        Ok(self.get(key, &mut data)?.then_some(data))
+    }
+}
+
+/// Bucket of S3-compatible storage, requests to which are signed with AWS Signature Version 4
+struct Bucket {
+    config: Config,
+    agent: ureq::Agent,
+}
+
+impl Bucket {
+    fn new(config: Config) -> Self {
+        Self {
+            config,
+            agent: ureq::AgentBuilder::new().timeout(TIMEOUT).build(),
+        }
+    }
+
+    fn status_error(&self, key: &str, status: u16) -> Error {
+        Error::Status {
+            key: format!("{}{key}", self.config.prefix),
+            status,
+        }
+    }
+
+    /// Request signed over the headers and the `payload_hash` of the body
+    fn request(
+        &self,
+        method: &str,
+        key: &str,
+        payload_hash: &str,
+        headers: &[(&str, &str)],
+    ) -> Result<ureq::Request, Error> {
+        let Config {
+            endpoint,
+            bucket,
+            region,
+            prefix,
+            access_key_id,
+            secret_access_key,
+            ..
+        } = &self.config;
+        let url = endpoint
+            .join(&format!("{bucket}/{prefix}{key}"))
+            .map_err(|_| Error::InvalidKey(format!("{prefix}{key}")))?;
+        let host = match url.port() {
+            Some(port) => format!("{}:{port}", url.host_str().unwrap_or_default()),
+            None => url.host_str().unwrap_or_default().to_owned(),
+        };
+        // `YYYYMMDDTHHMMSSZ`
+        let timestamp: String = humantime::format_rfc3339_seconds(SystemTime::now())
+            .to_string()
+            .chars()
+            .filter(|c| !matches!(c, '-' | ':'))
+            .collect();
+
+        let mut signed = vec![
+            ("host", host.as_str()),
+            ("x-amz-content-sha256", payload_hash),
+            ("x-amz-date", timestamp.as_str()),
+        ];
+        signed.extend_from_slice(headers);
+        signed.sort_unstable();
+        let canonical_headers: String = signed
+            .iter()
+            .map(|(name, value)| format!("{name}:{value}\n"))
+            .collect();
+        let signed_headers = signed
+            .iter()
+            .map(|(name, _)| *name)
+            .collect::<Vec<_>>()
+            .join(";");
+        let canonical_request = format!(
+            "{method}\n{path}\n\n{canonical_headers}\n{signed_headers}\n{payload_hash}",
+            path = url.path(),
+        );
+        let scope = format!("{}/{region}/s3/aws4_request", &timestamp[..8]);
+        let string_to_sign = format!(
+            "AWS4-HMAC-SHA256\n{timestamp}\n{scope}\n{}",
+            Sha256Digest::of(canonical_request.as_bytes()).0
+        );
+        let signature = hex::encode(hmac(
+            &signing_key(secret_access_key, &timestamp[..8], region, "s3"),
+            string_to_sign.as_bytes(),
+        ));
+
+        let request = signed.iter().filter(|(name, _)| *name != "host").fold(
+            self.agent.request(method, url.as_str()),
+            |request, (name, value)| request.set(name, value),
+        );
+        Ok(request.set(
+            "authorization",
+            &format!(
+                "AWS4-HMAC-SHA256 Credential={access_key_id}/{scope}, SignedHeaders={signed_headers}, Signature={signature}"
+            ),
+        ))
+    }
+}
+
+impl ObjectStore for Bucket {
+    fn put(
+        &self,
+        key: &str,
+        body: &mut dyn Read,
+        length: u64,
+        overwrite: bool,
+    ) -> Result<(), Error> {
+        let length = length.to_string();
+        let mut headers = vec![("content-length", length.as_str())];
+        if !overwrite {
+            headers.push(("if-none-match", "*"));
+        }
+        match self
+            .request("PUT", key, UNSIGNED_PAYLOAD, &headers)?
+            .send(body)
+        {
+            Ok(_) => Ok(()),
+            Err(ureq::Error::Status(409 | 412, _)) => Err(Error::Conflict(key.to_owned())),
+            Err(ureq::Error::Status(status, _)) => Err(self.status_error(key, status)),
+            Err(ureq::Error::Transport(error)) => Err(error.into()),
+        }
+    }
+
+    fn get(&self, key: &str, body: &mut dyn Write) -> Result<bool, Error> {
+        let payload_hash = Sha256Digest::of(&[]).0;
+        match self.request("GET", key, &payload_hash, &[])?.call() {
+            Ok(response) => {
+                io::copy(&mut response.into_reader(), body)?;
+                Ok(true)
+            }
+            Err(ureq::Error::Status(404, _)) => Ok(false),
+            Err(ureq::Error::Status(status, _)) => Err(self.status_error(key, status)),
+            Err(ureq::Error::Transport(error)) => Err(error.into()),
+        }
+    }
+}
+
+fn hmac(key: &[u8], data: &[u8]) -> Vec<u8> {
+    let mut mac = Hmac::<Sha256>::new_from_slice(key).expect("HMAC accepts keys of any size");
+    mac.update(data);
+    mac.finalize().into_bytes().to_vec()
+}
+
+/// Key the requests to the `service` are signed with on the `date` (`YYYYMMDD`)
+fn signing_key(secret_access_key: &str, date: &str, region: &str, service: &str) -> Vec<u8> {
+    let key = hmac(
+        format!("AWS4{secret_access_key}").as_bytes(),
+        date.as_bytes(),
+    );
+    let key = hmac(&key, region.as_bytes());
+    let key = hmac(&key, service.as_bytes());
+    hmac(&key, b"aws4_request")
+}
+
+/// Actor uploading the finalized blocks and the latest snapshot to the storage.
+pub struct Archiver {
+    bucket: Arc<Bucket>,
+    kura: Arc<Kura>,
+    /// Path to the snapshot file, if the snapshots are made
+    snapshot_path: Option<PathBuf>,
+    period: Duration,
+}
+
+impl Archiver {
+    /// Create the actor archiving the blocks of `kura` and the snapshots made according to `snapshot`.
+    pub fn new(config: Config, snapshot: &SnapshotConfig, kura: Arc<Kura>) -> Self {
+        let snapshot_path =
+            matches!(snapshot.mode, iroha_config::snapshot::Mode::ReadWrite).then(|| {
+                snapshot
+                    .store_dir
+                    .resolve_relative_path()
+                    .join(SNAPSHOT_FILE_NAME)
+            });
+        Self {
+            period: config.period,
+            bucket: Arc::new(Bucket::new(config)),
+            kura,
+            snapshot_path,
+        }
+    }
+
+    /// Start the actor.
+    pub fn start(self, shutdown_signal: ShutdownSignal) -> Child {
+        Child::new(tokio::spawn(self.run(shutdown_signal)), OnShutdown::Abort)
+    }
+
+    async fn run(self, shutdown_signal: ShutdownSignal) {
+        let mut interval = tokio::time::interval(self.period);
+        interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+        // Progress of the archive, downloaded on the first upload and after a failed one
+        let mut progress: Option<Progress> = None;
+
+        loop {
+            tokio::select! {
+                _ = interval.tick() => {
+                    let bucket = Arc::clone(&self.bucket);
+                    let kura = Arc::clone(&self.kura);
+                    let snapshot_path = self.snapshot_path.clone();
+                    let previous = progress.take();
+                    let result = tokio::task::spawn_blocking(move || {
+                        archive(&*bucket, &kura, snapshot_path.as_deref(), previous)
+                    })
+                    .await
+                    .map_err(Error::from)
+                    .and_then(|result| result);
+                    match result {
+                        Ok(archived) => {
+                            debug!(blocks = archived.blocks.len(), "Archive is up to date");
+                            progress = Some(archived);
+                        }
+                        Err(error @ Error::Diverged(_)) => {
+                            error!(%error, "Archive belongs to another chain, not archiving");
+                        }
+                        Err(error) => warn!(%error, "Failed to archive blocks"),
+                    }
+                }
+                () = shutdown_signal.receive() => {
+                    debug!("Shutting down archiver");
+                    break;
+                }
+            }
+        }
+    }
+}
+
+/// State of the archive as seen by the [`Archiver`]
+#[derive(Debug)]
+struct Progress {
+    /// Number of the manifest segments
+    segments: u64,
+    /// Hashes of the archived blocks
+    blocks: Vec<HashOf<BlockHeader>>,
+    /// Number of the archived blocks checked to match the local chain
+    verified: usize,
+    /// Latest archived snapshot
+    snapshot: Option<Sha256Digest>,
+    /// Modification time of the snapshot file when it was last archived
+    snapshot_modified: Option<SystemTime>,
+}
+
+impl Progress {
+    fn fetch(store: &impl ObjectStore) -> Result<Self, Error> {
+        let manifest = Manifest::fetch(store)?;
+        Ok(Self {
+            segments: manifest.segments,
+            blocks: manifest
+                .blocks
+                .into_iter()
+                .map(|entry| entry.hash)
+                .collect(),
+            verified: 0,
+            snapshot: manifest.snapshot,
+            snapshot_modified: None,
+        })
+    }
+}
+
+/// Upload the blocks and the snapshot which aren't archived yet and append them to the manifest.
+///
+/// Returns the progress of the archive once it's up to date.
+fn archive(
+    store: &impl ObjectStore,
+    kura: &Kura,
+    snapshot_path: Option<&Path>,
+    progress: Option<Progress>,
+) -> Result<Progress, Error> {
+    let mut progress = match progress {
+        Some(progress) => progress,
+        None => Progress::fetch(store)?,
+    };
+
+    // The top block might still be replaced by a soft fork
+    let finalized = kura.blocks_count().saturating_sub(1);
+    // Archive is only appended to by the peers whose chain matches the archived one
+    let verifiable = progress.blocks.len().min(finalized);
+    for height in (progress.verified + 1..=verifiable).filter_map(NonZeroUsize::new) {
+        if kura.get_block_hash(height) != Some(progress.blocks[height.get() - 1]) {
+            return Err(Error::Diverged(height));
+        }
+    }
+    progress.verified = verifiable;
+    // Archive is ahead if this peer lags behind or its block store is restored from a snapshot
+    if progress.blocks.len() > finalized {
+        return Ok(progress);
+    }
+
+    let mut segment = Segment::default();
+    for height in (progress.blocks.len() + 1..=finalized).filter_map(NonZeroUsize::new) {
+        let Some(block) = kura.get_block(height) else {
+            break;
+        };
+        let data = block.encode_versioned();
+        segment.blocks.push(ArchivedBlock {
+            hash: block.hash(),
+            sha256: Sha256Digest::of(&data),
+        });
+        store.put(
+            &block_key(height),
+            &mut data.as_slice(),
+            data.len() as u64,
+            true,
+        )?;
+    }
+
+    let mut snapshot_modified = progress.snapshot_modified;
+    if let Some(mut file) = snapshot_path.and_then(|path| File::open(path).ok()) {
+        let metadata = file.metadata()?;
+        let modified = metadata.modified().ok();
+        if modified.is_none() || modified != progress.snapshot_modified {
+            let sha256 = Sha256Digest::of_reader(&mut file)?;
+            if progress.snapshot.as_ref() != Some(&sha256) {
+                file.rewind()?;
+                store.put(&snapshot_key(&sha256), &mut file, metadata.len(), true)?;
+                segment.snapshot = Some(sha256);
+            }
+            snapshot_modified = modified;
+        }
+    }
+
+    if segment != Segment::default() {
+        let data = serde_json::to_vec_pretty(&segment)?;
+        store.put(
+            &segment_key(progress.segments),
+            &mut data.as_slice(),
+            data.len() as u64,
+            false,
+        )?;
+        progress.segments += 1;
+        progress.verified += segment.blocks.len();
+        progress
+            .blocks
+            .extend(segment.blocks.iter().map(|entry| entry.hash));
+        info!(
+            blocks = progress.blocks.len(),
+            "Uploaded the new blocks to the archive"
+        );
+        if segment.snapshot.is_some() {
+            progress.snapshot = segment.snapshot;
+        }
+    }
+    progress.snapshot_modified = snapshot_modified;
+
+    Ok(progress)
+}
+
+/// Restore the block store in `store_dir` and the snapshot in `snapshot_dir` from the archive.
+///
+/// Returns the number of restored blocks.
+///
+/// # Errors
+/// Fails if the block store isn't empty, the storage can't be reached
+/// or the archived objects don't match the manifest.
+pub fn restore(config: Config, store_dir: &Path, snapshot_dir: &Path) -> Result<usize, Error> {
+    restore_from(&Bucket::new(config), store_dir, snapshot_dir)
+}
+
+fn restore_from(
+    store: &impl ObjectStore,
+    store_dir: &Path,
+    snapshot_dir: &Path,
+) -> Result<usize, Error> {
+    let mut block_store = BlockStore::new(store_dir);
+    block_store.create_files_if_they_do_not_exist()?;
+    let stored = block_store.read_index_count()?;
+    if stored > 0 {
+        return Err(Error::NotEmpty(stored));
+    }
+
+    let manifest = Manifest::fetch(store)?;
+    if manifest.segments == 0 {
+        return Err(Error::Missing(segment_key(0)));
+    }
+
+    let mut previous = None;
+    for height in (1..=manifest.blocks.len()).filter_map(NonZeroUsize::new) {
+        let key = block_key(height);
+        let data = store.get_bytes(&key)?.ok_or(Error::Missing(key))?;
+        let block = manifest.verify_block(height, &data, previous)?;
+        block_store.append_block_to_chain(&block)?;
+        previous = Some(block.hash());
+    }
+    block_store.sync()?;
+
+    if let Some(sha256) = &manifest.snapshot {
+        let key = snapshot_key(sha256);
+        fs::create_dir_all(snapshot_dir)?;
+        let tmp_path = snapshot_dir.join(SNAPSHOT_TMP_FILE_NAME);
+        let mut file = File::options()
+            .read(true)
+            .write(true)
+            .create(true)
+            .truncate(true)
+            .open(&tmp_path)?;
+        if !store.get(&key, &mut file)? {
+            return Err(Error::Missing(key));
+        }
+        file.rewind()?;
+        if Sha256Digest::of_reader(&mut file)? != *sha256 {
+            return Err(Error::Corrupted(key));
+        }
+        file.sync_all()?;
+        fs::rename(tmp_path, snapshot_dir.join(SNAPSHOT_FILE_NAME))?;
+    }
+
+    Ok(manifest.blocks.len())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{collections::BTreeMap, sync::Mutex};
+
+    use iroha_crypto::{KeyPair, PrivateKey};
+
+    use super::*;
+    use crate::block::ValidBlock;
+
+    /// Storage keeping the objects in memory
+    #[derive(Default)]
+    struct MemoryStore(Mutex<BTreeMap<String, Vec<u8>>>);
+
+    impl ObjectStore for MemoryStore {
+        fn put(
+            &self,
+            key: &str,
+            body: &mut dyn Read,
+            _length: u64,
+            overwrite: bool,
+        ) -> Result<(), Error> {
+            let mut objects = self.0.lock().unwrap();
+            if !overwrite && objects.contains_key(key) {
+                return Err(Error::Conflict(key.to_owned()));
+            }
+            let mut data = Vec::new();
+            body.read_to_end(&mut data)?;
+            objects.insert(key.to_owned(), data);
+            Ok(())
+        }
+
+        fn get(&self, key: &str, body: &mut dyn Write) -> Result<bool, Error> {
+            let objects = self.0.lock().unwrap();
+            let Some(data) = objects.get(key) else {
+                return Ok(false);
+            };
+            body.write_all(data)?;
+            Ok(true)
+        }
+    }
+
+    /// Kura holding a chain of `count` blocks, which are told apart from other chains by `creation_time_ms`
+    fn kura_with_blocks(
+        leader_private_key: &PrivateKey,
+        count: u64,
+        creation_time_ms: u64,
+    ) -> Arc<Kura> {
+        let kura = Kura::blank_kura_for_testing();
+        for height in 1..=count {
+            push_block(&kura, leader_private_key, height, creation_time_ms);
+        }
+        kura
+    }
+
+    fn push_block(
+        kura: &Kura,
+        leader_private_key: &PrivateKey,
+        height: u64,
+        creation_time_ms: u64,
+    ) {
+        let prev_block_hash =
+            NonZeroUsize::new(kura.blocks_count()).and_then(|height| kura.get_block_hash(height));
+        let block: SignedBlock =
+            ValidBlock::new_dummy_and_modify_header(leader_private_key, |header| {
+                header.height = height.try_into().unwrap();
+                header.prev_block_hash = prev_block_hash;
+                header.creation_time_ms = creation_time_ms;
+            })
+            .into();
+        kura.store_block(block);
+    }
+
+    #[test]
+    fn signing_key_matches_reference() {
+        // https://docs.aws.amazon.com/general/latest/gr/signature-v4-examples.html
+        let key = signing_key(
+            "wJalrXUtnFEMI/K7MDENG+bPxRfiCYEXAMPLEKEY",
+            "20120215",
+            "us-east-1",
+            "iam",
+        );
+
+        assert_eq!(
+            hex::encode(key),
+            "f4780e2d9f65fa895f9c67b32ce1baf0b0d8a43505a000a1a9e090d414db404d"
+        );
+    }
+
+    #[test]
+    fn tampered_block_is_not_restored() {
+        let (_, leader_private_key) = KeyPair::random().into_parts();
+        let block: SignedBlock = ValidBlock::new_dummy(&leader_private_key).into();
+        let data = block.encode_versioned();
+        let height = NonZeroUsize::new(1).unwrap();
+        let manifest = Manifest {
+            segments: 1,
+            blocks: vec![ArchivedBlock {
+                hash: block.hash(),
+                sha256: Sha256Digest::of(&data),
+            }],
+            snapshot: None,
+        };
+
+        assert_eq!(
+            manifest.verify_block(height, &data, None).unwrap().hash(),
+            block.hash()
+        );
+        let mut tampered = data.clone();
+        *tampered.last_mut().unwrap() ^= 1;
+        assert!(matches!(
+            manifest.verify_block(height, &tampered, None),
+            Err(Error::Corrupted(_))
+        ));
+        assert!(matches!(
+            manifest.verify_block(height, &data, Some(block.hash())),
+            Err(Error::Corrupted(_))
+        ));
+    }
+
+    #[test]
+    fn archived_blocks_and_snapshot_are_restored() {
+        let (_, leader_private_key) = KeyPair::random().into_parts();
+        let store = MemoryStore::default();
+        let kura = kura_with_blocks(&leader_private_key, 3, 0);
+        let dir = tempfile::tempdir().unwrap();
+        let snapshot_path = dir.path().join(SNAPSHOT_FILE_NAME);
+        fs::write(&snapshot_path, b"state").unwrap();
+
+        let progress = archive(&store, &kura, Some(&snapshot_path), None).unwrap();
+        assert_eq!(progress.segments, 1);
+        assert_eq!(progress.blocks.len(), 2);
+        // Only the new block is appended
+        push_block(&kura, &leader_private_key, 4, 0);
+        let progress = archive(&store, &kura, Some(&snapshot_path), Some(progress)).unwrap();
+        assert_eq!(progress.segments, 2);
+        let segment: Segment =
+            serde_json::from_slice(&store.get_bytes(&segment_key(1)).unwrap().unwrap()).unwrap();
+        assert_eq!(segment.blocks.len(), 1);
+        assert_eq!(segment.snapshot, None);
+        // Nothing changed, nothing is uploaded
+        let progress = archive(&store, &kura, Some(&snapshot_path), Some(progress)).unwrap();
+        assert_eq!(progress.segments, 2);
+
+        let store_dir = dir.path().join("blocks");
+        let snapshot_dir = dir.path().join("restored");
+        assert_eq!(restore_from(&store, &store_dir, &snapshot_dir).unwrap(), 3);
+        let block_store = BlockStore::new(&store_dir);
+        assert_eq!(block_store.read_index_count().unwrap(), 3);
+        assert_eq!(
+            block_store.read_block_hashes(0, 3).unwrap(),
+            (1..=3)
+                .map(|height| kura
+                    .get_block_hash(NonZeroUsize::new(height).unwrap())
+                    .unwrap())
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(
+            fs::read(snapshot_dir.join(SNAPSHOT_FILE_NAME)).unwrap(),
+            b"state"
+        );
+        assert!(matches!(
+            restore_from(&store, &store_dir, &snapshot_dir),
+            Err(Error::NotEmpty(3))
+        ));
+    }
+
+    #[test]
+    fn archive_is_only_appended_to_by_matching_chain() {
+        let (_, leader_private_key) = KeyPair::random().into_parts();
+        let store = MemoryStore::default();
+        archive(
+            &store,
+            &kura_with_blocks(&leader_private_key, 4, 0),
+            None,
+            None,
+        )
+        .unwrap();
+
+        // Lagging peer leaves the archive intact
+        let progress = archive(
+            &store,
+            &kura_with_blocks(&leader_private_key, 2, 0),
+            None,
+            None,
+        )
+        .unwrap();
+        assert_eq!(progress.blocks.len(), 3);
+        assert_eq!(Manifest::fetch(&store).unwrap().blocks.len(), 3);
+
+        // Peer of another chain doesn't append to the archive
+        assert!(matches!(
+            archive(
+                &store,
+                &kura_with_blocks(&leader_private_key, 5, 1),
+                None,
+                None
+            ),
+            Err(Error::Diverged(_))
+        ));
+        let manifest = Manifest::fetch(&store).unwrap();
+        assert_eq!(manifest.segments, 1);
+        assert_eq!(manifest.blocks.len(), 3);
+    }
+}
@@ -365,8 +365,12 @@ mod valid {
 
     use commit::CommittedBlock;
     use iroha_data_model::{
-        account::AccountId, events::pipeline::PipelineEventBox, isi::InstructionBox,
-        prelude::Executable, ChainId,
+        account::AccountId,
+        events::pipeline::PipelineEventBox,
+        isi::InstructionBox,
+        prelude::Executable,
+        transaction::error::{TransactionLimitError, TransactionRejectionReason},
+        ChainId,
     };
 
     use super::*;
@@ -620,11 +624,13 @@ mod valid {
             };
 
             for tx in block.external_transactions() {
-                if state
-                    .transactions()
-                    .get(&tx.hash())
-                    // In case of soft-fork transaction is check if it was added at the same height as candidate block
-                    .is_some_and(|height| height.get() < expected_block_height)
+                // NOTE: expired transactions are rejected on execution, as their hashes may be pruned from the index
+                if !AcceptedTransaction::is_expired(tx, block_creation_time)
+                    && state
+                        .transactions()
+                        .get(&tx.hash())
+                        // In case of soft-fork transaction is check if it was added at the same height as candidate block
+                        .is_some_and(|height| height.get() < expected_block_height)
                 {
                     return Err(BlockValidationError::HasCommittedTransactions);
                 }
@@ -668,6 +674,25 @@ mod valid {
             block: &mut SignedBlock,
             state_block: &mut StateBlock<'_>,
         ) {
+            let _span =
+                iroha_logger::info_span!("block_validation", height=%block.header().height())
+                    .entered();
+            state_block.activate_peer_changes(block.header().height().get());
+            state_block.expire_grants(block.header().creation_time());
+
+            let max_heavy_instructions = if block.header().is_genesis() {
+                u64::MAX
+            } else {
+                state_block
+                    .world
+                    .parameters
+                    .block
+                    .max_heavy_instructions
+                    .get()
+            };
+            let mut heavy_instructions = 0_u64;
+            let block_creation_time = block.header().creation_time();
+
             let mut wasm_cache = WasmCache::new();
             let (mut hashes, mut results) = block.external_transactions().cloned().fold(
                 (Vec::new(), Vec::new()),
@@ -676,8 +701,37 @@ mod valid {
                     // FIXME: cloning is unnecessary; use Cow?
                     let accepted_tx = AcceptedTransaction::new_unchecked(tx.clone());
 
-                    let (hash, result) =
-                        state_block.validate_transaction(accepted_tx, &mut wasm_cache);
+                    let tx_heavy_instructions = accepted_tx.heavy_instructions();
+                    let (hash, result) = if AcceptedTransaction::is_expired(
+                        accepted_tx.as_ref(),
+                        block_creation_time,
+                    ) {
+                        (
+                            accepted_tx.as_ref().hash_as_entrypoint(),
+                            Err(TransactionRejectionReason::LimitCheck(
+                                TransactionLimitError {
+                                    reason: "Transaction's time to live is over by the block creation".to_owned(),
+                                },
+                            )),
+                        )
+                    } else if heavy_instructions
+                        .saturating_add(tx_heavy_instructions)
+                        <= max_heavy_instructions
+                    {
+                        heavy_instructions += tx_heavy_instructions;
+                        state_block.validate_transaction(accepted_tx, &mut wasm_cache)
+                    } else {
+                        (
+                            accepted_tx.as_ref().hash_as_entrypoint(),
+                            Err(TransactionRejectionReason::LimitCheck(
+                                TransactionLimitError {
+                                    reason: format!(
+                                        "Too many heavyweight instructions in block, max number is {max_heavy_instructions}"
+                                    ),
+                                },
+                            )),
+                        )
+                    };
 
                     match &result {
                         Err(reason) => {
@@ -1324,6 +1378,97 @@ mod tests {
         assert_eq!(valid_block.as_ref().errors().next().unwrap().0, 1);
     }
 
+    #[tokio::test]
+    async fn heavy_instructions_over_block_limit_are_rejected() {
+        let chain_id = ChainId::from("00000000-0000-0000-0000-000000000000");
+
+        // Predefined world state
+        let (alice_id, alice_keypair) = gen_account_in("wonderland");
+        let account = Account::new(alice_id.clone()).build(&alice_id);
+        let domain_id = "wonderland".parse().expect("Valid");
+        let domain = Domain::new(domain_id).build(&alice_id);
+        let world = World::with([domain], [account], []);
+        let kura = Kura::blank_kura_for_testing();
+        let query_handle = LiveQueryStore::start_test();
+        let state = State::new(world, kura, query_handle);
+        {
+            let mut world = state.world.block();
+            world.parameters.block.max_heavy_instructions = nonzero_ext::nonzero!(1_u64);
+            world.commit();
+        }
+
+        // Each smart contract counts as a heavyweight instruction
+        let transactions = [0_u8, 1]
+            .map(|byte| {
+                let tx = TransactionBuilder::new(chain_id.clone(), alice_id.clone())
+                    .with_wasm(WasmSmartContract::from_compiled(vec![byte]))
+                    .sign(alice_keypair.private_key());
+                AcceptedTransaction::new_unchecked(tx)
+            })
+            .to_vec();
+        let unverified_block = BlockBuilder::new(transactions)
+            .chain(0, state.view().latest_block().as_deref())
+            .sign(alice_keypair.private_key())
+            .unpack(|_| {});
+        // The limit doesn't apply to the genesis block
+        let mut header = unverified_block.header();
+        header.height = nonzero_ext::nonzero!(2_u64);
+        let unverified_block = unverified_block.update_header(header, alice_keypair.private_key());
+
+        let mut state_block = state.block(unverified_block.header);
+        let valid_block = unverified_block
+            .validate_and_record_transactions(&mut state_block)
+            .unpack(|_| {});
+        state_block.commit();
+
+        // The 2nd transaction doesn't fit into the block
+        let (idx, reason) = valid_block.as_ref().errors().last().unwrap();
+        assert_eq!(idx, 1);
+        assert!(matches!(reason, TransactionRejectionReason::LimitCheck(_)));
+    }
+
+    #[tokio::test]
+    async fn expired_transactions_are_rejected() {
+        let chain_id = ChainId::from("00000000-0000-0000-0000-000000000000");
+
+        // Predefined world state
+        let (alice_id, alice_keypair) = gen_account_in("wonderland");
+        let account = Account::new(alice_id.clone()).build(&alice_id);
+        let domain_id = "wonderland".parse().expect("Valid");
+        let domain = Domain::new(domain_id).build(&alice_id);
+        let world = World::with([domain], [account], []);
+        let kura = Kura::blank_kura_for_testing();
+        let query_handle = LiveQueryStore::start_test();
+        let state = State::new(world, kura, query_handle);
+
+        // The hashes of such transactions may be pruned from the index, so their resubmission is rejected on execution
+        let now = std::time::SystemTime::now()
+            .duration_since(std::time::SystemTime::UNIX_EPOCH)
+            .unwrap();
+        let mut tx =
+            TransactionBuilder::new(chain_id.clone(), alice_id).with_instructions([Log::new(
+                iroha_data_model::Level::INFO,
+                "expired".to_owned(),
+            )]);
+        tx.set_creation_time(now - Duration::from_secs(10))
+            .set_ttl(Duration::from_secs(1));
+        let tx = tx.sign(alice_keypair.private_key());
+        let unverified_block = BlockBuilder::new(vec![AcceptedTransaction::new_unchecked(tx)])
+            .chain(0, state.view().latest_block().as_deref())
+            .sign(alice_keypair.private_key())
+            .unpack(|_| {});
+
+        let mut state_block = state.block(unverified_block.header);
+        let valid_block = unverified_block
+            .validate_and_record_transactions(&mut state_block)
+            .unpack(|_| {});
+        state_block.commit();
+
+        let (idx, reason) = valid_block.as_ref().errors().next().unwrap();
+        assert_eq!(idx, 0);
+        assert!(matches!(reason, TransactionRejectionReason::LimitCheck(_)));
+    }
+
     #[tokio::test]
     async fn tx_order_same_in_validation_and_revalidation() {
         let chain_id = ChainId::from("00000000-0000-0000-0000-000000000000");
@@ -0,0 +1,146 @@
+//! This module contains the actor compacting the indices of the [`State`] in the background,
+//! as bounded by the [`Retention`] of the peer.
+use std::{collections::BTreeMap, num::NonZeroUsize, sync::Arc, time::Duration};
+
+use iroha_config::parameters::actual::Retention;
+use iroha_crypto::HashOf;
+use iroha_data_model::transaction::SignedTransaction;
+use iroha_futures::supervisor::{Child, OnShutdown, ShutdownSignal};
+use iroha_logger::prelude::*;
+
+use crate::{
+    kura::Kura,
+    state::{State, StateReadOnly},
+};
+
+/// Actor compacting the indices once per [`Retention::compact_every_blocks`].
+///
+/// The indices are scanned off the block application, which only drops the entries found by the scan.
+pub struct Compactor {
+    state: Arc<State>,
+    kura: Arc<Kura>,
+    retention: Retention,
+    /// Height of the state at the latest compaction
+    compacted_at: usize,
+    /// Height of the next block whose transactions are to be dropped from the index once expired.
+    ///
+    /// Starts from the genesis after a restart, since the index restored from a snapshot
+    /// is compacted up to an unknown height.
+    next_height: usize,
+    /// Hashes of the transactions of the blocks before `next_height`, keyed by the time their time to live is over at
+    expiring: BTreeMap<Duration, Vec<HashOf<SignedTransaction>>>,
+}
+
+impl Compactor {
+    /// Interval at which the height of the state is checked
+    const POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+    /// Create from [`Retention`].
+    ///
+    /// Might return [`None`] if none of the indices are bounded.
+    pub fn from_config(retention: Retention, state: Arc<State>, kura: Arc<Kura>) -> Option<Self> {
+        let bounded = retention.asset_history_max_age_blocks.is_some()
+            || retention.asset_history_max_entries.is_some()
+            || retention.transactions_max_age_blocks.is_some();
+
+        bounded.then(|| Self {
+            state,
+            kura,
+            retention,
+            compacted_at: 0,
+            next_height: 1,
+            expiring: BTreeMap::new(),
+        })
+    }
+
+    /// Start the actor.
+    pub fn start(self, shutdown_signal: ShutdownSignal) -> Child {
+        Child::new(tokio::spawn(self.run(shutdown_signal)), OnShutdown::Abort)
+    }
+
+    async fn run(mut self, shutdown_signal: ShutdownSignal) {
+        let mut poll = tokio::time::interval(Self::POLL_INTERVAL);
+        poll.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+        let compact_every = usize::try_from(self.retention.compact_every_blocks.get())
+            .expect("INTERNAL BUG: Blockchain height exceeds usize::MAX");
+
+        loop {
+            tokio::select! {
+                _ = poll.tick() => {
+                    let height = self.state.view().height();
+                    if height < self.compacted_at.saturating_add(compact_every) {
+                        continue;
+                    }
+                    // Offload the compaction into blocking thread
+                    let handle = tokio::task::spawn_blocking(move || {
+                        self.compact(height);
+                        self
+                    });
+                    match handle.await {
+                        Ok(compactor) => self = compactor,
+                        Err(panic) => {
+                            error!(%panic, "Task panicked during compaction of the indices");
+                            break;
+                        }
+                    }
+                },
+                () = shutdown_signal.receive() => break,
+            }
+            tokio::task::yield_now().await;
+        }
+    }
+
+    fn compact(&mut self, height: usize) {
+        self.state.compact_asset_history();
+        self.compact_transactions(height);
+        self.compacted_at = height;
+    }
+
+    /// Drop the hashes of the transactions whose time to live is over from the index,
+    /// once their block is [`Retention::transactions_max_age_blocks`] old.
+    ///
+    /// The blocks with such transactions are valid, but the transactions are rejected,
+    /// so the index doesn't have to reject their resubmission.
+    fn compact_transactions(&mut self, height: usize) {
+        let Some(max_age) = self.retention.transactions_max_age_blocks else {
+            return;
+        };
+        let max_age = usize::try_from(max_age.get()).unwrap_or(usize::MAX);
+        // NOTE: the latest block may be replaced by a soft fork with a block created before it
+        let Some(expired_by) = self
+            .state
+            .view()
+            .prev_block()
+            .map(|block| block.header().creation_time())
+        else {
+            return;
+        };
+
+        while self.next_height.saturating_add(max_age) <= height {
+            let Some(block) = NonZeroUsize::new(self.next_height)
+                .and_then(|block_height| self.kura.get_block(block_height))
+            else {
+                break;
+            };
+            for tx in block.external_transactions() {
+                if let Some(time_to_live) = tx.time_to_live() {
+                    self.expiring
+                        .entry(tx.creation_time().saturating_add(time_to_live))
+                        .or_default()
+                        .push(tx.hash());
+                }
+            }
+            self.next_height += 1;
+        }
+
+        let unexpired = self.expiring.split_off(&expired_by);
+        let expired = core::mem::replace(&mut self.expiring, unexpired);
+        #[cfg_attr(not(feature = "telemetry"), allow(unused_variables))]
+        let pruned = self
+            .state
+            .transactions
+            .prune(expired.into_values().flatten());
+        #[cfg(feature = "telemetry")]
+        self.state.telemetry.prune_transactions(pruned as u64);
+    }
+}
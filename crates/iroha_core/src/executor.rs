@@ -3,6 +3,7 @@
 use std::sync::Arc;
 
 use derive_more::DebugCustom;
+use iroha_crypto::HashOf;
 use iroha_data_model::{
     account::AccountId,
     executor as data_model_executor,
@@ -268,6 +269,16 @@ impl Executor {
         *self = Self::UserProvided(loaded_executor);
         Ok(())
     }
+
+    /// Hash of the user-provided executor, `None` for the [`Initial`](Executor::Initial) one.
+    pub fn hash(&self) -> Option<HashOf<data_model_executor::Executor>> {
+        match self {
+            Self::Initial => None,
+            Self::UserProvided(loaded_executor) => {
+                Some(HashOf::new(&*loaded_executor.raw_executor))
+            }
+        }
+    }
 }
 
 /// [`Executor`] with [`Module`](wasmtime::Module) for execution.
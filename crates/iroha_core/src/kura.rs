@@ -8,12 +8,15 @@ use std::{
     num::NonZeroUsize,
     path::{Path, PathBuf},
     sync::Arc,
-    time::Duration,
+    time::{Duration, Instant, SystemTime},
 };
 
 use iroha_config::{
-    kura::InitMode,
-    parameters::{actual::Kura as Config, defaults::kura::BLOCKS_IN_MEMORY},
+    kura::{FsyncMode, InitMode, StoreMode},
+    parameters::{
+        actual::Kura as Config,
+        defaults::kura::{BLOCKS_IN_MEMORY, FSYNC_PERIOD, WRITE_BUFFER_SIZE},
+    },
 };
 use iroha_crypto::{Hash, HashOf};
 use iroha_data_model::block::{BlockHeader, SignedBlock};
@@ -22,6 +25,7 @@ use iroha_logger::prelude::*;
 use iroha_version::scale::{DecodeVersioned, EncodeVersioned};
 use parity_scale_codec::DecodeAll;
 use parking_lot::Mutex;
+use serde::Serialize;
 
 use crate::block::CommittedBlock;
 
@@ -34,6 +38,8 @@ impl From<CommittedBlock> for Arc<SignedBlock> {
 const INDEX_FILE_NAME: &str = "blocks.index";
 const DATA_FILE_NAME: &str = "blocks.data";
 const HASHES_FILE_NAME: &str = "blocks.hashes";
+const QUARANTINE_DIR_NAME: &str = "quarantine";
+const REPORT_FILE_NAME: &str = "report.json";
 
 const SIZE_OF_BLOCK_HASH: u64 = Hash::LENGTH as u64;
 
@@ -44,11 +50,19 @@ pub struct Kura {
     block_store: Mutex<BlockStore>,
     /// The array of block hashes and a slot for an arc of the block. This is normally recovered from the index file.
     block_data: Mutex<BlockData>,
+    /// Whether the blocks are written to the block store or only kept in memory
+    store_mode: StoreMode,
     /// Path to file for plain text blocks.
     block_plain_text_path: Option<PathBuf>,
     /// At most N last blocks will be stored in memory.
     /// Older blocks will be dropped from memory and loaded from the disk if they are needed.
     blocks_in_memory: NonZeroUsize,
+    /// When the written blocks are flushed to the disk
+    fsync: FsyncMode,
+    /// Period of flushing in the [`FsyncMode::Periodic`] mode
+    fsync_period: Duration,
+    /// Up to how many bytes of the consecutive blocks are written at once
+    write_buffer_size: usize,
     /// Amount of blocks loaded during initialization
     init_block_count: usize,
 }
@@ -60,6 +74,8 @@ impl Kura {
     ///
     /// This does _not_ start the thread which receives and stores new blocks, see [`Self::start`].
     ///
+    /// In the [`StoreMode::Memory`] the store directory isn't touched and Kura starts empty.
+    ///
     /// # Errors
     /// Fails if there are filesystem errors when trying
     /// to access the block store indicated by the provided
@@ -67,21 +83,34 @@ impl Kura {
     pub fn new(config: &Config) -> Result<(Arc<Self>, BlockCount)> {
         let store_dir = config.store_dir.resolve_relative_path();
         let mut block_store = BlockStore::new(&store_dir);
-        block_store.create_files_if_they_do_not_exist()?;
-
-        let block_plain_text_path = config
-            .debug_output_new_blocks
-            .then(|| store_dir.join("blocks.json"));
 
-        let block_data = Kura::init(&mut block_store, config.init_mode)?;
+        let (block_data, block_plain_text_path) = match config.store_mode {
+            StoreMode::Disk => {
+                block_store.create_files_if_they_do_not_exist()?;
+                let block_data = Kura::init(&mut block_store, config.init_mode)?;
+                let block_plain_text_path = config
+                    .debug_output_new_blocks
+                    .then(|| store_dir.join("blocks.json"));
+                (block_data, block_plain_text_path)
+            }
+            StoreMode::Memory => (Vec::new(), None),
+        };
         let block_count = block_data.len();
-        info!(mode=?config.init_mode, block_count, "Kura init complete");
+        info!(mode=?config.init_mode, store_mode=%config.store_mode, block_count, "Kura init complete");
 
         let kura = Arc::new(Self {
             block_store: Mutex::new(block_store),
             block_data: Mutex::new(block_data),
+            store_mode: config.store_mode,
             block_plain_text_path,
             blocks_in_memory: config.blocks_in_memory,
+            fsync: config.fsync,
+            fsync_period: config.fsync_period,
+            write_buffer_size: config
+                .write_buffer_size
+                .get()
+                .try_into()
+                .unwrap_or(usize::MAX),
             init_block_count: block_count,
         });
 
@@ -94,14 +123,31 @@ impl Kura {
         Arc::new(Self {
             block_store: Mutex::new(BlockStore::new(PathBuf::new())),
             block_data: Mutex::new(Vec::new()),
+            store_mode: StoreMode::Memory,
             block_plain_text_path: None,
             blocks_in_memory: BLOCKS_IN_MEMORY,
+            fsync: FsyncMode::default(),
+            fsync_period: FSYNC_PERIOD,
+            write_buffer_size: WRITE_BUFFER_SIZE
+                .get()
+                .try_into()
+                .expect("INTERNAL BUG: default write buffer size exceeds usize::MAX"),
             init_block_count: 0,
         })
     }
 
     /// Start a thread that receives and stores new blocks
+    ///
+    /// In the [`StoreMode::Memory`] there is nothing to store, so the returned child
+    /// only waits for the shutdown.
     pub fn start(kura: Arc<Self>, shutdown_signal: ShutdownSignal) -> Child {
+        if kura.store_mode == StoreMode::Memory {
+            return Child::new(
+                tokio::task::spawn(async move { shutdown_signal.receive().await }),
+                OnShutdown::Abort,
+            );
+        }
+
         Child::new(
             tokio::task::spawn(spawn_os_thread_as_future(
                 std::thread::Builder::new().name("kura".to_owned()),
@@ -115,6 +161,9 @@ impl Kura {
 
     /// Initialize [`Kura`] after its construction to be able to work with it.
     ///
+    /// In the strict mode every block is verified. If a block is corrupted, it and all the blocks
+    /// above it are moved to the quarantine, see [`BlockStore::quarantine`], and are fetched from the peers again.
+    ///
     /// # Errors
     /// Fails if:
     /// - file storage is unavailable
@@ -164,32 +213,36 @@ impl Kura {
 
         let mut block_indices = vec![BlockIndex::default(); block_index_count];
         block_store.read_block_indices(0, &mut block_indices)?;
+        // Hashes recorded when the blocks were written, the blocks must still have them
+        let recorded_hashes = block_store
+            .read_hashes_count()
+            .and_then(|count| {
+                block_store.read_block_hashes(0, block_index_count.min(count.try_into()?))
+            })
+            .unwrap_or_default();
+        let data_size = block_store.read_data_size()?;
 
         let mut prev_block_hash = None;
-        for block in block_indices {
-            // This is re-allocated every iteration. This could cause a problem.
-            let mut block_data_buffer = vec![0_u8; block.length.try_into()?];
-
-            match block_store.read_block_data(block.start, &mut block_data_buffer) {
-                Ok(()) => match SignedBlock::decode_all_versioned(&block_data_buffer) {
-                    Ok(decoded_block) => {
-                        if prev_block_hash != decoded_block.header().prev_block_hash {
-                            error!(expected=?prev_block_hash, actual=?decoded_block.header().prev_block_hash,
-                                "Block has wrong previous block hash. Not reading any blocks beyond this height."
-                            );
-                            break;
-                        }
-                        let decoded_block_hash = decoded_block.hash();
-                        block_hashes.push(decoded_block_hash);
-                        prev_block_hash = Some(decoded_block_hash);
-                    }
-                    Err(error) => {
-                        error!(?error, "Encountered malformed block. Not reading any blocks beyond this height.");
-                        break;
-                    }
-                },
-                Err(error) => {
-                    error!(?error, "Malformed block index or corrupted block data file. Not reading any blocks beyond this height.");
+        for (block, height) in block_indices.into_iter().zip(1_usize..) {
+            let verified = Self::verify_block(
+                block_store,
+                block,
+                data_size,
+                prev_block_hash,
+                recorded_hashes.get(height - 1).copied(),
+            )?;
+            match verified {
+                Ok(block_hash) => {
+                    block_hashes.push(block_hash);
+                    prev_block_hash = Some(block_hash);
+                }
+                Err(corruption) => {
+                    error!(height, %corruption, "Encountered corrupted block. Not reading any blocks beyond this height.");
+                    let report = block_store.quarantine(block_hashes.len() as u64, &corruption)?;
+                    warn!(
+                        ?report,
+                        "Corrupted blocks were quarantined and will be fetched from the peers again"
+                    );
                     break;
                 }
             }
@@ -200,6 +253,46 @@ impl Kura {
         Ok(block_hashes)
     }
 
+    /// Verify the `block` stored in `block_store` and get its hash
+    fn verify_block(
+        block_store: &BlockStore,
+        block: BlockIndex,
+        data_size: u64,
+        prev_block_hash: Option<HashOf<BlockHeader>>,
+        recorded_hash: Option<HashOf<BlockHeader>>,
+    ) -> Result<Result<HashOf<BlockHeader>, Corruption>> {
+        if block
+            .start
+            .checked_add(block.length)
+            .map_or(true, |end| end > data_size)
+        {
+            return Ok(Err(Corruption::OutOfBounds));
+        }
+
+        let mut block_data_buffer = vec![0_u8; block.length.try_into()?];
+        block_store.read_block_data(block.start, &mut block_data_buffer)?;
+        let decoded_block = match SignedBlock::decode_all_versioned(&block_data_buffer) {
+            Ok(decoded_block) => decoded_block,
+            Err(error) => return Ok(Err(Corruption::Malformed(error.to_string()))),
+        };
+
+        if prev_block_hash != decoded_block.header().prev_block_hash {
+            return Ok(Err(Corruption::BrokenChain {
+                expected: prev_block_hash,
+                actual: decoded_block.header().prev_block_hash,
+            }));
+        }
+        let block_hash = decoded_block.hash();
+        if let Some(recorded) = recorded_hash.filter(|recorded| *recorded != block_hash) {
+            return Ok(Err(Corruption::HashMismatch {
+                recorded,
+                actual: block_hash,
+            }));
+        }
+
+        Ok(Ok(block_hash))
+    }
+
     #[iroha_logger::log(skip_all)]
     fn receive_blocks_loop(&self, shutdown_signal: &ShutdownSignal) {
         let kura = self;
@@ -211,6 +304,8 @@ impl Kura {
                 .map(|idx| block_data[idx].0)
         };
 
+        // Time of the oldest write which isn't flushed to the disk yet
+        let mut unsynced_since = None;
         let mut should_exit = false;
         loop {
             // If kura receive shutdown then close block channel and write remaining blocks to the storage
@@ -231,12 +326,14 @@ impl Kura {
 
             if written_block_count >= block_data.len() {
                 if should_exit {
+                    kura.sync_block_store(&mut unsynced_since, true);
                     info!("Kura has written remaining blocks to disk and is shutting down.");
                     return;
                 }
 
                 written_block_count = block_data.len();
                 drop(block_data);
+                kura.sync_block_store(&mut unsynced_since, false);
                 std::thread::sleep(std::time::Duration::from_millis(1));
                 continue;
             }
@@ -279,16 +376,42 @@ impl Kura {
                 panic!("Kura has encountered a fatal IO error.");
             }
 
-            for block in blocks_to_be_written {
-                if let Err(error) = block_store_guard.append_block_to_chain(&block) {
-                    error!(?error, "Failed to store block");
-                    panic!("Kura has encountered a fatal IO error.");
-                }
-                latest_written_block_hash = Some(block.hash());
+            if let Err(error) = block_store_guard.append_blocks_to_chain(
+                blocks_to_be_written.iter().map(AsRef::as_ref),
+                kura.write_buffer_size,
+            ) {
+                error!(?error, "Failed to store block");
+                panic!("Kura has encountered a fatal IO error.");
             }
+            drop(block_store_guard);
+            latest_written_block_hash = blocks_to_be_written.last().map(|block| block.hash());
+            unsynced_since.get_or_insert_with(Instant::now);
+            kura.sync_block_store(&mut unsynced_since, false);
         }
     }
 
+    /// Flush the written blocks to the disk if it is due according to [`FsyncMode`].
+    /// Pending blocks are always flushed on `shutdown`, unless flushing is disabled.
+    fn sync_block_store(&self, unsynced_since: &mut Option<Instant>, shutdown: bool) {
+        let Some(since) = *unsynced_since else {
+            return;
+        };
+        let is_due = match self.fsync {
+            FsyncMode::Block => true,
+            FsyncMode::Periodic => shutdown || since.elapsed() >= self.fsync_period,
+            FsyncMode::Never => false,
+        };
+        if !is_due {
+            return;
+        }
+
+        if let Err(error) = self.block_store.lock().sync() {
+            error!(?error, "Failed to flush blocks to disk");
+            panic!("Kura has encountered a fatal IO error.");
+        }
+        *unsynced_since = None;
+    }
+
     /// Get the hash of the block at the provided height.
     pub fn get_block_hash(&self, block_height: NonZeroUsize) -> Option<HashOf<BlockHeader>> {
         let hash_data_guard = self.block_data.lock();
@@ -484,6 +607,16 @@ impl BlockStore {
         Ok(index)
     }
 
+    /// Get the size of the data file in bytes.
+    ///
+    /// # Errors
+    /// IO Error.
+    pub fn read_data_size(&self) -> Result<u64> {
+        let path = self.path_to_blockchain.join(DATA_FILE_NAME);
+        let size = std::fs::metadata(&path).add_err_context(&path)?.len();
+        Ok(size)
+    }
+
     /// Get the number of indices in the index file, which is
     /// calculated as the size of the index file in bytes divided by
     /// `2*size_of(u64)`.
@@ -768,26 +901,153 @@ impl BlockStore {
     /// Fails if any of the required platform-specific functions
     /// fail.
     pub fn append_block_to_chain(&mut self, block: &SignedBlock) -> Result<()> {
-        let bytes = block.encode_versioned();
-        let new_block_height = self.read_index_count()?;
-        let start_location_in_data_file = if new_block_height == 0 {
+        self.append_blocks_to_chain([block], 0)
+    }
+
+    /// Append `blocks` to this block store like [`Self::append_block_to_chain`],
+    /// but write the data of the consecutive blocks to the data file
+    /// at once, in chunks of up to `write_buffer_size` bytes.
+    /// A block larger than `write_buffer_size` is written on its own.
+    ///
+    /// # Errors
+    /// Fails if any of the required platform-specific functions
+    /// fail.
+    pub fn append_blocks_to_chain<'block>(
+        &mut self,
+        blocks: impl IntoIterator<Item = &'block SignedBlock>,
+        write_buffer_size: usize,
+    ) -> Result<()> {
+        let mut new_block_height = self.read_index_count()?;
+        let mut start_location_in_data_file = if new_block_height == 0 {
             0
         } else {
             let ultimate_block = self.read_block_index(new_block_height - 1)?;
             ultimate_block.start + ultimate_block.length
         };
 
-        self.write_block_data(start_location_in_data_file, &bytes)?;
-        self.write_block_index(
-            new_block_height,
-            start_location_in_data_file,
-            bytes.len() as u64,
-        )?;
-        self.write_block_hash(new_block_height, block.hash())?;
+        let mut buffer = Vec::new();
+        let mut buffered_blocks = Vec::new();
+        for block in blocks {
+            let bytes = block.encode_versioned();
+            if !buffer.is_empty() && buffer.len() + bytes.len() > write_buffer_size {
+                self.write_buffered_blocks(new_block_height, &buffer, &buffered_blocks)?;
+                new_block_height += buffered_blocks.len() as u64;
+                buffer.clear();
+                buffered_blocks.clear();
+            }
+
+            let index = BlockIndex {
+                start: start_location_in_data_file,
+                length: bytes.len() as u64,
+            };
+            start_location_in_data_file += index.length;
+            buffer.extend_from_slice(&bytes);
+            buffered_blocks.push((index, block.hash()));
+        }
+        if !buffered_blocks.is_empty() {
+            self.write_buffered_blocks(new_block_height, &buffer, &buffered_blocks)?;
+        }
 
         Ok(())
     }
 
+    /// Write the data of consecutive `blocks` starting at `block_height`, and then their indices and hashes,
+    /// so that the index never refers to data which isn't written yet.
+    fn write_buffered_blocks(
+        &mut self,
+        block_height: u64,
+        data: &[u8],
+        blocks: &[(BlockIndex, HashOf<BlockHeader>)],
+    ) -> Result<()> {
+        let Some((first, _)) = blocks.first() else {
+            return Ok(());
+        };
+        self.write_block_data(first.start, data)?;
+        for ((index, hash), height) in blocks.iter().zip(block_height..) {
+            self.write_block_index(height, index.start, index.length)?;
+            self.write_block_hash(height, *hash)?;
+        }
+
+        Ok(())
+    }
+
+    /// Flush the data, the index and the hashes files from the OS page cache to the disk.
+    ///
+    /// # Errors
+    /// IO Error.
+    pub fn sync(&self) -> Result<()> {
+        for file_name in [DATA_FILE_NAME, INDEX_FILE_NAME, HASHES_FILE_NAME] {
+            let path = self.path_to_blockchain.join(file_name);
+            std::fs::OpenOptions::new()
+                .write(true)
+                .open(path.clone())
+                .and_then(|file| file.sync_data())
+                .add_err_context(&path)?;
+        }
+        Ok(())
+    }
+
+    /// Move the blocks starting from the given `height` (counting from 0) to a new directory in the quarantine
+    /// for the operator to inspect, and prune the block storage to the `height`.
+    ///
+    /// The tails of the data, index and hashes files are copied to the quarantine
+    /// along with the report of the repair, which is also returned.
+    ///
+    /// # Errors
+    ///
+    /// - If files do not exist (call [`Self::create_files_if_they_do_not_exist`])
+    /// - Other IO errors
+    pub fn quarantine(&self, height: u64, reason: &Corruption) -> Result<RepairReport> {
+        let index_count = self.read_index_count()?;
+        let data_start = if height == 0 {
+            0
+        } else {
+            let ultimate_block = self.read_block_index(height - 1)?;
+            ultimate_block.start + ultimate_block.length
+        };
+
+        let quarantined_at = SystemTime::now()
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .unwrap_or_default();
+        let quarantine_dir = self
+            .path_to_blockchain
+            .join(QUARANTINE_DIR_NAME)
+            .join(quarantined_at.as_millis().to_string());
+        std::fs::create_dir_all(&quarantine_dir)
+            .map_err(|e| Error::MkDir(e, quarantine_dir.clone()))?;
+
+        for (file_name, start) in [
+            (DATA_FILE_NAME, data_start),
+            (INDEX_FILE_NAME, height * BlockIndex::SIZE),
+            (HASHES_FILE_NAME, height * SIZE_OF_BLOCK_HASH),
+        ] {
+            let path = self.path_to_blockchain.join(file_name);
+            let mut file = std::fs::File::open(&path).add_err_context(&path)?;
+            file.seek(SeekFrom::Start(start)).add_err_context(&path)?;
+            let quarantine_path = quarantine_dir.join(file_name);
+            let mut quarantine_file =
+                std::fs::File::create(&quarantine_path).add_err_context(&quarantine_path)?;
+            std::io::copy(&mut file, &mut quarantine_file).add_err_context(&quarantine_path)?;
+        }
+
+        let report = RepairReport {
+            verified_blocks: height,
+            quarantined_blocks: index_count.saturating_sub(height),
+            reason: reason.to_string(),
+            quarantine_dir,
+        };
+        let report_path = report.quarantine_dir.join(REPORT_FILE_NAME);
+        std::fs::write(
+            &report_path,
+            serde_json::to_vec_pretty(&report).expect("serialization is infallible"),
+        )
+        .add_err_context(&report_path)?;
+
+        self.prune(height)?;
+
+        Ok(report)
+    }
+
     /// Prune the block storage to the given height
     ///
     /// Removes block entries higher than the given height from
@@ -842,6 +1102,42 @@ impl BlockStore {
     }
 }
 
+/// Reason a stored block is rejected during the verification of the block store
+#[derive(Debug, Clone, displaydoc::Display)]
+pub enum Corruption {
+    /// Block index refers beyond the end of the data file
+    OutOfBounds,
+    /// Block data can't be decoded: {0}
+    Malformed(String),
+    /// Block doesn't follow the previous block: expected previous block hash {expected:?}, found {actual:?}
+    BrokenChain {
+        /// Hash of the previous block
+        expected: Option<HashOf<BlockHeader>>,
+        /// Previous block hash stored in the block
+        actual: Option<HashOf<BlockHeader>>,
+    },
+    /// Block hash {actual} differs from the hash {recorded} recorded when it was written
+    HashMismatch {
+        /// Hash recorded in the hashes file
+        recorded: HashOf<BlockHeader>,
+        /// Hash of the stored block
+        actual: HashOf<BlockHeader>,
+    },
+}
+
+/// Report of the repair of the block store, written to the quarantine directory
+#[derive(Debug, Clone, Serialize)]
+pub struct RepairReport {
+    /// Number of blocks which passed the verification and were kept
+    pub verified_blocks: u64,
+    /// Number of blocks moved to the quarantine, starting from the first corrupted one
+    pub quarantined_blocks: u64,
+    /// Why the first quarantined block was rejected
+    pub reason: String,
+    /// Directory the quarantined blocks were moved to
+    pub quarantine_dir: PathBuf,
+}
+
 /// Helper to reduce boilerplate of file ops
 // TODO: use in more places when refactor
 struct FileWrap {
@@ -1108,20 +1404,89 @@ mod tests {
         }
     }
 
+    #[test]
+    fn append_blocks_to_chain_in_chunks() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut block_store = BlockStore::new(dir.path());
+        block_store.create_files_if_they_do_not_exist().unwrap();
+
+        let dummy_block: SignedBlock =
+            ValidBlock::new_dummy(KeyPair::random().private_key()).into();
+        let block_data = dummy_block.encode_versioned();
+
+        let append_count = 10;
+        // Three blocks fit into the buffer, so the last chunk is incomplete
+        block_store
+            .append_blocks_to_chain(
+                std::iter::repeat(&dummy_block).take(append_count),
+                block_data.len() * 3,
+            )
+            .unwrap();
+        block_store.sync().unwrap();
+
+        assert_eq!(block_store.read_index_count().unwrap(), append_count as u64);
+        assert_eq!(
+            block_store.read_hashes_count().unwrap(),
+            append_count as u64
+        );
+        for i in 0..append_count as u64 {
+            let BlockIndex { start, length } = block_store.read_block_index(i).unwrap();
+            assert_eq!(i * block_data.len() as u64, start);
+            assert_eq!(block_data.len() as u64, length);
+        }
+    }
+
     #[test]
     fn strict_init_kura() {
         let temp_dir = TempDir::new().unwrap();
         Kura::new(&Config {
             init_mode: InitMode::Strict,
+            store_mode: StoreMode::Disk,
             store_dir: iroha_config::base::WithOrigin::inline(
                 temp_dir.path().to_str().unwrap().into(),
             ),
             blocks_in_memory: BLOCKS_IN_MEMORY,
+            fsync: FsyncMode::Periodic,
+            fsync_period: FSYNC_PERIOD,
+            write_buffer_size: WRITE_BUFFER_SIZE,
             debug_output_new_blocks: false,
         })
         .unwrap();
     }
 
+    #[tokio::test]
+    async fn memory_kura_keeps_blocks_without_store_dir() {
+        let temp_dir = TempDir::new().unwrap();
+        let store_dir = temp_dir.path().join("storage");
+        let (kura, block_count) = Kura::new(&Config {
+            init_mode: InitMode::Strict,
+            store_mode: StoreMode::Memory,
+            store_dir: iroha_config::base::WithOrigin::inline(store_dir.clone()),
+            blocks_in_memory: nonzero!(1_usize),
+            fsync: FsyncMode::Block,
+            fsync_period: FSYNC_PERIOD,
+            write_buffer_size: WRITE_BUFFER_SIZE,
+            debug_output_new_blocks: true,
+        })
+        .unwrap();
+        assert_eq!(block_count.0, 0);
+
+        let shutdown_signal = ShutdownSignal::new();
+        let _handle = Kura::start(kura.clone(), shutdown_signal.clone());
+        let mut blocks = DummyBlocks::new();
+        for _ in 0..3 {
+            kura.store_block(blocks.next());
+        }
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        shutdown_signal.send();
+
+        for height in 1..=3 {
+            let block = kura.get_block(NonZeroUsize::new(height).unwrap()).unwrap();
+            assert_eq!(block, blocks.get(height - 1).unwrap());
+        }
+        assert!(!store_dir.exists());
+    }
+
     #[test]
     fn kura_not_miss_replace_block() {
         let rt = tokio::runtime::Builder::new_multi_thread()
@@ -1143,10 +1508,14 @@ mod tests {
         {
             let (kura, block_count) = Kura::new(&Config {
                 init_mode: InitMode::Strict,
+                store_mode: StoreMode::Disk,
                 store_dir: iroha_config::base::WithOrigin::inline(
                     temp_dir.path().to_str().unwrap().into(),
                 ),
                 blocks_in_memory: BLOCKS_IN_MEMORY,
+                fsync: FsyncMode::Periodic,
+                fsync_period: FSYNC_PERIOD,
+                write_buffer_size: WRITE_BUFFER_SIZE,
                 debug_output_new_blocks: false,
             })
             .unwrap();
@@ -1168,6 +1537,62 @@ mod tests {
         }
     }
 
+    #[test]
+    fn corrupted_blocks_are_quarantined() {
+        let rt = tokio::runtime::Builder::new_multi_thread()
+            .enable_time()
+            .build()
+            .unwrap();
+
+        let temp_dir = TempDir::new().unwrap();
+        let [_block_genesis, _block, _block_soft_fork, block_next] =
+            create_blocks(&rt, &temp_dir).try_into().unwrap();
+
+        // Lose the end of the top block, as if the peer went down in the middle of writing it
+        let data_path = temp_dir.path().join(DATA_FILE_NAME);
+        let data_size = std::fs::metadata(&data_path).unwrap().len();
+        std::fs::OpenOptions::new()
+            .write(true)
+            .open(&data_path)
+            .unwrap()
+            .set_len(data_size - 1)
+            .unwrap();
+
+        let (kura, block_count) = Kura::new(&Config {
+            init_mode: InitMode::Strict,
+            store_mode: StoreMode::Disk,
+            store_dir: iroha_config::base::WithOrigin::inline(
+                temp_dir.path().to_str().unwrap().into(),
+            ),
+            blocks_in_memory: BLOCKS_IN_MEMORY,
+            fsync: FsyncMode::Periodic,
+            fsync_period: FSYNC_PERIOD,
+            write_buffer_size: WRITE_BUFFER_SIZE,
+            debug_output_new_blocks: false,
+        })
+        .unwrap();
+
+        assert_eq!(block_count.0, 2);
+        assert!(kura.get_block(nonzero!(3_usize)).is_none());
+        let block_store = BlockStore::new(temp_dir.path());
+        assert_eq!(block_store.read_index_count().unwrap(), 2);
+
+        let quarantine_dir = std::fs::read_dir(temp_dir.path().join(QUARANTINE_DIR_NAME))
+            .unwrap()
+            .next()
+            .unwrap()
+            .unwrap()
+            .path();
+        let report: serde_json::Value =
+            serde_json::from_slice(&std::fs::read(quarantine_dir.join(REPORT_FILE_NAME)).unwrap())
+                .unwrap();
+        assert_eq!(report["verified_blocks"], 2);
+        assert_eq!(report["quarantined_blocks"], 1);
+        let quarantined_data = std::fs::read(quarantine_dir.join(DATA_FILE_NAME)).unwrap();
+        let block_data = SignedBlock::from(block_next).encode_versioned();
+        assert_eq!(quarantined_data, block_data[..block_data.len() - 1]);
+    }
+
     #[allow(clippy::too_many_lines)]
     fn create_blocks(rt: &tokio::runtime::Runtime, temp_dir: &TempDir) -> Vec<CommittedBlock> {
         const BLOCK_FLUSH_TIMEOUT: Duration = Duration::from_secs(1);
@@ -1195,10 +1620,14 @@ mod tests {
 
         let (kura, block_count) = Kura::new(&Config {
             init_mode: InitMode::Strict,
+            store_mode: StoreMode::Disk,
             store_dir: iroha_config::base::WithOrigin::inline(
                 temp_dir.path().to_str().unwrap().into(),
             ),
             blocks_in_memory: BLOCKS_IN_MEMORY,
+            fsync: FsyncMode::Periodic,
+            fsync_period: FSYNC_PERIOD,
+            write_buffer_size: WRITE_BUFFER_SIZE,
             debug_output_new_blocks: false,
         })
         .unwrap();
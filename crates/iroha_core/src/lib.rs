@@ -1,20 +1,26 @@
 //! Iroha — A simple, enterprise-grade decentralized ledger.
 
+pub mod archive;
 pub mod block;
 pub mod block_sync;
+pub mod compaction;
 pub mod executor;
 pub mod gossiper;
 pub mod kiso;
 pub mod kura;
+pub mod migration;
 pub mod peers_gossiper;
 pub mod query;
 pub mod queue;
+pub mod replay;
+pub mod simulation;
 pub mod smartcontracts;
 pub mod snapshot;
 pub mod state;
 pub mod sumeragi;
 #[cfg(feature = "telemetry")]
 pub mod telemetry;
+pub mod tiering;
 pub mod tx;
 
 use core::time::Duration;
@@ -92,6 +98,28 @@ pub mod role {
         pub id: RoleId,
     }
 
+    /// [`AccountId`] holding the [`RoleId`], ordered by the role first.
+    #[derive(
+        Debug,
+        Clone,
+        Constructor,
+        PartialEq,
+        Eq,
+        PartialOrd,
+        Ord,
+        Hash,
+        Decode,
+        Encode,
+        Deserialize,
+        Serialize,
+    )]
+    pub struct RoleHolder {
+        /// [`RoleId`] of the held role.
+        pub id: RoleId,
+        /// [`AccountId`] of the holder.
+        pub account: AccountId,
+    }
+
     /// Reference to [`RoleIdWithOwner`].
     #[derive(Debug, Clone, Copy, Constructor, PartialEq, Eq, PartialOrd, Ord, Hash)]
     pub struct RoleIdWithOwnerRef<'role> {
@@ -117,6 +145,112 @@ pub mod role {
     }
 }
 
+pub mod tag {
+    //! Module with extension for account tags to be stored inside state.
+
+    use derive_more::Constructor;
+    use serde::{Deserialize, Serialize};
+
+    use super::*;
+
+    /// [`AccountId`] labeled with the tag, ordered by the tag first.
+    #[derive(
+        Debug,
+        Clone,
+        Constructor,
+        PartialEq,
+        Eq,
+        PartialOrd,
+        Ord,
+        Hash,
+        Decode,
+        Encode,
+        Deserialize,
+        Serialize,
+    )]
+    pub struct TaggedAccount {
+        /// Tag the account is labeled with.
+        pub tag: Name,
+        /// [`AccountId`] of the labeled account.
+        pub account: AccountId,
+    }
+}
+
+pub mod history {
+    //! Module with extension for the history of asset quantities to be stored inside state.
+
+    use core::num::NonZeroU64;
+
+    use derive_more::Constructor;
+    use serde::{Deserialize, Serialize};
+
+    use super::*;
+
+    /// Change of the quantity of the [`AssetId`] by the block at the height, ordered by the asset first.
+    #[derive(
+        Debug,
+        Clone,
+        Constructor,
+        PartialEq,
+        Eq,
+        PartialOrd,
+        Ord,
+        Hash,
+        Decode,
+        Encode,
+        Deserialize,
+        Serialize,
+    )]
+    pub struct AssetChange {
+        /// [`AssetId`] of the changed holding.
+        pub asset: AssetId,
+        /// Height of the block which changed the quantity.
+        pub height: NonZeroU64,
+    }
+}
+
+pub mod grant {
+    //! Module with extension for expiring grants to be stored inside state.
+
+    use derive_more::Constructor;
+    use serde::{Deserialize, Serialize};
+
+    use super::*;
+
+    /// What is granted to an account.
+    #[derive(
+        Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Decode, Encode, Deserialize, Serialize,
+    )]
+    pub enum Granted {
+        /// [`Permission`] granted directly.
+        Permission(Permission),
+        /// [`RoleId`] of the granted role.
+        Role(RoleId),
+    }
+
+    /// [`Granted`] object held by the [`AccountId`] until it expires.
+    #[derive(
+        Debug,
+        Clone,
+        Constructor,
+        PartialEq,
+        Eq,
+        PartialOrd,
+        Ord,
+        Hash,
+        Decode,
+        Encode,
+        Deserialize,
+        Serialize,
+    )]
+    pub struct AccountGrant {
+        /// [`AccountId`] of the holder.
+        pub account: AccountId,
+        /// Granted object.
+        pub object: Granted,
+    }
+}
+
 pub mod prelude {
     //! Re-exports important traits and types. Meant to be glob imported when using `Iroha`.
 
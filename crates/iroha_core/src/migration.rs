@@ -0,0 +1,408 @@
+//! Export of the world state as genesis instructions.
+//!
+//! Used to migrate to a new chain (e.g. when the parameters must change incompatibly):
+//! the state of the old chain is rebuilt from its blocks, see [`crate::replay::rebuild_state`],
+//! and recreated on the new chain by the instructions of its genesis.
+//!
+//! Only the data is exported: triggers, subscriptions, dead letters and tombstones
+//! are bound to the execution history of the old chain and have to be recreated manually.
+//! Entities of the genesis domain are not exported either, as it is recreated by the new genesis.
+
+use std::collections::BTreeMap;
+
+use iroha_data_model::{isi::InstructionBox, prelude::*};
+use iroha_genesis::GENESIS_DOMAIN_ID;
+
+use crate::{
+    grant::{AccountGrant, Granted},
+    state::WorldReadOnly,
+};
+
+/// Produce the instructions recreating `world` when executed in genesis by `genesis_account`.
+///
+/// Entities are registered by `genesis_account` and then transferred to their owners.
+/// Holdings of assets which can't be minted anymore are minted at once and distributed with transfers.
+/// Zero balances are not exported, holdings of key-value store assets are recreated from their entries.
+/// Frozen assets are frozen again once everything else is recreated.
+/// Permissions and roles granted until some time are not exported.
+/// Roles nobody holds are registered for `genesis_account` and revoked from it right away.
+pub fn export_world(
+    world: &impl WorldReadOnly,
+    genesis_account: &AccountId,
+) -> Vec<InstructionBox> {
+    let is_exported = |account: &AccountId| *account.domain() != *GENESIS_DOMAIN_ID;
+    // Temporary access isn't carried over, since it could expire before the new genesis
+    let is_expiring = |account: &AccountId, object: Granted| {
+        world
+            .grant_expiry(&AccountGrant::new(account.clone(), object))
+            .is_some()
+    };
+    let mut instructions: Vec<InstructionBox> = Vec::new();
+    let mut transfers: Vec<InstructionBox> = Vec::new();
+    let mut freezes: Vec<InstructionBox> = Vec::new();
+
+    for domain in world
+        .domains_iter()
+        .filter(|domain| *domain.id() != *GENESIS_DOMAIN_ID)
+    {
+        let mut new_domain =
+            Domain::new(domain.id().clone()).with_metadata(domain.metadata().clone());
+        if let Some(logo) = domain.logo() {
+            new_domain = new_domain.with_logo(logo.clone());
+        }
+        instructions.push(Register::domain(new_domain).into());
+        if is_exported(domain.owned_by()) {
+            transfers.push(
+                Transfer::domain(
+                    genesis_account.clone(),
+                    domain.id().clone(),
+                    domain.owned_by().clone(),
+                )
+                .into(),
+            );
+        }
+    }
+
+    for account in world
+        .accounts_iter()
+        .filter(|account| is_exported(account.id))
+    {
+        let new_account = Account::new(account.id.clone()).with_metadata(account.metadata.clone());
+        instructions.push(Register::account(new_account).into());
+        for tag in account.tags {
+            instructions.push(AddTag::new(account.id.clone(), tag.clone()).into());
+        }
+    }
+
+    let mut holdings = BTreeMap::<_, Vec<_>>::new();
+    for asset in world
+        .assets_iter()
+        .filter(|asset| is_exported(asset.id.account()) && !asset.value.is_zero())
+    {
+        holdings
+            .entry(asset.id.definition().clone())
+            .or_default()
+            .push(asset);
+    }
+
+    for definition in world
+        .asset_definitions_iter()
+        .filter(|definition| *definition.id().domain() != *GENESIS_DOMAIN_ID)
+    {
+        let holdings = holdings.remove(definition.id()).unwrap_or_default();
+
+        let mut new_definition = AssetDefinition::new(definition.id().clone(), definition.spec())
+            .with_metadata(definition.metadata().clone());
+        if let Some(logo) = definition.logo() {
+            new_definition = new_definition.with_logo(logo.clone());
+        }
+        new_definition.kind = definition.kind();
+        new_definition.max_supply = definition.max_supply();
+        new_definition.mintable = match definition.mintable() {
+            // Minting the whole supply at once keeps it fixed on the new chain
+            Mintable::Once | Mintable::Not if !holdings.is_empty() => Mintable::Once,
+            mintable => mintable,
+        };
+        instructions.push(Register::asset_definition(new_definition).into());
+        if definition.frozen() {
+            freezes.push(Freeze::asset_definition(definition.id().clone()).into());
+        }
+        if is_exported(definition.owned_by()) {
+            transfers.push(
+                Transfer::asset_definition(
+                    genesis_account.clone(),
+                    definition.id().clone(),
+                    definition.owned_by().clone(),
+                )
+                .into(),
+            );
+        }
+
+        if definition.kind() == AssetKind::Store {
+            // Holdings of a store have no quantity, setting their entries recreates them
+            for asset in world.assets_iter().filter(|asset| {
+                asset.id.definition() == definition.id() && is_exported(asset.id.account())
+            }) {
+                for (key, value) in asset.metadata.iter() {
+                    instructions.push(
+                        SetKeyValue::asset(asset.id.clone(), key.clone(), value.clone()).into(),
+                    );
+                }
+                if asset.frozen {
+                    freezes.push(Freeze::asset(asset.id.clone()).into());
+                }
+            }
+            continue;
+        }
+
+        match definition.mintable() {
+            // The rate of minting isn't limited in genesis
+            Mintable::Infinitely | Mintable::Periodic(_) => {
+                for asset in &holdings {
+                    instructions.push(Mint::asset_numeric(*asset.value, asset.id.clone()).into());
+                }
+            }
+            Mintable::Once | Mintable::Not => {
+                if let Some((first, rest)) = holdings.split_first() {
+                    let supply = rest.iter().fold(*first.value, |supply, asset| {
+                        supply
+                            .checked_add(*asset.value)
+                            .expect("INTERNAL BUG: total quantity of an asset overflows")
+                    });
+                    instructions.push(Mint::asset_numeric(supply, first.id.clone()).into());
+                    for asset in rest {
+                        instructions.push(
+                            Transfer::asset_numeric(
+                                first.id.clone(),
+                                *asset.value,
+                                asset.id.account().clone(),
+                            )
+                            .into(),
+                        );
+                    }
+                }
+            }
+        }
+
+        for asset in &holdings {
+            for (key, value) in asset.metadata.iter() {
+                instructions
+                    .push(SetKeyValue::asset(asset.id.clone(), key.clone(), value.clone()).into());
+            }
+            if asset.frozen {
+                freezes.push(Freeze::asset(asset.id.clone()).into());
+            }
+        }
+    }
+
+    for nft in world
+        .nfts_iter()
+        .filter(|nft| *nft.id().domain() != *GENESIS_DOMAIN_ID)
+    {
+        let mut new_nft = Nft::new(nft.id().clone(), nft.content().clone());
+        if !nft.transferable() {
+            new_nft = new_nft.non_transferable();
+        }
+        instructions.push(Register::nft(new_nft).into());
+        if is_exported(nft.owned_by()) {
+            transfers.push(
+                Transfer::nft(
+                    genesis_account.clone(),
+                    nft.id().clone(),
+                    nft.owned_by().clone(),
+                )
+                .into(),
+            );
+        }
+    }
+
+    for role in world.roles().iter().map(|(_, role)| role) {
+        let holders = world
+            .role_holders_iter(role.id())
+            .filter(|account| is_exported(account))
+            .filter(|account| !is_expiring(account, Granted::Role(role.id().clone())))
+            .collect::<Vec<_>>();
+        // Registering a role grants it, so the roles nobody holds are granted to genesis account
+        let grant_to = holders.first().copied().unwrap_or(genesis_account);
+        // The hierarchy is flattened, since the extended roles may be nobody's to recreate
+        let new_role = world.role_permissions(role.id()).into_iter().cloned().fold(
+            Role::new(role.id().clone(), grant_to.clone()),
+            |role, permission| role.add_permission(permission),
+        );
+        instructions.push(Register::role(new_role).into());
+        if holders.is_empty() {
+            instructions
+                .push(Revoke::account_role(role.id().clone(), genesis_account.clone()).into());
+        }
+        for holder in holders.iter().skip(1) {
+            instructions.push(Grant::account_role(role.id().clone(), (*holder).clone()).into());
+        }
+    }
+
+    for (account, permissions) in world.account_permissions().iter() {
+        if is_exported(account) {
+            for permission in permissions.iter().filter(|permission| {
+                !is_expiring(account, Granted::Permission((*permission).clone()))
+            }) {
+                instructions
+                    .push(Grant::account_permission(permission.clone(), account.clone()).into());
+            }
+        }
+    }
+
+    // Ownership is transferred last, so that genesis account is allowed to populate the entities
+    instructions.extend(transfers);
+    // Holds are put last, as the frozen holdings couldn't be distributed otherwise
+    instructions.extend(freezes);
+    instructions
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeSet;
+
+    use iroha_crypto::KeyPair;
+    use iroha_primitives::json::Json;
+    use iroha_test_samples::{gen_account_in, ALICE_ID};
+
+    use super::*;
+    use crate::{
+        block::ValidBlock,
+        kura::Kura,
+        query::store::LiveQueryStore,
+        smartcontracts::Execute,
+        state::{State, World},
+    };
+
+    /// Execute `instructions` in a block, granting the registered roles as the default executor does
+    fn execute_in_block(state: &State, authority: &AccountId, instructions: Vec<InstructionBox>) {
+        let block_header = ValidBlock::new_dummy(&KeyPair::random().into_parts().1)
+            .as_ref()
+            .header();
+        let mut state_block = state.block(block_header);
+        let mut state_transaction = state_block.transaction();
+        for instruction in instructions {
+            let grant = match &instruction {
+                InstructionBox::Register(RegisterBox::Role(register)) => Some(Grant::account_role(
+                    register.object.id().clone(),
+                    register.object.grant_to.clone(),
+                )),
+                _ => None,
+            };
+            instruction
+                .execute(authority, &mut state_transaction)
+                .unwrap();
+            if let Some(grant) = grant {
+                grant.execute(authority, &mut state_transaction).unwrap();
+            }
+        }
+        state_transaction.apply();
+        state_block.commit();
+    }
+
+    fn new_state(world: World) -> State {
+        State::new(
+            world,
+            Kura::blank_kura_for_testing(),
+            LiveQueryStore::start_test(),
+        )
+    }
+
+    #[test]
+    fn fixed_supply_is_minted_once_and_distributed() {
+        let (genesis_account, _) = gen_account_in(&GENESIS_DOMAIN_ID);
+        let (bob_id, _) = gen_account_in("wonderland");
+        let definition_id: AssetDefinitionId = "rose#wonderland".parse().unwrap();
+        let alice_rose = AssetId::new(definition_id.clone(), ALICE_ID.clone());
+        let bob_rose = AssetId::new(definition_id.clone(), bob_id.clone());
+        let world = World::with_assets(
+            [Domain::new("wonderland".parse().unwrap()).build(&ALICE_ID)],
+            [
+                Account::new(ALICE_ID.clone()).build(&ALICE_ID),
+                Account::new(bob_id.clone()).build(&ALICE_ID),
+            ],
+            [AssetDefinition::numeric(definition_id.clone())
+                .mintable_once()
+                .build(&ALICE_ID)],
+            [Asset::new(alice_rose, 10_u32), Asset::new(bob_rose, 5_u32)],
+            [],
+        );
+        let state = State::new(
+            world,
+            Kura::blank_kura_for_testing(),
+            LiveQueryStore::start_test(),
+        );
+
+        let instructions = export_world(&state.view().world, &genesis_account);
+
+        let minted = instructions
+            .iter()
+            .filter_map(|isi| match isi {
+                InstructionBox::Mint(MintBox::Asset(mint)) => Some(mint.object),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(minted, [Numeric::from(15_u32)]);
+        let transferred = instructions
+            .iter()
+            .filter_map(|isi| match isi {
+                InstructionBox::Transfer(TransferBox::Asset(transfer)) => Some(transfer.object),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(transferred.len(), 1);
+        assert!(instructions.contains(
+            &Transfer::domain(
+                genesis_account,
+                "wonderland".parse().unwrap(),
+                ALICE_ID.clone()
+            )
+            .into()
+        ));
+    }
+
+    #[test]
+    fn exported_world_is_recreated_in_genesis() {
+        let (genesis_account, _) = gen_account_in(&GENESIS_DOMAIN_ID);
+        let (bob_id, _) = gen_account_in("wonderland");
+        let definition_id: AssetDefinitionId = "rose#wonderland".parse().unwrap();
+        let alice_rose = AssetId::new(definition_id.clone(), ALICE_ID.clone());
+        let gardener: RoleId = "GARDENER".parse().unwrap();
+        let auditor: RoleId = "AUDITOR".parse().unwrap();
+        let can_paint_roses = Permission::new("CanPaintRoses".to_owned(), Json::from(true));
+        let old = new_state(World::with_assets(
+            [Domain::new("wonderland".parse().unwrap()).build(&ALICE_ID)],
+            [
+                Account::new(ALICE_ID.clone()).build(&ALICE_ID),
+                Account::new(bob_id.clone()).build(&ALICE_ID),
+            ],
+            [AssetDefinition::numeric(definition_id).build(&ALICE_ID)],
+            [Asset::new(alice_rose.clone(), 10_u32)],
+            [],
+        ));
+        execute_in_block(
+            &old,
+            &ALICE_ID,
+            vec![
+                Register::role(Role::new(gardener.clone(), ALICE_ID.clone())).into(),
+                Grant::account_role(gardener.clone(), bob_id.clone()).into(),
+                Register::role(
+                    Role::new(auditor.clone(), ALICE_ID.clone())
+                        .add_permission(can_paint_roses.clone()),
+                )
+                .into(),
+                Revoke::account_role(auditor.clone(), ALICE_ID.clone()).into(),
+            ],
+        );
+
+        let new = new_state(World::with(
+            [Domain::new(GENESIS_DOMAIN_ID.clone()).build(&genesis_account)],
+            [Account::new(genesis_account.clone()).build(&genesis_account)],
+            [],
+        ));
+        execute_in_block(
+            &new,
+            &genesis_account,
+            export_world(&old.view().world, &genesis_account),
+        );
+
+        let view = new.view();
+        assert_eq!(
+            *view.world.asset(&alice_rose).unwrap().value(),
+            Numeric::from(10_u32)
+        );
+        assert_eq!(
+            view.world.role_holders_iter(&gardener).collect::<Vec<_>>(),
+            old.view()
+                .world
+                .role_holders_iter(&gardener)
+                .collect::<Vec<_>>()
+        );
+        // Nobody holds the role, not even genesis account
+        assert_eq!(view.world.role_holders_iter(&auditor).count(), 0);
+        assert_eq!(
+            view.world.role_permissions(&auditor),
+            BTreeSet::from([&can_paint_roses])
+        );
+    }
+}
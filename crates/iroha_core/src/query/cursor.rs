@@ -11,7 +11,8 @@ use iroha_data_model::{
     },
 };
 
-fn evaluate_selector_tuple<T>(
+/// Evaluate the `selector` on every item of the `batch`
+pub(crate) fn evaluate_selector_tuple<T>(
     batch: Vec<T>,
     selector: &SelectorTuple<T>,
 ) -> Result<QueryOutputBatchBoxTuple, QueryExecutionFail>
@@ -41,8 +42,10 @@ trait BatchedTrait {
     fn next_batch(
         &mut self,
         cursor: u64,
+        max_batch_size: Option<NonZeroU64>,
     ) -> Result<(QueryOutputBatchBoxTuple, Option<NonZeroU64>), QueryExecutionFail>;
     fn remaining(&self) -> u64;
+    fn batch_size(&self) -> NonZeroU64;
 }
 
 struct BatchedInner<I>
@@ -66,6 +69,7 @@ where
     fn next_batch(
         &mut self,
         cursor: u64,
+        max_batch_size: Option<NonZeroU64>,
     ) -> Result<(QueryOutputBatchBoxTuple, Option<NonZeroU64>), QueryExecutionFail> {
         let Some(server_cursor) = self.cursor else {
             // the server is done with the iterator
@@ -77,8 +81,10 @@ where
             return Err(QueryExecutionFail::CursorMismatch);
         }
 
-        let expected_batch_size: usize = self
-            .batch_size
+        let expected_batch_size: usize = max_batch_size
+            .map_or(self.batch_size, |max_batch_size| {
+                max_batch_size.min(self.batch_size)
+            })
             .get()
             .try_into()
             .expect("`u32` should always fit into `usize`");
@@ -88,12 +94,7 @@ where
             .iter
             .by_ref()
             .inspect(|_| current_batch_size += 1)
-            .take(
-                self.batch_size
-                    .get()
-                    .try_into()
-                    .expect("`u32` should always fit into `usize`"),
-            )
+            .take(expected_batch_size)
             .collect();
 
         // evaluate the requested projections
@@ -120,6 +121,10 @@ where
     fn remaining(&self) -> u64 {
         self.iter.len() as u64
     }
+
+    fn batch_size(&self) -> NonZeroU64 {
+        self.batch_size
+    }
 }
 
 /// A query output iterator that combines evaluating selectors, batching and type erasure.
@@ -158,6 +163,7 @@ impl ErasedQueryIterator {
     /// Checks if the cursor matches the server's cursor.
     ///
     /// Returns the batch and the next cursor if the query iterator is not drained.
+    /// The batch is shorter than requested by the client if `max_batch_size` is smaller.
     ///
     /// # Errors
     ///
@@ -166,8 +172,14 @@ impl ErasedQueryIterator {
     pub fn next_batch(
         &mut self,
         cursor: u64,
+        max_batch_size: Option<NonZeroU64>,
     ) -> Result<(QueryOutputBatchBoxTuple, Option<NonZeroU64>), QueryExecutionFail> {
-        self.inner.next_batch(cursor)
+        self.inner.next_batch(cursor, max_batch_size)
+    }
+
+    /// Returns the size of the batches requested by the client.
+    pub fn batch_size(&self) -> NonZeroU64 {
+        self.inner.batch_size()
     }
 
     /// Returns the number of remaining elements in the iterator.
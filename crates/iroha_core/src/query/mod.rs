@@ -2,4 +2,5 @@
 
 pub mod cursor;
 pub mod pagination;
+pub mod scope;
 pub mod store;
@@ -0,0 +1,156 @@
+//! Module with [`DomainScope`] restricting the visible data to a subset of domains.
+
+use std::{collections::BTreeSet, sync::Arc};
+
+use iroha_data_model::{
+    block::{BlockHeader, SignedBlock},
+    prelude::*,
+    query::{AssetWithDefinition, CommittedTransaction},
+};
+
+/// Subset of domains the data of which is visible, e.g. to a single tenant of a shared peer.
+///
+/// The entities which can't be attributed to a domain (e.g. peers, roles and block headers) are visible,
+/// unless they reveal the data of the other domains (e.g. blocks and trigger ids).
+/// Cloning is cheap: the clones share the domains.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DomainScope(Arc<BTreeSet<DomainId>>);
+
+impl DomainScope {
+    /// Construct [`DomainScope`] of the `domains`.
+    pub fn new(domains: impl IntoIterator<Item = DomainId>) -> Self {
+        Self(Arc::new(domains.into_iter().collect()))
+    }
+
+    /// Whether the data of the `domain` is visible
+    pub fn contains(&self, domain: &DomainId) -> bool {
+        self.0.contains(domain)
+    }
+
+    /// Whether the `event` is visible
+    pub fn allows_event(&self, event: &EventBox) -> bool {
+        match event {
+            EventBox::Data(event) => match event.untagged() {
+                DataEvent::Domain(event) => self.contains(event.origin()),
+                DataEvent::Peer(_) | DataEvent::Configuration(_) | DataEvent::Executor(_) => true,
+                DataEvent::Trigger(_) | DataEvent::Role(_) | DataEvent::Tagged(_) => false,
+            },
+            EventBox::Pipeline(_) | EventBox::Time(_) => true,
+            EventBox::TriggerCompleted(_) => false,
+            EventBox::ExecuteTrigger(event) => self.contains(event.authority().domain()),
+        }
+    }
+}
+
+/// Query output which can be restricted to a [`DomainScope`]
+pub trait ScopedQueryOutput {
+    /// Whether the output is visible in the `scope`
+    fn is_visible_in(&self, scope: &DomainScope) -> bool;
+}
+
+impl ScopedQueryOutput for Domain {
+    fn is_visible_in(&self, scope: &DomainScope) -> bool {
+        scope.contains(self.id())
+    }
+}
+
+impl ScopedQueryOutput for Account {
+    fn is_visible_in(&self, scope: &DomainScope) -> bool {
+        scope.contains(self.id().domain())
+    }
+}
+
+impl ScopedQueryOutput for AssetDefinition {
+    fn is_visible_in(&self, scope: &DomainScope) -> bool {
+        scope.contains(self.id().domain())
+    }
+}
+
+impl ScopedQueryOutput for Asset {
+    fn is_visible_in(&self, scope: &DomainScope) -> bool {
+        scope.contains(self.id().account().domain())
+    }
+}
+
+impl ScopedQueryOutput for AssetWithDefinition {
+    fn is_visible_in(&self, scope: &DomainScope) -> bool {
+        self.asset.is_visible_in(scope)
+    }
+}
+
+impl ScopedQueryOutput for Nft {
+    fn is_visible_in(&self, scope: &DomainScope) -> bool {
+        scope.contains(self.id().domain())
+    }
+}
+
+impl ScopedQueryOutput for Trigger {
+    fn is_visible_in(&self, scope: &DomainScope) -> bool {
+        scope.contains(self.action().authority().domain())
+    }
+}
+
+impl ScopedQueryOutput for TriggerId {
+    fn is_visible_in(&self, _scope: &DomainScope) -> bool {
+        false
+    }
+}
+
+impl ScopedQueryOutput for CommittedTransaction {
+    fn is_visible_in(&self, scope: &DomainScope) -> bool {
+        scope.contains(self.entrypoint.authority().domain())
+    }
+}
+
+impl ScopedQueryOutput for SignedBlock {
+    fn is_visible_in(&self, _scope: &DomainScope) -> bool {
+        false
+    }
+}
+
+macro_rules! impl_visible_in_any_scope {
+    ($($ty:ty),+ $(,)?) => {
+        $(
+            impl ScopedQueryOutput for $ty {
+                fn is_visible_in(&self, _scope: &DomainScope) -> bool {
+                    true
+                }
+            }
+        )+
+    };
+}
+
+impl_visible_in_any_scope!(Role, RoleId, Permission, Parameter, PeerId, BlockHeader);
+
+/// Restrict the outputs of `iter` to the `scope`, if any
+pub fn restrict<I>(iter: I, scope: Option<DomainScope>) -> impl Iterator<Item = I::Item>
+where
+    I: Iterator<Item: ScopedQueryOutput>,
+{
+    iter.filter(move |output| {
+        scope
+            .as_ref()
+            .is_none_or(|scope| output.is_visible_in(scope))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use iroha_test_samples::{gen_account_in, ALICE_ID};
+
+    use super::*;
+
+    #[test]
+    fn only_accounts_of_scoped_domains_are_visible() {
+        let (mad_hatter_id, _) = gen_account_in("tea_party");
+        let accounts = [
+            Account::new(ALICE_ID.clone()).build(&ALICE_ID),
+            Account::new(mad_hatter_id).build(&ALICE_ID),
+        ];
+        let scope = DomainScope::new(["wonderland".parse().unwrap()]);
+
+        let visible = restrict(accounts.clone().into_iter(), Some(scope)).collect::<Vec<_>>();
+        assert_eq!(visible, [accounts[0].clone()]);
+        assert_eq!(restrict(accounts.into_iter(), None).count(), 2);
+    }
+}
@@ -13,7 +13,7 @@ use iroha_data_model::{
     query::{
         error::QueryExecutionFail,
         parameters::{ForwardCursor, QueryId},
-        QueryOutput, QueryOutputBatchBoxTuple,
+        QueryOutput, QueryOutputBatchBoxTuple, QueryResponse,
     },
 };
 use iroha_futures::supervisor::{Child, OnShutdown, ShutdownSignal};
@@ -35,6 +35,10 @@ pub struct LiveQueryStore {
     capacity: NonZeroUsize,
     // The maximum number of queries in the store per user
     capacity_per_user: NonZeroUsize,
+    // The number of queries in the store from which no new queries are started
+    // and the batches of the live ones are cut to `shed_fetch_size`
+    shed_threshold: Option<NonZeroUsize>,
+    shed_fetch_size: NonZeroU64,
     // Queries older then this time will be automatically removed from the store
     idle_time: Duration,
     shutdown_signal: ShutdownSignal,
@@ -56,6 +60,8 @@ impl LiveQueryStore {
             idle_time: cfg.idle_time,
             capacity: cfg.capacity,
             capacity_per_user: cfg.capacity_per_user,
+            shed_threshold: cfg.shed_threshold,
+            shed_fetch_size: cfg.shed_fetch_size,
             shutdown_signal,
         }
     }
@@ -78,7 +84,7 @@ impl LiveQueryStore {
         let store = Arc::new(self);
         let handle = Arc::clone(&store).spawn_pruning_task();
         (
-            LiveQueryStoreHandle { store },
+            LiveQueryStoreHandle { store, shed: false },
             Child::new(
                 handle,
                 // should shutdown immediately anyway
@@ -155,19 +161,36 @@ impl LiveQueryStore {
         &self,
         query_id: QueryId,
         cursor: NonZeroU64,
-    ) -> Result<(QueryOutputBatchBoxTuple, u64, Option<NonZeroU64>), QueryExecutionFail> {
+        shed: bool,
+    ) -> Result<(QueryOutputBatchBoxTuple, u64, Option<NonZeroU64>, bool), QueryExecutionFail> {
         trace!(%query_id, "Advancing existing query");
+        // NOTE: the load is measured before the query is taken out of the store
+        let shed = shed && self.is_loaded();
         let QueryInfo {
             mut live_query,
             authority,
             ..
         } = self.remove(&query_id).ok_or(QueryExecutionFail::NotFound)?;
-        let (next_batch, next_cursor) = live_query.next_batch(cursor.get())?;
+        let max_batch_size = shed
+            .then_some(self.shed_fetch_size)
+            .filter(|shed_fetch_size| *shed_fetch_size < live_query.batch_size());
+        let (next_batch, next_cursor) = live_query.next_batch(cursor.get(), max_batch_size)?;
         let remaining = live_query.remaining();
         if next_cursor.is_some() {
             self.insert(query_id, live_query, authority);
         }
-        Ok((next_batch, remaining, next_cursor))
+        Ok((
+            next_batch,
+            remaining,
+            next_cursor,
+            max_batch_size.is_some() && next_cursor.is_some(),
+        ))
+    }
+
+    // Whether the store holds enough queries to shed the load
+    fn is_loaded(&self) -> bool {
+        self.shed_threshold
+            .is_some_and(|threshold| self.queries.len() >= threshold.get())
     }
 
     fn check_capacity(&self, authority: &AccountId) -> Result<(), QueryExecutionFail> {
@@ -196,12 +219,42 @@ impl LiveQueryStore {
 #[derive(Clone)]
 pub struct LiveQueryStoreHandle {
     store: Arc<LiveQueryStore>,
+    // Whether the load may be shed
+    shed: bool,
 }
 
 impl LiveQueryStoreHandle {
     /// Create a new handle for the store
     pub fn new(store: Arc<LiveQueryStore>) -> Self {
-        Self { store }
+        Self { store, shed: false }
+    }
+
+    /// Handle which refuses to start new queries and cuts the batches of the live ones short
+    /// once the store holds too many queries.
+    ///
+    /// Meant for the queries of the clients only: the queries of the smart contracts
+    /// must be served the same by every peer.
+    #[must_use]
+    pub fn with_load_shedding(&self) -> Self {
+        Self {
+            store: Arc::clone(&self.store),
+            shed: true,
+        }
+    }
+
+    /// Check that a new query may be started.
+    ///
+    /// Called before the query is executed, so that shedding it costs the peer nothing.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`QueryExecutionFail::Shed`] if the handle sheds the load and the store holds too many queries.
+    pub fn check_load(&self) -> Result<(), QueryExecutionFail> {
+        if self.shed && self.store.is_loaded() {
+            trace!("Shedding the load, new query is refused");
+            return Err(QueryExecutionFail::Shed);
+        }
+        Ok(())
     }
 
     /// Construct a batched response from a post-processed query output.
@@ -218,7 +271,7 @@ impl LiveQueryStoreHandle {
         let query_id = uuid::Uuid::new_v4().to_string();
 
         let curr_cursor = 0;
-        let (batch, next_cursor) = live_query.next_batch(curr_cursor)?;
+        let (batch, next_cursor) = live_query.next_batch(curr_cursor, None)?;
 
         // NOTE: we are checking remaining items _after_ the first batch is taken
         let remaining_items = live_query.remaining();
@@ -238,6 +291,8 @@ impl LiveQueryStoreHandle {
 
     /// Retrieve next batch of query output using `cursor`.
     ///
+    /// The batch is returned as [`QueryResponse::Shed`] if it was cut short under load.
+    ///
     /// # Errors
     ///
     /// - Returns an [`QueryExecutionFail`] if the query id is not found,
@@ -245,16 +300,17 @@ impl LiveQueryStoreHandle {
     pub fn handle_iter_continue(
         &self,
         ForwardCursor { query, cursor }: ForwardCursor,
-    ) -> Result<QueryOutput, QueryExecutionFail> {
-        let (batch, remaining, next_cursor) =
-            self.store.get_query_next_batch(query.clone(), cursor)?;
+    ) -> Result<QueryResponse, QueryExecutionFail> {
+        let (batch, remaining, next_cursor, shed) =
+            self.store
+                .get_query_next_batch(query.clone(), cursor, self.shed)?;
+        let output = Self::construct_query_response(batch, remaining, query, next_cursor);
 
-        Ok(Self::construct_query_response(
-            batch,
-            remaining,
-            query,
-            next_cursor,
-        ))
+        Ok(if shed {
+            QueryResponse::Shed(output)
+        } else {
+            QueryResponse::Iterable(output)
+        })
     }
 
     /// Remove query from the storage if there is any.
@@ -308,6 +364,7 @@ mod tests {
                 pagination,
                 sorting,
                 fetch_size,
+                aggregation: None,
             };
 
             // it's not important which type we use here, just to test the flow
@@ -329,7 +386,9 @@ mod tests {
             counter += batch.len();
 
             while let Some(cursor) = current_cursor {
-                let Ok(batched) = query_handle.handle_iter_continue(cursor) else {
+                let Ok(QueryResponse::Iterable(batched)) =
+                    query_handle.handle_iter_continue(cursor)
+                else {
                     break;
                 };
                 let (batch, _remaining_items, cursor) = batched.into_parts();
@@ -341,4 +400,68 @@ mod tests {
             assert_eq!(counter, 100, "failed on {i} iteration");
         }
     }
+
+    #[test]
+    fn batches_are_shed_under_load() {
+        let threaded_rt = tokio::runtime::Runtime::new().unwrap();
+        let config = Config {
+            shed_threshold: Some(nonzero!(1_usize)),
+            shed_fetch_size: nonzero!(2_u64),
+            ..Config::default()
+        };
+        let query_handle = threaded_rt.block_on(async {
+            LiveQueryStore::from_config(config, ShutdownSignal::new())
+                .start()
+                .0
+        });
+        let shedding_handle = query_handle.with_load_shedding();
+        let live_query = || {
+            let query_params = QueryParams {
+                fetch_size: FetchSize {
+                    fetch_size: Some(nonzero!(10_u64)),
+                },
+                ..QueryParams::default()
+            };
+            let query_output =
+                (0..100).map(|_| Permission::new(String::default(), Json::from(false)));
+            crate::smartcontracts::query::apply_query_postprocessing(
+                query_output,
+                SelectorTuple::default(),
+                &query_params,
+            )
+            .unwrap()
+        };
+
+        // The store is empty, so the first query isn't shed
+        shedding_handle.check_load().unwrap();
+        let output = shedding_handle
+            .handle_iter_start(live_query(), &ALICE_ID)
+            .unwrap();
+        assert_eq!(output.batch.len(), 10);
+
+        // No new queries are started as long as the load stays
+        assert!(matches!(
+            shedding_handle.check_load(),
+            Err(QueryExecutionFail::Shed)
+        ));
+        query_handle.check_load().unwrap();
+
+        // The live query is continued with shorter batches
+        let QueryResponse::Shed(output) = shedding_handle
+            .handle_iter_continue(output.continue_cursor.unwrap())
+            .unwrap()
+        else {
+            panic!("batch should be shed");
+        };
+        assert_eq!(output.batch.len(), 2);
+        assert_eq!(output.remaining_items, 88);
+
+        let QueryResponse::Iterable(output) = query_handle
+            .handle_iter_continue(output.continue_cursor.unwrap())
+            .unwrap()
+        else {
+            panic!("batch shouldn't be shed");
+        };
+        assert_eq!(output.batch.len(), 10);
+    }
 }
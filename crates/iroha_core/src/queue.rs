@@ -13,7 +13,7 @@ use iroha_data_model::{
     events::pipeline::{TransactionEvent, TransactionStatus},
     transaction::prelude::*,
 };
-use iroha_logger::{trace, warn};
+use iroha_logger::{info_span, trace, warn};
 use iroha_primitives::time::TimeSource;
 use thiserror::Error;
 
@@ -186,6 +186,7 @@ impl Queue {
     /// # Errors
     /// See [`enum@Error`]
     pub fn push(&self, tx: AcceptedTransaction, state_view: StateView) -> Result<(), Failure> {
+        let _span = info_span!("tx_queue", tx=%tx.as_ref().hash()).entered();
         trace!(tx=%tx.as_ref().hash(), "Pushing to the queue");
         if let Err(err) = self.check_tx(&tx, &state_view) {
             return Err(Failure { tx: tx.into(), err });
@@ -292,6 +293,11 @@ impl Queue {
         self.txs.len()
     }
 
+    /// Return the maximum number of transactions the queue can hold.
+    pub fn capacity(&self) -> NonZeroUsize {
+        self.capacity
+    }
+
     /// Gets transactions till they fill whole block or till the end of queue.
     ///
     /// BEWARE: Shouldn't be called in parallel with itself.
@@ -453,6 +459,7 @@ pub mod tests {
         let tx_limits = TransactionParameters {
             max_instructions: nonzero!(4096_u64),
             smart_contract_size: nonzero!(1024_u64),
+            ..TransactionParameters::default()
         };
         AcceptedTransaction::accept(tx, &chain_id, Duration::from_millis(10), tx_limits)
             .expect("Failed to accept Transaction.")
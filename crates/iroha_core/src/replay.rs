@@ -0,0 +1,307 @@
+//! Re-execution of a single committed transaction in isolation.
+//!
+//! The state preceding the block of the transaction is rebuilt from the blocks stored in [`Kura`],
+//! so that "why was this transaction rejected (or accepted)" can be answered long after the fact.
+//! Replaying the whole chain is expensive: this is a debugging tool, not something to serve to every client.
+//! [`Replayer`] keeps the latest rebuilt state and runs one replay at a time to bound the work.
+
+use std::{num::NonZeroUsize, sync::Arc};
+
+use iroha_crypto::HashOf;
+use iroha_data_model::{
+    events::EventBox, isi::InstructionBox, prelude::*, transaction::TransactionResultInner,
+};
+use iroha_logger::prelude::*;
+use parking_lot::Mutex;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+#[cfg(all(feature = "telemetry", not(test)))]
+use crate::telemetry::StateTelemetry;
+use crate::{
+    block::ValidBlock,
+    kura::Kura,
+    query::store::LiveQueryStoreHandle,
+    smartcontracts::wasm::cache::WasmCache,
+    state::{State, StateReadOnlyWithTransactions, TransactionsReadOnly, World, WorldReadOnly},
+    tx::AcceptedTransaction,
+};
+
+/// Outcome of re-executing a committed transaction against the state preceding its block.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TransactionReplay {
+    /// Height of the block the transaction was committed in.
+    pub block_height: NonZeroUsize,
+    /// Result of the re-execution: the data trigger sequence or the rejection reason.
+    pub result: TransactionResultInner,
+    /// Decision of the executor on every instruction of the transaction, in order.
+    ///
+    /// Each instruction is executed on top of the preceding ones regardless of their outcome.
+    /// Empty for WASM transactions, which the executor validates as a whole.
+    pub instructions: Vec<InstructionReplay>,
+    /// Events emitted by the re-execution.
+    pub events: Vec<EventBox>,
+}
+
+/// Decision of the executor on a single instruction.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct InstructionReplay {
+    /// The executed instruction.
+    pub instruction: InstructionBox,
+    /// Result of the execution.
+    pub result: Result<(), ValidationFail>,
+}
+
+/// Error which occurs while replaying a transaction.
+#[derive(Debug, displaydoc::Display, Clone, PartialEq, Eq, Error)]
+pub enum Error {
+    /// Transaction `{0}` is not committed
+    NotCommitted(HashOf<SignedTransaction>),
+    /// Block at height {0} is missing from the block storage
+    MissingBlock(NonZeroUsize),
+    /// Another transaction is being replayed
+    Busy,
+}
+
+/// Rebuild the state as it was after the block at `height` by applying the blocks stored in `kura` to an empty world.
+///
+/// The transactions are not re-executed, so the rebuilt state matches the one of the node which committed them.
+///
+/// # Errors
+/// Some block up to `height` is missing from `kura`
+pub fn rebuild_state(
+    kura: &Arc<Kura>,
+    query_handle: LiveQueryStoreHandle,
+    height: usize,
+) -> Result<State, Error> {
+    let state = empty_state(kura, query_handle);
+    apply_blocks(&state, kura, height)?;
+
+    Ok(state)
+}
+
+fn empty_state(kura: &Arc<Kura>, query_handle: LiveQueryStoreHandle) -> State {
+    State::new(
+        World::new(),
+        Arc::clone(kura),
+        query_handle,
+        // Keep the metrics of the node intact
+        #[cfg(all(feature = "telemetry", not(test)))]
+        StateTelemetry::default(),
+    )
+}
+
+/// Apply the blocks following the latest one of `state` up to `height`
+fn apply_blocks(state: &State, kura: &Kura, height: usize) -> Result<(), Error> {
+    for height in state.view().height() + 1..=height {
+        let height = NonZeroUsize::new(height).expect("iteration starts at 1");
+        let block = kura.get_block(height).ok_or(Error::MissingBlock(height))?;
+        trace!(%height, "Replaying block");
+
+        let mut state_block = state.block(block.header());
+        let block = ValidBlock::validate_unchecked((*block).clone(), &mut state_block)
+            .unpack(|_| {})
+            .commit_unchecked()
+            .unpack(|_| {});
+        let topology = state_block.world.peers().clone().into_iter().collect();
+        let _events = state_block.apply_without_execution(&block, topology);
+        state_block.commit();
+    }
+
+    Ok(())
+}
+
+/// Replays committed transactions one at a time.
+///
+/// The state rebuilt for the latest replay is kept: replaying a transaction of the same or a later block
+/// only applies the blocks committed in between instead of the whole chain.
+pub struct Replayer {
+    kura: Arc<Kura>,
+    query_handle: LiveQueryStoreHandle,
+    /// State rebuilt by the latest replay, locked for the duration of a replay
+    rebuilt: Mutex<Option<State>>,
+}
+
+impl Replayer {
+    /// Construct [`Self`] replaying the blocks of `kura`.
+    pub fn new(kura: Arc<Kura>, query_handle: LiveQueryStoreHandle) -> Self {
+        Self {
+            kura,
+            query_handle,
+            rebuilt: Mutex::new(None),
+        }
+    }
+
+    /// Re-execute the committed transaction identified by `hash` against the state as it was just before its block.
+    ///
+    /// The other transactions of the block are not executed, so the transaction is observed in isolation.
+    ///
+    /// # Errors
+    /// - Another transaction is being replayed
+    /// - The transaction is not committed according to `state`
+    /// - Some block preceding the transaction is missing from `kura`
+    pub fn replay(
+        &self,
+        state: &State,
+        hash: HashOf<SignedTransaction>,
+    ) -> Result<TransactionReplay, Error> {
+        let mut rebuilt = self.rebuilt.try_lock().ok_or(Error::Busy)?;
+        let block_height = state
+            .view()
+            .transactions()
+            .get(&hash)
+            .ok_or(Error::NotCommitted(hash))?;
+        let historical = self.rebuild(&mut rebuilt, block_height.get() - 1)?;
+
+        replay_on(historical, &self.kura, block_height, hash)
+    }
+
+    /// Bring the kept state to `height`, starting over if it's already past it
+    fn rebuild<'state>(
+        &self,
+        rebuilt: &'state mut Option<State>,
+        height: usize,
+    ) -> Result<&'state State, Error> {
+        if rebuilt
+            .as_ref()
+            .is_some_and(|state| state.view().height() > height)
+        {
+            *rebuilt = None;
+        }
+        let state =
+            rebuilt.get_or_insert_with(|| empty_state(&self.kura, self.query_handle.clone()));
+        // Blocks are committed one by one, so the state is kept consistent even if some block is missing
+        apply_blocks(state, &self.kura, height)?;
+
+        Ok(state)
+    }
+}
+
+fn replay_on(
+    historical: &State,
+    kura: &Kura,
+    block_height: NonZeroUsize,
+    hash: HashOf<SignedTransaction>,
+) -> Result<TransactionReplay, Error> {
+    let block = kura
+        .get_block(block_height)
+        .ok_or(Error::MissingBlock(block_height))?;
+    let tx = block
+        .external_transactions()
+        .find(|tx| tx.hash() == hash)
+        .expect("INTERNAL BUG: transaction is not in the block it's recorded in")
+        .clone();
+    let authority = tx.authority().clone();
+    debug!(tx=%hash, block=%block_height, "Replaying transaction");
+
+    let mut state_block = historical.block(block.header());
+    state_block.activate_peer_changes(block.header().height().get());
+    state_block.expire_grants(block.header().creation_time());
+
+    let instructions = match tx.instructions() {
+        Executable::Instructions(instructions) => {
+            // Dropped without applying, only the decisions are of interest
+            let mut state_transaction = state_block.transaction();
+            let executor = state_transaction.world.executor.clone();
+            instructions
+                .iter()
+                .cloned()
+                .map(|instruction| {
+                    let result = executor.execute_instruction(
+                        &mut state_transaction,
+                        &authority,
+                        instruction.clone(),
+                    );
+                    debug!(?instruction, ?result, "Executor decision");
+                    InstructionReplay {
+                        instruction,
+                        result,
+                    }
+                })
+                .collect()
+        }
+        Executable::Wasm(_) => Vec::new(),
+    };
+
+    let (_, result) = state_block.validate_transaction(
+        AcceptedTransaction::new_unchecked(tx),
+        &mut WasmCache::new(),
+    );
+    debug!(tx=%hash, ?result, "Transaction replayed");
+    let events: Vec<EventBox> = core::mem::take(&mut state_block.world.external_event_buf);
+
+    Ok(TransactionReplay {
+        block_height,
+        result,
+        instructions,
+        events,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::num::NonZeroU64;
+
+    use iroha_crypto::{Hash, KeyPair};
+
+    use super::*;
+    use crate::{query::store::LiveQueryStore, sumeragi::network_topology::Topology};
+
+    fn kura_with_blocks(count: u64) -> Arc<Kura> {
+        let kura = Kura::blank_kura_for_testing();
+        let (leader_public_key, leader_private_key) = KeyPair::random().into_parts();
+        let topology = Topology::new(vec![PeerId::new(leader_public_key)]);
+
+        let mut prev_block_hash = None;
+        for height in 1..=count {
+            let block = ValidBlock::new_dummy_and_modify_header(&leader_private_key, |header| {
+                header.height = NonZeroU64::new(height).unwrap();
+                header.prev_block_hash = prev_block_hash;
+            })
+            .commit(&topology)
+            .unpack(|_| {})
+            .unwrap();
+            prev_block_hash = Some(block.as_ref().hash());
+            kura.store_block(block);
+        }
+
+        kura
+    }
+
+    #[test]
+    fn rebuilt_state_is_kept_for_later_blocks() {
+        let replayer = Replayer::new(kura_with_blocks(5), LiveQueryStore::start_test());
+        let mut rebuilt = replayer.rebuilt.lock();
+
+        let state: *const State = replayer.rebuild(&mut rebuilt, 2).unwrap();
+        let extended = replayer.rebuild(&mut rebuilt, 4).unwrap();
+        assert_eq!(extended.view().height(), 4);
+        assert!(core::ptr::eq(state, extended));
+
+        let earlier = replayer.rebuild(&mut rebuilt, 1).unwrap();
+        assert_eq!(earlier.view().height(), 1);
+
+        assert_eq!(
+            replayer.rebuild(&mut rebuilt, 7).map(|_| ()),
+            Err(Error::MissingBlock(NonZeroUsize::new(6).unwrap()))
+        );
+        assert_eq!(rebuilt.as_ref().unwrap().view().height(), 5);
+    }
+
+    #[test]
+    fn one_transaction_is_replayed_at_a_time() {
+        let kura = kura_with_blocks(1);
+        let query_handle = LiveQueryStore::start_test();
+        let state = State::new(World::default(), Arc::clone(&kura), query_handle.clone());
+        let replayer = Replayer::new(kura, query_handle);
+        let hash = HashOf::from_untyped_unchecked(Hash::new([42]));
+
+        let replaying = replayer.rebuilt.lock();
+        assert_eq!(replayer.replay(&state, hash), Err(Error::Busy));
+        drop(replaying);
+        assert_eq!(
+            replayer.replay(&state, hash),
+            Err(Error::NotCommitted(hash))
+        );
+    }
+}
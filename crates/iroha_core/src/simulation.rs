@@ -0,0 +1,322 @@
+//! Execution of a transaction against the current state without committing it.
+//!
+//! Lets the clients check how a transaction would be processed (e.g. whether a complex batch of instructions
+//! is permitted and which events it emits) and how much of the limited resources it consumes before submitting it.
+//! The transaction is executed in a block on top of the latest committed one, which is dropped without committing
+//! like the blocks failing validation are, so nothing is committed or gossiped.
+
+use std::time::SystemTime;
+
+use iroha_data_model::{
+    block::BlockHeader,
+    prelude::*,
+    query::error::FindError,
+    transaction::{
+        error::TransactionRejectionReason,
+        simulation::{
+            InstructionOutcome, ResourceUsage, TransactionEstimate, TransactionSimulation,
+        },
+        TransactionResultInner,
+    },
+};
+use iroha_logger::prelude::*;
+use nonzero_ext::nonzero;
+
+use crate::{
+    smartcontracts::wasm,
+    state::{State, StateReadOnly, StateTransaction, WorldReadOnly},
+    tx::AcceptedTransaction,
+};
+
+/// Reason the transaction can't be simulated
+#[derive(Debug, Clone, Copy, PartialEq, Eq, displaydoc::Display, thiserror::Error)]
+pub enum SimulationError {
+    /// Simulation of WASM smart contracts is not supported, since the executor validates them as a whole
+    WasmNotSupported,
+}
+
+/// Execute `tx` on top of the latest committed block of `state` and discard the changes.
+///
+/// The instructions are executed once, one by one until the first one the executor rejects,
+/// and then the data triggers they've set off.
+///
+/// # Errors
+/// If `tx` is a WASM smart contract
+pub fn simulate_transaction(
+    state: &State,
+    tx: AcceptedTransaction,
+) -> Result<TransactionSimulation, SimulationError> {
+    let Executable::Instructions(instructions) = tx.as_ref().instructions() else {
+        return Err(SimulationError::WasmNotSupported);
+    };
+    let hash = tx.as_ref().hash();
+    let authority = tx.as_ref().authority().clone();
+    let header = next_block_header(state);
+    debug!(tx=%hash, height=%header.height, "Simulating transaction");
+
+    // Dropped without committing
+    let mut state_block = state.block(header);
+    let mut state_transaction = state_block.transaction();
+    let mut outcomes = Vec::with_capacity(instructions.len());
+    let result = execute_instructions(
+        &mut state_transaction,
+        &authority,
+        instructions,
+        &mut outcomes,
+    );
+    if result.is_ok() {
+        state_transaction.apply();
+    } else {
+        drop(state_transaction);
+    }
+    debug!(tx=%hash, ?result, "Transaction simulated");
+    let events = core::mem::take(&mut state_block.world.external_event_buf);
+
+    Ok(TransactionSimulation {
+        result,
+        instructions: outcomes,
+        events,
+    })
+}
+
+/// Execute `instructions` until the first rejected one, recording the decision of the executor on each of them
+fn execute_instructions(
+    state_transaction: &mut StateTransaction<'_, '_>,
+    authority: &AccountId,
+    instructions: &[InstructionBox],
+    outcomes: &mut Vec<InstructionOutcome>,
+) -> TransactionResultInner {
+    if state_transaction.world.account(authority).is_err() {
+        return Err(TransactionRejectionReason::AccountDoesNotExist(
+            FindError::Account(authority.clone()),
+        ));
+    }
+
+    let executor = state_transaction.world.executor.clone();
+    for instruction in instructions.iter().cloned() {
+        let result =
+            executor.execute_instruction(state_transaction, authority, instruction.clone());
+        outcomes.push(InstructionOutcome {
+            instruction,
+            result: result.clone(),
+        });
+        result?;
+    }
+
+    state_transaction.execute_data_triggers_dfs(authority)
+}
+
+/// Estimate the resources `tx` consumes against the limits set by the chain parameters of `state`.
+///
+/// WASM transactions are executed on top of the latest committed block to meter the fuel, and the changes are discarded.
+/// The fuel the executor consumes validating the transaction isn't accounted for.
+pub fn estimate_transaction(state: &State, tx: &SignedTransaction) -> TransactionEstimate {
+    let (transaction_limits, smart_contract_limits) = {
+        let state_view = state.view();
+        let parameters = state_view.world().parameters();
+        (parameters.transaction(), parameters.smart_contract())
+    };
+
+    match tx.instructions() {
+        Executable::Instructions(instructions) => TransactionEstimate {
+            instructions: Some(ResourceUsage {
+                used: instructions
+                    .iter()
+                    .map(InstructionBox::instruction_count)
+                    .sum::<usize>() as u64,
+                limit: transaction_limits.max_instructions().get(),
+            }),
+            smart_contract_size: None,
+            fuel: None,
+        },
+        Executable::Wasm(smart_contract) => {
+            let limit = smart_contract_limits.fuel().get();
+            // Dropped without committing
+            let mut state_block = state.block(next_block_header(state));
+            let mut state_transaction = state_block.transaction();
+            let fuel = wasm::RuntimeBuilder::<wasm::state::SmartContract>::new()
+                .with_config(smart_contract_limits)
+                .with_engine(state.engine.clone())
+                .build()
+                .map(|mut runtime| {
+                    let (result, used) = runtime.execute_metered(
+                        &mut state_transaction,
+                        tx.authority().clone(),
+                        smart_contract,
+                    );
+                    debug!(tx=%tx.hash(), used, ?result, "Smart contract metered");
+                    ResourceUsage {
+                        used: if result.is_err() && used >= limit {
+                            limit.saturating_add(1)
+                        } else {
+                            used
+                        },
+                        limit,
+                    }
+                })
+                .inspect_err(|error| warn!(?error, "Failed to create WASM runtime"))
+                .ok();
+
+            TransactionEstimate {
+                instructions: None,
+                smart_contract_size: Some(ResourceUsage {
+                    used: smart_contract.size_bytes() as u64,
+                    limit: transaction_limits.smart_contract_size().get(),
+                }),
+                fuel,
+            }
+        }
+    }
+}
+
+/// Header of the block which would follow the latest committed one
+fn next_block_header(state: &State) -> BlockHeader {
+    let latest_block = state.view().latest_block();
+    let now_ms = SystemTime::now()
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .map_or(0, |now| now.as_millis().try_into().unwrap_or(u64::MAX));
+
+    BlockHeader {
+        height: latest_block.as_ref().map_or(nonzero!(1_u64), |block| {
+            block.header().height().saturating_add(1)
+        }),
+        prev_block_hash: latest_block.as_ref().map(|block| block.hash()),
+        merkle_root: None,
+        result_merkle_root: None,
+        creation_time_ms: latest_block.as_ref().map_or(now_ms, |block| {
+            now_ms.max(block.header().creation_time_ms.saturating_add(1))
+        }),
+        view_change_index: 0,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use iroha_test_samples::{ALICE_ID, ALICE_KEYPAIR};
+
+    use super::*;
+    use crate::{kura::Kura, query::store::LiveQueryStore, state::World};
+
+    fn state() -> State {
+        let domain_id: DomainId = "wonderland".parse().unwrap();
+        State::new(
+            World::with(
+                [Domain::new(domain_id).build(&ALICE_ID)],
+                [Account::new(ALICE_ID.clone()).build(&ALICE_ID)],
+                [],
+            ),
+            Kura::blank_kura_for_testing(),
+            LiveQueryStore::start_test(),
+        )
+    }
+
+    #[tokio::test]
+    async fn simulated_transaction_is_not_committed() {
+        let state = state();
+        let domain_id: DomainId = "wonderland".parse().unwrap();
+        let chain_id = ChainId::from("00000000-0000-0000-0000-000000000000");
+        let new_domain: DomainId = "looking_glass".parse().unwrap();
+        let tx = TransactionBuilder::new(chain_id, ALICE_ID.clone())
+            .with_instructions([
+                InstructionBox::from(Register::domain(Domain::new(new_domain.clone()))),
+                Register::domain(Domain::new(domain_id)).into(),
+            ])
+            .sign(ALICE_KEYPAIR.private_key());
+        let tx = AcceptedTransaction::new_unchecked(tx);
+
+        let simulation = simulate_transaction(&state, tx).unwrap();
+
+        assert!(!simulation.is_ok());
+        assert_eq!(simulation.instructions.len(), 2);
+        assert!(simulation.instructions[0].result.is_ok());
+        assert!(simulation.instructions[1].result.is_err());
+        assert!(state.view().world().domain(&new_domain).is_err());
+    }
+
+    #[tokio::test]
+    async fn simulated_instructions_are_executed_once() {
+        let state = state();
+        let chain_id = ChainId::from("00000000-0000-0000-0000-000000000000");
+        let new_domain: DomainId = "looking_glass".parse().unwrap();
+        let tx = TransactionBuilder::new(chain_id, ALICE_ID.clone())
+            .with_instructions([Register::domain(Domain::new(new_domain.clone()))])
+            .sign(ALICE_KEYPAIR.private_key());
+
+        let simulation =
+            simulate_transaction(&state, AcceptedTransaction::new_unchecked(tx)).unwrap();
+
+        assert!(simulation.is_ok());
+        let created = simulation
+            .events
+            .iter()
+            .filter(|event| {
+                matches!(
+                    event,
+                    EventBox::Data(DataEvent::Domain(DomainEvent::Created(domain)))
+                        if *domain.id() == new_domain
+                )
+            })
+            .count();
+        assert_eq!(created, 1);
+        assert!(state.view().world().domain(&new_domain).is_err());
+    }
+
+    #[tokio::test]
+    async fn simulation_stops_at_first_rejected_instruction() {
+        let state = state();
+        let domain_id: DomainId = "wonderland".parse().unwrap();
+        let chain_id = ChainId::from("00000000-0000-0000-0000-000000000000");
+        let tx = TransactionBuilder::new(chain_id.clone(), ALICE_ID.clone())
+            .with_instructions([
+                InstructionBox::from(Register::domain(Domain::new(domain_id))),
+                Register::domain(Domain::new("looking_glass".parse().unwrap())).into(),
+            ])
+            .sign(ALICE_KEYPAIR.private_key());
+
+        let simulation =
+            simulate_transaction(&state, AcceptedTransaction::new_unchecked(tx)).unwrap();
+
+        assert!(!simulation.is_ok());
+        assert_eq!(simulation.instructions.len(), 1);
+        assert!(simulation.instructions[0].result.is_err());
+
+        let tx = TransactionBuilder::new(chain_id, ALICE_ID.clone())
+            .with_wasm(WasmSmartContract::from_compiled(vec![0_u8; 8]))
+            .sign(ALICE_KEYPAIR.private_key());
+        assert_eq!(
+            simulate_transaction(&state, AcceptedTransaction::new_unchecked(tx)),
+            Err(SimulationError::WasmNotSupported)
+        );
+    }
+
+    #[tokio::test]
+    async fn instructions_are_estimated_against_limit() {
+        let state = state();
+        let max_instructions = state
+            .view()
+            .world()
+            .parameters()
+            .transaction()
+            .max_instructions()
+            .get();
+        let chain_id = ChainId::from("00000000-0000-0000-0000-000000000000");
+        let log = Log::new(Level::INFO, "estimated".to_owned());
+        let tx = TransactionBuilder::new(chain_id, ALICE_ID.clone())
+            .with_instructions(
+                std::iter::repeat(log).take(usize::try_from(max_instructions).unwrap() + 1),
+            )
+            .sign(ALICE_KEYPAIR.private_key());
+
+        let estimate = estimate_transaction(&state, &tx);
+
+        assert_eq!(
+            estimate.instructions,
+            Some(ResourceUsage {
+                used: max_instructions + 1,
+                limit: max_instructions,
+            })
+        );
+        assert_eq!(estimate.fuel, None);
+        assert!(!estimate.is_within_limits());
+    }
+}
@@ -13,13 +13,21 @@ use super::prelude::*;
 /// - grant permissions and roles
 /// - Revoke permissions or roles
 pub mod isi {
-    use iroha_data_model::isi::{
-        error::{MintabilityError, RepetitionError},
-        InstructionType,
+    use iroha_data_model::{
+        isi::{
+            error::{MathError, MintabilityError, RepetitionError},
+            InstructionType,
+        },
+        message::{
+            MAX_MESSAGES_PER_INBOX, MAX_MESSAGES_PER_SENDER, MAX_MESSAGE_PAYLOAD_LEN,
+            MAX_MESSAGE_TTL_BLOCKS,
+        },
+        permission::MAX_DELEGATION_DEPTH,
+        subscription::{MAX_SUBSCRIPTIONS_PER_ACCOUNT, MAX_WEBHOOK_URL_LEN},
     };
 
     use super::*;
-    use crate::{role::RoleIdWithOwner, state::StateTransaction};
+    use crate::{grant::Granted, state::StateTransaction};
 
     impl Execute for Transfer<Account, AssetDefinitionId, Account> {
         fn execute(
@@ -64,6 +72,7 @@ pub mod isi {
             state_transaction: &mut StateTransaction<'_, '_>,
         ) -> Result<(), Error> {
             let account_id = self.object;
+            check_metadata_limits(&self.value, state_transaction)?;
 
             state_transaction
                 .world
@@ -118,6 +127,23 @@ pub mod isi {
         }
     }
 
+    /// Check that a grant valid until `valid_until_ms` isn't expired by the current block
+    fn check_grant_expiry(
+        valid_until_ms: Option<u64>,
+        state_transaction: &StateTransaction<'_, '_>,
+    ) -> Result<(), Error> {
+        let block_time = state_transaction.curr_block.creation_time();
+        if valid_until_ms
+            .is_some_and(|valid_until_ms| u128::from(valid_until_ms) <= block_time.as_millis())
+        {
+            return Err(Error::InvariantViolation(
+                "Grant is expired before the current block".to_owned(),
+            ));
+        }
+
+        Ok(())
+    }
+
     impl Execute for Grant<Permission, Account> {
         #[metrics(+"grant_account_permission")]
         fn execute(
@@ -130,6 +156,7 @@ pub mod isi {
 
             // Check if account exists
             state_transaction.world.account_mut(&account_id)?;
+            check_grant_expiry(self.valid_until_ms, state_transaction)?;
 
             if state_transaction
                 .world
@@ -145,6 +172,13 @@ pub mod isi {
             state_transaction
                 .world
                 .add_account_permission(&account_id, permission.clone());
+            if let Some(valid_until_ms) = self.valid_until_ms {
+                state_transaction.world.add_grant_expiry(
+                    &account_id,
+                    Granted::Permission(permission.clone()),
+                    valid_until_ms,
+                );
+            }
 
             state_transaction
                 .world
@@ -178,15 +212,86 @@ pub mod isi {
             {
                 return Err(FindError::Permission(permission.into()).into());
             }
+            state_transaction
+                .world
+                .remove_incoming_delegation(&account_id, &permission);
 
             state_transaction
                 .world
                 .emit_events(Some(AccountEvent::PermissionRemoved(
                     AccountPermissionChanged {
-                        account: account_id,
-                        permission,
+                        account: account_id.clone(),
+                        permission: permission.clone(),
                     },
                 )));
+            state_transaction
+                .world
+                .revoke_delegations(&account_id, &permission);
+
+            Ok(())
+        }
+    }
+
+    impl Execute for Delegate {
+        #[metrics(+"delegate_permission")]
+        fn execute(
+            self,
+            authority: &AccountId,
+            state_transaction: &mut StateTransaction<'_, '_>,
+        ) -> Result<(), Error> {
+            let account_id = self.destination;
+            let permission = self.permission;
+
+            // Check if account exists
+            state_transaction.world.account(&account_id)?;
+
+            // Permissions held through roles belong to the role and can't be delegated
+            if !state_transaction
+                .world
+                .account_contains_inherent_permission(authority, &permission)
+            {
+                return Err(FindError::Permission(permission.into()).into());
+            }
+
+            let depth = state_transaction
+                .world
+                .incoming_delegation(authority, &permission)
+                .map_or(1, |delegation| delegation.depth.saturating_add(1));
+            if depth > MAX_DELEGATION_DEPTH {
+                return Err(Error::InvariantViolation(format!(
+                    "Permission {permission} can't be delegated more than {MAX_DELEGATION_DEPTH} times"
+                )));
+            }
+
+            if state_transaction
+                .world
+                .account_contains_inherent_permission(&account_id, &permission)
+            {
+                return Err(RepetitionError {
+                    instruction: InstructionType::Delegate,
+                    id: permission.into(),
+                }
+                .into());
+            }
+
+            let delegation = Delegation {
+                delegator: authority.clone(),
+                delegate: account_id.clone(),
+                permission: permission.clone(),
+                depth,
+            };
+            state_transaction
+                .world
+                .add_account_permission(&account_id, permission.clone());
+            state_transaction.world.add_delegation(delegation.clone());
+
+            state_transaction.world.emit_events([
+                AccountEvent::PermissionAdded(AccountPermissionChanged {
+                    account: account_id,
+                    permission,
+                }),
+                AccountEvent::PermissionDelegated(delegation),
+            ]);
 
             Ok(())
         }
@@ -204,15 +309,11 @@ pub mod isi {
 
             state_transaction.world.role(&role_id)?;
             state_transaction.world.account(&account_id)?;
+            check_grant_expiry(self.valid_until_ms, state_transaction)?;
 
-            if state_transaction
+            if !state_transaction
                 .world
-                .account_roles
-                .insert(
-                    RoleIdWithOwner::new(account_id.clone(), role_id.clone()),
-                    (),
-                )
-                .is_some()
+                .add_account_role(&account_id, &role_id)
             {
                 return Err(RepetitionError {
                     instruction: InstructionType::Grant,
@@ -220,6 +321,13 @@ pub mod isi {
                 }
                 .into());
             }
+            if let Some(valid_until_ms) = self.valid_until_ms {
+                state_transaction.world.add_grant_expiry(
+                    &account_id,
+                    Granted::Role(role_id.clone()),
+                    valid_until_ms,
+                );
+            }
 
             state_transaction
                 .world
@@ -242,14 +350,9 @@ pub mod isi {
             let account_id = self.destination;
             let role_id = self.object;
 
-            if state_transaction
+            if !state_transaction
                 .world
-                .account_roles
-                .remove(RoleIdWithOwner {
-                    account: account_id.clone(),
-                    id: role_id.clone(),
-                })
-                .is_none()
+                .remove_account_role(&account_id, &role_id)
             {
                 return Err(FindError::Role(role_id).into());
             }
@@ -265,6 +368,198 @@ pub mod isi {
         }
     }
 
+    impl Execute for AddTag {
+        #[metrics(+"add_tag")]
+        fn execute(
+            self,
+            _authority: &AccountId,
+            state_transaction: &mut StateTransaction<'_, '_>,
+        ) -> Result<(), Error> {
+            let AddTag { account, tag } = self;
+
+            if !state_transaction
+                .world
+                .add_account_tag(&account, tag.clone())?
+            {
+                return Err(Error::InvariantViolation(format!(
+                    "Account {account} is already tagged with `{tag}`"
+                )));
+            }
+
+            state_transaction
+                .world
+                .emit_events(Some(AccountEvent::TagAdded(AccountTagChanged {
+                    account,
+                    tag,
+                })));
+
+            Ok(())
+        }
+    }
+
+    impl Execute for RemoveTag {
+        #[metrics(+"remove_tag")]
+        fn execute(
+            self,
+            _authority: &AccountId,
+            state_transaction: &mut StateTransaction<'_, '_>,
+        ) -> Result<(), Error> {
+            let RemoveTag { account, tag } = self;
+
+            if !state_transaction.world.remove_account_tag(&account, &tag)? {
+                return Err(Error::InvariantViolation(format!(
+                    "Account {account} isn't tagged with `{tag}`"
+                )));
+            }
+
+            state_transaction
+                .world
+                .emit_events(Some(AccountEvent::TagRemoved(AccountTagChanged {
+                    account,
+                    tag,
+                })));
+
+            Ok(())
+        }
+    }
+
+    impl Execute for Subscribe {
+        #[metrics(+"subscribe")]
+        fn execute(
+            self,
+            _authority: &AccountId,
+            state_transaction: &mut StateTransaction<'_, '_>,
+        ) -> Result<(), Error> {
+            let subscription = self.subscription;
+            let subscription_id = subscription.id().clone();
+
+            let _ = state_transaction.world.account(&subscription_id.account)?;
+            if state_transaction
+                .world
+                .subscriptions
+                .get(&subscription_id)
+                .is_some()
+            {
+                return Err(Error::InvariantViolation(format!(
+                    "Subscription {subscription_id} is already registered"
+                )));
+            }
+            if let SubscriptionDelivery::Webhook(url) = subscription.delivery() {
+                if !(url.starts_with("https://") || url.starts_with("http://"))
+                    || url.len() > MAX_WEBHOOK_URL_LEN
+                {
+                    return Err(Error::InvariantViolation(format!(
+                        "Webhook of subscription {subscription_id} must be an `http` or `https` URL of at most {MAX_WEBHOOK_URL_LEN} bytes"
+                    )));
+                }
+            }
+            if state_transaction
+                .world
+                .account_subscriptions_iter(&subscription_id.account)
+                .count()
+                >= MAX_SUBSCRIPTIONS_PER_ACCOUNT
+            {
+                return Err(Error::InvariantViolation(format!(
+                    "Account {} already has {MAX_SUBSCRIPTIONS_PER_ACCOUNT} subscriptions",
+                    subscription_id.account
+                )));
+            }
+
+            state_transaction
+                .world
+                .subscriptions
+                .insert(subscription_id, subscription);
+
+            Ok(())
+        }
+    }
+
+    impl Execute for Unsubscribe {
+        #[metrics(+"unsubscribe")]
+        fn execute(
+            self,
+            _authority: &AccountId,
+            state_transaction: &mut StateTransaction<'_, '_>,
+        ) -> Result<(), Error> {
+            let subscription_id = self.subscription;
+
+            if state_transaction
+                .world
+                .subscriptions
+                .remove(subscription_id.clone())
+                .is_none()
+            {
+                return Err(FindError::Subscription(subscription_id).into());
+            }
+
+            Ok(())
+        }
+    }
+
+    impl Execute for Message {
+        #[metrics(+"message")]
+        fn execute(
+            self,
+            authority: &AccountId,
+            state_transaction: &mut StateTransaction<'_, '_>,
+        ) -> Result<(), Error> {
+            let Message {
+                recipient,
+                payload,
+                ttl,
+            } = self;
+
+            let _ = state_transaction.world.account(&recipient)?;
+            if payload.ciphertext.len() > MAX_MESSAGE_PAYLOAD_LEN {
+                return Err(Error::InvariantViolation(format!(
+                    "Message payload is {} bytes long, the limit is {MAX_MESSAGE_PAYLOAD_LEN}",
+                    payload.ciphertext.len()
+                )));
+            }
+            if ttl.get() > MAX_MESSAGE_TTL_BLOCKS {
+                return Err(Error::InvariantViolation(format!(
+                    "Message can't be kept for {ttl} blocks, the limit is {MAX_MESSAGE_TTL_BLOCKS}"
+                )));
+            }
+
+            let (inbox_len, sent_len, last_sequence) = state_transaction
+                .world
+                .inbox_iter(&recipient)
+                .fold((0, 0, None), |(len, sent_len, _), message| {
+                    let sent_len = sent_len + usize::from(message.sender == *authority);
+                    (len + 1, sent_len, Some(message.id.sequence))
+                });
+            if inbox_len >= MAX_MESSAGES_PER_INBOX {
+                return Err(Error::InvariantViolation(format!(
+                    "Inbox of {recipient} is full"
+                )));
+            }
+            if sent_len >= MAX_MESSAGES_PER_SENDER {
+                return Err(Error::InvariantViolation(format!(
+                    "{authority} already has {MAX_MESSAGES_PER_SENDER} messages in the inbox of {recipient}"
+                )));
+            }
+
+            let sent_at = state_transaction.curr_block.height();
+            let expires_at = sent_at.checked_add(ttl.get()).ok_or(MathError::Overflow)?;
+            let message_id =
+                DirectMessageId::new(recipient, last_sequence.map_or(0, |sequence| sequence + 1));
+            state_transaction.world.insert_message(DirectMessage::new(
+                message_id.clone(),
+                authority.clone(),
+                payload,
+                sent_at,
+                expires_at,
+            ));
+
+            state_transaction
+                .world
+                .emit_events(Some(AccountEvent::MessageReceived(message_id)));
+
+            Ok(())
+        }
+    }
+
     /// Stop minting on the [`AssetDefinition`] globally.
     ///
     /// # Errors
@@ -305,9 +600,13 @@ pub mod query {
         permission::Permission,
         query::{dsl::CompoundPredicate, error::QueryExecutionFail as Error},
     };
+    use mv::storage::StorageReadOnly;
 
     use super::*;
-    use crate::{smartcontracts::ValidQuery, state::StateReadOnly};
+    use crate::{
+        smartcontracts::{ValidQuery, ValidSingularQuery},
+        state::StateReadOnly,
+    };
 
     impl ValidQuery for FindRolesByAccountId {
         #[metrics(+"find_roles_by_account_id")]
@@ -342,6 +641,23 @@ pub mod query {
         }
     }
 
+    impl ValidSingularQuery for FindDelegations {
+        #[metrics(+"find_delegations")]
+        fn execute(&self, state_ro: &impl StateReadOnly) -> Result<Vec<Delegation>, Error> {
+            let account_id = &self.id;
+            state_ro.world().account(account_id)?;
+            let world = state_ro.world();
+            Ok(world
+                .delegations()
+                .get(account_id)
+                .into_iter()
+                .chain(world.incoming_delegations().get(account_id))
+                .flatten()
+                .cloned()
+                .collect())
+        }
+    }
+
     impl ValidQuery for FindAccounts {
         #[metrics(+"find_accounts")]
         fn execute(
@@ -384,4 +700,74 @@ pub mod query {
                 .map(|account| account.to_owned()))
         }
     }
+
+    impl ValidQuery for FindAccountsWithRole {
+        #[metrics(+"find_accounts_with_role")]
+        fn execute(
+            self,
+            filter: CompoundPredicate<Account>,
+            state_ro: &impl StateReadOnly,
+        ) -> Result<impl Iterator<Item = Account>, Error> {
+            let role_id = &self.role;
+            state_ro.world().role(role_id)?;
+            Ok(state_ro
+                .world()
+                .role_holders_iter(role_id)
+                .map(move |account_id| {
+                    state_ro
+                        .world()
+                        .account(account_id)
+                        .expect("INTERNAL BUG: role holder must be registered")
+                })
+                .filter(move |account| filter.applies_to_entry(account))
+                .map(|account| account.to_owned()))
+        }
+    }
+
+    impl ValidQuery for FindAccountsWithTag {
+        #[metrics(+"find_accounts_with_tag")]
+        fn execute(
+            self,
+            filter: CompoundPredicate<Account>,
+            state_ro: &impl StateReadOnly,
+        ) -> Result<impl Iterator<Item = Account>, Error> {
+            let tag = self.tag;
+            Ok(state_ro
+                .world()
+                .tagged_accounts_iter(&tag)
+                .map(move |account_id| {
+                    state_ro
+                        .world()
+                        .account(account_id)
+                        .expect("INTERNAL BUG: tagged account must be registered")
+                })
+                .filter(move |account| filter.applies_to_entry(account))
+                .map(|account| account.to_owned()))
+        }
+    }
+
+    impl ValidSingularQuery for FindSubscription {
+        #[metrics(+"find_subscription")]
+        fn execute(&self, state_ro: &impl StateReadOnly) -> Result<Subscription, Error> {
+            Ok(state_ro.world().subscription(&self.id)?.clone())
+        }
+    }
+
+    impl ValidSingularQuery for FindInbox {
+        #[metrics(+"find_inbox")]
+        fn execute(&self, state_ro: &impl StateReadOnly) -> Result<Vec<DirectMessage>, Error> {
+            let account_id = &self.id;
+            state_ro.world().account(account_id)?;
+            Ok(state_ro.world().inbox_iter(account_id).cloned().collect())
+        }
+    }
+
+    impl ValidSingularQuery for FindEscrows {
+        #[metrics(+"find_escrows")]
+        fn execute(&self, state_ro: &impl StateReadOnly) -> Result<Vec<Escrow>, Error> {
+            let account_id = &self.id;
+            state_ro.world().account(account_id)?;
+            Ok(state_ro.world().escrows_iter(account_id).cloned().collect())
+        }
+    }
 }
@@ -16,6 +16,7 @@ use super::prelude::*;
 /// - transfer, etc.
 pub mod isi {
     use iroha_data_model::isi::error::MintabilityError;
+    use mv::storage::StorageReadOnly;
 
     use super::*;
     use crate::smartcontracts::account::isi::forbid_minting;
@@ -32,8 +33,9 @@ pub mod isi {
                 .world
                 .asset_definition(&asset_id.definition)?;
             assert_numeric_spec(&self.object, &asset_definition)?;
+            assert_within_supply_cap(self.object, &asset_definition)?;
 
-            assert_can_mint(&asset_definition, state_transaction)?;
+            assert_can_mint(self.object, &asset_definition, state_transaction)?;
             let asset = state_transaction
                 .world
                 .asset_or_insert(&asset_id, Numeric::ZERO)?;
@@ -76,6 +78,8 @@ pub mod isi {
                 .world
                 .asset_definition(&asset_id.definition)?;
             assert_numeric_spec(&self.object, &asset_definition)?;
+            assert_not_sunset(&asset_definition, state_transaction)?;
+            assert_not_frozen(&asset_id, &asset_definition, state_transaction)?;
 
             let asset = state_transaction
                 .world
@@ -131,6 +135,8 @@ pub mod isi {
                 .world
                 .asset_definition(&source_id.definition)?;
             assert_numeric_spec(&self.object, &asset_definition)?;
+            assert_not_sunset(&asset_definition, state_transaction)?;
+            assert_not_frozen(&source_id, &asset_definition, state_transaction)?;
 
             {
                 let asset = state_transaction
@@ -182,11 +188,687 @@ pub mod isi {
         }
     }
 
+    impl Execute for Split {
+        #[metrics(+"split_asset")]
+        fn execute(
+            self,
+            _authority: &AccountId,
+            state_transaction: &mut StateTransaction<'_, '_>,
+        ) -> Result<(), Error> {
+            let source_id = self.asset;
+
+            let asset_definition = state_transaction
+                .world
+                .asset_definition(&source_id.definition)?;
+            assert_not_sunset(&asset_definition, state_transaction)?;
+            assert_not_frozen(&source_id, &asset_definition, state_transaction)?;
+            let mut total = Numeric::ZERO;
+            for (account_id, quantity) in &self.parts {
+                assert_numeric_spec(quantity, &asset_definition)?;
+                if quantity.is_zero() {
+                    return Err(Error::InvariantViolation(format!(
+                        "Part of {source_id} credited to {account_id} must not be zero"
+                    )));
+                }
+                total = total.checked_add(*quantity).ok_or(MathError::Overflow)?;
+            }
+
+            let held = state_transaction.world.asset_mut(&source_id)?.value;
+            // Decimal arithmetic is exact, so any remainder means the parts were miscalculated
+            if total != held {
+                return Err(Error::InvariantViolation(format!(
+                    "Parts of {source_id} add up to {total} instead of the held {held}"
+                )));
+            }
+
+            let kept = self.parts.get(&source_id.account).copied();
+            if let Some(kept) = kept {
+                state_transaction.world.asset_mut(&source_id)?.value = kept;
+            } else {
+                assert!(state_transaction
+                    .world
+                    .assets
+                    .remove(source_id.clone())
+                    .is_some());
+            }
+
+            let mut events = Vec::with_capacity(self.parts.len() + 2);
+            events.push(AssetEvent::Removed(AssetChanged {
+                asset: source_id.clone(),
+                amount: held
+                    .checked_sub(kept.unwrap_or(Numeric::ZERO))
+                    .expect("INTERNAL BUG: kept part exceeds the held quantity"),
+            }));
+            let mut parts = Vec::with_capacity(self.parts.len());
+            for (account_id, quantity) in self.parts {
+                let destination_id = AssetId::new(source_id.definition.clone(), account_id);
+                if destination_id != source_id {
+                    let destination_asset = state_transaction
+                        .world
+                        .asset_or_insert(&destination_id, Numeric::ZERO)?;
+                    destination_asset.value = destination_asset
+                        .value
+                        .checked_add(quantity)
+                        .ok_or(MathError::Overflow)?;
+                    events.push(AssetEvent::Added(AssetChanged {
+                        asset: destination_id.clone(),
+                        amount: quantity,
+                    }));
+                }
+                parts.push(AssetChanged {
+                    asset: destination_id,
+                    amount: quantity,
+                });
+            }
+            events.push(AssetEvent::Split(AssetSplit {
+                asset: source_id,
+                parts,
+            }));
+
+            state_transaction.world.emit_events(events);
+
+            Ok(())
+        }
+    }
+
+    impl Execute for Merge {
+        #[metrics(+"merge_assets")]
+        fn execute(
+            self,
+            _authority: &AccountId,
+            state_transaction: &mut StateTransaction<'_, '_>,
+        ) -> Result<(), Error> {
+            let Some(definition_id) = self.assets.first().map(|asset_id| &asset_id.definition)
+            else {
+                return Err(Error::InvariantViolation(
+                    "At least one holding must be merged".to_owned(),
+                ));
+            };
+            if let Some(asset_id) = self
+                .assets
+                .iter()
+                .find(|asset_id| asset_id.definition != *definition_id)
+            {
+                return Err(Error::InvariantViolation(format!(
+                    "Can't merge {asset_id} with holdings of {definition_id}"
+                )));
+            }
+            let asset_definition = state_transaction.world.asset_definition(definition_id)?;
+            assert_numeric_kind(&asset_definition)?;
+            assert_not_sunset(&asset_definition, state_transaction)?;
+            let destination_id = AssetId::new(definition_id.clone(), self.destination);
+
+            let mut events = Vec::with_capacity(self.assets.len() + 2);
+            let mut sources = Vec::with_capacity(self.assets.len());
+            let mut total = Numeric::ZERO;
+            for asset_id in self.assets {
+                if asset_id == destination_id {
+                    continue;
+                }
+                assert_not_frozen(&asset_id, &asset_definition, state_transaction)?;
+                let quantity = state_transaction.world.asset_mut(&asset_id)?.value;
+                assert!(state_transaction
+                    .world
+                    .assets
+                    .remove(asset_id.clone())
+                    .is_some());
+                total = total.checked_add(quantity).ok_or(MathError::Overflow)?;
+
+                events.push(AssetEvent::Removed(AssetChanged {
+                    asset: asset_id.clone(),
+                    amount: quantity,
+                }));
+                sources.push(AssetChanged {
+                    asset: asset_id,
+                    amount: quantity,
+                });
+            }
+
+            let destination_asset = state_transaction
+                .world
+                .asset_or_insert(&destination_id, Numeric::ZERO)?;
+            destination_asset.value = destination_asset
+                .value
+                .checked_add(total)
+                .ok_or(MathError::Overflow)?;
+
+            events.push(AssetEvent::Added(AssetChanged {
+                asset: destination_id.clone(),
+                amount: total,
+            }));
+            events.push(AssetEvent::Merged(AssetMerged {
+                asset: destination_id,
+                sources,
+            }));
+
+            state_transaction.world.emit_events(events);
+
+            Ok(())
+        }
+    }
+
+    impl Execute for Payout {
+        #[metrics(+"payout")]
+        fn execute(
+            self,
+            _authority: &AccountId,
+            state_transaction: &mut StateTransaction<'_, '_>,
+        ) -> Result<(), Error> {
+            let source_id = self.source;
+
+            let asset_definition = state_transaction
+                .world
+                .asset_definition(&source_id.definition)?;
+            assert_not_sunset(&asset_definition, state_transaction)?;
+            assert_not_frozen(&source_id, &asset_definition, state_transaction)?;
+            let mut total = Numeric::ZERO;
+            for quantity in self.payments.values() {
+                assert_numeric_spec(quantity, &asset_definition)?;
+                total = total.checked_add(*quantity).ok_or(MathError::Overflow)?;
+            }
+
+            // The holding is looked up and debited once for all the payments
+            {
+                let asset = state_transaction
+                    .world
+                    .assets
+                    .get_mut(&source_id)
+                    .ok_or_else(|| FindError::Asset(source_id.clone().into()))?;
+                asset.value = asset
+                    .value
+                    .checked_sub(total)
+                    .ok_or(MathError::NotEnoughQuantity)?;
+                if asset.value.is_zero() {
+                    assert!(state_transaction
+                        .world
+                        .assets
+                        .remove(source_id.clone())
+                        .is_some());
+                }
+            }
+
+            let mut events = Vec::with_capacity(self.payments.len() + 1);
+            events.push(AssetEvent::Removed(AssetChanged {
+                asset: source_id.clone(),
+                amount: total,
+            }));
+            for (account_id, quantity) in self.payments {
+                let destination_id = AssetId::new(source_id.definition.clone(), account_id);
+                let destination_asset = state_transaction
+                    .world
+                    .asset_or_insert(&destination_id, Numeric::ZERO)?;
+                destination_asset.value = destination_asset
+                    .value
+                    .checked_add(quantity)
+                    .ok_or(MathError::Overflow)?;
+                events.push(AssetEvent::Added(AssetChanged {
+                    asset: destination_id,
+                    amount: quantity,
+                }));
+            }
+
+            #[allow(clippy::float_arithmetic)]
+            #[cfg(feature = "telemetry")]
+            state_transaction
+                .telemetry
+                .observe_tx_amount(total.to_f64());
+
+            state_transaction.world.emit_events(events);
+
+            Ok(())
+        }
+    }
+
+    impl Execute for TransferMany {
+        #[metrics(+"transfer_many")]
+        fn execute(
+            self,
+            authority: &AccountId,
+            state_transaction: &mut StateTransaction<'_, '_>,
+        ) -> Result<(), Error> {
+            // Failing transfer fails the whole transaction, reverting the ones made before it
+            for transfer in self.transfers() {
+                transfer.execute(authority, state_transaction)?;
+            }
+
+            Ok(())
+        }
+    }
+
+    impl Execute for Approve {
+        #[metrics(+"approve")]
+        fn execute(
+            self,
+            _authority: &AccountId,
+            state_transaction: &mut StateTransaction<'_, '_>,
+        ) -> Result<(), Error> {
+            state_transaction.world.account(&self.owner)?;
+            state_transaction.world.account(&self.spender)?;
+            let asset_definition = state_transaction
+                .world
+                .asset_definition(&self.asset_definition)?;
+            assert_numeric_spec(&self.limit, &asset_definition)?;
+
+            let allowance = Allowance {
+                owner: self.owner,
+                spender: self.spender,
+                asset_definition: self.asset_definition,
+                limit: self.limit,
+            };
+            state_transaction.world.set_allowance(allowance.clone());
+            state_transaction
+                .world
+                .emit_events(Some(AccountEvent::AllowanceChanged(allowance)));
+
+            Ok(())
+        }
+    }
+
+    impl Execute for TransferFrom {
+        #[metrics(+"transfer_from")]
+        fn execute(
+            self,
+            authority: &AccountId,
+            state_transaction: &mut StateTransaction<'_, '_>,
+        ) -> Result<(), Error> {
+            let source_id = self.source;
+
+            let mut allowance = state_transaction
+                .world
+                .allowance(&source_id.account, authority, &source_id.definition)
+                .cloned()
+                .ok_or_else(|| {
+                    Error::InvariantViolation(format!(
+                        "`{authority}` isn't allowed to spend `{source_id}`"
+                    ))
+                })?;
+            allowance.limit = allowance.limit.checked_sub(self.object).ok_or_else(|| {
+                Error::InvariantViolation(format!(
+                    "Transfer of `{}` exceeds the allowance of `{authority}` to spend `{source_id}`",
+                    self.object
+                ))
+            })?;
+
+            // The holding is debited as by its owner, so the transfer is subject to the same checks
+            Transfer::asset_numeric(source_id, self.object, self.destination)
+                .execute(authority, state_transaction)?;
+
+            state_transaction.world.set_allowance(allowance.clone());
+            state_transaction
+                .world
+                .emit_events(Some(AccountEvent::AllowanceChanged(allowance)));
+
+            Ok(())
+        }
+    }
+
+    impl Execute for Convert {
+        #[metrics(+"convert")]
+        fn execute(
+            self,
+            authority: &AccountId,
+            state_transaction: &mut StateTransaction<'_, '_>,
+        ) -> Result<(), Error> {
+            if self.from_definition == self.to_definition {
+                return Err(Error::InvariantViolation(format!(
+                    "Can't convert `{}` into itself",
+                    self.from_definition
+                )));
+            }
+            if self.rate.is_zero() {
+                return Err(Error::InvariantViolation(
+                    "Conversion rate must be positive".to_owned(),
+                ));
+            }
+            let source_id = AssetId::new(self.from_definition, self.holder.clone());
+            let destination_id = AssetId::new(self.to_definition, self.holder);
+
+            let to_spec = state_transaction
+                .world
+                .asset_definition(&destination_id.definition)?
+                .spec;
+            let burned = *state_transaction.world.asset(&source_id)?.value();
+            // Truncated rather than rounded, so that the holder never gets more than the rate gives
+            let minted = burned
+                .checked_mul(self.rate, NumericSpec::unconstrained())
+                .ok_or(MathError::Overflow)?
+                .trunc(to_spec);
+            if minted.is_zero() {
+                return Err(Error::InvariantViolation(format!(
+                    "Conversion of `{source_id}` at `{}` yields nothing",
+                    self.rate
+                )));
+            }
+
+            // Both holdings are changed as by the owners of the assets, so the same checks apply
+            Burn::asset_numeric(burned, source_id.clone()).execute(authority, state_transaction)?;
+            Mint::asset_numeric(minted, destination_id.clone())
+                .execute(authority, state_transaction)?;
+
+            state_transaction
+                .world
+                .emit_events(Some(AssetEvent::Converted(AssetConverted {
+                    asset: source_id,
+                    burned,
+                    destination: destination_id,
+                    minted,
+                    rate: self.rate,
+                })));
+
+            Ok(())
+        }
+    }
+
+    impl Execute for CreateEscrow {
+        #[metrics(+"create_escrow")]
+        fn execute(
+            self,
+            _authority: &AccountId,
+            state_transaction: &mut StateTransaction<'_, '_>,
+        ) -> Result<(), Error> {
+            let escrow = self.escrow;
+
+            state_transaction.world.account(&escrow.recipient)?;
+            if state_transaction.world.escrows.get(&escrow.id).is_some() {
+                return Err(Error::InvariantViolation(format!(
+                    "Escrow `{}` already exists",
+                    escrow.id
+                )));
+            }
+            if escrow.expires_at_ms <= escrow.release_at_ms {
+                return Err(Error::InvariantViolation(format!(
+                    "Escrow `{}` expires before it's released",
+                    escrow.id
+                )));
+            }
+            if escrow.amount.is_zero() {
+                return Err(Error::InvariantViolation(format!(
+                    "Escrow `{}` locks nothing",
+                    escrow.id
+                )));
+            }
+
+            let source_id = AssetId::new(escrow.asset_definition.clone(), escrow.id.sender.clone());
+            let asset_definition = state_transaction
+                .world
+                .asset_definition(&source_id.definition)?;
+            assert_numeric_spec(&escrow.amount, &asset_definition)?;
+            assert_not_sunset(&asset_definition, state_transaction)?;
+            assert_not_frozen(&source_id, &asset_definition, state_transaction)?;
+
+            {
+                let asset = state_transaction
+                    .world
+                    .assets
+                    .get_mut(&source_id)
+                    .ok_or_else(|| FindError::Asset(source_id.clone().into()))?;
+                asset.value = asset
+                    .value
+                    .checked_sub(escrow.amount)
+                    .ok_or(MathError::NotEnoughQuantity)?;
+                if asset.value.is_zero() {
+                    assert!(state_transaction
+                        .world
+                        .assets
+                        .remove(source_id.clone())
+                        .is_some());
+                }
+            }
+
+            state_transaction
+                .world
+                .escrows
+                .insert(escrow.id.clone(), escrow.clone());
+            state_transaction
+                .world
+                .emit_events(Some(AssetEvent::Removed(AssetChanged {
+                    asset: source_id,
+                    amount: escrow.amount,
+                })));
+            state_transaction
+                .world
+                .emit_events(Some(AccountEvent::EscrowCreated(escrow)));
+
+            Ok(())
+        }
+    }
+
+    impl Execute for ClaimEscrow {
+        #[metrics(+"claim_escrow")]
+        fn execute(
+            self,
+            _authority: &AccountId,
+            state_transaction: &mut StateTransaction<'_, '_>,
+        ) -> Result<(), Error> {
+            let escrow = state_transaction.world.escrow(&self.escrow)?;
+            if !escrow.is_claimable(state_transaction.curr_block.creation_time()) {
+                return Err(Error::InvariantViolation(format!(
+                    "Escrow `{}` can't be claimed until {} ms or after {} ms",
+                    escrow.id, escrow.release_at_ms, escrow.expires_at_ms
+                )));
+            }
+            let destination = escrow.recipient.clone();
+
+            close_escrow(self.escrow, destination, state_transaction)
+        }
+    }
+
+    impl Execute for RefundEscrow {
+        #[metrics(+"refund_escrow")]
+        fn execute(
+            self,
+            _authority: &AccountId,
+            state_transaction: &mut StateTransaction<'_, '_>,
+        ) -> Result<(), Error> {
+            let escrow = state_transaction.world.escrow(&self.escrow)?;
+            if !escrow.is_expired(state_transaction.curr_block.creation_time()) {
+                return Err(Error::InvariantViolation(format!(
+                    "Escrow `{}` can't be refunded until {} ms",
+                    escrow.id, escrow.expires_at_ms
+                )));
+            }
+            let destination = escrow.id.sender.clone();
+
+            close_escrow(self.escrow, destination, state_transaction)
+        }
+    }
+
+    /// Remove the escrow and credit the locked quantity to the `destination` account
+    fn close_escrow(
+        escrow_id: EscrowId,
+        destination: AccountId,
+        state_transaction: &mut StateTransaction<'_, '_>,
+    ) -> Result<(), Error> {
+        let escrow = state_transaction
+            .world
+            .escrows
+            .remove(escrow_id.clone())
+            .ok_or_else(|| FindError::Escrow(escrow_id.clone()))?;
+        let asset_definition = state_transaction
+            .world
+            .asset_definition(&escrow.asset_definition)?;
+        assert_not_sunset(&asset_definition, state_transaction)?;
+        let source_id = AssetId::new(escrow.asset_definition.clone(), escrow.id.sender.clone());
+        assert_not_frozen(&source_id, &asset_definition, state_transaction)?;
+        let destination_id = AssetId::new(escrow.asset_definition, destination);
+        let destination_asset = state_transaction
+            .world
+            .asset_or_insert(&destination_id, Numeric::ZERO)?;
+        destination_asset.value = destination_asset
+            .value
+            .checked_add(escrow.amount)
+            .ok_or(MathError::Overflow)?;
+
+        state_transaction
+            .world
+            .emit_events(Some(AssetEvent::Added(AssetChanged {
+                asset: destination_id,
+                amount: escrow.amount,
+            })));
+        state_transaction
+            .world
+            .emit_events(Some(AccountEvent::EscrowClosed(escrow_id)));
+
+        Ok(())
+    }
+
+    impl Execute for Freeze<Asset> {
+        #[metrics(+"freeze_asset")]
+        fn execute(
+            self,
+            _authority: &AccountId,
+            state_transaction: &mut StateTransaction<'_, '_>,
+        ) -> Result<(), Error> {
+            let asset_id = self.object;
+
+            let asset = state_transaction.world.asset_mut(&asset_id)?;
+            if asset.frozen {
+                return Err(Error::InvariantViolation(format!(
+                    "Asset `{asset_id}` is already frozen"
+                )));
+            }
+            asset.frozen = true;
+
+            state_transaction
+                .world
+                .emit_events(Some(AssetEvent::Frozen(asset_id)));
+
+            Ok(())
+        }
+    }
+
+    impl Execute for Unfreeze<Asset> {
+        #[metrics(+"unfreeze_asset")]
+        fn execute(
+            self,
+            _authority: &AccountId,
+            state_transaction: &mut StateTransaction<'_, '_>,
+        ) -> Result<(), Error> {
+            let asset_id = self.object;
+
+            let asset = state_transaction.world.asset_mut(&asset_id)?;
+            if !asset.frozen {
+                return Err(Error::InvariantViolation(format!(
+                    "Asset `{asset_id}` is not frozen"
+                )));
+            }
+            asset.frozen = false;
+
+            state_transaction
+                .world
+                .emit_events(Some(AssetEvent::Unfrozen(asset_id)));
+
+            Ok(())
+        }
+    }
+
+    impl Execute for SetKeyValue<Asset> {
+        #[metrics(+"set_asset_key_value")]
+        fn execute(
+            self,
+            _authority: &AccountId,
+            state_transaction: &mut StateTransaction<'_, '_>,
+        ) -> Result<(), Error> {
+            let asset_id = self.object;
+            check_metadata_limits(&self.value, state_transaction)?;
+
+            let kind = state_transaction
+                .world
+                .asset_definition(&asset_id.definition)?
+                .kind;
+            let asset = match kind {
+                AssetKind::Numeric => state_transaction.world.asset_mut(&asset_id)?,
+                AssetKind::Store => state_transaction
+                    .world
+                    .asset_or_insert(&asset_id, Numeric::ZERO)?,
+            };
+            if kind == AssetKind::Numeric
+                && !asset.metadata.contains(&self.key)
+                && asset.metadata.iter().len() >= Asset::MAX_METADATA_ENTRIES
+            {
+                return Err(Error::InvariantViolation(format!(
+                    "Asset {asset_id} can't hold more than {} metadata entries",
+                    Asset::MAX_METADATA_ENTRIES
+                )));
+            }
+            if kind == AssetKind::Numeric && self.value.get().len() > Asset::MAX_METADATA_VALUE_SIZE
+            {
+                return Err(Error::InvariantViolation(format!(
+                    "Asset {asset_id} can't hold metadata values larger than {} bytes",
+                    Asset::MAX_METADATA_VALUE_SIZE
+                )));
+            }
+            asset.metadata.insert(self.key.clone(), self.value.clone());
+
+            state_transaction
+                .world
+                .emit_events(Some(AssetEvent::MetadataInserted(MetadataChanged {
+                    target: asset_id,
+                    key: self.key,
+                    value: self.value,
+                })));
+
+            Ok(())
+        }
+    }
+
+    impl Execute for RemoveKeyValue<Asset> {
+        #[metrics(+"remove_asset_key_value")]
+        fn execute(
+            self,
+            _authority: &AccountId,
+            state_transaction: &mut StateTransaction<'_, '_>,
+        ) -> Result<(), Error> {
+            let asset_id = self.object;
+
+            let value = state_transaction
+                .world
+                .asset_mut(&asset_id)
+                .and_then(|asset| {
+                    asset
+                        .metadata
+                        .remove(&self.key)
+                        .ok_or_else(|| FindError::MetadataKey(self.key.clone()))
+                })?;
+
+            let is_empty_store = state_transaction
+                .world
+                .asset_definition(&asset_id.definition)?
+                .kind
+                == AssetKind::Store
+                && state_transaction
+                    .world
+                    .asset_mut(&asset_id)?
+                    .metadata
+                    .iter()
+                    .len()
+                    == 0;
+            if is_empty_store {
+                assert!(state_transaction
+                    .world
+                    .assets
+                    .remove(asset_id.clone())
+                    .is_some());
+            }
+
+            state_transaction
+                .world
+                .emit_events(Some(AssetEvent::MetadataRemoved(MetadataChanged {
+                    target: asset_id,
+                    key: self.key,
+                    value,
+                })));
+
+            Ok(())
+        }
+    }
+
     /// Assert that asset type is Numeric and that it satisfy asset definition spec
     pub(crate) fn assert_numeric_spec(
         object: &Numeric,
         asset_definition: &AssetDefinition,
     ) -> Result<NumericSpec, Error> {
+        assert_numeric_kind(asset_definition)?;
         let object_spec = NumericSpec::fractional(object.scale());
         let asset_spec = asset_definition.spec;
         asset_spec.check(object).map_err(|_| {
@@ -198,11 +880,27 @@ pub mod isi {
         Ok(asset_spec)
     }
 
-    /// Assert that this asset is `mintable`.
+    /// Assert that the holdings of the asset are quantities rather than key-value stores
+    fn assert_numeric_kind(asset_definition: &AssetDefinition) -> Result<(), Error> {
+        if asset_definition.kind != AssetKind::Numeric {
+            return Err(TypeError::from(Mismatch {
+                expected: asset_definition.kind,
+                actual: AssetKind::Numeric,
+            })
+            .into());
+        }
+        Ok(())
+    }
+
+    /// Assert that `amount` of this asset is `mintable`.
     fn assert_can_mint(
+        amount: Numeric,
         asset_definition: &AssetDefinition,
         state_transaction: &mut StateTransaction<'_, '_>,
     ) -> Result<(), Error> {
+        if asset_definition.deprecation.is_some() {
+            return Err(Error::Mintability(MintabilityError::MintDeprecated));
+        }
         match asset_definition.mintable {
             Mintable::Infinitely => Ok(()),
             Mintable::Not => Err(Error::Mintability(MintabilityError::MintUnmintable)),
@@ -217,8 +915,91 @@ pub mod isi {
                 ));
                 Ok(())
             }
+            Mintable::Periodic(_) if state_transaction.curr_block.is_genesis() => Ok(()),
+            Mintable::Periodic(rate) => {
+                let block_time_ms =
+                    u64::try_from(state_transaction.curr_block.creation_time().as_millis())
+                        .expect("INTERNAL BUG: block creation time doesn't fit into u64");
+                let period = block_time_ms / rate.period_ms.get();
+                let minted = asset_definition
+                    .minted_in_period
+                    .filter(|minting| minting.period == period)
+                    .map_or(Numeric::ZERO, |minting| minting.minted)
+                    .checked_add(amount)
+                    .ok_or(MathError::Overflow)?;
+                if minted > rate.amount {
+                    return Err(Error::Mintability(MintabilityError::PeriodLimitExceeded));
+                }
+
+                state_transaction
+                    .world
+                    .asset_definition_mut(&asset_definition.id)?
+                    .minted_in_period = Some(MintedInPeriod { period, minted });
+                Ok(())
+            }
         }
     }
+
+    /// Assert that minting `amount` keeps the total quantity of the asset within its maximum supply
+    fn assert_within_supply_cap(
+        amount: Numeric,
+        asset_definition: &AssetDefinition,
+    ) -> Result<(), Error> {
+        let Some(max_supply) = asset_definition.max_supply else {
+            return Ok(());
+        };
+        let total_quantity = asset_definition
+            .total_quantity
+            .checked_add(amount)
+            .ok_or(MathError::Overflow)?;
+        if total_quantity > max_supply {
+            return Err(Error::Mintability(MintabilityError::SupplyCapExceeded));
+        }
+        Ok(())
+    }
+
+    /// Holdings of a deprecated asset are frozen once its sunset window is over
+    fn assert_not_sunset(
+        asset_definition: &AssetDefinition,
+        state_transaction: &StateTransaction<'_, '_>,
+    ) -> Result<(), Error> {
+        match asset_definition.deprecation {
+            Some(deprecation)
+                if deprecation.is_sunset_over(state_transaction.curr_block.creation_time()) =>
+            {
+                Err(Error::InvariantViolation(format!(
+                    "Sunset window of the deprecated asset `{}` is over",
+                    asset_definition.id
+                )))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Quantity can't leave a holding while it or all holdings of its asset are frozen
+    fn assert_not_frozen(
+        asset_id: &AssetId,
+        asset_definition: &AssetDefinition,
+        state_transaction: &StateTransaction<'_, '_>,
+    ) -> Result<(), Error> {
+        if asset_definition.frozen {
+            return Err(Error::InvariantViolation(format!(
+                "Holdings of the asset `{}` are frozen",
+                asset_definition.id
+            )));
+        }
+        if state_transaction
+            .world
+            .assets
+            .get(asset_id)
+            .is_some_and(|asset| asset.frozen)
+        {
+            return Err(Error::InvariantViolation(format!(
+                "Asset `{asset_id}` is frozen"
+            )));
+        }
+        Ok(())
+    }
 }
 
 /// Asset-related query implementations.
@@ -226,11 +1007,14 @@ pub mod query {
     use eyre::Result;
     use iroha_data_model::{
         asset::{Asset, AssetDefinition},
-        query::{dsl::CompoundPredicate, error::QueryExecutionFail as Error},
+        query::{dsl::CompoundPredicate, error::QueryExecutionFail as Error, AssetWithDefinition},
     };
 
     use super::*;
-    use crate::{smartcontracts::ValidQuery, state::StateReadOnly};
+    use crate::{
+        smartcontracts::{ValidQuery, ValidSingularQuery},
+        state::StateReadOnly,
+    };
 
     impl ValidQuery for FindAssets {
         #[metrics(+"find_assets")]
@@ -260,4 +1044,55 @@ pub mod query {
                 .cloned())
         }
     }
+
+    impl ValidQuery for FindAssetsWithDefinition {
+        #[metrics(+"find_assets_with_definition")]
+        fn execute(
+            self,
+            filter: CompoundPredicate<AssetWithDefinition>,
+            state_ro: &impl StateReadOnly,
+        ) -> Result<impl Iterator<Item = AssetWithDefinition>, Error> {
+            let world = state_ro.world();
+            Ok(world
+                .assets_iter()
+                .map(move |asset| {
+                    let definition = world
+                        .asset_definition(&asset.id().definition)
+                        .expect("INTERNAL BUG: Asset exists without its definition");
+                    AssetWithDefinition {
+                        asset: asset.to_owned(),
+                        definition,
+                    }
+                })
+                .filter(move |entry| filter.applies(entry)))
+        }
+    }
+
+    impl ValidSingularQuery for FindAssetQuantityAt {
+        #[metrics(+"find_asset_quantity_at")]
+        fn execute(&self, state_ro: &impl StateReadOnly) -> Result<Numeric, Error> {
+            if self.height.get() > state_ro.height() as u64 {
+                return Err(Error::NotCommitted(self.height));
+            }
+            state_ro
+                .world()
+                .asset_quantity_at(&self.asset_id, self.height)
+        }
+    }
+
+    impl ValidSingularQuery for FindAllowances {
+        #[metrics(+"find_allowances")]
+        fn execute(&self, state_ro: &impl StateReadOnly) -> Result<Vec<Allowance>, Error> {
+            let account_id = &self.id;
+            state_ro.world().account(account_id)?;
+            Ok(state_ro
+                .world()
+                .allowances_iter()
+                .filter(|allowance| {
+                    allowance.owner == *account_id || allowance.spender == *account_id
+                })
+                .cloned()
+                .collect())
+        }
+    }
 }
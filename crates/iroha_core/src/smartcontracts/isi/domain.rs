@@ -19,6 +19,7 @@ pub mod isi {
     use iroha_logger::prelude::*;
 
     use super::*;
+    use crate::tiering::Tiered;
 
     impl Execute for Register<Account> {
         #[metrics(+"register_account")]
@@ -28,6 +29,7 @@ pub mod isi {
             state_transaction: &mut StateTransaction<'_, '_>,
         ) -> Result<(), Error> {
             let account: Account = self.object.build(authority);
+            check_metadata(&account.metadata, state_transaction)?;
             let (account_id, account_value) = account.clone().into_key_value();
 
             if *account_id.domain() == *iroha_genesis::GENESIS_DOMAIN_ID {
@@ -44,10 +46,14 @@ pub mod isi {
                 }
                 .into());
             }
+            state_transaction
+                .world
+                .tombstones
+                .remove(IdBox::AccountId(account_id.clone()));
             state_transaction
                 .world
                 .accounts
-                .insert(account_id, account_value);
+                .insert(account_id, Tiered::new(account_value));
 
             state_transaction
                 .world
@@ -61,7 +67,7 @@ pub mod isi {
         #[metrics(+"unregister_account")]
         fn execute(
             self,
-            _authority: &AccountId,
+            authority: &AccountId,
             state_transaction: &mut StateTransaction<'_, '_>,
         ) -> Result<(), Error> {
             let account_id = self.object;
@@ -88,8 +94,23 @@ pub mod isi {
                 .world
                 .account_permissions
                 .remove(account_id.clone());
+            state_transaction
+                .world
+                .remove_account_grant_expiries(&account_id);
+            state_transaction
+                .world
+                .remove_account_delegations(&account_id);
+            state_transaction
+                .world
+                .remove_account_allowances(&account_id);
+            state_transaction.world.remove_account_messages(&account_id);
+            state_transaction.world.remove_account_escrows(&account_id);
 
             state_transaction.world.remove_account_roles(&account_id);
+            state_transaction.world.remove_account_tags(&account_id);
+            state_transaction
+                .world
+                .remove_account_subscriptions(&account_id);
 
             let remove_assets: Vec<AssetId> = state_transaction
                 .world
@@ -109,6 +130,16 @@ pub mod isi {
                 return Err(FindError::Account(account_id).into());
             }
 
+            let tombstone = Tombstone::new(
+                IdBox::AccountId(account_id.clone()),
+                state_transaction.curr_block.height(),
+                authority.clone(),
+            );
+            state_transaction
+                .world
+                .tombstones
+                .insert(tombstone.id().clone(), tombstone);
+
             state_transaction
                 .world
                 .emit_events(Some(AccountEvent::Deleted(account_id)));
@@ -117,6 +148,32 @@ pub mod isi {
         }
     }
 
+    impl Execute for Purge<Account> {
+        #[metrics(+"purge_account")]
+        fn execute(
+            self,
+            _authority: &AccountId,
+            state_transaction: &mut StateTransaction<'_, '_>,
+        ) -> Result<(), Error> {
+            let account_id = self.object;
+
+            if state_transaction
+                .world
+                .tombstones
+                .remove(IdBox::AccountId(account_id.clone()))
+                .is_none()
+            {
+                return Err(FindError::Tombstone(Box::new(IdBox::AccountId(account_id))).into());
+            }
+
+            state_transaction
+                .world
+                .emit_events(Some(AccountEvent::Purged(account_id)));
+
+            Ok(())
+        }
+    }
+
     impl Execute for Register<AssetDefinition> {
         #[metrics(+"register_asset_definition")]
         fn execute(
@@ -125,6 +182,7 @@ pub mod isi {
             state_transaction: &mut StateTransaction<'_, '_>,
         ) -> Result<(), Error> {
             let asset_definition = self.object.build(authority);
+            check_metadata(&asset_definition.metadata, state_transaction)?;
 
             let asset_definition_id = asset_definition.id().clone();
             if state_transaction
@@ -202,6 +260,12 @@ pub mod isi {
             let _ = state_transaction
                 .world
                 .domain(&asset_definition_id.domain)?;
+            state_transaction
+                .world
+                .remove_asset_definition_allowances(&asset_definition_id);
+            state_transaction
+                .world
+                .remove_asset_definition_escrows(&asset_definition_id);
 
             events.push(DataEvent::from(AssetDefinitionEvent::Deleted(
                 asset_definition_id,
@@ -221,6 +285,7 @@ pub mod isi {
             state_transaction: &mut StateTransaction<'_, '_>,
         ) -> Result<(), Error> {
             let asset_definition_id = self.object;
+            check_metadata_limits(&self.value, state_transaction)?;
 
             state_transaction
                 .world
@@ -279,6 +344,99 @@ pub mod isi {
         }
     }
 
+    impl Execute for Deprecate<AssetDefinition> {
+        #[metrics(+"deprecate_asset_definition")]
+        fn execute(
+            self,
+            _authority: &AccountId,
+            state_transaction: &mut StateTransaction<'_, '_>,
+        ) -> Result<(), Error> {
+            let asset_definition_id = self.object;
+            let deprecated_at_ms =
+                u64::try_from(state_transaction.curr_block.creation_time().as_millis())
+                    .expect("INTERNAL BUG: block creation time doesn't fit into u64");
+            let deprecation = AssetDefinitionDeprecation {
+                deprecated_at_ms,
+                sunset_end_ms: deprecated_at_ms.saturating_add(self.sunset_ms),
+            };
+
+            let asset_definition = state_transaction
+                .world
+                .asset_definition_mut(&asset_definition_id)?;
+            if asset_definition.deprecation.is_some() {
+                return Err(Error::InvariantViolation(format!(
+                    "Asset definition `{asset_definition_id}` is already deprecated"
+                )));
+            }
+            asset_definition.deprecation = Some(deprecation);
+
+            state_transaction
+                .world
+                .emit_events(Some(AssetDefinitionEvent::Deprecated(
+                    AssetDefinitionDeprecated {
+                        asset_definition: asset_definition_id,
+                        deprecation,
+                    },
+                )));
+
+            Ok(())
+        }
+    }
+
+    impl Execute for Freeze<AssetDefinition> {
+        #[metrics(+"freeze_asset_definition")]
+        fn execute(
+            self,
+            _authority: &AccountId,
+            state_transaction: &mut StateTransaction<'_, '_>,
+        ) -> Result<(), Error> {
+            let asset_definition_id = self.object;
+
+            let asset_definition = state_transaction
+                .world
+                .asset_definition_mut(&asset_definition_id)?;
+            if asset_definition.frozen {
+                return Err(Error::InvariantViolation(format!(
+                    "Asset definition `{asset_definition_id}` is already frozen"
+                )));
+            }
+            asset_definition.frozen = true;
+
+            state_transaction
+                .world
+                .emit_events(Some(AssetDefinitionEvent::Frozen(asset_definition_id)));
+
+            Ok(())
+        }
+    }
+
+    impl Execute for Unfreeze<AssetDefinition> {
+        #[metrics(+"unfreeze_asset_definition")]
+        fn execute(
+            self,
+            _authority: &AccountId,
+            state_transaction: &mut StateTransaction<'_, '_>,
+        ) -> Result<(), Error> {
+            let asset_definition_id = self.object;
+
+            let asset_definition = state_transaction
+                .world
+                .asset_definition_mut(&asset_definition_id)?;
+            if !asset_definition.frozen {
+                return Err(Error::InvariantViolation(format!(
+                    "Asset definition `{asset_definition_id}` is not frozen"
+                )));
+            }
+            asset_definition.frozen = false;
+
+            state_transaction
+                .world
+                .emit_events(Some(AssetDefinitionEvent::Unfrozen(asset_definition_id)));
+
+            Ok(())
+        }
+    }
+
     impl Execute for SetKeyValue<Domain> {
         #[metrics(+"set_domain_key_value")]
         fn execute(
@@ -287,6 +445,7 @@ pub mod isi {
             state_transaction: &mut StateTransaction<'_, '_>,
         ) -> Result<(), Error> {
             let domain_id = self.object;
+            check_metadata_limits(&self.value, state_transaction)?;
 
             let domain = state_transaction.world.domain_mut(&domain_id)?;
             domain.metadata.insert(self.key.clone(), self.value.clone());
@@ -15,9 +15,11 @@ use eyre::Result;
 pub use iroha_data_model::Registrable;
 use iroha_data_model::{
     isi::{error::InstructionExecutionError as Error, *},
+    parameter::TransactionParameters,
     prelude::*,
 };
 use iroha_logger::prelude::*;
+use iroha_primitives::json::Json;
 use mv::storage::StorageReadOnly;
 
 use super::Execute;
@@ -37,9 +39,12 @@ impl Execute for InstructionBox {
         match self {
             Self::Register(isi) => isi.execute(authority, state_transaction),
             Self::Unregister(isi) => isi.execute(authority, state_transaction),
+            Self::Purge(isi) => isi.execute(authority, state_transaction),
             Self::Mint(isi) => isi.execute(authority, state_transaction),
             Self::Burn(isi) => isi.execute(authority, state_transaction),
             Self::Transfer(isi) => isi.execute(authority, state_transaction),
+            Self::Split(isi) => isi.execute(authority, state_transaction),
+            Self::Merge(isi) => isi.execute(authority, state_transaction),
             Self::SetKeyValue(isi) => isi.execute(authority, state_transaction),
             Self::RemoveKeyValue(isi) => isi.execute(authority, state_transaction),
             Self::Grant(isi) => isi.execute(authority, state_transaction),
@@ -48,6 +53,27 @@ impl Execute for InstructionBox {
             Self::SetParameter(isi) => isi.execute(authority, state_transaction),
             Self::Upgrade(isi) => isi.execute(authority, state_transaction),
             Self::Log(isi) => isi.execute(authority, state_transaction),
+            Self::Subscribe(isi) => isi.execute(authority, state_transaction),
+            Self::Unsubscribe(isi) => isi.execute(authority, state_transaction),
+            Self::RetryDeadLetter(isi) => isi.execute(authority, state_transaction),
+            Self::DiscardDeadLetter(isi) => isi.execute(authority, state_transaction),
+            Self::Deprecate(isi) => isi.execute(authority, state_transaction),
+            Self::Delegate(isi) => isi.execute(authority, state_transaction),
+            Self::Tagged(isi) => isi.execute(authority, state_transaction),
+            Self::Onboard(isi) => isi.execute(authority, state_transaction),
+            Self::Payout(isi) => isi.execute(authority, state_transaction),
+            Self::AddTag(isi) => isi.execute(authority, state_transaction),
+            Self::RemoveTag(isi) => isi.execute(authority, state_transaction),
+            Self::Freeze(isi) => isi.execute(authority, state_transaction),
+            Self::Unfreeze(isi) => isi.execute(authority, state_transaction),
+            Self::Approve(isi) => isi.execute(authority, state_transaction),
+            Self::TransferFrom(isi) => isi.execute(authority, state_transaction),
+            Self::Message(isi) => isi.execute(authority, state_transaction),
+            Self::Convert(isi) => isi.execute(authority, state_transaction),
+            Self::CreateEscrow(isi) => isi.execute(authority, state_transaction),
+            Self::ClaimEscrow(isi) => isi.execute(authority, state_transaction),
+            Self::RefundEscrow(isi) => isi.execute(authority, state_transaction),
+            Self::TransferMany(isi) => isi.execute(authority, state_transaction),
             Self::Custom(_) => {
                 panic!("Custom instructions should be handled in custom executor");
             }
@@ -93,6 +119,48 @@ impl Execute for UnregisterBox {
     }
 }
 
+impl Execute for PurgeBox {
+    #[iroha_logger::log(name = "purge", skip_all, fields(id))]
+    fn execute(
+        self,
+        authority: &AccountId,
+        state_transaction: &mut StateTransaction<'_, '_>,
+    ) -> Result<(), Error> {
+        match self {
+            Self::Domain(isi) => isi.execute(authority, state_transaction),
+            Self::Account(isi) => isi.execute(authority, state_transaction),
+        }
+    }
+}
+
+impl Execute for FreezeBox {
+    #[iroha_logger::log(name = "freeze", skip_all, fields(id))]
+    fn execute(
+        self,
+        authority: &AccountId,
+        state_transaction: &mut StateTransaction<'_, '_>,
+    ) -> Result<(), Error> {
+        match self {
+            Self::Asset(isi) => isi.execute(authority, state_transaction),
+            Self::AssetDefinition(isi) => isi.execute(authority, state_transaction),
+        }
+    }
+}
+
+impl Execute for UnfreezeBox {
+    #[iroha_logger::log(name = "unfreeze", skip_all, fields(id))]
+    fn execute(
+        self,
+        authority: &AccountId,
+        state_transaction: &mut StateTransaction<'_, '_>,
+    ) -> Result<(), Error> {
+        match self {
+            Self::Asset(isi) => isi.execute(authority, state_transaction),
+            Self::AssetDefinition(isi) => isi.execute(authority, state_transaction),
+        }
+    }
+}
+
 impl Execute for MintBox {
     #[iroha_logger::log(name = "Mint", skip_all, fields(destination))]
     fn execute(
@@ -147,6 +215,7 @@ impl Execute for SetKeyValueBox {
             Self::Domain(isi) => isi.execute(authority, state_transaction),
             Self::Account(isi) => isi.execute(authority, state_transaction),
             Self::AssetDefinition(isi) => isi.execute(authority, state_transaction),
+            Self::Asset(isi) => isi.execute(authority, state_transaction),
             Self::Nft(isi) => isi.execute(authority, state_transaction),
             Self::Trigger(isi) => isi.execute(authority, state_transaction),
         }
@@ -163,6 +232,7 @@ impl Execute for RemoveKeyValueBox {
             Self::Domain(isi) => isi.execute(authority, state_transaction),
             Self::Account(isi) => isi.execute(authority, state_transaction),
             Self::AssetDefinition(isi) => isi.execute(authority, state_transaction),
+            Self::Asset(isi) => isi.execute(authority, state_transaction),
             Self::Nft(isi) => isi.execute(authority, state_transaction),
             Self::Trigger(isi) => isi.execute(authority, state_transaction),
         }
@@ -199,6 +269,105 @@ impl Execute for RevokeBox {
     }
 }
 
+impl Execute for Tagged {
+    fn execute(
+        self,
+        authority: &AccountId,
+        state_transaction: &mut StateTransaction<'_, '_>,
+    ) -> Result<(), Error> {
+        let Self { tag, instruction } = self;
+        if matches!(*instruction, InstructionBox::Tagged(_)) {
+            return Err(Error::InvariantViolation(
+                "Tagged instructions can't be nested".to_owned(),
+            ));
+        }
+        let position = state_transaction.world.events_position();
+
+        // The wrapped instruction is validated by the executor as if it wasn't tagged
+        execute_validated(*instruction, authority, state_transaction)?;
+
+        state_transaction.world.tag_events_since(position, &tag);
+        Ok(())
+    }
+}
+
+impl Execute for Onboard {
+    fn execute(
+        self,
+        authority: &AccountId,
+        state_transaction: &mut StateTransaction<'_, '_>,
+    ) -> Result<(), Error> {
+        // Checked up front, so that nothing is executed for an onboarding bound to fail
+        check_metadata(&self.account.metadata, state_transaction)?;
+
+        // The transaction is rolled back if any of the instructions fails
+        for instruction in self.instructions() {
+            execute_validated(instruction, authority, state_transaction)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Validate the `instruction` nested into another one with the executor and execute it.
+fn execute_validated(
+    instruction: InstructionBox,
+    authority: &AccountId,
+    state_transaction: &mut StateTransaction<'_, '_>,
+) -> Result<(), Error> {
+    let executor = state_transaction.world.executor.clone();
+    executor
+        .execute_instruction(state_transaction, authority, instruction)
+        .map_err(|error| match error {
+            ValidationFail::InstructionFailed(error) => error,
+            error => Error::InvariantViolation(format!("Nested instruction failed: {error}")),
+        })
+}
+
+/// Check the metadata `value` inserted by an instruction against the limits in [`TransactionParameters`].
+pub(crate) fn check_metadata_limits(
+    value: &Json,
+    state_transaction: &StateTransaction<'_, '_>,
+) -> Result<(), Error> {
+    check_metadata_value(value, state_transaction.world.parameters().transaction)
+}
+
+/// Check every value of the `metadata` supplied by an instruction, see [`check_metadata_limits`].
+pub(crate) fn check_metadata(
+    metadata: &Metadata,
+    state_transaction: &StateTransaction<'_, '_>,
+) -> Result<(), Error> {
+    metadata
+        .iter()
+        .try_for_each(|(_, value)| check_metadata_limits(value, state_transaction))
+}
+
+/// Check the metadata `value` against the `limits`.
+pub(crate) fn check_metadata_value(
+    value: &Json,
+    limits: TransactionParameters,
+) -> Result<(), Error> {
+    // The size is checked first to not parse values of any size
+    let size = value.get().len() as u64;
+    if size > limits.max_metadata_size.get() {
+        return Err(Error::InvariantViolation(format!(
+            "Metadata value of {size} bytes exceeds the limit of {} bytes",
+            limits.max_metadata_size
+        )));
+    }
+    let depth = value
+        .depth()
+        .map_err(|error| Error::Conversion(error.to_string()))? as u64;
+    if depth > limits.max_metadata_depth.get() {
+        return Err(Error::InvariantViolation(format!(
+            "Metadata value nested {depth} levels deep exceeds the limit of {}",
+            limits.max_metadata_depth
+        )));
+    }
+
+    Ok(())
+}
+
 pub mod prelude {
     //! Re-export important traits and types for glob import `(::*)`
     pub use super::*;
@@ -206,11 +375,22 @@ pub mod prelude {
 
 #[cfg(test)]
 mod tests {
-    use std::sync::Arc;
+    use std::{num::NonZeroU64, sync::Arc, time::Duration};
 
     use iroha_crypto::KeyPair;
+    use iroha_data_model::{
+        dead_letter::MAX_DEAD_LETTERS_PER_TRIGGER,
+        isi::error::{InstructionEvaluationError, MintabilityError, Mismatch, TypeError},
+        message::{MAX_MESSAGES_PER_SENDER, MAX_MESSAGE_PAYLOAD_LEN, MAX_MESSAGE_TTL_BLOCKS},
+        parameter::TransactionParameter,
+        permission::MAX_DELEGATION_DEPTH,
+        query::error::FindError,
+        subscription::MAX_SUBSCRIPTIONS_PER_ACCOUNT,
+    };
+    use iroha_primitives::json::Json;
     use iroha_test_samples::{
-        gen_account_in, ALICE_ID, SAMPLE_GENESIS_ACCOUNT_ID, SAMPLE_GENESIS_ACCOUNT_KEYPAIR,
+        gen_account_in, ALICE_ID, ALICE_KEYPAIR, SAMPLE_GENESIS_ACCOUNT_ID,
+        SAMPLE_GENESIS_ACCOUNT_KEYPAIR,
     };
     use tokio::test;
 
@@ -269,6 +449,39 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    async fn non_transferable_nft_is_moved_by_domain_owner_only() -> Result<()> {
+        let kura = Kura::blank_kura_for_testing();
+        let state = state_with_test_domains(&kura)?;
+        let block_header = ValidBlock::new_dummy(&KeyPair::random().into_parts().1)
+            .as_ref()
+            .header();
+        let mut state_block = state.block(block_header);
+        let mut state_transaction = state_block.transaction();
+        let alice_id = ALICE_ID.clone();
+        let (bob_id, _) = gen_account_in("wonderland");
+        Register::account(Account::new(bob_id.clone()))
+            .execute(&SAMPLE_GENESIS_ACCOUNT_ID, &mut state_transaction)?;
+        Register::domain(Domain::new("garden".parse()?))
+            .execute(&alice_id, &mut state_transaction)?;
+        let nft_id: NftId = "badge$garden".parse()?;
+        Register::nft(Nft::new(nft_id.clone(), Metadata::default()).non_transferable())
+            .execute(&alice_id, &mut state_transaction)?;
+
+        Transfer::nft(alice_id.clone(), nft_id.clone(), bob_id.clone())
+            .execute(&alice_id, &mut state_transaction)?;
+        Transfer::nft(bob_id.clone(), nft_id.clone(), alice_id.clone())
+            .execute(&bob_id, &mut state_transaction)
+            .expect_err("holder can't transfer non-transferable NFT");
+        assert_eq!(state_transaction.world.nft(&nft_id)?.owned_by(), &bob_id);
+
+        Transfer::nft(bob_id, nft_id.clone(), alice_id.clone())
+            .execute(&alice_id, &mut state_transaction)?;
+        assert_eq!(state_transaction.world.nft(&nft_id)?.owned_by(), &alice_id);
+
+        Ok(())
+    }
+
     #[test]
     async fn account_metadata() -> Result<()> {
         let kura = Kura::blank_kura_for_testing();
@@ -324,7 +537,7 @@ mod tests {
     }
 
     #[test]
-    async fn domain_metadata() -> Result<()> {
+    async fn asset_metadata() -> Result<()> {
         let kura = Kura::blank_kura_for_testing();
         let state = state_with_test_domains(&kura)?;
         let block_header = ValidBlock::new_dummy(&KeyPair::random().into_parts().1)
@@ -332,26 +545,43 @@ mod tests {
             .header();
         let mut state_block = state.block(block_header);
         let mut state_transaction = state_block.transaction();
-        let domain_id = "wonderland".parse::<DomainId>()?;
         let account_id = ALICE_ID.clone();
-        let key = "Bytes".parse::<Name>()?;
-        SetKeyValue::domain(domain_id.clone(), key.clone(), vec![1_u32, 2_u32, 3_u32])
+        let asset_id = AssetId::new("rose#wonderland".parse()?, account_id.clone());
+        let key = "batch".parse::<Name>()?;
+        Mint::asset_numeric(1_u32, asset_id.clone())
+            .execute(&account_id, &mut state_transaction)?;
+        SetKeyValue::asset(asset_id.clone(), key.clone(), "B-42")
+            .execute(&account_id, &mut state_transaction)?;
+        let err = SetKeyValue::asset(
+            asset_id.clone(),
+            key.clone(),
+            "B".repeat(Asset::MAX_METADATA_VALUE_SIZE),
+        )
+        .execute(&account_id, &mut state_transaction)
+        .expect_err("holding metadata values should be bounded");
+        assert!(matches!(err, Error::InvariantViolation(_)));
+        for i in 1..Asset::MAX_METADATA_ENTRIES {
+            SetKeyValue::asset(asset_id.clone(), format!("key{i}").parse()?, "filler")
+                .execute(&account_id, &mut state_transaction)?;
+        }
+        // Overwriting an existing entry is always allowed
+        SetKeyValue::asset(asset_id.clone(), key.clone(), "B-43")
             .execute(&account_id, &mut state_transaction)?;
+        let err = SetKeyValue::asset(asset_id.clone(), "overflow".parse()?, 0_u32)
+            .execute(&account_id, &mut state_transaction)
+            .expect_err("holding metadata should be bounded");
+        assert!(matches!(err, Error::InvariantViolation(_)));
         state_transaction.apply();
         state_block.commit();
-        let bytes = state
-            .view()
-            .world
-            .domain(&domain_id)?
-            .metadata()
-            .get(&key)
-            .cloned();
-        assert_eq!(bytes, Some(vec![1_u32, 2_u32, 3_u32,].into()));
+        let state_view = state.view();
+        let asset = state_view.world.asset(&asset_id)?;
+        assert_eq!(asset.metadata().get(&key), Some(&"B-43".into()));
+        assert_eq!(asset.metadata().iter().len(), Asset::MAX_METADATA_ENTRIES);
         Ok(())
     }
 
     #[test]
-    async fn executing_unregistered_trigger_should_return_error() -> Result<()> {
+    async fn metadata_values_are_limited() -> Result<()> {
         let kura = Kura::blank_kura_for_testing();
         let state = state_with_test_domains(&kura)?;
         let block_header = ValidBlock::new_dummy(&KeyPair::random().into_parts().1)
@@ -360,23 +590,167 @@ mod tests {
         let mut state_block = state.block(block_header);
         let mut state_transaction = state_block.transaction();
         let account_id = ALICE_ID.clone();
-        let trigger_id = "test_trigger_id".parse()?;
+        let key = "nested".parse::<Name>()?;
+        for parameter in [
+            TransactionParameter::MaxMetadataDepth(NonZeroU64::new(2).unwrap()),
+            TransactionParameter::MaxMetadataSize(NonZeroU64::new(16).unwrap()),
+        ] {
+            SetParameter::new(Parameter::Transaction(parameter))
+                .execute(&SAMPLE_GENESIS_ACCOUNT_ID, &mut state_transaction)?;
+        }
 
-        assert!(matches!(
-            ExecuteTrigger::new(trigger_id)
+        SetKeyValue::account(account_id.clone(), key.clone(), Json::new([[1_u32]]))
+            .execute(&account_id, &mut state_transaction)?;
+        for rejected in [Json::new([[[1_u32]]]), Json::new("a".repeat(16))] {
+            let err = SetKeyValue::account(account_id.clone(), key.clone(), rejected)
                 .execute(&account_id, &mut state_transaction)
-                .expect_err("Error expected"),
-            Error::Find(_)
-        ));
+                .expect_err("metadata value should be limited");
+            assert!(matches!(err, Error::InvariantViolation(_)));
+        }
+        let account = state_transaction.world.account(&account_id)?;
+        assert_eq!(account.metadata().get(&key), Some(&Json::new([[1_u32]])));
+
+        // The metadata supplied on registration is limited as well
+        let mut nested = Metadata::default();
+        nested.insert(key, Json::new([[[1_u32]]]));
+        let err =
+            Register::domain(Domain::new("looking_glass".parse()?).with_metadata(nested.clone()))
+                .execute(&account_id, &mut state_transaction)
+                .expect_err("domain metadata should be limited");
+        assert!(matches!(err, Error::InvariantViolation(_)));
+        let err = Register::asset_definition(
+            AssetDefinition::numeric("tulip#wonderland".parse()?).with_metadata(nested),
+        )
+        .execute(&account_id, &mut state_transaction)
+        .expect_err("asset definition metadata should be limited");
+        assert!(matches!(err, Error::InvariantViolation(_)));
+
+        Ok(())
+    }
+
+    #[test]
+    async fn split_and_merge_preserve_total_quantity() -> Result<()> {
+        let kura = Kura::blank_kura_for_testing();
+        let state = state_with_test_domains(&kura)?;
+        let block_header = ValidBlock::new_dummy(&KeyPair::random().into_parts().1)
+            .as_ref()
+            .header();
+        let mut state_block = state.block(block_header);
+        let mut state_transaction = state_block.transaction();
+        let definition_id = "rose#wonderland".parse::<AssetDefinitionId>()?;
+        let alice_id = ALICE_ID.clone();
+        let (bob_id, _) = gen_account_in("wonderland");
+        let alice_asset = AssetId::new(definition_id.clone(), alice_id.clone());
+        let bob_asset = AssetId::new(definition_id.clone(), bob_id.clone());
+        Register::account(Account::new(bob_id.clone()))
+            .execute(&SAMPLE_GENESIS_ACCOUNT_ID, &mut state_transaction)?;
+        Mint::asset_numeric(Numeric::new(10, 1), alice_asset.clone())
+            .execute(&alice_id, &mut state_transaction)?;
+
+        // Parts must add up to exactly the held quantity
+        let err = Split::new(
+            alice_asset.clone(),
+            [
+                (alice_id.clone(), Numeric::new(3, 1)),
+                (bob_id.clone(), Numeric::new(3, 1)),
+            ],
+        )
+        .execute(&alice_id, &mut state_transaction)
+        .expect_err("parts don't add up to the held quantity");
+        assert!(matches!(err, Error::InvariantViolation(_)));
+
+        Split::new(
+            alice_asset.clone(),
+            [
+                (alice_id.clone(), Numeric::new(3, 1)),
+                (bob_id.clone(), Numeric::new(7, 1)),
+            ],
+        )
+        .execute(&alice_id, &mut state_transaction)?;
+        assert_eq!(
+            *state_transaction.world.asset(&alice_asset)?.value(),
+            Numeric::new(3, 1)
+        );
+        assert_eq!(
+            *state_transaction.world.asset(&bob_asset)?.value(),
+            Numeric::new(7, 1)
+        );
+
+        Merge::new([alice_asset.clone(), bob_asset.clone()], bob_id)
+            .execute(&alice_id, &mut state_transaction)?;
+        assert!(state_transaction.world.asset(&alice_asset).is_err());
+        assert_eq!(
+            *state_transaction.world.asset(&bob_asset)?.value(),
+            Numeric::ONE
+        );
+        assert_eq!(
+            state_transaction
+                .world
+                .asset_definition(&definition_id)?
+                .total_quantity(),
+            Numeric::ONE
+        );
 
         state_transaction.apply();
         state_block.commit();
+        Ok(())
+    }
+
+    #[test]
+    async fn payout_debits_total_once() -> Result<()> {
+        let kura = Kura::blank_kura_for_testing();
+        let state = state_with_test_domains(&kura)?;
+        let block_header = ValidBlock::new_dummy(&KeyPair::random().into_parts().1)
+            .as_ref()
+            .header();
+        let mut state_block = state.block(block_header);
+        let mut state_transaction = state_block.transaction();
+        let definition_id = "rose#wonderland".parse::<AssetDefinitionId>()?;
+        let alice_id = ALICE_ID.clone();
+        let (bob_id, _) = gen_account_in("wonderland");
+        let (carol_id, _) = gen_account_in("wonderland");
+        for account_id in [&bob_id, &carol_id] {
+            Register::account(Account::new(account_id.clone()))
+                .execute(&SAMPLE_GENESIS_ACCOUNT_ID, &mut state_transaction)?;
+        }
+        let alice_asset = AssetId::new(definition_id.clone(), alice_id.clone());
+        Mint::asset_numeric(10_u32, alice_asset.clone())
+            .execute(&alice_id, &mut state_transaction)?;
+
+        let err = Payout::new(
+            alice_asset.clone(),
+            [(bob_id.clone(), 6_u32), (carol_id.clone(), 5_u32)],
+        )
+        .execute(&alice_id, &mut state_transaction)
+        .expect_err("payments exceed the held quantity");
+        assert!(matches!(err, Error::Math(MathError::NotEnoughQuantity)));
+
+        Payout::new(
+            alice_asset.clone(),
+            [(bob_id.clone(), 6_u32), (carol_id.clone(), 4_u32)],
+        )
+        .execute(&alice_id, &mut state_transaction)?;
+        assert!(state_transaction.world.asset(&alice_asset).is_err());
+        for (account_id, quantity) in [(&bob_id, 6_u32), (&carol_id, 4_u32)] {
+            let asset_id = AssetId::new(definition_id.clone(), account_id.clone());
+            assert_eq!(
+                *state_transaction.world.asset(&asset_id)?.value(),
+                Numeric::from(quantity)
+            );
+        }
+
+        // A single payment to an unregistered account fails the whole payout
+        let (dave_id, _) = gen_account_in("wonderland");
+        let bob_asset = AssetId::new(definition_id, bob_id.clone());
+        Payout::new(bob_asset, [(carol_id, 1_u32), (dave_id, 1_u32)])
+            .execute(&bob_id, &mut state_transaction)
+            .expect_err("dave isn't registered");
 
         Ok(())
     }
 
     #[test]
-    async fn unauthorized_trigger_execution_should_return_error() -> Result<()> {
+    async fn transfer_many_makes_every_transfer() -> Result<()> {
         let kura = Kura::blank_kura_for_testing();
         let state = state_with_test_domains(&kura)?;
         let block_header = ValidBlock::new_dummy(&KeyPair::random().into_parts().1)
@@ -384,48 +758,91 @@ mod tests {
             .header();
         let mut state_block = state.block(block_header);
         let mut state_transaction = state_block.transaction();
-        let account_id = ALICE_ID.clone();
-        let (fake_account_id, _fake_account_keypair) = gen_account_in("wonderland");
-        let trigger_id = "test_trigger_id".parse::<TriggerId>()?;
+        let definition_id = "rose#wonderland".parse::<AssetDefinitionId>()?;
+        let alice_id = ALICE_ID.clone();
+        let (bob_id, _) = gen_account_in("wonderland");
+        Register::account(Account::new(bob_id.clone()))
+            .execute(&SAMPLE_GENESIS_ACCOUNT_ID, &mut state_transaction)?;
+        let alice_asset = AssetId::new(definition_id.clone(), alice_id.clone());
+        Mint::asset_numeric(10_u32, alice_asset.clone())
+            .execute(&alice_id, &mut state_transaction)?;
 
-        // register fake account
-        let register_account = Register::account(Account::new(fake_account_id.clone()));
-        register_account.execute(&account_id, &mut state_transaction)?;
+        // An account may receive several transfers
+        let transfer = TransferMany::new(
+            alice_asset.clone(),
+            [(bob_id.clone(), 3_u32), (bob_id.clone(), 4_u32)],
+        );
+        assert_eq!(transfer.transfers().len(), 2);
+        transfer.execute(&alice_id, &mut state_transaction)?;
+        let bob_asset = AssetId::new(definition_id, bob_id);
+        assert_eq!(
+            *state_transaction.world.asset(&alice_asset)?.value(),
+            Numeric::from(3_u32)
+        );
+        assert_eq!(
+            *state_transaction.world.asset(&bob_asset)?.value(),
+            Numeric::from(7_u32)
+        );
 
-        // register the trigger
-        let register_trigger = Register::trigger(Trigger::new(
-            trigger_id.clone(),
-            Action::new(
-                Vec::<InstructionBox>::new(),
-                Repeats::Indefinitely,
-                account_id.clone(),
-                ExecuteTriggerEventFilter::new()
-                    .for_trigger(trigger_id.clone())
-                    .under_authority(account_id.clone()),
-            ),
-        ));
+        let (carol_id, _) = gen_account_in("wonderland");
+        TransferMany::new(alice_asset, [(carol_id, 1_u32)])
+            .execute(&alice_id, &mut state_transaction)
+            .expect_err("carol isn't registered");
 
-        register_trigger.execute(&account_id, &mut state_transaction)?;
+        Ok(())
+    }
 
-        // execute with the valid account
-        ExecuteTrigger::new(trigger_id.clone()).execute(&account_id, &mut state_transaction)?;
+    #[test]
+    async fn account_tags_are_indexed() -> Result<()> {
+        let kura = Kura::blank_kura_for_testing();
+        let state = state_with_test_domains(&kura)?;
+        let block_header = ValidBlock::new_dummy(&KeyPair::random().into_parts().1)
+            .as_ref()
+            .header();
+        let mut state_block = state.block(block_header);
+        let mut state_transaction = state_block.transaction();
+        let tag = "exchange".parse::<Name>()?;
+        let alice_id = ALICE_ID.clone();
+        let (bob_id, _) = gen_account_in("wonderland");
+        Register::account(Account::new(bob_id.clone()))
+            .execute(&SAMPLE_GENESIS_ACCOUNT_ID, &mut state_transaction)?;
+        for account_id in [&alice_id, &bob_id] {
+            AddTag::new(account_id.clone(), tag.clone())
+                .execute(&alice_id, &mut state_transaction)?;
+        }
+        AddTag::new(alice_id.clone(), tag.clone())
+            .execute(&alice_id, &mut state_transaction)
+            .expect_err("account is already tagged");
 
-        // execute with the fake account
-        assert!(matches!(
-            ExecuteTrigger::new(trigger_id)
-                .execute(&fake_account_id, &mut state_transaction)
-                .expect_err("Error expected"),
-            Error::InvariantViolation(_)
-        ));
+        RemoveTag::new(alice_id.clone(), tag.clone()).execute(&alice_id, &mut state_transaction)?;
+        assert!(state_transaction
+            .world
+            .account(&alice_id)?
+            .tags()
+            .is_empty());
+        assert!(state_transaction
+            .world
+            .account(&bob_id)?
+            .tags()
+            .contains(&tag));
+        let tagged = state_transaction
+            .world
+            .tagged_accounts_iter(&tag)
+            .cloned()
+            .collect::<Vec<_>>();
+        assert_eq!(tagged, [bob_id.clone()]);
 
-        state_transaction.apply();
-        state_block.commit();
+        Unregister::account(bob_id).execute(&alice_id, &mut state_transaction)?;
+        assert_eq!(
+            state_transaction.world.tagged_accounts_iter(&tag).count(),
+            0
+        );
 
         Ok(())
     }
 
     #[test]
-    async fn not_allowed_to_register_genesis_domain_or_account() -> Result<()> {
+    async fn mint_is_capped_by_max_supply() -> Result<()> {
         let kura = Kura::blank_kura_for_testing();
         let state = state_with_test_domains(&kura)?;
         let block_header = ValidBlock::new_dummy(&KeyPair::random().into_parts().1)
@@ -434,43 +851,1232 @@ mod tests {
         let mut state_block = state.block(block_header);
         let mut state_transaction = state_block.transaction();
         let account_id = ALICE_ID.clone();
+        let definition_id = "gold#wonderland".parse::<AssetDefinitionId>()?;
+        let asset_id = AssetId::new(definition_id.clone(), account_id.clone());
+        Register::asset_definition(
+            AssetDefinition::numeric(definition_id.clone()).with_max_supply(100_u32.into()),
+        )
+        .execute(&account_id, &mut state_transaction)?;
+
+        Mint::asset_numeric(60_u32, asset_id.clone())
+            .execute(&account_id, &mut state_transaction)?;
+        let error = Mint::asset_numeric(41_u32, asset_id.clone())
+            .execute(&account_id, &mut state_transaction)
+            .expect_err("mint exceeds the max supply");
         assert!(matches!(
-            Register::domain(Domain::new("genesis".parse()?))
-                .execute(&account_id, &mut state_transaction)
-                .expect_err("Error expected"),
-            Error::InvariantViolation(_)
+            error,
+            Error::Mintability(MintabilityError::SupplyCapExceeded)
         ));
-        let register_account = Register::account(Account::new(SAMPLE_GENESIS_ACCOUNT_ID.clone()));
+        Mint::asset_numeric(40_u32, asset_id.clone())
+            .execute(&account_id, &mut state_transaction)?;
+        assert_eq!(
+            state_transaction
+                .world
+                .asset_definition(&definition_id)?
+                .total_quantity,
+            100_u32.into()
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    async fn periodic_mint_is_limited_per_period() -> Result<()> {
+        let kura = Kura::blank_kura_for_testing();
+        let state = state_with_test_domains(&kura)?;
+        let account_id = ALICE_ID.clone();
+        let definition_id = "tulip#wonderland".parse::<AssetDefinitionId>()?;
+        let asset_id = AssetId::new(definition_id.clone(), account_id.clone());
+        let block_at = |creation_time_ms| {
+            let mut block_header = ValidBlock::new_dummy(&KeyPair::random().into_parts().1)
+                .as_ref()
+                .header();
+            block_header.creation_time_ms = creation_time_ms;
+            block_header
+        };
+
+        let mut state_block = state.block(block_at(1_000));
+        let mut state_transaction = state_block.transaction();
+        Register::asset_definition(
+            AssetDefinition::numeric(definition_id.clone())
+                .mintable_periodically(10_u32.into(), NonZeroU64::new(1_000).unwrap()),
+        )
+        .execute(&account_id, &mut state_transaction)?;
+        Mint::asset_numeric(6_u32, asset_id.clone())
+            .execute(&account_id, &mut state_transaction)?;
+        let error = Mint::asset_numeric(5_u32, asset_id.clone())
+            .execute(&account_id, &mut state_transaction)
+            .expect_err("mint exceeds the amount of the period");
         assert!(matches!(
-            register_account
-                .execute(&account_id, &mut state_transaction)
-                .expect_err("Error expected"),
-            Error::InvariantViolation(_)
+            error,
+            Error::Mintability(MintabilityError::PeriodLimitExceeded)
         ));
+        Mint::asset_numeric(4_u32, asset_id.clone())
+            .execute(&account_id, &mut state_transaction)?;
         state_transaction.apply();
         state_block.commit();
 
+        // The next period starts from scratch
+        let mut state_block = state.block(block_at(2_500));
+        let mut state_transaction = state_block.transaction();
+        Mint::asset_numeric(10_u32, asset_id.clone())
+            .execute(&account_id, &mut state_transaction)?;
+        assert_eq!(
+            *state_transaction.world.asset(&asset_id)?.value(),
+            20_u32.into()
+        );
+
         Ok(())
     }
 
     #[test]
-    async fn transaction_signed_by_genesis_account_should_be_rejected() -> Result<()> {
-        let chain_id = ChainId::from("00000000-0000-0000-0000-000000000000");
+    async fn frozen_assets_can_not_be_transferred_or_burned() -> Result<()> {
         let kura = Kura::blank_kura_for_testing();
         let state = state_with_test_domains(&kura)?;
-        let (max_clock_drift, tx_limits) = {
-            let state_view = state.world.view();
-            let params = state_view.parameters();
-            (params.sumeragi().max_clock_drift(), params.transaction)
-        };
+        let block_header = ValidBlock::new_dummy(&KeyPair::random().into_parts().1)
+            .as_ref()
+            .header();
+        let mut state_block = state.block(block_header);
+        let mut state_transaction = state_block.transaction();
+        let alice_id = ALICE_ID.clone();
+        let (bob_id, _) = gen_account_in("wonderland");
+        Register::account(Account::new(bob_id.clone()))
+            .execute(&alice_id, &mut state_transaction)?;
+        let definition_id = "rose#wonderland".parse::<AssetDefinitionId>()?;
+        let asset_id = AssetId::new(definition_id.clone(), alice_id.clone());
+        Mint::asset_numeric(10_u32, asset_id.clone()).execute(&alice_id, &mut state_transaction)?;
 
-        let tx = TransactionBuilder::new(chain_id.clone(), SAMPLE_GENESIS_ACCOUNT_ID.clone())
-            .with_instructions::<InstructionBox>([])
-            .sign(SAMPLE_GENESIS_ACCOUNT_KEYPAIR.private_key());
-        assert!(matches!(
+        Freeze::asset(asset_id.clone()).execute(&alice_id, &mut state_transaction)?;
+        Transfer::asset_numeric(asset_id.clone(), 1_u32, bob_id.clone())
+            .execute(&alice_id, &mut state_transaction)
+            .expect_err("holding is frozen");
+        Burn::asset_numeric(1_u32, asset_id.clone())
+            .execute(&alice_id, &mut state_transaction)
+            .expect_err("holding is frozen");
+        // Crediting a frozen holding is fine
+        Mint::asset_numeric(1_u32, asset_id.clone()).execute(&alice_id, &mut state_transaction)?;
+        Unfreeze::asset(asset_id.clone()).execute(&alice_id, &mut state_transaction)?;
+        Transfer::asset_numeric(asset_id.clone(), 1_u32, bob_id.clone())
+            .execute(&alice_id, &mut state_transaction)?;
+
+        Freeze::asset_definition(definition_id.clone())
+            .execute(&alice_id, &mut state_transaction)?;
+        Freeze::asset_definition(definition_id.clone())
+            .execute(&alice_id, &mut state_transaction)
+            .expect_err("asset is already frozen");
+        Burn::asset_numeric(1_u32, AssetId::new(definition_id.clone(), bob_id))
+            .execute(&alice_id, &mut state_transaction)
+            .expect_err("all holdings are frozen");
+        Unfreeze::asset_definition(definition_id).execute(&alice_id, &mut state_transaction)?;
+        Burn::asset_numeric(10_u32, asset_id.clone()).execute(&alice_id, &mut state_transaction)?;
+        assert!(state_transaction.world.assets.get(&asset_id).is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    async fn transfer_from_spends_allowance() -> Result<()> {
+        let kura = Kura::blank_kura_for_testing();
+        let state = state_with_test_domains(&kura)?;
+        let block_header = ValidBlock::new_dummy(&KeyPair::random().into_parts().1)
+            .as_ref()
+            .header();
+        let mut state_block = state.block(block_header);
+        let mut state_transaction = state_block.transaction();
+        let alice_id = ALICE_ID.clone();
+        let (bob_id, _) = gen_account_in("wonderland");
+        Register::account(Account::new(bob_id.clone()))
+            .execute(&alice_id, &mut state_transaction)?;
+        let definition_id = "rose#wonderland".parse::<AssetDefinitionId>()?;
+        let asset_id = AssetId::new(definition_id.clone(), alice_id.clone());
+        Mint::asset_numeric(10_u32, asset_id.clone()).execute(&alice_id, &mut state_transaction)?;
+
+        TransferFrom::new(asset_id.clone(), 1_u32, bob_id.clone())
+            .execute(&bob_id, &mut state_transaction)
+            .expect_err("bob has no allowance");
+        Approve::new(
+            alice_id.clone(),
+            bob_id.clone(),
+            definition_id.clone(),
+            5_u32,
+        )
+        .execute(&alice_id, &mut state_transaction)?;
+        TransferFrom::new(asset_id.clone(), 3_u32, bob_id.clone())
+            .execute(&bob_id, &mut state_transaction)?;
+        TransferFrom::new(asset_id.clone(), 3_u32, bob_id.clone())
+            .execute(&bob_id, &mut state_transaction)
+            .expect_err("transfer exceeds the allowance");
+        assert_eq!(
+            state_transaction
+                .world
+                .allowance(&alice_id, &bob_id, &definition_id)
+                .map(|allowance| allowance.limit),
+            Some(2_u32.into())
+        );
+        assert_eq!(
+            *state_transaction
+                .world
+                .asset(&AssetId::new(definition_id.clone(), bob_id.clone()))?
+                .value(),
+            Numeric::from(3_u32)
+        );
+
+        TransferFrom::new(asset_id, 2_u32, bob_id.clone())
+            .execute(&bob_id, &mut state_transaction)?;
+        assert!(state_transaction
+            .world
+            .allowance(&alice_id, &bob_id, &definition_id)
+            .is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    async fn convert_truncates_minted_quantity() -> Result<()> {
+        let kura = Kura::blank_kura_for_testing();
+        let state = state_with_test_domains(&kura)?;
+        let block_header = ValidBlock::new_dummy(&KeyPair::random().into_parts().1)
+            .as_ref()
+            .header();
+        let mut state_block = state.block(block_header);
+        let mut state_transaction = state_block.transaction();
+        let account_id = ALICE_ID.clone();
+        let from_definition = "rose#wonderland".parse::<AssetDefinitionId>()?;
+        let to_definition = "tulip#wonderland".parse::<AssetDefinitionId>()?;
+        Register::asset_definition(AssetDefinition::new(
+            to_definition.clone(),
+            NumericSpec::fractional(1),
+        ))
+        .execute(&account_id, &mut state_transaction)?;
+        let from_id = AssetId::new(from_definition.clone(), account_id.clone());
+        let to_id = AssetId::new(to_definition.clone(), account_id.clone());
+        Mint::asset_numeric(Numeric::new(659, 2), from_id.clone())
+            .execute(&account_id, &mut state_transaction)?;
+
+        Convert::new(
+            account_id.clone(),
+            from_definition.clone(),
+            from_definition.clone(),
+            1_u32,
+        )
+        .execute(&account_id, &mut state_transaction)
+        .expect_err("asset can't be converted into itself");
+        Convert::new(
+            account_id.clone(),
+            from_definition.clone(),
+            to_definition.clone(),
+            Numeric::new(1, 3),
+        )
+        .execute(&account_id, &mut state_transaction)
+        .expect_err("conversion yields less than the scale of tulip");
+        Convert::new(
+            account_id.clone(),
+            from_definition.clone(),
+            to_definition.clone(),
+            10_u32,
+        )
+        .execute(&account_id, &mut state_transaction)?;
+
+        assert!(state_transaction.world.assets.get(&from_id).is_none());
+        assert_eq!(
+            *state_transaction.world.asset(&to_id)?.value(),
+            Numeric::new(659, 1)
+        );
+        assert_eq!(
+            state_transaction
+                .world
+                .asset_definition(&to_definition)?
+                .total_quantity,
+            Numeric::new(659, 1)
+        );
+
+        Convert::new(
+            account_id.clone(),
+            to_definition,
+            from_definition.clone(),
+            Numeric::new(3, 1),
+        )
+        .execute(&account_id, &mut state_transaction)?;
+        assert!(state_transaction.world.assets.get(&to_id).is_none());
+        // 65.9 * 0.3 = 19.77 is kept in full as rose has no scale limit
+        assert_eq!(
+            *state_transaction.world.asset(&from_id)?.value(),
+            Numeric::new(1977, 2)
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    async fn escrow_is_claimed_or_refunded_in_time() -> Result<()> {
+        let kura = Kura::blank_kura_for_testing();
+        let state = state_with_test_domains(&kura)?;
+        let mut block_header = ValidBlock::new_dummy(&KeyPair::random().into_parts().1)
+            .as_ref()
+            .header();
+        block_header.creation_time_ms = 1_000;
+        let mut state_block = state.block(block_header);
+        let mut state_transaction = state_block.transaction();
+        let definition_id = "rose#wonderland".parse::<AssetDefinitionId>()?;
+        let alice_id = ALICE_ID.clone();
+        let (bob_id, _) = gen_account_in("wonderland");
+        Register::account(Account::new(bob_id.clone()))
+            .execute(&SAMPLE_GENESIS_ACCOUNT_ID, &mut state_transaction)?;
+        let alice_asset = AssetId::new(definition_id.clone(), alice_id.clone());
+        let bob_asset = AssetId::new(definition_id.clone(), bob_id.clone());
+        Mint::asset_numeric(10_u32, alice_asset.clone())
+            .execute(&alice_id, &mut state_transaction)?;
+
+        let released = EscrowId::new(alice_id.clone(), "released".parse()?);
+        let expired = EscrowId::new(alice_id.clone(), "expired".parse()?);
+        CreateEscrow::new(Escrow::new(
+            released.clone(),
+            bob_id.clone(),
+            definition_id.clone(),
+            Numeric::from(11_u32),
+            500,
+            2_000,
+        ))
+        .execute(&alice_id, &mut state_transaction)
+        .expect_err("alice holds less than the escrowed quantity");
+        CreateEscrow::new(Escrow::new(
+            released.clone(),
+            bob_id.clone(),
+            definition_id.clone(),
+            Numeric::from(6_u32),
+            500,
+            2_000,
+        ))
+        .execute(&alice_id, &mut state_transaction)?;
+        CreateEscrow::new(Escrow::new(
+            expired.clone(),
+            bob_id.clone(),
+            definition_id.clone(),
+            Numeric::from(4_u32),
+            0,
+            1_000,
+        ))
+        .execute(&alice_id, &mut state_transaction)?;
+        assert!(state_transaction.world.asset(&alice_asset).is_err());
+
+        RefundEscrow::new(released.clone())
+            .execute(&alice_id, &mut state_transaction)
+            .expect_err("escrow isn't expired yet");
+        ClaimEscrow::new(expired.clone())
+            .execute(&bob_id, &mut state_transaction)
+            .expect_err("escrow is already expired");
+        ClaimEscrow::new(released.clone()).execute(&bob_id, &mut state_transaction)?;
+        RefundEscrow::new(expired).execute(&alice_id, &mut state_transaction)?;
+        ClaimEscrow::new(released)
+            .execute(&bob_id, &mut state_transaction)
+            .expect_err("escrow is already claimed");
+
+        assert_eq!(
+            *state_transaction.world.asset(&bob_asset)?.value(),
+            Numeric::from(6_u32)
+        );
+        assert_eq!(
+            *state_transaction.world.asset(&alice_asset)?.value(),
+            Numeric::from(4_u32)
+        );
+        assert!(state_transaction
+            .world
+            .escrows_iter(&alice_id)
+            .next()
+            .is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    async fn sunset_holdings_are_not_split_merged_or_released() -> Result<()> {
+        let kura = Kura::blank_kura_for_testing();
+        let state = state_with_test_domains(&kura)?;
+        let definition_id = "rose#wonderland".parse::<AssetDefinitionId>()?;
+        let alice_id = ALICE_ID.clone();
+        let (bob_id, _) = gen_account_in("wonderland");
+        let alice_asset = AssetId::new(definition_id.clone(), alice_id.clone());
+        let escrow_id = EscrowId::new(alice_id.clone(), "sunset".parse()?);
+        let block_at = |creation_time_ms| {
+            let mut block_header = ValidBlock::new_dummy(&KeyPair::random().into_parts().1)
+                .as_ref()
+                .header();
+            block_header.creation_time_ms = creation_time_ms;
+            block_header
+        };
+
+        let mut state_block = state.block(block_at(1_000));
+        let mut state_transaction = state_block.transaction();
+        Register::account(Account::new(bob_id.clone()))
+            .execute(&SAMPLE_GENESIS_ACCOUNT_ID, &mut state_transaction)?;
+        Mint::asset_numeric(10_u32, alice_asset.clone())
+            .execute(&alice_id, &mut state_transaction)?;
+        CreateEscrow::new(Escrow::new(
+            escrow_id.clone(),
+            bob_id.clone(),
+            definition_id.clone(),
+            Numeric::from(4_u32),
+            0,
+            5_000,
+        ))
+        .execute(&alice_id, &mut state_transaction)?;
+        Deprecate::asset_definition(definition_id, 100)
+            .execute(&alice_id, &mut state_transaction)?;
+        state_transaction.apply();
+        state_block.commit();
+
+        let mut state_block = state.block(block_at(2_000));
+        let mut state_transaction = state_block.transaction();
+        let error = Split::new(
+            alice_asset.clone(),
+            [
+                (alice_id.clone(), 3_u32.into()),
+                (bob_id.clone(), 3_u32.into()),
+            ],
+        )
+        .execute(&alice_id, &mut state_transaction)
+        .expect_err("sunset window is over");
+        assert!(matches!(error, Error::InvariantViolation(_)));
+        Merge::new([alice_asset], bob_id.clone())
+            .execute(&alice_id, &mut state_transaction)
+            .expect_err("sunset window is over");
+        ClaimEscrow::new(escrow_id)
+            .execute(&bob_id, &mut state_transaction)
+            .expect_err("sunset window is over");
+
+        Ok(())
+    }
+
+    #[test]
+    async fn message_is_left_in_inbox_of_recipient() -> Result<()> {
+        let kura = Kura::blank_kura_for_testing();
+        let state = state_with_test_domains(&kura)?;
+        let block_header = ValidBlock::new_dummy(&KeyPair::random().into_parts().1)
+            .as_ref()
+            .header();
+        let mut state_block = state.block(block_header);
+        let mut state_transaction = state_block.transaction();
+        let alice_id = ALICE_ID.clone();
+        let (bob_id, _) = gen_account_in("wonderland");
+        Register::account(Account::new(bob_id.clone()))
+            .execute(&alice_id, &mut state_transaction)?;
+        let payload = |len| EncryptedValue {
+            algorithm: EncryptionAlgorithm::X25519ChaCha20Poly1305,
+            recipient: bob_id.signatory().clone(),
+            ciphertext: vec![0; len],
+        };
+        let ttl = NonZeroU64::new(10).unwrap();
+
+        Message::new(bob_id.clone(), payload(MAX_MESSAGE_PAYLOAD_LEN + 1), ttl)
+            .execute(&alice_id, &mut state_transaction)
+            .expect_err("payload is too large");
+        Message::new(
+            bob_id.clone(),
+            payload(32),
+            NonZeroU64::new(MAX_MESSAGE_TTL_BLOCKS + 1).unwrap(),
+        )
+        .execute(&alice_id, &mut state_transaction)
+        .expect_err("ttl is too long");
+        Message::new(bob_id.clone(), payload(32), ttl)
+            .execute(&alice_id, &mut state_transaction)?;
+        Message::new(bob_id.clone(), payload(64), ttl)
+            .execute(&alice_id, &mut state_transaction)?;
+
+        let inbox: Vec<_> = state_transaction.world.inbox_iter(&bob_id).collect();
+        assert_eq!(inbox.len(), 2);
+        assert_eq!(*inbox[1].id(), DirectMessageId::new(bob_id.clone(), 1));
+        assert_eq!(inbox[1].sender(), &alice_id);
+        assert_eq!(inbox[1].payload().ciphertext.len(), 64);
+        assert!(state_transaction
+            .world
+            .inbox_iter(&alice_id)
+            .next()
+            .is_none());
+
+        for _ in inbox.len()..MAX_MESSAGES_PER_SENDER {
+            Message::new(bob_id.clone(), payload(32), ttl)
+                .execute(&alice_id, &mut state_transaction)?;
+        }
+        Message::new(bob_id.clone(), payload(32), ttl)
+            .execute(&alice_id, &mut state_transaction)
+            .expect_err("alice keeps too many messages in the inbox");
+        Message::new(bob_id.clone(), payload(32), ttl).execute(&bob_id, &mut state_transaction)?;
+        assert_eq!(
+            state_transaction.world.inbox_iter(&bob_id).count(),
+            MAX_MESSAGES_PER_SENDER + 1
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    async fn store_asset_holding_follows_its_entries() -> Result<()> {
+        let kura = Kura::blank_kura_for_testing();
+        let state = state_with_test_domains(&kura)?;
+        let block_header = ValidBlock::new_dummy(&KeyPair::random().into_parts().1)
+            .as_ref()
+            .header();
+        let mut state_block = state.block(block_header);
+        let mut state_transaction = state_block.transaction();
+        let account_id = ALICE_ID.clone();
+        let definition_id = "profile#wonderland".parse::<AssetDefinitionId>()?;
+        let asset_id = AssetId::new(definition_id.clone(), account_id.clone());
+        let key = "nickname".parse::<Name>()?;
+        Register::asset_definition(AssetDefinition::store(definition_id))
+            .execute(&account_id, &mut state_transaction)?;
+
+        SetKeyValue::asset(asset_id.clone(), key.clone(), "alice".to_owned())
+            .execute(&account_id, &mut state_transaction)?;
+        assert!(state_transaction
+            .world
+            .asset(&asset_id)?
+            .metadata
+            .contains(&key));
+        let error = Mint::asset_numeric(1_u32, asset_id.clone())
+            .execute(&account_id, &mut state_transaction)
+            .expect_err("store asset can't be minted");
+        assert!(matches!(
+            error,
+            Error::Evaluate(InstructionEvaluationError::Type(TypeError::AssetKind(
+                Mismatch {
+                    expected: AssetKind::Store,
+                    actual: AssetKind::Numeric,
+                }
+            )))
+        ));
+
+        RemoveKeyValue::asset(asset_id.clone(), key)
+            .execute(&account_id, &mut state_transaction)?;
+        assert!(state_transaction.world.asset(&asset_id).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    async fn domain_metadata() -> Result<()> {
+        let kura = Kura::blank_kura_for_testing();
+        let state = state_with_test_domains(&kura)?;
+        let block_header = ValidBlock::new_dummy(&KeyPair::random().into_parts().1)
+            .as_ref()
+            .header();
+        let mut state_block = state.block(block_header);
+        let mut state_transaction = state_block.transaction();
+        let domain_id = "wonderland".parse::<DomainId>()?;
+        let account_id = ALICE_ID.clone();
+        let key = "Bytes".parse::<Name>()?;
+        SetKeyValue::domain(domain_id.clone(), key.clone(), vec![1_u32, 2_u32, 3_u32])
+            .execute(&account_id, &mut state_transaction)?;
+        state_transaction.apply();
+        state_block.commit();
+        let bytes = state
+            .view()
+            .world
+            .domain(&domain_id)?
+            .metadata()
+            .get(&key)
+            .cloned();
+        assert_eq!(bytes, Some(vec![1_u32, 2_u32, 3_u32,].into()));
+        Ok(())
+    }
+
+    #[test]
+    async fn onboarded_account_receives_assets_and_roles() -> Result<()> {
+        let kura = Kura::blank_kura_for_testing();
+        let state = state_with_test_domains(&kura)?;
+        let block_header = ValidBlock::new_dummy(&KeyPair::random().into_parts().1)
+            .as_ref()
+            .header();
+        let mut state_block = state.block(block_header);
+        let mut state_transaction = state_block.transaction();
+        let (mad_hatter_id, _) = gen_account_in("wonderland");
+        let definition_id = "rose#wonderland".parse::<AssetDefinitionId>()?;
+        let role_id = "tea_party_guest".parse::<RoleId>()?;
+        Register::role(Role::new(role_id.clone(), ALICE_ID.clone()))
+            .execute(&ALICE_ID, &mut state_transaction)?;
+
+        Register::account_with(
+            Account::new(mad_hatter_id.clone()),
+            [(definition_id.clone(), Numeric::new(13, 0))],
+            [role_id.clone()],
+        )
+        .execute(&ALICE_ID, &mut state_transaction)?;
+        let asset_id = AssetId::new(definition_id, mad_hatter_id.clone());
+        assert_eq!(
+            *state_transaction.world.asset(&asset_id)?.value(),
+            Numeric::new(13, 0)
+        );
+        assert!(state_transaction
+            .world
+            .account_roles_iter(&mad_hatter_id)
+            .any(|role| *role == role_id));
+
+        // The account is registered, but the asset definition is missing
+        let (march_hare_id, _) = gen_account_in("wonderland");
+        assert!(matches!(
+            Register::account_with(
+                Account::new(march_hare_id),
+                [("tulip#wonderland".parse()?, Numeric::new(1, 0))],
+                [],
+            )
+            .execute(&ALICE_ID, &mut state_transaction)
+            .expect_err("Error expected"),
+            Error::Find(FindError::AssetDefinition(_))
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    async fn unregistered_account_leaves_tombstone_until_purged() -> Result<()> {
+        let kura = Kura::blank_kura_for_testing();
+        let state = state_with_test_domains(&kura)?;
+        let block_header = ValidBlock::new_dummy(&KeyPair::random().into_parts().1)
+            .as_ref()
+            .header();
+        let mut state_block = state.block(block_header);
+        let mut state_transaction = state_block.transaction();
+        let account_id = ALICE_ID.clone();
+        let tombstone_id = IdBox::AccountId(account_id.clone());
+
+        Unregister::account(account_id.clone())
+            .execute(&SAMPLE_GENESIS_ACCOUNT_ID, &mut state_transaction)?;
+        let tombstone = state_transaction.world.tombstone(&tombstone_id)?;
+        assert_eq!(*tombstone.removed_by(), *SAMPLE_GENESIS_ACCOUNT_ID);
+        assert_eq!(*tombstone.removed_at(), block_header.height());
+
+        Register::account(Account::new(account_id.clone()))
+            .execute(&SAMPLE_GENESIS_ACCOUNT_ID, &mut state_transaction)?;
+        assert!(state_transaction.world.tombstone(&tombstone_id).is_err());
+
+        Unregister::account(account_id.clone())
+            .execute(&SAMPLE_GENESIS_ACCOUNT_ID, &mut state_transaction)?;
+        Purge::account(account_id.clone())
+            .execute(&SAMPLE_GENESIS_ACCOUNT_ID, &mut state_transaction)?;
+        assert!(state_transaction.world.tombstone(&tombstone_id).is_err());
+        assert!(matches!(
+            Purge::account(account_id)
+                .execute(&SAMPLE_GENESIS_ACCOUNT_ID, &mut state_transaction)
+                .expect_err("Error expected"),
+            Error::Find(FindError::Tombstone(_))
+        ));
+
+        state_transaction.apply();
+        state_block.commit();
+
+        Ok(())
+    }
+
+    #[test]
+    async fn reregistered_domain_clears_tombstones() -> Result<()> {
+        let kura = Kura::blank_kura_for_testing();
+        let state = state_with_test_domains(&kura)?;
+        let block_header = ValidBlock::new_dummy(&KeyPair::random().into_parts().1)
+            .as_ref()
+            .header();
+        let mut state_block = state.block(block_header);
+        let mut state_transaction = state_block.transaction();
+        let domain_id = ALICE_ID.domain().clone();
+        let domain_tombstone_id = IdBox::DomainId(domain_id.clone());
+        let account_tombstone_id = IdBox::AccountId(ALICE_ID.clone());
+
+        Unregister::domain(domain_id.clone())
+            .execute(&SAMPLE_GENESIS_ACCOUNT_ID, &mut state_transaction)?;
+        assert!(state_transaction
+            .world
+            .tombstone(&domain_tombstone_id)
+            .is_ok());
+        assert!(state_transaction
+            .world
+            .tombstone(&account_tombstone_id)
+            .is_ok());
+
+        Register::domain(Domain::new(domain_id))
+            .execute(&SAMPLE_GENESIS_ACCOUNT_ID, &mut state_transaction)?;
+        assert!(state_transaction
+            .world
+            .tombstone(&domain_tombstone_id)
+            .is_err());
+        assert!(state_transaction
+            .world
+            .tombstone(&account_tombstone_id)
+            .is_err());
+
+        state_transaction.apply();
+        state_block.commit();
+
+        Ok(())
+    }
+
+    #[test]
+    async fn peer_registration_waits_for_activation_delay() -> Result<()> {
+        let kura = Kura::blank_kura_for_testing();
+        let state = state_with_test_domains(&kura)?;
+        let block_header = ValidBlock::new_dummy(&KeyPair::random().into_parts().1)
+            .as_ref()
+            .header();
+        let mut state_block = state.block(block_header);
+        state_block
+            .world
+            .parameters
+            .sumeragi
+            .peer_activation_delay_blocks = 2;
+        let peer_id = PeerId::new(KeyPair::random().into_parts().0);
+
+        let mut state_transaction = state_block.transaction();
+        Register::peer(peer_id.clone())
+            .execute(&SAMPLE_GENESIS_ACCOUNT_ID, &mut state_transaction)?;
+        assert!(matches!(
+            Register::peer(peer_id.clone())
+                .execute(&SAMPLE_GENESIS_ACCOUNT_ID, &mut state_transaction)
+                .expect_err("Error expected"),
+            Error::Repetition(_)
+        ));
+        state_transaction.apply();
+        assert!(!state_block.world.peers.contains(&peer_id));
+
+        let activation_height = block_header.height().get() + 2;
+        state_block.activate_peer_changes(activation_height - 1);
+        assert!(!state_block.world.peers.contains(&peer_id));
+        state_block.activate_peer_changes(activation_height);
+        assert!(state_block.world.peers.contains(&peer_id));
+        assert!(state_block.world.pending_peer_changes.is_empty());
+
+        state_block.commit();
+
+        Ok(())
+    }
+
+    #[test]
+    async fn fuel_schedule_is_activated_at_later_height() -> Result<()> {
+        let kura = Kura::blank_kura_for_testing();
+        let state = state_with_test_domains(&kura)?;
+        let block_header = ValidBlock::new_dummy(&KeyPair::random().into_parts().1)
+            .as_ref()
+            .header();
+        let height = block_header.height().get();
+        let mut state_block = state.block(block_header);
+        let mut state_transaction = state_block.transaction();
+        let schedule = |version, activation_height| FuelSchedule {
+            version,
+            activation_height,
+            instruction: 100 * u64::from(version),
+            query: 10 * u64::from(version),
+        };
+
+        assert!(matches!(
+            SetParameter::new(Parameter::FuelSchedule(schedule(1, height)))
+                .execute(&SAMPLE_GENESIS_ACCOUNT_ID, &mut state_transaction)
+                .expect_err("Error expected"),
+            Error::InvariantViolation(_)
+        ));
+        SetParameter::new(Parameter::FuelSchedule(schedule(1, height + 1)))
+            .execute(&SAMPLE_GENESIS_ACCOUNT_ID, &mut state_transaction)?;
+        for rejected in [schedule(1, height + 2), schedule(2, height)] {
+            assert!(matches!(
+                SetParameter::new(Parameter::FuelSchedule(rejected))
+                    .execute(&SAMPLE_GENESIS_ACCOUNT_ID, &mut state_transaction)
+                    .expect_err("Error expected"),
+                Error::InvariantViolation(_)
+            ));
+        }
+        SetParameter::new(Parameter::FuelSchedule(schedule(2, height + 3)))
+            .execute(&SAMPLE_GENESIS_ACCOUNT_ID, &mut state_transaction)?;
+
+        let parameters = state_transaction.world.parameters();
+        assert_eq!(parameters.fuel_schedule(height), FuelSchedule::default());
+        assert_eq!(
+            parameters.fuel_schedule(height + 1),
+            schedule(1, height + 1)
+        );
+        assert_eq!(
+            parameters.fuel_schedule(height + 2),
+            schedule(1, height + 1)
+        );
+        assert_eq!(
+            parameters.fuel_schedule(height + 3),
+            schedule(2, height + 3)
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    async fn executing_unregistered_trigger_should_return_error() -> Result<()> {
+        let kura = Kura::blank_kura_for_testing();
+        let state = state_with_test_domains(&kura)?;
+        let block_header = ValidBlock::new_dummy(&KeyPair::random().into_parts().1)
+            .as_ref()
+            .header();
+        let mut state_block = state.block(block_header);
+        let mut state_transaction = state_block.transaction();
+        let account_id = ALICE_ID.clone();
+        let trigger_id = "test_trigger_id".parse()?;
+
+        assert!(matches!(
+            ExecuteTrigger::new(trigger_id)
+                .execute(&account_id, &mut state_transaction)
+                .expect_err("Error expected"),
+            Error::Find(_)
+        ));
+
+        state_transaction.apply();
+        state_block.commit();
+
+        Ok(())
+    }
+
+    #[test]
+    async fn unauthorized_trigger_execution_should_return_error() -> Result<()> {
+        let kura = Kura::blank_kura_for_testing();
+        let state = state_with_test_domains(&kura)?;
+        let block_header = ValidBlock::new_dummy(&KeyPair::random().into_parts().1)
+            .as_ref()
+            .header();
+        let mut state_block = state.block(block_header);
+        let mut state_transaction = state_block.transaction();
+        let account_id = ALICE_ID.clone();
+        let (fake_account_id, _fake_account_keypair) = gen_account_in("wonderland");
+        let trigger_id = "test_trigger_id".parse::<TriggerId>()?;
+
+        // register fake account
+        let register_account = Register::account(Account::new(fake_account_id.clone()));
+        register_account.execute(&account_id, &mut state_transaction)?;
+
+        // register the trigger
+        let register_trigger = Register::trigger(Trigger::new(
+            trigger_id.clone(),
+            Action::new(
+                Vec::<InstructionBox>::new(),
+                Repeats::Indefinitely,
+                account_id.clone(),
+                ExecuteTriggerEventFilter::new()
+                    .for_trigger(trigger_id.clone())
+                    .under_authority(account_id.clone()),
+            ),
+        ));
+
+        register_trigger.execute(&account_id, &mut state_transaction)?;
+
+        // execute with the valid account
+        ExecuteTrigger::new(trigger_id.clone()).execute(&account_id, &mut state_transaction)?;
+
+        // execute with the fake account
+        assert!(matches!(
+            ExecuteTrigger::new(trigger_id)
+                .execute(&fake_account_id, &mut state_transaction)
+                .expect_err("Error expected"),
+            Error::InvariantViolation(_)
+        ));
+
+        state_transaction.apply();
+        state_block.commit();
+
+        Ok(())
+    }
+
+    #[test]
+    async fn failed_trigger_execution_is_kept_as_dead_letter() -> Result<()> {
+        let kura = Kura::blank_kura_for_testing();
+        let state = state_with_test_domains(&kura)?;
+        let block_header = ValidBlock::new_dummy(&KeyPair::random().into_parts().1)
+            .as_ref()
+            .header();
+        let mut state_block = state.block(block_header);
+        let account_id = ALICE_ID.clone();
+        let trigger_id = "failing_trigger".parse::<TriggerId>()?;
+
+        let mut state_transaction = state_block.transaction();
+        Register::trigger(Trigger::new(
+            trigger_id.clone(),
+            Action::new(
+                [Unregister::domain("nowhere".parse()?)],
+                Repeats::Indefinitely,
+                account_id.clone(),
+                ExecuteTriggerEventFilter::new()
+                    .for_trigger(trigger_id.clone())
+                    .under_authority(account_id.clone()),
+            ),
+        ))
+        .execute(&account_id, &mut state_transaction)?;
+        state_transaction.apply();
+
+        for _ in 0..=MAX_DEAD_LETTERS_PER_TRIGGER {
+            let mut state_transaction = state_block.transaction();
+            assert!(ExecuteTrigger::new(trigger_id.clone())
+                .execute(&account_id, &mut state_transaction)
+                .is_err());
+            let failure = state_transaction
+                .failed_trigger
+                .take()
+                .expect("failure should be captured");
+            drop(state_transaction);
+            state_block.record_dead_letter(failure);
+        }
+
+        let dead_letters = state_block
+            .world
+            .trigger_dead_letters_iter(&trigger_id)
+            .cloned()
+            .collect::<Vec<_>>();
+        assert_eq!(dead_letters.len(), MAX_DEAD_LETTERS_PER_TRIGGER);
+        // The oldest dead letter is evicted
+        assert_eq!(*dead_letters[0].id().sequence(), 1);
+        assert_eq!(dead_letters[0].authority(), &account_id);
+
+        let mut state_transaction = state_block.transaction();
+        let dead_letter_id = dead_letters[0].id().clone();
+        DiscardDeadLetter::new(dead_letter_id.clone())
+            .execute(&account_id, &mut state_transaction)?;
+        assert!(matches!(
+            DiscardDeadLetter::new(dead_letter_id)
+                .execute(&account_id, &mut state_transaction)
+                .expect_err("Error expected"),
+            Error::Find(FindError::DeadLetter(_))
+        ));
+
+        Unregister::trigger(trigger_id.clone()).execute(&account_id, &mut state_transaction)?;
+        assert_eq!(
+            state_transaction
+                .world
+                .trigger_dead_letters_iter(&trigger_id)
+                .count(),
+            0
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    async fn subscriptions_follow_their_account() -> Result<()> {
+        let kura = Kura::blank_kura_for_testing();
+        let state = state_with_test_domains(&kura)?;
+        let block_header = ValidBlock::new_dummy(&KeyPair::random().into_parts().1)
+            .as_ref()
+            .header();
+        let mut state_block = state.block(block_header);
+        let mut state_transaction = state_block.transaction();
+        let account_id = ALICE_ID.clone();
+        let subscription_id = SubscriptionId::new(account_id.clone(), "mint_alerts".parse()?);
+        let subscription = Subscription::new(
+            subscription_id.clone(),
+            DataEventFilter::Any.into(),
+            SubscriptionDelivery::Inbox,
+        );
+
+        Subscribe::new(subscription.clone()).execute(&account_id, &mut state_transaction)?;
+        assert!(matches!(
+            Subscribe::new(subscription.clone())
+                .execute(&account_id, &mut state_transaction)
+                .expect_err("Error expected"),
+            Error::InvariantViolation(_)
+        ));
+        assert_eq!(
+            state_transaction.world.subscription(&subscription_id)?,
+            &subscription
+        );
+
+        Unregister::account(account_id.clone()).execute(&account_id, &mut state_transaction)?;
+        assert!(matches!(
+            state_transaction.world.subscription(&subscription_id),
+            Err(FindError::Subscription(_))
+        ));
+        assert!(matches!(
+            Unsubscribe::new(subscription_id)
+                .execute(&account_id, &mut state_transaction)
+                .expect_err("Error expected"),
+            Error::Find(FindError::Subscription(_))
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    async fn subscriptions_are_bounded() -> Result<()> {
+        let kura = Kura::blank_kura_for_testing();
+        let state = state_with_test_domains(&kura)?;
+        let block_header = ValidBlock::new_dummy(&KeyPair::random().into_parts().1)
+            .as_ref()
+            .header();
+        let mut state_block = state.block(block_header);
+        let mut state_transaction = state_block.transaction();
+        let account_id = ALICE_ID.clone();
+        let subscription = |name: &str, delivery| {
+            Subscribe::new(Subscription::new(
+                SubscriptionId::new(account_id.clone(), name.parse().unwrap()),
+                DataEventFilter::Any.into(),
+                delivery,
+            ))
+        };
+
+        assert!(matches!(
+            subscription(
+                "hook",
+                SubscriptionDelivery::Webhook("file:///etc/passwd".to_owned())
+            )
+            .execute(&account_id, &mut state_transaction)
+            .expect_err("Error expected"),
+            Error::InvariantViolation(_)
+        ));
+        for index in 0..MAX_SUBSCRIPTIONS_PER_ACCOUNT {
+            subscription(&format!("inbox_{index}"), SubscriptionDelivery::Inbox)
+                .execute(&account_id, &mut state_transaction)?;
+        }
+        assert!(matches!(
+            subscription("one_too_many", SubscriptionDelivery::Inbox)
+                .execute(&account_id, &mut state_transaction)
+                .expect_err("Error expected"),
+            Error::InvariantViolation(_)
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    async fn revoking_permission_cascades_to_delegations() -> Result<()> {
+        let kura = Kura::blank_kura_for_testing();
+        let state = state_with_test_domains(&kura)?;
+        let block_header = ValidBlock::new_dummy(&KeyPair::random().into_parts().1)
+            .as_ref()
+            .header();
+        let mut state_block = state.block(block_header);
+        let mut state_transaction = state_block.transaction();
+        let accounts = (0..=MAX_DELEGATION_DEPTH)
+            .map(|_| gen_account_in("wonderland").0)
+            .collect::<Vec<_>>();
+        for account_id in &accounts {
+            Register::account(Account::new(account_id.clone()))
+                .execute(&SAMPLE_GENESIS_ACCOUNT_ID, &mut state_transaction)?;
+        }
+        let permission = Permission::new("CanPaintRoses".to_owned(), Json::from(true));
+        Grant::account_permission(permission.clone(), ALICE_ID.clone())
+            .execute(&SAMPLE_GENESIS_ACCOUNT_ID, &mut state_transaction)?;
+
+        let mut delegator = ALICE_ID.clone();
+        for account_id in &accounts[..accounts.len() - 1] {
+            Delegate::new(permission.clone(), account_id.clone())
+                .execute(&delegator, &mut state_transaction)?;
+            delegator = account_id.clone();
+        }
+        let last = accounts.last().expect("accounts are generated");
+        assert!(matches!(
+            Delegate::new(permission.clone(), last.clone())
+                .execute(&delegator, &mut state_transaction)
+                .expect_err("Error expected"),
+            Error::InvariantViolation(_)
+        ));
+        assert_eq!(
+            state_transaction
+                .world
+                .incoming_delegation(&delegator, &permission)
+                .map(|delegation| delegation.depth),
+            Some(MAX_DELEGATION_DEPTH)
+        );
+
+        Revoke::account_permission(permission.clone(), ALICE_ID.clone())
+            .execute(&SAMPLE_GENESIS_ACCOUNT_ID, &mut state_transaction)?;
+        for account_id in &accounts {
+            assert!(!state_transaction
+                .world
+                .account_contains_inherent_permission(account_id, &permission));
+        }
+        assert_eq!(state_transaction.world.delegations_iter().count(), 0);
+        assert_eq!(
+            state_transaction
+                .world
+                .incoming_delegations()
+                .iter()
+                .count(),
+            0
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    async fn expired_grants_are_revoked() -> Result<()> {
+        let kura = Kura::blank_kura_for_testing();
+        let state = state_with_test_domains(&kura)?;
+        let mut block_header = ValidBlock::new_dummy(&KeyPair::random().into_parts().1)
+            .as_ref()
+            .header();
+        block_header.creation_time_ms = 1_000;
+        let mut state_block = state.block(block_header);
+        let mut state_transaction = state_block.transaction();
+        let temporary = Permission::new("CanPaintRoses".to_owned(), Json::from(true));
+        let permanent = Permission::new("CanPlantRoses".to_owned(), Json::from(true));
+        let operator: RoleId = "operator".parse()?;
+        Register::role(Role::new(operator.clone(), ALICE_ID.clone()))
+            .execute(&SAMPLE_GENESIS_ACCOUNT_ID, &mut state_transaction)?;
+
+        assert!(matches!(
+            Grant::account_permission(temporary.clone(), ALICE_ID.clone())
+                .valid_until(1_000)
+                .execute(&SAMPLE_GENESIS_ACCOUNT_ID, &mut state_transaction)
+                .expect_err("Error expected"),
+            Error::InvariantViolation(_)
+        ));
+        assert!(matches!(
+            Grant::role_permission(temporary.clone(), operator.clone())
+                .valid_until(2_000)
+                .execute(&SAMPLE_GENESIS_ACCOUNT_ID, &mut state_transaction)
+                .expect_err("Error expected"),
+            Error::InvariantViolation(_)
+        ));
+        Grant::account_permission(temporary.clone(), ALICE_ID.clone())
+            .valid_until(2_000)
+            .execute(&SAMPLE_GENESIS_ACCOUNT_ID, &mut state_transaction)?;
+        Grant::account_permission(permanent.clone(), ALICE_ID.clone())
+            .execute(&SAMPLE_GENESIS_ACCOUNT_ID, &mut state_transaction)?;
+        Grant::account_role(operator.clone(), ALICE_ID.clone())
+            .valid_until(2_000)
+            .execute(&SAMPLE_GENESIS_ACCOUNT_ID, &mut state_transaction)?;
+        state_transaction.apply();
+
+        state_block.expire_grants(Duration::from_millis(1_999));
+        assert!(state_block
+            .world
+            .account_contains_inherent_permission(&ALICE_ID, &temporary));
+        assert_eq!(state_block.world.account_roles_iter(&ALICE_ID).count(), 1);
+
+        state_block.expire_grants(Duration::from_millis(2_000));
+        assert!(!state_block
+            .world
+            .account_contains_inherent_permission(&ALICE_ID, &temporary));
+        assert!(state_block
+            .world
+            .account_contains_inherent_permission(&ALICE_ID, &permanent));
+        assert_eq!(state_block.world.account_roles_iter(&ALICE_ID).count(), 0);
+        assert!(state_block.world.grant_expiries.iter().next().is_none());
+        assert!(state_block.world.grants_by_expiry.iter().next().is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    async fn role_includes_roles_it_extends() -> Result<()> {
+        let kura = Kura::blank_kura_for_testing();
+        let state = state_with_test_domains(&kura)?;
+        let block_header = ValidBlock::new_dummy(&KeyPair::random().into_parts().1)
+            .as_ref()
+            .header();
+        let mut state_block = state.block(block_header);
+        let mut state_transaction = state_block.transaction();
+        let reader: RoleId = "reader".parse()?;
+        let writer: RoleId = "writer".parse()?;
+        let admin: RoleId = "admin".parse()?;
+        let permission = Permission::new("CanReadRoses".to_owned(), Json::from(true));
+
+        assert!(matches!(
+            Register::role(Role::new(writer.clone(), ALICE_ID.clone()).extend(reader.clone()))
+                .execute(&SAMPLE_GENESIS_ACCOUNT_ID, &mut state_transaction)
+                .expect_err("Error expected"),
+            Error::Find(FindError::Role(_))
+        ));
+        Register::role(
+            Role::new(reader.clone(), ALICE_ID.clone()).add_permission(permission.clone()),
+        )
+        .execute(&SAMPLE_GENESIS_ACCOUNT_ID, &mut state_transaction)?;
+        Register::role(Role::new(writer.clone(), ALICE_ID.clone()).extend(reader.clone()))
+            .execute(&SAMPLE_GENESIS_ACCOUNT_ID, &mut state_transaction)?;
+        Register::role(Role::new(admin.clone(), ALICE_ID.clone()).extend(writer.clone()))
+            .execute(&SAMPLE_GENESIS_ACCOUNT_ID, &mut state_transaction)?;
+        assert_eq!(
+            state_transaction.world.inherited_roles(&admin),
+            [&reader, &writer].into_iter().collect()
+        );
+        assert_eq!(
+            state_transaction.world.role_permissions(&admin),
+            [&permission].into_iter().collect()
+        );
+
+        Unregister::role(reader.clone())
+            .execute(&SAMPLE_GENESIS_ACCOUNT_ID, &mut state_transaction)?;
+        assert_eq!(
+            state_transaction.world.inherited_roles(&admin),
+            [&writer].into_iter().collect()
+        );
+        assert_eq!(state_transaction.world.role(&writer)?.extends().len(), 0);
+
+        Ok(())
+    }
+
+    #[test]
+    async fn not_allowed_to_register_genesis_domain_or_account() -> Result<()> {
+        let kura = Kura::blank_kura_for_testing();
+        let state = state_with_test_domains(&kura)?;
+        let block_header = ValidBlock::new_dummy(&KeyPair::random().into_parts().1)
+            .as_ref()
+            .header();
+        let mut state_block = state.block(block_header);
+        let mut state_transaction = state_block.transaction();
+        let account_id = ALICE_ID.clone();
+        assert!(matches!(
+            Register::domain(Domain::new("genesis".parse()?))
+                .execute(&account_id, &mut state_transaction)
+                .expect_err("Error expected"),
+            Error::InvariantViolation(_)
+        ));
+        let register_account = Register::account(Account::new(SAMPLE_GENESIS_ACCOUNT_ID.clone()));
+        assert!(matches!(
+            register_account
+                .execute(&account_id, &mut state_transaction)
+                .expect_err("Error expected"),
+            Error::InvariantViolation(_)
+        ));
+        state_transaction.apply();
+        state_block.commit();
+
+        Ok(())
+    }
+
+    #[test]
+    async fn transaction_signed_by_genesis_account_should_be_rejected() -> Result<()> {
+        let chain_id = ChainId::from("00000000-0000-0000-0000-000000000000");
+        let kura = Kura::blank_kura_for_testing();
+        let state = state_with_test_domains(&kura)?;
+        let (max_clock_drift, tx_limits) = {
+            let state_view = state.world.view();
+            let params = state_view.parameters();
+            (params.sumeragi().max_clock_drift(), params.transaction)
+        };
+
+        let tx = TransactionBuilder::new(chain_id.clone(), SAMPLE_GENESIS_ACCOUNT_ID.clone())
+            .with_instructions::<InstructionBox>([])
+            .sign(SAMPLE_GENESIS_ACCOUNT_KEYPAIR.private_key());
+        assert!(matches!(
             AcceptedTransaction::accept(tx, &chain_id, max_clock_drift, tx_limits),
             Err(AcceptTransactionFail::UnexpectedGenesisAccountSignature)
         ));
         Ok(())
     }
+
+    #[test]
+    async fn onboarding_counts_towards_max_instructions() -> Result<()> {
+        let chain_id = ChainId::from("00000000-0000-0000-0000-000000000000");
+        let kura = Kura::blank_kura_for_testing();
+        let state = state_with_test_domains(&kura)?;
+        let (max_clock_drift, mut tx_limits) = {
+            let state_view = state.world.view();
+            let params = state_view.parameters();
+            (params.sumeragi().max_clock_drift(), params.transaction)
+        };
+        tx_limits.max_instructions = NonZeroU64::new(2).unwrap();
+
+        let (mad_hatter_id, _) = gen_account_in("wonderland");
+        let onboard = Register::account_with(
+            Account::new(mad_hatter_id),
+            [("rose#wonderland".parse()?, Numeric::new(13, 0))],
+            ["tea_party_guest".parse()?],
+        );
+        assert_eq!(InstructionBox::from(onboard.clone()).instruction_count(), 3);
+
+        let tx = TransactionBuilder::new(chain_id.clone(), ALICE_ID.clone())
+            .with_instructions([onboard])
+            .sign(ALICE_KEYPAIR.private_key());
+        assert!(matches!(
+            AcceptedTransaction::accept(tx, &chain_id, max_clock_drift, tx_limits),
+            Err(AcceptTransactionFail::TransactionLimit(_))
+        ));
+        Ok(())
+    }
 }
@@ -22,6 +22,7 @@ pub mod isi {
             state_transaction: &mut StateTransaction<'_, '_>,
         ) -> Result<(), Error> {
             let nft = self.object.build(authority);
+            check_metadata(&nft.content, state_transaction)?;
             let (nft_id, nft_value) = nft.clone().into_key_value();
 
             if state_transaction.world.nft(&nft_id).is_ok() {
@@ -81,6 +82,7 @@ pub mod isi {
             state_transaction: &mut StateTransaction<'_, '_>,
         ) -> Result<(), Error> {
             let nft_id = self.object;
+            check_metadata_limits(&self.value, state_transaction)?;
 
             state_transaction
                 .world
@@ -131,7 +133,7 @@ pub mod isi {
         #[metrics(+"transfer_nft")]
         fn execute(
             self,
-            _authority: &AccountId,
+            authority: &AccountId,
             state_transaction: &mut StateTransaction<'_, '_>,
         ) -> Result<(), Error> {
             let Transfer {
@@ -143,8 +145,17 @@ pub mod isi {
             state_transaction.world.account(&source)?;
             state_transaction.world.account(&destination)?;
 
+            // Non-transferable NFTs are issued and revoked by the owner of their domain
+            let is_issuer = state_transaction.curr_block.is_genesis()
+                || state_transaction.world.domain(&object.domain)?.owned_by() == authority;
             let nft = state_transaction.world.nft_mut(&object)?;
 
+            if !nft.transferable && !is_issuer {
+                return Err(Error::InvariantViolation(format!(
+                    "NFT {object} is non-transferable and can only be moved by the owner of its domain",
+                )));
+            }
+
             if nft.owned_by != source {
                 return Err(Error::InvariantViolation(format!(
                     "Can't transfer NFT {object} since {source} doesn't own it",
@@ -7,49 +7,100 @@ use iroha_data_model::{
     prelude::*,
     query::{
         dsl::{EvaluateSelector, HasProjection, SelectorMarker},
-        error::QueryExecutionFail as Error,
-        parameters::QueryParams,
-        CommittedTransaction, QueryBox, QueryOutputBatchBox, QueryRequest,
+        error::{FindError, QueryExecutionFail as Error},
+        parameters::{Aggregation, QueryParams, SortKey},
+        AssetWithDefinition, CommittedTransaction, QueryBox, QueryOutputBatchBox, QueryRequest,
         QueryRequestWithAuthority, QueryResponse, SingularQueryBox, SingularQueryOutputBox,
     },
 };
 
 use crate::{
     prelude::ValidSingularQuery,
-    query::{cursor::ErasedQueryIterator, pagination::Paginate as _, store::LiveQueryStoreHandle},
+    query::{
+        cursor::{evaluate_selector_tuple, ErasedQueryIterator},
+        pagination::Paginate as _,
+        scope::{restrict, DomainScope},
+        store::LiveQueryStoreHandle,
+    },
     smartcontracts::{wasm, ValidQuery},
     state::{StateReadOnly, WorldReadOnly},
 };
 
-/// Allows to generalize retrieving the metadata key for all the query output types
+/// Allows to generalize retrieving the sorting keys for all the query output types
 pub trait SortableQueryOutput {
     /// Get the sorting key for the output, from metadata
     ///
     /// If the type doesn't have metadata or metadata key doesn't exist - return None
     fn get_metadata_sorting_key(&self, key: &Name) -> Option<Json>;
+
+    /// Compare the outputs by the `key` in the ascending order
+    ///
+    /// If the type doesn't have the key - return [`Ordering::Equal`], keeping the order of the outputs
+    fn compare_by(&self, other: &Self, key: SortKey) -> Ordering;
 }
 
 impl SortableQueryOutput for Account {
     fn get_metadata_sorting_key(&self, key: &Name) -> Option<Json> {
         self.metadata.get(key).cloned()
     }
+
+    fn compare_by(&self, other: &Self, key: SortKey) -> Ordering {
+        match key {
+            SortKey::Id => self.id.cmp(&other.id),
+            SortKey::Value => Ordering::Equal,
+        }
+    }
 }
 
 impl SortableQueryOutput for Domain {
     fn get_metadata_sorting_key(&self, key: &Name) -> Option<Json> {
         self.metadata.get(key).cloned()
     }
+
+    fn compare_by(&self, other: &Self, key: SortKey) -> Ordering {
+        match key {
+            SortKey::Id => self.id.cmp(&other.id),
+            SortKey::Value => Ordering::Equal,
+        }
+    }
 }
 
 impl SortableQueryOutput for AssetDefinition {
     fn get_metadata_sorting_key(&self, key: &Name) -> Option<Json> {
         self.metadata.get(key).cloned()
     }
+
+    fn compare_by(&self, other: &Self, key: SortKey) -> Ordering {
+        match key {
+            SortKey::Id => self.id.cmp(&other.id),
+            SortKey::Value => Ordering::Equal,
+        }
+    }
 }
 
 impl SortableQueryOutput for Asset {
-    fn get_metadata_sorting_key(&self, _key: &Name) -> Option<Json> {
-        None
+    fn get_metadata_sorting_key(&self, key: &Name) -> Option<Json> {
+        self.metadata.get(key).cloned()
+    }
+
+    fn compare_by(&self, other: &Self, key: SortKey) -> Ordering {
+        match key {
+            SortKey::Id => self.id.cmp(&other.id),
+            SortKey::Value => self.value.cmp(&other.value),
+        }
+    }
+}
+
+impl SortableQueryOutput for AssetWithDefinition {
+    fn get_metadata_sorting_key(&self, key: &Name) -> Option<Json> {
+        self.asset.metadata.get(key).cloned()
+    }
+
+    fn compare_by(&self, other: &Self, key: SortKey) -> Ordering {
+        match key {
+            SortKey::Id => self.asset.id.cmp(&other.asset.id),
+            SortKey::Value => self.asset.value.cmp(&other.asset.value),
+        }
     }
 }
 
@@ -57,67 +108,146 @@ impl SortableQueryOutput for Nft {
     fn get_metadata_sorting_key(&self, key: &Name) -> Option<Json> {
         self.content.get(key).cloned()
     }
+
+    fn compare_by(&self, other: &Self, key: SortKey) -> Ordering {
+        match key {
+            SortKey::Id => self.id.cmp(&other.id),
+            SortKey::Value => Ordering::Equal,
+        }
+    }
 }
 
 impl SortableQueryOutput for Role {
     fn get_metadata_sorting_key(&self, _key: &Name) -> Option<Json> {
         None
     }
+
+    fn compare_by(&self, other: &Self, key: SortKey) -> Ordering {
+        match key {
+            SortKey::Id => self.id.cmp(&other.id),
+            SortKey::Value => Ordering::Equal,
+        }
+    }
 }
 
 impl SortableQueryOutput for RoleId {
     fn get_metadata_sorting_key(&self, _key: &Name) -> Option<Json> {
         None
     }
+
+    fn compare_by(&self, other: &Self, key: SortKey) -> Ordering {
+        match key {
+            SortKey::Id => self.cmp(other),
+            SortKey::Value => Ordering::Equal,
+        }
+    }
 }
 
 impl SortableQueryOutput for CommittedTransaction {
     fn get_metadata_sorting_key(&self, _key: &Name) -> Option<Json> {
         None
     }
+
+    fn compare_by(&self, _other: &Self, _key: SortKey) -> Ordering {
+        Ordering::Equal
+    }
 }
 
 impl SortableQueryOutput for PeerId {
     fn get_metadata_sorting_key(&self, _key: &Name) -> Option<Json> {
         None
     }
+
+    fn compare_by(&self, other: &Self, key: SortKey) -> Ordering {
+        match key {
+            SortKey::Id => self.cmp(other),
+            SortKey::Value => Ordering::Equal,
+        }
+    }
 }
 
 impl SortableQueryOutput for Permission {
     fn get_metadata_sorting_key(&self, _key: &Name) -> Option<Json> {
         None
     }
+
+    fn compare_by(&self, other: &Self, key: SortKey) -> Ordering {
+        match key {
+            SortKey::Id => self.cmp(other),
+            SortKey::Value => Ordering::Equal,
+        }
+    }
+}
+
+impl SortableQueryOutput for Parameter {
+    fn get_metadata_sorting_key(&self, _key: &Name) -> Option<Json> {
+        None
+    }
+
+    fn compare_by(&self, _other: &Self, _key: SortKey) -> Ordering {
+        Ordering::Equal
+    }
 }
 
 impl SortableQueryOutput for Trigger {
     fn get_metadata_sorting_key(&self, _key: &Name) -> Option<Json> {
         None
     }
+
+    fn compare_by(&self, other: &Self, key: SortKey) -> Ordering {
+        match key {
+            SortKey::Id => self.id.cmp(&other.id),
+            SortKey::Value => Ordering::Equal,
+        }
+    }
 }
 
 impl SortableQueryOutput for TriggerId {
     fn get_metadata_sorting_key(&self, _key: &Name) -> Option<Json> {
         None
     }
+
+    fn compare_by(&self, other: &Self, key: SortKey) -> Ordering {
+        match key {
+            SortKey::Id => self.cmp(other),
+            SortKey::Value => Ordering::Equal,
+        }
+    }
 }
 
 impl SortableQueryOutput for iroha_data_model::block::SignedBlock {
     fn get_metadata_sorting_key(&self, _key: &Name) -> Option<Json> {
         None
     }
+
+    fn compare_by(&self, other: &Self, key: SortKey) -> Ordering {
+        match key {
+            SortKey::Id => self.header().height().cmp(&other.header().height()),
+            SortKey::Value => Ordering::Equal,
+        }
+    }
 }
 
 impl SortableQueryOutput for iroha_data_model::block::BlockHeader {
     fn get_metadata_sorting_key(&self, _key: &Name) -> Option<Json> {
         None
     }
+
+    fn compare_by(&self, other: &Self, key: SortKey) -> Ordering {
+        match key {
+            SortKey::Id => self.height().cmp(&other.height()),
+            SortKey::Value => Ordering::Equal,
+        }
+    }
 }
 
-/// Applies sorting and pagination to the query output and wraps it into a type-erasing batching iterator.
+/// Applies sorting and pagination or aggregation to the query output and wraps it into a type-erasing batching iterator.
+///
+/// Aggregations are computed over the whole output, the pagination isn't applied to them.
 ///
 /// # Errors
 ///
-/// Returns an error if the fetch size is too big
+/// Returns an error if the fetch size is too big or the output can't be aggregated
 pub fn apply_query_postprocessing<I>(
     iter: I,
     selector: SelectorTuple<I::Item>,
@@ -125,6 +255,7 @@ pub fn apply_query_postprocessing<I>(
         pagination,
         ref sorting,
         fetch_size,
+        aggregation,
     }: &QueryParams,
 ) -> Result<ErasedQueryIterator, Error>
 where
@@ -141,6 +272,16 @@ where
         return Err(Error::FetchSizeTooBig);
     }
 
+    // aggregate the whole output, before it could be paginated
+    if let Some(aggregation) = aggregation {
+        let value = aggregate(iter.collect(), &selector, aggregation)?;
+        return Ok(ErasedQueryIterator::new(
+            value.into_iter(),
+            SelectorTuple::default(),
+            fetch_size,
+        ));
+    }
+
     // sort & paginate, erase the iterator with QueryBatchedErasedIterator
     let output = if let Some(key) = &sorting.sort_by_metadata_key {
         // if sorting was requested, we need to retrieve all the results first
@@ -152,7 +293,7 @@ where
             .collect();
         pairs.sort_by(
             |(left_key, _), (right_key, _)| match (left_key, right_key) {
-                (Some(l), Some(r)) => l.cmp(r),
+                (Some(l), Some(r)) => sorting.order.apply(l.cmp(r)),
                 (Some(_), None) => Ordering::Less,
                 (None, Some(_)) => Ordering::Greater,
                 (None, None) => Ordering::Equal,
@@ -164,6 +305,16 @@ where
             selector,
             fetch_size,
         )
+    } else if let Some(key) = sorting.sort_by {
+        // same as above, the results are sorted before the pagination
+        let mut output = iter.collect::<Vec<_>>();
+        output.sort_by(|left, right| sorting.order.apply(left.compare_by(right, key)));
+
+        ErasedQueryIterator::new(
+            output.into_iter().paginate(pagination),
+            selector,
+            fetch_size,
+        )
     } else {
         // FP: this collect is very deliberate
         #[allow(clippy::needless_collect)]
@@ -182,6 +333,43 @@ where
     Ok(output)
 }
 
+/// Aggregate the `output` into a single value, `None` if there is nothing to aggregate.
+///
+/// All the aggregations but [`Aggregation::Count`] require exactly one numeric value to be selected by the `selector`.
+fn aggregate<T>(
+    output: Vec<T>,
+    selector: &SelectorTuple<T>,
+    aggregation: Aggregation,
+) -> Result<Option<Numeric>, Error>
+where
+    T: HasProjection<SelectorMarker, AtomType = ()> + 'static,
+    T::Projection: EvaluateSelector<T>,
+{
+    let numeric_values = |output: Vec<T>| {
+        let mut selected = evaluate_selector_tuple(output, selector)?.tuple;
+        match selected.as_mut_slice() {
+            [QueryOutputBatchBox::Numeric(values)] => Ok(core::mem::take(values)),
+            _ => Err(Error::Aggregation(
+                "exactly one numeric value has to be selected".to_owned(),
+            )),
+        }
+    };
+
+    let value = match aggregation {
+        Aggregation::Count => Some(Numeric::from(output.len() as u64)),
+        Aggregation::Sum => Some(
+            numeric_values(output)?
+                .into_iter()
+                .try_fold(Numeric::ZERO, Numeric::checked_add)
+                .ok_or_else(|| Error::Aggregation("sum overflowed".to_owned()))?,
+        ),
+        Aggregation::Min => numeric_values(output)?.into_iter().min(),
+        Aggregation::Max => numeric_values(output)?.into_iter().max(),
+    };
+
+    Ok(value)
+}
+
 /// Query Request statefully validated on the Iroha node side.
 #[derive(Debug, Clone)]
 pub struct ValidQueryRequest(QueryRequest);
@@ -229,13 +417,34 @@ impl ValidQueryRequest {
     /// # Errors
     ///
     /// Returns an error if the query execution fails.
-    #[allow(clippy::too_many_lines)] // not much we can do, we _need_ to list all the box types here
     pub fn execute(
         self,
         live_query_store: &LiveQueryStoreHandle,
         state: &impl StateReadOnly,
         authority: &AccountId,
     ) -> Result<QueryResponse, Error> {
+        self.execute_in_scope(live_query_store, state, authority, None)
+    }
+
+    /// Execute a validated query request, restricting its output to the `scope` if any.
+    ///
+    /// Continuations of iterable queries are not restricted, as the whole output is restricted when the query starts.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query execution fails
+    /// or a singular query targets an entity outside of the `scope`.
+    #[allow(clippy::too_many_lines)] // not much we can do, we _need_ to list all the box types here
+    pub fn execute_in_scope(
+        self,
+        live_query_store: &LiveQueryStoreHandle,
+        state: &impl StateReadOnly,
+        authority: &AccountId,
+        scope: Option<DomainScope>,
+    ) -> Result<QueryResponse, Error> {
+        let is_visible =
+            |domain: &DomainId| scope.as_ref().is_none_or(|scope| scope.contains(domain));
+
         match self.0 {
             QueryRequest::Singular(singular_query) => {
                 let output = match singular_query {
@@ -245,90 +454,225 @@ impl ValidQueryRequest {
                     SingularQueryBox::FindParameters(q) => {
                         SingularQueryOutputBox::from(q.execute(state)?)
                     }
+                    SingularQueryBox::FindFuelSchedule(q) => {
+                        SingularQueryOutputBox::from(q.execute(state)?)
+                    }
+                    SingularQueryBox::FindTombstone(q) => {
+                        SingularQueryOutputBox::from(q.execute(state)?)
+                    }
+                    SingularQueryBox::FindAssetQuantityAt(q) => {
+                        if !is_visible(q.asset_id.account().domain()) {
+                            return Err(Error::Find(FindError::Asset(Box::new(q.asset_id))));
+                        }
+                        SingularQueryOutputBox::from(q.execute(state)?)
+                    }
+                    SingularQueryBox::FindSubscription(q) => {
+                        SingularQueryOutputBox::from(q.execute(state)?)
+                    }
+                    SingularQueryBox::FindDeadLetters(q) => {
+                        SingularQueryOutputBox::from(q.execute(state)?)
+                    }
+                    SingularQueryBox::FindDelegations(q) => {
+                        if !is_visible(q.id.domain()) {
+                            return Err(Error::Find(FindError::Account(q.id)));
+                        }
+                        SingularQueryOutputBox::from(q.execute(state)?)
+                    }
+                    SingularQueryBox::FindAllowances(q) => {
+                        if !is_visible(q.id.domain()) {
+                            return Err(Error::Find(FindError::Account(q.id)));
+                        }
+                        SingularQueryOutputBox::from(q.execute(state)?)
+                    }
+                    SingularQueryBox::FindInbox(q) => {
+                        if !is_visible(q.id.domain()) {
+                            return Err(Error::Find(FindError::Account(q.id)));
+                        }
+                        // Only the counterparties can see the messages, even though they are encrypted
+                        let mut messages = q.execute(state)?;
+                        messages.retain(|message| {
+                            message.sender() == authority || message.id().recipient() == authority
+                        });
+                        SingularQueryOutputBox::from(messages)
+                    }
+                    SingularQueryBox::FindEscrows(q) => {
+                        if !is_visible(q.id.domain()) {
+                            return Err(Error::Find(FindError::Account(q.id)));
+                        }
+                        SingularQueryOutputBox::from(q.execute(state)?)
+                    }
                 };
 
                 Ok(QueryResponse::Singular(output))
             }
             QueryRequest::Start(iter_query) => {
+                // shed the load before the query is executed, so that it costs the peer nothing
+                live_query_store.check_load()?;
                 let output = match iter_query.query {
                     // dispatch on a concrete query type, erasing the type with `QueryBatchedErasedIterator` in the end
                     QueryBox::FindDomains(q) => apply_query_postprocessing(
-                        ValidQuery::execute(q.query, q.predicate, state)?,
+                        restrict(
+                            ValidQuery::execute(q.query, q.predicate, state)?,
+                            scope.clone(),
+                        ),
                         q.selector,
                         &iter_query.params,
                     )?,
                     QueryBox::FindAccounts(q) => apply_query_postprocessing(
-                        ValidQuery::execute(q.query, q.predicate, state)?,
+                        restrict(
+                            ValidQuery::execute(q.query, q.predicate, state)?,
+                            scope.clone(),
+                        ),
                         q.selector,
                         &iter_query.params,
                     )?,
                     QueryBox::FindAssets(q) => apply_query_postprocessing(
-                        ValidQuery::execute(q.query, q.predicate, state)?,
+                        restrict(
+                            ValidQuery::execute(q.query, q.predicate, state)?,
+                            scope.clone(),
+                        ),
                         q.selector,
                         &iter_query.params,
                     )?,
                     QueryBox::FindAssetsDefinitions(q) => apply_query_postprocessing(
-                        ValidQuery::execute(q.query, q.predicate, state)?,
+                        restrict(
+                            ValidQuery::execute(q.query, q.predicate, state)?,
+                            scope.clone(),
+                        ),
                         q.selector,
                         &iter_query.params,
                     )?,
                     QueryBox::FindNfts(q) => apply_query_postprocessing(
-                        ValidQuery::execute(q.query, q.predicate, state)?,
+                        restrict(
+                            ValidQuery::execute(q.query, q.predicate, state)?,
+                            scope.clone(),
+                        ),
                         q.selector,
                         &iter_query.params,
                     )?,
                     QueryBox::FindRoles(q) => apply_query_postprocessing(
-                        ValidQuery::execute(q.query, q.predicate, state)?,
+                        restrict(
+                            ValidQuery::execute(q.query, q.predicate, state)?,
+                            scope.clone(),
+                        ),
                         q.selector,
                         &iter_query.params,
                     )?,
                     QueryBox::FindRoleIds(q) => apply_query_postprocessing(
-                        ValidQuery::execute(q.query, q.predicate, state)?,
+                        restrict(
+                            ValidQuery::execute(q.query, q.predicate, state)?,
+                            scope.clone(),
+                        ),
                         q.selector,
                         &iter_query.params,
                     )?,
+                    QueryBox::FindPermissionsByAccountId(q) if !is_visible(q.query.id.domain()) => {
+                        return Err(Error::Find(FindError::Account(q.query.id)));
+                    }
                     QueryBox::FindPermissionsByAccountId(q) => apply_query_postprocessing(
-                        ValidQuery::execute(q.query, q.predicate, state)?,
+                        restrict(
+                            ValidQuery::execute(q.query, q.predicate, state)?,
+                            scope.clone(),
+                        ),
                         q.selector,
                         &iter_query.params,
                     )?,
+                    QueryBox::FindRolesByAccountId(q) if !is_visible(q.query.id.domain()) => {
+                        return Err(Error::Find(FindError::Account(q.query.id)));
+                    }
                     QueryBox::FindRolesByAccountId(q) => apply_query_postprocessing(
-                        ValidQuery::execute(q.query, q.predicate, state)?,
+                        restrict(
+                            ValidQuery::execute(q.query, q.predicate, state)?,
+                            scope.clone(),
+                        ),
                         q.selector,
                         &iter_query.params,
                     )?,
                     QueryBox::FindAccountsWithAsset(q) => apply_query_postprocessing(
-                        ValidQuery::execute(q.query, q.predicate, state)?,
+                        restrict(
+                            ValidQuery::execute(q.query, q.predicate, state)?,
+                            scope.clone(),
+                        ),
+                        q.selector,
+                        &iter_query.params,
+                    )?,
+                    QueryBox::FindAccountsWithRole(q) => apply_query_postprocessing(
+                        restrict(
+                            ValidQuery::execute(q.query, q.predicate, state)?,
+                            scope.clone(),
+                        ),
+                        q.selector,
+                        &iter_query.params,
+                    )?,
+                    QueryBox::FindAccountsWithTag(q) => apply_query_postprocessing(
+                        restrict(
+                            ValidQuery::execute(q.query, q.predicate, state)?,
+                            scope.clone(),
+                        ),
+                        q.selector,
+                        &iter_query.params,
+                    )?,
+                    QueryBox::FindAssetsWithDefinition(q) => apply_query_postprocessing(
+                        restrict(
+                            ValidQuery::execute(q.query, q.predicate, state)?,
+                            scope.clone(),
+                        ),
                         q.selector,
                         &iter_query.params,
                     )?,
                     QueryBox::FindPeers(q) => apply_query_postprocessing(
-                        ValidQuery::execute(q.query, q.predicate, state)?,
+                        restrict(
+                            ValidQuery::execute(q.query, q.predicate, state)?,
+                            scope.clone(),
+                        ),
                         q.selector,
                         &iter_query.params,
                     )?,
                     QueryBox::FindActiveTriggerIds(q) => apply_query_postprocessing(
-                        ValidQuery::execute(q.query, q.predicate, state)?,
+                        restrict(
+                            ValidQuery::execute(q.query, q.predicate, state)?,
+                            scope.clone(),
+                        ),
                         q.selector,
                         &iter_query.params,
                     )?,
                     QueryBox::FindTriggers(q) => apply_query_postprocessing(
-                        ValidQuery::execute(q.query, q.predicate, state)?,
+                        restrict(
+                            ValidQuery::execute(q.query, q.predicate, state)?,
+                            scope.clone(),
+                        ),
                         q.selector,
                         &iter_query.params,
                     )?,
                     QueryBox::FindTransactions(q) => apply_query_postprocessing(
-                        ValidQuery::execute(q.query, q.predicate, state)?,
+                        restrict(
+                            ValidQuery::execute(q.query, q.predicate, state)?,
+                            scope.clone(),
+                        ),
                         q.selector,
                         &iter_query.params,
                     )?,
                     QueryBox::FindBlocks(q) => apply_query_postprocessing(
-                        ValidQuery::execute(q.query, q.predicate, state)?,
+                        restrict(
+                            ValidQuery::execute(q.query, q.predicate, state)?,
+                            scope.clone(),
+                        ),
                         q.selector,
                         &iter_query.params,
                     )?,
                     QueryBox::FindBlockHeaders(q) => apply_query_postprocessing(
-                        ValidQuery::execute(q.query, q.predicate, state)?,
+                        restrict(
+                            ValidQuery::execute(q.query, q.predicate, state)?,
+                            scope.clone(),
+                        ),
+                        q.selector,
+                        &iter_query.params,
+                    )?,
+                    QueryBox::FindAllParameters(q) => apply_query_postprocessing(
+                        restrict(
+                            ValidQuery::execute(q.query, q.predicate, state)?,
+                            scope.clone(),
+                        ),
                         q.selector,
                         &iter_query.params,
                     )?,
@@ -338,9 +682,7 @@ impl ValidQueryRequest {
                     live_query_store.handle_iter_start(output, authority)?,
                 ))
             }
-            QueryRequest::Continue(cursor) => Ok(QueryResponse::Iterable(
-                live_query_store.handle_iter_continue(cursor)?,
-            )),
+            QueryRequest::Continue(cursor) => Ok(live_query_store.handle_iter_continue(cursor)?),
         }
     }
 }
@@ -29,6 +29,7 @@ pub mod isi {
             state_transaction: &mut StateTransaction<'_, '_>,
         ) -> Result<(), Error> {
             let new_trigger = self.object;
+            check_metadata(&new_trigger.action.metadata, state_transaction)?;
 
             if !new_trigger.action.filter.mintable() {
                 match &new_trigger.action.repeats {
@@ -118,6 +119,9 @@ pub mod isi {
 
             let triggers = &mut state_transaction.world.triggers;
             if triggers.remove(trigger_id.clone()) {
+                state_transaction
+                    .world
+                    .remove_trigger_dead_letters(&trigger_id);
                 state_transaction
                     .world
                     .emit_events(Some(TriggerEvent::Deleted(trigger_id)));
@@ -206,6 +210,7 @@ pub mod isi {
             state_transaction: &mut StateTransaction<'_, '_>,
         ) -> Result<(), Error> {
             let trigger_id = self.object;
+            check_metadata_limits(&self.value, state_transaction)?;
 
             state_transaction
                 .world
@@ -304,30 +309,91 @@ pub mod isi {
             // This step information could be buffered in state_transaction, but just drop it for now.
             let _step = state_transaction
                 .execute_called_trigger(id, event)
-                // Workaround until #5147: avoid circular error dependencies.
-                .map_err(|err| {
-                    Error::InvariantViolation({
-                        use std::error::Error as _;
-                        let mut trace = vec![format!("{err}")];
-                        let mut source = err.source();
-                        while let Some(err) = source {
-                            trace.push(format!("caused by: {err}"));
-                            source = err.source();
-                        }
-                        trace.join("; ")
-                    })
-                })?;
+                .map_err(rejection_to_invariant_violation)?;
+
+            Ok(())
+        }
+    }
+
+    impl Execute for RetryDeadLetter {
+        #[metrics(+"retry_dead_letter")]
+        fn execute(
+            self,
+            _authority: &AccountId,
+            state_transaction: &mut StateTransaction<'_, '_>,
+        ) -> Result<(), Error> {
+            let id = self.dead_letter;
+            let event = state_transaction.world.dead_letter(&id)?.event().clone();
+            let trigger_id = id.trigger();
+
+            let (authority, executable) = state_transaction
+                .world
+                .triggers
+                .inspect_by_id(trigger_id, |action| {
+                    (action.authority().clone(), action.executable().clone())
+                })
+                .ok_or_else(|| Error::Find(FindError::Trigger(trigger_id.clone())))?;
+
+            let result =
+                state_transaction.execute_trigger(trigger_id, &authority, &executable, event);
+            // The dead letter is already recorded, don't duplicate it if the retry fails again
+            state_transaction.failed_trigger = None;
+            let _step = result.map_err(rejection_to_invariant_violation)?;
+
+            state_transaction
+                .world
+                .triggers
+                .decrease_repeats([trigger_id].into_iter());
+            state_transaction.world.dead_letters.remove(id);
 
             Ok(())
         }
     }
+
+    impl Execute for DiscardDeadLetter {
+        #[metrics(+"discard_dead_letter")]
+        fn execute(
+            self,
+            _authority: &AccountId,
+            state_transaction: &mut StateTransaction<'_, '_>,
+        ) -> Result<(), Error> {
+            let id = self.dead_letter;
+
+            if state_transaction
+                .world
+                .dead_letters
+                .remove(id.clone())
+                .is_none()
+            {
+                return Err(FindError::DeadLetter(id).into());
+            }
+
+            Ok(())
+        }
+    }
+
+    /// Workaround until #5147: avoid circular error dependencies.
+    fn rejection_to_invariant_violation(err: TransactionRejectionReason) -> Error {
+        Error::InvariantViolation({
+            use std::error::Error as _;
+            let mut trace = vec![format!("{err}")];
+            let mut source = err.source();
+            while let Some(err) = source {
+                trace.push(format!("caused by: {err}"));
+                source = err.source();
+            }
+            trace.join("; ")
+        })
+    }
 }
 
 pub mod query {
     //! Queries associated to triggers.
     use iroha_data_model::{
+        dead_letter::DeadLetter,
         query::{
-            dsl::CompoundPredicate, error::QueryExecutionFail as Error, trigger::FindTriggers,
+            dead_letter::FindDeadLetters, dsl::CompoundPredicate,
+            error::QueryExecutionFail as Error, trigger::FindTriggers,
         },
         trigger::{Trigger, TriggerId},
     };
@@ -335,8 +401,8 @@ pub mod query {
     use super::*;
     use crate::{
         prelude::*,
-        smartcontracts::{triggers::set::SetReadOnly, ValidQuery},
-        state::StateReadOnly,
+        smartcontracts::{triggers::set::SetReadOnly, ValidQuery, ValidSingularQuery},
+        state::{StateReadOnly, WorldReadOnly},
     };
 
     impl ValidQuery for FindActiveTriggerIds {
@@ -378,4 +444,15 @@ pub mod query {
                    .filter(move |trigger| filter.applies(trigger)))
         }
     }
+
+    impl ValidSingularQuery for FindDeadLetters {
+        #[metrics(+"find_dead_letters")]
+        fn execute(&self, state_ro: &impl StateReadOnly) -> Result<Vec<DeadLetter>, Error> {
+            Ok(state_ro
+                .world()
+                .trigger_dead_letters_iter(&self.trigger)
+                .cloned()
+                .collect())
+        }
+    }
 }
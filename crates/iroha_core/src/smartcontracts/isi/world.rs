@@ -18,6 +18,7 @@ pub mod isi {
     use iroha_primitives::{json::Json, unique_vec::PushResult};
 
     use super::*;
+    use crate::state::PendingPeerChange;
 
     impl Execute for Register<Peer> {
         #[metrics(+"register_peer")]
@@ -28,7 +29,32 @@ pub mod isi {
         ) -> Result<(), Error> {
             let peer_id = self.object;
 
+            let delay = peer_activation_delay(state_transaction);
+            let activation_height = state_transaction.curr_block.height().get() + delay;
             let world = &mut state_transaction.world;
+            if delay > 0 {
+                if world.peers.contains(&peer_id)
+                    || world
+                        .pending_peer_changes
+                        .iter()
+                        .any(|change| change.register && change.peer == peer_id)
+                {
+                    return Err(RepetitionError {
+                        instruction: InstructionType::Register,
+                        id: IdBox::PeerId(peer_id),
+                    }
+                    .into());
+                }
+
+                // `PeerEvent::Added` is emitted once the change is activated
+                world.pending_peer_changes.push(PendingPeerChange {
+                    activation_height,
+                    peer: peer_id,
+                    register: true,
+                });
+                return Ok(());
+            }
+
             if let PushResult::Duplicate(duplicate) = world.peers.push(peer_id.clone()) {
                 return Err(RepetitionError {
                     instruction: InstructionType::Register,
@@ -51,11 +77,36 @@ pub mod isi {
             state_transaction: &mut StateTransaction<'_, '_>,
         ) -> Result<(), Error> {
             let peer_id = self.object;
+
+            let delay = peer_activation_delay(state_transaction);
+            let activation_height = state_transaction.curr_block.height().get() + delay;
             let world = &mut state_transaction.world;
             let Some(index) = world.peers.iter().position(|id| id == &peer_id) else {
                 return Err(FindError::Peer(peer_id).into());
             };
 
+            if delay > 0 {
+                if world
+                    .pending_peer_changes
+                    .iter()
+                    .any(|change| !change.register && change.peer == peer_id)
+                {
+                    return Err(RepetitionError {
+                        instruction: InstructionType::Unregister,
+                        id: IdBox::PeerId(peer_id),
+                    }
+                    .into());
+                }
+
+                // `PeerEvent::Removed` is emitted once the change is activated
+                world.pending_peer_changes.push(PendingPeerChange {
+                    activation_height,
+                    peer: peer_id,
+                    register: false,
+                });
+                return Ok(());
+            }
+
             world.peers.remove(index);
 
             world.emit_events(Some(PeerEvent::Removed(peer_id)));
@@ -64,6 +115,19 @@ pub mod isi {
         }
     }
 
+    /// Number of blocks a change of the peer set waits before it is applied to the topology.
+    /// Peers of the genesis block are trusted right away.
+    fn peer_activation_delay(state_transaction: &StateTransaction<'_, '_>) -> u64 {
+        if state_transaction.curr_block.is_genesis() {
+            return 0;
+        }
+        state_transaction
+            .world
+            .parameters
+            .sumeragi
+            .peer_activation_delay_blocks
+    }
+
     impl Execute for Register<Domain> {
         #[metrics("register_domain")]
         fn execute(
@@ -73,6 +137,7 @@ pub mod isi {
         ) -> Result<(), Error> {
             let domain: Domain = self.object.build(authority);
             let domain_id = domain.id().clone();
+            check_metadata(&domain.metadata, state_transaction)?;
 
             if domain_id == *iroha_genesis::GENESIS_DOMAIN_ID {
                 return Err(InstructionExecutionError::InvariantViolation(
@@ -89,6 +154,8 @@ pub mod isi {
                 .into());
             }
 
+            // A re-registered domain starts afresh, without the accounts buried with the old one
+            world.remove_domain_tombstones(&domain_id);
             world.domains.insert(domain_id, domain.clone());
             world.emit_events(Some(DomainEvent::Created(domain)));
 
@@ -100,7 +167,7 @@ pub mod isi {
         #[metrics("unregister_domain")]
         fn execute(
             self,
-            _authority: &AccountId,
+            authority: &AccountId,
             state_transaction: &mut StateTransaction<'_, '_>,
         ) -> Result<(), Error> {
             let domain_id = self.object;
@@ -123,6 +190,7 @@ pub mod isi {
                         .expect("should succeed")
                 });
 
+            let removed_at = state_transaction.curr_block.height();
             let remove_accounts: Vec<AccountId> = state_transaction
                 .world
                 .accounts_in_domain_iter(&domain_id)
@@ -133,8 +201,19 @@ pub mod isi {
                     .world
                     .account_permissions
                     .remove(account.clone());
+                state_transaction
+                    .world
+                    .remove_account_grant_expiries(&account);
+                state_transaction.world.remove_account_delegations(&account);
+                state_transaction.world.remove_account_allowances(&account);
+                state_transaction.world.remove_account_messages(&account);
+                state_transaction.world.remove_account_escrows(&account);
 
                 state_transaction.world.remove_account_roles(&account);
+                state_transaction.world.remove_account_tags(&account);
+                state_transaction
+                    .world
+                    .remove_account_subscriptions(&account);
 
                 let remove_assets: Vec<AssetId> = state_transaction
                     .world
@@ -145,7 +224,14 @@ pub mod isi {
                     state_transaction.world.assets.remove(asset_id);
                 }
 
-                state_transaction.world.accounts.remove(account);
+                state_transaction.world.accounts.remove(account.clone());
+
+                let tombstone =
+                    Tombstone::new(IdBox::AccountId(account), removed_at, authority.clone());
+                state_transaction
+                    .world
+                    .tombstones
+                    .insert(tombstone.id().clone(), tombstone);
             }
 
             let remove_asset_definitions: Vec<AssetDefinitionId> = state_transaction
@@ -154,6 +240,12 @@ pub mod isi {
                 .map(|ad| ad.id().clone())
                 .collect();
             for asset_definition_id in remove_asset_definitions {
+                state_transaction
+                    .world
+                    .remove_asset_definition_allowances(&asset_definition_id);
+                state_transaction
+                    .world
+                    .remove_asset_definition_escrows(&asset_definition_id);
                 state_transaction
                     .world
                     .asset_definitions
@@ -178,6 +270,16 @@ pub mod isi {
                 return Err(FindError::Domain(domain_id).into());
             }
 
+            let tombstone = Tombstone::new(
+                IdBox::DomainId(domain_id.clone()),
+                removed_at,
+                authority.clone(),
+            );
+            state_transaction
+                .world
+                .tombstones
+                .insert(tombstone.id().clone(), tombstone);
+
             state_transaction
                 .world
                 .emit_events(Some(DomainEvent::Deleted(domain_id)));
@@ -186,6 +288,32 @@ pub mod isi {
         }
     }
 
+    impl Execute for Purge<Domain> {
+        #[metrics(+"purge_domain")]
+        fn execute(
+            self,
+            _authority: &AccountId,
+            state_transaction: &mut StateTransaction<'_, '_>,
+        ) -> Result<(), Error> {
+            let domain_id = self.object;
+
+            // Accounts are buried together with their domain, so purge them as well
+            if state_transaction
+                .world
+                .remove_domain_tombstones(&domain_id)
+                .is_none()
+            {
+                return Err(FindError::Tombstone(Box::new(IdBox::DomainId(domain_id))).into());
+            }
+
+            state_transaction
+                .world
+                .emit_events(Some(DomainEvent::Purged(domain_id)));
+
+            Ok(())
+        }
+    }
+
     impl Execute for Register<Role> {
         #[metrics(+"register_role")]
         fn execute(
@@ -194,6 +322,9 @@ pub mod isi {
             state_transaction: &mut StateTransaction<'_, '_>,
         ) -> Result<(), Error> {
             let role = self.object.build(authority);
+            for permission in role.permissions() {
+                check_metadata_limits(permission.payload(), state_transaction)?;
+            }
 
             if state_transaction.world.roles.get(role.id()).is_some() {
                 return Err(RepetitionError {
@@ -203,6 +334,21 @@ pub mod isi {
                 .into());
             }
 
+            for extended in role.extends() {
+                state_transaction.world.role(extended)?;
+                if extended == role.id()
+                    || state_transaction
+                        .world
+                        .inherited_roles(extended)
+                        .contains(role.id())
+                {
+                    return Err(InstructionExecutionError::InvariantViolation(format!(
+                        "Role `{}` can't extend itself through `{extended}`",
+                        role.id()
+                    )));
+                }
+            }
+
             let world = &mut state_transaction.world;
             let role_id = role.id().clone();
             world.roles.insert(role_id, role.clone());
@@ -224,11 +370,7 @@ pub mod isi {
 
             let accounts_with_role = state_transaction
                 .world
-                .account_roles
-                .iter()
-                .map(|(role, ())| role)
-                .filter(|role| role.id.eq(&role_id))
-                .map(|role| &role.account)
+                .role_holders_iter(&role_id)
                 .cloned()
                 .collect::<Vec<_>>();
 
@@ -244,6 +386,8 @@ pub mod isi {
             if world.roles.remove(role_id.clone()).is_none() {
                 return Err(FindError::Role(role_id).into());
             }
+            // Otherwise a role registered with the same id would be extended unawares
+            world.remove_extended_role(&role_id);
 
             world.emit_events(Some(RoleEvent::Deleted(role_id)));
 
@@ -261,6 +405,11 @@ pub mod isi {
             let role_id = self.destination;
             let permission = self.object;
 
+            if self.valid_until_ms.is_some() {
+                return Err(InstructionExecutionError::InvariantViolation(
+                    "Only grants to accounts can expire".to_owned(),
+                ));
+            }
             let Some(role) = state_transaction.world.roles.get_mut(&role_id) else {
                 return Err(FindError::Role(role_id).into());
             };
@@ -340,6 +489,42 @@ pub mod isi {
                                 }))
                             );
                         })*
+                        Parameter::FuelSchedule(next) => {
+                            let prev = state_transaction
+                                .world
+                                .parameters
+                                .fuel_schedules
+                                .last()
+                                .copied()
+                                .unwrap_or_default();
+
+                            if next.version <= prev.version {
+                                return Err(InstructionExecutionError::InvariantViolation(
+                                    format!("Fuel schedule version must be greater than {}", prev.version),
+                                ));
+                            }
+                            // The current block is already executed with the previous schedule
+                            if next.activation_height <= state_transaction.curr_block.height().get()
+                                && !state_transaction.curr_block.is_genesis()
+                            {
+                                return Err(InstructionExecutionError::InvariantViolation(
+                                    "Fuel schedule must be activated at a later block".to_owned(),
+                                ));
+                            }
+                            if next.activation_height < prev.activation_height {
+                                return Err(InstructionExecutionError::InvariantViolation(
+                                    format!("Fuel schedule can't be activated before the height {}", prev.activation_height),
+                                ));
+                            }
+
+                            state_transaction.world.parameters.add_fuel_schedule(next);
+                            state_transaction
+                                .world
+                                .emit_events(Some(ConfigurationEvent::Changed(ParameterChanged {
+                                    old_value: Parameter::FuelSchedule(prev),
+                                    new_value: Parameter::FuelSchedule(next),
+                                })));
+                        }
                         Parameter::Custom(next) => {
                             let prev = state_transaction
                                 .world
@@ -373,11 +558,15 @@ pub mod isi {
                 Sumeragi(sumeragi.max_clock_drift_ms) => SumeragiParameter::MaxClockDriftMs,
                 Sumeragi(sumeragi.block_time_ms) => SumeragiParameter::BlockTimeMs,
                 Sumeragi(sumeragi.commit_time_ms) => SumeragiParameter::CommitTimeMs,
+                Sumeragi(sumeragi.peer_activation_delay_blocks) => SumeragiParameter::PeerActivationDelayBlocks,
 
                 Block(block.max_transactions) => BlockParameter::MaxTransactions,
+                Block(block.max_heavy_instructions) => BlockParameter::MaxHeavyInstructions,
 
                 Transaction(transaction.max_instructions) => TransactionParameter::MaxInstructions,
                 Transaction(transaction.smart_contract_size) => TransactionParameter::SmartContractSize,
+                Transaction(transaction.max_metadata_depth) => TransactionParameter::MaxMetadataDepth,
+                Transaction(transaction.max_metadata_size) => TransactionParameter::MaxMetadataSize,
 
                 SmartContract(smart_contract.fuel) => SmartContractParameter::Fuel,
                 SmartContract(smart_contract.memory) => SmartContractParameter::Memory,
@@ -450,7 +639,7 @@ pub mod isi {
 pub mod query {
     use eyre::Result;
     use iroha_data_model::{
-        parameter::Parameters,
+        parameter::{Parameter, Parameters},
         prelude::*,
         query::{dsl::CompoundPredicate, error::QueryExecutionFail as Error},
         role::Role,
@@ -523,4 +712,35 @@ pub mod query {
             Ok(state_ro.world().parameters().clone())
         }
     }
+
+    impl ValidSingularQuery for FindFuelSchedule {
+        #[metrics(+"find_fuel_schedule")]
+        fn execute(&self, state_ro: &impl StateReadOnly) -> Result<FuelSchedule, Error> {
+            // Schedule the next block is executed with
+            let height = state_ro.height() as u64 + 1;
+            Ok(state_ro.world().parameters().fuel_schedule(height))
+        }
+    }
+
+    impl ValidQuery for FindAllParameters {
+        #[metrics(+"find_all_parameters")]
+        fn execute(
+            self,
+            filter: CompoundPredicate<Parameter>,
+            state_ro: &impl StateReadOnly,
+        ) -> Result<impl Iterator<Item = Self::Item>, Error> {
+            Ok(state_ro
+                .world()
+                .parameters()
+                .parameters()
+                .filter(move |parameter| filter.applies(parameter)))
+        }
+    }
+
+    impl ValidSingularQuery for FindTombstone {
+        #[metrics(+"find_tombstone")]
+        fn execute(&self, state_ro: &impl StateReadOnly) -> Result<Tombstone, Error> {
+            Ok(state_ro.world().tombstone(&self.id)?.clone())
+        }
+    }
 }
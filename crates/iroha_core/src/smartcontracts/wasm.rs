@@ -59,6 +59,19 @@ mod export {
 
     pub const DBG: &str = "dbg";
     pub const LOG: &str = "log";
+
+    /// Every function the host provides to WASM.
+    /// Modules importing anything else (e.g. clock, randomness or environment) are rejected.
+    pub const ALL: &[&str] = &[
+        EXECUTE_ISI,
+        EXECUTE_QUERY,
+        SET_DATA_MODEL,
+        CONSUME_FUEL,
+        ADD_FUEL,
+        GET_FUEL,
+        DBG,
+        LOG,
+    ];
 }
 
 mod import {
@@ -116,6 +129,13 @@ pub mod error {
         Initialization(#[source] WasmtimeError),
         /// Failed to load module
         ModuleLoading(#[source] WasmtimeError),
+        /// Module imports `{module}::{name}` which isn't provided by the host
+        ForbiddenImport {
+            /// Module of the import
+            module: String,
+            /// Name of the import
+            name: String,
+        },
         /// Module could not be instantiated
         Instantiation(#[from] InstantiationError),
         /// Export error
@@ -232,12 +252,27 @@ pub type Result<T, E = Error> = core::result::Result<T, E>;
 
 /// Create [`Module`] from bytes.
 ///
+/// Execution has to be deterministic on every peer, so the module
+/// may only import the functions provided by the host in the `iroha` module.
+///
 /// # Errors
 ///
-/// See [`Module::new`]
+/// - See [`Module::new`]
+/// - [`Error::ForbiddenImport`] if the module imports anything else
 // TODO: Probably we can do some checks here such as searching for entrypoint function
 pub fn load_module(engine: &Engine, bytes: impl AsRef<[u8]>) -> Result<wasmtime::Module> {
-    Module::new(engine, bytes).map_err(Error::ModuleLoading)
+    let module = Module::new(engine, bytes).map_err(Error::ModuleLoading)?;
+    if let Some(import) = module.imports().find(|import| {
+        import.module() != WASM_MODULE
+            || !matches!(import.ty(), wasmtime::ExternType::Func(_))
+            || !export::ALL.contains(&import.name())
+    }) {
+        return Err(Error::ForbiddenImport {
+            module: import.module().to_owned(),
+            name: import.name().to_owned(),
+        });
+    }
+    Ok(module)
 }
 
 /// Create [`Engine`] with a predefined configuration.
@@ -256,6 +291,9 @@ fn create_config() -> Result<WasmtimeConfig> {
     let mut config = WasmtimeConfig::new();
     config
         .consume_fuel(true)
+        // NaN bit patterns and relaxed SIMD results otherwise depend on the host CPU
+        .cranelift_nan_canonicalization(true)
+        .relaxed_simd_deterministic(true)
         .cache_config_load_default()
         .map_err(Error::Initialization)?;
     #[cfg(feature = "profiling")]
@@ -294,13 +332,19 @@ impl LimitsExecutor {
     }
 
     /// Checks if number of instructions in wasm smartcontract exceeds maximum
+    /// once the `instruction` is executed
     ///
     /// # Errors
     ///
     /// If number of instructions exceeds maximum
     #[inline]
-    pub fn check_instruction_limits(&mut self) -> Result<(), ValidationFail> {
-        self.instruction_count += 1;
+    pub fn check_instruction_limits(
+        &mut self,
+        instruction: &InstructionBox,
+    ) -> Result<(), ValidationFail> {
+        self.instruction_count = self
+            .instruction_count
+            .saturating_add(instruction.instruction_count() as u64);
 
         if self.instruction_count > self.max_instruction_count.get() {
             return Err(ValidationFail::TooComplex);
@@ -348,6 +392,8 @@ pub mod state {
         /// Span inside of which all logs are recorded for this smart contract
         pub(super) log_span: Span,
         pub(super) executed_queries: IndexSet<QueryId>,
+        /// Fuel charged for the host calls, on top of the fuel of the wasm instructions
+        pub(super) fuel_schedule: FuelSchedule,
         /// State kind
         pub(super) state: W,
         /// Concrete state for specific executable
@@ -368,11 +414,19 @@ pub mod state {
                 store_limits: store_limits_from_config(&config),
                 log_span,
                 executed_queries: IndexSet::new(),
+                fuel_schedule: FuelSchedule::default(),
                 state,
                 specific_state,
             }
         }
 
+        /// Charge the host calls according to the given [`FuelSchedule`]
+        #[must_use]
+        pub fn with_fuel_schedule(mut self, fuel_schedule: FuelSchedule) -> Self {
+            self.fuel_schedule = fuel_schedule;
+            self
+        }
+
         /// Get authority
         pub fn authority(&self) -> &AccountId {
             &self.authority
@@ -765,6 +819,24 @@ impl<W, S> Runtime<state::CommonState<W, S>> {
 
         store
     }
+
+    /// Charge the fuel of a host call according to the [`FuelSchedule`] of the executable.
+    ///
+    /// # Errors
+    ///
+    /// [`wasmtime::Trap::OutOfFuel`] if the remaining fuel doesn't cover the charge
+    fn charge_fuel(
+        caller: &mut Caller<state::CommonState<W, S>>,
+        fuel: impl FnOnce(&FuelSchedule) -> u64,
+    ) -> wasmtime::Result<()> {
+        let fuel = fuel(&caller.data().fuel_schedule);
+        let current = caller.get_fuel()?;
+        let Some(remaining) = current.checked_sub(fuel) else {
+            return Err(wasmtime::Trap::OutOfFuel.into());
+        };
+
+        caller.set_fuel(remaining)
+    }
 }
 
 impl<W, S> Runtime<Option<CommonState<W, S>>> {
@@ -975,17 +1047,42 @@ impl<'wrld, 'block: 'wrld, 'state: 'block> Runtime<state::SmartContract<'wrld, '
         bytes: impl AsRef<[u8]>,
     ) -> Result<()> {
         let span = wasm_log_span!("Smart contract execution", %authority);
+        let fuel_schedule = Self::fuel_schedule(state_transaction);
         let state = state::SmartContract::new(
             authority,
             self.config,
             span,
             state::chain_state::WithMut(state_transaction),
             state::specific::SmartContract::new(None),
-        );
+        )
+        .with_fuel_schedule(fuel_schedule);
 
         self.execute_smart_contract_with_state(bytes, state)
     }
 
+    /// Executes the given wasm smartcontract like [`Self::execute`],
+    /// reporting the amount of fuel it consumed along with the outcome,
+    /// which is known even if the execution failed (e.g. because the fuel ran out).
+    pub fn execute_metered(
+        &mut self,
+        state_transaction: &'wrld mut StateTransaction<'block, 'state>,
+        authority: AccountId,
+        bytes: impl AsRef<[u8]>,
+    ) -> (Result<()>, u64) {
+        let span = wasm_log_span!("Smart contract metered execution", %authority);
+        let fuel_schedule = Self::fuel_schedule(state_transaction);
+        let state = state::SmartContract::new(
+            authority,
+            self.config,
+            span,
+            state::chain_state::WithMut(state_transaction),
+            state::specific::SmartContract::new(None),
+        )
+        .with_fuel_schedule(fuel_schedule);
+
+        self.execute_smart_contract_metered(bytes, state)
+    }
+
     /// Validates that the given smartcontract is eligible for execution
     ///
     /// # Errors
@@ -1001,13 +1098,15 @@ impl<'wrld, 'block: 'wrld, 'state: 'block> Runtime<state::SmartContract<'wrld, '
         max_instruction_count: NonZeroU64,
     ) -> Result<()> {
         let span = wasm_log_span!("Smart contract validation", %authority);
+        let fuel_schedule = Self::fuel_schedule(state_transaction);
         let state = state::SmartContract::new(
             authority,
             self.config,
             span,
             state::chain_state::WithMut(state_transaction),
             state::specific::SmartContract::new(Some(LimitsExecutor::new(max_instruction_count))),
-        );
+        )
+        .with_fuel_schedule(fuel_schedule);
 
         self.execute_smart_contract_with_state(bytes, state)
     }
@@ -1017,20 +1116,44 @@ impl<'wrld, 'block: 'wrld, 'state: 'block> Runtime<state::SmartContract<'wrld, '
         bytes: impl AsRef<[u8]>,
         state: state::SmartContract<'wrld, 'block, 'state>,
     ) -> Result<()> {
+        self.execute_smart_contract_metered(bytes, state).0
+    }
+
+    fn execute_smart_contract_metered(
+        &mut self,
+        bytes: impl AsRef<[u8]>,
+        state: state::SmartContract<'wrld, 'block, 'state>,
+    ) -> (Result<()>, u64) {
         let mut store = self.create_store(state);
-        let smart_contract = self.create_smart_contract(&mut store, bytes)?;
+        let result = self.call_smart_contract_main(&mut store, bytes);
+        let fuel_consumed = self
+            .config
+            .fuel
+            .get()
+            .saturating_sub(store.get_fuel().unwrap_or_default());
+
+        let mut state = store.into_data();
+        let executed_queries = state.take_executed_queries();
+        forget_all_executed_queries(state.state.0.query_handle, executed_queries);
+
+        (result, fuel_consumed)
+    }
+
+    fn call_smart_contract_main(
+        &self,
+        store: &mut Store<state::SmartContract<'wrld, 'block, 'state>>,
+        bytes: impl AsRef<[u8]>,
+    ) -> Result<()> {
+        let smart_contract = self.create_smart_contract(store, bytes)?;
 
         let main_fn: TypedFunc<_, ()> =
-            Self::get_typed_func(&smart_contract, &mut store, import::SMART_CONTRACT_MAIN)?;
-        let context = Self::get_smart_contract_context(&smart_contract, &mut store);
+            Self::get_typed_func(&smart_contract, &mut *store, import::SMART_CONTRACT_MAIN)?;
+        let context = Self::get_smart_contract_context(&smart_contract, store);
 
         // NOTE: This function takes ownership of the pointer
         main_fn
-            .call(&mut store, context)
+            .call(&mut *store, context)
             .map_err(ExportFnCallError::from)?;
-        let mut state = store.into_data();
-        let executed_queries = state.take_executed_queries();
-        forget_all_executed_queries(state.state.0.query_handle, executed_queries);
 
         Ok(())
     }
@@ -1066,7 +1189,7 @@ impl<'wrld, 'block, 'state>
         state: &mut state::SmartContract<'wrld, 'block, 'state>,
     ) -> Result<(), ValidationFail> {
         if let Some(limits_executor) = state.specific_state.limits_executor.as_mut() {
-            limits_executor.check_instruction_limits()?;
+            limits_executor.check_instruction_limits(&instruction)?;
         }
 
         Self::default_execute_instruction(instruction, state)
@@ -1089,13 +1212,15 @@ impl<'wrld, 'block: 'wrld, 'state: 'block> Runtime<state::Trigger<'wrld, 'block,
         event: EventBox,
     ) -> Result<ExecutionStep> {
         let span = wasm_log_span!("Trigger execution", %id, %authority);
+        let fuel_schedule = Self::fuel_schedule(state_transaction);
         let state = state::Trigger::new(
             authority,
             self.config,
             span,
             state::chain_state::WithMut(state_transaction),
             state::specific::Trigger::new(id.clone(), event),
-        );
+        )
+        .with_fuel_schedule(fuel_schedule);
 
         let mut store = self.create_store(state);
         let instance = self.instantiate_module(module, &mut store)?;
@@ -1254,6 +1379,14 @@ impl<S> Runtime<S> {
     fn get_fuel(caller: ::wasmtime::Caller<S>) -> ::wasmtime::Result<u64> {
         caller.get_fuel()
     }
+
+    /// [`FuelSchedule`] active at the height of the block being executed
+    fn fuel_schedule(state_transaction: &StateTransaction<'_, '_>) -> FuelSchedule {
+        state_transaction
+            .world
+            .parameters()
+            .fuel_schedule(state_transaction.curr_block.height().get())
+    }
 }
 
 /// Marker trait to auto-implement [`import_traits::SetExecutorDataModel`] for a concrete [`Runtime`].
@@ -1595,8 +1728,14 @@ impl<'wrld, 'block, 'state> RuntimeBuilder<state::SmartContract<'wrld, 'block, '
             let mut linker = Linker::new(engine);
 
             create_imports!(linker, state::SmartContract<'wrld, 'block, 'state>,
-                export::EXECUTE_ISI => |caller: ::wasmtime::Caller<state::SmartContract<'wrld, 'block, 'state>>, offset, len| Runtime::execute_instruction(caller, offset, len),
-                export::EXECUTE_QUERY => |caller: ::wasmtime::Caller<state::SmartContract<'wrld, 'block, 'state>>, offset, len| Runtime::execute_query(caller, offset, len),
+                export::EXECUTE_ISI => |mut caller: ::wasmtime::Caller<state::SmartContract<'wrld, 'block, 'state>>, offset, len| {
+                    Runtime::charge_fuel(&mut caller, |schedule| schedule.instruction)?;
+                    Runtime::execute_instruction(caller, offset, len)
+                },
+                export::EXECUTE_QUERY => |mut caller: ::wasmtime::Caller<state::SmartContract<'wrld, 'block, 'state>>, offset, len| {
+                    Runtime::charge_fuel(&mut caller, |schedule| schedule.query)?;
+                    Runtime::execute_query(caller, offset, len)
+                },
             )?;
             Ok(linker)
         })
@@ -1614,8 +1753,14 @@ impl<'wrld, 'block, 'state> RuntimeBuilder<state::Trigger<'wrld, 'block, 'state>
             let mut linker = Linker::new(engine);
 
             create_imports!(linker, state::Trigger<'wrld, 'block, 'state>,
-                export::EXECUTE_ISI => |caller: ::wasmtime::Caller<state::Trigger<'wrld, 'block, 'state>>, offset, len| Runtime::execute_instruction(caller, offset, len),
-                export::EXECUTE_QUERY => |caller: ::wasmtime::Caller<state::Trigger<'wrld, 'block, 'state>>, offset, len| Runtime::execute_query(caller, offset, len),
+                export::EXECUTE_ISI => |mut caller: ::wasmtime::Caller<state::Trigger<'wrld, 'block, 'state>>, offset, len| {
+                    Runtime::charge_fuel(&mut caller, |schedule| schedule.instruction)?;
+                    Runtime::execute_instruction(caller, offset, len)
+                },
+                export::EXECUTE_QUERY => |mut caller: ::wasmtime::Caller<state::Trigger<'wrld, 'block, 'state>>, offset, len| {
+                    Runtime::charge_fuel(&mut caller, |schedule| schedule.query)?;
+                    Runtime::execute_query(caller, offset, len)
+                },
             )?;
             Ok(linker)
         })
@@ -1746,7 +1891,13 @@ mod tests {
     use tokio::test;
 
     use super::*;
-    use crate::{block::ValidBlock, kura::Kura, query::store::LiveQueryStore, state::State, World};
+    use crate::{
+        block::ValidBlock,
+        kura::Kura,
+        query::store::LiveQueryStore,
+        state::{State, StateBlock},
+        World,
+    };
 
     fn world_with_test_account(authority: &AccountId) -> World {
         let domain_id = authority.domain.clone();
@@ -1849,6 +2000,98 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    async fn instructions_are_charged_by_fuel_schedule() -> Result<(), Error> {
+        let (authority, _authority_keypair) = gen_account_in("wonderland");
+        let kura = Kura::blank_kura_for_testing();
+        let query_handle = LiveQueryStore::start_test();
+        let state = State::new(world_with_test_account(&authority), kura, query_handle);
+
+        let isi_hex = {
+            let (new_authority, _new_authority_keypair) = gen_account_in("wonderland");
+            let register_isi = Register::account(Account::new(new_authority));
+            encode_hex(InstructionBox::from(register_isi))
+        };
+
+        let wat = format!(
+            r#"
+            (module
+                ;; Import host function to execute
+                (import "iroha" "{execute_fn_name}"
+                    (func $exec_fn (param i32 i32) (result i32)))
+
+                {memory_and_alloc}
+
+                ;; Function which starts the smartcontract execution
+                (func (export "{main_fn_name}") (param i32)
+                    (call $exec_fn (i32.const 0) (i32.const {isi_len}))
+
+                    ;; No use of return values
+                    drop))
+            "#,
+            main_fn_name = import::SMART_CONTRACT_MAIN,
+            execute_fn_name = export::EXECUTE_ISI,
+            memory_and_alloc = memory_and_alloc(&isi_hex),
+            isi_len = isi_hex.len() / 3,
+        );
+        let block_header = ValidBlock::new_dummy(&KeyPair::random().into_parts().1)
+            .as_ref()
+            .header();
+        let mut state_block = state.block(block_header);
+
+        let execute_metered = |state_block: &mut StateBlock<'_>| {
+            let mut runtime = RuntimeBuilder::<state::SmartContract>::new().build()?;
+            let mut state_transaction = state_block.transaction();
+            let (result, consumed) =
+                runtime.execute_metered(&mut state_transaction, authority.clone(), &wat);
+            result.expect("Execution failed");
+            Ok::<_, Error>(consumed)
+        };
+
+        let unscheduled = execute_metered(&mut state_block)?;
+        state_block
+            .world
+            .parameters
+            .add_fuel_schedule(FuelSchedule {
+                version: 1,
+                activation_height: block_header.height().get(),
+                instruction: 1_000,
+                query: 0,
+            });
+        let scheduled = execute_metered(&mut state_block)?;
+        assert_eq!(scheduled, unscheduled + 1_000);
+
+        Ok(())
+    }
+
+    #[test]
+    async fn host_capabilities_not_provided_are_rejected() {
+        let engine = create_engine();
+        for (module, name) in [
+            ("iroha", "execute_instruction"),
+            ("iroha", "now"),
+            ("env", "random"),
+        ] {
+            let wat = format!(
+                r#"
+                (module
+                    (import "{module}" "{name}" (func (param i32 i32) (result i32)))
+                    (func (export "{main_fn_name}") (param i32)))
+                "#,
+                main_fn_name = import::SMART_CONTRACT_MAIN,
+            );
+            let result = load_module(&engine, wat);
+            if name == export::EXECUTE_ISI {
+                result.expect("host function should be importable");
+            } else {
+                assert!(matches!(
+                    result,
+                    Err(Error::ForbiddenImport { module: m, name: n }) if m == module && n == name
+                ));
+            }
+        }
+    }
+
     #[test]
     async fn execute_query_exported() -> Result<(), Error> {
         let (authority, _authority_keypair) = gen_account_in("wonderland");
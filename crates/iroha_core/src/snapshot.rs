@@ -23,9 +23,9 @@ use crate::{
 };
 
 /// Name of the [`State`] snapshot file.
-const SNAPSHOT_FILE_NAME: &str = "snapshot.data";
+pub(crate) const SNAPSHOT_FILE_NAME: &str = "snapshot.data";
 /// Name of the temporary [`State`] snapshot file.
-const SNAPSHOT_TMP_FILE_NAME: &str = "snapshot.tmp";
+pub(crate) const SNAPSHOT_TMP_FILE_NAME: &str = "snapshot.tmp";
 
 // /// Errors produced by [`SnapshotMaker`] actor.
 // pub type Result<T, E = Error> = core::result::Result<T, E>;
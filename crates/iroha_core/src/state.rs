@@ -1,14 +1,20 @@
 //! This module provides the [`State`] — an in-memory representation of the current blockchain state.
 use std::{
-    collections::BTreeSet, marker::PhantomData, num::NonZeroUsize, sync::Arc, time::Duration,
+    collections::{BTreeMap, BTreeSet},
+    marker::PhantomData,
+    num::{NonZeroU64, NonZeroUsize},
+    sync::Arc,
+    time::Duration,
 };
 
 use eyre::Result;
+use iroha_config::parameters::actual::Retention;
 use iroha_crypto::HashOf;
 use iroha_data_model::{
     account::{AccountEntry, AccountValue},
     asset::{AssetEntry, AssetValue},
     block::{BlockHeader, SignedBlock},
+    dead_letter::MAX_DEAD_LETTERS_PER_TRIGGER,
     events::{
         pipeline::BlockEvent,
         time::TimeEvent,
@@ -26,7 +32,7 @@ use iroha_data_model::{
     IntoKeyValue,
 };
 use iroha_logger::prelude::*;
-use iroha_primitives::{const_vec::ConstVec, numeric::Numeric};
+use iroha_primitives::{const_vec::ConstVec, numeric::Numeric, unique_vec::PushResult};
 use mv::{
     cell::{Block as CellBlock, Cell, Transaction as CellTransaction, View as CellView},
     storage::{
@@ -47,9 +53,11 @@ use crate::telemetry::StateTelemetry;
 use crate::{
     block::CommittedBlock,
     executor::Executor,
+    grant::{AccountGrant, Granted},
+    history::AssetChange,
     kura::Kura,
     query::store::LiveQueryStoreHandle,
-    role::RoleIdWithOwner,
+    role::{RoleHolder, RoleIdWithOwner},
     smartcontracts::{
         triggers::{
             set::{
@@ -62,11 +70,25 @@ use crate::{
         wasm,
     },
     state::storage_transactions::{TransactionsBlock, TransactionsStorage, TransactionsView},
+    tag::TaggedAccount,
+    tiering::{ColdStore, ColdStoreError, Tiered},
     Peers,
 };
 
 pub(crate) mod storage_transactions;
 
+/// Change of the peer set scheduled by [`Register<Peer>`] or [`Unregister<Peer>`]
+/// and applied to the topology once the chain reaches its activation height.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, serde::Deserialize)]
+pub struct PendingPeerChange {
+    /// Height of the block at the start of which the change takes effect
+    pub activation_height: u64,
+    /// Peer added to or removed from the peer set
+    pub peer: PeerId,
+    /// Whether the peer is added (`true`) or removed (`false`)
+    pub register: bool,
+}
+
 /// The global entity consisting of `domains`, `triggers` and etc.
 /// For example registration of domain, will have this as an ISI target.
 #[derive(Default, Serialize)]
@@ -75,10 +97,12 @@ pub struct World {
     pub(crate) parameters: Cell<Parameters>,
     /// Identifications of discovered peers.
     pub(crate) peers: Cell<Peers>,
+    /// Peer set changes waiting for their safety delay to pass.
+    pub(crate) pending_peer_changes: Cell<Vec<PendingPeerChange>>,
     /// Registered domains.
     pub(crate) domains: Storage<DomainId, Domain>,
     /// Registered accounts.
-    pub(crate) accounts: Storage<AccountId, AccountValue>,
+    pub(crate) accounts: Storage<AccountId, Tiered<AccountId, AccountValue>>,
     /// Registered asset definitions.
     pub(crate) asset_definitions: Storage<AssetDefinitionId, AssetDefinition>,
     /// Registered assets.
@@ -91,6 +115,39 @@ pub struct World {
     pub(crate) account_permissions: Storage<AccountId, Permissions>,
     /// Roles of an account.
     pub(crate) account_roles: Storage<RoleIdWithOwner, ()>,
+    /// Holders of a role, i.e. `account_roles` keyed by the role.
+    pub(crate) role_holders: Storage<RoleHolder, ()>,
+    /// Accounts labeled with a tag, keyed by the tag.
+    pub(crate) tagged_accounts: Storage<TaggedAccount, ()>,
+    /// Accounts ordered by the height of the block which last changed them, to find the dormant ones.
+    #[serde(skip)]
+    pub(crate) accounts_by_change: Storage<(u64, AccountId), ()>,
+    /// Tombstones of unregistered accounts and domains.
+    pub(crate) tombstones: Storage<IdBox, Tombstone>,
+    /// Quantities of assets at the end of the blocks which changed them.
+    pub(crate) asset_history: Storage<AssetChange, Numeric>,
+    /// Height of the oldest change kept in the history of an asset, once the older ones are dropped by the retention.
+    pub(crate) asset_history_horizons: Storage<AssetId, NonZeroU64>,
+    /// Notification subscriptions of accounts.
+    pub(crate) subscriptions: Storage<SubscriptionId, Subscription>,
+    /// Failed trigger executions awaiting a retry or a discard by the trigger owner.
+    pub(crate) dead_letters: Storage<DeadLetterId, DeadLetter>,
+    /// Permissions delegated by an account, keyed by the delegator.
+    pub(crate) delegations: Storage<AccountId, BTreeSet<Delegation>>,
+    /// Permissions delegated to an account, i.e. `delegations` keyed by the delegate.
+    pub(crate) incoming_delegations: Storage<AccountId, BTreeSet<Delegation>>,
+    /// Allowances to spend the assets of an account, keyed by the owner.
+    pub(crate) allowances: Storage<AccountId, Vec<Allowance>>,
+    /// Encrypted messages awaiting in the inboxes of accounts until they expire.
+    pub(crate) messages: Storage<DirectMessageId, DirectMessage>,
+    /// Identifiers of the messages, keyed by the height of the block at the end of which they expire.
+    pub(crate) message_expiries: Storage<NonZeroU64, Vec<DirectMessageId>>,
+    /// Assets locked in escrows until they are claimed or refunded.
+    pub(crate) escrows: Storage<EscrowId, Escrow>,
+    /// Time (since the Unix epoch, in milliseconds) the expiring grants to accounts are revoked at.
+    pub(crate) grant_expiries: Storage<AccountGrant, u64>,
+    /// The expiring grants ordered by the time they're revoked at.
+    pub(crate) grants_by_expiry: Storage<(u64, AccountGrant), ()>,
     /// Triggers
     pub(crate) triggers: TriggerSet,
     /// Runtime Executor
@@ -108,10 +165,12 @@ pub struct WorldBlock<'world> {
     pub parameters: CellBlock<'world, Parameters>,
     /// Identifications of discovered peers.
     pub(crate) peers: CellBlock<'world, Peers>,
+    /// Peer set changes waiting for their safety delay to pass.
+    pub(crate) pending_peer_changes: CellBlock<'world, Vec<PendingPeerChange>>,
     /// Registered domains.
     pub(crate) domains: StorageBlock<'world, DomainId, Domain>,
     /// Registered accounts.
-    pub(crate) accounts: StorageBlock<'world, AccountId, AccountValue>,
+    pub(crate) accounts: StorageBlock<'world, AccountId, Tiered<AccountId, AccountValue>>,
     /// Registered asset definitions.
     pub(crate) asset_definitions: StorageBlock<'world, AssetDefinitionId, AssetDefinition>,
     /// Registered assets.
@@ -124,6 +183,38 @@ pub struct WorldBlock<'world> {
     pub(crate) account_permissions: StorageBlock<'world, AccountId, Permissions>,
     /// Roles of an account.
     pub(crate) account_roles: StorageBlock<'world, RoleIdWithOwner, ()>,
+    /// Holders of a role, i.e. `account_roles` keyed by the role.
+    pub(crate) role_holders: StorageBlock<'world, RoleHolder, ()>,
+    /// Accounts labeled with a tag, keyed by the tag.
+    pub(crate) tagged_accounts: StorageBlock<'world, TaggedAccount, ()>,
+    /// Accounts ordered by the height of the block which last changed them, to find the dormant ones.
+    pub(crate) accounts_by_change: StorageBlock<'world, (u64, AccountId), ()>,
+    /// Tombstones of unregistered accounts and domains.
+    pub(crate) tombstones: StorageBlock<'world, IdBox, Tombstone>,
+    /// Quantities of assets at the end of the blocks which changed them.
+    pub(crate) asset_history: StorageBlock<'world, AssetChange, Numeric>,
+    /// Height of the oldest change kept in the history of an asset, once the older ones are dropped by the retention.
+    pub(crate) asset_history_horizons: StorageBlock<'world, AssetId, NonZeroU64>,
+    /// Notification subscriptions of accounts.
+    pub(crate) subscriptions: StorageBlock<'world, SubscriptionId, Subscription>,
+    /// Failed trigger executions awaiting a retry or a discard by the trigger owner.
+    pub(crate) dead_letters: StorageBlock<'world, DeadLetterId, DeadLetter>,
+    /// Permissions delegated by an account, keyed by the delegator.
+    pub(crate) delegations: StorageBlock<'world, AccountId, BTreeSet<Delegation>>,
+    /// Permissions delegated to an account, i.e. `delegations` keyed by the delegate.
+    pub(crate) incoming_delegations: StorageBlock<'world, AccountId, BTreeSet<Delegation>>,
+    /// Allowances to spend the assets of an account, keyed by the owner.
+    pub(crate) allowances: StorageBlock<'world, AccountId, Vec<Allowance>>,
+    /// Encrypted messages awaiting in the inboxes of accounts until they expire.
+    pub(crate) messages: StorageBlock<'world, DirectMessageId, DirectMessage>,
+    /// Identifiers of the messages, keyed by the height of the block at the end of which they expire.
+    pub(crate) message_expiries: StorageBlock<'world, NonZeroU64, Vec<DirectMessageId>>,
+    /// Assets locked in escrows until they are claimed or refunded.
+    pub(crate) escrows: StorageBlock<'world, EscrowId, Escrow>,
+    /// Time (since the Unix epoch, in milliseconds) the expiring grants to accounts are revoked at.
+    pub(crate) grant_expiries: StorageBlock<'world, AccountGrant, u64>,
+    /// The expiring grants ordered by the time they're revoked at.
+    pub(crate) grants_by_expiry: StorageBlock<'world, (u64, AccountGrant), ()>,
     /// Triggers
     pub(crate) triggers: TriggerSetBlock<'world>,
     /// Runtime Executor
@@ -131,7 +222,7 @@ pub struct WorldBlock<'world> {
     /// Executor-defined data model
     pub(crate) executor_data_model: CellBlock<'world, ExecutorDataModel>,
     /// Buffer of events pending publication to external subscribers.
-    external_event_buf: CellBlock<'world, Vec<EventBox>>,
+    pub(crate) external_event_buf: CellBlock<'world, Vec<EventBox>>,
 }
 
 /// Struct for single transaction's aggregated changes
@@ -140,10 +231,13 @@ pub struct WorldTransaction<'block, 'world> {
     pub(crate) parameters: CellTransaction<'block, 'world, Parameters>,
     /// Identifications of discovered peers.
     pub(crate) peers: CellTransaction<'block, 'world, Peers>,
+    /// Peer set changes waiting for their safety delay to pass.
+    pub(crate) pending_peer_changes: CellTransaction<'block, 'world, Vec<PendingPeerChange>>,
     /// Registered domains.
     pub(crate) domains: StorageTransaction<'block, 'world, DomainId, Domain>,
     /// Registered accounts.
-    pub(crate) accounts: StorageTransaction<'block, 'world, AccountId, AccountValue>,
+    pub(crate) accounts:
+        StorageTransaction<'block, 'world, AccountId, Tiered<AccountId, AccountValue>>,
     /// Registered asset definitions.
     pub(crate) asset_definitions:
         StorageTransaction<'block, 'world, AssetDefinitionId, AssetDefinition>,
@@ -157,6 +251,40 @@ pub struct WorldTransaction<'block, 'world> {
     pub(crate) account_permissions: StorageTransaction<'block, 'world, AccountId, Permissions>,
     /// Roles of an account.
     pub(crate) account_roles: StorageTransaction<'block, 'world, RoleIdWithOwner, ()>,
+    /// Holders of a role, i.e. `account_roles` keyed by the role.
+    pub(crate) role_holders: StorageTransaction<'block, 'world, RoleHolder, ()>,
+    /// Accounts labeled with a tag, keyed by the tag.
+    pub(crate) tagged_accounts: StorageTransaction<'block, 'world, TaggedAccount, ()>,
+    /// Accounts ordered by the height of the block which last changed them, to find the dormant ones.
+    pub(crate) accounts_by_change: StorageTransaction<'block, 'world, (u64, AccountId), ()>,
+    /// Tombstones of unregistered accounts and domains.
+    pub(crate) tombstones: StorageTransaction<'block, 'world, IdBox, Tombstone>,
+    /// Quantities of assets at the end of the blocks which changed them.
+    pub(crate) asset_history: StorageTransaction<'block, 'world, AssetChange, Numeric>,
+    /// Height of the oldest change kept in the history of an asset, once the older ones are dropped by the retention.
+    pub(crate) asset_history_horizons: StorageTransaction<'block, 'world, AssetId, NonZeroU64>,
+    /// Notification subscriptions of accounts.
+    pub(crate) subscriptions: StorageTransaction<'block, 'world, SubscriptionId, Subscription>,
+    /// Failed trigger executions awaiting a retry or a discard by the trigger owner.
+    pub(crate) dead_letters: StorageTransaction<'block, 'world, DeadLetterId, DeadLetter>,
+    /// Permissions delegated by an account, keyed by the delegator.
+    pub(crate) delegations: StorageTransaction<'block, 'world, AccountId, BTreeSet<Delegation>>,
+    /// Permissions delegated to an account, i.e. `delegations` keyed by the delegate.
+    pub(crate) incoming_delegations:
+        StorageTransaction<'block, 'world, AccountId, BTreeSet<Delegation>>,
+    /// Allowances to spend the assets of an account, keyed by the owner.
+    pub(crate) allowances: StorageTransaction<'block, 'world, AccountId, Vec<Allowance>>,
+    /// Encrypted messages awaiting in the inboxes of accounts until they expire.
+    pub(crate) messages: StorageTransaction<'block, 'world, DirectMessageId, DirectMessage>,
+    /// Identifiers of the messages, keyed by the height of the block at the end of which they expire.
+    pub(crate) message_expiries:
+        StorageTransaction<'block, 'world, NonZeroU64, Vec<DirectMessageId>>,
+    /// Assets locked in escrows until they are claimed or refunded.
+    pub(crate) escrows: StorageTransaction<'block, 'world, EscrowId, Escrow>,
+    /// Time (since the Unix epoch, in milliseconds) the expiring grants to accounts are revoked at.
+    pub(crate) grant_expiries: StorageTransaction<'block, 'world, AccountGrant, u64>,
+    /// The expiring grants ordered by the time they're revoked at.
+    pub(crate) grants_by_expiry: StorageTransaction<'block, 'world, (u64, AccountGrant), ()>,
     /// Triggers
     pub(crate) triggers: TriggerSetTransaction<'block, 'world>,
     /// Runtime Executor
@@ -176,10 +304,12 @@ pub struct WorldView<'world> {
     pub(crate) parameters: CellView<'world, Parameters>,
     /// Identifications of discovered peers.
     pub(crate) peers: CellView<'world, Peers>,
+    /// Peer set changes waiting for their safety delay to pass.
+    pub(crate) pending_peer_changes: CellView<'world, Vec<PendingPeerChange>>,
     /// Registered domains.
     pub(crate) domains: StorageView<'world, DomainId, Domain>,
     /// Registered accounts.
-    pub(crate) accounts: StorageView<'world, AccountId, AccountValue>,
+    pub(crate) accounts: StorageView<'world, AccountId, Tiered<AccountId, AccountValue>>,
     /// Registered asset definitions.
     pub(crate) asset_definitions: StorageView<'world, AssetDefinitionId, AssetDefinition>,
     /// Registered assets.
@@ -192,6 +322,38 @@ pub struct WorldView<'world> {
     pub(crate) account_permissions: StorageView<'world, AccountId, Permissions>,
     /// Roles of an account.
     pub(crate) account_roles: StorageView<'world, RoleIdWithOwner, ()>,
+    /// Holders of a role, i.e. `account_roles` keyed by the role.
+    pub(crate) role_holders: StorageView<'world, RoleHolder, ()>,
+    /// Accounts labeled with a tag, keyed by the tag.
+    pub(crate) tagged_accounts: StorageView<'world, TaggedAccount, ()>,
+    /// Accounts ordered by the height of the block which last changed them, to find the dormant ones.
+    pub(crate) accounts_by_change: StorageView<'world, (u64, AccountId), ()>,
+    /// Tombstones of unregistered accounts and domains.
+    pub(crate) tombstones: StorageView<'world, IdBox, Tombstone>,
+    /// Quantities of assets at the end of the blocks which changed them.
+    pub(crate) asset_history: StorageView<'world, AssetChange, Numeric>,
+    /// Height of the oldest change kept in the history of an asset, once the older ones are dropped by the retention.
+    pub(crate) asset_history_horizons: StorageView<'world, AssetId, NonZeroU64>,
+    /// Notification subscriptions of accounts.
+    pub(crate) subscriptions: StorageView<'world, SubscriptionId, Subscription>,
+    /// Failed trigger executions awaiting a retry or a discard by the trigger owner.
+    pub(crate) dead_letters: StorageView<'world, DeadLetterId, DeadLetter>,
+    /// Permissions delegated by an account, keyed by the delegator.
+    pub(crate) delegations: StorageView<'world, AccountId, BTreeSet<Delegation>>,
+    /// Permissions delegated to an account, i.e. `delegations` keyed by the delegate.
+    pub(crate) incoming_delegations: StorageView<'world, AccountId, BTreeSet<Delegation>>,
+    /// Allowances to spend the assets of an account, keyed by the owner.
+    pub(crate) allowances: StorageView<'world, AccountId, Vec<Allowance>>,
+    /// Encrypted messages awaiting in the inboxes of accounts until they expire.
+    pub(crate) messages: StorageView<'world, DirectMessageId, DirectMessage>,
+    /// Identifiers of the messages, keyed by the height of the block at the end of which they expire.
+    pub(crate) message_expiries: StorageView<'world, NonZeroU64, Vec<DirectMessageId>>,
+    /// Assets locked in escrows until they are claimed or refunded.
+    pub(crate) escrows: StorageView<'world, EscrowId, Escrow>,
+    /// Time (since the Unix epoch, in milliseconds) the expiring grants to accounts are revoked at.
+    pub(crate) grant_expiries: StorageView<'world, AccountGrant, u64>,
+    /// The expiring grants ordered by the time they're revoked at.
+    pub(crate) grants_by_expiry: StorageView<'world, (u64, AccountGrant), ()>,
     /// Triggers
     pub(crate) triggers: TriggerSetView<'world>,
     /// Runtime Executor
@@ -229,6 +391,16 @@ pub struct State {
     #[cfg(feature = "telemetry")]
     #[serde(skip)]
     pub telemetry: StateTelemetry,
+    /// Bounds of the indices kept by this peer, unbounded if not set
+    #[serde(skip)]
+    retention: Option<Retention>,
+    /// Changes of the asset quantities found beyond the retention by [`Self::compact_asset_history`],
+    /// dropped by the next block: the assets and the heights of their oldest changes to keep
+    #[serde(skip)]
+    asset_history_pruning: parking_lot::Mutex<Vec<(AssetId, NonZeroU64)>>,
+    /// Store of the dormant accounts, all of them are kept in memory if not set
+    #[serde(skip)]
+    cold_store: Option<ColdStore<AccountId>>,
     /// Lock to prevent getting inconsistent view of the state
     #[serde(skip)]
     view_lock: parking_lot::RwLock<()>,
@@ -256,6 +428,12 @@ pub struct StateBlock<'state> {
     /// State telemetry
     #[cfg(feature = "telemetry")]
     pub telemetry: &'state StateTelemetry,
+    /// Bounds of the asset history kept by this peer
+    retention: Option<&'state Retention>,
+    /// Changes of the asset quantities to be dropped by this block
+    asset_history_pruning: &'state parking_lot::Mutex<Vec<(AssetId, NonZeroU64)>>,
+    /// Store of the dormant accounts
+    cold_store: Option<&'state ColdStore<AccountId>>,
     /// Lock to prevent getting inconsistent view of the state
     view_lock: &'state parking_lot::RwLock<()>,
 
@@ -282,10 +460,19 @@ pub struct StateTransaction<'block, 'state> {
     /// State telemetry
     #[cfg(feature = "telemetry")]
     pub telemetry: &'state StateTelemetry,
+    /// The latest trigger execution that failed, recorded as a [`DeadLetter`] if the transaction is discarded.
+    pub(crate) failed_trigger: Option<TriggerFailure>,
 
     pub(crate) curr_block: BlockHeader,
 }
 
+/// Trigger execution which failed within a [`StateTransaction`].
+pub(crate) struct TriggerFailure {
+    trigger: TriggerId,
+    event: EventBox,
+    error: String,
+}
+
 /// Consistent point in time view of the [`State`]
 pub struct StateView<'state> {
     /// The world. Contains `domains`, `triggers`, `roles` and other data representing the current state of the blockchain.
@@ -345,10 +532,27 @@ impl World {
             .into_iter()
             .map(|domain| (domain.id().clone(), domain))
             .collect();
-        let accounts = accounts
+        let accounts: Vec<_> = accounts
             .into_iter()
             .map(IntoKeyValue::into_key_value)
             .collect();
+        let tagged_accounts = accounts
+            .iter()
+            .flat_map(|(id, account)| {
+                account
+                    .tags
+                    .iter()
+                    .map(|tag| (TaggedAccount::new(tag.clone(), id.clone()), ()))
+            })
+            .collect();
+        let accounts_by_change = accounts
+            .iter()
+            .map(|(id, _)| ((0, id.clone()), ()))
+            .collect();
+        let accounts = accounts
+            .into_iter()
+            .map(|(id, account)| (id, Tiered::new(account)))
+            .collect();
         let asset_definitions = asset_definitions
             .into_iter()
             .map(|ad| (ad.id().clone(), ad))
@@ -361,6 +565,8 @@ impl World {
         Self {
             domains,
             accounts,
+            tagged_accounts,
+            accounts_by_change,
             asset_definitions,
             assets,
             nfts,
@@ -373,6 +579,7 @@ impl World {
         WorldBlock {
             parameters: self.parameters.block(),
             peers: self.peers.block(),
+            pending_peer_changes: self.pending_peer_changes.block(),
             domains: self.domains.block(),
             accounts: self.accounts.block(),
             asset_definitions: self.asset_definitions.block(),
@@ -381,6 +588,22 @@ impl World {
             roles: self.roles.block(),
             account_permissions: self.account_permissions.block(),
             account_roles: self.account_roles.block(),
+            role_holders: self.role_holders.block(),
+            tagged_accounts: self.tagged_accounts.block(),
+            accounts_by_change: self.accounts_by_change.block(),
+            tombstones: self.tombstones.block(),
+            asset_history: self.asset_history.block(),
+            asset_history_horizons: self.asset_history_horizons.block(),
+            subscriptions: self.subscriptions.block(),
+            dead_letters: self.dead_letters.block(),
+            delegations: self.delegations.block(),
+            incoming_delegations: self.incoming_delegations.block(),
+            allowances: self.allowances.block(),
+            messages: self.messages.block(),
+            message_expiries: self.message_expiries.block(),
+            escrows: self.escrows.block(),
+            grant_expiries: self.grant_expiries.block(),
+            grants_by_expiry: self.grants_by_expiry.block(),
             triggers: self.triggers.block(),
             executor: self.executor.block(),
             executor_data_model: self.executor_data_model.block(),
@@ -393,6 +616,7 @@ impl World {
         WorldBlock {
             parameters: self.parameters.block_and_revert(),
             peers: self.peers.block_and_revert(),
+            pending_peer_changes: self.pending_peer_changes.block_and_revert(),
             domains: self.domains.block_and_revert(),
             accounts: self.accounts.block_and_revert(),
             asset_definitions: self.asset_definitions.block_and_revert(),
@@ -401,6 +625,22 @@ impl World {
             roles: self.roles.block_and_revert(),
             account_permissions: self.account_permissions.block_and_revert(),
             account_roles: self.account_roles.block_and_revert(),
+            role_holders: self.role_holders.block_and_revert(),
+            tagged_accounts: self.tagged_accounts.block_and_revert(),
+            accounts_by_change: self.accounts_by_change.block_and_revert(),
+            tombstones: self.tombstones.block_and_revert(),
+            asset_history: self.asset_history.block_and_revert(),
+            asset_history_horizons: self.asset_history_horizons.block_and_revert(),
+            subscriptions: self.subscriptions.block_and_revert(),
+            dead_letters: self.dead_letters.block_and_revert(),
+            delegations: self.delegations.block_and_revert(),
+            incoming_delegations: self.incoming_delegations.block_and_revert(),
+            allowances: self.allowances.block_and_revert(),
+            messages: self.messages.block_and_revert(),
+            message_expiries: self.message_expiries.block_and_revert(),
+            escrows: self.escrows.block_and_revert(),
+            grant_expiries: self.grant_expiries.block_and_revert(),
+            grants_by_expiry: self.grants_by_expiry.block_and_revert(),
             triggers: self.triggers.block_and_revert(),
             executor: self.executor.block_and_revert(),
             executor_data_model: self.executor_data_model.block_and_revert(),
@@ -413,6 +653,7 @@ impl World {
         WorldView {
             parameters: self.parameters.view(),
             peers: self.peers.view(),
+            pending_peer_changes: self.pending_peer_changes.view(),
             domains: self.domains.view(),
             accounts: self.accounts.view(),
             asset_definitions: self.asset_definitions.view(),
@@ -421,6 +662,22 @@ impl World {
             roles: self.roles.view(),
             account_permissions: self.account_permissions.view(),
             account_roles: self.account_roles.view(),
+            role_holders: self.role_holders.view(),
+            tagged_accounts: self.tagged_accounts.view(),
+            accounts_by_change: self.accounts_by_change.view(),
+            tombstones: self.tombstones.view(),
+            asset_history: self.asset_history.view(),
+            asset_history_horizons: self.asset_history_horizons.view(),
+            subscriptions: self.subscriptions.view(),
+            dead_letters: self.dead_letters.view(),
+            delegations: self.delegations.view(),
+            incoming_delegations: self.incoming_delegations.view(),
+            allowances: self.allowances.view(),
+            messages: self.messages.view(),
+            message_expiries: self.message_expiries.view(),
+            escrows: self.escrows.view(),
+            grant_expiries: self.grant_expiries.view(),
+            grants_by_expiry: self.grants_by_expiry.view(),
             triggers: self.triggers.view(),
             executor: self.executor.view(),
             executor_data_model: self.executor_data_model.view(),
@@ -428,19 +685,43 @@ impl World {
     }
 }
 
+/// Unwrap the [`Account`] read back from the [`ColdStore`].
+///
+/// The peer can't go on without it, since the other peers execute the blocks against the account.
+fn expect_account<T>(id: &AccountId, account: Result<T, ColdStoreError>) -> T {
+    account.unwrap_or_else(|error| {
+        error!(%id, %error, "Failed to read a dormant account back from the disk");
+        panic!("Cold store has encountered a fatal IO error.");
+    })
+}
+
 /// Trait to perform read-only operations on [`WorldBlock`], [`WorldTransaction`] and [`WorldView`]
 #[allow(missing_docs)]
 pub trait WorldReadOnly {
     fn parameters(&self) -> &Parameters;
     fn peers(&self) -> &Peers;
+    fn pending_peer_changes(&self) -> &[PendingPeerChange];
     fn domains(&self) -> &impl StorageReadOnly<DomainId, Domain>;
-    fn accounts(&self) -> &impl StorageReadOnly<AccountId, AccountValue>;
+    fn accounts(&self) -> &impl StorageReadOnly<AccountId, Tiered<AccountId, AccountValue>>;
     fn asset_definitions(&self) -> &impl StorageReadOnly<AssetDefinitionId, AssetDefinition>;
     fn assets(&self) -> &impl StorageReadOnly<AssetId, AssetValue>;
     fn nfts(&self) -> &impl StorageReadOnly<NftId, NftValue>;
     fn roles(&self) -> &impl StorageReadOnly<RoleId, Role>;
     fn account_permissions(&self) -> &impl StorageReadOnly<AccountId, Permissions>;
     fn account_roles(&self) -> &impl StorageReadOnly<RoleIdWithOwner, ()>;
+    fn role_holders(&self) -> &impl StorageReadOnly<RoleHolder, ()>;
+    fn tagged_accounts(&self) -> &impl StorageReadOnly<TaggedAccount, ()>;
+    fn tombstones(&self) -> &impl StorageReadOnly<IdBox, Tombstone>;
+    fn asset_history(&self) -> &impl StorageReadOnly<AssetChange, Numeric>;
+    fn asset_history_horizons(&self) -> &impl StorageReadOnly<AssetId, NonZeroU64>;
+    fn subscriptions(&self) -> &impl StorageReadOnly<SubscriptionId, Subscription>;
+    fn dead_letters(&self) -> &impl StorageReadOnly<DeadLetterId, DeadLetter>;
+    fn delegations(&self) -> &impl StorageReadOnly<AccountId, BTreeSet<Delegation>>;
+    fn incoming_delegations(&self) -> &impl StorageReadOnly<AccountId, BTreeSet<Delegation>>;
+    fn allowances(&self) -> &impl StorageReadOnly<AccountId, Vec<Allowance>>;
+    fn messages(&self) -> &impl StorageReadOnly<DirectMessageId, DirectMessage>;
+    fn escrows(&self) -> &impl StorageReadOnly<EscrowId, Escrow>;
+    fn grant_expiries(&self) -> &impl StorageReadOnly<AccountGrant, u64>;
     fn triggers(&self) -> &impl TriggerSetReadOnly;
     fn executor(&self) -> &Executor;
     fn executor_data_model(&self) -> &ExecutorDataModel;
@@ -484,7 +765,7 @@ pub trait WorldReadOnly {
     fn accounts_in_domain_iter(&self, id: &DomainId) -> impl Iterator<Item = AccountEntry> {
         self.accounts()
             .range::<dyn AsAccountIdDomainCompare>(AccountByDomainBounds::new(id))
-            .map(|(id, value)| AccountEntry::new(id, value))
+            .map(|(id, value)| AccountEntry::new(id, expect_account(id, value.get())))
     }
 
     /// Returns reference for accounts map
@@ -492,7 +773,7 @@ pub trait WorldReadOnly {
     fn accounts_iter(&self) -> impl Iterator<Item = AccountEntry> {
         self.accounts()
             .iter()
-            .map(|(id, value)| AccountEntry::new(id, value))
+            .map(|(id, value)| AccountEntry::new(id, expect_account(id, value.get())))
     }
 
     /// Iterate asset definitions in domain
@@ -540,7 +821,7 @@ pub trait WorldReadOnly {
     fn account<'a>(&'a self, id: &'a AccountId) -> Result<AccountEntry<'a>, FindError> {
         self.accounts()
             .get(id)
-            .map(|value| AccountEntry::new(id, value))
+            .map(|value| AccountEntry::new(id, expect_account(id, value.get())))
             .ok_or_else(|| FindError::Account(id.clone()))
     }
 
@@ -690,6 +971,235 @@ pub trait WorldReadOnly {
             .get(id)
             .ok_or_else(|| FindError::Role(id.clone()))
     }
+
+    /// Get the time (since the Unix epoch, in milliseconds) the grant expires at, if it does
+    fn grant_expiry(&self, grant: &AccountGrant) -> Option<u64> {
+        self.grant_expiries().get(grant).copied()
+    }
+
+    /// Get the permissions of the [`Role`] including the ones of the roles it extends,
+    /// resolved the same way the executor does when checking the permissions of the role holders
+    fn role_permissions<'slf>(&'slf self, id: &RoleId) -> BTreeSet<&'slf Permission> {
+        core::iter::once(id)
+            .chain(self.inherited_roles(id))
+            .filter_map(|id| self.roles().get(id))
+            .flat_map(Role::permissions)
+            .collect()
+    }
+
+    /// Get [`RoleId`]s of the roles the [`Role`] extends, directly or through other roles
+    fn inherited_roles<'slf>(&'slf self, id: &RoleId) -> BTreeSet<&'slf RoleId> {
+        let mut inherited = BTreeSet::new();
+        let mut pending = self.roles().get(id).into_iter().collect::<Vec<_>>();
+        while let Some(role) = pending.pop() {
+            for extended in role.extends() {
+                if inherited.insert(extended) {
+                    pending.extend(self.roles().get(extended));
+                }
+            }
+        }
+
+        inherited
+    }
+
+    /// Get [`AccountId`]s of the accounts holding the [`Role`]
+    // NOTE: have to use concreate type because don't want to capture lifetme of `id`
+    #[allow(clippy::type_complexity)]
+    fn role_holders_iter<'slf>(
+        &'slf self,
+        id: &RoleId,
+    ) -> core::iter::Map<
+        RangeIter<'slf, RoleHolder, ()>,
+        fn((&'slf RoleHolder, &'slf ())) -> &'slf AccountId,
+    > {
+        self.role_holders()
+            .range(AccountIdByRoleBounds::new(id))
+            .map(|(holder, ())| &holder.account)
+    }
+
+    /// Get [`AccountId`]s of the accounts labeled with the `tag`
+    #[allow(clippy::type_complexity)]
+    fn tagged_accounts_iter<'slf>(
+        &'slf self,
+        tag: &Name,
+    ) -> core::iter::Map<
+        RangeIter<'slf, TaggedAccount, ()>,
+        fn((&'slf TaggedAccount, &'slf ())) -> &'slf AccountId,
+    > {
+        self.tagged_accounts()
+            .range(AccountIdByTagBounds::new(tag))
+            .map(|(tagged, ())| &tagged.account)
+    }
+
+    // Tombstone-related methods
+
+    /// Get [`Tombstone`] left by an unregistered entity.
+    ///
+    /// # Errors
+    /// Fails if there is no tombstone
+    fn tombstone(&self, id: &IdBox) -> Result<&Tombstone, FindError> {
+        self.tombstones()
+            .get(id)
+            .ok_or_else(|| FindError::Tombstone(Box::new(id.clone())))
+    }
+
+    /// Get quantity of the asset at the end of the block with the given height.
+    ///
+    /// Holdings which did not exist at that height have zero quantity.
+    ///
+    /// # Errors
+    ///
+    /// Fails if the height is beyond the [`Retention`] of the peer
+    fn asset_quantity_at(
+        &self,
+        id: &AssetId,
+        height: NonZeroU64,
+    ) -> Result<Numeric, QueryExecutionFail> {
+        if let Some(horizon) = self.asset_history_horizons().get(id) {
+            if height < *horizon {
+                return Err(QueryExecutionFail::Pruned(*horizon));
+            }
+        }
+        Ok(self
+            .asset_history()
+            .range::<dyn AsAssetChangeAssetCompare>(AssetChangeByAssetBounds::up_to(id, &height))
+            .last()
+            .map_or(Numeric::ZERO, |(_, quantity)| *quantity))
+    }
+
+    /// Heights of the blocks which changed the quantity of the asset, oldest first
+    fn asset_history_iter<'a>(&'a self, id: &'a AssetId) -> impl Iterator<Item = NonZeroU64> + 'a {
+        self.asset_history()
+            .range::<dyn AsAssetChangeAssetCompare>(AssetChangeByAssetBounds::new(id))
+            .map(|(change, _)| change.height)
+    }
+
+    // Subscription-related methods
+
+    /// Iterate subscriptions of all accounts
+    fn subscriptions_iter(&self) -> impl Iterator<Item = &Subscription> {
+        self.subscriptions()
+            .iter()
+            .map(|(_, subscription)| subscription)
+    }
+
+    /// Iterate subscriptions of the account
+    fn account_subscriptions_iter<'a>(
+        &'a self,
+        account: &'a AccountId,
+    ) -> impl Iterator<Item = &'a Subscription> {
+        self.subscriptions()
+            .range::<dyn AsSubscriptionIdAccountCompare>(SubscriptionByAccountBounds::new(account))
+            .map(|(_, subscription)| subscription)
+    }
+
+    /// Get [`Subscription`] registered by an account.
+    ///
+    /// # Errors
+    /// Fails if there is no subscription with the given id
+    fn subscription(&self, id: &SubscriptionId) -> Result<&Subscription, FindError> {
+        self.subscriptions()
+            .get(id)
+            .ok_or_else(|| FindError::Subscription(id.clone()))
+    }
+
+    // DeadLetter-related methods
+
+    /// Iterate dead letters recorded for the trigger, oldest first
+    fn trigger_dead_letters_iter<'a>(
+        &'a self,
+        trigger: &'a TriggerId,
+    ) -> impl Iterator<Item = &'a DeadLetter> + 'a {
+        self.dead_letters()
+            .range::<dyn AsDeadLetterIdTriggerCompare>(DeadLetterByTriggerBounds::new(trigger))
+            .map(|(_, dead_letter)| dead_letter)
+    }
+
+    /// Get [`DeadLetter`] recorded for a failed trigger execution.
+    ///
+    /// # Errors
+    /// Fails if there is no dead letter with the given id
+    fn dead_letter(&self, id: &DeadLetterId) -> Result<&DeadLetter, FindError> {
+        self.dead_letters()
+            .get(id)
+            .ok_or_else(|| FindError::DeadLetter(id.clone()))
+    }
+
+    // Delegation-related methods
+
+    /// Iterate [`Delegation`]s of all accounts
+    fn delegations_iter(&self) -> impl Iterator<Item = &Delegation> {
+        self.delegations()
+            .iter()
+            .flat_map(|(_, delegations)| delegations.iter())
+    }
+
+    /// Get the [`Delegation`] through which the account holds the permission, if any.
+    fn incoming_delegation(
+        &self,
+        account: &AccountId,
+        permission: &Permission,
+    ) -> Option<&Delegation> {
+        self.incoming_delegations()
+            .get(account)?
+            .iter()
+            .find(|delegation| delegation.permission == *permission)
+    }
+
+    // Allowance-related methods
+
+    /// Iterate [`Allowance`]s of all accounts
+    fn allowances_iter(&self) -> impl Iterator<Item = &Allowance> {
+        self.allowances()
+            .iter()
+            .flat_map(|(_, allowances)| allowances.iter())
+    }
+
+    /// Get the [`Allowance`] of the `spender` to spend the `owner`'s asset, if any.
+    fn allowance(
+        &self,
+        owner: &AccountId,
+        spender: &AccountId,
+        asset_definition: &AssetDefinitionId,
+    ) -> Option<&Allowance> {
+        self.allowances().get(owner)?.iter().find(|allowance| {
+            allowance.spender == *spender && allowance.asset_definition == *asset_definition
+        })
+    }
+
+    // Message-related methods
+
+    /// Iterate messages in the inbox of the account, oldest first
+    fn inbox_iter<'a>(
+        &'a self,
+        recipient: &'a AccountId,
+    ) -> impl Iterator<Item = &'a DirectMessage> + 'a {
+        self.messages()
+            .range::<dyn AsDirectMessageIdRecipientCompare>(DirectMessageByRecipientBounds::new(
+                recipient,
+            ))
+            .map(|(_, message)| message)
+    }
+
+    // Escrow-related methods
+
+    /// Get [`Escrow`] by its id.
+    ///
+    /// # Errors
+    /// Fails if there is no escrow with the given id
+    fn escrow(&self, id: &EscrowId) -> Result<&Escrow, FindError> {
+        self.escrows()
+            .get(id)
+            .ok_or_else(|| FindError::Escrow(id.clone()))
+    }
+
+    /// Iterate escrows sent or received by the account
+    fn escrows_iter<'a>(&'a self, account: &'a AccountId) -> impl Iterator<Item = &'a Escrow> + 'a {
+        self.escrows()
+            .iter()
+            .map(|(_, escrow)| escrow)
+            .filter(move |escrow| escrow.id.sender == *account || escrow.recipient == *account)
+    }
 }
 
 macro_rules! impl_world_ro {
@@ -701,10 +1211,13 @@ macro_rules! impl_world_ro {
             fn peers(&self) -> &Peers {
                 &self.peers
             }
+            fn pending_peer_changes(&self) -> &[PendingPeerChange] {
+                &self.pending_peer_changes
+            }
             fn domains(&self) -> &impl StorageReadOnly<DomainId, Domain> {
                 &self.domains
             }
-            fn accounts(&self) -> &impl StorageReadOnly<AccountId, AccountValue> {
+            fn accounts(&self) -> &impl StorageReadOnly<AccountId, Tiered<AccountId, AccountValue>> {
                 &self.accounts
             }
             fn asset_definitions(&self) -> &impl StorageReadOnly<AssetDefinitionId, AssetDefinition> {
@@ -725,6 +1238,45 @@ macro_rules! impl_world_ro {
             fn account_roles(&self) -> &impl StorageReadOnly<RoleIdWithOwner, ()> {
                 &self.account_roles
             }
+            fn role_holders(&self) -> &impl StorageReadOnly<RoleHolder, ()> {
+                &self.role_holders
+            }
+            fn tagged_accounts(&self) -> &impl StorageReadOnly<TaggedAccount, ()> {
+                &self.tagged_accounts
+            }
+            fn tombstones(&self) -> &impl StorageReadOnly<IdBox, Tombstone> {
+                &self.tombstones
+            }
+            fn asset_history(&self) -> &impl StorageReadOnly<AssetChange, Numeric> {
+                &self.asset_history
+            }
+            fn asset_history_horizons(&self) -> &impl StorageReadOnly<AssetId, NonZeroU64> {
+                &self.asset_history_horizons
+            }
+            fn subscriptions(&self) -> &impl StorageReadOnly<SubscriptionId, Subscription> {
+                &self.subscriptions
+            }
+            fn dead_letters(&self) -> &impl StorageReadOnly<DeadLetterId, DeadLetter> {
+                &self.dead_letters
+            }
+            fn delegations(&self) -> &impl StorageReadOnly<AccountId, BTreeSet<Delegation>> {
+                &self.delegations
+            }
+            fn incoming_delegations(&self) -> &impl StorageReadOnly<AccountId, BTreeSet<Delegation>> {
+                &self.incoming_delegations
+            }
+            fn allowances(&self) -> &impl StorageReadOnly<AccountId, Vec<Allowance>> {
+                &self.allowances
+            }
+            fn messages(&self) -> &impl StorageReadOnly<DirectMessageId, DirectMessage> {
+                &self.messages
+            }
+            fn escrows(&self) -> &impl StorageReadOnly<EscrowId, Escrow> {
+                &self.escrows
+            }
+            fn grant_expiries(&self) -> &impl StorageReadOnly<AccountGrant, u64> {
+                &self.grant_expiries
+            }
             fn triggers(&self) -> &impl TriggerSetReadOnly {
                 &self.triggers
             }
@@ -748,6 +1300,7 @@ impl<'world> WorldBlock<'world> {
         WorldTransaction {
             parameters: self.parameters.transaction(),
             peers: self.peers.transaction(),
+            pending_peer_changes: self.pending_peer_changes.transaction(),
             domains: self.domains.transaction(),
             accounts: self.accounts.transaction(),
             asset_definitions: self.asset_definitions.transaction(),
@@ -756,6 +1309,22 @@ impl<'world> WorldBlock<'world> {
             roles: self.roles.transaction(),
             account_permissions: self.account_permissions.transaction(),
             account_roles: self.account_roles.transaction(),
+            role_holders: self.role_holders.transaction(),
+            tagged_accounts: self.tagged_accounts.transaction(),
+            accounts_by_change: self.accounts_by_change.transaction(),
+            tombstones: self.tombstones.transaction(),
+            asset_history: self.asset_history.transaction(),
+            asset_history_horizons: self.asset_history_horizons.transaction(),
+            subscriptions: self.subscriptions.transaction(),
+            dead_letters: self.dead_letters.transaction(),
+            delegations: self.delegations.transaction(),
+            incoming_delegations: self.incoming_delegations.transaction(),
+            allowances: self.allowances.transaction(),
+            messages: self.messages.transaction(),
+            message_expiries: self.message_expiries.transaction(),
+            escrows: self.escrows.transaction(),
+            grant_expiries: self.grant_expiries.transaction(),
+            grants_by_expiry: self.grants_by_expiry.transaction(),
             triggers: self.triggers.transaction(),
             executor: self.executor.transaction(),
             executor_data_model: self.executor_data_model.transaction(),
@@ -770,6 +1339,7 @@ impl<'world> WorldBlock<'world> {
         let Self {
             parameters,
             peers,
+            pending_peer_changes,
             domains,
             accounts,
             asset_definitions,
@@ -778,6 +1348,22 @@ impl<'world> WorldBlock<'world> {
             roles,
             account_permissions,
             account_roles,
+            role_holders,
+            tagged_accounts,
+            accounts_by_change,
+            tombstones,
+            asset_history,
+            asset_history_horizons,
+            subscriptions,
+            dead_letters,
+            delegations,
+            incoming_delegations,
+            allowances,
+            messages,
+            message_expiries,
+            escrows,
+            grant_expiries,
+            grants_by_expiry,
             triggers,
             executor,
             executor_data_model,
@@ -788,6 +1374,22 @@ impl<'world> WorldBlock<'world> {
         executor_data_model.commit();
         executor.commit();
         triggers.commit();
+        grants_by_expiry.commit();
+        grant_expiries.commit();
+        escrows.commit();
+        message_expiries.commit();
+        messages.commit();
+        allowances.commit();
+        incoming_delegations.commit();
+        delegations.commit();
+        dead_letters.commit();
+        subscriptions.commit();
+        asset_history_horizons.commit();
+        asset_history.commit();
+        tombstones.commit();
+        accounts_by_change.commit();
+        tagged_accounts.commit();
+        role_holders.commit();
         account_roles.commit();
         account_permissions.commit();
         roles.commit();
@@ -796,6 +1398,7 @@ impl<'world> WorldBlock<'world> {
         asset_definitions.commit();
         accounts.commit();
         domains.commit();
+        pending_peer_changes.commit();
         peers.commit();
         parameters.commit();
     }
@@ -808,6 +1411,7 @@ impl WorldTransaction<'_, '_> {
         let Self {
             parameters,
             peers,
+            pending_peer_changes,
             domains,
             accounts,
             asset_definitions,
@@ -816,6 +1420,22 @@ impl WorldTransaction<'_, '_> {
             roles,
             account_permissions,
             account_roles,
+            role_holders,
+            tagged_accounts,
+            accounts_by_change,
+            tombstones,
+            asset_history,
+            asset_history_horizons,
+            subscriptions,
+            dead_letters,
+            delegations,
+            incoming_delegations,
+            allowances,
+            messages,
+            message_expiries,
+            escrows,
+            grant_expiries,
+            grants_by_expiry,
             triggers,
             executor,
             executor_data_model,
@@ -826,6 +1446,22 @@ impl WorldTransaction<'_, '_> {
         executor_data_model.apply();
         executor.apply();
         triggers.apply();
+        grants_by_expiry.apply();
+        grant_expiries.apply();
+        escrows.apply();
+        message_expiries.apply();
+        messages.apply();
+        allowances.apply();
+        incoming_delegations.apply();
+        delegations.apply();
+        dead_letters.apply();
+        subscriptions.apply();
+        asset_history_horizons.apply();
+        asset_history.apply();
+        tombstones.apply();
+        accounts_by_change.apply();
+        tagged_accounts.apply();
+        role_holders.apply();
         account_roles.apply();
         account_permissions.apply();
         roles.apply();
@@ -834,6 +1470,7 @@ impl WorldTransaction<'_, '_> {
         asset_definitions.apply();
         accounts.apply();
         domains.apply();
+        pending_peer_changes.apply();
         peers.apply();
         parameters.apply();
     }
@@ -857,6 +1494,7 @@ impl WorldTransaction<'_, '_> {
     pub fn account_mut(&mut self, id: &AccountId) -> Result<&mut AccountValue, FindError> {
         self.accounts
             .get_mut(id)
+            .map(|account| expect_account(id, account.get_mut()))
             .ok_or_else(|| FindError::Account(id.clone()))
     }
 
@@ -884,21 +1522,381 @@ impl WorldTransaction<'_, '_> {
     /// Remove a [`permission`](Permission) from the [`Account`] if the account has this permission.
     /// Return a Boolean value indicating whether the [`Account`] had this permission.
     pub fn remove_account_permission(&mut self, account: &AccountId, token: &Permission) -> bool {
+        self.remove_grant_expiry(AccountGrant::new(
+            account.clone(),
+            Granted::Permission(token.clone()),
+        ));
         self.account_permissions
             .get_mut(account)
             .is_some_and(|permissions| permissions.remove(token))
     }
 
+    /// Grant the [`Role`] to the [`Account`].
+    ///
+    /// Return `false` if the [`Account`] already had this role.
+    pub fn add_account_role(&mut self, account: &AccountId, role: &RoleId) -> bool {
+        self.role_holders
+            .insert(RoleHolder::new(role.clone(), account.clone()), ());
+        self.account_roles
+            .insert(RoleIdWithOwner::new(account.clone(), role.clone()), ())
+            .is_none()
+    }
+
+    /// Revoke the [`Role`] from the [`Account`].
+    ///
+    /// Return `false` if the [`Account`] didn't have this role.
+    pub fn remove_account_role(&mut self, account: &AccountId, role: &RoleId) -> bool {
+        self.remove_grant_expiry(AccountGrant::new(
+            account.clone(),
+            Granted::Role(role.clone()),
+        ));
+        self.role_holders
+            .remove(RoleHolder::new(role.clone(), account.clone()));
+        self.account_roles
+            .remove(RoleIdWithOwner::new(account.clone(), role.clone()))
+            .is_some()
+    }
+
+    /// Make the grant of the `object` to the [`Account`] expire at `valid_until_ms`
+    pub fn add_grant_expiry(&mut self, account: &AccountId, object: Granted, valid_until_ms: u64) {
+        let grant = AccountGrant::new(account.clone(), object);
+        self.remove_grant_expiry(grant.clone());
+        self.grants_by_expiry
+            .insert((valid_until_ms, grant.clone()), ());
+        self.grant_expiries.insert(grant, valid_until_ms);
+    }
+
+    fn remove_grant_expiry(&mut self, grant: AccountGrant) {
+        if let Some(valid_until_ms) = self.grant_expiries.remove(grant.clone()) {
+            self.grants_by_expiry.remove((valid_until_ms, grant));
+        }
+    }
+
+    /// Remove the expiries of the grants to the [`Account`]
+    pub fn remove_account_grant_expiries(&mut self, account: &AccountId) {
+        let expiries_to_remove = self
+            .grant_expiries
+            .range::<dyn AsAccountGrantAccountCompare>(AccountGrantByAccountBounds::new(account))
+            .map(|(grant, _)| grant.clone())
+            .collect::<Vec<_>>();
+
+        for grant in expiries_to_remove {
+            self.remove_grant_expiry(grant);
+        }
+    }
+
     /// Remove all [`Role`]s from the [`Account`]
     pub fn remove_account_roles(&mut self, account: &AccountId) {
         let roles_to_remove = self
             .account_roles_iter(account)
             .cloned()
-            .map(|role| RoleIdWithOwner::new(account.clone(), role.clone()))
             .collect::<Vec<_>>();
 
         for role in roles_to_remove {
-            self.account_roles.remove(role);
+            self.remove_account_role(account, &role);
+        }
+    }
+
+    /// Remove the [`Role`] from the roles extending it
+    pub fn remove_extended_role(&mut self, role: &RoleId) {
+        let extending = self
+            .roles
+            .iter()
+            .filter(|(_, extending)| extending.extends.contains(role))
+            .map(|(id, _)| id.clone())
+            .collect::<Vec<_>>();
+
+        for id in extending {
+            if let Some(extending) = self.roles.get_mut(&id) {
+                extending.extends.remove(role);
+            }
+        }
+    }
+
+    /// Label the [`Account`] with the `tag`.
+    ///
+    /// Return `false` if the [`Account`] already had this tag.
+    ///
+    /// # Errors
+    /// Fails if there is no account
+    pub fn add_account_tag(&mut self, account: &AccountId, tag: Name) -> Result<bool, FindError> {
+        if !self.account_mut(account)?.tags.insert(tag.clone()) {
+            return Ok(false);
+        }
+        self.tagged_accounts
+            .insert(TaggedAccount::new(tag, account.clone()), ());
+        Ok(true)
+    }
+
+    /// Remove the `tag` from the [`Account`].
+    ///
+    /// Return `false` if the [`Account`] didn't have this tag.
+    ///
+    /// # Errors
+    /// Fails if there is no account
+    pub fn remove_account_tag(
+        &mut self,
+        account: &AccountId,
+        tag: &Name,
+    ) -> Result<bool, FindError> {
+        if !self.account_mut(account)?.tags.remove(tag) {
+            return Ok(false);
+        }
+        self.tagged_accounts
+            .remove(TaggedAccount::new(tag.clone(), account.clone()));
+        Ok(true)
+    }
+
+    /// Remove the [`Account`] from the index of tagged accounts, leaving its own tags as is
+    pub fn remove_account_tags(&mut self, account: &AccountId) {
+        let tags = self
+            .accounts
+            .get(account)
+            .map(|value| expect_account(account, value.get()).tags.clone())
+            .unwrap_or_default();
+
+        for tag in tags {
+            self.tagged_accounts
+                .remove(TaggedAccount::new(tag, account.clone()));
+        }
+    }
+
+    /// Remove all [`Subscription`]s of the [`Account`]
+    pub fn remove_account_subscriptions(&mut self, account: &AccountId) {
+        let subscriptions_to_remove = self
+            .account_subscriptions_iter(account)
+            .map(|subscription| subscription.id().clone())
+            .collect::<Vec<_>>();
+
+        for subscription in subscriptions_to_remove {
+            self.subscriptions.remove(subscription);
+        }
+    }
+
+    /// Remove all [`DeadLetter`]s of the trigger
+    pub fn remove_trigger_dead_letters(&mut self, trigger: &TriggerId) {
+        let dead_letters_to_remove = self
+            .dead_letters
+            .range::<dyn AsDeadLetterIdTriggerCompare>(DeadLetterByTriggerBounds::new(trigger))
+            .map(|(id, _)| id.clone())
+            .collect::<Vec<_>>();
+
+        for dead_letter in dead_letters_to_remove {
+            self.dead_letters.remove(dead_letter);
+        }
+    }
+
+    /// Remove the [`Tombstone`] of the domain together with the ones of the accounts buried with it.
+    ///
+    /// Returns the removed [`Tombstone`] of the domain, if any.
+    pub fn remove_domain_tombstones(&mut self, domain: &DomainId) -> Option<Tombstone> {
+        let account_tombstones = self
+            .tombstones
+            .iter()
+            .filter_map(|(id, _)| match id {
+                IdBox::AccountId(account_id) if account_id.domain() == domain => Some(id.clone()),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        for id in account_tombstones {
+            self.tombstones.remove(id);
+        }
+
+        self.tombstones.remove(IdBox::DomainId(domain.clone()))
+    }
+
+    /// Record the [`Delegation`] of a permission.
+    pub fn add_delegation(&mut self, delegation: Delegation) {
+        match self.incoming_delegations.get_mut(&delegation.delegate) {
+            None => {
+                self.incoming_delegations.insert(
+                    delegation.delegate.clone(),
+                    BTreeSet::from([delegation.clone()]),
+                );
+            }
+            Some(delegations) => {
+                delegations.insert(delegation.clone());
+            }
+        }
+        match self.delegations.get_mut(&delegation.delegator) {
+            None => {
+                self.delegations
+                    .insert(delegation.delegator.clone(), BTreeSet::from([delegation]));
+            }
+            Some(delegations) => {
+                delegations.insert(delegation);
+            }
+        }
+    }
+
+    /// Remove the record of the [`Delegation`] without revoking the delegated permission.
+    fn remove_delegation(&mut self, delegation: &Delegation) {
+        if let Some(delegations) = self.incoming_delegations.get_mut(&delegation.delegate) {
+            delegations.remove(delegation);
+            if delegations.is_empty() {
+                self.incoming_delegations
+                    .remove(delegation.delegate.clone());
+            }
+        }
+        let Some(delegations) = self.delegations.get_mut(&delegation.delegator) else {
+            return;
+        };
+        delegations.remove(delegation);
+        if delegations.is_empty() {
+            self.delegations.remove(delegation.delegator.clone());
+        }
+    }
+
+    /// Remove the record of the [`Delegation`] through which the account holds the permission, if any.
+    pub fn remove_incoming_delegation(&mut self, account: &AccountId, permission: &Permission) {
+        if let Some(delegation) = self.incoming_delegation(account, permission).cloned() {
+            self.remove_delegation(&delegation);
+        }
+    }
+
+    /// Revoke the permission from the accounts the `delegator` delegated it to,
+    /// cascading to the accounts they delegated it to in turn.
+    pub fn revoke_delegations(&mut self, delegator: &AccountId, permission: &Permission) {
+        let revoked = self
+            .delegations
+            .get(delegator)
+            .into_iter()
+            .flatten()
+            .filter(|delegation| delegation.permission == *permission)
+            .cloned()
+            .collect::<Vec<_>>();
+
+        for delegation in revoked {
+            self.remove_delegation(&delegation);
+            if self.remove_account_permission(&delegation.delegate, permission) {
+                self.emit_events(Some(AccountEvent::PermissionRemoved(
+                    AccountPermissionChanged {
+                        account: delegation.delegate.clone(),
+                        permission: permission.clone(),
+                    },
+                )));
+            }
+            self.revoke_delegations(&delegation.delegate, permission);
+            self.emit_events(Some(AccountEvent::DelegationRevoked(delegation)));
+        }
+    }
+
+    /// Remove all [`Delegation`]s of the [`Account`], revoking the permissions it delegated
+    pub fn remove_account_delegations(&mut self, account: &AccountId) {
+        let delegated = self
+            .delegations
+            .get(account)
+            .into_iter()
+            .flatten()
+            .map(|delegation| delegation.permission.clone())
+            .collect::<BTreeSet<_>>();
+        for permission in delegated {
+            self.revoke_delegations(account, &permission);
+        }
+
+        let incoming = self
+            .incoming_delegations
+            .get(account)
+            .into_iter()
+            .flatten()
+            .cloned()
+            .collect::<Vec<_>>();
+        for delegation in incoming {
+            self.remove_delegation(&delegation);
+        }
+    }
+
+    /// Replace the allowance of the spender with the given [`Allowance`], zero limit withdraws it.
+    pub fn set_allowance(&mut self, allowance: Allowance) {
+        let owner = allowance.owner.clone();
+        let mut allowances = self.allowances.remove(owner.clone()).unwrap_or_default();
+        allowances.retain(|other| {
+            other.spender != allowance.spender
+                || other.asset_definition != allowance.asset_definition
+        });
+        if !allowance.limit.is_zero() {
+            allowances.push(allowance);
+        }
+        if !allowances.is_empty() {
+            self.allowances.insert(owner, allowances);
+        }
+    }
+
+    /// Remove the [`Allowance`]s given by or to the [`Account`]
+    pub fn remove_account_allowances(&mut self, account: &AccountId) {
+        self.allowances.remove(account.clone());
+        self.retain_allowances(|allowance| allowance.spender != *account);
+    }
+
+    /// Remove the [`Allowance`]s to spend the asset of the [`AssetDefinition`]
+    pub fn remove_asset_definition_allowances(&mut self, asset_definition: &AssetDefinitionId) {
+        self.retain_allowances(|allowance| allowance.asset_definition != *asset_definition);
+    }
+
+    /// Leave the [`DirectMessage`] in the inbox of its recipient until it expires
+    pub fn insert_message(&mut self, message: DirectMessage) {
+        let mut expiring = self
+            .message_expiries
+            .remove(message.expires_at)
+            .unwrap_or_default();
+        expiring.push(message.id.clone());
+        self.message_expiries.insert(message.expires_at, expiring);
+        self.messages.insert(message.id.clone(), message);
+    }
+
+    /// Remove the messages in the inbox of the [`Account`]
+    pub fn remove_account_messages(&mut self, account: &AccountId) {
+        let messages_to_remove = self
+            .inbox_iter(account)
+            .map(|message| (message.id().clone(), message.expires_at))
+            .collect::<Vec<_>>();
+        for (message, expires_at) in messages_to_remove {
+            let mut expiring = self.message_expiries.remove(expires_at).unwrap_or_default();
+            expiring.retain(|id| *id != message);
+            if !expiring.is_empty() {
+                self.message_expiries.insert(expires_at, expiring);
+            }
+            self.messages.remove(message);
+        }
+    }
+
+    /// Remove the escrows sent by the [`Account`], since the locked assets go with its holdings.
+    ///
+    /// Escrows destined to the account stay refundable by their senders.
+    pub fn remove_account_escrows(&mut self, account: &AccountId) {
+        self.retain_escrows(|escrow| escrow.id.sender != *account);
+    }
+
+    /// Remove the escrows locking the asset of the [`AssetDefinition`]
+    pub fn remove_asset_definition_escrows(&mut self, asset_definition: &AssetDefinitionId) {
+        self.retain_escrows(|escrow| escrow.asset_definition != *asset_definition);
+    }
+
+    fn retain_escrows(&mut self, f: impl Fn(&Escrow) -> bool) {
+        let escrows_to_remove = self
+            .escrows
+            .iter()
+            .filter(|(_, escrow)| !f(escrow))
+            .map(|(id, _)| id.clone())
+            .collect::<Vec<_>>();
+        for escrow in escrows_to_remove {
+            self.escrows.remove(escrow);
+        }
+    }
+
+    fn retain_allowances(&mut self, f: impl Fn(&Allowance) -> bool) {
+        let owners = self
+            .allowances
+            .iter()
+            .filter(|(_, allowances)| !allowances.iter().all(&f))
+            .map(|(owner, _)| owner.clone())
+            .collect::<Vec<_>>();
+        for owner in owners {
+            let mut allowances = self.allowances.remove(owner.clone()).unwrap_or_default();
+            allowances.retain(&f);
+            if !allowances.is_empty() {
+                self.allowances.insert(owner, allowances);
+            }
         }
     }
 
@@ -1070,6 +2068,35 @@ impl WorldTransaction<'_, '_> {
         )
     }
 
+    /// Position in the event buffers, see [`Self::tag_events_since()`].
+    pub(crate) fn events_position(&self) -> (usize, usize) {
+        (self.external_event_buf.len(), self.internal_event_buf.len())
+    }
+
+    /// Tag the data events emitted since the `position` with `tag`, e.g. by a [`Tagged`] instruction.
+    pub(crate) fn tag_events_since(&mut self, (external, internal): (usize, usize), tag: &Name) {
+        let external = external.min(self.external_event_buf.len());
+        let tagged = self
+            .external_event_buf
+            .split_off(external)
+            .into_iter()
+            .map(|event| match event {
+                EventBox::Data(event) => EventBox::Data(event.with_tag(tag.clone())),
+                event => event,
+            })
+            .collect::<Vec<_>>();
+        self.external_event_buf.extend(tagged);
+
+        let internal = internal.min(self.internal_event_buf.len());
+        let tagged = self
+            .internal_event_buf
+            .split_off(internal)
+            .into_iter()
+            .map(|event| event.with_tag(tag.clone()))
+            .collect::<Vec<_>>();
+        self.internal_event_buf.extend(tagged);
+    }
+
     /// Implementation of [`Self::emit_events()`].
     ///
     /// Usable when you can't call [`Self::emit_events()`] due to mutable reference to self.
@@ -1104,10 +2131,73 @@ impl State {
             query_handle,
             #[cfg(feature = "telemetry")]
             telemetry,
+            retention: None,
+            asset_history_pruning: parking_lot::Mutex::default(),
+            cold_store: None,
             view_lock: parking_lot::RwLock::new(()),
         }
     }
 
+    /// Bound the indices kept by this peer, see [`WorldReadOnly::asset_quantity_at`]
+    pub fn set_retention(&mut self, retention: Retention) {
+        self.retention = Some(retention);
+    }
+
+    /// Bounds of the indices kept by this peer, unbounded if not set
+    pub fn retention(&self) -> Option<&Retention> {
+        self.retention.as_ref()
+    }
+
+    /// Find the changes of the asset quantities beyond the [`Retention`], to be dropped by the next block.
+    ///
+    /// The whole history is scanned, so it's done by the [`Compactor`](crate::compaction::Compactor)
+    /// in the background rather than on block application.
+    pub fn compact_asset_history(&self) {
+        let Some(retention) = &self.retention else {
+            return;
+        };
+        let view = self.view();
+        let cutoff = asset_history_cutoff(retention, view.height() as u64);
+
+        let mut pruning = Vec::new();
+        #[cfg(feature = "telemetry")]
+        let mut entries = 0_u64;
+        // The changes are ordered by the asset, so the history of each asset is collected in turn
+        let mut heights = Vec::new();
+        let mut changes = view
+            .world
+            .asset_history
+            .iter()
+            .map(|(change, _)| change)
+            .peekable();
+        while let Some(change) = changes.next() {
+            heights.push(change.height);
+            if changes
+                .peek()
+                .is_some_and(|next| next.asset == change.asset)
+            {
+                continue;
+            }
+            let excess = asset_history_excess(&heights, retention, cutoff);
+            if excess > 0 {
+                pruning.push((change.asset.clone(), heights[excess]));
+            }
+            #[cfg(feature = "telemetry")]
+            {
+                entries += (heights.len() - excess) as u64;
+            }
+            heights.clear();
+        }
+        #[cfg(feature = "telemetry")]
+        self.telemetry.set_asset_history_entries(entries);
+        self.asset_history_pruning.lock().extend(pruning);
+    }
+
+    /// Move the accounts dormant for [`ColdStore::dormant_after_blocks`] to the disk
+    pub fn set_cold_store(&mut self, cold_store: ColdStore<AccountId>) {
+        self.cold_store = Some(cold_store);
+    }
+
     /// Construct [`State`] with given [`World`].
     #[must_use]
     #[inline]
@@ -1167,6 +2257,9 @@ impl State {
             query_handle: &self.query_handle,
             #[cfg(feature = "telemetry")]
             telemetry: &self.telemetry,
+            retention: self.retention.as_ref(),
+            asset_history_pruning: &self.asset_history_pruning,
+            cold_store: self.cold_store.as_ref(),
             view_lock: &self.view_lock,
             curr_block,
         }
@@ -1185,6 +2278,9 @@ impl State {
             query_handle: &self.query_handle,
             #[cfg(feature = "telemetry")]
             telemetry: &self.telemetry,
+            retention: self.retention.as_ref(),
+            asset_history_pruning: &self.asset_history_pruning,
+            cold_store: self.cold_store.as_ref(),
             view_lock: &self.view_lock,
             curr_block,
         }
@@ -1355,6 +2451,29 @@ impl StateReadOnlyWithTransactions for StateBlock<'_> {
     }
 }
 
+/// Height of the latest block whose changes of the asset quantities are beyond the `retention` at `block_height`.
+fn asset_history_cutoff(retention: &Retention, block_height: u64) -> u64 {
+    retention
+        .asset_history_max_age_blocks
+        .map_or(0, |max_age| block_height.saturating_sub(max_age.get()))
+}
+
+/// Number of the oldest changes in the `history` of an asset quantity beyond the `retention`,
+/// given the heights of the changes, oldest first.
+///
+/// The latest change made at or before the `cutoff` height is kept, so that the quantities after it are still known.
+fn asset_history_excess(history: &[NonZeroU64], retention: &Retention, cutoff: u64) -> usize {
+    let by_size = retention
+        .asset_history_max_entries
+        .map_or(0, |max_entries| {
+            history.len().saturating_sub(max_entries.get())
+        });
+    let by_age = history
+        .partition_point(|changed_at| changed_at.get() <= cutoff)
+        .saturating_sub(1);
+    by_size.max(by_age)
+}
+
 impl<'state> StateBlock<'state> {
     /// Create struct to store changes during transaction or trigger execution
     pub fn transaction(&mut self) -> StateTransaction<'_, 'state> {
@@ -1368,10 +2487,59 @@ impl<'state> StateBlock<'state> {
             query_handle: self.query_handle,
             #[cfg(feature = "telemetry")]
             telemetry: self.telemetry,
+            failed_trigger: None,
             curr_block: self.curr_block,
         }
     }
 
+    /// Hash of the events emitted by the block so far.
+    ///
+    /// Covers only the changes reported as events: e.g. the asset history, the transaction index
+    /// and the internals of the parameters and triggers aren't hashed, so equal hashes don't imply equal states.
+    pub fn events_hash(&self) -> HashOf<Vec<EventBox>> {
+        HashOf::new(&*self.world.external_event_buf)
+    }
+
+    /// Record the failed trigger execution of a discarded transaction as a [`DeadLetter`] of the trigger owner.
+    ///
+    /// Only the latest [`MAX_DEAD_LETTERS_PER_TRIGGER`] dead letters of a trigger are kept.
+    pub(crate) fn record_dead_letter(&mut self, failure: TriggerFailure) {
+        let TriggerFailure {
+            trigger,
+            event,
+            error,
+        } = failure;
+        // The trigger might have been registered by the discarded transaction itself
+        let Some(authority) = self
+            .world
+            .triggers
+            .inspect_by_id(&trigger, |action| action.authority().clone())
+        else {
+            return;
+        };
+
+        let recorded = self
+            .world
+            .trigger_dead_letters_iter(&trigger)
+            .map(|dead_letter| dead_letter.id().clone())
+            .collect::<Vec<_>>();
+        let sequence = recorded.last().map_or(0, |id| id.sequence + 1);
+        let evicted = (recorded.len() + 1).saturating_sub(MAX_DEAD_LETTERS_PER_TRIGGER);
+        for id in recorded.into_iter().take(evicted) {
+            self.world.dead_letters.remove(id);
+        }
+
+        let id = DeadLetterId::new(trigger, sequence);
+        let dead_letter = DeadLetter::new(
+            id.clone(),
+            authority,
+            event,
+            error,
+            self.curr_block.height(),
+        );
+        self.world.dead_letters.insert(id, dead_letter);
+    }
+
     /// Commit changes aggregated during application of block
     pub fn commit(self) {
         // NOTE: intentionally destruct self not to forget commit some fields
@@ -1422,6 +2590,10 @@ impl<'state> StateBlock<'state> {
         *self.prev_commit_topology = core::mem::take(&mut self.commit_topology);
         *self.commit_topology = topology;
 
+        self.record_asset_history(block.as_ref().header().height());
+        self.tier_accounts(block.as_ref().header().height());
+        self.prune_expired_messages(block.as_ref().header().height());
+
         self.world.external_event_buf.push(
             BlockEvent {
                 header: block.as_ref().header(),
@@ -1432,6 +2604,275 @@ impl<'state> StateBlock<'state> {
         core::mem::take(&mut self.world.external_event_buf)
     }
 
+    /// Record quantities of the assets changed by the block, so that they can be queried at its height.
+    ///
+    /// The history of the changed assets is bounded by the [`Retention`] right away,
+    /// the changes of the other assets found beyond it by [`State::compact_asset_history`] are dropped.
+    fn record_asset_history(&mut self, block_height: NonZeroU64) {
+        let pruning = core::mem::take(&mut *self.asset_history_pruning.lock());
+        for (asset_id, keep_from) in pruning {
+            self.prune_asset_history(asset_id, keep_from);
+        }
+
+        let cutoff = self.retention.map_or(0, |retention| {
+            asset_history_cutoff(retention, block_height.get())
+        });
+
+        let changed: BTreeSet<AssetId> = self
+            .world
+            .external_event_buf
+            .iter()
+            .filter_map(|event| match event {
+                EventBox::Data(event) => match event.untagged() {
+                    DataEvent::Domain(DomainEvent::Account(AccountEvent::Asset(asset_event))) => {
+                        Some(asset_event.origin().clone())
+                    }
+                    _ => None,
+                },
+                _ => None,
+            })
+            .collect();
+
+        for asset_id in changed {
+            let quantity = self
+                .world
+                .assets
+                .get(&asset_id)
+                .map_or(Numeric::ZERO, |asset| asset.value);
+            let latest = self
+                .world
+                .asset_history
+                .range::<dyn AsAssetChangeAssetCompare>(AssetChangeByAssetBounds::new(&asset_id))
+                .last()
+                .map(|(_, latest)| *latest);
+            if latest == Some(quantity) {
+                continue;
+            }
+            self.world
+                .asset_history
+                .insert(AssetChange::new(asset_id.clone(), block_height), quantity);
+
+            let Some(retention) = self.retention else {
+                continue;
+            };
+            let heights: Vec<_> = self.world.asset_history_iter(&asset_id).collect();
+            let excess = asset_history_excess(&heights, retention, cutoff);
+            if excess > 0 {
+                self.prune_asset_history(asset_id, heights[excess]);
+            }
+        }
+    }
+
+    /// Drop the changes of the quantity of the asset made before the `keep_from` height
+    fn prune_asset_history(&mut self, asset_id: AssetId, keep_from: NonZeroU64) {
+        let excess: Vec<_> = self
+            .world
+            .asset_history
+            .range::<dyn AsAssetChangeAssetCompare>(AssetChangeByAssetBounds::before(
+                &asset_id, &keep_from,
+            ))
+            .map(|(change, _)| change.clone())
+            .collect();
+        if excess.is_empty() {
+            return;
+        }
+        #[cfg(feature = "telemetry")]
+        self.telemetry.prune_asset_history(excess.len() as u64);
+        for change in excess {
+            self.world.asset_history.remove(change);
+        }
+        self.world
+            .asset_history_horizons
+            .insert(asset_id, keep_from);
+    }
+
+    /// Record the accounts changed by the block and move the ones dormant since
+    /// [`ColdStore::dormant_after_blocks`] to the disk once per this many blocks.
+    fn tier_accounts(&mut self, block_height: NonZeroU64) {
+        let Some(cold_store) = self.cold_store else {
+            return;
+        };
+
+        let changed: BTreeSet<AccountId> = self
+            .world
+            .external_event_buf
+            .iter()
+            .filter_map(|event| match event {
+                EventBox::Data(event) => match event.untagged() {
+                    DataEvent::Domain(DomainEvent::Account(event)) => Some(event.origin().clone()),
+                    _ => None,
+                },
+                _ => None,
+            })
+            .collect();
+        for account_id in changed {
+            if let Some(account) = self.world.accounts.get_mut(&account_id) {
+                self.world
+                    .accounts_by_change
+                    .remove((account.changed_at(), account_id.clone()));
+                account.touch(block_height.get());
+                self.world
+                    .accounts_by_change
+                    .insert((block_height.get(), account_id), ());
+            }
+        }
+
+        let dormant_after = cold_store.dormant_after_blocks.get();
+        if block_height.get() % dormant_after != 0 {
+            return;
+        }
+        let cutoff = block_height.get() - dormant_after;
+        let newly_dormant: Vec<_> = self
+            .world
+            .accounts_by_change
+            .iter()
+            .take_while(|((changed_at, _), ())| *changed_at <= cutoff)
+            .map(|(entry, ())| entry.clone())
+            .collect();
+        // Evicted accounts leave the index until changed, the ones read back since are evicted again
+        let mut dormant = cold_store.take_woken();
+        for (changed_at, account_id) in newly_dormant {
+            self.world
+                .accounts_by_change
+                .remove((changed_at, account_id.clone()));
+            dormant.push(account_id);
+        }
+        for account_id in dormant {
+            // Unregistered or changed since
+            if !self
+                .world
+                .accounts
+                .get(&account_id)
+                .is_some_and(|account| account.is_dormant(cutoff))
+            {
+                continue;
+            }
+            let account = self
+                .world
+                .accounts
+                .get_mut(&account_id)
+                .expect("INTERNAL BUG: account was just found");
+            if let Err(error) = account.evict(cold_store, &account_id) {
+                // The account is kept in memory, so that the state is intact
+                warn!(%error, %account_id, "Failed to move a dormant account to the disk");
+                self.world
+                    .accounts_by_change
+                    .insert((account.changed_at(), account_id), ());
+            }
+        }
+    }
+
+    /// Remove the messages whose time to live is over by the end of the block
+    fn prune_expired_messages(&mut self, block_height: NonZeroU64) {
+        let expired: Vec<_> = self
+            .world
+            .message_expiries
+            .range(..=block_height)
+            .map(|(expires_at, _)| *expires_at)
+            .collect();
+        for expires_at in expired {
+            let ids = self
+                .world
+                .message_expiries
+                .remove(expires_at)
+                .unwrap_or_default();
+            for id in ids {
+                self.world.messages.remove(id);
+            }
+        }
+    }
+
+    /// Revoke the grants to accounts which expire by the creation of the block.
+    ///
+    /// Like peer set changes, grants are revoked before any transaction of the block,
+    /// so that the checks of the executor in the block don't pass with them anymore.
+    pub(crate) fn expire_grants(&mut self, block_time: Duration) {
+        let expired: Vec<_> = self
+            .world
+            .grants_by_expiry
+            .iter()
+            .take_while(|((valid_until_ms, _), ())| {
+                u128::from(*valid_until_ms) <= block_time.as_millis()
+            })
+            .map(|((_, grant), ())| grant.clone())
+            .collect();
+        if expired.is_empty() {
+            return;
+        }
+
+        let mut transaction = self.transaction();
+        for AccountGrant { account, object } in expired {
+            match object {
+                Granted::Permission(permission) => {
+                    transaction
+                        .world
+                        .remove_account_permission(&account, &permission);
+                    transaction
+                        .world
+                        .remove_incoming_delegation(&account, &permission);
+                    transaction
+                        .world
+                        .emit_events(Some(AccountEvent::PermissionExpired(
+                            AccountPermissionChanged {
+                                account: account.clone(),
+                                permission: permission.clone(),
+                            },
+                        )));
+                    transaction.world.revoke_delegations(&account, &permission);
+                }
+                Granted::Role(role) => {
+                    transaction.world.remove_account_role(&account, &role);
+                    transaction
+                        .world
+                        .emit_events(Some(AccountEvent::RoleExpired(AccountRoleChanged {
+                            account,
+                            role,
+                        })));
+                }
+            }
+        }
+        transaction.apply();
+    }
+
+    /// Apply peer set changes whose safety delay ends at the given block height.
+    ///
+    /// Changes are applied before any transaction of the block,
+    /// so the block which activates a change is the first one to observe it.
+    pub(crate) fn activate_peer_changes(&mut self, block_height: u64) {
+        if self
+            .world
+            .pending_peer_changes
+            .iter()
+            .all(|change| block_height < change.activation_height)
+        {
+            return;
+        }
+
+        let (due, pending): (Vec<_>, Vec<_>) =
+            core::mem::take(&mut *self.world.pending_peer_changes)
+                .into_iter()
+                .partition(|change| change.activation_height <= block_height);
+        *self.world.pending_peer_changes = pending;
+
+        for PendingPeerChange { peer, register, .. } in due {
+            let event = if register {
+                if let PushResult::Duplicate(_) = self.world.peers.push(peer.clone()) {
+                    continue;
+                }
+                PeerEvent::Added(peer)
+            } else {
+                let Some(index) = self.world.peers.iter().position(|id| *id == peer) else {
+                    continue;
+                };
+                self.world.peers.remove(index);
+                PeerEvent::Removed(peer)
+            };
+            self.world
+                .external_event_buf
+                .push(DataEvent::from(event).into());
+        }
+    }
+
     /// Execute time-triggered transactions for the given block, applying their state changes on success.
     ///
     /// Returns a triplet of vectors: the first contains the time-triggered entrypoints,
@@ -1482,7 +2923,7 @@ impl<'state> StateBlock<'state> {
         )
     }
 
-    /// Execute a scheduled trigger, applying its state changes on success, or leaving the state unchanged on failure.
+    /// Execute a scheduled trigger, applying its state changes on success, or only recording the failed trigger execution as a dead letter on failure.
     ///
     /// Returns the hash and the result of this "transaction" --
     /// the trigger sequence including this entrypoint on success, or the rejection reason on failure.
@@ -1509,6 +2950,11 @@ impl<'state> StateBlock<'state> {
                 entrypoint.instructions = step;
             }
             Err(reason) => {
+                let failure = transaction.failed_trigger.take();
+                drop(transaction);
+                if let Some(failure) = failure {
+                    self.record_dead_letter(failure);
+                }
                 return (
                     // FIXME: fetch the actual time trigger instructions.
                     entrypoint,
@@ -1518,7 +2964,14 @@ impl<'state> StateBlock<'state> {
         }
         let trigger_sequence = match transaction.execute_data_triggers_dfs(action.authority()) {
             Ok(sequence) => sequence,
-            err => return (entrypoint, err),
+            err => {
+                let failure = transaction.failed_trigger.take();
+                drop(transaction);
+                if let Some(failure) = failure {
+                    self.record_dead_letter(failure);
+                }
+                return (entrypoint, err);
+            }
         };
 
         transaction
@@ -1549,8 +3002,10 @@ impl<'state> StateBlock<'state> {
     /// Apply a committed block to the world state.
     ///
     /// Execution order:
-    /// 1. Transactions (including invoked data triggers)
-    /// 2. Time triggers (including invoked data triggers)
+    /// 1. Peer set changes whose safety delay has passed
+    /// 2. Revocation of the expired grants
+    /// 3. Transactions (including invoked data triggers)
+    /// 4. Time triggers (including invoked data triggers)
     ///
     /// # Panics
     ///
@@ -1558,6 +3013,8 @@ impl<'state> StateBlock<'state> {
     #[cfg(any(test, feature = "bench"))]
     #[iroha_logger::log(skip_all, fields(block_height))]
     pub fn apply(&mut self, block: &CommittedBlock, topology: Vec<PeerId>) -> Vec<EventBox> {
+        self.activate_peer_changes(block.as_ref().header().height().get());
+        self.expire_grants(block.as_ref().header().creation_time());
         self.apply_transactions(block);
         debug!(height = %self.height(), "Transactions applied");
         self.execute_time_triggers(&block.as_ref().header());
@@ -1655,7 +3112,13 @@ impl StateTransaction<'_, '_> {
         while let Some((event, trg_id, depth)) = stack.pop() {
             let max_depth = self.world.parameters.smart_contract.execution_depth;
             if max_depth < depth {
-                return Err(TriggerExecutionFail::MaxDepthExceeded.into());
+                let error = TriggerExecutionFail::MaxDepthExceeded;
+                self.failed_trigger = Some(TriggerFailure {
+                    trigger: trg_id,
+                    event: event.into(),
+                    error: error.to_string(),
+                });
+                return Err(error.into());
             }
             let executable = {
                 let action = self
@@ -1718,7 +3181,7 @@ impl StateTransaction<'_, '_> {
     /// Execute any condition of trigger, staging its state changes.
     ///
     /// Returns the execution step on success, or the rejection reason on failure.
-    fn execute_trigger(
+    pub(crate) fn execute_trigger(
         &mut self,
         id: &TriggerId,
         authority: &AccountId,
@@ -1746,7 +3209,7 @@ impl StateTransaction<'_, '_> {
                             id,
                             authority.clone(),
                             &module,
-                            event,
+                            event.clone(),
                         )
                     })
                     .map_err(ValidationFail::from)
@@ -1756,10 +3219,18 @@ impl StateTransaction<'_, '_> {
         let outcome = match &res {
             // TODO: Integrate step information into pipeline events (entrypoint hash, index in trigger sequence, etc.)
             Ok(_execution_step) => TriggerCompletedOutcome::Success,
-            Err(error) => TriggerCompletedOutcome::Failure(error.to_string()),
+            Err(error) => {
+                let error = error.to_string();
+                self.failed_trigger = Some(TriggerFailure {
+                    trigger: id.clone(),
+                    event,
+                    error: error.clone(),
+                });
+                TriggerCompletedOutcome::Failure(error)
+            }
         };
-        let event = TriggerCompletedEvent::new(id.clone(), outcome);
-        self.world.external_event_buf.push(event.into());
+        let completed = TriggerCompletedEvent::new(id.clone(), outcome);
+        self.world.external_event_buf.push(completed.into());
 
         res.map_err(Into::into)
     }
@@ -1812,7 +3283,10 @@ mod range_bounds {
     use iroha_primitives::{cmpext::MinMaxExt, impl_as_dyn_key};
 
     use super::*;
-    use crate::role::RoleIdWithOwner;
+    use crate::{
+        role::{RoleHolder, RoleIdWithOwner},
+        tag::TaggedAccount,
+    };
 
     /// Key for range queries over account for roles
     #[derive(PartialEq, Eq, PartialOrd, Ord, Copy, Clone)]
@@ -1868,6 +3342,114 @@ mod range_bounds {
         trait: AsRoleIdByAccount
     }
 
+    /// Key for range queries over role for accounts
+    #[derive(PartialEq, Eq, PartialOrd, Ord, Copy, Clone)]
+    pub struct AccountIdByRole<'role> {
+        role_id: &'role RoleId,
+        account_id: MinMaxExt<&'role AccountId>,
+    }
+
+    /// Bounds for range quired over role for accounts
+    pub struct AccountIdByRoleBounds<'role> {
+        start: AccountIdByRole<'role>,
+        end: AccountIdByRole<'role>,
+    }
+
+    impl<'role> AccountIdByRoleBounds<'role> {
+        /// Create range bounds for range quires of accounts over role
+        pub fn new(role_id: &'role RoleId) -> Self {
+            Self {
+                start: AccountIdByRole {
+                    role_id,
+                    account_id: MinMaxExt::Min,
+                },
+                end: AccountIdByRole {
+                    role_id,
+                    account_id: MinMaxExt::Max,
+                },
+            }
+        }
+    }
+
+    impl<'role> RangeBounds<dyn AsAccountIdByRole + 'role> for AccountIdByRoleBounds<'role> {
+        fn start_bound(&self) -> Bound<&(dyn AsAccountIdByRole + 'role)> {
+            Bound::Excluded(&self.start)
+        }
+
+        fn end_bound(&self) -> Bound<&(dyn AsAccountIdByRole + 'role)> {
+            Bound::Excluded(&self.end)
+        }
+    }
+
+    impl AsAccountIdByRole for RoleHolder {
+        fn as_key(&self) -> AccountIdByRole<'_> {
+            AccountIdByRole {
+                role_id: &self.id,
+                account_id: (&self.account).into(),
+            }
+        }
+    }
+
+    impl_as_dyn_key! {
+        target: RoleHolder,
+        key: AccountIdByRole<'_>,
+        trait: AsAccountIdByRole
+    }
+
+    /// Key for range queries over tag for accounts
+    #[derive(PartialEq, Eq, PartialOrd, Ord, Copy, Clone)]
+    pub struct AccountIdByTag<'tag> {
+        tag: &'tag Name,
+        account_id: MinMaxExt<&'tag AccountId>,
+    }
+
+    /// Bounds for range quired over tag for accounts
+    pub struct AccountIdByTagBounds<'tag> {
+        start: AccountIdByTag<'tag>,
+        end: AccountIdByTag<'tag>,
+    }
+
+    impl<'tag> AccountIdByTagBounds<'tag> {
+        /// Create range bounds for range quires of accounts over tag
+        pub fn new(tag: &'tag Name) -> Self {
+            Self {
+                start: AccountIdByTag {
+                    tag,
+                    account_id: MinMaxExt::Min,
+                },
+                end: AccountIdByTag {
+                    tag,
+                    account_id: MinMaxExt::Max,
+                },
+            }
+        }
+    }
+
+    impl<'tag> RangeBounds<dyn AsAccountIdByTag + 'tag> for AccountIdByTagBounds<'tag> {
+        fn start_bound(&self) -> Bound<&(dyn AsAccountIdByTag + 'tag)> {
+            Bound::Excluded(&self.start)
+        }
+
+        fn end_bound(&self) -> Bound<&(dyn AsAccountIdByTag + 'tag)> {
+            Bound::Excluded(&self.end)
+        }
+    }
+
+    impl AsAccountIdByTag for TaggedAccount {
+        fn as_key(&self) -> AccountIdByTag<'_> {
+            AccountIdByTag {
+                tag: &self.tag,
+                account_id: (&self.account).into(),
+            }
+        }
+    }
+
+    impl_as_dyn_key! {
+        target: TaggedAccount,
+        key: AccountIdByTag<'_>,
+        trait: AsAccountIdByTag
+    }
+
     /// `DomainId` wrapper for fetching accounts beloning to a domain from the global store
     #[derive(PartialEq, Eq, Ord, PartialOrd, Copy, Clone)]
     pub struct AccountIdDomainCompare<'a> {
@@ -2085,6 +3667,304 @@ mod range_bounds {
         key: AssetIdAccountCompare<'_>,
         trait: AsAssetIdAccountCompare
     }
+
+    /// `AccountId` wrapper for fetching subscriptions of an account from the global store
+    #[derive(PartialEq, Eq, Ord, PartialOrd, Copy, Clone)]
+    pub struct SubscriptionIdAccountCompare<'a> {
+        account_id: &'a AccountId,
+        name: MinMaxExt<&'a Name>,
+    }
+
+    /// Bounds for range quired over subscriptions by account
+    pub struct SubscriptionByAccountBounds<'a> {
+        start: SubscriptionIdAccountCompare<'a>,
+        end: SubscriptionIdAccountCompare<'a>,
+    }
+
+    impl<'a> SubscriptionByAccountBounds<'a> {
+        /// Create range bounds for range quires over subscriptions by account
+        pub fn new(account_id: &'a AccountId) -> Self {
+            Self {
+                start: SubscriptionIdAccountCompare {
+                    account_id,
+                    name: MinMaxExt::Min,
+                },
+                end: SubscriptionIdAccountCompare {
+                    account_id,
+                    name: MinMaxExt::Max,
+                },
+            }
+        }
+    }
+
+    impl<'a> RangeBounds<dyn AsSubscriptionIdAccountCompare + 'a> for SubscriptionByAccountBounds<'a> {
+        fn start_bound(&self) -> Bound<&(dyn AsSubscriptionIdAccountCompare + 'a)> {
+            Bound::Excluded(&self.start)
+        }
+
+        fn end_bound(&self) -> Bound<&(dyn AsSubscriptionIdAccountCompare + 'a)> {
+            Bound::Excluded(&self.end)
+        }
+    }
+
+    impl AsSubscriptionIdAccountCompare for SubscriptionId {
+        fn as_key(&self) -> SubscriptionIdAccountCompare<'_> {
+            SubscriptionIdAccountCompare {
+                account_id: &self.account,
+                name: (&self.name).into(),
+            }
+        }
+    }
+
+    impl_as_dyn_key! {
+        target: SubscriptionId,
+        key: SubscriptionIdAccountCompare<'_>,
+        trait: AsSubscriptionIdAccountCompare
+    }
+
+    /// `TriggerId` wrapper for fetching dead letters of a trigger from the global store
+    #[derive(PartialEq, Eq, Ord, PartialOrd, Copy, Clone)]
+    pub struct DeadLetterIdTriggerCompare<'a> {
+        trigger: &'a TriggerId,
+        sequence: MinMaxExt<&'a u64>,
+    }
+
+    /// Bounds for range quired over dead letters by trigger
+    pub struct DeadLetterByTriggerBounds<'a> {
+        start: DeadLetterIdTriggerCompare<'a>,
+        end: DeadLetterIdTriggerCompare<'a>,
+    }
+
+    impl<'a> DeadLetterByTriggerBounds<'a> {
+        /// Create range bounds for range quires over dead letters by trigger
+        pub fn new(trigger: &'a TriggerId) -> Self {
+            Self {
+                start: DeadLetterIdTriggerCompare {
+                    trigger,
+                    sequence: MinMaxExt::Min,
+                },
+                end: DeadLetterIdTriggerCompare {
+                    trigger,
+                    sequence: MinMaxExt::Max,
+                },
+            }
+        }
+    }
+
+    impl<'a> RangeBounds<dyn AsDeadLetterIdTriggerCompare + 'a> for DeadLetterByTriggerBounds<'a> {
+        fn start_bound(&self) -> Bound<&(dyn AsDeadLetterIdTriggerCompare + 'a)> {
+            Bound::Excluded(&self.start)
+        }
+
+        fn end_bound(&self) -> Bound<&(dyn AsDeadLetterIdTriggerCompare + 'a)> {
+            Bound::Excluded(&self.end)
+        }
+    }
+
+    impl AsDeadLetterIdTriggerCompare for DeadLetterId {
+        fn as_key(&self) -> DeadLetterIdTriggerCompare<'_> {
+            DeadLetterIdTriggerCompare {
+                trigger: &self.trigger,
+                sequence: (&self.sequence).into(),
+            }
+        }
+    }
+
+    impl_as_dyn_key! {
+        target: DeadLetterId,
+        key: DeadLetterIdTriggerCompare<'_>,
+        trait: AsDeadLetterIdTriggerCompare
+    }
+
+    /// `AccountId` wrapper for fetching the inbox of an account from the global store
+    #[derive(PartialEq, Eq, Ord, PartialOrd, Copy, Clone)]
+    pub struct DirectMessageIdRecipientCompare<'a> {
+        recipient: &'a AccountId,
+        sequence: MinMaxExt<&'a u64>,
+    }
+
+    /// Bounds for range quired over messages by recipient
+    pub struct DirectMessageByRecipientBounds<'a> {
+        start: DirectMessageIdRecipientCompare<'a>,
+        end: DirectMessageIdRecipientCompare<'a>,
+    }
+
+    impl<'a> DirectMessageByRecipientBounds<'a> {
+        /// Create range bounds for range quires over messages by recipient
+        pub fn new(recipient: &'a AccountId) -> Self {
+            Self {
+                start: DirectMessageIdRecipientCompare {
+                    recipient,
+                    sequence: MinMaxExt::Min,
+                },
+                end: DirectMessageIdRecipientCompare {
+                    recipient,
+                    sequence: MinMaxExt::Max,
+                },
+            }
+        }
+    }
+
+    impl<'a> RangeBounds<dyn AsDirectMessageIdRecipientCompare + 'a>
+        for DirectMessageByRecipientBounds<'a>
+    {
+        fn start_bound(&self) -> Bound<&(dyn AsDirectMessageIdRecipientCompare + 'a)> {
+            Bound::Excluded(&self.start)
+        }
+
+        fn end_bound(&self) -> Bound<&(dyn AsDirectMessageIdRecipientCompare + 'a)> {
+            Bound::Excluded(&self.end)
+        }
+    }
+
+    impl AsDirectMessageIdRecipientCompare for DirectMessageId {
+        fn as_key(&self) -> DirectMessageIdRecipientCompare<'_> {
+            DirectMessageIdRecipientCompare {
+                recipient: &self.recipient,
+                sequence: (&self.sequence).into(),
+            }
+        }
+    }
+
+    impl_as_dyn_key! {
+        target: DirectMessageId,
+        key: DirectMessageIdRecipientCompare<'_>,
+        trait: AsDirectMessageIdRecipientCompare
+    }
+
+    /// `AccountId` wrapper for fetching the expiring grants to an account from the global store
+    #[derive(PartialEq, Eq, Ord, PartialOrd, Copy, Clone)]
+    pub struct AccountGrantAccountCompare<'a> {
+        account: &'a AccountId,
+        object: MinMaxExt<&'a Granted>,
+    }
+
+    /// Bounds for range quired over expiring grants by account
+    pub struct AccountGrantByAccountBounds<'a> {
+        start: AccountGrantAccountCompare<'a>,
+        end: AccountGrantAccountCompare<'a>,
+    }
+
+    impl<'a> AccountGrantByAccountBounds<'a> {
+        /// Create range bounds for range quires over expiring grants by account
+        pub fn new(account: &'a AccountId) -> Self {
+            Self {
+                start: AccountGrantAccountCompare {
+                    account,
+                    object: MinMaxExt::Min,
+                },
+                end: AccountGrantAccountCompare {
+                    account,
+                    object: MinMaxExt::Max,
+                },
+            }
+        }
+    }
+
+    impl<'a> RangeBounds<dyn AsAccountGrantAccountCompare + 'a> for AccountGrantByAccountBounds<'a> {
+        fn start_bound(&self) -> Bound<&(dyn AsAccountGrantAccountCompare + 'a)> {
+            Bound::Excluded(&self.start)
+        }
+
+        fn end_bound(&self) -> Bound<&(dyn AsAccountGrantAccountCompare + 'a)> {
+            Bound::Excluded(&self.end)
+        }
+    }
+
+    impl AsAccountGrantAccountCompare for AccountGrant {
+        fn as_key(&self) -> AccountGrantAccountCompare<'_> {
+            AccountGrantAccountCompare {
+                account: &self.account,
+                object: (&self.object).into(),
+            }
+        }
+    }
+
+    impl_as_dyn_key! {
+        target: AccountGrant,
+        key: AccountGrantAccountCompare<'_>,
+        trait: AsAccountGrantAccountCompare
+    }
+
+    /// `AssetId` wrapper for fetching the history of an asset from the global store
+    #[derive(PartialEq, Eq, Ord, PartialOrd, Copy, Clone)]
+    pub struct AssetChangeAssetCompare<'a> {
+        asset: &'a AssetId,
+        height: MinMaxExt<&'a NonZeroU64>,
+    }
+
+    /// Bounds for range quired over the history of an asset
+    pub struct AssetChangeByAssetBounds<'a> {
+        start: AssetChangeAssetCompare<'a>,
+        end: AssetChangeAssetCompare<'a>,
+        end_included: bool,
+    }
+
+    impl<'a> AssetChangeByAssetBounds<'a> {
+        /// Create range bounds for range quires over all the changes of an asset
+        pub fn new(asset: &'a AssetId) -> Self {
+            Self {
+                start: AssetChangeAssetCompare {
+                    asset,
+                    height: MinMaxExt::Min,
+                },
+                end: AssetChangeAssetCompare {
+                    asset,
+                    height: MinMaxExt::Max,
+                },
+                end_included: false,
+            }
+        }
+
+        /// Create range bounds for range quires over the changes of an asset made up to the `height`
+        pub fn up_to(asset: &'a AssetId, height: &'a NonZeroU64) -> Self {
+            Self {
+                end: AssetChangeAssetCompare {
+                    asset,
+                    height: MinMaxExt::Value(height),
+                },
+                end_included: true,
+                ..Self::new(asset)
+            }
+        }
+
+        /// Create range bounds for range quires over the changes of an asset made before the `height`
+        pub fn before(asset: &'a AssetId, height: &'a NonZeroU64) -> Self {
+            Self {
+                end_included: false,
+                ..Self::up_to(asset, height)
+            }
+        }
+    }
+
+    impl<'a> RangeBounds<dyn AsAssetChangeAssetCompare + 'a> for AssetChangeByAssetBounds<'a> {
+        fn start_bound(&self) -> Bound<&(dyn AsAssetChangeAssetCompare + 'a)> {
+            Bound::Excluded(&self.start)
+        }
+
+        fn end_bound(&self) -> Bound<&(dyn AsAssetChangeAssetCompare + 'a)> {
+            if self.end_included {
+                Bound::Included(&self.end)
+            } else {
+                Bound::Excluded(&self.end)
+            }
+        }
+    }
+
+    impl AsAssetChangeAssetCompare for AssetChange {
+        fn as_key(&self) -> AssetChangeAssetCompare<'_> {
+            AssetChangeAssetCompare {
+                asset: &self.asset,
+                height: (&self.height).into(),
+            }
+        }
+    }
+
+    impl_as_dyn_key! {
+        target: AssetChange,
+        key: AssetChangeAssetCompare<'_>,
+        trait: AsAssetChangeAssetCompare
+    }
 }
 
 pub(crate) mod deserialize {
@@ -2181,6 +4061,7 @@ pub(crate) mod deserialize {
                 {
                     let mut parameters = None;
                     let mut peers = None;
+                    let mut pending_peer_changes = None;
                     let mut domains = None;
                     let mut accounts = None;
                     let mut asset_definitions = None;
@@ -2189,6 +4070,21 @@ pub(crate) mod deserialize {
                     let mut roles = None;
                     let mut account_permissions = None;
                     let mut account_roles = None;
+                    let mut role_holders = None;
+                    let mut tagged_accounts = None;
+                    let mut tombstones = None;
+                    let mut asset_history = None;
+                    let mut asset_history_horizons = None;
+                    let mut subscriptions = None;
+                    let mut dead_letters = None;
+                    let mut delegations = None;
+                    let mut incoming_delegations = None;
+                    let mut allowances = None;
+                    let mut messages = None;
+                    let mut message_expiries = None;
+                    let mut escrows = None;
+                    let mut grant_expiries = None;
+                    let mut grants_by_expiry = None;
                     let mut triggers = None;
                     let mut executor = None;
                     let mut executor_data_model = None;
@@ -2202,6 +4098,9 @@ pub(crate) mod deserialize {
                             "peers" => {
                                 peers = Some(map.next_value()?);
                             }
+                            "pending_peer_changes" => {
+                                pending_peer_changes = Some(map.next_value()?);
+                            }
                             "domains" => {
                                 domains = Some(map.next_value()?);
                             }
@@ -2226,6 +4125,51 @@ pub(crate) mod deserialize {
                             "account_roles" => {
                                 account_roles = Some(map.next_value()?);
                             }
+                            "role_holders" => {
+                                role_holders = Some(map.next_value()?);
+                            }
+                            "tagged_accounts" => {
+                                tagged_accounts = Some(map.next_value()?);
+                            }
+                            "tombstones" => {
+                                tombstones = Some(map.next_value()?);
+                            }
+                            "asset_history" => {
+                                asset_history = Some(map.next_value()?);
+                            }
+                            "asset_history_horizons" => {
+                                asset_history_horizons = Some(map.next_value()?);
+                            }
+                            "subscriptions" => {
+                                subscriptions = Some(map.next_value()?);
+                            }
+                            "dead_letters" => {
+                                dead_letters = Some(map.next_value()?);
+                            }
+                            "delegations" => {
+                                delegations = Some(map.next_value()?);
+                            }
+                            "incoming_delegations" => {
+                                incoming_delegations = Some(map.next_value()?);
+                            }
+                            "allowances" => {
+                                allowances = Some(map.next_value()?);
+                            }
+                            "messages" => {
+                                messages = Some(map.next_value()?);
+                            }
+                            "message_expiries" => {
+                                message_expiries = Some(map.next_value()?);
+                            }
+                            "escrows" => {
+                                escrows = Some(map.next_value()?);
+                            }
+                            "grant_expiries" => {
+                                grant_expiries = Some(map.next_value()?);
+                            }
+                            "grants_by_expiry" => {
+                                grants_by_expiry = Some(map.next_value()?);
+                            }
                             "triggers" => {
                                 triggers =
                                     Some(map.next_value_seed(self.loader.cast::<TriggerSet>())?);
@@ -2246,14 +4190,91 @@ pub(crate) mod deserialize {
                         }
                     }
 
+                    let account_roles: Storage<RoleIdWithOwner, ()> = account_roles
+                        .ok_or_else(|| serde::de::Error::missing_field("account_roles"))?;
+                    // Absent in snapshots taken before permissions could be delegated
+                    let delegations: Storage<AccountId, BTreeSet<Delegation>> =
+                        delegations.unwrap_or_default();
+                    // Absent in snapshots taken before the delegations were indexed by the delegate
+                    let incoming_delegations = incoming_delegations.unwrap_or_else(|| {
+                        let mut incoming = BTreeMap::<_, BTreeSet<_>>::new();
+                        for (_, delegations) in delegations.view().iter() {
+                            for delegation in delegations {
+                                incoming
+                                    .entry(delegation.delegate.clone())
+                                    .or_default()
+                                    .insert(delegation.clone());
+                            }
+                        }
+                        incoming.into_iter().collect()
+                    });
+                    // Absent in snapshots taken before the holders of roles were indexed
+                    let role_holders = role_holders.unwrap_or_else(|| {
+                        account_roles
+                            .view()
+                            .iter()
+                            .map(|(role, ())| {
+                                (RoleHolder::new(role.id.clone(), role.account.clone()), ())
+                            })
+                            .collect()
+                    });
+                    let accounts: Storage<AccountId, Tiered<AccountId, AccountValue>> =
+                        accounts.ok_or_else(|| serde::de::Error::missing_field("accounts"))?;
+                    // Absent in snapshots taken before accounts could be tagged
+                    let tagged_accounts = tagged_accounts.unwrap_or_else(|| {
+                        accounts
+                            .view()
+                            .iter()
+                            .flat_map(|(id, account)| {
+                                expect_account(id, account.get())
+                                    .tags
+                                    .iter()
+                                    .map(|tag| (TaggedAccount::new(tag.clone(), id.clone()), ()))
+                            })
+                            .collect()
+                    });
+                    // Not kept in snapshots, the restored accounts count as last changed at height 0
+                    let accounts_by_change = accounts
+                        .view()
+                        .iter()
+                        .map(|(id, _)| ((0, id.clone()), ()))
+                        .collect();
+
+                    let messages: Storage<DirectMessageId, DirectMessage> =
+                        messages.unwrap_or_default();
+                    // Absent in snapshots taken before the messages were indexed by expiry
+                    let message_expiries = message_expiries.unwrap_or_else(|| {
+                        let mut message_expiries = BTreeMap::<_, Vec<_>>::new();
+                        for (id, message) in messages.view().iter() {
+                            message_expiries
+                                .entry(message.expires_at)
+                                .or_default()
+                                .push(id.clone());
+                        }
+                        message_expiries.into_iter().collect()
+                    });
+
+                    // Absent in snapshots taken before grants could expire
+                    let grant_expiries: Storage<AccountGrant, u64> =
+                        grant_expiries.unwrap_or_default();
+                    // Absent in snapshots taken before the grants were ordered by expiry
+                    let grants_by_expiry = grants_by_expiry.unwrap_or_else(|| {
+                        grant_expiries
+                            .view()
+                            .iter()
+                            .map(|(grant, valid_until_ms)| ((*valid_until_ms, grant.clone()), ()))
+                            .collect()
+                    });
+
                     Ok(World {
                         parameters: parameters
                             .ok_or_else(|| serde::de::Error::missing_field("parameters"))?,
                         peers: peers.ok_or_else(|| serde::de::Error::missing_field("peers"))?,
+                        // Absent in snapshots taken before peer changes could be delayed
+                        pending_peer_changes: pending_peer_changes.unwrap_or_default(),
                         domains: domains
                             .ok_or_else(|| serde::de::Error::missing_field("domains"))?,
-                        accounts: accounts
-                            .ok_or_else(|| serde::de::Error::missing_field("accounts"))?,
+                        accounts,
                         asset_definitions: asset_definitions
                             .ok_or_else(|| serde::de::Error::missing_field("asset_definitions"))?,
                         assets: assets.ok_or_else(|| serde::de::Error::missing_field("assets"))?,
@@ -2262,8 +4283,30 @@ pub(crate) mod deserialize {
                         account_permissions: account_permissions.ok_or_else(|| {
                             serde::de::Error::missing_field("account_permissions")
                         })?,
-                        account_roles: account_roles
-                            .ok_or_else(|| serde::de::Error::missing_field("account_roles"))?,
+                        account_roles,
+                        role_holders,
+                        tagged_accounts,
+                        accounts_by_change,
+                        // Absent in snapshots taken before unregistered entities left tombstones
+                        tombstones: tombstones.unwrap_or_default(),
+                        asset_history: asset_history.unwrap_or_default(),
+                        // Absent in snapshots taken before the pruned history was told apart
+                        asset_history_horizons: asset_history_horizons.unwrap_or_default(),
+                        // Absent in snapshots taken before the subscriptions were introduced
+                        subscriptions: subscriptions.unwrap_or_default(),
+                        // Absent in snapshots taken before the failed trigger executions were recorded
+                        dead_letters: dead_letters.unwrap_or_default(),
+                        delegations,
+                        incoming_delegations,
+                        // Absent in snapshots taken before allowances could be approved
+                        allowances: allowances.unwrap_or_default(),
+                        // Absent in snapshots taken before accounts could exchange messages
+                        messages,
+                        message_expiries,
+                        // Absent in snapshots taken before assets could be escrowed
+                        escrows: escrows.unwrap_or_default(),
+                        grant_expiries,
+                        grants_by_expiry,
                         triggers: triggers
                             .ok_or_else(|| serde::de::Error::missing_field("triggers"))?,
                         executor: executor
@@ -2282,10 +4325,26 @@ pub(crate) mod deserialize {
                 &[
                     "parameters",
                     "peers",
+                    "pending_peer_changes",
                     "domains",
                     "roles",
                     "account_permissions",
                     "account_roles",
+                    "role_holders",
+                    "tagged_accounts",
+                    "tombstones",
+                    "asset_history",
+                    "asset_history_horizons",
+                    "subscriptions",
+                    "dead_letters",
+                    "delegations",
+                    "incoming_delegations",
+                    "allowances",
+                    "messages",
+                    "message_expiries",
+                    "escrows",
+                    "grant_expiries",
+                    "grants_by_expiry",
                     "triggers",
                     "executor",
                     "executor_data_model",
@@ -2378,6 +4437,9 @@ pub(crate) mod deserialize {
                         #[cfg(feature = "telemetry")]
                         telemetry: self.loader.telemetry,
                         engine,
+                        retention: None,
+                        asset_history_pruning: parking_lot::Mutex::default(),
+                        cold_store: None,
                         view_lock: parking_lot::RwLock::new(()),
                     })
                 }
@@ -2402,12 +4464,17 @@ pub(crate) mod deserialize {
 mod tests {
     use core::num::NonZeroU64;
 
+    use iroha_config::{base::WithOrigin, parameters::actual::Tiering};
     use iroha_test_samples::gen_account_in;
 
     use super::*;
     use crate::{
-        block::ValidBlock, query::store::LiveQueryStore, role::RoleIdWithOwner,
+        block::ValidBlock,
+        query::store::LiveQueryStore,
+        role::{RoleHolder, RoleIdWithOwner},
+        smartcontracts::ValidSingularQuery,
         sumeragi::network_topology::Topology,
+        tag::TaggedAccount,
     };
 
     /// Used to inject faulty payload for testing
@@ -2508,6 +4575,204 @@ mod tests {
         }
     }
 
+    #[test]
+    fn account_role_range() {
+        let role_id: RoleId = "1".parse().unwrap();
+        let holders = [
+            RoleHolder::new(role_id.clone(), gen_account_in("wonderland").0),
+            RoleHolder::new(role_id.clone(), gen_account_in("0").0),
+            RoleHolder::new("0".parse().unwrap(), gen_account_in("wonderland").0),
+            RoleHolder::new("2".parse().unwrap(), gen_account_in("wonderland").0),
+        ]
+        .map(|holder| (holder, ()));
+        let map = Storage::from_iter(holders);
+
+        let view = map.view();
+        let range = view
+            .range(AccountIdByRoleBounds::new(&role_id))
+            .collect::<Vec<_>>();
+        assert_eq!(range.len(), 2);
+        for (holder, ()) in range {
+            assert_eq!(&holder.id, &role_id);
+        }
+    }
+
+    #[test]
+    fn asset_history_is_bounded_by_retention() {
+        let height = |height| NonZeroU64::new(height).unwrap();
+        let history = [1, 5, 10, 20].map(height);
+        let retention = |max_age: Option<u64>, max_entries: Option<usize>| Retention {
+            asset_history_max_age_blocks: max_age.and_then(NonZeroU64::new),
+            asset_history_max_entries: max_entries.and_then(NonZeroUsize::new),
+            transactions_max_age_blocks: None,
+            events_max_entries: nonzero!(10_usize),
+            compact_every_blocks: height(100),
+        };
+
+        assert_eq!(asset_history_excess(&history, &retention(None, None), 0), 0);
+        assert_eq!(
+            asset_history_excess(&history, &retention(None, Some(3)), 0),
+            1
+        );
+        // The change at height 5 is kept as the quantity at heights 7 to 9
+        assert_eq!(
+            asset_history_excess(&history, &retention(Some(15), None), 7),
+            1
+        );
+        assert_eq!(
+            asset_history_excess(&history, &retention(Some(15), Some(1)), 7),
+            3
+        );
+        // The latest change is never dropped
+        assert_eq!(
+            asset_history_excess(&history, &retention(Some(1), None), 99),
+            3
+        );
+    }
+
+    #[tokio::test]
+    async fn pruned_asset_history_is_not_queried() {
+        let kura = Kura::blank_kura_for_testing();
+        let query_handle = LiveQueryStore::start_test();
+        let mut state = State::new(World::default(), kura, query_handle);
+        state.set_retention(Retention {
+            asset_history_max_age_blocks: None,
+            asset_history_max_entries: NonZeroUsize::new(2),
+            transactions_max_age_blocks: None,
+            events_max_entries: nonzero!(10_usize),
+            compact_every_blocks: nonzero!(1_u64),
+        });
+        let height = |height| NonZeroU64::new(height).unwrap();
+        let rose: AssetDefinitionId = "rose#wonderland".parse().unwrap();
+        let asset_id = |account_id| AssetId::new(rose.clone(), account_id);
+        let (alice_id, _) = gen_account_in("wonderland");
+        let (bob_id, _) = gen_account_in("wonderland");
+
+        let block = new_dummy_block_with_payload(|_| {});
+        let mut state_block = state.block(block.as_ref().header());
+        for changed_at in [1, 5, 10] {
+            state_block.world.asset_history.insert(
+                AssetChange::new(asset_id(alice_id.clone()), height(changed_at)),
+                Numeric::from(changed_at),
+            );
+        }
+        // The history of another asset is not affected
+        state_block.world.asset_history.insert(
+            AssetChange::new(asset_id(bob_id.clone()), height(2)),
+            Numeric::from(2_u64),
+        );
+        state_block.commit();
+
+        // The history found beyond the retention in the background is dropped by the next block
+        state.compact_asset_history();
+        let mut state_block = state.block(block.as_ref().header());
+        state_block.record_asset_history(height(11));
+        state_block.commit();
+
+        let view = state.view();
+        assert_eq!(
+            view.world
+                .asset_quantity_at(&asset_id(alice_id.clone()), height(7)),
+            Ok(Numeric::from(5_u64))
+        );
+        assert_eq!(
+            view.world
+                .asset_quantity_at(&asset_id(alice_id.clone()), height(3)),
+            Err(QueryExecutionFail::Pruned(height(5)))
+        );
+        assert_eq!(
+            view.world
+                .asset_quantity_at(&asset_id(bob_id.clone()), height(3)),
+            Ok(Numeric::from(2_u64))
+        );
+        assert_eq!(
+            view.world.asset_quantity_at(&asset_id(bob_id), height(1)),
+            Ok(Numeric::ZERO)
+        );
+        // The quantities are known up to the latest committed block only
+        let not_committed = height(view.height() as u64 + 1);
+        assert_eq!(
+            FindAssetQuantityAt {
+                asset_id: asset_id(alice_id.clone()),
+                height: not_committed,
+            }
+            .execute(&view),
+            Err(QueryExecutionFail::NotCommitted(not_committed))
+        );
+        assert_eq!(
+            view.world
+                .asset_history_iter(&asset_id(alice_id))
+                .collect::<Vec<_>>(),
+            [height(5), height(10)]
+        );
+    }
+
+    #[tokio::test]
+    async fn dormant_accounts_stay_on_the_disk_when_serialized() {
+        let dir = tempfile::tempdir().unwrap();
+        let (alice_id, _) = gen_account_in("wonderland");
+        let world = World::with([], [Account::new(alice_id.clone()).build(&alice_id)], []);
+        let kura = Kura::blank_kura_for_testing();
+        let query_handle = LiveQueryStore::start_test();
+        let mut state = State::new(world, kura, query_handle);
+        let dormant_after_blocks = nonzero!(10_u64);
+        state.set_cold_store(
+            ColdStore::from_config(&Tiering {
+                dormant_after_blocks: Some(dormant_after_blocks),
+                store_dir: WithOrigin::inline(dir.path().to_path_buf()),
+            })
+            .unwrap()
+            .unwrap(),
+        );
+        let accounts = serde_json::to_value(&state).unwrap()["world"]["accounts"].take();
+
+        let block = new_dummy_block_with_payload(|_| {});
+        let mut state_block = state.block(block.as_ref().header());
+        state_block.tier_accounts(dormant_after_blocks);
+        state_block.commit();
+        assert!(state
+            .view()
+            .world
+            .accounts
+            .get(&alice_id)
+            .unwrap()
+            .is_evicted());
+
+        assert_eq!(
+            serde_json::to_value(&state).unwrap()["world"]["accounts"],
+            accounts
+        );
+        assert!(state
+            .view()
+            .world
+            .accounts
+            .get(&alice_id)
+            .unwrap()
+            .is_evicted());
+    }
+
+    #[test]
+    fn account_tag_range() {
+        let tag: Name = "exchange".parse().unwrap();
+        let tagged = [
+            TaggedAccount::new(tag.clone(), gen_account_in("wonderland").0),
+            TaggedAccount::new(tag.clone(), gen_account_in("0").0),
+            TaggedAccount::new("custodian".parse().unwrap(), gen_account_in("wonderland").0),
+            TaggedAccount::new("fund".parse().unwrap(), gen_account_in("wonderland").0),
+        ]
+        .map(|tagged| (tagged, ()));
+        let map = Storage::from_iter(tagged);
+
+        let view = map.view();
+        let range = view
+            .range(AccountIdByTagBounds::new(&tag))
+            .collect::<Vec<_>>();
+        assert_eq!(range.len(), 2);
+        for (tagged, ()) in range {
+            assert_eq!(&tagged.tag, &tag);
+        }
+    }
+
     #[test]
     fn account_domain_range() {
         let accounts = [
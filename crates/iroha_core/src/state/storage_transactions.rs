@@ -52,6 +52,15 @@ impl TransactionsStorage {
         }
     }
 
+    /// Drop the `transactions` committed before the latest block from the storage,
+    /// returning the number of the dropped ones
+    pub fn prune(&self, transactions: impl IntoIterator<Item = Key>) -> usize {
+        transactions
+            .into_iter()
+            .filter(|transaction| self.blocks.remove(transaction).is_some())
+            .count()
+    }
+
     /// Create persistent view of storage at certain point in time
     pub fn view(&self) -> TransactionsView {
         TransactionsView {
@@ -399,6 +408,26 @@ mod tests {
         assert_eq!(view2.get(&k0), Some(v1));
     }
 
+    #[test]
+    fn prune() {
+        let [k0, k1, k2] = get_keys();
+        let [v1, v2] = get_values();
+
+        let storage = TransactionsStorage::new();
+        for (keys, value) in [(&[k0, k1][..], v1), (&[k2][..], v2)] {
+            let mut block = storage.block();
+            insert_keys(&mut block, keys, value);
+            block.commit();
+        }
+
+        // Transactions of the latest block aren't dropped
+        assert_eq!(storage.prune([k0, k2]), 1);
+        let view = storage.view();
+        assert_eq!(view.get(&k0), None);
+        assert_eq!(view.get(&k1), Some(v1));
+        assert_eq!(view.get(&k2), Some(v2));
+    }
+
     #[test]
     fn serialization() {
         fn assert_views_equal(view1: &TransactionsView, view2: &TransactionsView, keys: &[Key]) {
@@ -7,7 +7,11 @@ use iroha_futures::supervisor::ShutdownSignal;
 use iroha_p2p::UpdateTopology;
 use tracing::{span, Level};
 
-use super::{view_change::ProofBuilder, *};
+use super::{
+    proposal_check::{run_checks, ProposalChecks},
+    view_change::ProofBuilder,
+    *,
+};
 #[cfg(feature = "telemetry")]
 use crate::telemetry::Telemetry;
 use crate::{
@@ -40,6 +44,11 @@ pub struct Sumeragi {
     /// Only used in testing. Causes the genesis peer to withhold blocks when it
     /// is the proxy tail.
     pub debug_force_soft_fork: bool,
+    /// Only used in testing. Causes every block to be executed twice
+    /// to detect nondeterministic execution.
+    pub debug_verify_determinism: bool,
+    /// Peer-local checks consulted before voting for a block proposal
+    pub proposal_checks: ProposalChecks,
     /// The current network topology.
     pub topology: Topology,
     /// In order to *be fast*, we must minimize communication with
@@ -357,6 +366,13 @@ impl Sumeragi {
         block: CommittedBlock,
         mut state_block: StateBlock<'_>,
     ) {
+        let _span = span!(
+            Level::INFO,
+            "block_commit",
+            height=%block.as_ref().header().height(),
+            hash=%block.as_ref().hash()
+        )
+        .entered();
         let prev_role = self.role();
 
         self.topology
@@ -407,6 +423,32 @@ impl Sumeragi {
         });
     }
 
+    /// Execute `block` once more from scratch if `debug_verify_determinism` is set
+    /// and report if the emitted events or transaction results differ from the first execution.
+    fn verify_determinism<'state>(
+        &self,
+        block: &SignedBlock,
+        state: &'state State,
+        state_block: StateBlock<'state>,
+    ) -> StateBlock<'state> {
+        if !self.debug_verify_determinism {
+            return state_block;
+        }
+
+        let (state_block, mismatch) = reexecute_block(block, state, state_block);
+        if let Some((expected, actual)) = mismatch {
+            error!(
+                peer_id=%self.peer,
+                block=%block.hash(),
+                %expected,
+                %actual,
+                "Block execution is nondeterministic"
+            );
+        }
+
+        state_block
+    }
+
     fn validate_block<'state>(
         &self,
         block: SignedBlock,
@@ -425,7 +467,10 @@ impl Sumeragi {
             false,
         )
         .unpack(|e| self.send_event(e))
-        .map(|(block, state_block)| VotingBlock::new(block, state_block))
+        .map(|(block, state_block)| {
+            let state_block = self.verify_determinism(block.as_ref(), state, state_block);
+            VotingBlock::new(block, state_block)
+        })
         .map_err(|(block, error)| {
             warn!(
                 peer_id=%self.peer,
@@ -438,6 +483,23 @@ impl Sumeragi {
         .ok()
     }
 
+    /// Whether any of the proposal checks asks to abstain from voting for the `block`
+    fn abstains_from_voting(&self, block: &ValidBlock) -> bool {
+        let Err((check, reason)) = run_checks(&self.proposal_checks, block.as_ref()) else {
+            return false;
+        };
+
+        warn!(
+            peer_id=%self.peer,
+            role=%self.role(),
+            block=%block.as_ref().hash(),
+            %check,
+            %reason,
+            "Abstained from voting for the block"
+        );
+        true
+    }
+
     fn prune_view_change_proofs_and_calculate_current_index(
         &self,
         latest_block: HashOf<BlockHeader>,
@@ -569,17 +631,20 @@ impl Sumeragi {
                 if let Some(mut valid_block) =
                     self.validate_block(block, state, topology, genesis_account, voting_block)
                 {
-                    valid_block.block.sign(&self.key_pair, topology);
+                    // NOTE: Abstaining peer keeps the block to apply it if it's committed anyway
+                    if !self.abstains_from_voting(&valid_block.block) {
+                        valid_block.block.sign(&self.key_pair, topology);
 
-                    let msg = BlockSigned::from(&valid_block.block);
-                    self.broadcast_packet_to(msg, [topology.proxy_tail()]);
+                        let msg = BlockSigned::from(&valid_block.block);
+                        self.broadcast_packet_to(msg, [topology.proxy_tail()]);
 
-                    info!(
-                        peer_id=%self.peer,
-                        role=%self.role(),
-                        block=%valid_block.block.as_ref().hash(),
-                        "Voted for the block"
-                    );
+                        info!(
+                            peer_id=%self.peer,
+                            role=%self.role(),
+                            block=%valid_block.block.as_ref().hash(),
+                            "Voted for the block"
+                        );
+                    }
                     *voting_block = Some(valid_block);
                 }
             }
@@ -599,7 +664,7 @@ impl Sumeragi {
                 if let Some(mut valid_block) =
                     self.validate_block(block, state, topology, genesis_account, voting_block)
                 {
-                    if view_change_index >= 1 {
+                    if view_change_index >= 1 && !self.abstains_from_voting(&valid_block.block) {
                         valid_block.block.sign(&self.key_pair, topology);
 
                         let msg = BlockSigned::from(&valid_block.block);
@@ -636,6 +701,8 @@ impl Sumeragi {
                         }
                     }
 
+                    // NOTE: Abstaining proxy tail neither commits the block nor lets it be committed
+                    valid_block.abstained = self.abstains_from_voting(&valid_block.block);
                     *voting_block = self.try_commit_block(valid_block, is_genesis_peer);
                 }
             }
@@ -827,6 +894,10 @@ impl Sumeragi {
     ) -> Option<VotingBlock<'state>> {
         assert_eq!(self.role(), Role::ProxyTail);
 
+        if voting_block.abstained {
+            return Some(voting_block);
+        }
+
         let votes_count = voting_block.block.as_ref().signatures().len();
         if votes_count + 1 >= self.topology.min_votes_for_commit() {
             voting_block.block.sign(&self.key_pair, &self.topology);
@@ -899,9 +970,28 @@ impl Sumeragi {
         let block_expected = tx_cache_non_empty || !prev_block_is_empty;
 
         if tx_cache_full || block_expected && (view_change_in_progress || deadline_reached) {
+            let max_heavy_instructions = state
+                .world
+                .view()
+                .parameters
+                .block
+                .max_heavy_instructions
+                .get();
+            let mut heavy_instructions = 0_u64;
             let transactions = self
                 .transaction_cache
                 .iter()
+                .filter(|tx| {
+                    let tx_heavy_instructions = tx.heavy_instructions();
+                    let fits = heavy_instructions.saturating_add(tx_heavy_instructions)
+                        <= max_heavy_instructions;
+                    if fits {
+                        heavy_instructions += tx_heavy_instructions;
+                    }
+                    // Transactions which can never fit are proposed anyway to be rejected,
+                    // the rest stay in the cache until the following blocks
+                    fits || max_heavy_instructions < tx_heavy_instructions
+                })
                 .map(|tx| tx.deref().clone())
                 .collect::<Vec<_>>();
 
@@ -912,6 +1002,12 @@ impl Sumeragi {
                 )
                 .sign(self.key_pair.private_key())
                 .unpack(|e| self.send_event(e));
+            let _span = span!(
+                Level::INFO,
+                "block_proposal",
+                height=%unverified_block.header().height()
+            )
+            .entered();
             info!(
                 peer_id=%self.peer,
                 block_hash=%unverified_block.header().hash(),
@@ -929,6 +1025,7 @@ impl Sumeragi {
             let block = unverified_block
                 .validate_and_record_transactions(&mut state_block)
                 .unpack(|e| self.send_event(e));
+            let state_block = self.verify_determinism(block.as_ref(), state, state_block);
 
             *voting_block = if self.topology.is_consensus_required().is_some() {
                 Some(VotingBlock::new(block, state_block))
@@ -959,6 +1056,35 @@ pub enum ReceiveNetworkPacketError {
     ChannelDisconnected,
 }
 
+/// Execute `block` once more from scratch in place of the `state_block` it was executed in.
+///
+/// Returns the hashes of the events emitted by both executions, see [`StateBlock::events_hash`],
+/// if either they or the transaction results differ.
+fn reexecute_block<'state>(
+    block: &SignedBlock,
+    state: &'state State,
+    state_block: StateBlock<'state>,
+) -> (
+    StateBlock<'state>,
+    Option<(
+        HashOf<Vec<iroha_data_model::events::EventBox>>,
+        HashOf<Vec<iroha_data_model::events::EventBox>>,
+    )>,
+) {
+    let expected = state_block.events_hash();
+    // Release block writer before creating new one
+    drop(state_block);
+    let mut state_block = state.block(block.header());
+    let reexecuted = ValidBlock::validate_unchecked(block.clone(), &mut state_block).unpack(|_| {});
+    let actual = state_block.events_hash();
+
+    let mismatch = (expected != actual
+        || block.header().result_merkle_root()
+            != reexecuted.as_ref().header().result_merkle_root())
+    .then_some((expected, actual));
+    (state_block, mismatch)
+}
+
 #[allow(clippy::too_many_arguments)]
 fn reset_state(
     peer_id: &PeerId,
@@ -1571,6 +1697,35 @@ mod tests {
         (state, kura, block, genesis_account)
     }
 
+    #[test]
+    async fn reexecuted_block_emits_same_events() {
+        let chain_id = ChainId::from("00000000-0000-0000-0000-000000000000");
+
+        let (leader_public_key, leader_private_key) = KeyPair::random().into_parts();
+        let peer_id = PeerId::new(leader_public_key);
+        let topology = Topology::new(vec![peer_id]);
+        let (state, _, unverified_block, _) =
+            create_data_for_test(&chain_id, &topology, &leader_private_key);
+
+        let mut state_block = state.block(unverified_block.header());
+        let committed_block = unverified_block
+            .validate_and_record_transactions(&mut state_block)
+            .unpack(|_| {})
+            .commit(&topology)
+            .unpack(|_| {})
+            .expect("Block is valid");
+        let block = committed_block.as_ref();
+
+        let (state_block, mismatch) = reexecute_block(block, &state, state_block);
+        assert!(mismatch.is_none());
+        drop(state_block);
+
+        // The block wasn't executed in this state block, so no events were emitted
+        let state_block = state.block(block.header());
+        let (_, mismatch) = reexecute_block(block, &state, state_block);
+        assert!(mismatch.is_some());
+    }
+
     #[test]
     async fn block_sync_invalid_block() {
         let chain_id = ChainId::from("00000000-0000-0000-0000-000000000000");
@@ -1856,4 +2011,26 @@ mod tests {
         assert!(matches!(result, Err((_, BlockSyncError::BlockNotValid(_)))));
         assert!(voting_block.is_some());
     }
+
+    #[test]
+    async fn proposal_checks_abstain_from_oversized_block() {
+        use crate::sumeragi::proposal_check::{run_checks, MaxTransactions, ProposalCheck};
+
+        let chain_id = ChainId::from("00000000-0000-0000-0000-000000000000");
+
+        let (leader_public_key, leader_private_key) = KeyPair::random().into_parts();
+        let peer_id = PeerId::new(leader_public_key);
+        let topology = Topology::new(vec![peer_id]);
+        let (_state, _, block, _) = create_data_for_test(&chain_id, &topology, &leader_private_key);
+        let block: SignedBlock = block.into();
+
+        let checks: Vec<Arc<dyn ProposalCheck>> =
+            vec![Arc::new(MaxTransactions(nonzero!(2_usize)))];
+        assert!(run_checks(&checks, &block).is_ok());
+
+        let checks: Vec<Arc<dyn ProposalCheck>> =
+            vec![Arc::new(MaxTransactions(nonzero!(1_usize)))];
+        let (check, _reason) = run_checks(&checks, &block).unwrap_err();
+        assert_eq!(check, "max_transactions");
+    }
 }
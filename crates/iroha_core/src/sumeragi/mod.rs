@@ -25,9 +25,10 @@ use crate::{
 pub mod main_loop;
 pub mod message;
 pub mod network_topology;
+pub mod proposal_check;
 pub mod view_change;
 
-use self::{message::*, view_change::ProofChain};
+use self::{message::*, proposal_check::ProposalChecks, view_change::ProofChain};
 #[cfg(feature = "telemetry")]
 use crate::telemetry::Telemetry;
 use crate::{
@@ -140,9 +141,11 @@ impl SumeragiStartArgs {
     #[allow(clippy::too_many_lines)]
     pub fn start(self, shutdown_signal: ShutdownSignal) -> (SumeragiHandle, Child) {
         let Self {
-            config: SumeragiConfig {
-                debug_force_soft_fork,
-            },
+            config:
+                SumeragiConfig {
+                    debug_force_soft_fork,
+                    debug_verify_determinism,
+                },
             common_config,
             events_sender,
             state,
@@ -152,6 +155,7 @@ impl SumeragiStartArgs {
             peers_gossiper,
             genesis_network,
             block_count: BlockCount(block_count),
+            proposal_checks,
             #[cfg(feature = "telemetry")]
                 telemetry: metrics,
         } = self;
@@ -165,6 +169,13 @@ impl SumeragiStartArgs {
         {
             let state_view = state.view();
             let skip_block_count = state_view.height();
+            if skip_block_count < block_count {
+                info!(
+                    from = skip_block_count + 1,
+                    to = block_count,
+                    "Replaying blocks stored in Kura"
+                );
+            }
             blocks_iter = (skip_block_count + 1..=block_count).map(|block_height| {
                 NonZeroUsize::new(block_height).and_then(|height| kura.get_block(height)).expect(
                     "Sumeragi should be able to load the block that was reported as presented. \
@@ -189,6 +200,7 @@ impl SumeragiStartArgs {
             genesis_network.public_key.clone(),
         );
 
+        let mut last_progress_report = Instant::now();
         for block in blocks_iter {
             let mut state_block = state.block(block.header());
             SumeragiHandle::replay_block(
@@ -201,6 +213,15 @@ impl SumeragiStartArgs {
             );
 
             state_block.commit();
+
+            if last_progress_report.elapsed() >= REPLAY_PROGRESS_INTERVAL {
+                info!(
+                    height = %block.header().height(),
+                    of = block_count,
+                    "Replaying blocks stored in Kura"
+                );
+                last_progress_report = Instant::now();
+            }
         }
 
         info!("Sumeragi has finished loading blocks and setting up the state");
@@ -218,6 +239,8 @@ impl SumeragiStartArgs {
             control_message_receiver,
             message_receiver,
             debug_force_soft_fork,
+            debug_verify_determinism,
+            proposal_checks,
             topology,
             transaction_cache: Vec::new(),
             #[cfg(feature = "telemetry")]
@@ -257,6 +280,8 @@ pub const TX_RETRIEVAL_INTERVAL: Duration = Duration::from_millis(200);
 pub const PEERS_CONNECT_INTERVAL: Duration = Duration::from_secs(1);
 /// The interval of telemetry updates.
 pub const TELEMETRY_INTERVAL: Duration = Duration::from_secs(5);
+/// Minimal interval between progress reports while replaying blocks on startup
+pub const REPLAY_PROGRESS_INTERVAL: Duration = Duration::from_secs(5);
 
 /// Structure represents a block that is currently in discussion.
 pub struct VotingBlock<'state> {
@@ -266,6 +291,8 @@ pub struct VotingBlock<'state> {
     pub voted_at: Instant,
     /// State after applying transactions to it but before it was committed
     pub state_block: StateBlock<'state>,
+    /// Whether this peer abstains from voting for this block, see [`proposal_check`]
+    pub abstained: bool,
 }
 
 impl AsRef<ValidBlock> for VotingBlock<'_> {
@@ -281,6 +308,7 @@ impl VotingBlock<'_> {
             block,
             voted_at: Instant::now(),
             state_block,
+            abstained: false,
         }
     }
 }
@@ -298,6 +326,7 @@ pub struct SumeragiStartArgs {
     pub peers_gossiper: PeersGossiperHandle,
     pub genesis_network: GenesisWithPubKey,
     pub block_count: BlockCount,
+    pub proposal_checks: ProposalChecks,
     #[cfg(feature = "telemetry")]
     pub telemetry: Telemetry,
 }
@@ -0,0 +1,82 @@
+//! Peer-local checks of the received block proposals, consulted before voting.
+//!
+//! The checks let an operator plug in policies that aren't part of the consensus rules,
+//! e.g. an external sanction list or a stricter size policy, without forking sumeragi.
+//!
+//! # Determinism
+//!
+//! The checks are *not* part of block validation: different peers may run different checks
+//! or see different external data, so a failed check never rejects the block. Instead the peer
+//! abstains, i.e. keeps the block but doesn't sign it. If the rest of the network collects enough
+//! votes, the block is committed and applied by this peer as usual. If too many peers abstain,
+//! the round ends with a view change, as if the votes were lost.
+//!
+//! Validating and observing peers consult the checks before signing a proposal received from
+//! the leader, and the proxy tail before signing and committing it. The leader doesn't, since
+//! it creates the proposal itself.
+//!
+//! The checks are configured in `[sumeragi.proposal_checks]`, see [`from_config`].
+
+use std::{fmt::Debug, num::NonZeroUsize, sync::Arc};
+
+use iroha_config::parameters::actual::Sumeragi as Config;
+use iroha_data_model::block::SignedBlock;
+
+/// Check of a block proposal run by this peer before voting for it
+pub trait ProposalCheck: Debug + Send + Sync {
+    /// Name of the check, reported when the peer abstains
+    fn name(&self) -> &str;
+
+    /// Inspect the `block` which already passed the validation.
+    ///
+    /// # Errors
+    /// Reason to abstain from voting for the `block`
+    fn check(&self, block: &SignedBlock) -> Result<(), String>;
+}
+
+/// Checks consulted by sumeragi in the order they were added
+pub type ProposalChecks = Vec<Arc<dyn ProposalCheck>>;
+
+/// Checks enabled in the `config`
+pub fn from_config(config: &Config) -> ProposalChecks {
+    let mut checks: ProposalChecks = Vec::new();
+    if let Some(max_transactions) = config.proposal_max_transactions {
+        checks.push(Arc::new(MaxTransactions(max_transactions)));
+    }
+    checks
+}
+
+/// Run the `checks` against the `block`, stopping at the first failed one.
+///
+/// # Errors
+/// Name of the failed check and the reason it gave
+pub fn run_checks<'check>(
+    checks: &'check [Arc<dyn ProposalCheck>],
+    block: &SignedBlock,
+) -> Result<(), (&'check str, String)> {
+    checks
+        .iter()
+        .try_for_each(|check| check.check(block).map_err(|reason| (check.name(), reason)))
+}
+
+/// Abstain from voting for blocks with more than the given number of transactions
+#[derive(Debug, Clone, Copy)]
+pub struct MaxTransactions(pub NonZeroUsize);
+
+impl ProposalCheck for MaxTransactions {
+    fn name(&self) -> &str {
+        "max_transactions"
+    }
+
+    fn check(&self, block: &SignedBlock) -> Result<(), String> {
+        let count = block.external_transactions().len();
+        if count > self.0.get() {
+            return Err(format!(
+                "block has {count} transactions, the limit is {}",
+                self.0
+            ));
+        }
+
+        Ok(())
+    }
+}
@@ -1,14 +1,24 @@
 //! Metrics and status reporting
 
-use std::{num::NonZeroUsize, sync::Arc, time::Duration};
+use std::{
+    collections::{BTreeMap, VecDeque},
+    num::NonZeroUsize,
+    sync::Arc,
+    time::Duration,
+};
 
 #[cfg(debug_assertions)]
 use iroha_crypto::HashOf;
-use iroha_data_model::block::BlockHeader;
+use iroha_data_model::{
+    block::{BlockHeader, SignedBlock},
+    domain::DomainId,
+    isi::InstructionType,
+    transaction::Executable,
+};
 use iroha_futures::supervisor::{Child, OnShutdown};
 use iroha_p2p::OnlinePeers;
 use iroha_primitives::time::TimeSource;
-use iroha_telemetry::metrics::Metrics;
+use iroha_telemetry::metrics::{InstructionStats, Metrics};
 use mv::storage::StorageReadOnly;
 use tokio::sync::{mpsc, oneshot, watch, RwLock};
 
@@ -36,12 +46,36 @@ impl StateTelemetry {
     pub fn observe_tx_amount(&self, value: f64) {
         self.metrics.tx_amounts.observe(value);
     }
+
+    /// Count the changes of the asset quantities dropped from the history by the retention
+    pub fn prune_asset_history(&self, pruned: u64) {
+        self.metrics.asset_history_pruned.inc_by(pruned);
+    }
+
+    /// Report the number of the changes of the asset quantities kept in the history after a compaction
+    pub fn set_asset_history_entries(&self, entries: u64) {
+        self.metrics.asset_history_entries.set(entries);
+    }
+
+    /// Count the expired transactions dropped from the index by the retention
+    pub fn prune_transactions(&self, pruned: u64) {
+        self.metrics.transactions_pruned.inc_by(pruned);
+    }
 }
 
 const CHANNEL_CAPACITY: usize = 1024;
 
+/// Instructions of the blocks created earlier than this are not counted in [`InstructionStats`]
+pub const INSTRUCTION_STATS_RETENTION: Duration = Duration::from_secs(24 * 60 * 60);
+
 enum Message {
-    Sync { reply: oneshot::Sender<()> },
+    Sync {
+        reply: oneshot::Sender<()>,
+    },
+    InstructionStats {
+        window: Duration,
+        reply: oneshot::Sender<InstructionStats>,
+    },
 }
 
 /// Handle to the telemetry state
@@ -92,6 +126,89 @@ impl Telemetry {
         rx.await.expect("metrics actor must be alive");
         &self.metrics
     }
+
+    /// Instructions executed in the blocks created within the `window` ending now,
+    /// counted by type and by the domain of the transaction authority.
+    ///
+    /// The `window` is capped at [`INSTRUCTION_STATS_RETENTION`].
+    pub async fn instruction_stats(&self, window: Duration) -> InstructionStats {
+        let (tx, rx) = oneshot::channel();
+        self.actor
+            .send(Message::InstructionStats { window, reply: tx })
+            .await
+            .expect("metrics actor must be alive");
+        rx.await.expect("metrics actor must be alive")
+    }
+}
+
+/// Instructions executed in a committed block
+struct BlockUsage {
+    created_at: Duration,
+    by_domain: BTreeMap<DomainId, BTreeMap<InstructionType, u64>>,
+}
+
+impl BlockUsage {
+    fn new(block: &SignedBlock) -> Self {
+        let mut by_domain: BTreeMap<_, BTreeMap<_, _>> = BTreeMap::new();
+        for (tx, result) in block.external_transactions().zip(block.results()) {
+            let (Ok(_), Executable::Instructions(instructions)) = (result, tx.instructions())
+            else {
+                continue;
+            };
+            let counts = by_domain
+                .entry(tx.authority().domain().clone())
+                .or_default();
+            for instruction in instructions {
+                *counts
+                    .entry(InstructionType::from(instruction))
+                    .or_default() += 1;
+            }
+        }
+
+        Self {
+            created_at: block.header().creation_time(),
+            by_domain,
+        }
+    }
+}
+
+/// Usage of the blocks committed within [`INSTRUCTION_STATS_RETENTION`], oldest first
+#[derive(Default)]
+struct InstructionUsage(VecDeque<BlockUsage>);
+
+impl InstructionUsage {
+    fn record(&mut self, usage: BlockUsage, now: Duration) {
+        self.0.push_back(usage);
+        let oldest = now.saturating_sub(INSTRUCTION_STATS_RETENTION);
+        while self
+            .0
+            .front()
+            .is_some_and(|usage| usage.created_at < oldest)
+        {
+            self.0.pop_front();
+        }
+    }
+
+    fn stats(&self, now: Duration, window: Duration) -> InstructionStats {
+        let window = window.min(INSTRUCTION_STATS_RETENTION);
+        let since = now.saturating_sub(window);
+        let mut stats = InstructionStats {
+            window_ms: u64::try_from(window.as_millis()).expect("window is capped"),
+            ..InstructionStats::default()
+        };
+
+        for usage in self.0.iter().filter(|usage| usage.created_at >= since) {
+            stats.blocks += 1;
+            for (domain, counts) in &usage.by_domain {
+                let domain_counts = stats.by_domain.entry(domain.clone()).or_default();
+                for (&instruction, &count) in counts {
+                    *domain_counts.entry(instruction).or_default() += count;
+                    *stats.by_type.entry(instruction).or_default() += count;
+                }
+            }
+        }
+        stats
+    }
 }
 
 struct Actor {
@@ -99,11 +216,13 @@ struct Actor {
     last_reported_block: Arc<RwLock<Option<BlockCommitReport>>>,
     last_sync_block: usize,
     online_peers: watch::Receiver<OnlinePeers>,
+    rejected_peers: watch::Receiver<u64>,
     metrics: Arc<Metrics>,
     state: Arc<State>,
     kura: Arc<Kura>,
     queue: Arc<Queue>,
     time_source: TimeSource,
+    instruction_usage: InstructionUsage,
 }
 
 impl Actor {
@@ -114,6 +233,13 @@ impl Actor {
                     self.sync().await;
                     let _ = reply.send(());
                 }
+                Message::InstructionStats { window, reply } => {
+                    self.sync().await;
+                    let stats = self
+                        .instruction_usage
+                        .stats(self.time_source.get_unix_time(), window);
+                    let _ = reply.send(stats);
+                }
             }
         }
     }
@@ -123,6 +249,9 @@ impl Actor {
         self.metrics
             .connected_peers
             .set(self.online_peers.borrow().len() as u64);
+        self.metrics
+            .rejected_peer_connections
+            .set(*self.rejected_peers.borrow());
         self.metrics.queue_size.set(self.queue.tx_len() as u64);
 
         let last_reported_block = {
@@ -154,6 +283,8 @@ impl Actor {
                     break;
                 };
                 block_index += 1;
+                self.instruction_usage
+                    .record(BlockUsage::new(&block), self.time_source.get_unix_time());
 
                 let block_txs_rejected = block.errors().count() as u64;
                 let block_txs_all = block.external_transactions().count() as u64;
@@ -286,6 +417,7 @@ pub fn start(
     kura: Arc<Kura>,
     queue: Arc<Queue>,
     online_peers: watch::Receiver<OnlinePeers>,
+    rejected_peers: watch::Receiver<u64>,
     time_source: TimeSource,
 ) -> (Telemetry, Child) {
     let (actor, handle) = mpsc::channel(CHANNEL_CAPACITY);
@@ -308,7 +440,9 @@ pub fn start(
                     last_sync_block: 0,
                     last_reported_block,
                     online_peers,
+                    rejected_peers,
                     time_source,
+                    instruction_usage: InstructionUsage::default(),
                 }
                 .run(),
             ),
@@ -390,6 +524,7 @@ mod tests {
                 kura.clone(),
                 queue,
                 peers_rx,
+                watch::channel(0).1,
                 time_source.clone(),
             );
 
@@ -553,6 +688,55 @@ mod tests {
         assert_eq!(metrics.last_commit_time_ms.get(), 170 - CORRECTION);
     }
 
+    #[test]
+    fn instruction_stats_are_aggregated_within_window() {
+        let usage =
+            |created_at: u64, domain: &str, instructions: &[(InstructionType, u64)]| BlockUsage {
+                created_at: Duration::from_secs(created_at),
+                by_domain: [(
+                    domain.parse().unwrap(),
+                    instructions.iter().copied().collect(),
+                )]
+                .into_iter()
+                .collect(),
+            };
+        let now = Duration::from_secs(100_000);
+        let mut usage_log = InstructionUsage::default();
+        usage_log.record(
+            usage(1_000, "wonderland", &[(InstructionType::Mint, 5)]),
+            now,
+        );
+        usage_log.record(
+            usage(99_000, "wonderland", &[(InstructionType::Mint, 1)]),
+            now,
+        );
+        usage_log.record(
+            usage(
+                99_500,
+                "looking_glass",
+                &[(InstructionType::Mint, 2), (InstructionType::Transfer, 3)],
+            ),
+            now,
+        );
+
+        // older than the retention
+        assert_eq!(usage_log.0.len(), 2);
+
+        let stats = usage_log.stats(now, Duration::from_secs(60 * 60));
+        assert_eq!(stats.window_ms, 60 * 60 * 1000);
+        assert_eq!(stats.blocks, 2);
+        assert_eq!(stats.by_type[&InstructionType::Mint], 3);
+        assert_eq!(stats.by_type[&InstructionType::Transfer], 3);
+        assert_eq!(
+            stats.by_domain[&"wonderland".parse::<DomainId>().unwrap()],
+            [(InstructionType::Mint, 1)].into_iter().collect()
+        );
+
+        let stats = usage_log.stats(now, Duration::from_secs(700));
+        assert_eq!(stats.blocks, 1);
+        assert_eq!(stats.by_type[&InstructionType::Mint], 2);
+    }
+
     #[test]
     fn genesis_commit_time_is_zero() {
         let (time_handle, time_source) = TimeSource::new_mock(Duration::from_millis(1500));
@@ -0,0 +1,300 @@
+//! Tiering of the rarely changed parts of the [`World`](crate::state::World) between memory and disk.
+//!
+//! The values unchanged for [`Tiering::dormant_after_blocks`] are written to the [`ColdStore`]
+//! and dropped from memory, keeping only their keys and the files they're written to. They are
+//! read back on access and stay in memory until the next eviction, unless changed in the meantime.
+//! A file is shared by the versions of the value in the views of the state taken before the
+//! eviction and in the blocks reverted after it, and removed once none of them refers to it.
+
+use std::{
+    fs, io,
+    num::NonZeroU64,
+    path::{Path, PathBuf},
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc, OnceLock,
+    },
+};
+
+use iroha_config::parameters::actual::Tiering;
+use iroha_logger::prelude::*;
+use parking_lot::Mutex;
+use serde::{
+    de::DeserializeOwned, ser::Error as _, Deserialize, Deserializer, Serialize, Serializer,
+};
+use thiserror::Error;
+
+/// Error which occurs while reading a dormant value back from the [`ColdStore`]
+#[derive(Debug, displaydoc::Display, Error)]
+pub enum ColdStoreError {
+    /// Failed to read a dormant value from {1:?}
+    Read(#[source] io::Error, PathBuf),
+    /// Failed to decode a dormant value read from {1:?}
+    Decode(#[source] serde_json::Error, PathBuf),
+}
+
+/// Directory the dormant values with keys `K` are moved to.
+#[derive(Debug)]
+pub struct ColdStore<K> {
+    dir: Arc<Path>,
+    /// Number of the next file written to the store
+    next_file: AtomicU64,
+    /// Keys of the dormant values read back since the latest eviction
+    woken: Arc<Mutex<Vec<K>>>,
+    /// Values unchanged for this many blocks are moved to the disk.
+    pub dormant_after_blocks: NonZeroU64,
+}
+
+impl<K> ColdStore<K> {
+    /// Prepare the store configured by [`Tiering`], if enabled.
+    ///
+    /// The files left from the previous run are removed, since the state is restored
+    /// from the blocks or a snapshot holding the values themselves.
+    ///
+    /// # Errors
+    ///
+    /// If the directory can't be cleared or created
+    pub fn from_config(config: &Tiering) -> io::Result<Option<Self>> {
+        let Some(dormant_after_blocks) = config.dormant_after_blocks else {
+            return Ok(None);
+        };
+        let dir = config.store_dir.resolve_relative_path();
+        match fs::remove_dir_all(&dir) {
+            Err(error) if error.kind() != io::ErrorKind::NotFound => return Err(error),
+            _ => {}
+        }
+        fs::create_dir_all(&dir)?;
+
+        Ok(Some(Self {
+            dir: dir.into(),
+            next_file: AtomicU64::new(0),
+            woken: Arc::default(),
+            dormant_after_blocks,
+        }))
+    }
+
+    /// Take the keys of the dormant values read back since the previous call, to evict them again
+    pub fn take_woken(&self) -> Vec<K> {
+        core::mem::take(&mut *self.woken.lock())
+    }
+}
+
+/// Value with key `K` kept in memory while it changes and on the disk while it's dormant.
+#[derive(Debug, Clone)]
+pub struct Tiered<K, T> {
+    /// Height of the block which last changed the value.
+    changed_at: u64,
+    hot: OnceLock<Box<T>>,
+    cold: Option<Arc<ColdFile<K>>>,
+}
+
+#[derive(Debug)]
+struct ColdFile<K> {
+    path: PathBuf,
+    key: K,
+    woken: Arc<Mutex<Vec<K>>>,
+}
+
+impl<K> Drop for ColdFile<K> {
+    fn drop(&mut self) {
+        if let Err(error) = fs::remove_file(&self.path) {
+            warn!(%error, path=%self.path.display(), "Failed to remove a dormant value");
+        }
+    }
+}
+
+impl<K, T> Tiered<K, T> {
+    /// Keep the `value` in memory.
+    pub fn new(value: T) -> Self {
+        Self {
+            changed_at: 0,
+            hot: OnceLock::from(Box::new(value)),
+            cold: None,
+        }
+    }
+
+    /// Whether the value is in memory and hasn't changed since the block at `height`
+    pub fn is_dormant(&self, height: u64) -> bool {
+        !self.is_evicted() && self.changed_at <= height
+    }
+
+    /// Whether the value is only on the disk
+    pub fn is_evicted(&self) -> bool {
+        self.hot.get().is_none()
+    }
+
+    /// Height of the block which last changed the value
+    pub fn changed_at(&self) -> u64 {
+        self.changed_at
+    }
+
+    /// Record that the value was changed by the block at `height`
+    pub fn touch(&mut self, height: u64) {
+        self.changed_at = height;
+    }
+}
+
+impl<K: Clone, T: Serialize + DeserializeOwned> Tiered<K, T> {
+    /// Get the value, reading it back from the disk if it's dormant.
+    ///
+    /// # Errors
+    ///
+    /// If the file of the dormant value is missing or corrupt
+    pub fn get(&self) -> Result<&T, ColdStoreError> {
+        if let Some(value) = self.hot.get() {
+            return Ok(value);
+        }
+        let value = self.read()?;
+        if let Some(cold) = &self.cold {
+            cold.woken.lock().push(cold.key.clone());
+        }
+
+        Ok(self.hot.get_or_init(|| Box::new(value)))
+    }
+
+    fn read(&self) -> Result<T, ColdStoreError> {
+        let cold = self
+            .cold
+            .as_ref()
+            .expect("INTERNAL BUG: tiered value is neither in memory nor on the disk");
+        let bytes =
+            fs::read(&cold.path).map_err(|error| ColdStoreError::Read(error, cold.path.clone()))?;
+        serde_json::from_slice(&bytes)
+            .map_err(|error| ColdStoreError::Decode(error, cold.path.clone()))
+    }
+
+    /// Get the value to change it, reading it back from the disk if it's dormant.
+    ///
+    /// The value is written to the disk anew on the next eviction.
+    ///
+    /// # Errors
+    ///
+    /// If the file of the dormant value is missing or corrupt
+    pub fn get_mut(&mut self) -> Result<&mut T, ColdStoreError> {
+        self.get()?;
+        self.cold = None;
+        Ok(self
+            .hot
+            .get_mut()
+            .expect("INTERNAL BUG: value was just read"))
+    }
+
+    /// Move the value with `key` to the `store`, dropping it from memory.
+    ///
+    /// # Errors
+    ///
+    /// If the value can't be written, in which case it's kept in memory
+    pub fn evict(&mut self, store: &ColdStore<K>, key: &K) -> io::Result<()> {
+        if self.cold.is_none() {
+            let value = self
+                .hot
+                .get()
+                .expect("INTERNAL BUG: tiered value is neither in memory nor on the disk");
+            let bytes = serde_json::to_vec(value).map_err(io::Error::other)?;
+            let path = store
+                .dir
+                .join(store.next_file.fetch_add(1, Ordering::Relaxed).to_string());
+            fs::write(&path, bytes)?;
+            self.cold = Some(Arc::new(ColdFile {
+                path,
+                key: key.clone(),
+                woken: Arc::clone(&store.woken),
+            }));
+        }
+        self.hot = OnceLock::new();
+
+        Ok(())
+    }
+}
+
+impl<K: Clone, T: Serialize + DeserializeOwned> Serialize for Tiered<K, T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        // Dormant values are serialized without being kept in memory afterwards
+        match self.hot.get() {
+            Some(value) => value.serialize(serializer),
+            None => self.read().map_err(S::Error::custom)?.serialize(serializer),
+        }
+    }
+}
+
+impl<'de, K, T: Serialize + DeserializeOwned> Deserialize<'de> for Tiered<K, T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        T::deserialize(deserializer).map(Self::new)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use iroha_config::base::WithOrigin;
+
+    use super::*;
+
+    fn store(dir: &Path) -> ColdStore<u32> {
+        ColdStore::from_config(&Tiering {
+            dormant_after_blocks: Some(nonzero_ext::nonzero!(10_u64)),
+            store_dir: WithOrigin::inline(dir.to_path_buf()),
+        })
+        .unwrap()
+        .unwrap()
+    }
+
+    #[test]
+    fn dormant_value_is_read_back() {
+        let dir = tempfile::tempdir().unwrap();
+        let store = store(dir.path());
+        let mut value = Tiered::new(vec!["rose".to_owned()]);
+        value.touch(5);
+        assert!(!value.is_dormant(4));
+        assert!(value.is_dormant(5));
+
+        value.evict(&store, &7).unwrap();
+        assert!(!value.is_dormant(5));
+        let snapshot = value.clone();
+        assert_eq!(value.get().unwrap(), &["rose".to_owned()]);
+        assert!(value.is_dormant(5));
+        assert_eq!(store.take_woken(), [7]);
+
+        value.get_mut().unwrap().push("tulip".to_owned());
+        value.evict(&store, &7).unwrap();
+        assert_eq!(value.get().unwrap().len(), 2);
+        assert_eq!(snapshot.get().unwrap(), &["rose".to_owned()]);
+    }
+
+    #[test]
+    fn file_is_removed_once_not_referred_to() {
+        let dir = tempfile::tempdir().unwrap();
+        let store = store(dir.path());
+        let files = || fs::read_dir(dir.path()).unwrap().count();
+        let mut value = Tiered::new(vec!["rose".to_owned()]);
+
+        value.evict(&store, &7).unwrap();
+        let snapshot = value.clone();
+        value.get_mut().unwrap().push("tulip".to_owned());
+        value.evict(&store, &7).unwrap();
+        assert_eq!(files(), 2);
+
+        drop(snapshot);
+        assert_eq!(files(), 1);
+        // Evicting a value read back reuses its file
+        value.get().unwrap();
+        value.evict(&store, &7).unwrap();
+        assert_eq!(files(), 1);
+        drop(value);
+        assert_eq!(files(), 0);
+    }
+
+    #[test]
+    fn missing_file_is_reported() {
+        let dir = tempfile::tempdir().unwrap();
+        let store = store(dir.path());
+        let mut value = Tiered::new(vec!["rose".to_owned()]);
+        value.evict(&store, &7).unwrap();
+
+        for entry in fs::read_dir(dir.path()).unwrap() {
+            fs::remove_file(entry.unwrap().path()).unwrap();
+        }
+        assert!(matches!(value.get(), Err(ColdStoreError::Read(..))));
+        assert!(matches!(value.get_mut(), Err(ColdStoreError::Read(..))));
+        assert!(serde_json::to_vec(&value).is_err());
+    }
+}
@@ -16,12 +16,12 @@ use iroha_data_model::{
     query::error::FindError,
     transaction::{error::TransactionLimitError, TransactionPayload},
 };
-use iroha_logger::{debug, error};
+use iroha_logger::{debug, error, info_span};
 use iroha_macro::FromVariant;
 use mv::storage::StorageReadOnly;
 
 use crate::{
-    smartcontracts::{wasm, wasm::cache::WasmCache},
+    smartcontracts::{isi::check_metadata_value, wasm, wasm::cache::WasmCache},
     state::{StateBlock, StateTransaction},
 };
 
@@ -133,6 +133,14 @@ impl AcceptedTransaction {
             ));
         }
 
+        for (_, value) in tx.metadata().iter() {
+            check_metadata_value(value, limits).map_err(|error| {
+                AcceptTransactionFail::TransactionLimit(TransactionLimitError {
+                    reason: format!("Transaction metadata: {error}"),
+                })
+            })?;
+        }
+
         match &tx.instructions() {
             Executable::Instructions(instructions) => {
                 if instructions.is_empty() {
@@ -149,13 +157,16 @@ impl AcceptedTransaction {
                     .try_into()
                     .expect("INTERNAL BUG: max instructions exceeds usize::MAX");
 
-                if instructions.len() > instruction_limit {
+                let instruction_count = instructions
+                    .iter()
+                    .map(InstructionBox::instruction_count)
+                    .sum::<usize>();
+                if instruction_count > instruction_limit {
                     return Err(AcceptTransactionFail::TransactionLimit(
                         TransactionLimitError {
                             reason: format!(
                                 "Too many instructions in payload, max number is {}, but got {}",
-                                limits.max_instructions,
-                                instructions.len()
+                                limits.max_instructions, instruction_count
                             ),
                         },
                     ));
@@ -219,10 +230,40 @@ impl AcceptedTransaction {
         Self::validate(&tx, expected_chain_id, max_clock_drift, limits).map(|()| Self(tx))
     }
 
+    /// Whether the time to live of the transaction is over by the time `at`, e.g. the creation time of a block.
+    ///
+    /// Transactions without a time to live never expire.
+    pub fn is_expired(tx: &SignedTransaction, at: Duration) -> bool {
+        tx.time_to_live()
+            .is_some_and(|time_to_live| at.saturating_sub(tx.creation_time()) > time_to_live)
+    }
+
     /// Assume the transaction is acceptable.
     pub fn new_unchecked(tx: SignedTransaction) -> Self {
         Self(tx)
     }
+
+    /// Number of heavyweight instructions, limited per block by
+    /// [`BlockParameters::max_heavy_instructions`](iroha_data_model::parameter::BlockParameters::max_heavy_instructions).
+    ///
+    /// WASM smart contract counts as a single heavyweight instruction.
+    pub fn heavy_instructions(&self) -> u64 {
+        match self.0.instructions() {
+            Executable::Wasm(_) => 1,
+            Executable::Instructions(instructions) => instructions
+                .iter()
+                .filter(|isi| match isi {
+                    InstructionBox::Upgrade(_) => true,
+                    InstructionBox::Register(RegisterBox::Trigger(register)) => {
+                        matches!(register.object.action.executable, Executable::Wasm(_))
+                    }
+                    _ => false,
+                })
+                .count()
+                .try_into()
+                .expect("INTERNAL BUG: instructions in transaction exceed u64::MAX"),
+        }
+    }
 }
 
 impl From<AcceptedTransaction> for SignedTransaction {
@@ -244,7 +285,7 @@ impl AsRef<SignedTransaction> for AcceptedTransaction {
 }
 
 impl StateBlock<'_> {
-    /// Validate and apply the transaction to the state if validation succeeds; leave the state unchanged on failure.
+    /// Validate and apply the transaction to the state if validation succeeds; on failure only record the failed trigger execution, if any, as a dead letter.
     ///
     /// Returns the hash and the result of the transaction -- the trigger sequence on success, or the rejection reason on failure.
     pub fn validate_transaction(
@@ -252,11 +293,20 @@ impl StateBlock<'_> {
         tx: AcceptedTransaction,
         wasm_cache: &mut WasmCache<'_, '_, '_>,
     ) -> (HashOf<TransactionEntrypoint>, TransactionResultInner) {
+        let _span = info_span!(
+            "tx_execution",
+            tx=%tx.as_ref().hash(),
+            height=%self.curr_block.height()
+        )
+        .entered();
         let mut state_transaction = self.transaction();
         let hash = tx.as_ref().hash_as_entrypoint();
         let result = Self::validate_transaction_internal(tx, &mut state_transaction, wasm_cache);
         if result.is_ok() {
             state_transaction.apply();
+        } else if let Some(failure) = state_transaction.failed_trigger.take() {
+            drop(state_transaction);
+            self.record_dead_letter(failure);
         }
 
         (hash, result)
@@ -16,6 +16,7 @@
 //! as it is the only one used by the Iroha p2p transport protocol.
 
 mod chacha20poly1305;
+pub mod sealed;
 
 #[cfg(not(feature = "std"))]
 use alloc::{vec, vec::Vec};
@@ -28,7 +29,7 @@ use displaydoc::Display;
 use rand::{rngs::OsRng, RngCore};
 
 pub use self::chacha20poly1305::ChaCha20Poly1305;
-use crate::SessionKey;
+use crate::{Algorithm, SessionKey};
 
 /// An error that can occur during encryption or decryption
 #[cfg_attr(feature = "std", derive(thiserror::Error))]
@@ -42,6 +43,8 @@ pub enum Error {
     Decryption(AeadError),
     /// Not enough data to decrypt message
     NotEnoughData,
+    /// Keys of `{0}` algorithm can't be used for encryption
+    UnsupportedKey(Algorithm),
 }
 
 // Helpful for generating bytes using the operating system random number generator
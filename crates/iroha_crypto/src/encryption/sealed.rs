@@ -0,0 +1,109 @@
+//! Encryption of messages to the holder of a private key, without a shared secret agreed upfront.
+//!
+//! The message is encrypted with [`ChaCha20Poly1305`] under the key agreed by [`X25519Sha256`]
+//! between a fresh ephemeral key of the sender and the key of the recipient.
+//! The public part of the ephemeral key is prepended to the ciphertext.
+//!
+//! Ed25519 keys are converted to their X25519 counterparts, so that messages can be encrypted
+//! to the signatories of the accounts. Other algorithms are not supported.
+
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
+use super::{ChaCha20Poly1305, Error, SymmetricEncryptor};
+#[cfg(feature = "rand")]
+use crate::KeyGenOption;
+use crate::{
+    kex::{KeyExchangeScheme, X25519Sha256},
+    secrecy::ExposeSecret,
+    Algorithm, PrivateKey, PrivateKeyInner, PublicKey,
+};
+
+fn exchange_public_key(public_key: &PublicKey) -> Result<x25519_dalek::PublicKey, Error> {
+    let (algorithm, payload) = public_key.to_bytes();
+    if algorithm != Algorithm::Ed25519 {
+        return Err(Error::UnsupportedKey(algorithm));
+    }
+    let payload = <[u8; 32]>::try_from(payload).expect("Ed25519 public key is 32 bytes long");
+    let public_key = ed25519_dalek::VerifyingKey::from_bytes(&payload)
+        .expect("payload of the public key is valid");
+
+    Ok(public_key.to_montgomery().to_bytes().into())
+}
+
+fn exchange_private_key(private_key: &PrivateKey) -> Result<x25519_dalek::StaticSecret, Error> {
+    match private_key.0.expose_secret() {
+        PrivateKeyInner::Ed25519(signing_key) => Ok(signing_key.to_scalar_bytes().into()),
+        _ => Err(Error::UnsupportedKey(private_key.algorithm())),
+    }
+}
+
+/// Encrypt `plaintext` so that only the holder of the private key of `recipient` can [`open`] it
+///
+/// # Errors
+///
+/// - If the key of the recipient is not an Ed25519 key
+/// - If nonce generation or encryption fails
+#[cfg(feature = "rand")]
+pub fn seal(recipient: &PublicKey, plaintext: &[u8]) -> Result<Vec<u8>, Error> {
+    let recipient = exchange_public_key(recipient)?;
+    let scheme = X25519Sha256::new();
+    let (ephemeral_public_key, ephemeral_private_key) = scheme.keypair(KeyGenOption::Random);
+    let key = scheme.compute_shared_secret(&ephemeral_private_key, &recipient);
+
+    let ephemeral_public_key = X25519Sha256::encode_public_key(&ephemeral_public_key);
+    let ciphertext = SymmetricEncryptor::<ChaCha20Poly1305>::new_from_session_key(&key)
+        .encrypt_easy(ephemeral_public_key, plaintext)?;
+
+    Ok([ephemeral_public_key, &ciphertext].concat())
+}
+
+/// Decrypt the message [`seal`]ed to the public key of `private_key`
+///
+/// # Errors
+///
+/// - If the key is not an Ed25519 key
+/// - If the message is truncated, tampered with or sealed to another key
+pub fn open(private_key: &PrivateKey, sealed: &[u8]) -> Result<Vec<u8>, Error> {
+    let private_key = exchange_private_key(private_key)?;
+    if sealed.len() < X25519Sha256::PUBLIC_KEY_SIZE {
+        return Err(Error::NotEnoughData);
+    }
+    let (ephemeral_public_key, ciphertext) = sealed.split_at(X25519Sha256::PUBLIC_KEY_SIZE);
+    let key = X25519Sha256::new().compute_shared_secret(
+        &private_key,
+        &X25519Sha256::decode_public_key(ephemeral_public_key.to_vec())
+            .expect("length of the key is checked"),
+    );
+
+    SymmetricEncryptor::<ChaCha20Poly1305>::new_from_session_key(&key)
+        .decrypt_easy(ephemeral_public_key, ciphertext)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::KeyPair;
+
+    #[test]
+    fn sealed_message_is_opened_by_recipient_only() {
+        let recipient = KeyPair::random();
+        let sealed = seal(recipient.public_key(), b"Hidden message").unwrap();
+
+        assert_eq!(
+            open(recipient.private_key(), &sealed).unwrap(),
+            b"Hidden message"
+        );
+        open(KeyPair::random().private_key(), &sealed).unwrap_err();
+        open(recipient.private_key(), &sealed[..16]).unwrap_err();
+    }
+
+    #[test]
+    fn only_ed25519_keys_are_supported() {
+        let recipient = KeyPair::random_with_algorithm(Algorithm::Secp256k1);
+        assert!(matches!(
+            seal(recipient.public_key(), b"Hidden message"),
+            Err(Error::UnsupportedKey(Algorithm::Secp256k1))
+        ));
+    }
+}
@@ -212,6 +212,14 @@ impl<T> SignatureOf<T> {
         Self(Signature::new(private_key, hash.as_ref()), PhantomData)
     }
 
+    /// Adds type information to the signature of a hash, e.g. produced by a remote signer.
+    /// Be careful about using this function since it is not possible to validate the correctness of the conversion.
+    /// Prefer creating new signatures with [`SignatureOf::new`] whenever possible
+    #[must_use]
+    pub fn from_untyped_unchecked(signature: Signature) -> Self {
+        Self(signature, PhantomData)
+    }
+
     /// Verify signature for this hash
     ///
     /// # Errors
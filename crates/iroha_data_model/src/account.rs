@@ -1,7 +1,9 @@
 //! Structures, traits and impls related to `Account`s.
 #[cfg(not(feature = "std"))]
-use alloc::{format, string::String, vec::Vec};
+use alloc::{collections::BTreeSet, format, string::String, vec::Vec};
 use core::str::FromStr;
+#[cfg(feature = "std")]
+use std::collections::BTreeSet;
 
 use derive_more::{Constructor, DebugCustom, Display};
 use iroha_data_model_derive::{model, IdEqOrdHash};
@@ -12,8 +14,8 @@ use serde_with::{DeserializeFromStr, SerializeDisplay};
 
 pub use self::model::*;
 use crate::{
-    domain::prelude::*, metadata::Metadata, HasMetadata, Identifiable, IntoKeyValue, ParseError,
-    PublicKey, Registered, Registrable,
+    domain::prelude::*, metadata::Metadata, name::Name, HasMetadata, Identifiable, IntoKeyValue,
+    ParseError, PublicKey, Registered, Registrable,
 };
 
 #[model]
@@ -75,6 +77,11 @@ mod model {
         pub id: AccountId,
         /// Metadata of this account as a key-value store.
         pub metadata: Metadata,
+        /// Labels of this account, e.g. `exchange`, see [`AddTag`](crate::isi::AddTag).
+        ///
+        /// Unlike metadata, tags are indexed, so the accounts with a tag are found without a scan.
+        #[serde(default)]
+        pub tags: BTreeSet<Name>,
     }
 
     /// Builder which should be submitted in a transaction to create a new [`Account`]
@@ -100,6 +107,8 @@ pub struct AccountEntry<'world> {
     pub id: &'world AccountId,
     /// Metadata of this account as a key-value store.
     pub metadata: &'world Metadata,
+    /// Labels of this account.
+    pub tags: &'world BTreeSet<Name>,
 }
 
 /// [`Account`] without `id`.
@@ -109,6 +118,9 @@ pub struct AccountEntry<'world> {
 pub struct AccountValue {
     /// Metadata of this account as a key-value store.
     pub metadata: Metadata,
+    /// Labels of this account.
+    #[serde(default)]
+    pub tags: BTreeSet<Name>,
 }
 
 impl AccountId {
@@ -133,6 +145,12 @@ impl Account {
     pub fn signatory(&self) -> &PublicKey {
         &self.id.signatory
     }
+
+    /// Check if the account is labeled with the `tag`.
+    #[inline]
+    pub fn has_tag(&self, tag: &Name) -> bool {
+        self.tags.contains(tag)
+    }
 }
 
 impl NewAccount {
@@ -159,6 +177,7 @@ impl NewAccount {
         Account {
             id: self.id,
             metadata: self.metadata,
+            tags: BTreeSet::new(),
         }
     }
 }
@@ -187,6 +206,7 @@ impl Registrable for NewAccount {
         Account {
             id: self.id,
             metadata: self.metadata,
+            tags: BTreeSet::new(),
         }
     }
 }
@@ -224,6 +244,7 @@ impl<'world> AccountEntry<'world> {
         Self {
             id,
             metadata: &value.metadata,
+            tags: &value.tags,
         }
     }
 
@@ -237,11 +258,17 @@ impl<'world> AccountEntry<'world> {
         self.metadata
     }
 
+    /// Getter for `tags`
+    pub fn tags(&self) -> &BTreeSet<Name> {
+        self.tags
+    }
+
     /// Converts to `Account`
     pub fn to_owned(&self) -> Account {
         Account {
             id: self.id.clone(),
             metadata: self.metadata.clone(),
+            tags: self.tags.clone(),
         }
     }
 }
@@ -252,6 +279,7 @@ impl IntoKeyValue for Account {
     fn into_key_value(self) -> (Self::Key, Self::Value) {
         let value = AccountValue {
             metadata: self.metadata,
+            tags: self.tags,
         };
         (self.id, value)
     }
@@ -2,7 +2,7 @@
 //! instructions implementations.
 #[cfg(not(feature = "std"))]
 use alloc::{collections::btree_map, format, string::String, vec::Vec};
-use core::{fmt, str::FromStr};
+use core::{fmt, num::NonZeroU64, str::FromStr};
 #[cfg(feature = "std")]
 use std::collections::btree_map;
 
@@ -114,6 +114,10 @@ mod model {
         /// Numeric spec of this asset.
         #[getset(get_copy = "pub")]
         pub spec: NumericSpec,
+        /// Kind of the value of the holdings.
+        #[getset(get_copy = "pub")]
+        #[serde(default)]
+        pub kind: AssetKind,
         /// Is the asset mintable
         #[getset(get_copy = "pub")]
         pub mintable: Mintable,
@@ -128,6 +132,96 @@ mod model {
         /// The total amount of this asset in existence (sum of all asset values).
         #[getset(get_copy = "pub")]
         pub total_quantity: Numeric,
+        /// The total amount can't be minted above this cap, if set.
+        #[getset(get_copy = "pub")]
+        #[serde(default)]
+        pub max_supply: Option<Numeric>,
+        /// Set once the asset is deprecated, see [`AssetDefinitionDeprecation`].
+        #[getset(get_copy = "pub")]
+        #[serde(default)]
+        pub deprecation: Option<AssetDefinitionDeprecation>,
+        /// Whether all holdings of the asset are frozen, see [`Freeze`](crate::isi::Freeze).
+        #[getset(get_copy = "pub")]
+        #[serde(default)]
+        pub frozen: bool,
+        /// Amount minted in the latest period with minting, if the asset is [`Mintable::Periodic`].
+        #[getset(get_copy = "pub")]
+        #[serde(default)]
+        pub minted_in_period: Option<MintedInPeriod>,
+    }
+
+    /// Rate limit of [`Mintable::Periodic`] assets.
+    #[derive(
+        Debug,
+        Display,
+        Clone,
+        Copy,
+        PartialEq,
+        Eq,
+        PartialOrd,
+        Ord,
+        Decode,
+        Encode,
+        Deserialize,
+        Serialize,
+        IntoSchema,
+    )]
+    #[display(fmt = "{amount}/{period_ms}ms")]
+    #[ffi_type]
+    pub struct MintingRate {
+        /// Maximum amount minted in a period.
+        pub amount: Numeric,
+        /// Length of a period in milliseconds.
+        pub period_ms: NonZeroU64,
+    }
+
+    /// Amount of a [`Mintable::Periodic`] asset minted in a period.
+    #[derive(
+        Debug,
+        Clone,
+        Copy,
+        PartialEq,
+        Eq,
+        PartialOrd,
+        Ord,
+        Decode,
+        Encode,
+        Deserialize,
+        Serialize,
+        IntoSchema,
+    )]
+    #[ffi_type]
+    pub struct MintedInPeriod {
+        /// Index of the period, i.e. the block creation time divided by the length of the period.
+        pub period: u64,
+        /// Amount minted in the period so far.
+        pub minted: Numeric,
+    }
+
+    /// Retirement of an asset, see [`Deprecate`](crate::isi::Deprecate).
+    ///
+    /// A deprecated asset can't be minted anymore,
+    /// while its holdings can still be transferred and burned until the end of the sunset window.
+    #[derive(
+        Debug,
+        Clone,
+        Copy,
+        PartialEq,
+        Eq,
+        PartialOrd,
+        Ord,
+        Decode,
+        Encode,
+        Deserialize,
+        Serialize,
+        IntoSchema,
+    )]
+    #[ffi_type]
+    pub struct AssetDefinitionDeprecation {
+        /// Creation time of the block which deprecated the asset, in milliseconds since the Unix epoch.
+        pub deprecated_at_ms: u64,
+        /// End of the sunset window, in milliseconds since the Unix epoch.
+        pub sunset_end_ms: u64,
     }
 
     /// Asset represents some sort of commodity or value.
@@ -137,6 +231,7 @@ mod model {
         Display,
         Clone,
         IdEqOrdHash,
+        CopyGetters,
         Getters,
         Decode,
         Encode,
@@ -152,6 +247,50 @@ mod model {
         /// Asset's Quantity.
         #[getset(get = "pub")]
         pub value: Numeric,
+        /// Metadata of this particular holding, e.g. batch number or expiry.
+        /// Bounded by [`Asset::MAX_METADATA_ENTRIES`] of up to [`Asset::MAX_METADATA_VALUE_SIZE`] bytes
+        /// and dropped together with the holding once its value reaches zero.
+        ///
+        /// For [`AssetKind::Store`] assets it's the value of the holding, which isn't bounded.
+        #[getset(get = "pub")]
+        pub metadata: Metadata,
+        /// Whether this particular holding is frozen, see [`Freeze`](crate::isi::Freeze).
+        ///
+        /// Holding is also frozen if its [`AssetDefinition`] is, regardless of this flag.
+        #[getset(get_copy = "pub")]
+        #[serde(default)]
+        pub frozen: bool,
+    }
+
+    /// Quantity of an asset the owner allows the spender to transfer out of the owner's holding,
+    /// see [`Approve`](crate::isi::Approve) and [`TransferFrom`](crate::isi::TransferFrom).
+    #[derive(
+        Debug,
+        Display,
+        Clone,
+        PartialEq,
+        Eq,
+        PartialOrd,
+        Ord,
+        Getters,
+        Decode,
+        Encode,
+        Deserialize,
+        Serialize,
+        IntoSchema,
+    )]
+    #[display(fmt = "{owner} -> {spender}: {limit} of {asset_definition}")]
+    #[getset(get = "pub")]
+    #[ffi_type]
+    pub struct Allowance {
+        /// Account whose holding may be spent.
+        pub owner: AccountId,
+        /// Account allowed to spend the holding.
+        pub spender: AccountId,
+        /// Asset which may be spent.
+        pub asset_definition: AssetDefinitionId,
+        /// Quantity which may still be spent.
+        pub limit: Numeric,
     }
 
     /// Builder which can be submitted in a transaction to create a new [`AssetDefinition`]
@@ -166,17 +305,23 @@ mod model {
         pub id: AssetDefinitionId,
         /// The numeric spec associated with the asset definition builder.
         pub spec: NumericSpec,
+        /// Kind of the value of the holdings.
+        #[serde(default)]
+        pub kind: AssetKind,
         /// The mintablility associated with the asset definition builder.
         pub mintable: Mintable,
         /// IPFS link to the [`AssetDefinition`] logo
         pub logo: Option<IpfsPath>,
         /// Metadata associated with the asset definition builder.
         pub metadata: Metadata,
+        /// The cap of the total amount associated with the asset definition builder.
+        #[serde(default)]
+        pub max_supply: Option<Numeric>,
     }
 
     /// An assets mintability scheme. `Infinitely` means elastic
     /// supply. `Once` is what you want to use. Don't use `Not` explicitly
-    /// outside of smartcontracts.
+    /// outside of smartcontracts. `Periodic` bounds the rate the supply grows at.
     #[derive(
         Debug,
         Display,
@@ -193,7 +338,6 @@ mod model {
         IntoSchema,
     )]
     #[ffi_type]
-    #[repr(u8)]
     pub enum Mintable {
         /// Regular asset with elastic supply. Can be minted and burned.
         #[display(fmt = "+")]
@@ -204,7 +348,41 @@ mod model {
         /// Non-mintable asset (token), with a fixed supply. Can be burned, but not minted.
         #[display(fmt = "-")]
         Not,
-        // TODO: Support more variants using bit-compacted tag, and `u32` mintability tokens.
+        /// Asset with supply growing at a bounded rate. Can be burned, and minted up to the amount
+        /// of the [`MintingRate`] in every period of block creation time.
+        ///
+        /// The limit doesn't apply to the genesis block, which mints the initial supply.
+        #[display(fmt = "{_0}")]
+        Periodic(MintingRate),
+    }
+
+    /// Kind of the value of the holdings of an asset.
+    #[derive(
+        Debug,
+        Display,
+        Clone,
+        Copy,
+        Default,
+        PartialEq,
+        Eq,
+        PartialOrd,
+        Ord,
+        Decode,
+        Encode,
+        Deserialize,
+        Serialize,
+        IntoSchema,
+    )]
+    #[ffi_type]
+    #[repr(u8)]
+    pub enum AssetKind {
+        /// Quantity, minted, burned and transferred according to the [`NumericSpec`].
+        #[default]
+        Numeric,
+        /// Key-value store, i.e. the value of the holding is its [`Metadata`](Asset::metadata).
+        ///
+        /// The holding is created by [`SetKeyValue`](crate::isi::SetKeyValue) and dropped once the store is empty.
+        Store,
     }
 }
 
@@ -215,15 +393,25 @@ pub struct AssetEntry<'world> {
     pub id: &'world AssetId,
     /// Asset's Quantity.
     pub value: &'world Numeric,
+    /// Metadata of this particular holding.
+    pub metadata: &'world Metadata,
+    /// Whether this particular holding is frozen.
+    pub frozen: bool,
 }
 
 /// [`Asset`] without `id` field.
 /// Needed only for [`World::assets`] map to reduce memory usage.
 /// In other places use [`Asset`] directly.
-#[derive(Copy, Clone, Deserialize, Serialize)]
+#[derive(Clone, Deserialize, Serialize)]
 pub struct AssetValue {
     /// Asset's Quantity.
     pub value: Numeric,
+    /// Metadata of this particular holding.
+    #[serde(default)]
+    pub metadata: Metadata,
+    /// Whether this particular holding is frozen.
+    #[serde(default)]
+    pub frozen: bool,
 }
 
 impl AssetDefinition {
@@ -240,6 +428,23 @@ impl AssetDefinition {
     pub fn numeric(id: AssetDefinitionId) -> <Self as Registered>::With {
         <Self as Registered>::With::new(id, NumericSpec::default())
     }
+
+    /// Construct builder for [`AssetDefinition`] of the [`AssetKind::Store`] holdings.
+    #[must_use]
+    #[inline]
+    pub fn store(id: AssetDefinitionId) -> <Self as Registered>::With {
+        let mut definition = <Self as Registered>::With::new(id, NumericSpec::default());
+        definition.kind = AssetKind::Store;
+        definition
+    }
+}
+
+impl AssetDefinitionDeprecation {
+    /// Whether the sunset window is over at `time` (since the Unix epoch),
+    /// i.e. the holdings can't be transferred or burned anymore.
+    pub fn is_sunset_over(&self, time: core::time::Duration) -> bool {
+        time.as_millis() > u128::from(self.sunset_end_ms)
+    }
 }
 
 impl AssetId {
@@ -253,24 +458,46 @@ impl AssetId {
 }
 
 impl Asset {
+    /// Maximum number of metadata entries that can be attached to a single holding.
+    ///
+    /// Holdings are far more numerous than asset definitions, so their metadata
+    /// is meant for small provenance records rather than arbitrary storage.
+    /// Doesn't apply to [`AssetKind::Store`] assets, storing the data is their purpose.
+    pub const MAX_METADATA_ENTRIES: usize = 16;
+
+    /// Maximum size in bytes of the JSON of a metadata value attached to a single holding.
+    ///
+    /// Doesn't apply to [`AssetKind::Store`] assets, see [`Self::MAX_METADATA_ENTRIES`].
+    pub const MAX_METADATA_VALUE_SIZE: usize = 256;
+
     /// Constructor
     pub fn new(id: AssetId, value: impl Into<Numeric>) -> <Self as Registered>::With {
         Self {
             id,
             value: value.into(),
+            metadata: Metadata::default(),
+            frozen: false,
         }
     }
 }
 
+impl HasMetadata for Asset {
+    fn metadata(&self) -> &Metadata {
+        &self.metadata
+    }
+}
+
 impl NewAssetDefinition {
     /// Create a [`NewAssetDefinition`], reserved for internal use.
     fn new(id: AssetDefinitionId, spec: NumericSpec) -> Self {
         Self {
             id,
             spec,
+            kind: AssetKind::Numeric,
             mintable: Mintable::Infinitely,
             logo: None,
             metadata: Metadata::default(),
+            max_supply: None,
         }
     }
 
@@ -282,6 +509,14 @@ impl NewAssetDefinition {
         self
     }
 
+    /// Set mintability to [`Mintable::Periodic`], allowing to mint up to `amount` every `period_ms`
+    #[inline]
+    #[must_use]
+    pub fn mintable_periodically(mut self, amount: Numeric, period_ms: NonZeroU64) -> Self {
+        self.mintable = Mintable::Periodic(MintingRate { amount, period_ms });
+        self
+    }
+
     /// Add [`logo`](IpfsPath) to the asset definition replacing previously defined value
     #[must_use]
     pub fn with_logo(mut self, logo: IpfsPath) -> Self {
@@ -296,6 +531,14 @@ impl NewAssetDefinition {
         self.metadata = metadata;
         self
     }
+
+    /// Cap the total amount of the asset, minting above it fails
+    #[inline]
+    #[must_use]
+    pub fn with_max_supply(mut self, max_supply: Numeric) -> Self {
+        self.max_supply = Some(max_supply);
+        self
+    }
 }
 
 impl HasMetadata for AssetDefinition {
@@ -393,11 +636,16 @@ impl Registrable for NewAssetDefinition {
         Self::Target {
             id: self.id,
             spec: self.spec,
+            kind: self.kind,
             mintable: self.mintable,
             logo: self.logo,
             metadata: self.metadata,
             owned_by: authority.clone(),
             total_quantity: Numeric::ZERO,
+            max_supply: self.max_supply,
+            deprecation: None,
+            frozen: false,
+            minted_in_period: None,
         }
     }
 }
@@ -408,6 +656,8 @@ impl<'world> AssetEntry<'world> {
         Self {
             id,
             value: &value.value,
+            metadata: &value.metadata,
+            frozen: value.frozen,
         }
     }
 
@@ -421,11 +671,23 @@ impl<'world> AssetEntry<'world> {
         self.value
     }
 
+    /// Getter for `metadata`
+    pub fn metadata(&self) -> &Metadata {
+        self.metadata
+    }
+
+    /// Getter for `frozen`
+    pub fn frozen(&self) -> bool {
+        self.frozen
+    }
+
     /// Converts to `Asset`
     pub fn to_owned(&self) -> Asset {
         Asset {
             id: self.id.clone(),
             value: *self.value,
+            metadata: self.metadata.clone(),
+            frozen: self.frozen,
         }
     }
 }
@@ -434,7 +696,11 @@ impl IntoKeyValue for Asset {
     type Key = AssetId;
     type Value = AssetValue;
     fn into_key_value(self) -> (Self::Key, Self::Value) {
-        let value = AssetValue { value: self.value };
+        let value = AssetValue {
+            value: self.value,
+            metadata: self.metadata,
+            frozen: self.frozen,
+        };
         (self.id, value)
     }
 }
@@ -442,7 +708,8 @@ impl IntoKeyValue for Asset {
 /// The prelude re-exports most commonly used traits, structs and macros from this crate.
 pub mod prelude {
     pub use super::{
-        Asset, AssetDefinition, AssetDefinitionId, AssetId, Mintable, NewAssetDefinition,
+        Allowance, Asset, AssetDefinition, AssetDefinitionDeprecation, AssetDefinitionId, AssetId,
+        AssetKind, Mintable, MintedInPeriod, MintingRate, NewAssetDefinition,
     };
 }
 
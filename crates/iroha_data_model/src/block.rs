@@ -699,6 +699,19 @@ pub mod stream {
         #[derive(Debug, Clone, Decode, Encode, Deserialize, Serialize, IntoSchema)]
         #[repr(transparent)]
         pub struct BlockMessage(pub SignedBlock);
+
+        /// Message sent by the block headers stream producer.
+        ///
+        /// Carries only what a watch-only client needs to follow and verify chain progress:
+        /// the header with the merkle roots of transactions and their results,
+        /// and the signatures of the peers that committed the block.
+        #[derive(Debug, Clone, Decode, Encode, Deserialize, Serialize, IntoSchema)]
+        pub struct BlockHeaderMessage {
+            /// Header of the committed block.
+            pub header: BlockHeader,
+            /// Signatures of the peers that committed the block.
+            pub signatures: Vec<BlockSignature>,
+        }
     }
 
     impl From<BlockMessage> for SignedBlock {
@@ -707,9 +720,18 @@ pub mod stream {
         }
     }
 
+    impl From<&SignedBlock> for BlockHeaderMessage {
+        fn from(block: &SignedBlock) -> Self {
+            Self {
+                header: block.header(),
+                signatures: block.signatures().cloned().collect(),
+            }
+        }
+    }
+
     /// Exports common structs and enums from this module.
     pub mod prelude {
-        pub use super::{BlockMessage, BlockSubscriptionRequest};
+        pub use super::{BlockHeaderMessage, BlockMessage, BlockSubscriptionRequest};
     }
 }
 
@@ -0,0 +1,95 @@
+//! Structures, traits and impls related to `DeadLetter`s.
+//!
+//! A [`DeadLetter`] is recorded whenever a trigger execution traps or exceeds its limits,
+//! so that its owner can inspect the failure and either retry it with
+//! [`RetryDeadLetter`](crate::isi::RetryDeadLetter) or drop it with
+//! [`DiscardDeadLetter`](crate::isi::DiscardDeadLetter).
+
+#[cfg(not(feature = "std"))]
+use alloc::{format, string::String, vec::Vec};
+use core::num::NonZeroU64;
+
+use iroha_data_model_derive::model;
+
+pub use self::model::*;
+use crate::{account::AccountId, events::EventBox, trigger::TriggerId};
+
+/// Number of dead letters kept for a single trigger; the oldest ones are evicted first.
+pub const MAX_DEAD_LETTERS_PER_TRIGGER: usize = 16;
+
+#[model]
+mod model {
+    use derive_more::{Constructor, Display};
+    use getset::Getters;
+    use iroha_schema::IntoSchema;
+    use parity_scale_codec::{Decode, Encode};
+    use serde::{Deserialize, Serialize};
+
+    use super::*;
+
+    /// Identification of a [`DeadLetter`]: failures of a trigger are numbered in the order they occurred.
+    #[derive(
+        Debug,
+        Display,
+        Clone,
+        PartialEq,
+        Eq,
+        PartialOrd,
+        Ord,
+        Hash,
+        Constructor,
+        Getters,
+        Decode,
+        Encode,
+        Deserialize,
+        Serialize,
+        IntoSchema,
+    )]
+    #[display(fmt = "{trigger}#{sequence}")]
+    #[getset(get = "pub")]
+    #[ffi_type]
+    pub struct DeadLetterId {
+        /// Trigger whose execution failed.
+        pub trigger: TriggerId,
+        /// Sequence number of the failure among the failures of the trigger.
+        pub sequence: u64,
+    }
+
+    /// Failed trigger execution kept on-chain until its owner retries or discards it.
+    #[derive(
+        Debug,
+        Display,
+        Clone,
+        PartialEq,
+        Eq,
+        PartialOrd,
+        Ord,
+        Constructor,
+        Getters,
+        Decode,
+        Encode,
+        Deserialize,
+        Serialize,
+        IntoSchema,
+    )]
+    #[display(fmt = "{id} failed at height {failed_at}: {error}")]
+    #[getset(get = "pub")]
+    #[ffi_type]
+    pub struct DeadLetter {
+        /// Identification of the dead letter.
+        pub id: DeadLetterId,
+        /// Owner of the trigger.
+        pub authority: AccountId,
+        /// Event the trigger was executed with.
+        pub event: EventBox,
+        /// Reason of the failure.
+        pub error: String,
+        /// Height of the block in which the execution failed.
+        pub failed_at: NonZeroU64,
+    }
+}
+
+/// The prelude re-exports most commonly used traits, structs and macros from this module.
+pub mod prelude {
+    pub use super::{DeadLetter, DeadLetterId};
+}
@@ -0,0 +1,111 @@
+//! Structures, traits and impls related to `Escrow`s.
+//!
+//! An [`Escrow`] locks a quantity of an asset of the sender for the recipient with
+//! [`CreateEscrow`](crate::isi::CreateEscrow). The recipient takes it with
+//! [`ClaimEscrow`](crate::isi::ClaimEscrow) once it's released, and the sender takes it back with
+//! [`RefundEscrow`](crate::isi::RefundEscrow) if it wasn't claimed before it expired.
+
+#[cfg(not(feature = "std"))]
+use alloc::{format, string::String, vec::Vec};
+use core::time::Duration;
+
+use iroha_data_model_derive::model;
+use iroha_primitives::numeric::Numeric;
+
+pub use self::model::*;
+use crate::{account::AccountId, asset::AssetDefinitionId, name::Name};
+
+#[model]
+mod model {
+    use derive_more::{Constructor, Display};
+    use getset::Getters;
+    use iroha_schema::IntoSchema;
+    use parity_scale_codec::{Decode, Encode};
+    use serde::{Deserialize, Serialize};
+
+    use super::*;
+
+    /// Identification of an [`Escrow`]: the name is unique among the escrows of the sender.
+    #[derive(
+        Debug,
+        Display,
+        Clone,
+        PartialEq,
+        Eq,
+        PartialOrd,
+        Ord,
+        Hash,
+        Constructor,
+        Getters,
+        Decode,
+        Encode,
+        Deserialize,
+        Serialize,
+        IntoSchema,
+    )]
+    #[display(fmt = "{name}/{sender}")]
+    #[getset(get = "pub")]
+    #[ffi_type]
+    pub struct EscrowId {
+        /// Account whose asset is locked.
+        pub sender: AccountId,
+        /// Name of the escrow.
+        pub name: Name,
+    }
+
+    /// Quantity of an asset locked for the recipient until it's claimed or refunded.
+    ///
+    /// The recipient can claim it from `release_at_ms` until `expires_at_ms`,
+    /// the sender can refund it from `expires_at_ms` on. Both are compared
+    /// to the creation time of the block, in milliseconds since the Unix epoch.
+    #[derive(
+        Debug,
+        Display,
+        Clone,
+        PartialEq,
+        Eq,
+        PartialOrd,
+        Ord,
+        Constructor,
+        Getters,
+        Decode,
+        Encode,
+        Deserialize,
+        Serialize,
+        IntoSchema,
+    )]
+    #[display(fmt = "{id}: {amount} of {asset_definition} to {recipient}")]
+    #[getset(get = "pub")]
+    #[ffi_type]
+    pub struct Escrow {
+        /// Identification of the escrow.
+        pub id: EscrowId,
+        /// Account the asset is destined to.
+        pub recipient: AccountId,
+        /// Asset which is locked.
+        pub asset_definition: AssetDefinitionId,
+        /// Quantity which is locked.
+        pub amount: Numeric,
+        /// Time from which the recipient can claim the escrow.
+        pub release_at_ms: u64,
+        /// Time from which the sender can refund the escrow.
+        pub expires_at_ms: u64,
+    }
+}
+
+impl Escrow {
+    /// Whether the recipient can claim the escrow at `time` (since the Unix epoch).
+    pub fn is_claimable(&self, time: Duration) -> bool {
+        u128::from(self.release_at_ms) <= time.as_millis() && !self.is_expired(time)
+    }
+
+    /// Whether the escrow is expired at `time` (since the Unix epoch), so the sender can refund it.
+    pub fn is_expired(&self, time: Duration) -> bool {
+        u128::from(self.expires_at_ms) <= time.as_millis()
+    }
+}
+
+/// The prelude re-exports most commonly used traits, structs and macros from this module.
+pub mod prelude {
+    pub use super::{Escrow, EscrowId};
+}
@@ -91,6 +91,32 @@ mod model {
         Configuration(config::ConfigurationEvent),
         /// Executor event
         Executor(executor::ExecutorEvent),
+        /// Event emitted by a [`Tagged`](crate::isi::Tagged) instruction
+        Tagged(TaggedEvent),
+    }
+
+    /// Data event along with the tag of the instruction which emitted it.
+    #[derive(
+        Debug,
+        Clone,
+        PartialEq,
+        Eq,
+        PartialOrd,
+        Ord,
+        Getters,
+        Decode,
+        Encode,
+        Deserialize,
+        Serialize,
+        IntoSchema,
+    )]
+    #[getset(get = "pub")]
+    #[ffi_type(opaque)]
+    pub struct TaggedEvent {
+        /// Tag of the instruction
+        pub tag: Name,
+        /// Event itself, never tagged
+        pub event: Box<DataEvent>,
     }
 }
 
@@ -102,6 +128,7 @@ mod asset {
     pub use self::model::*;
     use super::*;
 
+    type AssetMetadataChanged = MetadataChanged<AssetId>;
     type AssetDefinitionMetadataChanged = MetadataChanged<AssetDefinitionId>;
 
     data_event! {
@@ -114,6 +141,18 @@ mod asset {
             Added(AssetChanged),
             #[has_origin(asset_changed => &asset_changed.asset)]
             Removed(AssetChanged),
+            #[has_origin(metadata_changed => &metadata_changed.target)]
+            MetadataInserted(AssetMetadataChanged),
+            #[has_origin(metadata_changed => &metadata_changed.target)]
+            MetadataRemoved(AssetMetadataChanged),
+            #[has_origin(asset_split => &asset_split.asset)]
+            Split(AssetSplit),
+            #[has_origin(asset_merged => &asset_merged.asset)]
+            Merged(AssetMerged),
+            Frozen(AssetId),
+            Unfrozen(AssetId),
+            #[has_origin(asset_converted => &asset_converted.asset)]
+            Converted(AssetConverted),
         }
     }
 
@@ -132,6 +171,10 @@ mod asset {
             TotalQuantityChanged(AssetDefinitionTotalQuantityChanged),
             #[has_origin(ownership_changed => &ownership_changed.asset_definition)]
             OwnerChanged(AssetDefinitionOwnerChanged),
+            #[has_origin(deprecated => &deprecated.asset_definition)]
+            Deprecated(AssetDefinitionDeprecated),
+            Frozen(AssetDefinitionId),
+            Unfrozen(AssetDefinitionId),
         }
     }
 
@@ -161,6 +204,84 @@ mod asset {
             pub amount: Numeric,
         }
 
+        /// [`Self`] represents a holding split into parts credited to holdings of the same asset.
+        #[derive(
+            Debug,
+            Clone,
+            PartialEq,
+            Eq,
+            PartialOrd,
+            Ord,
+            Getters,
+            Decode,
+            Encode,
+            Deserialize,
+            Serialize,
+            IntoSchema,
+        )]
+        #[getset(get = "pub")]
+        #[ffi_type]
+        pub struct AssetSplit {
+            /// Id of the holding being split
+            pub asset: AssetId,
+            /// Quantities credited to the receiving holdings
+            pub parts: Vec<AssetChanged>,
+        }
+
+        /// [`Self`] represents holdings merged into a holding of the same asset.
+        #[derive(
+            Debug,
+            Clone,
+            PartialEq,
+            Eq,
+            PartialOrd,
+            Ord,
+            Getters,
+            Decode,
+            Encode,
+            Deserialize,
+            Serialize,
+            IntoSchema,
+        )]
+        #[getset(get = "pub")]
+        #[ffi_type]
+        pub struct AssetMerged {
+            /// Id of the receiving holding
+            pub asset: AssetId,
+            /// Quantities taken from the merged holdings
+            pub sources: Vec<AssetChanged>,
+        }
+
+        /// [`Self`] represents a holding converted into a holding of another asset of the same account.
+        #[derive(
+            Debug,
+            Clone,
+            PartialEq,
+            Eq,
+            PartialOrd,
+            Ord,
+            Getters,
+            Decode,
+            Encode,
+            Deserialize,
+            Serialize,
+            IntoSchema,
+        )]
+        #[getset(get = "pub")]
+        #[ffi_type]
+        pub struct AssetConverted {
+            /// Id of the converted holding
+            pub asset: AssetId,
+            /// Quantity burned from the converted holding
+            pub burned: Numeric,
+            /// Id of the receiving holding
+            pub destination: AssetId,
+            /// Quantity minted to the receiving holding
+            pub minted: Numeric,
+            /// Rate the quantity was converted at
+            pub rate: Numeric,
+        }
+
         /// [`Self`] represents updated total asset quantity.
         #[derive(
             Debug,
@@ -206,6 +327,30 @@ mod asset {
             /// Id of new owning account
             pub new_owner: AccountId,
         }
+
+        /// [`Self`] represents the deprecation of an asset definition.
+        #[derive(
+            Debug,
+            Clone,
+            PartialEq,
+            Eq,
+            PartialOrd,
+            Ord,
+            Getters,
+            Decode,
+            Encode,
+            Deserialize,
+            Serialize,
+            IntoSchema,
+        )]
+        #[getset(get = "pub")]
+        #[ffi_type]
+        pub struct AssetDefinitionDeprecated {
+            /// Id of asset definition being deprecated
+            pub asset_definition: AssetDefinitionId,
+            /// Deprecation of the asset definition, including its sunset window
+            pub deprecation: AssetDefinitionDeprecation,
+        }
     }
 }
 
@@ -361,6 +506,27 @@ mod account {
             MetadataInserted(AccountMetadataChanged),
             #[has_origin(metadata_changed => &metadata_changed.target)]
             MetadataRemoved(AccountMetadataChanged),
+            Purged(AccountId),
+            #[has_origin(delegation => &delegation.delegate)]
+            PermissionDelegated(Delegation),
+            #[has_origin(delegation => &delegation.delegate)]
+            DelegationRevoked(Delegation),
+            #[has_origin(tag_changed => &tag_changed.account)]
+            TagAdded(AccountTagChanged),
+            #[has_origin(tag_changed => &tag_changed.account)]
+            TagRemoved(AccountTagChanged),
+            #[has_origin(allowance => &allowance.owner)]
+            AllowanceChanged(Allowance),
+            #[has_origin(message_id => &message_id.recipient)]
+            MessageReceived(DirectMessageId),
+            #[has_origin(escrow => &escrow.id.sender)]
+            EscrowCreated(Escrow),
+            #[has_origin(escrow_id => &escrow_id.sender)]
+            EscrowClosed(EscrowId),
+            #[has_origin(permission_changed => &permission_changed.account)]
+            PermissionExpired(AccountPermissionChanged),
+            #[has_origin(role_changed => &role_changed.account)]
+            RoleExpired(AccountRoleChanged),
         }
     }
 
@@ -413,6 +579,28 @@ mod account {
             pub account: AccountId,
             pub role: RoleId,
         }
+
+        /// Depending on the wrapping event, [`AccountTagChanged`] represents the added or removed tag
+        #[derive(
+            Debug,
+            Clone,
+            PartialEq,
+            Eq,
+            PartialOrd,
+            Ord,
+            Getters,
+            Decode,
+            Encode,
+            Deserialize,
+            Serialize,
+            IntoSchema,
+        )]
+        #[getset(get = "pub")]
+        #[ffi_type]
+        pub struct AccountTagChanged {
+            pub account: AccountId,
+            pub tag: Name,
+        }
     }
 
     impl AccountPermissionChanged {
@@ -449,6 +637,7 @@ mod domain {
             MetadataRemoved(DomainMetadataChanged),
             #[has_origin(owner_changed => &owner_changed.domain)]
             OwnerChanged(DomainOwnerChanged),
+            Purged(DomainId),
         }
     }
 
@@ -688,6 +877,7 @@ impl DataEvent {
     pub fn domain(&self) -> Option<&DomainId> {
         match self {
             Self::Domain(event) => Some(event.origin()),
+            Self::Tagged(event) => event.event.domain(),
             Self::Configuration(_)
             | Self::Executor(_)
             | Self::Peer(_)
@@ -695,6 +885,32 @@ impl DataEvent {
             | Self::Trigger(_) => None,
         }
     }
+
+    /// Tag of the instruction which emitted the event, if any
+    pub fn tag(&self) -> Option<&Name> {
+        match self {
+            Self::Tagged(event) => Some(&event.tag),
+            _ => None,
+        }
+    }
+
+    /// The event without the tag
+    pub fn untagged(&self) -> &DataEvent {
+        match self {
+            Self::Tagged(event) => &event.event,
+            event => event,
+        }
+    }
+
+    /// Tag the event with `tag`, replacing the previous tag, if any
+    #[must_use]
+    pub fn with_tag(self, tag: Name) -> Self {
+        let event = match self {
+            Self::Tagged(event) => event.event,
+            event => Box::new(event),
+        };
+        Self::Tagged(TaggedEvent { tag, event })
+    }
 }
 
 impl<Id> MetadataChanged<Id> {
@@ -716,11 +932,15 @@ impl<Id> MetadataChanged<Id> {
 
 pub mod prelude {
     pub use super::{
-        account::{AccountEvent, AccountEventSet, AccountPermissionChanged, AccountRoleChanged},
+        account::{
+            AccountEvent, AccountEventSet, AccountPermissionChanged, AccountRoleChanged,
+            AccountTagChanged,
+        },
         asset::{
-            AssetChanged, AssetDefinitionEvent, AssetDefinitionEventSet,
-            AssetDefinitionOwnerChanged, AssetDefinitionTotalQuantityChanged, AssetEvent,
-            AssetEventSet,
+            AssetChanged, AssetConverted, AssetDefinitionDeprecated, AssetDefinitionEvent,
+            AssetDefinitionEventSet, AssetDefinitionOwnerChanged,
+            AssetDefinitionTotalQuantityChanged, AssetEvent, AssetEventSet, AssetMerged,
+            AssetSplit,
         },
         config::{ConfigurationEvent, ConfigurationEventSet, ParameterChanged},
         domain::{DomainEvent, DomainEventSet, DomainOwnerChanged},
@@ -729,6 +949,6 @@ pub mod prelude {
         peer::{PeerEvent, PeerEventSet},
         role::{RoleEvent, RoleEventSet, RolePermissionChanged},
         trigger::{TriggerEvent, TriggerEventSet, TriggerNumberOfExecutionsChanged},
-        DataEvent, HasOrigin, MetadataChanged,
+        DataEvent, HasOrigin, MetadataChanged, TaggedEvent,
     };
 }
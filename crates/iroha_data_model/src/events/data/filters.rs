@@ -53,6 +53,8 @@ mod model {
         Configuration(ConfigurationEventFilter),
         /// Matches [`ExecutorEvent`]s
         Executor(ExecutorEventFilter),
+        /// Matches the events emitted by the [`Tagged`](crate::isi::Tagged) instructions with the tag
+        Tag(Name),
     }
 
     /// An event filter for [`PeerEvent`]s
@@ -744,6 +746,10 @@ impl EventFilter for DataEventFilter {
 
         #[allow(clippy::match_same_arms)]
         match (event, self) {
+            (DataEvent::Tagged(event), Tag(tag)) => event.tag == *tag,
+            // The other filters don't depend on the tag
+            (DataEvent::Tagged(event), filter) => filter.matches(&event.event),
+
             (
                 DataEvent::Domain(DomainEvent::Account(AccountEvent::Asset(event))),
                 Asset(filter),
@@ -845,4 +851,31 @@ mod tests {
         assert!(!asset_filter.matches(&account_created));
         assert!(asset_filter.matches(&asset_created));
     }
+
+    #[test]
+    #[cfg(feature = "transparent_api")]
+    fn tagged_events() {
+        let domain_id: DomainId = "wonderland".parse().unwrap();
+        let owner_id = AccountId::new(domain_id.clone(), KeyPair::random().into_parts().0);
+        let domain = Domain {
+            id: domain_id.clone(),
+            logo: None,
+            metadata: Metadata::default(),
+            owned_by: owner_id,
+        };
+        let domain_created: DataEvent = DomainEvent::Created(domain).into();
+        let tagged = domain_created.clone().with_tag("payroll".parse().unwrap());
+
+        let tag_filter = DataEventFilter::Tag("payroll".parse().unwrap());
+        let other_tag_filter = DataEventFilter::Tag("refunds".parse().unwrap());
+        let domain_filter = DataEventFilter::Domain(DomainEventFilter::new().for_domain(domain_id));
+
+        assert!(tag_filter.matches(&tagged));
+        assert!(!tag_filter.matches(&domain_created));
+        assert!(!other_tag_filter.matches(&tagged));
+        // the other filters see through the tag
+        assert!(domain_filter.matches(&tagged));
+        assert!(DataEventFilter::Any.matches(&tagged));
+        assert_eq!(tagged.untagged(), &domain_created);
+    }
 }
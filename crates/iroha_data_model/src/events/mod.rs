@@ -255,7 +255,7 @@ mod conversions {
 pub mod stream {
     //! Structures related to event streaming over HTTP
 
-    use derive_more::Constructor;
+    use derive_more::{Constructor, Display};
     use iroha_data_model_derive::model;
     use iroha_version::prelude::*;
 
@@ -266,22 +266,78 @@ pub mod stream {
     mod model {
         use super::*;
 
+        /// Position of an event among the events emitted by the committed blocks.
+        ///
+        /// Events are ordered first by the height of the block emitting them and then by
+        /// their index among the events of that block, the [`BlockStatus::Applied`](pipeline::BlockStatus::Applied)
+        /// event of the block being the last one. Every peer assigns the same sequence to the same event,
+        /// so the stream can be resumed from another peer or after a restart of the peer.
+        #[derive(
+            Debug,
+            Clone,
+            Copy,
+            PartialEq,
+            Eq,
+            PartialOrd,
+            Ord,
+            Hash,
+            Constructor,
+            Display,
+            Decode,
+            Encode,
+            Deserialize,
+            Serialize,
+            IntoSchema,
+        )]
+        #[display(fmt = "{block_height}:{index}")]
+        pub struct EventSequence {
+            /// Height of the block emitting the event.
+            pub block_height: u64,
+            /// Index of the event among the ones emitted by the block at `block_height`.
+            pub index: u64,
+        }
+
         /// Message sent by the stream producer.
         /// Event sent by the peer.
-        #[derive(Debug, Clone, Decode, Encode, Deserialize, Serialize, IntoSchema)]
-        #[repr(transparent)]
-        pub struct EventMessage(pub EventBox);
+        #[derive(Debug, Clone, Constructor, Decode, Encode, Deserialize, Serialize, IntoSchema)]
+        pub struct EventMessage {
+            /// Position of the event among the events of the committed blocks,
+            /// `None` for the events emitted by the peer on its own, e.g. the statuses of the
+            /// transactions in its queue, as these differ between the peers.
+            pub sequence: Option<EventSequence>,
+            /// Event itself.
+            pub event: EventBox,
+        }
 
         /// Message sent by the stream consumer.
         /// Request sent by the client to subscribe to events.
         #[derive(Debug, Clone, Constructor, Decode, Encode, Deserialize, Serialize, IntoSchema)]
         #[repr(transparent)]
         pub struct EventSubscriptionRequest(pub Vec<EventFilterBox>);
+
+        /// Message sent by the peer on the stream of the sequenced events.
+        #[derive(Debug, Clone, Decode, Encode, Deserialize, Serialize, IntoSchema)]
+        pub enum SequencedEventMessage {
+            /// Event along with its sequence.
+            Event(EventMessage),
+            /// Some of the events following the requested one aren't kept by the peer anymore,
+            /// the stream continues with the ones it keeps.
+            Lost,
+        }
+
+        /// Request sent by the client to subscribe to the sequenced events.
+        #[derive(Debug, Clone, Constructor, Decode, Encode, Deserialize, Serialize, IntoSchema)]
+        pub struct SequencedEventSubscriptionRequest {
+            /// Filters the events must match any of.
+            pub filters: Vec<EventFilterBox>,
+            /// Replay the recent events following the one with this sequence before the new ones.
+            pub after: Option<EventSequence>,
+        }
     }
 
     impl From<EventMessage> for EventBox {
         fn from(source: EventMessage) -> Self {
-            source.0
+            source.event
         }
     }
 }
@@ -289,7 +345,10 @@ pub mod stream {
 /// Exports common structs and enums from this module.
 pub mod prelude {
     #[cfg(feature = "http")]
-    pub use super::stream::{EventMessage, EventSubscriptionRequest};
+    pub use super::stream::{
+        EventMessage, EventSequence, EventSubscriptionRequest, SequencedEventMessage,
+        SequencedEventSubscriptionRequest,
+    };
     #[cfg(feature = "transparent_api")]
     pub use super::EventFilter;
     pub use super::{
@@ -1,8 +1,16 @@
 //! This library contains basic Iroha Special Instructions.
 
 #[cfg(not(feature = "std"))]
-use alloc::{format, string::String, vec::Vec};
+use alloc::{
+    boxed::Box,
+    collections::{BTreeMap, BTreeSet},
+    format,
+    string::String,
+    vec::Vec,
+};
 use core::fmt::{Debug, Display};
+#[cfg(feature = "std")]
+use std::collections::{BTreeMap, BTreeSet};
 
 use derive_more::{Constructor, DebugCustom, Display};
 use iroha_data_model_derive::{model, EnumRef};
@@ -120,6 +128,75 @@ mod model {
 
         #[debug(fmt = "{_0:?}")]
         Custom(CustomInstruction),
+
+        // Instructions are appended to keep the discriminants of the existing ones
+        #[debug(fmt = "{_0:?}")]
+        #[enum_ref(transparent)]
+        Purge(PurgeBox),
+        #[debug(fmt = "{_0:?}")]
+        Split(Split),
+        #[debug(fmt = "{_0:?}")]
+        Merge(Merge),
+        #[debug(fmt = "{_0:?}")]
+        Subscribe(Subscribe),
+        #[debug(fmt = "{_0:?}")]
+        Unsubscribe(Unsubscribe),
+        #[debug(fmt = "{_0:?}")]
+        RetryDeadLetter(RetryDeadLetter),
+        #[debug(fmt = "{_0:?}")]
+        DiscardDeadLetter(DiscardDeadLetter),
+        #[debug(fmt = "{_0:?}")]
+        Deprecate(Deprecate<AssetDefinition>),
+        #[debug(fmt = "{_0:?}")]
+        Delegate(Delegate),
+        #[debug(fmt = "{_0:?}")]
+        Onboard(Onboard),
+        #[debug(fmt = "{_0:?}")]
+        Payout(Payout),
+        #[debug(fmt = "{_0:?}")]
+        AddTag(AddTag),
+        #[debug(fmt = "{_0:?}")]
+        RemoveTag(RemoveTag),
+        #[debug(fmt = "{_0:?}")]
+        #[enum_ref(transparent)]
+        Freeze(FreezeBox),
+        #[debug(fmt = "{_0:?}")]
+        #[enum_ref(transparent)]
+        Unfreeze(UnfreezeBox),
+        #[debug(fmt = "{_0:?}")]
+        Approve(Approve),
+        #[debug(fmt = "{_0:?}")]
+        TransferFrom(TransferFrom),
+        #[debug(fmt = "{_0:?}")]
+        Message(Message),
+        #[debug(fmt = "{_0:?}")]
+        Convert(Convert),
+        #[debug(fmt = "{_0:?}")]
+        CreateEscrow(CreateEscrow),
+        #[debug(fmt = "{_0:?}")]
+        ClaimEscrow(ClaimEscrow),
+        #[debug(fmt = "{_0:?}")]
+        RefundEscrow(RefundEscrow),
+        #[debug(fmt = "{_0:?}")]
+        Tagged(Tagged),
+        #[debug(fmt = "{_0:?}")]
+        TransferMany(TransferMany),
+    }
+}
+
+impl InstructionBox {
+    /// Number of instructions this one counts as towards
+    /// [`TransactionParameters::max_instructions`](crate::parameter::TransactionParameters::max_instructions).
+    ///
+    /// [`Onboard`] counts as all of the instructions it consists of, see [`Onboard::instructions`],
+    /// and [`TransferMany`] as all of its transfers, see [`TransferMany::transfers`].
+    pub fn instruction_count(&self) -> usize {
+        match self {
+            Self::Onboard(onboard) => 1 + onboard.assets.len() + onboard.roles.len(),
+            Self::TransferMany(transfer) => transfer.recipients.len().max(1),
+            Self::Tagged(tagged) => tagged.instruction.instruction_count(),
+            _ => 1,
+        }
     }
 }
 
@@ -138,11 +215,13 @@ macro_rules! impl_instruction {
 impl_instruction! {
     SetKeyValue<Domain>,
     SetKeyValue<AssetDefinition>,
+    SetKeyValue<Asset>,
     SetKeyValue<Account>,
     SetKeyValue<Nft>,
     SetKeyValue<Trigger>,
     RemoveKeyValue<Domain>,
     RemoveKeyValue<AssetDefinition>,
+    RemoveKeyValue<Asset>,
     RemoveKeyValue<Account>,
     RemoveKeyValue<Nft>,
     RemoveKeyValue<Trigger>,
@@ -160,6 +239,8 @@ impl_instruction! {
     Unregister<Nft>,
     Unregister<Role>,
     Unregister<Trigger>,
+    Purge<Domain>,
+    Purge<Account>,
     Mint<Numeric, Asset>,
     Mint<u32, Trigger>,
     Burn<Numeric, Asset>,
@@ -168,6 +249,8 @@ impl_instruction! {
     Transfer<Account, AssetDefinitionId, Account>,
     Transfer<Asset, Numeric, Account>,
     Transfer<Account, NftId, Account>,
+    Split,
+    Merge,
     Grant<Permission, Account>,
     Grant<RoleId, Account>,
     Grant<Permission, Role>,
@@ -178,6 +261,29 @@ impl_instruction! {
     Upgrade,
     ExecuteTrigger,
     Log,
+    Subscribe,
+    Unsubscribe,
+    RetryDeadLetter,
+    DiscardDeadLetter,
+    Deprecate<AssetDefinition>,
+    Delegate,
+    Tagged,
+    Onboard,
+    Payout,
+    AddTag,
+    RemoveTag,
+    Freeze<Asset>,
+    Freeze<AssetDefinition>,
+    Unfreeze<Asset>,
+    Unfreeze<AssetDefinition>,
+    Approve,
+    TransferFrom,
+    Message,
+    Convert,
+    CreateEscrow,
+    ClaimEscrow,
+    RefundEscrow,
+    TransferMany,
 }
 
 impl Instruction for InstructionBox {}
@@ -262,6 +368,13 @@ mod transparent {
         pub struct SetParameter(pub Parameter);
     }
 
+    impl SetParameter {
+        /// Parameter to be set
+        pub fn parameter(&self) -> &Parameter {
+            &self.0
+        }
+    }
+
     isi! {
         /// Generic instruction to set key value at the object.
         pub struct SetKeyValue<O: Identifiable> {
@@ -311,6 +424,17 @@ mod transparent {
         }
     }
 
+    impl SetKeyValue<Asset> {
+        /// Constructs a new [`SetKeyValue`] for an [`Asset`] holding with the given `key` and `value`.
+        pub fn asset(asset_id: AssetId, key: Name, value: impl Into<Json>) -> Self {
+            Self {
+                object: asset_id,
+                key,
+                value: value.into(),
+            }
+        }
+    }
+
     impl SetKeyValue<Nft> {
         /// Constructs a new [`SetKeyValue`] for an [`Nft`] with the given `key` and `value`.
         pub fn nft(nft_id: NftId, key: Name, value: impl Into<Json>) -> Self {
@@ -347,6 +471,7 @@ mod transparent {
         SetKeyValue<Domain> |
         SetKeyValue<Account> |
         SetKeyValue<AssetDefinition> |
+        SetKeyValue<Asset> |
         SetKeyValue<Nft> |
         SetKeyValue<Trigger>
     => SetKeyValueBox => InstructionBox[SetKeyValue],
@@ -393,6 +518,16 @@ mod transparent {
         }
     }
 
+    impl RemoveKeyValue<Asset> {
+        /// Constructs a new [`RemoveKeyValue`] for an [`Asset`] holding with the given `key`.
+        pub fn asset(asset_id: AssetId, key: Name) -> Self {
+            Self {
+                object: asset_id,
+                key,
+            }
+        }
+    }
+
     impl RemoveKeyValue<Nft> {
         /// Constructs a new [`RemoveKeyValue`] for an [`Nft`] with the given `key`.
         pub fn nft(nft_id: NftId, key: Name) -> Self {
@@ -427,6 +562,7 @@ mod transparent {
         RemoveKeyValue<Domain> |
         RemoveKeyValue<Account> |
         RemoveKeyValue<AssetDefinition> |
+        RemoveKeyValue<Asset> |
         RemoveKeyValue<Nft> |
         RemoveKeyValue<Trigger>
     => RemoveKeyValueBox => InstructionBox[RemoveKeyValue],
@@ -463,6 +599,20 @@ mod transparent {
                 object: new_account,
             }
         }
+
+        /// Constructs an [`Onboard`] registering an [`Account`] along with its initial `assets` and `roles`.
+        /// The metadata of the account is set with [`NewAccount::with_metadata`].
+        pub fn account_with(
+            new_account: NewAccount,
+            assets: impl IntoIterator<Item = (AssetDefinitionId, Numeric)>,
+            roles: impl IntoIterator<Item = RoleId>,
+        ) -> Onboard {
+            Onboard {
+                account: new_account,
+                assets: assets.into_iter().collect(),
+                roles: roles.into_iter().collect(),
+            }
+        }
     }
 
     impl Register<AssetDefinition> {
@@ -600,6 +750,160 @@ mod transparent {
         }
     }
 
+    isi! {
+        /// Generic instruction for a removal of the [`Tombstone`] left by an unregistered object.
+        pub struct Purge<O: Identifiable> {
+            /// [`Identifiable::Id`] of the unregistered object which should be purged.
+            pub object: O::Id,
+        }
+    }
+
+    impl_display! {
+        Purge<O>
+        where
+            O: Identifiable,
+            O::Id: Display,
+        =>
+        "PURGE `{}`",
+        object,
+    }
+
+    impl_into_box! {
+        Purge<Domain> |
+        Purge<Account>
+    => PurgeBox => InstructionBox[Purge],
+    => PurgeBoxRef<'a> => InstructionBoxRef<'a>[Purge]
+    }
+
+    impl Purge<Domain> {
+        /// Constructs a new [`Purge`] for a [`Domain`].
+        pub fn domain(domain_id: DomainId) -> Self {
+            Self { object: domain_id }
+        }
+    }
+
+    impl Purge<Account> {
+        /// Constructs a new [`Purge`] for an [`Account`].
+        pub fn account(account_id: AccountId) -> Self {
+            Self { object: account_id }
+        }
+    }
+
+    isi! {
+        /// Generic instruction for a deprecation of an object, retiring it after a sunset window.
+        pub struct Deprecate<O: Identifiable> {
+            /// [`Identifiable::Id`] of the object which should be deprecated.
+            pub object: O::Id,
+            /// Duration of the sunset window in milliseconds, counted from the creation of the block deprecating the object.
+            pub sunset_ms: u64,
+        }
+    }
+
+    impl_display! {
+        Deprecate<O>
+        where
+            O: Identifiable,
+            O::Id: Display,
+        =>
+        "DEPRECATE `{}` WITH SUNSET OF {} ms",
+        object,
+        sunset_ms,
+    }
+
+    impl Deprecate<AssetDefinition> {
+        /// Constructs a new [`Deprecate`] for an [`AssetDefinition`].
+        ///
+        /// The asset can't be minted anymore, while its holdings can be transferred and burned for `sunset_ms`.
+        pub fn asset_definition(asset_definition_id: AssetDefinitionId, sunset_ms: u64) -> Self {
+            Self {
+                object: asset_definition_id,
+                sunset_ms,
+            }
+        }
+    }
+
+    isi! {
+        /// Generic instruction for a compliance hold on an object, blocking transfers and burns of it until it's unfrozen.
+        pub struct Freeze<O: Identifiable> {
+            /// [`Identifiable::Id`] of the object which should be frozen.
+            pub object: O::Id,
+        }
+    }
+
+    impl_display! {
+        Freeze<O>
+        where
+            O: Identifiable,
+            O::Id: Display,
+        =>
+        "FREEZE `{}`",
+        object,
+    }
+
+    impl_into_box! {
+        Freeze<Asset> |
+        Freeze<AssetDefinition>
+    => FreezeBox => InstructionBox[Freeze],
+    => FreezeBoxRef<'a> => InstructionBoxRef<'a>[Freeze]
+    }
+
+    impl Freeze<Asset> {
+        /// Constructs a new [`Freeze`] for a single [`Asset`] holding.
+        pub fn asset(asset_id: AssetId) -> Self {
+            Self { object: asset_id }
+        }
+    }
+
+    impl Freeze<AssetDefinition> {
+        /// Constructs a new [`Freeze`] for all holdings of an [`AssetDefinition`].
+        pub fn asset_definition(asset_definition_id: AssetDefinitionId) -> Self {
+            Self {
+                object: asset_definition_id,
+            }
+        }
+    }
+
+    isi! {
+        /// Generic instruction for a release of the hold put on an object with [`Freeze`].
+        pub struct Unfreeze<O: Identifiable> {
+            /// [`Identifiable::Id`] of the object which should be unfrozen.
+            pub object: O::Id,
+        }
+    }
+
+    impl_display! {
+        Unfreeze<O>
+        where
+            O: Identifiable,
+            O::Id: Display,
+        =>
+        "UNFREEZE `{}`",
+        object,
+    }
+
+    impl_into_box! {
+        Unfreeze<Asset> |
+        Unfreeze<AssetDefinition>
+    => UnfreezeBox => InstructionBox[Unfreeze],
+    => UnfreezeBoxRef<'a> => InstructionBoxRef<'a>[Unfreeze]
+    }
+
+    impl Unfreeze<Asset> {
+        /// Constructs a new [`Unfreeze`] for a single [`Asset`] holding.
+        pub fn asset(asset_id: AssetId) -> Self {
+            Self { object: asset_id }
+        }
+    }
+
+    impl Unfreeze<AssetDefinition> {
+        /// Constructs a new [`Unfreeze`] for all holdings of an [`AssetDefinition`].
+        pub fn asset_definition(asset_definition_id: AssetDefinitionId) -> Self {
+            Self {
+                object: asset_definition_id,
+            }
+        }
+    }
+
     isi! {
         /// Generic instruction for a mint of an object to the identifiable destination.
         pub struct Mint<O, D: Identifiable> {
@@ -785,6 +1089,339 @@ mod transparent {
     => TransferBoxRef<'a> => InstructionBoxRef<'a>[Transfer]
     }
 
+    isi! {
+        /// Instruction to split the whole holding of an asset into parts credited to the given accounts.
+        ///
+        /// Meant for assets representing fractionalized instruments: the parts must add up
+        /// to exactly the quantity held, so that the total supply of the asset is preserved.
+        #[derive(Display)]
+        #[display(fmt = "SPLIT `{asset}` INTO {} PARTS", "parts.len()")]
+        pub struct Split {
+            /// Holding to split.
+            pub asset: AssetId,
+            /// Quantity credited to each of the receiving accounts.
+            pub parts: BTreeMap<AccountId, Numeric>,
+        }
+    }
+
+    impl Split {
+        /// Constructs a new [`Split`] of the `asset` holding.
+        pub fn new(
+            asset: AssetId,
+            parts: impl IntoIterator<Item = (AccountId, impl Into<Numeric>)>,
+        ) -> Self {
+            Self {
+                asset,
+                parts: parts
+                    .into_iter()
+                    .map(|(account, quantity)| (account, quantity.into()))
+                    .collect(),
+            }
+        }
+    }
+
+    isi! {
+        /// Instruction to merge whole holdings of the same asset into the holding of the destination account.
+        ///
+        /// The inverse of [`Split`]: the total supply of the asset is preserved.
+        #[derive(Display)]
+        #[display(fmt = "MERGE {} HOLDINGS INTO `{destination}`", "assets.len()")]
+        pub struct Merge {
+            /// Holdings to merge.
+            pub assets: BTreeSet<AssetId>,
+            /// Account receiving the merged quantity.
+            pub destination: AccountId,
+        }
+    }
+
+    impl Merge {
+        /// Constructs a new [`Merge`] of the `assets` holdings.
+        pub fn new(assets: impl IntoIterator<Item = AssetId>, destination: AccountId) -> Self {
+            Self {
+                assets: assets.into_iter().collect(),
+                destination,
+            }
+        }
+    }
+
+    isi! {
+        /// Instruction to pay quantities of an asset from a single holding to many accounts, e.g. the payroll.
+        ///
+        /// Unlike the equivalent [`Transfer`]s, the holding is checked and debited once for the total,
+        /// and a single [`AssetEvent::Removed`](crate::events::prelude::AssetEvent::Removed) is emitted for it.
+        /// Each payment is reported with its own [`AssetEvent::Added`](crate::events::prelude::AssetEvent::Added).
+        /// Either all the payments are made or, if any of them fails, none is.
+        #[derive(Display)]
+        #[display(fmt = "PAY OUT `{source}` TO {} ACCOUNTS", "payments.len()")]
+        pub struct Payout {
+            /// Holding the payments are made from.
+            pub source: AssetId,
+            /// Quantity paid to each of the accounts.
+            pub payments: BTreeMap<AccountId, Numeric>,
+        }
+    }
+
+    impl Payout {
+        /// Constructs a new [`Payout`] from the `source` holding.
+        pub fn new(
+            source: AssetId,
+            payments: impl IntoIterator<Item = (AccountId, impl Into<Numeric>)>,
+        ) -> Self {
+            Self {
+                source,
+                payments: payments
+                    .into_iter()
+                    .map(|(account, quantity)| (account, quantity.into()))
+                    .collect(),
+            }
+        }
+    }
+
+    isi! {
+        /// Instruction to transfer quantities of an asset from a single holding to many accounts, e.g. an airdrop.
+        ///
+        /// Counts as all of the transfers it consists of towards the transaction limits (see [`Self::transfers`]),
+        /// each of them emitting its own events. Either all the transfers are made or, if any of them fails, none is.
+        /// Unlike [`Payout`], an account may receive several transfers.
+        #[derive(Display)]
+        #[display(fmt = "TRANSFER `{source_asset}` TO {} RECIPIENTS", "recipients.len()")]
+        pub struct TransferMany {
+            /// Holding the quantities are transferred from.
+            pub source_asset: AssetId,
+            /// Transfers in the order they are made in.
+            pub recipients: Vec<TransferLeg>,
+        }
+    }
+
+    isi! {
+        /// Single transfer of [`TransferMany`].
+        #[derive(Constructor, Display)]
+        #[display(fmt = "`{quantity}` TO `{recipient}`")]
+        pub struct TransferLeg {
+            /// Account the quantity is transferred to.
+            pub recipient: AccountId,
+            /// Quantity transferred.
+            pub quantity: Numeric,
+        }
+    }
+
+    impl TransferMany {
+        /// Constructs a new [`TransferMany`] from the `source_asset` holding.
+        pub fn new(
+            source_asset: AssetId,
+            recipients: impl IntoIterator<Item = (AccountId, impl Into<Numeric>)>,
+        ) -> Self {
+            Self {
+                source_asset,
+                recipients: recipients
+                    .into_iter()
+                    .map(|(recipient, quantity)| TransferLeg::new(recipient, quantity.into()))
+                    .collect(),
+            }
+        }
+
+        /// Transfers the instruction consists of, in the order of execution.
+        pub fn transfers(&self) -> Vec<Transfer<Asset, Numeric, Account>> {
+            self.recipients
+                .iter()
+                .map(|leg| {
+                    Transfer::asset_numeric(
+                        self.source_asset.clone(),
+                        leg.quantity,
+                        leg.recipient.clone(),
+                    )
+                })
+                .collect()
+        }
+    }
+
+    isi! {
+        /// Instruction to allow the `spender` to transfer up to `limit` of the `owner`'s asset
+        /// with [`TransferFrom`], e.g. to let an exchange settle the orders of the owner.
+        ///
+        /// Replaces the allowance given to the `spender` before, zero `limit` withdraws it.
+        #[derive(Display)]
+        #[display(
+            fmt = "APPROVE `{spender}` TO SPEND `{limit}` OF `{asset_definition}` OF `{owner}`"
+        )]
+        pub struct Approve {
+            /// Account whose holding may be spent.
+            pub owner: AccountId,
+            /// Account allowed to spend the holding.
+            pub spender: AccountId,
+            /// Asset which may be spent.
+            pub asset_definition: AssetDefinitionId,
+            /// Quantity which may be spent in total.
+            pub limit: Numeric,
+        }
+    }
+
+    impl Approve {
+        /// Constructs a new [`Approve`] of the `owner`'s asset.
+        pub fn new(
+            owner: AccountId,
+            spender: AccountId,
+            asset_definition: AssetDefinitionId,
+            limit: impl Into<Numeric>,
+        ) -> Self {
+            Self {
+                owner,
+                spender,
+                asset_definition,
+                limit: limit.into(),
+            }
+        }
+    }
+
+    isi! {
+        /// Instruction to transfer a quantity of another account's asset on its behalf,
+        /// decreasing the allowance the authority was given with [`Approve`] by the quantity.
+        #[derive(Display)]
+        #[display(fmt = "TRANSFER `{object}` FROM `{source}` TO `{destination}` ON BEHALF")]
+        pub struct TransferFrom {
+            /// Holding of the owner the quantity is transferred from.
+            pub source: AssetId,
+            /// Quantity to transfer.
+            pub object: Numeric,
+            /// Account receiving the quantity.
+            pub destination: AccountId,
+        }
+    }
+
+    impl TransferFrom {
+        /// Constructs a new [`TransferFrom`] of the `quantity` of the owner's `asset_id` holding.
+        pub fn new(asset_id: AssetId, quantity: impl Into<Numeric>, to: AccountId) -> Self {
+            Self {
+                source: asset_id,
+                object: quantity.into(),
+                destination: to,
+            }
+        }
+    }
+
+    isi! {
+        /// Instruction to convert the whole holding of one asset into another one at the given `rate`,
+        /// e.g. to redenominate a currency or to redeem a wrapped asset.
+        ///
+        /// The holding of `from_definition` is burned and `rate` times its quantity of `to_definition`
+        /// is minted to the same `holder` atomically. The minted quantity is truncated to the scale of
+        /// `to_definition`, the remainder is lost, so that conversions never inflate the supply.
+        #[derive(Display)]
+        #[display(
+            fmt = "CONVERT `{from_definition}` OF `{holder}` TO `{to_definition}` AT `{rate}`"
+        )]
+        pub struct Convert {
+            /// Account whose holding is converted.
+            pub holder: AccountId,
+            /// Asset which is burned.
+            pub from_definition: AssetDefinitionId,
+            /// Asset which is minted.
+            pub to_definition: AssetDefinitionId,
+            /// Quantity of `to_definition` minted per unit of `from_definition`.
+            pub rate: Numeric,
+        }
+    }
+
+    impl Convert {
+        /// Constructs a new [`Convert`] of the `holder`'s asset.
+        pub fn new(
+            holder: AccountId,
+            from_definition: AssetDefinitionId,
+            to_definition: AssetDefinitionId,
+            rate: impl Into<Numeric>,
+        ) -> Self {
+            Self {
+                holder,
+                from_definition,
+                to_definition,
+                rate: rate.into(),
+            }
+        }
+    }
+
+    isi! {
+        /// Instruction to lock a quantity of an asset of the sender in an [`Escrow`] for the recipient.
+        ///
+        /// The quantity is debited from the holding of the sender right away and credited
+        /// with [`ClaimEscrow`] or [`RefundEscrow`], the total supply of the asset doesn't change.
+        #[derive(Constructor, Display)]
+        #[display(fmt = "CREATE ESCROW `{escrow}`")]
+        #[serde(transparent)]
+        #[repr(transparent)]
+        pub struct CreateEscrow {
+            /// Escrow to create.
+            pub escrow: Escrow,
+        }
+    }
+
+    isi! {
+        /// Instruction to credit the quantity locked in a released [`Escrow`] to its recipient.
+        #[derive(Constructor, Display)]
+        #[display(fmt = "CLAIM ESCROW `{escrow}`")]
+        #[serde(transparent)]
+        #[repr(transparent)]
+        pub struct ClaimEscrow {
+            /// Identification of the escrow to claim.
+            pub escrow: EscrowId,
+        }
+    }
+
+    isi! {
+        /// Instruction to credit the quantity locked in an expired [`Escrow`] back to its sender.
+        #[derive(Constructor, Display)]
+        #[display(fmt = "REFUND ESCROW `{escrow}`")]
+        #[serde(transparent)]
+        #[repr(transparent)]
+        pub struct RefundEscrow {
+            /// Identification of the escrow to refund.
+            pub escrow: EscrowId,
+        }
+    }
+
+    isi! {
+        /// Instruction to leave a [`DirectMessage`] in the inbox of the `recipient`,
+        /// e.g. settlement instructions for a counterparty.
+        ///
+        /// The `payload` is encrypted to a signatory of the `recipient`, so only the recipient can read it.
+        /// The message is pruned at the end of the `ttl`-th block after the one it was sent in.
+        #[derive(Constructor, Display)]
+        #[display(fmt = "MESSAGE `{recipient}` FOR {ttl} BLOCKS")]
+        pub struct Message {
+            /// Account the message is addressed to.
+            pub recipient: AccountId,
+            /// Content of the message.
+            pub payload: EncryptedValue,
+            /// Number of blocks the message is kept for.
+            pub ttl: core::num::NonZeroU64,
+        }
+    }
+
+    isi! {
+        /// Instruction to label an [`Account`] with a tag, e.g. `exchange`.
+        ///
+        /// Accounts are indexed by their tags, see [`FindAccountsWithTag`](crate::query::account::FindAccountsWithTag).
+        #[derive(Constructor, Display)]
+        #[display(fmt = "ADD TAG `{tag}` TO `{account}`")]
+        pub struct AddTag {
+            /// Account to label.
+            pub account: AccountId,
+            /// Tag to add.
+            pub tag: Name,
+        }
+    }
+
+    isi! {
+        /// Instruction to remove a tag added with [`AddTag`] from an [`Account`].
+        #[derive(Constructor, Display)]
+        #[display(fmt = "REMOVE TAG `{tag}` FROM `{account}`")]
+        pub struct RemoveTag {
+            /// Account to remove the tag from.
+            pub account: AccountId,
+            /// Tag to remove.
+            pub tag: Name,
+        }
+    }
+
     isi! {
         /// Generic instruction for granting permission to an entity.
         pub struct Grant<O, D: Identifiable> {
@@ -792,6 +1429,10 @@ mod transparent {
             pub object: O,
             /// Entity to which to grant this token.
             pub destination: D::Id,
+            /// Time (since the Unix epoch, in milliseconds) from which the grant is revoked.
+            /// Only the grants to accounts can expire.
+            #[serde(default)]
+            pub valid_until_ms: Option<u64>,
         }
     }
 
@@ -801,6 +1442,7 @@ mod transparent {
             Self {
                 object: permission.into(),
                 destination: to,
+                valid_until_ms: None,
             }
         }
     }
@@ -811,6 +1453,7 @@ mod transparent {
             Self {
                 object: role_id,
                 destination: to,
+                valid_until_ms: None,
             }
         }
     }
@@ -821,10 +1464,20 @@ mod transparent {
             Self {
                 object: permission.into(),
                 destination: to,
+                valid_until_ms: None,
             }
         }
     }
 
+    impl<O, D: Identifiable> Grant<O, D> {
+        /// Revoke the grant once a block is created at `valid_until_ms` (since the Unix epoch) or later.
+        #[must_use]
+        pub fn valid_until(mut self, valid_until_ms: u64) -> Self {
+            self.valid_until_ms = Some(valid_until_ms);
+            self
+        }
+    }
+
     impl_display! {
         Grant<O, D>
         where
@@ -905,6 +1558,183 @@ mod transparent {
     => RevokeBoxRef<'a> => InstructionBoxRef<'a>[Revoke]
     }
 
+    isi! {
+        /// Instruction to delegate a [`Permission`] held by the authority to another account.
+        ///
+        /// The delegated permission is revoked along with the permission of the authority.
+        /// See [`Delegation`] and [`MAX_DELEGATION_DEPTH`](crate::permission::MAX_DELEGATION_DEPTH).
+        #[derive(Constructor, Display)]
+        #[display(fmt = "DELEGATE `{permission}` TO `{destination}`")]
+        pub struct Delegate {
+            /// Permission to delegate.
+            pub permission: Permission,
+            /// Account to which to delegate the permission.
+            pub destination: AccountId,
+        }
+    }
+
+    iroha_data_model_derive::model_single! {
+        /// Instruction to execute the wrapped instruction, tagging the data events it emits.
+        ///
+        /// Lets the applications correlate the events with the instructions which caused them,
+        /// e.g. by subscribing with [`DataEventFilter::Tag`].
+        /// The instruction is validated by the executor as if it wasn't tagged.
+        /// Tagged instructions can't be nested.
+        #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Display)]
+        #[derive(getset::Getters)]
+        #[derive(parity_scale_codec::Encode, serde::Serialize)]
+        #[derive(iroha_schema::IntoSchema)]
+        #[getset(get = "pub")]
+        #[display(fmt = "TAG `{tag}`: {instruction}")]
+        pub struct Tagged {
+            /// Tag copied onto the events.
+            pub tag: Name,
+            /// Instruction to execute, which can't be [`Tagged`] itself.
+            pub instruction: Box<InstructionBox>,
+        }
+    }
+
+    impl Tagged {
+        /// Construct [`Tagged`] executing the `instruction` under the `tag`.
+        ///
+        /// # Panics
+        ///
+        /// If the `instruction` is [`Tagged`] itself
+        pub fn new(tag: Name, instruction: impl Into<InstructionBox>) -> Self {
+            let tagged = candidate::TaggedCandidate {
+                tag,
+                instruction: Box::new(instruction.into()),
+            };
+
+            tagged.validate().unwrap()
+        }
+    }
+
+    mod candidate {
+        use parity_scale_codec::Input;
+
+        use super::*;
+
+        #[derive(Decode, Deserialize)]
+        pub(super) struct TaggedCandidate {
+            pub tag: Name,
+            pub instruction: Box<InstructionBox>,
+        }
+
+        impl TaggedCandidate {
+            pub(super) fn validate(self) -> Result<Tagged, &'static str> {
+                if matches!(*self.instruction, InstructionBox::Tagged(_)) {
+                    return Err("Tagged instructions can't be nested");
+                }
+
+                Ok(Tagged {
+                    tag: self.tag,
+                    instruction: self.instruction,
+                })
+            }
+        }
+
+        /// [`Input`] yielding the already read byte before the rest of the input.
+        struct Peeked<'input, I> {
+            byte: Option<u8>,
+            input: &'input mut I,
+        }
+
+        impl<I: Input> Input for Peeked<'_, I> {
+            fn remaining_len(&mut self) -> Result<Option<usize>, parity_scale_codec::Error> {
+                let remaining = self.input.remaining_len()?;
+                Ok(remaining.map(|len| len + usize::from(self.byte.is_some())))
+            }
+
+            fn read(&mut self, into: &mut [u8]) -> Result<(), parity_scale_codec::Error> {
+                match (self.byte.take(), into.split_first_mut()) {
+                    (Some(byte), Some((first, rest))) => {
+                        *first = byte;
+                        self.input.read(rest)
+                    }
+                    (byte, _) => {
+                        self.byte = byte;
+                        self.input.read(into)
+                    }
+                }
+            }
+        }
+
+        impl Decode for Tagged {
+            fn decode<I: Input>(input: &mut I) -> Result<Self, parity_scale_codec::Error> {
+                let tag = Name::decode(input)?;
+
+                // Reject the nested instruction before decoding it to not recurse without bound
+                let discriminant = input.read_byte()?;
+                if discriminant == InstructionType::Tagged as u8 {
+                    return Err("Tagged instructions can't be nested".into());
+                }
+                let instruction = Box::new(InstructionBox::decode(&mut Peeked {
+                    byte: Some(discriminant),
+                    input,
+                })?);
+
+                TaggedCandidate { tag, instruction }
+                    .validate()
+                    .map_err(Into::into)
+            }
+        }
+
+        impl<'de> Deserialize<'de> for Tagged {
+            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                use serde::de::Error as _;
+
+                TaggedCandidate::deserialize(deserializer)?
+                    .validate()
+                    .map_err(D::Error::custom)
+            }
+        }
+    }
+
+    isi! {
+        /// Instruction to register an [`Account`] along with its initial assets and roles.
+        ///
+        /// Counts as all of the instructions it consists of towards the transaction limits.
+        /// Validated by the executor as the instructions it consists of (see [`Self::instructions`]),
+        /// which either all succeed or all fail.
+        #[derive(Display)]
+        #[display(fmt = "ONBOARD `{account}`")]
+        pub struct Onboard {
+            /// Account to register.
+            pub account: NewAccount,
+            /// Quantities of the assets minted to the account.
+            pub assets: BTreeMap<AssetDefinitionId, Numeric>,
+            /// Roles granted to the account.
+            pub roles: BTreeSet<RoleId>,
+        }
+    }
+
+    impl Onboard {
+        /// Instructions the onboarding consists of, in the order of execution:
+        /// [`Register<Account>`], [`Mint`] for every asset and [`Grant`] for every role.
+        pub fn instructions(&self) -> Vec<InstructionBox> {
+            let account_id = &self.account.id;
+
+            core::iter::once(Register::account(self.account.clone()).into())
+                .chain(self.assets.iter().map(|(definition, quantity)| {
+                    Mint::asset_numeric(
+                        *quantity,
+                        AssetId::new(definition.clone(), account_id.clone()),
+                    )
+                    .into()
+                }))
+                .chain(
+                    self.roles
+                        .iter()
+                        .map(|role| Grant::account_role(role.clone(), account_id.clone()).into()),
+                )
+                .collect()
+        }
+    }
+
     isi! {
         /// Instruction to execute specified trigger
         #[derive(Display)]
@@ -946,6 +1776,55 @@ mod transparent {
         }
     }
 
+    isi! {
+        /// Instruction to persist a notification [`Subscription`] on-chain.
+        #[derive(Constructor, Display)]
+        #[display(fmt = "SUBSCRIBE `{subscription}`")]
+        #[serde(transparent)]
+        #[repr(transparent)]
+        pub struct Subscribe {
+            /// Subscription to register.
+            pub subscription: Subscription,
+        }
+    }
+
+    isi! {
+        /// Instruction to remove a notification [`Subscription`].
+        #[derive(Constructor, Display)]
+        #[display(fmt = "UNSUBSCRIBE `{subscription}`")]
+        #[serde(transparent)]
+        #[repr(transparent)]
+        pub struct Unsubscribe {
+            /// Identification of the subscription to remove.
+            pub subscription: SubscriptionId,
+        }
+    }
+
+    isi! {
+        /// Instruction to execute the trigger of a [`DeadLetter`] again with the recorded event.
+        /// The dead letter is removed once the execution succeeds.
+        #[derive(Constructor, Display)]
+        #[display(fmt = "RETRY `{dead_letter}`")]
+        #[serde(transparent)]
+        #[repr(transparent)]
+        pub struct RetryDeadLetter {
+            /// Identification of the dead letter to retry.
+            pub dead_letter: DeadLetterId,
+        }
+    }
+
+    isi! {
+        /// Instruction to drop a [`DeadLetter`] without executing its trigger again.
+        #[derive(Constructor, Display)]
+        #[display(fmt = "DISCARD `{dead_letter}`")]
+        #[serde(transparent)]
+        #[repr(transparent)]
+        pub struct DiscardDeadLetter {
+            /// Identification of the dead letter to discard.
+            pub dead_letter: DeadLetterId,
+        }
+    }
+
     isi! {
         /// Instruction to print logs
         #[derive(Constructor, Display)]
@@ -1031,6 +1910,8 @@ isi_box! {
         Nft(SetKeyValue<Nft>),
         /// Set key value for [`Trigger`].
         Trigger(SetKeyValue<Trigger>),
+        /// Set key value for [`Asset`].
+        Asset(SetKeyValue<Asset>),
     }
 }
 
@@ -1052,6 +1933,8 @@ isi_box! {
         Nft(RemoveKeyValue<Nft>),
         /// Remove key value for [`Trigger`].
         Trigger(RemoveKeyValue<Trigger>),
+        /// Remove key value from [`Asset`].
+        Asset(RemoveKeyValue<Asset>),
     }
 }
 
@@ -1105,6 +1988,51 @@ isi_box! {
     }
 }
 
+isi_box! {
+    #[strum_discriminants(
+        vis(pub(crate)),
+        name(PurgeType),
+        derive(Encode),
+    )]
+    /// Enum with all supported [`Purge`] instructions.
+    pub enum PurgeBox {
+        /// Purge [`Domain`].
+        Domain(Purge<Domain>),
+        /// Purge [`Account`].
+        Account(Purge<Account>),
+    }
+}
+
+isi_box! {
+    #[strum_discriminants(
+        vis(pub(crate)),
+        name(FreezeType),
+        derive(Encode),
+    )]
+    /// Enum with all supported [`Freeze`] instructions.
+    pub enum FreezeBox {
+        /// Freeze [`Asset`].
+        Asset(Freeze<Asset>),
+        /// Freeze [`AssetDefinition`].
+        AssetDefinition(Freeze<AssetDefinition>),
+    }
+}
+
+isi_box! {
+    #[strum_discriminants(
+        vis(pub(crate)),
+        name(UnfreezeType),
+        derive(Encode),
+    )]
+    /// Enum with all supported [`Unfreeze`] instructions.
+    pub enum UnfreezeBox {
+        /// Unfreeze [`Asset`].
+        Asset(Unfreeze<Asset>),
+        /// Unfreeze [`AssetDefinition`].
+        AssetDefinition(Unfreeze<AssetDefinition>),
+    }
+}
+
 isi_box! {
     #[strum_discriminants(
         vis(pub(crate)),
@@ -1204,6 +2132,7 @@ pub mod error {
     pub use self::model::*;
     use super::InstructionType;
     use crate::{
+        asset::AssetKind,
         prelude::NumericSpec,
         query::error::{FindError, QueryExecutionFail},
         IdBox,
@@ -1341,6 +2270,8 @@ pub mod error {
         pub enum TypeError {
             /// Asset definition numeric spec mismatch (asset can't hold provided numeric value)
             AssetNumericSpec(#[cfg_attr(feature = "std", source)] Mismatch<NumericSpec>),
+            /// Asset definition kind mismatch (e.g. minting a key-value store asset)
+            AssetKind(#[cfg_attr(feature = "std", source)] Mismatch<AssetKind>),
         }
 
         /// Math error, which occurs during instruction execution
@@ -1406,6 +2337,12 @@ pub mod error {
             MintUnmintable,
             /// This asset was set as infinitely mintable. You cannot forbid its minting
             ForbidMintOnMintable,
+            /// This asset is deprecated, it can't be minted anymore
+            MintDeprecated,
+            /// Minting would raise the total quantity of this asset above its maximum supply
+            SupplyCapExceeded,
+            /// Minting would exceed the amount of this asset that can be minted in the current period
+            PeriodLimitExceeded,
         }
 
         /// Invalid instruction parameter error
@@ -1485,9 +2422,12 @@ pub mod error {
 /// The prelude re-exports most commonly used traits, structs and macros from this crate.
 pub mod prelude {
     pub use super::{
-        Burn, BurnBox, CustomInstruction, ExecuteTrigger, Grant, GrantBox, Instruction,
-        InstructionBox, Log, Mint, MintBox, Register, RegisterBox, RemoveKeyValue,
-        RemoveKeyValueBox, Revoke, RevokeBox, SetKeyValue, SetKeyValueBox, SetParameter, Transfer,
-        TransferBox, Unregister, UnregisterBox, Upgrade,
+        AddTag, Approve, Burn, BurnBox, ClaimEscrow, Convert, CreateEscrow, CustomInstruction,
+        Delegate, Deprecate, DiscardDeadLetter, ExecuteTrigger, Freeze, FreezeBox, Grant, GrantBox,
+        Instruction, InstructionBox, Log, Merge, Message, Mint, MintBox, Onboard, Payout, Purge,
+        PurgeBox, RefundEscrow, Register, RegisterBox, RemoveKeyValue, RemoveKeyValueBox,
+        RemoveTag, RetryDeadLetter, Revoke, RevokeBox, SetKeyValue, SetKeyValueBox, SetParameter,
+        Split, Subscribe, Tagged, Transfer, TransferBox, TransferFrom, TransferLeg, TransferMany,
+        Unfreeze, UnfreezeBox, Unregister, UnregisterBox, Unsubscribe, Upgrade,
     };
 }
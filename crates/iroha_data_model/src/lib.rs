@@ -34,11 +34,14 @@ use crate::name::Name;
 pub mod account;
 pub mod asset;
 pub mod block;
+pub mod dead_letter;
 pub mod domain;
+pub mod escrow;
 pub mod events;
 pub mod executor;
 pub mod ipfs;
 pub mod isi;
+pub mod message;
 pub mod metadata;
 pub mod name;
 pub mod nft;
@@ -48,6 +51,8 @@ pub mod permission;
 pub mod query;
 pub mod role;
 pub mod smart_contract;
+pub mod subscription;
+pub mod tombstone;
 pub mod transaction;
 pub mod trigger;
 pub mod visit;
@@ -71,12 +76,14 @@ mod seal {
 
         SetKeyValue<Domain>,
         SetKeyValue<AssetDefinition>,
+        SetKeyValue<Asset>,
         SetKeyValue<Account>,
         SetKeyValue<Nft>,
         SetKeyValue<Trigger>,
 
         RemoveKeyValue<Domain>,
         RemoveKeyValue<AssetDefinition>,
+        RemoveKeyValue<Asset>,
         RemoveKeyValue<Account>,
         RemoveKeyValue<Nft>,
         RemoveKeyValue<Trigger>,
@@ -97,6 +104,9 @@ mod seal {
         Unregister<Role>,
         Unregister<Trigger>,
 
+        Purge<Domain>,
+        Purge<Account>,
+
         Mint<Numeric, Asset>,
         Mint<u32, Trigger>,
 
@@ -108,6 +118,31 @@ mod seal {
         Transfer<Asset, Numeric, Account>,
         Transfer<Account, NftId, Account>,
 
+        Split,
+        Merge,
+
+        Deprecate<AssetDefinition>,
+        Delegate,
+        Tagged,
+        Onboard,
+        Payout,
+        AddTag,
+        RemoveTag,
+
+        Freeze<Asset>,
+        Freeze<AssetDefinition>,
+        Unfreeze<Asset>,
+        Unfreeze<AssetDefinition>,
+
+        Approve,
+        TransferFrom,
+        Message,
+        Convert,
+        CreateEscrow,
+        ClaimEscrow,
+        RefundEscrow,
+        TransferMany,
+
         Grant<Permission, Account>,
         Grant<RoleId, Account>,
         Grant<Permission, Role>,
@@ -120,13 +155,20 @@ mod seal {
         Upgrade,
         ExecuteTrigger,
         Log,
+        Subscribe,
+        Unsubscribe,
+        RetryDeadLetter,
+        DiscardDeadLetter,
 
         // Boxed queries
         SingularQueryBox,
         FindAccounts,
         FindAccountsWithAsset,
+        FindAccountsWithRole,
+        FindAccountsWithTag,
         FindAssets,
         FindAssetsDefinitions,
+        FindAssetsWithDefinition,
         FindNfts,
         FindDomains,
         FindPeers,
@@ -141,6 +183,16 @@ mod seal {
         FindRoleIds,
         FindRolesByAccountId,
         FindParameters,
+        FindAllParameters,
+        FindTombstone,
+        FindAssetQuantityAt,
+        FindSubscription,
+        FindDeadLetters,
+        FindDelegations,
+        FindAllowances,
+        FindInbox,
+        FindEscrows,
+        FindFuelSchedule,
     }
 }
 
@@ -518,11 +570,12 @@ pub mod prelude {
     };
 
     pub use super::{
-        account::prelude::*, asset::prelude::*, block::prelude::*, domain::prelude::*,
-        events::prelude::*, executor::prelude::*, ipfs::IpfsPath, isi::prelude::*,
-        metadata::prelude::*, name::prelude::*, nft::prelude::*, parameter::prelude::*,
-        peer::prelude::*, permission::prelude::*, query::prelude::*, role::prelude::*,
-        transaction::prelude::*, trigger::prelude::*, ChainId, EnumTryAsError, HasMetadata, IdBox,
-        Identifiable, Level, Registrable, ValidationFail,
+        account::prelude::*, asset::prelude::*, block::prelude::*, dead_letter::prelude::*,
+        domain::prelude::*, escrow::prelude::*, events::prelude::*, executor::prelude::*,
+        ipfs::IpfsPath, isi::prelude::*, message::prelude::*, metadata::prelude::*,
+        name::prelude::*, nft::prelude::*, parameter::prelude::*, peer::prelude::*,
+        permission::prelude::*, query::prelude::*, role::prelude::*, subscription::prelude::*,
+        tombstone::prelude::*, transaction::prelude::*, trigger::prelude::*, ChainId,
+        EnumTryAsError, HasMetadata, IdBox, Identifiable, Level, Registrable, ValidationFail,
     };
 }
@@ -0,0 +1,104 @@
+//! Structures, traits and impls related to `DirectMessage`s.
+//!
+//! A [`DirectMessage`] is left in the inbox of an account with the [`Message`](crate::isi::Message)
+//! instruction, so that counterparties can coordinate on-ledger, e.g. exchange settlement instructions.
+//! The payload is an [`EncryptedValue`] readable only by the recipient,
+//! and the message is pruned once its time to live is over.
+
+#[cfg(not(feature = "std"))]
+use alloc::{format, string::String, vec::Vec};
+use core::num::NonZeroU64;
+
+use iroha_data_model_derive::model;
+
+pub use self::model::*;
+use crate::{account::AccountId, metadata::EncryptedValue};
+
+/// Maximal size of the ciphertext of a [`DirectMessage`] in bytes.
+pub const MAX_MESSAGE_PAYLOAD_LEN: usize = 4096;
+
+/// Maximal number of blocks a [`DirectMessage`] is kept for.
+pub const MAX_MESSAGE_TTL_BLOCKS: u64 = 100_000;
+
+/// Number of messages an inbox holds; new messages are rejected until the oldest ones expire.
+pub const MAX_MESSAGES_PER_INBOX: usize = 256;
+
+/// Number of messages a sender may keep in the inbox of another account at once.
+pub const MAX_MESSAGES_PER_SENDER: usize = 16;
+
+#[model]
+mod model {
+    use derive_more::{Constructor, Display};
+    use getset::Getters;
+    use iroha_schema::IntoSchema;
+    use parity_scale_codec::{Decode, Encode};
+    use serde::{Deserialize, Serialize};
+
+    use super::*;
+
+    /// Identification of a [`DirectMessage`]: messages to an account are numbered in the order they were sent.
+    #[derive(
+        Debug,
+        Display,
+        Clone,
+        PartialEq,
+        Eq,
+        PartialOrd,
+        Ord,
+        Hash,
+        Constructor,
+        Getters,
+        Decode,
+        Encode,
+        Deserialize,
+        Serialize,
+        IntoSchema,
+    )]
+    #[display(fmt = "{recipient}#{sequence}")]
+    #[getset(get = "pub")]
+    #[ffi_type]
+    pub struct DirectMessageId {
+        /// Account the message is addressed to.
+        pub recipient: AccountId,
+        /// Sequence number of the message among the messages in the inbox of the recipient.
+        pub sequence: u64,
+    }
+
+    /// Encrypted message kept in the inbox of the recipient until it expires.
+    #[derive(
+        Debug,
+        Display,
+        Clone,
+        PartialEq,
+        Eq,
+        PartialOrd,
+        Ord,
+        Constructor,
+        Getters,
+        Decode,
+        Encode,
+        Deserialize,
+        Serialize,
+        IntoSchema,
+    )]
+    #[display(fmt = "{id} from {sender}")]
+    #[getset(get = "pub")]
+    #[ffi_type]
+    pub struct DirectMessage {
+        /// Identification of the message.
+        pub id: DirectMessageId,
+        /// Account which sent the message.
+        pub sender: AccountId,
+        /// Content of the message, encrypted to a signatory of the recipient.
+        pub payload: EncryptedValue,
+        /// Height of the block in which the message was sent.
+        pub sent_at: NonZeroU64,
+        /// Height of the block at the end of which the message is pruned.
+        pub expires_at: NonZeroU64,
+    }
+}
+
+/// The prelude re-exports most commonly used traits, structs and macros from this module.
+pub mod prelude {
+    pub use super::{DirectMessage, DirectMessageId};
+}
@@ -6,8 +6,11 @@ use core::borrow::Borrow;
 #[cfg(feature = "std")]
 use std::collections::BTreeMap;
 
+use iroha_crypto::PublicKey;
 use iroha_data_model_derive::model;
+pub use iroha_data_model_derive::MetadataValue;
 use iroha_primitives::json::Json;
+use serde::{de::DeserializeOwned, Serialize};
 
 pub use self::model::*;
 use crate::prelude::Name;
@@ -46,6 +49,61 @@ mod model {
     #[display(fmt = "Metadata")]
     #[allow(clippy::multiple_inherent_impl)]
     pub struct Metadata(pub(super) BTreeMap<Name, Json>);
+
+    /// Scheme an [`EncryptedValue`] is encrypted with
+    #[derive(
+        Debug,
+        Display,
+        Clone,
+        Copy,
+        PartialEq,
+        Eq,
+        PartialOrd,
+        Ord,
+        Hash,
+        Decode,
+        Encode,
+        Deserialize,
+        Serialize,
+        IntoSchema,
+    )]
+    #[ffi_type]
+    #[repr(u8)]
+    pub enum EncryptionAlgorithm {
+        /// `ChaCha20Poly1305` under the key agreed by `X25519` between an ephemeral key
+        /// and the Ed25519 key of the recipient, see `iroha_crypto::encryption::sealed`
+        X25519ChaCha20Poly1305,
+    }
+
+    /// Metadata value readable only by the holder of the private key of the recipient.
+    ///
+    /// Stored in [`Metadata`] as JSON, so that the encrypted attributes
+    /// of any entity share the same format.
+    #[derive(
+        Debug,
+        Display,
+        Clone,
+        PartialEq,
+        Eq,
+        PartialOrd,
+        Ord,
+        Decode,
+        Encode,
+        Deserialize,
+        Serialize,
+        IntoSchema,
+    )]
+    #[display(fmt = "{algorithm} value for {recipient}")]
+    #[ffi_type]
+    pub struct EncryptedValue {
+        /// Scheme the value is encrypted with
+        pub algorithm: EncryptionAlgorithm,
+        /// Public key of the recipient, hinting which key decrypts the value
+        pub recipient: PublicKey,
+        /// Encrypted value, in the format defined by the `algorithm`
+        #[serde(with = "crate::transaction::base64")]
+        pub ciphertext: Vec<u8>,
+    }
 }
 
 impl Metadata {
@@ -73,6 +131,128 @@ impl Metadata {
     pub fn insert(&mut self, key: Name, value: impl Into<Json>) -> Option<Json> {
         self.0.insert(key, value.into())
     }
+
+    /// Serialize `value` and insert it under the given key.  Returns `Some(value)`
+    /// if the value was already present, `None` otherwise.
+    pub fn set_serde<T: Serialize + ?Sized>(&mut self, key: Name, value: &T) -> Option<Json> {
+        self.insert(key, Json::new(value))
+    }
+
+    /// Deserialize the value associated to `key`. Return `Ok(None)` if not found.
+    ///
+    /// # Errors
+    /// If the value is not a valid representation of `T`
+    pub fn get_serde<T: DeserializeOwned>(
+        &self,
+        key: &Name,
+    ) -> Result<Option<T>, serde_json::Error> {
+        self.get(key).map(Json::try_into_any).transpose()
+    }
+
+    /// Insert `value` under the key of its type, see [`MetadataValue`].
+    /// Returns `Some(value)` if the value was already present, `None` otherwise.
+    pub fn set_typed<T: MetadataValue>(&mut self, value: &T) -> Option<Json> {
+        self.set_serde(T::key(), value)
+    }
+
+    /// Get the value of type `T` stored under its key, see [`MetadataValue`].
+    /// Return `Ok(None)` if not found.
+    ///
+    /// # Errors
+    /// If the value is not a valid representation of `T`
+    pub fn get_typed<T: MetadataValue>(&self) -> Result<Option<T>, serde_json::Error> {
+        self.get_serde(&T::key())
+    }
+
+    /// Get the value nested under `path`, e.g. `a.b[0]` is the first element of the array
+    /// under the field `b` of the value stored under the key `a`. Return `Ok(None)` if not found.
+    ///
+    /// # Errors
+    /// If the path is malformed
+    pub fn get_path(&self, path: &str) -> Result<Option<Json>, PathError> {
+        let malformed = || PathError::Malformed(String::from(path));
+        let mut segments = path_segments(path).ok_or_else(malformed)?.into_iter();
+        let Some(PathSegment::Key(key)) = segments.next() else {
+            return Err(malformed());
+        };
+        let Some(value) = self.get(key) else {
+            return Ok(None);
+        };
+        if segments.len() == 0 {
+            return Ok(Some(value.clone()));
+        }
+
+        let value: serde_json::Value = value.try_into_any().map_err(PathError::Type)?;
+        let mut nested = &value;
+        for segment in segments {
+            let next = match segment {
+                PathSegment::Key(key) => nested.get(key),
+                PathSegment::Index(index) => nested.get(index),
+            };
+            let Some(next) = next else {
+                return Ok(None);
+            };
+            nested = next;
+        }
+
+        Ok(Some(Json::from(nested)))
+    }
+
+    /// Deserialize the value nested under `path`, see [`Self::get_path`].
+    /// Return `Ok(None)` if not found.
+    ///
+    /// # Errors
+    /// - If the path is malformed
+    /// - If the value is not a valid representation of `T`
+    pub fn get_as<T: DeserializeOwned>(&self, path: &str) -> Result<Option<T>, PathError> {
+        self.get_path(path)?
+            .map(|value| value.try_into_any().map_err(PathError::Type))
+            .transpose()
+    }
+}
+
+/// Error of a lookup by path, see [`Metadata::get_path`]
+#[derive(Debug, displaydoc::Display)]
+#[cfg_attr(feature = "std", derive(thiserror::Error))]
+pub enum PathError {
+    /// Malformed path `{0}`
+    Malformed(String),
+    /// Value is not a valid representation of the requested type
+    Type(#[cfg_attr(feature = "std", source)] serde_json::Error),
+}
+
+enum PathSegment<'path> {
+    Key(&'path str),
+    Index(usize),
+}
+
+/// Split `path` of the form `key.field[index]` into segments
+fn path_segments(path: &str) -> Option<Vec<PathSegment<'_>>> {
+    let mut segments = Vec::new();
+    for part in path.split('.') {
+        let (key, mut indices) = part.split_at(part.find('[').unwrap_or(part.len()));
+        if key.is_empty() {
+            return None;
+        }
+        segments.push(PathSegment::Key(key));
+        while !indices.is_empty() {
+            let (index, rest) = indices.strip_prefix('[')?.split_once(']')?;
+            segments.push(PathSegment::Index(index.parse().ok()?));
+            indices = rest;
+        }
+    }
+
+    Some(segments)
+}
+
+/// Application value stored in [`Metadata`] under a key of its own,
+/// see [`Metadata::set_typed`] and [`Metadata::get_typed`].
+///
+/// Derive it with [`MetadataValue`](derive@MetadataValue): the key is the name of the type,
+/// unless it's set with `#[metadata_value(key = "...")]`.
+pub trait MetadataValue: Serialize + DeserializeOwned {
+    /// Key the value is stored under
+    fn key() -> Name;
 }
 
 #[cfg(feature = "transparent_api")]
@@ -91,5 +271,41 @@ impl Metadata {
 
 pub mod prelude {
     //! Prelude: re-export most commonly used traits, structs and macros from this module.
-    pub use super::Metadata;
+    pub use super::{EncryptedValue, EncryptionAlgorithm, Metadata, MetadataValue};
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn get_nested_values_by_path() {
+        let mut metadata = Metadata::default();
+        metadata.insert(
+            "a".parse().unwrap(),
+            json!({ "b": [1, { "c": "d" }], "e": true }),
+        );
+
+        assert_eq!(metadata.get_as::<u64>("a.b[0]").unwrap(), Some(1));
+        assert_eq!(
+            metadata.get_as::<String>("a.b[1].c").unwrap(),
+            Some("d".to_owned())
+        );
+        assert_eq!(metadata.get_as::<bool>("a.e").unwrap(), Some(true));
+        assert_eq!(metadata.get_path("a.b[2]").unwrap(), None);
+        assert_eq!(metadata.get_path("a.e.f").unwrap(), None);
+        assert_eq!(metadata.get_path("x.b").unwrap(), None);
+        assert!(matches!(
+            metadata.get_as::<u64>("a.b[1]"),
+            Err(PathError::Type(_))
+        ));
+        for malformed in ["", "a..b", "a.b[", "a.b[x]", "a.b]0[", ".a", "[0]"] {
+            assert!(
+                matches!(metadata.get_path(malformed), Err(PathError::Malformed(_))),
+                "{malformed} should be malformed"
+            );
+        }
+    }
 }
@@ -86,6 +86,10 @@ mod model {
         /// The account that owns this NFT.
         #[getset(get = "pub")]
         pub owned_by: AccountId,
+        /// Whether the owner can transfer this NFT, see [`NewNft::non_transferable`].
+        #[getset(get_copy = "pub")]
+        #[serde(default = "super::transferable_by_default")]
+        pub transferable: bool,
     }
 
     /// Builder which can be submitted in a transaction to create a new [`Nft`]
@@ -100,9 +104,16 @@ mod model {
         pub id: NftId,
         /// Content of the [`Nft`], as a key-value store.
         pub content: Metadata,
+        /// Whether the owner can transfer the [`Nft`].
+        #[serde(default = "super::transferable_by_default")]
+        pub transferable: bool,
     }
 }
 
+fn transferable_by_default() -> bool {
+    true
+}
+
 /// Read-only reference to [`Nft`].
 /// Used in query filters to avoid copying.
 pub struct NftEntry<'world> {
@@ -112,6 +123,8 @@ pub struct NftEntry<'world> {
     pub content: &'world Metadata,
     /// The account that owns this NFT.
     pub owned_by: &'world AccountId,
+    /// Whether the owner can transfer this NFT.
+    pub transferable: bool,
 }
 
 /// [`Nft`] without `id` field.
@@ -123,12 +136,31 @@ pub struct NftValue {
     pub content: Metadata,
     /// The account that owns this NFT.
     pub owned_by: AccountId,
+    /// Whether the owner can transfer this NFT.
+    #[serde(default = "transferable_by_default")]
+    pub transferable: bool,
 }
 
 impl Nft {
     /// Constructor
     pub fn new(id: NftId, content: Metadata) -> <Self as Registered>::With {
-        NewNft { id, content }
+        NewNft {
+            id,
+            content,
+            transferable: true,
+        }
+    }
+}
+
+impl NewNft {
+    /// Bind the [`Nft`] to its owner, e.g. for a certificate or a membership.
+    ///
+    /// Such NFT can only be transferred by the owner of its domain, which issues it to the holders and revokes it.
+    #[inline]
+    #[must_use]
+    pub fn non_transferable(mut self) -> Self {
+        self.transferable = false;
+        self
     }
 }
 
@@ -173,6 +205,7 @@ impl Registrable for NewNft {
             id: self.id,
             content: self.content,
             owned_by: authority.clone(),
+            transferable: self.transferable,
         }
     }
 }
@@ -184,6 +217,7 @@ impl<'world> NftEntry<'world> {
             id,
             content: &value.content,
             owned_by: &value.owned_by,
+            transferable: value.transferable,
         }
     }
 
@@ -202,12 +236,18 @@ impl<'world> NftEntry<'world> {
         self.owned_by
     }
 
+    /// Getter for `transferable`
+    pub fn transferable(&self) -> bool {
+        self.transferable
+    }
+
     /// Converts to `Nft`
     pub fn to_owned(&self) -> Nft {
         Nft {
             id: self.id.clone(),
             content: self.content.clone(),
             owned_by: self.owned_by.clone(),
+            transferable: self.transferable,
         }
     }
 }
@@ -219,6 +259,7 @@ impl IntoKeyValue for Nft {
         let value = NftValue {
             content: self.content,
             owned_by: self.owned_by,
+            transferable: self.transferable,
         };
         (self.id, value)
     }
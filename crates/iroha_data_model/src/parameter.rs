@@ -88,6 +88,11 @@ mod model {
         /// This value should be kept as low as possible to not affect soundness of the consensus
         #[serde(default = "defaults::sumeragi::max_clock_drift_ms")]
         pub max_clock_drift_ms: u64,
+        /// Number of blocks a committed change of the peer set waits before being applied to the topology.
+        ///
+        /// Gives operators time to provision or decommission a peer before it starts (or stops) voting
+        #[serde(default = "defaults::sumeragi::peer_activation_delay_blocks")]
+        pub peer_activation_delay_blocks: u64,
     }
 
     /// Single Sumeragi parameter
@@ -112,6 +117,7 @@ mod model {
         BlockTimeMs(u64),
         CommitTimeMs(u64),
         MaxClockDriftMs(u64),
+        PeerActivationDelayBlocks(u64),
     }
 
     /// Limits that a block must obey to be accepted.
@@ -129,7 +135,7 @@ mod model {
         Serialize,
         IntoSchema,
     )]
-    #[display(fmt = "{max_transactions}_BL")]
+    #[display(fmt = "{max_transactions},{max_heavy_instructions}_BL")]
     #[getset(get_copy = "pub")]
     pub struct BlockParameters {
         /// Maximal number of transactions in a block.
@@ -137,6 +143,11 @@ mod model {
         /// A block is created if this limit is reached or [`SumeragiParameters::block_time_ms`] has expired,
         /// whichever comes first. Regardless of the limits, an empty block is never created.
         pub max_transactions: NonZeroU64,
+        /// Maximal number of heavyweight instructions in a block.
+        ///
+        /// Executor upgrades, registrations of triggers with WASM executables and WASM smart contracts
+        /// are heavyweight. Transactions which don't fit are left for the following blocks.
+        pub max_heavy_instructions: NonZeroU64,
     }
 
     /// Single block parameter
@@ -147,6 +158,7 @@ mod model {
     )]
     pub enum BlockParameter {
         MaxTransactions(NonZeroU64),
+        MaxHeavyInstructions(NonZeroU64),
     }
 
     /// Limits that a transaction must obey to be accepted.
@@ -164,13 +176,19 @@ mod model {
         Serialize,
         IntoSchema,
     )]
-    #[display(fmt = "{max_instructions},{smart_contract_size}_TL")]
+    #[display(
+        fmt = "{max_instructions},{smart_contract_size},{max_metadata_depth},{max_metadata_size}_TL"
+    )]
     #[getset(get_copy = "pub")]
     pub struct TransactionParameters {
         /// Maximum number of instructions per transaction
         pub max_instructions: NonZeroU64,
         /// Maximum size of wasm binary in bytes
         pub smart_contract_size: NonZeroU64,
+        /// Maximum nesting depth of a metadata value inserted by an instruction
+        pub max_metadata_depth: NonZeroU64,
+        /// Maximum size of a metadata value inserted by an instruction, in bytes of its JSON
+        pub max_metadata_size: NonZeroU64,
     }
 
     /// Single transaction parameter
@@ -182,6 +200,8 @@ mod model {
     pub enum TransactionParameter {
         MaxInstructions(NonZeroU64),
         SmartContractSize(NonZeroU64),
+        MaxMetadataDepth(NonZeroU64),
+        MaxMetadataSize(NonZeroU64),
     }
 
     /// Limits that a smart contract must obey at runtime to considered valid.
@@ -222,6 +242,39 @@ mod model {
         ExecutionDepth(u8),
     }
 
+    /// Fuel charged for the operations smart contracts and triggers request from the host,
+    /// on top of the fuel consumed by their own WASM code.
+    ///
+    /// Schedules are versioned and take effect from their activation height, so that a change of
+    /// the costs is known in advance and never applies to the blocks already committed.
+    #[derive(
+        Debug,
+        Display,
+        Clone,
+        Copy,
+        PartialEq,
+        Eq,
+        PartialOrd,
+        Ord,
+        Default,
+        Decode,
+        Encode,
+        Deserialize,
+        Serialize,
+        IntoSchema,
+    )]
+    #[display(fmt = "v{version}@{activation_height}:{instruction},{query}_FS")]
+    pub struct FuelSchedule {
+        /// Version of the schedule, every new schedule must have a greater one
+        pub version: u32,
+        /// Height of the first block executed with the schedule
+        pub activation_height: u64,
+        /// Fuel charged for every instruction a smart contract submits for execution
+        pub instruction: u64,
+        /// Fuel charged for every query (or query batch) a smart contract requests
+        pub query: u64,
+    }
+
     /// Blockchain specific parameter defined in the executor
     #[derive(
         Debug, Display, Clone, IdEqOrdHash, Decode, Encode, Deserialize, Serialize, IntoSchema,
@@ -276,6 +329,11 @@ mod model {
         #[getset(get_copy = "pub")]
         #[serde(default)]
         pub smart_contract: SmartContractParameters,
+        /// Fuel schedules ordered by version, including the ones yet to be activated
+        #[getset(get = "pub")]
+        #[serde(default)]
+        #[serde(skip_serializing_if = "Vec::is_empty")]
+        pub fuel_schedules: Vec<FuelSchedule>,
         /// Collection of blockchain specific parameters
         #[getset(get = "pub")]
         #[serde(default)]
@@ -307,6 +365,7 @@ mod model {
         Transaction(TransactionParameter),
         SmartContract(SmartContractParameter),
         Executor(SmartContractParameter),
+        FuelSchedule(FuelSchedule),
         Custom(CustomParameter),
     }
 }
@@ -318,6 +377,7 @@ impl core::fmt::Display for Parameter {
             Self::Block(v) => core::fmt::Display::fmt(&v, f),
             Self::Transaction(v) => core::fmt::Display::fmt(&v, f),
             Self::SmartContract(v) | Self::Executor(v) => core::fmt::Display::fmt(&v, f),
+            Self::FuelSchedule(v) => core::fmt::Display::fmt(&v, f),
             Self::Custom(v) => write!(f, "{}({})", v.id, v.payload),
         }
     }
@@ -373,6 +433,9 @@ mod defaults {
         pub const fn max_clock_drift_ms() -> u64 {
             1_000
         }
+        pub const fn peer_activation_delay_blocks() -> u64 {
+            2
+        }
     }
 
     pub mod block {
@@ -384,6 +447,9 @@ mod defaults {
         pub const fn max_transactions() -> NonZeroU64 {
             nonzero!(2_u64.pow(9))
         }
+        pub const fn max_heavy_instructions() -> NonZeroU64 {
+            nonzero!(2_u64.pow(4))
+        }
     }
 
     pub mod transaction {
@@ -397,6 +463,12 @@ mod defaults {
         pub const fn smart_contract_size() -> NonZeroU64 {
             nonzero!(4 * 2_u64.pow(20))
         }
+        pub const fn max_metadata_depth() -> NonZeroU64 {
+            nonzero!(2_u64.pow(4))
+        }
+        pub const fn max_metadata_size() -> NonZeroU64 {
+            nonzero!(2_u64.pow(16))
+        }
     }
 
     pub mod smart_contract {
@@ -423,19 +495,26 @@ impl Default for SumeragiParameters {
             block_time_ms: block_time_ms(),
             commit_time_ms: commit_time_ms(),
             max_clock_drift_ms: max_clock_drift_ms(),
+            peer_activation_delay_blocks: peer_activation_delay_blocks(),
         }
     }
 }
 impl Default for BlockParameters {
     fn default() -> Self {
-        Self::new(defaults::block::max_transactions())
+        use defaults::block::*;
+        Self::new(max_transactions(), max_heavy_instructions())
     }
 }
 
 impl Default for TransactionParameters {
     fn default() -> Self {
         use defaults::transaction::*;
-        Self::new(max_instructions(), smart_contract_size())
+        Self::new(
+            max_instructions(),
+            smart_contract_size(),
+            max_metadata_depth(),
+            max_metadata_size(),
+        )
     }
 }
 
@@ -473,9 +552,38 @@ impl Parameters {
                     .parameters()
                     .map(Parameter::SmartContract),
             )
+            .chain(
+                self.fuel_schedules
+                    .iter()
+                    .copied()
+                    .map(Parameter::FuelSchedule),
+            )
             .chain(self.custom.values().cloned().map(Parameter::Custom))
     }
 
+    /// Get the [`FuelSchedule`] the block at `height` is executed with.
+    ///
+    /// It's the latest schedule activated by then or the default one, which charges nothing.
+    pub fn fuel_schedule(&self, height: u64) -> FuelSchedule {
+        self.fuel_schedules
+            .iter()
+            .rev()
+            .find(|schedule| schedule.activation_height <= height)
+            .copied()
+            .unwrap_or_default()
+    }
+
+    /// Add the `schedule`, replacing the one with the same version if any
+    pub fn add_fuel_schedule(&mut self, schedule: FuelSchedule) {
+        match self
+            .fuel_schedules
+            .binary_search_by_key(&schedule.version, |other| other.version)
+        {
+            Ok(index) => self.fuel_schedules[index] = schedule,
+            Err(index) => self.fuel_schedules.insert(index, schedule),
+        }
+    }
+
     /// Set `parameter` value to corresponding parameter in `self`
     pub fn set_parameter(&mut self, parameter: Parameter) {
         macro_rules! apply_parameter {
@@ -486,6 +594,9 @@ impl Parameters {
                         self.$param.$field = next;
                     }
                     )*
+                    Parameter::FuelSchedule(next) => {
+                        self.add_fuel_schedule(next);
+                    }
                     Parameter::Custom(next) => {
                         self.custom.insert(next.id.clone(), next);
                     }
@@ -497,11 +608,15 @@ impl Parameters {
             Sumeragi(sumeragi.max_clock_drift_ms) => SumeragiParameter::MaxClockDriftMs,
             Sumeragi(sumeragi.block_time_ms) => SumeragiParameter::BlockTimeMs,
             Sumeragi(sumeragi.commit_time_ms) => SumeragiParameter::CommitTimeMs,
+            Sumeragi(sumeragi.peer_activation_delay_blocks) => SumeragiParameter::PeerActivationDelayBlocks,
 
             Block(block.max_transactions) => BlockParameter::MaxTransactions,
+            Block(block.max_heavy_instructions) => BlockParameter::MaxHeavyInstructions,
 
             Transaction(transaction.max_instructions) => TransactionParameter::MaxInstructions,
             Transaction(transaction.smart_contract_size) => TransactionParameter::SmartContractSize,
+            Transaction(transaction.max_metadata_depth) => TransactionParameter::MaxMetadataDepth,
+            Transaction(transaction.max_metadata_size) => TransactionParameter::MaxMetadataSize,
 
             SmartContract(smart_contract.fuel) => SmartContractParameter::Fuel,
             SmartContract(smart_contract.memory) => SmartContractParameter::Memory,
@@ -530,6 +645,7 @@ impl SumeragiParameters {
                 .as_millis()
                 .try_into()
                 .expect("INTERNAL BUG: Time should fit into u64"),
+            peer_activation_delay_blocks: defaults::sumeragi::peer_activation_delay_blocks(),
         }
     }
 
@@ -539,6 +655,7 @@ impl SumeragiParameters {
             SumeragiParameter::BlockTimeMs(self.block_time_ms),
             SumeragiParameter::CommitTimeMs(self.commit_time_ms),
             SumeragiParameter::MaxClockDriftMs(self.max_clock_drift_ms),
+            SumeragiParameter::PeerActivationDelayBlocks(self.peer_activation_delay_blocks),
         ]
         .into_iter()
     }
@@ -546,22 +663,36 @@ impl SumeragiParameters {
 
 impl BlockParameters {
     /// Construct [`Self`]
-    pub const fn new(max_transactions: NonZeroU64) -> Self {
-        Self { max_transactions }
+    pub const fn new(max_transactions: NonZeroU64, max_heavy_instructions: NonZeroU64) -> Self {
+        Self {
+            max_transactions,
+            max_heavy_instructions,
+        }
     }
 
     /// Convert [`Self`] into iterator of individual parameters
     pub fn parameters(&self) -> impl Iterator<Item = BlockParameter> {
-        [BlockParameter::MaxTransactions(self.max_transactions)].into_iter()
+        [
+            BlockParameter::MaxTransactions(self.max_transactions),
+            BlockParameter::MaxHeavyInstructions(self.max_heavy_instructions),
+        ]
+        .into_iter()
     }
 }
 
 impl TransactionParameters {
     /// Construct [`Self`]
-    pub const fn new(max_instructions: NonZeroU64, smart_contract_size: NonZeroU64) -> Self {
+    pub const fn new(
+        max_instructions: NonZeroU64,
+        smart_contract_size: NonZeroU64,
+        max_metadata_depth: NonZeroU64,
+        max_metadata_size: NonZeroU64,
+    ) -> Self {
         Self {
             max_instructions,
             smart_contract_size,
+            max_metadata_depth,
+            max_metadata_size,
         }
     }
 
@@ -570,6 +701,8 @@ impl TransactionParameters {
         [
             TransactionParameter::MaxInstructions(self.max_instructions),
             TransactionParameter::SmartContractSize(self.smart_contract_size),
+            TransactionParameter::MaxMetadataDepth(self.max_metadata_depth),
+            TransactionParameter::MaxMetadataSize(self.max_metadata_size),
         ]
         .into_iter()
     }
@@ -621,6 +754,8 @@ mod candidate {
     enum TransactionParameterCandidate {
         MaxInstructions(NonZeroU64),
         SmartContractSize(NonZeroU64),
+        MaxMetadataDepth(NonZeroU64),
+        MaxMetadataSize(NonZeroU64),
     }
 
     #[derive(Decode, Deserialize)]
@@ -629,17 +764,24 @@ mod candidate {
         max_instructions: NonZeroU64,
         #[serde(default = "defaults::transaction::smart_contract_size")]
         smart_contract_size: NonZeroU64,
+        #[serde(default = "defaults::transaction::max_metadata_depth")]
+        max_metadata_depth: NonZeroU64,
+        #[serde(default = "defaults::transaction::max_metadata_size")]
+        max_metadata_size: NonZeroU64,
     }
 
     #[derive(Decode, Deserialize)]
     enum BlockParameterCandidate {
         MaxTransactions(NonZeroU64),
+        MaxHeavyInstructions(NonZeroU64),
     }
 
     #[derive(Decode, Deserialize)]
     struct BlockParametersCandidate {
         #[serde(default = "super::defaults::block::max_transactions")]
         max_transactions: NonZeroU64,
+        #[serde(default = "super::defaults::block::max_heavy_instructions")]
+        max_heavy_instructions: NonZeroU64,
     }
 
     #[derive(Decode, Deserialize)]
@@ -668,6 +810,9 @@ mod candidate {
 
                     BlockParameter::MaxTransactions(max_transactions)
                 }
+                Self::MaxHeavyInstructions(max_heavy_instructions) => {
+                    BlockParameter::MaxHeavyInstructions(max_heavy_instructions)
+                }
             })
         }
     }
@@ -679,6 +824,7 @@ mod candidate {
 
             Ok(BlockParameters {
                 max_transactions: self.max_transactions,
+                max_heavy_instructions: self.max_heavy_instructions,
             })
         }
     }
@@ -697,6 +843,12 @@ mod candidate {
                     })?;
                     TransactionParameter::SmartContractSize(smart_contract_size)
                 }
+                Self::MaxMetadataDepth(max_metadata_depth) => {
+                    TransactionParameter::MaxMetadataDepth(max_metadata_depth)
+                }
+                Self::MaxMetadataSize(max_metadata_size) => {
+                    TransactionParameter::MaxMetadataSize(max_metadata_size)
+                }
             })
         }
     }
@@ -712,6 +864,8 @@ mod candidate {
             Ok(TransactionParameters {
                 max_instructions: self.max_instructions,
                 smart_contract_size: self.smart_contract_size,
+                max_metadata_depth: self.max_metadata_depth,
+                max_metadata_size: self.max_metadata_size,
             })
         }
     }
@@ -870,5 +1024,7 @@ mod candidate {
 pub mod prelude {
     //! Prelude: re-export of most commonly used traits, structs and macros in this crate.
 
-    pub use super::{Parameter, Parameters, SmartContractParameters, TransactionParameters};
+    pub use super::{
+        FuelSchedule, Parameter, Parameters, SmartContractParameters, TransactionParameters,
+    };
 }
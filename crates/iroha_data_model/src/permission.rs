@@ -9,10 +9,14 @@ use iroha_primitives::json::Json;
 use iroha_schema::{Ident, IntoSchema};
 
 pub use self::model::*;
+use crate::account::AccountId;
 
 /// Collection of [`Permission`]s
 pub type Permissions = BTreeSet<Permission>;
 
+/// Maximal length of a chain of [`Delegation`]s starting at the account the permission was granted to.
+pub const MAX_DELEGATION_DEPTH: u8 = 3;
+
 #[model]
 mod model {
     use derive_more::Display;
@@ -47,6 +51,37 @@ mod model {
         /// the type defined in [`crate::executor::ExecutorDataModel`].
         pub payload: Json,
     }
+
+    /// Record of a [`Permission`] delegated by an account holding it to another account.
+    ///
+    /// Revoking the permission from the delegator revokes it from the delegate as well,
+    /// along with everything the delegate has delegated further.
+    #[derive(
+        Debug,
+        Clone,
+        PartialEq,
+        Eq,
+        PartialOrd,
+        Ord,
+        Decode,
+        Encode,
+        Deserialize,
+        Serialize,
+        IntoSchema,
+        Display,
+    )]
+    #[ffi_type]
+    #[display(fmt = "{delegator} -> {delegate}: {permission}")]
+    pub struct Delegation {
+        /// Account which delegated the permission.
+        pub delegator: AccountId,
+        /// Account the permission is delegated to.
+        pub delegate: AccountId,
+        /// Delegated permission.
+        pub permission: Permission,
+        /// Position in the chain of delegations: 1 if the delegator was granted the permission directly.
+        pub depth: u8,
+    }
 }
 
 impl Permission {
@@ -72,5 +107,5 @@ impl Permission {
 
 pub mod prelude {
     //! The prelude re-exports most commonly used traits, structs and macros from this crate.
-    pub use super::Permission;
+    pub use super::{Delegation, Permission};
 }
@@ -24,6 +24,7 @@ impl<T> ExactSizeIterator for TypedBatchIterUntupled<T> {
     }
 }
 
+/// An error that can occur when converting a type-erased batch into the expected type.
 #[derive(Debug, Copy, Clone, displaydoc::Display)]
 #[cfg_attr(feature = "std", derive(thiserror::Error))]
 pub enum TypedBatchDowncastError {
@@ -35,8 +36,15 @@ pub enum TypedBatchDowncastError {
     WrongType(usize),
 }
 
+/// A type (or a tuple of types) that can be extracted from a type-erased batch of iterable query results.
 pub trait HasTypedBatchIter {
+    /// Iterator over the typed results of a batch.
     type TypedBatchIter: Iterator<Item = Self> + ExactSizeIterator;
+    /// Convert a type-erased batch into an iterator over typed results.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the batch doesn't hold results of this type.
     fn downcast(
         erased_batch: QueryOutputBatchBoxTuple,
     ) -> Result<Self::TypedBatchIter, TypedBatchDowncastError>;
@@ -1,3 +1,6 @@
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
 use crate::query::{
     builder::{
         batch_downcast::{HasTypedBatchIter, TypedBatchDowncastError},
@@ -37,21 +40,25 @@ where
             continue_cursor,
         })
     }
-}
 
-impl<E, T> Iterator for QueryIterator<E, T>
-where
-    E: QueryExecutor,
-    T: HasTypedBatchIter,
-{
-    type Item = Result<T, E::Error>;
-
-    fn next(&mut self) -> Option<Self::Item> {
-        // if we haven't exhausted the current batch yet - return it
-        if let Some(item) = self.current_batch_iter.next() {
-            return Some(Ok(item));
+    /// Take the rest of the current batch, fetching the next one from iroha if it is exhausted.
+    ///
+    /// Lets the results be processed batch by batch, e.g. by a trigger scanning a large domain:
+    /// the memory used is bounded by the fetch size of the query,
+    /// and the scan can be stopped between the batches before running out of fuel.
+    /// Returns `None` once the results are exhausted.
+    pub fn next_batch(&mut self) -> Option<Result<Vec<T>, E::Error>> {
+        while self.current_batch_iter.len() == 0 {
+            if let Err(error) = self.fetch_next_batch()? {
+                return Some(Err(error));
+            }
         }
 
+        Some(Ok(self.current_batch_iter.by_ref().collect()))
+    }
+
+    /// Replace the exhausted batch with the next one from iroha, `None` if there is none
+    fn fetch_next_batch(&mut self) -> Option<Result<(), E::Error>> {
         // no cursor means the query result is exhausted or an error occurred on one of the previous iterations
         let cursor = self.continue_cursor.take()?;
 
@@ -70,6 +77,27 @@ where
         self.current_batch_iter = batch_iter;
         self.remaining_items = remaining_items;
 
+        Some(Ok(()))
+    }
+}
+
+impl<E, T> Iterator for QueryIterator<E, T>
+where
+    E: QueryExecutor,
+    T: HasTypedBatchIter,
+{
+    type Item = Result<T, E::Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        // if we haven't exhausted the current batch yet - return it
+        if let Some(item) = self.current_batch_iter.next() {
+            return Some(Ok(item));
+        }
+
+        if let Err(e) = self.fetch_next_batch()? {
+            return Some(Err(e));
+        }
+
         self.next()
     }
 }
@@ -87,3 +115,70 @@ where
             .expect("should be within the range of usize")
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use iroha_primitives::numeric::Numeric;
+
+    use super::*;
+    use crate::query::{
+        QueryOutputBatchBox, QueryWithParams, SingularQueryBox, SingularQueryOutputBox,
+    };
+
+    fn batch(values: [u32; 2]) -> QueryOutputBatchBoxTuple {
+        QueryOutputBatchBoxTuple::new(vec![QueryOutputBatchBox::Numeric(
+            values.into_iter().map(Numeric::from).collect(),
+        )])
+    }
+
+    /// Serves the batches `[2, 3]` and `[4, 5]` with cursors `1` and `2`
+    struct MockExecutor;
+
+    impl QueryExecutor for MockExecutor {
+        type Cursor = u32;
+        type Error = ();
+
+        fn execute_singular_query(
+            &self,
+            _query: SingularQueryBox,
+        ) -> Result<SingularQueryOutputBox, Self::Error> {
+            unreachable!()
+        }
+
+        fn start_query(
+            &self,
+            _query: QueryWithParams,
+        ) -> Result<(QueryOutputBatchBoxTuple, u64, Option<Self::Cursor>), Self::Error> {
+            unreachable!()
+        }
+
+        fn continue_query(
+            cursor: Self::Cursor,
+        ) -> Result<(QueryOutputBatchBoxTuple, u64, Option<Self::Cursor>), Self::Error> {
+            Ok((
+                batch([cursor * 2, cursor * 2 + 1]),
+                u64::from(2 - cursor) * 2,
+                (cursor < 2).then_some(cursor + 1),
+            ))
+        }
+    }
+
+    #[test]
+    fn batches_are_fetched_on_demand() {
+        let mut iter = QueryIterator::<MockExecutor, Numeric>::new(batch([0, 1]), 4, Some(1))
+            .expect("batch has the expected type");
+
+        assert_eq!(iter.next(), Some(Ok(Numeric::from(0_u32))));
+        assert_eq!(iter.next_batch(), Some(Ok(vec![Numeric::from(1_u32)])));
+        assert_eq!(
+            iter.next_batch(),
+            Some(Ok(vec![Numeric::from(2_u32), Numeric::from(3_u32)]))
+        );
+        assert_eq!(iter.len(), 2);
+        assert_eq!(
+            iter.next_batch(),
+            Some(Ok(vec![Numeric::from(4_u32), Numeric::from(5_u32)]))
+        );
+        assert_eq!(iter.next_batch(), None);
+    }
+}
@@ -7,18 +7,19 @@ mod iter;
 use alloc::vec::Vec;
 use core::marker::PhantomData;
 
+pub use batch_downcast::{HasTypedBatchIter, TypedBatchDowncastError};
 use derive_where::derive_where;
+use iroha_primitives::numeric::Numeric;
 pub use iter::QueryIterator;
 use parity_scale_codec::{Decode, Encode};
 use serde::{Deserialize, Serialize};
 
 use crate::query::{
-    builder::batch_downcast::HasTypedBatchIter,
     dsl::{
         BaseProjector, CompoundPredicate, HasPrototype, IntoSelectorTuple, PredicateMarker,
         SelectorMarker, SelectorTuple,
     },
-    parameters::{FetchSize, Pagination, QueryParams, Sorting},
+    parameters::{Aggregation, FetchSize, Pagination, QueryParams, SortKey, SortOrder, Sorting},
     Query, QueryBox, QueryOutputBatchBoxTuple, QueryWithFilter, QueryWithParams, SingularQueryBox,
     SingularQueryOutputBox,
 };
@@ -110,6 +111,7 @@ where
     pagination: Pagination,
     sorting: Sorting,
     fetch_size: FetchSize,
+    aggregation: Option<Aggregation>,
     // NOTE: T is a phantom type used to denote the selected tuple in `selector`
     phantom: PhantomData<T>,
 }
@@ -128,6 +130,7 @@ where
             pagination: Pagination::default(),
             sorting: Sorting::default(),
             fetch_size: FetchSize::default(),
+            aggregation: None,
             phantom: PhantomData,
         }
     }
@@ -198,6 +201,7 @@ where
             pagination: self.pagination,
             sorting: self.sorting,
             fetch_size: self.fetch_size,
+            aggregation: self.aggregation,
             phantom: PhantomData,
         }
     }
@@ -208,6 +212,29 @@ where
         Self { sorting, ..self }
     }
 
+    /// Sort the results by the `key`, e.g. the assets by their quantity.
+    ///
+    /// The results are sorted before the pagination is applied.
+    #[must_use]
+    pub fn sort_by(self, key: SortKey) -> Self {
+        Self {
+            sorting: Sorting {
+                sort_by: Some(key),
+                ..self.sorting
+            },
+            ..self
+        }
+    }
+
+    /// Sort the results in the `order`, ascending by default.
+    #[must_use]
+    pub fn order(self, order: SortOrder) -> Self {
+        Self {
+            sorting: self.sorting.with_order(order),
+            ..self
+        }
+    }
+
     /// Only return part of the results specified by the pagination.
     #[must_use]
     pub fn with_pagination(self, pagination: Pagination) -> Self {
@@ -221,21 +248,31 @@ where
     pub fn with_fetch_size(self, fetch_size: FetchSize) -> Self {
         Self { fetch_size, ..self }
     }
+
+    fn aggregate(self, aggregation: Aggregation) -> QueryBuilder<'a, E, Q, Numeric> {
+        QueryBuilder {
+            query_executor: self.query_executor,
+            query: self.query,
+            filter: self.filter,
+            selector: self.selector,
+            pagination: self.pagination,
+            sorting: self.sorting,
+            fetch_size: self.fetch_size,
+            aggregation: Some(aggregation),
+            phantom: PhantomData,
+        }
+    }
 }
 
-impl<E, Q, T> QueryBuilder<'_, E, Q, T>
+impl<'a, E, Q, T> QueryBuilder<'a, E, Q, T>
 where
     Q: Query,
-    E: QueryExecutor,
     QueryBox: From<QueryWithFilter<Q>>,
-    T: HasTypedBatchIter,
 {
-    /// Execute the query, returning an iterator over its results.
+    /// Split the builder into its backend and the assembled query.
     ///
-    /// # Errors
-    ///
-    /// Returns an error if the query execution fails.
-    pub fn execute(self) -> Result<QueryIterator<E, T>, E::Error> {
+    /// Useful for backends which can't implement [`QueryExecutor`], e.g. asynchronous ones.
+    pub fn into_parts(self) -> (&'a E, QueryWithParams) {
         let with_filter = QueryWithFilter::new(self.query, self.filter, self.selector);
         let boxed: QueryBox = with_filter.into();
 
@@ -245,11 +282,30 @@ where
                 pagination: self.pagination,
                 sorting: self.sorting,
                 fetch_size: self.fetch_size,
+                aggregation: self.aggregation,
             },
         };
 
-        let (first_batch, remaining_items, continue_cursor) =
-            self.query_executor.start_query(query)?;
+        (self.query_executor, query)
+    }
+}
+
+impl<E, Q, T> QueryBuilder<'_, E, Q, T>
+where
+    Q: Query,
+    E: QueryExecutor,
+    QueryBox: From<QueryWithFilter<Q>>,
+    T: HasTypedBatchIter,
+{
+    /// Execute the query, returning an iterator over its results.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query execution fails.
+    pub fn execute(self) -> Result<QueryIterator<E, T>, E::Error> {
+        let (query_executor, query) = self.into_parts();
+
+        let (first_batch, remaining_items, continue_cursor) = query_executor.start_query(query)?;
 
         let iterator = QueryIterator::<E, T>::new(first_batch, remaining_items, continue_cursor)
             .expect(
@@ -260,6 +316,65 @@ where
     }
 }
 
+impl<E, Q, T> QueryBuilder<'_, E, Q, T>
+where
+    Q: Query,
+    E: QueryExecutor,
+    QueryBox: From<QueryWithFilter<Q>>,
+{
+    /// Count the results on the peer, without fetching them.
+    ///
+    /// All the results are counted, regardless of the pagination.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query execution fails.
+    pub fn count(self) -> Result<u64, SingleQueryError<E::Error>> {
+        self.aggregate(Aggregation::Count)
+            .execute_single()
+            .map(|count| {
+                u64::try_from(count).expect(
+                    "INTERNAL BUG: iroha returned fractional number of results. Is there a schema mismatch?",
+                )
+            })
+    }
+}
+
+impl<E, Q> QueryBuilder<'_, E, Q, Numeric>
+where
+    Q: Query,
+    E: QueryExecutor,
+    QueryBox: From<QueryWithFilter<Q>>,
+{
+    /// Sum the selected values on the peer, without fetching them,
+    /// e.g. `.select_with(|asset| asset.value).sum()`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query execution fails, e.g. the sum overflows.
+    pub fn sum(self) -> Result<Numeric, SingleQueryError<E::Error>> {
+        self.aggregate(Aggregation::Sum).execute_single()
+    }
+
+    /// Find the least of the selected values on the peer, `None` if there are no results.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query execution fails.
+    pub fn min(self) -> Result<Option<Numeric>, SingleQueryError<E::Error>> {
+        self.aggregate(Aggregation::Min).execute_single_opt()
+    }
+
+    /// Find the greatest of the selected values on the peer, `None` if there are no results.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query execution fails.
+    pub fn max(self) -> Result<Option<Numeric>, SingleQueryError<E::Error>> {
+        self.aggregate(Aggregation::Max).execute_single_opt()
+    }
+}
+
 /// An extension trait for query builders that provides convenience methods to execute queries.
 pub trait QueryBuilderExt<E, Q, T>
 where
@@ -12,7 +12,10 @@ use crate::{
     asset::{Asset, AssetEntry},
     nft::{Nft, NftEntry},
     prelude::{AccountProjection, AssetProjection, NftProjection},
-    query::dsl::{BaseProjector, EvaluatePredicate, HasProjection, HasPrototype, PredicateMarker},
+    query::dsl::{
+        predicates::{AccountPredicateAtom, NftPredicateAtom},
+        BaseProjector, EvaluatePredicate, HasProjection, HasPrototype, PredicateMarker,
+    },
 };
 
 /// A compound predicate that is be used to combine multiple predicates using logical operators.
@@ -101,7 +104,9 @@ impl AccountProjection<PredicateMarker> {
     fn applies_to_entry(&self, input: &AccountEntry) -> bool {
         use AccountProjection::*;
         match self {
-            Atom(atom) => match *atom {},
+            Atom(atom) => match atom {
+                AccountPredicateAtom::HasTag(tag) => input.tags.contains(tag),
+            },
             Id(field) => field.applies(input.id),
             Metadata(field) => field.applies(input.metadata),
         }
@@ -115,6 +120,7 @@ impl AssetProjection<PredicateMarker> {
             Atom(atom) => match *atom {},
             Id(field) => field.applies(input.id),
             Value(field) => field.applies(input.value),
+            Metadata(field) => field.applies(input.metadata),
         }
     }
 }
@@ -123,7 +129,9 @@ impl NftProjection<PredicateMarker> {
     fn applies_to_entry(&self, input: &NftEntry) -> bool {
         use NftProjection::*;
         match self {
-            Atom(atom) => match *atom {},
+            Atom(atom) => match atom {
+                NftPredicateAtom::IsTransferable => input.transferable,
+            },
             Id(field) => field.applies(input.id),
             Metadata(field) => field.applies(input.content),
             AccountId(field) => field.applies(input.owned_by),
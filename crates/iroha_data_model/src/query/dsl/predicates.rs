@@ -8,13 +8,13 @@ use iroha_primitives::{json::Json, numeric::Numeric};
 
 use crate::{
     account::{Account, AccountId},
-    asset::{Asset, AssetDefinition, AssetDefinitionId, AssetId},
+    asset::{Asset, AssetDefinition, AssetDefinitionId, AssetId, AssetKind},
     block::{BlockHeader, SignedBlock},
     domain::{Domain, DomainId},
     metadata::Metadata,
     name::Name,
     nft::{Nft, NftId},
-    parameter::Parameter,
+    parameter::{CustomParameterId, Parameter},
     peer::PeerId,
     permission::Permission,
     query::{
@@ -22,18 +22,18 @@ use crate::{
             type_descriptions::{
                 AccountIdPrototype, AccountPrototype, ActionPrototype, AssetDefinitionIdPrototype,
                 AssetDefinitionPrototype, AssetIdPrototype, AssetPrototype,
-                BlockHeaderHashPrototype, BlockHeaderPrototype, CommittedTransactionPrototype,
-                DomainIdPrototype, DomainPrototype, JsonPrototype, MetadataPrototype,
-                NamePrototype, NftIdPrototype, NftPrototype, NumericPrototype, ParameterPrototype,
-                PeerIdPrototype, PermissionPrototype, PublicKeyPrototype, RoleIdPrototype,
-                RolePrototype, SignedBlockPrototype, StringPrototype,
+                AssetWithDefinitionPrototype, BlockHeaderHashPrototype, BlockHeaderPrototype,
+                CommittedTransactionPrototype, DomainIdPrototype, DomainPrototype, JsonPrototype,
+                MetadataPrototype, NamePrototype, NftIdPrototype, NftPrototype, NumericPrototype,
+                ParameterPrototype, PeerIdPrototype, PermissionPrototype, PublicKeyPrototype,
+                RoleIdPrototype, RolePrototype, SignedBlockPrototype, StringPrototype,
                 TransactionEntrypointHashPrototype, TransactionEntrypointPrototype,
                 TransactionResultHashPrototype, TransactionResultPrototype, TriggerIdPrototype,
                 TriggerPrototype,
             },
             CompoundPredicate, ObjectProjector, PredicateMarker,
         },
-        CommittedTransaction,
+        AssetWithDefinition, CommittedTransaction,
     },
     role::{Role, RoleId},
     transaction::{TransactionEntrypoint, TransactionResult},
@@ -276,11 +276,24 @@ impl_predicate_atom! {
         /// Checks if the input is equal to the expected value.
         Equals(expected: AccountId) [eq] => input == expected,
     }
-    AccountPredicateAtom(_input: Account) [AccountPrototype] {}
+    AccountPredicateAtom(input: Account) [AccountPrototype] {
+        /// Checks if the account is labeled with the expected tag.
+        HasTag(expected: Name) [has_tag] => input.tags.contains(expected),
+    }
 
     // asset
-    AssetDefinitionPredicateAtom(_input: AssetDefinition) [AssetDefinitionPrototype] {}
-    AssetPredicateAtom(_input: Asset) [AssetPrototype] {}
+    AssetDefinitionPredicateAtom(input: AssetDefinition) [AssetDefinitionPrototype] {
+        /// Checks if the asset definition is deprecated, regardless of its sunset window.
+        IsDeprecated [is_deprecated] => input.deprecation.is_some(),
+        /// Checks if the holdings of the asset are key-value stores, see [`AssetKind::Store`].
+        IsStore [is_store] => input.kind == AssetKind::Store,
+        /// Checks if all holdings of the asset are frozen.
+        IsFrozen [is_frozen] => input.frozen,
+    }
+    AssetPredicateAtom(input: Asset) [AssetPrototype] {
+        /// Checks if the holding itself is frozen, regardless of its asset definition.
+        IsFrozen [is_frozen] => input.frozen,
+    }
     AssetIdPredicateAtom(input: AssetId) [AssetIdPrototype] {
         /// Checks if the input is equal to the expected value.
         Equals(expected: AssetId) [eq] => input == expected,
@@ -289,9 +302,13 @@ impl_predicate_atom! {
         /// Checks if the input is equal to the expected value.
         Equals(expected: AssetDefinitionId) [eq] => input == expected,
     }
+    AssetWithDefinitionPredicateAtom(_input: AssetWithDefinition) [AssetWithDefinitionPrototype] {}
 
     // NFT
-    NftPredicateAtom(_input: Nft) [NftPrototype] {}
+    NftPredicateAtom(input: Nft) [NftPrototype] {
+        /// Checks if the NFT can be transferred by its owner.
+        IsTransferable [is_transferable] => input.transferable,
+    }
     NftIdPredicateAtom(input: NftId) [NftIdPrototype] {
         /// Checks if the input is equal to the expected value.
         Equals(expected: NftId) [eq] => input == expected,
@@ -314,6 +331,8 @@ impl_predicate_atom! {
     TransactionEntrypointPredicateAtom(input: TransactionEntrypoint) [TransactionEntrypointPrototype] {
         /// Returns true if the entrypoint is an user request.
         IsExternal [is_external] => matches!(input, TransactionEntrypoint::External(_)),
+        /// Returns true if the entrypoint has an instruction tagged with the specified tag.
+        ContainsTag(expected: Name) [contains_tag] => input.contains_tag(expected),
     }
     TransactionResultHashPredicateAtom(input: HashOf<TransactionResult>) [TransactionResultHashPrototype] {
         /// Returns true if the result hash matches the specified hash.
@@ -341,7 +360,12 @@ impl_predicate_atom! {
     PermissionPredicateAtom(_input: Permission) [PermissionPrototype] {}
 
     // parameter
-    ParameterPredicateAtom(_input: Parameter) [ParameterPrototype] {}
+    ParameterPredicateAtom(input: Parameter) [ParameterPrototype] {
+        /// Checks if the parameter is a custom one, defined by the executor.
+        IsCustom [is_custom] => matches!(input, Parameter::Custom(_)),
+        /// Checks if the parameter is the custom one with the specified ID.
+        CustomIdEquals(expected: CustomParameterId) [custom_id_eq] => matches!(input, Parameter::Custom(parameter) if parameter.id == *expected),
+    }
 
     // role
     RoleIdPredicateAtom(input: RoleId) [RoleIdPrototype] {
@@ -364,12 +388,12 @@ pub mod prelude {
     pub use super::{
         AccountIdPredicateAtom, AccountPredicateAtom, ActionPredicateAtom,
         AssetDefinitionIdPredicateAtom, AssetDefinitionPredicateAtom, AssetIdPredicateAtom,
-        AssetPredicateAtom, BlockHeaderHashPredicateAtom, BlockHeaderPredicateAtom,
-        CommittedTransactionPredicateAtom, DomainIdPredicateAtom, DomainPredicateAtom,
-        JsonPredicateAtom, MetadataPredicateAtom, NftIdPredicateAtom, NftPredicateAtom,
-        NumericPredicateAtom, ParameterPredicateAtom, PeerIdPredicateAtom, PermissionPredicateAtom,
-        PublicKeyPredicateAtom, RoleIdPredicateAtom, RolePredicateAtom, SignedBlockPredicateAtom,
-        StringPredicateAtom, TransactionEntrypointHashPredicateAtom,
+        AssetPredicateAtom, AssetWithDefinitionPredicateAtom, BlockHeaderHashPredicateAtom,
+        BlockHeaderPredicateAtom, CommittedTransactionPredicateAtom, DomainIdPredicateAtom,
+        DomainPredicateAtom, JsonPredicateAtom, MetadataPredicateAtom, NftIdPredicateAtom,
+        NftPredicateAtom, NumericPredicateAtom, ParameterPredicateAtom, PeerIdPredicateAtom,
+        PermissionPredicateAtom, PublicKeyPredicateAtom, RoleIdPredicateAtom, RolePredicateAtom,
+        SignedBlockPredicateAtom, StringPredicateAtom, TransactionEntrypointHashPredicateAtom,
         TransactionEntrypointPredicateAtom, TransactionResultHashPredicateAtom,
         TransactionResultPredicateAtom, TriggerIdPredicateAtom, TriggerPredicateAtom,
     };
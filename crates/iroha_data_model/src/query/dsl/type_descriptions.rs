@@ -25,7 +25,7 @@ use crate::{
     permission::Permission,
     query::{
         error::{FindError, QueryExecutionFail},
-        CommittedTransaction, QueryOutputBatchBox,
+        AssetWithDefinition, CommittedTransaction, QueryOutputBatchBox,
     },
     role::{Role, RoleId},
     transaction::{TransactionEntrypoint, TransactionResult},
@@ -265,11 +265,16 @@ type_descriptions! {
     Asset[AssetProjection, AssetPrototype]: AssetId, AccountId, DomainId, Name, PublicKey, AssetDefinitionId, Numeric, Metadata, Json {
         id(Id, AssetIdProjector): AssetId,
         value(Value, AssetValueProjector): Numeric,
+        metadata(Metadata, AssetMetadataProjector): Metadata,
     }
     AssetId[AssetIdProjection, AssetIdPrototype]: AccountId, DomainId, Name, PublicKey, AssetDefinitionId {
         account(Account, AssetIdAccountProjector): AccountId,
         definition(Definition, AssetIdDefinitionProjector): AssetDefinitionId,
     }
+    AssetWithDefinition[AssetWithDefinitionProjection, AssetWithDefinitionPrototype]: Asset, AssetId, AccountId, DomainId, Name, PublicKey, AssetDefinition, AssetDefinitionId, Numeric, Metadata, Json {
+        asset(Asset, AssetWithDefinitionAssetProjector): Asset,
+        definition(Definition, AssetWithDefinitionDefinitionProjector): AssetDefinition,
+    }
 
     // NFT
     Nft[NftProjection, NftPrototype]: NftId, DomainId, AccountId, PublicKey, Name, Metadata, Json {
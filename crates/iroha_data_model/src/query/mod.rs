@@ -13,7 +13,7 @@ use alloc::{
 use std::vec;
 
 use derive_more::Constructor;
-use iroha_crypto::{MerkleProof, PublicKey, SignatureOf};
+use iroha_crypto::{HashOf, MerkleProof, PublicKey, SignatureOf};
 use iroha_data_model_derive::model;
 use iroha_macro::FromVariant;
 use iroha_primitives::{json::Json, numeric::Numeric};
@@ -26,7 +26,7 @@ use serde::{Deserialize, Serialize};
 pub use self::model::*;
 use self::{
     account::*, asset::*, block::*, domain::*, dsl::*, executor::*, nft::*, peer::*, permission::*,
-    role::*, transaction::*, trigger::*,
+    role::*, tombstone::*, transaction::*, trigger::*,
 };
 use crate::{
     account::{Account, AccountId},
@@ -77,7 +77,6 @@ pub trait Query: Sealed {
 mod model {
     use derive_where::derive_where;
     use getset::Getters;
-    use iroha_crypto::HashOf;
     use iroha_macro::serde_where;
 
     use super::*;
@@ -126,6 +125,9 @@ mod model {
         FindPermissionsByAccountId(QueryWithFilter<FindPermissionsByAccountId>),
         FindRolesByAccountId(QueryWithFilter<FindRolesByAccountId>),
         FindAccountsWithAsset(QueryWithFilter<FindAccountsWithAsset>),
+        FindAccountsWithRole(QueryWithFilter<FindAccountsWithRole>),
+        FindAccountsWithTag(QueryWithFilter<FindAccountsWithTag>),
+        FindAssetsWithDefinition(QueryWithFilter<FindAssetsWithDefinition>),
 
         FindPeers(QueryWithFilter<FindPeers>),
         FindActiveTriggerIds(QueryWithFilter<FindActiveTriggerIds>),
@@ -133,6 +135,7 @@ mod model {
         FindTransactions(QueryWithFilter<FindTransactions>),
         FindBlocks(QueryWithFilter<FindBlocks>),
         FindBlockHeaders(QueryWithFilter<FindBlockHeaders>),
+        FindAllParameters(QueryWithFilter<FindAllParameters>),
     }
 
     /// An enum of all possible iterable query batches.
@@ -156,6 +159,7 @@ mod model {
         Asset(Vec<Asset>),
         AssetDefinitionId(Vec<AssetDefinitionId>),
         AssetDefinition(Vec<AssetDefinition>),
+        AssetWithDefinition(Vec<AssetWithDefinition>),
         NftId(Vec<NftId>),
         Nft(Vec<Nft>),
         Role(Vec<Role>),
@@ -190,6 +194,15 @@ mod model {
     pub enum SingularQueryBox {
         FindExecutorDataModel(FindExecutorDataModel),
         FindParameters(FindParameters),
+        FindTombstone(FindTombstone),
+        FindAssetQuantityAt(FindAssetQuantityAt),
+        FindSubscription(FindSubscription),
+        FindDeadLetters(FindDeadLetters),
+        FindDelegations(FindDelegations),
+        FindAllowances(FindAllowances),
+        FindInbox(FindInbox),
+        FindEscrows(FindEscrows),
+        FindFuelSchedule(FindFuelSchedule),
     }
 
     /// An enum of all possible singular query outputs
@@ -199,6 +212,15 @@ mod model {
     pub enum SingularQueryOutputBox {
         ExecutorDataModel(crate::executor::ExecutorDataModel),
         Parameters(Parameters),
+        Tombstone(crate::tombstone::Tombstone),
+        Numeric(Numeric),
+        Subscription(crate::subscription::Subscription),
+        DeadLetters(Vec<crate::dead_letter::DeadLetter>),
+        Delegations(Vec<crate::permission::Delegation>),
+        Allowances(Vec<crate::asset::Allowance>),
+        DirectMessages(Vec<crate::message::DirectMessage>),
+        Escrows(Vec<crate::escrow::Escrow>),
+        FuelSchedule(crate::parameter::FuelSchedule),
     }
 
     /// The results of a single iterable query request.
@@ -244,6 +266,9 @@ mod model {
     pub enum QueryResponse {
         Singular(SingularQueryOutputBox),
         Iterable(QueryOutput),
+        /// Iterable query output whose batch was cut short because the peer sheds the load.
+        /// The query is continued with the cursor as usual, preferably after a pause.
+        Shed(QueryOutput),
     }
 
     /// A [`QueryRequest`], combined with an authority that wants to execute the query
@@ -300,6 +325,30 @@ mod model {
         /// The result of executing the transaction (trigger sequence or rejection).
         pub result: TransactionResult,
     }
+
+    /// Response returned by [`FindAssetsWithDefinition`] query.
+    #[derive(
+        Debug,
+        Clone,
+        PartialOrd,
+        Ord,
+        PartialEq,
+        Eq,
+        Getters,
+        Decode,
+        Encode,
+        Deserialize,
+        Serialize,
+        IntoSchema,
+    )]
+    #[getset(get = "pub")]
+    #[ffi_type]
+    pub struct AssetWithDefinition {
+        /// The asset holding.
+        pub asset: Asset,
+        /// Definition of the asset, carrying its numeric spec, mintability and logo.
+        pub definition: AssetDefinition,
+    }
 }
 
 #[cfg(feature = "fault_injection")]
@@ -362,6 +411,7 @@ impl QueryOutputBatchBox {
             (Self::Asset(v1), Self::Asset(v2)) => v1.extend(v2),
             (Self::AssetDefinitionId(v1), Self::AssetDefinitionId(v2)) => v1.extend(v2),
             (Self::AssetDefinition(v1), Self::AssetDefinition(v2)) => v1.extend(v2),
+            (Self::AssetWithDefinition(v1), Self::AssetWithDefinition(v2)) => v1.extend(v2),
             (Self::NftId(v1), Self::NftId(v2)) => v1.extend(v2),
             (Self::Nft(v1), Self::Nft(v2)) => v1.extend(v2),
             (Self::Role(v1), Self::Role(v2)) => v1.extend(v2),
@@ -404,6 +454,7 @@ impl QueryOutputBatchBox {
             Self::Asset(v) => v.len(),
             Self::AssetDefinitionId(v) => v.len(),
             Self::AssetDefinition(v) => v.len(),
+            Self::AssetWithDefinition(v) => v.len(),
             Self::NftId(v) => v.len(),
             Self::Nft(v) => v.len(),
             Self::Role(v) => v.len(),
@@ -529,6 +580,26 @@ impl QueryRequestWithAuthority {
         }
         .into()
     }
+
+    /// Sign this [`QueryRequestWithAuthority`] with the signature of its hash produced by `sign`,
+    /// e.g. by a hardware security module or a remote signing service.
+    ///
+    /// # Errors
+    ///
+    /// Fails if `sign` fails
+    pub fn sign_with<E>(
+        self,
+        sign: impl FnOnce(&[u8]) -> Result<iroha_crypto::Signature, E>,
+    ) -> Result<SignedQuery, E> {
+        let hash = HashOf::new(&self);
+        let signature = SignatureOf::from_untyped_unchecked(sign(hash.as_ref())?);
+
+        Ok(SignedQueryV1 {
+            signature: QuerySignature(signature),
+            payload: self,
+        }
+        .into())
+    }
 }
 
 impl SignedQuery {
@@ -746,13 +817,26 @@ impl_iter_queries! {
     FindTriggers => crate::trigger::Trigger,
     FindTransactions => CommittedTransaction,
     FindAccountsWithAsset => crate::account::Account,
+    FindAccountsWithRole => crate::account::Account,
+    FindAccountsWithTag => crate::account::Account,
+    FindAssetsWithDefinition => AssetWithDefinition,
     FindBlockHeaders => crate::block::BlockHeader,
     FindBlocks => SignedBlock,
+    FindAllParameters => crate::parameter::Parameter,
 }
 
 impl_singular_queries! {
     FindParameters => crate::parameter::Parameters,
     FindExecutorDataModel => crate::executor::ExecutorDataModel,
+    FindTombstone => crate::tombstone::Tombstone,
+    FindSubscription => crate::subscription::Subscription,
+    FindDeadLetters => Vec<crate::dead_letter::DeadLetter>,
+    FindDelegations => Vec<crate::permission::Delegation>,
+    FindAllowances => Vec<crate::asset::Allowance>,
+    FindInbox => Vec<crate::message::DirectMessage>,
+    FindEscrows => Vec<crate::escrow::Escrow>,
+    FindFuelSchedule => crate::parameter::FuelSchedule,
+    FindAssetQuantityAt => Numeric,
 }
 
 /// A macro reducing boilerplate when defining query types.
@@ -839,11 +923,23 @@ pub mod permission {
             /// `Id` of an account to find.
             pub id: AccountId,
         }
+
+        /// [`FindDelegations`] Iroha Query finds the [`Delegation`]s
+        /// either made by or made to a specified account.
+        #[derive(Display)]
+        #[display(fmt = "Find delegations of `{id}` account")]
+        #[repr(transparent)]
+        // SAFETY: `FindDelegations` has no trap representation in `AccountId`
+        #[ffi_type(unsafe {robust})]
+        pub struct FindDelegations {
+            /// `Id` of an account to find.
+            pub id: AccountId,
+        }
     }
 
     /// The prelude re-exports most commonly used traits, structs and macros from this module.
     pub mod prelude {
-        pub use super::FindPermissionsByAccountId;
+        pub use super::{FindDelegations, FindPermissionsByAccountId};
     }
 }
 
@@ -876,11 +972,37 @@ pub mod account {
             /// `Id` of the definition of the asset which should be stored in founded accounts.
             pub asset_definition: AssetDefinitionId,
         }
+
+        /// [`FindAccountsWithRole`] Iroha Query gets [`Role`]s id as input and
+        /// finds all [`Account`]s holding this role.
+        #[derive(Display)]
+        #[display(fmt = "Find accounts with `{role}` role")]
+        #[repr(transparent)]
+        // SAFETY: `FindAccountsWithRole` has no trap representation in `RoleId`
+        #[ffi_type(unsafe {robust})]
+        pub struct FindAccountsWithRole {
+            /// `Id` of the role which should be held by founded accounts.
+            pub role: RoleId,
+        }
+
+        /// [`FindAccountsWithTag`] Iroha Query gets a tag as input and
+        /// finds all [`Account`]s labeled with it, see [`AddTag`].
+        #[derive(Display)]
+        #[display(fmt = "Find accounts with `{tag}` tag")]
+        #[repr(transparent)]
+        // SAFETY: `FindAccountsWithTag` has no trap representation in `Name`
+        #[ffi_type(unsafe {robust})]
+        pub struct FindAccountsWithTag {
+            /// Tag which founded accounts should be labeled with.
+            pub tag: Name,
+        }
     }
 
     /// The prelude re-exports most commonly used traits, structs and macros from this crate.
     pub mod prelude {
-        pub use super::{FindAccounts, FindAccountsWithAsset};
+        pub use super::{
+            FindAccounts, FindAccountsWithAsset, FindAccountsWithRole, FindAccountsWithTag,
+        };
     }
 }
 
@@ -891,9 +1013,12 @@ pub mod asset {
 
     #[cfg(not(feature = "std"))]
     use alloc::{format, string::String, vec::Vec};
+    use core::num::NonZeroU64;
 
     use derive_more::Display;
 
+    use crate::{account::AccountId, asset::AssetId};
+
     queries! {
         /// [`FindAssets`] Iroha Query finds all `Asset`s presented.
         #[derive(Copy, Display)]
@@ -906,10 +1031,50 @@ pub mod asset {
         #[display(fmt = "Find all asset definitions")]
         #[ffi_type]
         pub struct FindAssetsDefinitions;
+
+        /// [`FindAssetsWithDefinition`] Iroha Query finds all `Asset`s presented,
+        /// each joined with its `AssetDefinition`.
+        #[derive(Copy, Display)]
+        #[display(fmt = "Find all assets with their definitions")]
+        #[ffi_type]
+        pub struct FindAssetsWithDefinition;
+
+        /// [`FindAssetQuantityAt`] Iroha Query finds the quantity of an `Asset`
+        /// as it was at the end of the block with the given height.
+        ///
+        /// Holdings which did not exist at that height have zero quantity.
+        /// Peers may bound the history they keep, then the quantities older than it
+        /// fail with [`QueryExecutionFail::Pruned`](error::QueryExecutionFail::Pruned).
+        /// The heights of the blocks not committed yet fail with
+        /// [`QueryExecutionFail::NotCommitted`](error::QueryExecutionFail::NotCommitted).
+        #[derive(Display)]
+        #[display(fmt = "Find quantity of `{asset_id}` at height {height}")]
+        #[ffi_type]
+        pub struct FindAssetQuantityAt {
+            /// `Id` of the asset.
+            pub asset_id: AssetId,
+            /// Height of the block after which the quantity is taken.
+            pub height: NonZeroU64,
+        }
+
+        /// [`FindAllowances`] Iroha Query finds the [`Allowance`](crate::asset::Allowance)s
+        /// either given by or given to a specified account.
+        #[derive(Display)]
+        #[display(fmt = "Find allowances of `{id}` account")]
+        #[repr(transparent)]
+        // SAFETY: `FindAllowances` has no trap representation in `AccountId`
+        #[ffi_type(unsafe {robust})]
+        pub struct FindAllowances {
+            /// `Id` of an account to find.
+            pub id: AccountId,
+        }
     }
     /// The prelude re-exports most commonly used traits, structs and macros from this crate.
     pub mod prelude {
-        pub use super::{FindAssets, FindAssetsDefinitions};
+        pub use super::{
+            FindAllowances, FindAssetQuantityAt, FindAssets, FindAssetsDefinitions,
+            FindAssetsWithDefinition,
+        };
     }
 }
 
@@ -1001,11 +1166,177 @@ pub mod executor {
         #[display(fmt = "Find all peers parameters")]
         #[ffi_type]
         pub struct FindParameters;
+
+        /// [`FindAllParameters`] Iroha Query lists all defined executor configuration parameters one by one.
+        ///
+        /// Unlike [`FindParameters`], its output can be filtered, e.g. to the custom parameters only.
+        #[derive(Copy, Display)]
+        #[display(fmt = "Find all parameters")]
+        #[ffi_type]
+        pub struct FindAllParameters;
+
+        /// [`FindFuelSchedule`] Iroha Query finds the [`FuelSchedule`](crate::parameter::FuelSchedule)
+        /// the next block is executed with.
+        #[derive(Copy, Display)]
+        #[display(fmt = "Find fuel schedule")]
+        #[ffi_type]
+        pub struct FindFuelSchedule;
+    }
+
+    /// The prelude re-exports most commonly used traits, structs and macros from this crate.
+    pub mod prelude {
+        pub use super::{
+            FindAllParameters, FindExecutorDataModel, FindFuelSchedule, FindParameters,
+        };
+    }
+}
+
+pub mod tombstone {
+    //! Queries related to [`crate::tombstone`].
+
+    #[cfg(not(feature = "std"))]
+    use alloc::{format, string::String, vec::Vec};
+
+    use derive_more::Display;
+
+    use crate::prelude::*;
+
+    queries! {
+        /// [`FindTombstone`] Iroha Query finds the [`Tombstone`] left by an unregistered entity.
+        #[derive(Display)]
+        #[display(fmt = "Find tombstone of `{id}`")]
+        #[repr(transparent)]
+        // SAFETY: `FindTombstone` has no trap representation in `IdBox`
+        #[ffi_type(unsafe {robust})]
+        pub struct FindTombstone {
+            /// `Id` of the unregistered entity.
+            pub id: IdBox,
+        }
+    }
+
+    /// The prelude re-exports most commonly used traits, structs and macros from this crate.
+    pub mod prelude {
+        pub use super::FindTombstone;
+    }
+}
+
+pub mod subscription {
+    //! Queries related to [`crate::subscription`].
+
+    #[cfg(not(feature = "std"))]
+    use alloc::{format, string::String, vec::Vec};
+
+    use derive_more::Display;
+
+    use crate::prelude::*;
+
+    queries! {
+        /// [`FindSubscription`] Iroha Query finds the notification [`Subscription`] by its id.
+        #[derive(Display)]
+        #[display(fmt = "Find subscription `{id}`")]
+        #[repr(transparent)]
+        // SAFETY: `FindSubscription` has no trap representation in `SubscriptionId`
+        #[ffi_type(unsafe {robust})]
+        pub struct FindSubscription {
+            /// `Id` of the subscription.
+            pub id: SubscriptionId,
+        }
     }
 
     /// The prelude re-exports most commonly used traits, structs and macros from this crate.
     pub mod prelude {
-        pub use super::{FindExecutorDataModel, FindParameters};
+        pub use super::FindSubscription;
+    }
+}
+
+pub mod dead_letter {
+    //! Queries related to [`crate::dead_letter`].
+
+    #[cfg(not(feature = "std"))]
+    use alloc::{format, string::String, vec::Vec};
+
+    use derive_more::Display;
+
+    use crate::prelude::*;
+
+    queries! {
+        /// [`FindDeadLetters`] Iroha Query finds the [`DeadLetter`]s recorded for failed executions of a trigger.
+        ///
+        /// The default executor lets only the owner of the trigger find them.
+        #[derive(Display)]
+        #[display(fmt = "Find dead letters of `{trigger}`")]
+        #[repr(transparent)]
+        // SAFETY: `FindDeadLetters` has no trap representation in `TriggerId`
+        #[ffi_type(unsafe {robust})]
+        pub struct FindDeadLetters {
+            /// `Id` of the trigger.
+            pub trigger: TriggerId,
+        }
+    }
+
+    /// The prelude re-exports most commonly used traits, structs and macros from this crate.
+    pub mod prelude {
+        pub use super::FindDeadLetters;
+    }
+}
+
+pub mod message {
+    //! Queries related to [`crate::message`].
+
+    #[cfg(not(feature = "std"))]
+    use alloc::{format, string::String, vec::Vec};
+
+    use derive_more::Display;
+
+    use crate::prelude::*;
+
+    queries! {
+        /// [`FindInbox`] Iroha Query finds the [`DirectMessage`]s in the inbox of an account.
+        ///
+        /// Only the messages sent to or by the query authority are returned.
+        #[derive(Display)]
+        #[display(fmt = "Find inbox of `{id}` account")]
+        #[repr(transparent)]
+        // SAFETY: `FindInbox` has no trap representation in `AccountId`
+        #[ffi_type(unsafe {robust})]
+        pub struct FindInbox {
+            /// `Id` of the recipient account.
+            pub id: AccountId,
+        }
+    }
+
+    /// The prelude re-exports most commonly used traits, structs and macros from this crate.
+    pub mod prelude {
+        pub use super::FindInbox;
+    }
+}
+
+pub mod escrow {
+    //! Queries related to [`crate::escrow`].
+
+    #[cfg(not(feature = "std"))]
+    use alloc::{format, string::String, vec::Vec};
+
+    use derive_more::Display;
+
+    use crate::prelude::*;
+
+    queries! {
+        /// [`FindEscrows`] Iroha Query finds the [`Escrow`]s sent or received by an account.
+        #[derive(Display)]
+        #[display(fmt = "Find escrows of `{id}` account")]
+        #[repr(transparent)]
+        // SAFETY: `FindEscrows` has no trap representation in `AccountId`
+        #[ffi_type(unsafe {robust})]
+        pub struct FindEscrows {
+            /// `Id` of the sender or recipient account.
+            pub id: AccountId,
+        }
+    }
+
+    /// The prelude re-exports most commonly used traits, structs and macros from this crate.
+    pub mod prelude {
+        pub use super::FindEscrows;
     }
 }
 
@@ -1150,6 +1481,26 @@ pub mod error {
             InvalidSingularParameters,
             /// Reached the limit of parallel queries. Either wait for previous queries to complete, or increase the limit in the config.
             CapacityLimit,
+            /// Failed to aggregate the query results: {0}
+            Aggregation(
+                #[skip_from]
+                #[skip_try_from]
+                String,
+            ),
+            /// The history before height {0} was pruned by the peer
+            Pruned(
+                #[skip_from]
+                #[skip_try_from]
+                core::num::NonZeroU64,
+            ),
+            /// The peer sheds the load and doesn't start new queries. Retry after a pause.
+            Shed,
+            /// The block at height {0} is not committed yet
+            NotCommitted(
+                #[skip_from]
+                #[skip_try_from]
+                core::num::NonZeroU64,
+            ),
         }
 
         /// Type assertion error
@@ -1197,6 +1548,14 @@ pub mod error {
             Permission(Box<Permission>),
             /// Failed to find public key: `{0}`
             PublicKey(PublicKey),
+            /// Tombstone of `{0}` not found
+            Tombstone(Box<IdBox>),
+            /// Subscription `{0}` not found
+            Subscription(SubscriptionId),
+            /// Dead letter `{0}` not found
+            DeadLetter(DeadLetterId),
+            /// Escrow `{0}` not found
+            Escrow(EscrowId),
         }
     }
 }
@@ -1206,9 +1565,10 @@ pub mod error {
 pub mod prelude {
     pub use super::{
         account::prelude::*, asset::prelude::*, block::prelude::*, builder::prelude::*,
-        domain::prelude::*, dsl::prelude::*, executor::prelude::*, nft::prelude::*,
-        parameters::prelude::*, peer::prelude::*, permission::prelude::*, role::prelude::*,
-        transaction::prelude::*, trigger::prelude::*, CommittedTransaction, QueryBox, QueryRequest,
-        SingularQueryBox,
+        dead_letter::prelude::*, domain::prelude::*, dsl::prelude::*, escrow::prelude::*,
+        executor::prelude::*, message::prelude::*, nft::prelude::*, parameters::prelude::*,
+        peer::prelude::*, permission::prelude::*, role::prelude::*, subscription::prelude::*,
+        tombstone::prelude::*, transaction::prelude::*, trigger::prelude::*, AssetWithDefinition,
+        CommittedTransaction, QueryBox, QueryRequest, SingularQueryBox,
     };
 }
@@ -2,7 +2,7 @@
 
 #[cfg(not(feature = "std"))]
 use alloc::{borrow::ToOwned, format, string::String, string::ToString, vec::Vec};
-use core::num::NonZeroU64;
+use core::{cmp::Ordering, num::NonZeroU64};
 
 use derive_more::{Constructor, Display};
 use getset::Getters;
@@ -86,6 +86,45 @@ mod model {
     pub struct Sorting {
         /// Sort query result using [`Name`] of the key in metadata.
         pub sort_by_metadata_key: Option<Name>,
+        /// Sort query result by the key of the output, unless it is sorted by the metadata key.
+        #[serde(default)]
+        pub sort_by: Option<SortKey>,
+        /// Order of the sorted query result.
+        #[serde(default)]
+        pub order: SortOrder,
+    }
+
+    /// Key of the query output to sort by
+    #[derive(
+        Debug, Clone, Copy, PartialEq, Eq, Decode, Encode, Deserialize, Serialize, IntoSchema,
+    )]
+    pub enum SortKey {
+        /// Identifier of the output, e.g. of an account. Blocks are sorted by height
+        Id,
+        /// Value of the output, e.g. quantity of an asset
+        Value,
+    }
+
+    /// Order of the sorted query result
+    #[derive(
+        Debug,
+        Default,
+        Clone,
+        Copy,
+        PartialEq,
+        Eq,
+        Decode,
+        Encode,
+        Deserialize,
+        Serialize,
+        IntoSchema,
+    )]
+    pub enum SortOrder {
+        /// From the least to the greatest
+        #[default]
+        Ascending,
+        /// From the greatest to the least
+        Descending,
     }
 
     /// Structure for query fetch size parameter encoding/decoding
@@ -128,6 +167,24 @@ mod model {
         pub pagination: Pagination,
         pub sorting: Sorting,
         pub fetch_size: FetchSize,
+        /// Aggregate all the results into a single value instead of returning them, the pagination is ignored.
+        #[serde(default)]
+        pub aggregation: Option<Aggregation>,
+    }
+
+    /// Aggregation of the results of an iterable query, computed by the peer
+    #[derive(
+        Debug, Clone, Copy, PartialEq, Eq, Decode, Encode, Deserialize, Serialize, IntoSchema,
+    )]
+    pub enum Aggregation {
+        /// Number of the results
+        Count,
+        /// Sum of the selected numeric values, zero if there are no results
+        Sum,
+        /// Least of the selected numeric values, none if there are no results
+        Min,
+        /// Greatest of the selected numeric values, none if there are no results
+        Max,
     }
 }
 
@@ -136,11 +193,36 @@ impl Sorting {
     pub fn by_metadata_key(key: Name) -> Self {
         Self {
             sort_by_metadata_key: Some(key),
+            ..Self::default()
+        }
+    }
+
+    /// Creates a sorting by the [`SortKey`] of the output.
+    pub fn by(key: SortKey) -> Self {
+        Self {
+            sort_by: Some(key),
+            ..Self::default()
+        }
+    }
+
+    /// Sort in the `order`.
+    #[must_use]
+    pub fn with_order(self, order: SortOrder) -> Self {
+        Self { order, ..self }
+    }
+}
+
+impl SortOrder {
+    /// Apply the order to the `ordering` of the ascending sort
+    pub fn apply(self, ordering: Ordering) -> Ordering {
+        match self {
+            Self::Ascending => ordering,
+            Self::Descending => ordering.reverse(),
         }
     }
 }
 
 pub mod prelude {
     //! Prelude: re-export most commonly used traits, structs and macros from this module.
-    pub use super::{FetchSize, Pagination, Sorting};
+    pub use super::{Aggregation, FetchSize, Pagination, SortKey, SortOrder, Sorting};
 }
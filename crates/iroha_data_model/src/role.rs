@@ -1,7 +1,9 @@
 //! Structures, traits and impls related to `Role`s.
 
 #[cfg(not(feature = "std"))]
-use alloc::{format, string::String, vec::Vec};
+use alloc::{collections::BTreeSet, format, string::String, vec::Vec};
+#[cfg(feature = "std")]
+use std::collections::BTreeSet;
 
 use iroha_data_model_derive::model;
 
@@ -52,6 +54,8 @@ mod model {
     }
 
     /// Role is a tag for a set of permission tokens.
+    ///
+    /// A role can extend other roles, in which case it includes their permissions as well.
     #[derive(
         Debug, Display, Clone, IdEqOrdHash, Decode, Encode, Deserialize, Serialize, IntoSchema,
     )]
@@ -62,6 +66,9 @@ mod model {
         pub id: RoleId,
         /// Permission tokens.
         pub permissions: Permissions,
+        /// Roles whose permissions are included in this role.
+        #[serde(default)]
+        pub extends: BTreeSet<RoleId>,
     }
 
     /// Builder for [`Role`]
@@ -102,6 +109,12 @@ impl Role {
     pub fn permissions(&self) -> impl ExactSizeIterator<Item = &Permission> {
         self.permissions.iter()
     }
+
+    /// Get an iterator over the [`RoleId`]s of the roles this `Role` extends
+    #[inline]
+    pub fn extends(&self) -> impl ExactSizeIterator<Item = &RoleId> {
+        self.extends.iter()
+    }
 }
 
 impl NewRole {
@@ -114,6 +127,7 @@ impl NewRole {
             inner: Role {
                 id,
                 permissions: Permissions::new(),
+                extends: BTreeSet::new(),
             },
         }
     }
@@ -125,6 +139,14 @@ impl NewRole {
         self.inner.permissions.insert(perm.into());
         self
     }
+
+    /// Include the permissions of the role with `role_id` in the [`Role`]
+    #[must_use]
+    #[inline]
+    pub fn extend(mut self, role_id: RoleId) -> Self {
+        self.inner.extends.insert(role_id);
+        self
+    }
 }
 
 impl Registered for Role {
@@ -0,0 +1,119 @@
+//! Structures, traits and impls related to notification `Subscription`s.
+//!
+//! A [`Subscription`] is registered by an account with [`Subscribe`](crate::isi::Subscribe)
+//! and persisted on-chain, so that every peer can deliver the events matching its filter
+//! without any peer-local configuration.
+
+#[cfg(not(feature = "std"))]
+use alloc::{format, string::String, vec::Vec};
+
+use iroha_data_model_derive::model;
+
+pub use self::model::*;
+use crate::{account::AccountId, events::EventFilterBox, name::Name};
+
+/// Number of subscriptions an account may register.
+pub const MAX_SUBSCRIPTIONS_PER_ACCOUNT: usize = 16;
+
+/// Maximal length of the URL of a [`SubscriptionDelivery::Webhook`].
+pub const MAX_WEBHOOK_URL_LEN: usize = 2048;
+
+#[model]
+mod model {
+    use derive_more::{Constructor, Display};
+    use getset::Getters;
+    use iroha_schema::IntoSchema;
+    use parity_scale_codec::{Decode, Encode};
+    use serde::{Deserialize, Serialize};
+
+    use super::*;
+
+    /// Identification of a [`Subscription`]: the name is unique among the subscriptions of the account.
+    #[derive(
+        Debug,
+        Display,
+        Clone,
+        PartialEq,
+        Eq,
+        PartialOrd,
+        Ord,
+        Hash,
+        Constructor,
+        Getters,
+        Decode,
+        Encode,
+        Deserialize,
+        Serialize,
+        IntoSchema,
+    )]
+    #[display(fmt = "{name}/{account}")]
+    #[getset(get = "pub")]
+    #[ffi_type]
+    pub struct SubscriptionId {
+        /// Account which owns the subscription.
+        pub account: AccountId,
+        /// Name of the subscription.
+        pub name: Name,
+    }
+
+    /// The way events matching a [`Subscription`] reach the subscriber.
+    #[derive(
+        Debug,
+        Display,
+        Clone,
+        PartialEq,
+        Eq,
+        PartialOrd,
+        Ord,
+        Decode,
+        Encode,
+        Deserialize,
+        Serialize,
+        IntoSchema,
+    )]
+    #[ffi_type]
+    pub enum SubscriptionDelivery {
+        /// A single designated peer `POST`s every matching SCALE-encoded `EventMessage` to the
+        /// `http` or `https` URL, provided its host is allowed in the configuration of the peer.
+        #[display(fmt = "webhook {_0}")]
+        Webhook(String),
+        /// Any peer streams matching events to the subscriber connected to its inbox endpoint
+        /// who proves to control the account.
+        #[display(fmt = "inbox")]
+        Inbox,
+    }
+
+    /// Notification preferences of an account persisted on-chain.
+    #[derive(
+        Debug,
+        Display,
+        Clone,
+        PartialEq,
+        Eq,
+        PartialOrd,
+        Ord,
+        Constructor,
+        Getters,
+        Decode,
+        Encode,
+        Deserialize,
+        Serialize,
+        IntoSchema,
+    )]
+    #[display(fmt = "{id} ({delivery})")]
+    #[getset(get = "pub")]
+    #[ffi_type]
+    pub struct Subscription {
+        /// Identification of the subscription.
+        pub id: SubscriptionId,
+        /// Filter selecting the events to deliver.
+        pub filter: EventFilterBox,
+        /// The way the events are delivered.
+        pub delivery: SubscriptionDelivery,
+    }
+}
+
+/// The prelude re-exports most commonly used traits, structs and macros from this module.
+pub mod prelude {
+    pub use super::{Subscription, SubscriptionDelivery, SubscriptionId};
+}
@@ -0,0 +1,60 @@
+//! Structures, traits and impls related to `Tombstone`s.
+//!
+//! Unregistering an [`Account`](crate::account::Account) or a [`Domain`](crate::domain::Domain)
+//! leaves a [`Tombstone`] behind, so that ids referenced by historical blocks and events can
+//! still be resolved. Tombstones are removed either by registering the entity with the same id
+//! again or explicitly with [`Purge`](crate::isi::Purge).
+
+#[cfg(not(feature = "std"))]
+use alloc::{format, string::String, vec::Vec};
+use core::num::NonZeroU64;
+
+use iroha_data_model_derive::model;
+
+pub use self::model::*;
+use crate::{account::AccountId, IdBox};
+
+#[model]
+mod model {
+    use derive_more::{Constructor, Display};
+    use getset::Getters;
+    use iroha_schema::IntoSchema;
+    use parity_scale_codec::{Decode, Encode};
+    use serde::{Deserialize, Serialize};
+
+    use super::*;
+
+    /// Minimal record retained for an unregistered entity.
+    #[derive(
+        Debug,
+        Display,
+        Clone,
+        PartialEq,
+        Eq,
+        PartialOrd,
+        Ord,
+        Constructor,
+        Getters,
+        Decode,
+        Encode,
+        Deserialize,
+        Serialize,
+        IntoSchema,
+    )]
+    #[display(fmt = "{id} (removed at №{removed_at})")]
+    #[getset(get = "pub")]
+    #[ffi_type]
+    pub struct Tombstone {
+        /// Identification of the removed entity.
+        pub id: IdBox,
+        /// Height of the block in which the entity was unregistered.
+        pub removed_at: NonZeroU64,
+        /// Account which unregistered the entity.
+        pub removed_by: AccountId,
+    }
+}
+
+/// The prelude re-exports most commonly used traits, structs and macros from this module.
+pub mod prelude {
+    pub use super::Tombstone;
+}
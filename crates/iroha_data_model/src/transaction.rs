@@ -24,6 +24,7 @@ use crate::{
     account::AccountId,
     isi::{Instruction, InstructionBox},
     metadata::Metadata,
+    name::Name,
     trigger::TriggerId,
     ChainId,
 };
@@ -174,6 +175,18 @@ mod model {
         pub(super) payload: TransactionPayload,
     }
 
+    /// Transaction which is built, but not signed yet, see [`TransactionBuilder::into_unsigned`].
+    ///
+    /// Exported as SCALE or JSON, it can be signed on another machine with [`Self::sign_payload`],
+    /// so that the private key never leaves it, and combined with the detached signature by [`Self::with_signature`].
+    #[derive(Debug, Clone, PartialEq, Eq, Decode, Encode, Deserialize, Serialize)]
+    #[serde(transparent)]
+    #[repr(transparent)]
+    pub struct UnsignedTransaction {
+        /// Payload to be signed.
+        pub(super) payload: TransactionPayload,
+    }
+
     /// Initial execution step of a transaction, which may invoke data triggers.
     #[derive(
         Debug,
@@ -578,6 +591,13 @@ impl TransactionBuilder {
         self
     }
 
+    /// Finish building the transaction without signing it, to sign it elsewhere.
+    pub fn into_unsigned(self) -> UnsignedTransaction {
+        UnsignedTransaction {
+            payload: self.payload,
+        }
+    }
+
     /// Sign transaction with provided key pair.
     #[must_use]
     pub fn sign(self, private_key: &iroha_crypto::PrivateKey) -> SignedTransaction {
@@ -589,6 +609,81 @@ impl TransactionBuilder {
         }
         .into()
     }
+
+    /// Sign transaction with the signature of the hash of its payload produced by `sign`,
+    /// e.g. by a hardware security module or a remote signing service.
+    ///
+    /// # Errors
+    ///
+    /// Fails if `sign` fails
+    pub fn sign_with<E>(
+        self,
+        sign: impl FnOnce(&[u8]) -> Result<Signature, E>,
+    ) -> Result<SignedTransaction, E> {
+        let hash = HashOf::new(&self.payload);
+        let signature =
+            TransactionSignature(SignatureOf::from_untyped_unchecked(sign(hash.as_ref())?));
+
+        Ok(SignedTransactionV1 {
+            signature,
+            payload: self.payload,
+        }
+        .into())
+    }
+}
+
+impl UnsignedTransaction {
+    /// Account on behalf of which the transaction is executed, i.e. the one expected to sign it.
+    #[inline]
+    pub fn authority(&self) -> &AccountId {
+        &self.payload.authority
+    }
+
+    /// Instructions or a `WebAssembly` smart contract to be executed.
+    #[inline]
+    pub fn instructions(&self) -> &Executable {
+        &self.payload.instructions
+    }
+
+    /// Metadata of the transaction.
+    #[inline]
+    pub fn metadata(&self) -> &Metadata {
+        &self.payload.metadata
+    }
+
+    /// Id of the chain the transaction is meant for.
+    #[inline]
+    pub fn chain(&self) -> &ChainId {
+        &self.payload.chain
+    }
+
+    /// Produce the detached signature of the transaction.
+    ///
+    /// Nothing but the transaction itself is needed, so that the signing machine can stay offline.
+    #[must_use]
+    pub fn sign_payload(&self, private_key: &iroha_crypto::PrivateKey) -> TransactionSignature {
+        TransactionSignature(SignatureOf::new(private_key, &self.payload))
+    }
+
+    /// Attach the detached `signature` produced by [`Self::sign_payload`].
+    ///
+    /// # Errors
+    ///
+    /// Fails if `signature` wasn't made over this transaction by its authority.
+    pub fn with_signature(
+        self,
+        signature: TransactionSignature,
+    ) -> Result<SignedTransaction, iroha_crypto::Error> {
+        signature
+            .0
+            .verify(&self.payload.authority.signatory, &self.payload)?;
+
+        Ok(SignedTransactionV1 {
+            signature,
+            payload: self.payload,
+        }
+        .into())
+    }
 }
 
 impl TransactionEntrypoint {
@@ -601,6 +696,21 @@ impl TransactionEntrypoint {
         }
     }
 
+    /// Whether any of the instructions this transaction starts with is [`Tagged`](crate::isi::Tagged) with `tag`.
+    pub fn contains_tag(&self, tag: &Name) -> bool {
+        let instructions: &[InstructionBox] = match self {
+            TransactionEntrypoint::External(entrypoint) => match entrypoint.instructions() {
+                Executable::Instructions(instructions) => instructions,
+                Executable::Wasm(_) => &[],
+            },
+            TransactionEntrypoint::Time(entrypoint) => &entrypoint.instructions.0,
+        };
+
+        instructions.iter().any(|instruction| {
+            matches!(instruction, InstructionBox::Tagged(tagged) if tagged.tag == *tag)
+        })
+    }
+
     /// Hash for this transaction entrypoint.
     ///
     /// TODO: prevent divergent hashes caused by direct calls to `HashOf::new`,
@@ -639,9 +749,10 @@ impl TransactionResult {
     }
 }
 
-mod base64 {
+pub(crate) mod base64 {
     //! Module with (de-)serialization functions for
     //! [`WasmSmartContract`](super::WasmSmartContract)'s bytes using `base64`.
+    //! Also used for the ciphertext of [`EncryptedValue`](crate::metadata::EncryptedValue).
     //!
     //! No extra heap allocation is performed nor for serialization nor for deserialization.
 
@@ -838,7 +949,10 @@ pub mod error {
                 Mint(_) => "mint",
                 Register(_) => "register",
                 Transfer(_) => "transfer",
+                Split(_) => "split",
+                Merge(_) => "merge",
                 Unregister(_) => "un-register",
+                Purge(_) => "purge",
                 SetKeyValue(_) => "set key-value pair",
                 RemoveKeyValue(_) => "remove key-value pair",
                 Grant(_) => "grant",
@@ -847,6 +961,27 @@ pub mod error {
                 SetParameter(_) => "set parameter",
                 Upgrade(_) => "upgrade",
                 Log(_) => "log",
+                Subscribe(_) => "subscribe",
+                Unsubscribe(_) => "unsubscribe",
+                RetryDeadLetter(_) => "retry dead letter",
+                DiscardDeadLetter(_) => "discard dead letter",
+                Deprecate(_) => "deprecate",
+                Delegate(_) => "delegate",
+                Tagged(_) => "tagged",
+                Onboard(_) => "onboard",
+                Payout(_) => "payout",
+                AddTag(_) => "add tag",
+                RemoveTag(_) => "remove tag",
+                Freeze(_) => "freeze",
+                Unfreeze(_) => "unfreeze",
+                Approve(_) => "approve",
+                TransferFrom(_) => "transfer from",
+                Message(_) => "message",
+                Convert(_) => "convert",
+                CreateEscrow(_) => "create escrow",
+                ClaimEscrow(_) => "claim escrow",
+                RefundEscrow(_) => "refund escrow",
+                TransferMany(_) => "transfer many",
                 Custom(_) => "custom",
             };
             write!(
@@ -879,12 +1014,336 @@ pub mod error {
     }
 }
 
+pub mod inspection {
+    //! Human-readable rendering of [`SignedTransaction`]s, useful for debugging payloads
+    //! captured from logs or received from third parties.
+
+    #[cfg(not(feature = "std"))]
+    use alloc::{
+        string::{String, ToString},
+        vec::Vec,
+    };
+
+    use iroha_crypto::PublicKey;
+
+    use super::*;
+    use crate::isi::InstructionType;
+
+    /// Structured view of a [`SignedTransaction`]
+    #[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+    pub struct TransactionInspection {
+        /// Hash of the transaction
+        pub hash: HashOf<SignedTransaction>,
+        /// Chain the transaction is meant for
+        pub chain: ChainId,
+        /// Account on behalf of which the transaction is submitted
+        pub authority: AccountId,
+        /// Creation time (in milliseconds since the UNIX epoch)
+        pub creation_time_ms: u64,
+        /// Time-to-live (in milliseconds), if set
+        pub time_to_live_ms: Option<u64>,
+        /// Nonce, if set
+        pub nonce: Option<NonZeroU32>,
+        /// Metadata attached to the transaction
+        pub metadata: Metadata,
+        /// Payload of the transaction
+        pub executable: ExecutableInspection,
+        /// Outcome of the signature verification
+        pub signature: SignatureInspection,
+    }
+
+    /// Structured view of an [`Executable`]
+    #[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+    pub enum ExecutableInspection {
+        /// Ordered set of instructions
+        Instructions(Vec<InstructionInspection>),
+        /// WebAssembly smart contract, which can't be broken down any further
+        Wasm {
+            /// Size of the compiled smart contract
+            size_bytes: usize,
+            /// Hash of the compiled smart contract
+            hash: HashOf<WasmSmartContract>,
+        },
+    }
+
+    /// Structured view of an [`InstructionBox`]
+    #[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+    pub struct InstructionInspection {
+        /// Kind of the instruction
+        pub kind: InstructionType,
+        /// Short human-readable description of the instruction
+        pub summary: String,
+        /// The instruction itself
+        pub instruction: InstructionBox,
+    }
+
+    /// Outcome of the verification of a [`TransactionSignature`]
+    #[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+    pub struct SignatureInspection {
+        /// Public key the signature is checked against, i.e. the signatory of the authority
+        pub signatory: PublicKey,
+        /// Reason of the verification failure, `None` if the signature is valid
+        pub error: Option<String>,
+    }
+
+    impl SignatureInspection {
+        /// Whether the signature is valid
+        pub fn is_valid(&self) -> bool {
+            self.error.is_none()
+        }
+    }
+
+    impl From<&SignedTransaction> for TransactionInspection {
+        fn from(tx: &SignedTransaction) -> Self {
+            let executable = match tx.instructions() {
+                Executable::Instructions(instructions) => ExecutableInspection::Instructions(
+                    instructions
+                        .iter()
+                        .map(|instruction| InstructionInspection {
+                            kind: instruction.into(),
+                            summary: instruction.to_string(),
+                            instruction: instruction.clone(),
+                        })
+                        .collect(),
+                ),
+                Executable::Wasm(wasm) => ExecutableInspection::Wasm {
+                    size_bytes: wasm.size_bytes(),
+                    hash: HashOf::new(wasm),
+                },
+            };
+
+            Self {
+                hash: tx.hash(),
+                chain: tx.chain().clone(),
+                authority: tx.authority().clone(),
+                creation_time_ms: duration_ms(tx.creation_time()),
+                time_to_live_ms: tx.time_to_live().map(duration_ms),
+                nonce: tx.nonce(),
+                metadata: tx.metadata().clone(),
+                executable,
+                signature: SignatureInspection {
+                    signatory: tx.authority().signatory().clone(),
+                    error: tx.verify_signature().err().map(|err| err.to_string()),
+                },
+            }
+        }
+    }
+
+    fn duration_ms(duration: Duration) -> u64 {
+        duration
+            .as_millis()
+            .try_into()
+            .expect("INTERNAL BUG: Time should fit into u64")
+    }
+
+    impl Display for TransactionInspection {
+        fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+            writeln!(f, "transaction {}", self.hash)?;
+            writeln!(f, "  chain: {}", self.chain)?;
+            writeln!(f, "  authority: {}", self.authority)?;
+            writeln!(f, "  creation time: {} ms", self.creation_time_ms)?;
+            if let Some(time_to_live_ms) = self.time_to_live_ms {
+                writeln!(f, "  time to live: {time_to_live_ms} ms")?;
+            }
+            if let Some(nonce) = self.nonce {
+                writeln!(f, "  nonce: {nonce}")?;
+            }
+            match &self.signature.error {
+                None => writeln!(f, "  signature: valid ({})", self.signature.signatory)?,
+                Some(error) => writeln!(
+                    f,
+                    "  signature: INVALID ({}): {error}",
+                    self.signature.signatory
+                )?,
+            }
+            if self.metadata.iter().len() > 0 {
+                writeln!(f, "  metadata:")?;
+                for (key, value) in self.metadata.iter() {
+                    writeln!(f, "    {key}: {value}")?;
+                }
+            }
+            match &self.executable {
+                ExecutableInspection::Instructions(instructions) => {
+                    write!(f, "  instructions ({}):", instructions.len())?;
+                    for (i, instruction) in instructions.iter().enumerate() {
+                        write!(
+                            f,
+                            "\n    {}. [{}] {}",
+                            i + 1,
+                            instruction.kind,
+                            instruction.summary
+                        )?;
+                    }
+                    Ok(())
+                }
+                ExecutableInspection::Wasm { size_bytes, hash } => {
+                    write!(f, "  wasm: {size_bytes} bytes, {hash}")
+                }
+            }
+        }
+    }
+}
+
+pub mod simulation {
+    //! Outcome of executing a [`SignedTransaction`] against the current state without committing it,
+    //! and the estimation of the resources it consumes.
+    //!
+    //! The outcome is only indicative: the state may change by the time the transaction is committed.
+
+    #[cfg(not(feature = "std"))]
+    use alloc::vec::Vec;
+
+    use super::*;
+    use crate::{events::EventBox, ValidationFail};
+
+    /// Outcome of simulating a [`SignedTransaction`]
+    #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+    pub struct TransactionSimulation {
+        /// Result of the execution: the data trigger sequence or the rejection reason
+        pub result: TransactionResultInner,
+        /// Decision of the executor on the instructions of the transaction, in order.
+        ///
+        /// Each instruction is executed on top of the preceding ones. The instructions following
+        /// the first rejected one aren't executed, so they're left out.
+        pub instructions: Vec<InstructionOutcome>,
+        /// Events the transaction would emit if committed
+        pub events: Vec<EventBox>,
+    }
+
+    /// Decision of the executor on a single instruction
+    #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+    pub struct InstructionOutcome {
+        /// The executed instruction
+        pub instruction: InstructionBox,
+        /// Result of the execution
+        pub result: Result<(), ValidationFail>,
+    }
+
+    impl TransactionSimulation {
+        /// Whether the transaction would be committed
+        pub fn is_ok(&self) -> bool {
+            self.result.is_ok()
+        }
+    }
+
+    /// Resources a [`SignedTransaction`] consumes, compared against the limits set by the chain parameters.
+    ///
+    /// Resources which don't apply to the kind of the transaction are `None`.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+    pub struct TransactionEstimate {
+        /// Number of instructions, see [`TransactionParameters::max_instructions`](crate::parameter::TransactionParameters).
+        ///
+        /// Not known for WASM transactions, which emit their instructions at runtime.
+        pub instructions: Option<ResourceUsage>,
+        /// Size of the WASM binary in bytes, see [`TransactionParameters::smart_contract_size`](crate::parameter::TransactionParameters)
+        pub smart_contract_size: Option<ResourceUsage>,
+        /// Fuel consumed by executing the WASM binary against the current state,
+        /// see [`SmartContractParameters::fuel`](crate::parameter::SmartContractParameters).
+        ///
+        /// If the fuel runs out, the amount needed is unknown and `used` is reported as `limit + 1`.
+        pub fuel: Option<ResourceUsage>,
+    }
+
+    /// Amount of a resource consumed and the limit of it
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+    pub struct ResourceUsage {
+        /// Amount consumed
+        pub used: u64,
+        /// Maximum amount allowed
+        pub limit: u64,
+    }
+
+    impl ResourceUsage {
+        /// Whether more than allowed is consumed
+        pub fn exceeds_limit(&self) -> bool {
+            self.used > self.limit
+        }
+    }
+
+    impl TransactionEstimate {
+        /// Whether the transaction fits into all the limits
+        pub fn is_within_limits(&self) -> bool {
+            [self.instructions, self.smart_contract_size, self.fuel]
+                .into_iter()
+                .flatten()
+                .all(|usage| !usage.exceeds_limit())
+        }
+    }
+}
+
+pub mod receipt {
+    //! Acknowledgment of a submitted [`SignedTransaction`] signed by the peer which received it.
+    //!
+    //! The receipt proves that the peer accepted the transaction into its queue,
+    //! it doesn't prove that the transaction is committed.
+
+    use iroha_crypto::{KeyPair, PublicKey};
+
+    use super::*;
+    use crate::peer::PeerId;
+
+    /// What the peer acknowledges about a submitted transaction
+    #[derive(Debug, Clone, PartialEq, Eq, Decode, Encode, Serialize, Deserialize)]
+    pub struct TransactionReceiptPayload {
+        /// Hash of the submitted transaction
+        pub tx_hash: HashOf<SignedTransaction>,
+        /// Time the transaction was received at, in milliseconds since the Unix epoch
+        pub received_at_ms: u64,
+        /// Number of transactions waiting in the queue of the peer, including this one, when the receipt was signed.
+        ///
+        /// It's not the position of the transaction: the queue is shared by the concurrent submissions.
+        pub queue_len: u64,
+        /// Peer which received the transaction and signed the receipt
+        pub peer: PeerId,
+    }
+
+    /// [`TransactionReceiptPayload`] signed by the peer which received the transaction
+    #[derive(Debug, Clone, PartialEq, Eq, Decode, Encode, Serialize, Deserialize)]
+    pub struct TransactionReceipt {
+        /// Acknowledged facts
+        pub payload: TransactionReceiptPayload,
+        /// Signature of the payload by the key of [`TransactionReceiptPayload::peer`]
+        pub signature: SignatureOf<TransactionReceiptPayload>,
+    }
+
+    impl TransactionReceipt {
+        /// Sign the `payload` by the `key_pair` of the peer.
+        ///
+        /// # Panics
+        /// If the `key_pair` isn't the key pair of [`TransactionReceiptPayload::peer`]
+        pub fn sign(payload: TransactionReceiptPayload, key_pair: &KeyPair) -> Self {
+            assert_eq!(
+                payload.peer.public_key(),
+                key_pair.public_key(),
+                "receipt must be signed by the peer it names"
+            );
+            let signature = SignatureOf::new(key_pair.private_key(), &payload);
+            Self { payload, signature }
+        }
+
+        /// Public key of the peer which signed the receipt
+        pub fn signatory(&self) -> &PublicKey {
+            self.payload.peer.public_key()
+        }
+
+        /// Check that the receipt is signed by the peer it names.
+        ///
+        /// Whether the peer is trusted is up to the verifier.
+        ///
+        /// # Errors
+        /// Fails if the signature doesn't match the payload or the peer
+        pub fn verify(&self) -> Result<(), iroha_crypto::Error> {
+            self.signature.verify(self.signatory(), &self.payload)
+        }
+    }
+}
+
 /// The prelude re-exports most commonly used traits, structs and macros from this module.
 pub mod prelude {
     pub use super::{
         error::prelude::*, DataTriggerSequence, DataTriggerStep, Executable, ExecutionStep,
         SignedTransaction, TimeTriggerEntrypoint, TransactionBuilder, TransactionEntrypoint,
-        TransactionResult, TransactionResultInner, WasmSmartContract,
+        TransactionResult, TransactionResultInner, UnsignedTransaction, WasmSmartContract,
     };
 }
 
@@ -900,4 +1359,101 @@ mod tests {
         let contract = WasmSmartContract::from_compiled(vec![0, 1, 2, 3, 4]);
         assert_eq!(format!("{contract:?}"), "WASM binary(len = 5)");
     }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn inspection_reports_instructions_and_signature_validity() {
+        use iroha_crypto::KeyPair;
+
+        use super::inspection::{ExecutableInspection, TransactionInspection};
+        use crate::{isi::Log, Level};
+
+        let key_pair = KeyPair::random();
+        let authority =
+            AccountId::new("wonderland".parse().unwrap(), key_pair.public_key().clone());
+        let builder = || {
+            TransactionBuilder::new(
+                "00000000-0000-0000-0000-000000000000".into(),
+                authority.clone(),
+            )
+            .with_instructions([Log::new(Level::INFO, "hello".to_owned())])
+        };
+
+        let inspection = TransactionInspection::from(&builder().sign(key_pair.private_key()));
+        assert!(inspection.signature.is_valid());
+        let ExecutableInspection::Instructions(instructions) = &inspection.executable else {
+            panic!("expected instructions");
+        };
+        assert_eq!(instructions.len(), 1);
+        assert!(inspection.to_string().contains("hello"));
+
+        let forged = builder().sign(KeyPair::random().private_key());
+        assert!(!TransactionInspection::from(&forged).signature.is_valid());
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn unsigned_transaction_is_signed_offline() {
+        use iroha_crypto::KeyPair;
+        use parity_scale_codec::DecodeAll;
+
+        use crate::{isi::Log, Level};
+
+        let key_pair = KeyPair::random();
+        let authority =
+            AccountId::new("wonderland".parse().unwrap(), key_pair.public_key().clone());
+        let unsigned = TransactionBuilder::new(
+            "00000000-0000-0000-0000-000000000000".into(),
+            authority.clone(),
+        )
+        .with_instructions([Log::new(Level::INFO, "hello".to_owned())])
+        .into_unsigned();
+
+        // Round trip through the air gap
+        let exported = serde_json::to_string(&unsigned).unwrap();
+        let imported: UnsignedTransaction = serde_json::from_str(&exported).unwrap();
+        assert_eq!(imported, unsigned);
+        let imported = UnsignedTransaction::decode_all(&mut unsigned.encode().as_slice()).unwrap();
+        let signature = imported.sign_payload(key_pair.private_key());
+
+        let forged = unsigned.sign_payload(KeyPair::random().private_key());
+        assert!(unsigned.clone().with_signature(forged).is_err());
+        let transaction = unsigned.with_signature(signature).unwrap();
+        assert_eq!(transaction.authority(), &authority);
+        transaction.verify_signature().unwrap();
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn receipt_is_verified_against_the_named_peer() {
+        use iroha_crypto::KeyPair;
+
+        use super::receipt::{TransactionReceipt, TransactionReceiptPayload};
+        use crate::{isi::Log, peer::PeerId, Level};
+
+        let key_pair = KeyPair::random();
+        let authority =
+            AccountId::new("wonderland".parse().unwrap(), key_pair.public_key().clone());
+        let transaction =
+            TransactionBuilder::new("00000000-0000-0000-0000-000000000000".into(), authority)
+                .with_instructions([Log::new(Level::INFO, "hello".to_owned())])
+                .sign(key_pair.private_key());
+        let peer_key_pair = KeyPair::random();
+        let payload = TransactionReceiptPayload {
+            tx_hash: transaction.hash(),
+            received_at_ms: 1_000,
+            queue_len: 1,
+            peer: PeerId::new(peer_key_pair.public_key().clone()),
+        };
+
+        let receipt = TransactionReceipt::sign(payload, &peer_key_pair);
+        receipt.verify().unwrap();
+
+        let mut forged = receipt.clone();
+        forged.payload.queue_len = 0;
+        assert!(forged.verify().is_err());
+        let mut forged = receipt;
+        forged.payload.peer = PeerId::new(KeyPair::random().public_key().clone());
+        assert!(forged.verify().is_err());
+    }
 }
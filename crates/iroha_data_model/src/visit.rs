@@ -44,16 +44,49 @@ pub trait Visit {
         visit_set_key_value(&SetKeyValueBox),
         visit_transfer(&TransferBox),
         visit_unregister(&UnregisterBox),
+        visit_purge(&PurgeBox),
+        visit_freeze(&FreezeBox),
+        visit_unfreeze(&UnfreezeBox),
         visit_upgrade(&Upgrade),
 
         visit_execute_trigger(&ExecuteTrigger),
         visit_set_parameter(&SetParameter),
         visit_log(&Log),
+        visit_split(&Split),
+        visit_merge(&Merge),
+        visit_subscribe(&Subscribe),
+        visit_unsubscribe(&Unsubscribe),
+        visit_retry_dead_letter(&RetryDeadLetter),
+        visit_discard_dead_letter(&DiscardDeadLetter),
+        visit_deprecate_asset_definition(&Deprecate<AssetDefinition>),
+        visit_delegate(&Delegate),
+        visit_tagged(&Tagged),
+        visit_onboard(&Onboard),
+        visit_payout(&Payout),
+        visit_add_tag(&AddTag),
+        visit_remove_tag(&RemoveTag),
+        visit_approve(&Approve),
+        visit_transfer_from(&TransferFrom),
+        visit_message(&Message),
+        visit_convert(&Convert),
+        visit_create_escrow(&CreateEscrow),
+        visit_claim_escrow(&ClaimEscrow),
+        visit_refund_escrow(&RefundEscrow),
+        visit_transfer_many(&TransferMany),
         visit_custom_instruction(&CustomInstruction),
 
         // Visit SingularQueryBox
         visit_find_executor_data_model(&FindExecutorDataModel),
         visit_find_parameters(&FindParameters),
+        visit_find_tombstone(&FindTombstone),
+        visit_find_asset_quantity_at(&FindAssetQuantityAt),
+        visit_find_subscription(&FindSubscription),
+        visit_find_dead_letters(&FindDeadLetters),
+        visit_find_delegations(&FindDelegations),
+        visit_find_allowances(&FindAllowances),
+        visit_find_inbox(&FindInbox),
+        visit_find_escrows(&FindEscrows),
+        visit_find_fuel_schedule(&FindFuelSchedule),
 
         // Visit IterableQueryBox
         visit_find_domains(&QueryWithFilter<FindDomains>),
@@ -66,12 +99,16 @@ pub trait Visit {
         visit_find_permissions_by_account_id(&QueryWithFilter<FindPermissionsByAccountId>),
         visit_find_roles_by_account_id(&QueryWithFilter<FindRolesByAccountId>),
         visit_find_accounts_with_asset(&QueryWithFilter<FindAccountsWithAsset>),
+        visit_find_accounts_with_role(&QueryWithFilter<FindAccountsWithRole>),
+        visit_find_accounts_with_tag(&QueryWithFilter<FindAccountsWithTag>),
+        visit_find_assets_with_definition(&QueryWithFilter<FindAssetsWithDefinition>),
         visit_find_peers(&QueryWithFilter<FindPeers>),
         visit_find_active_trigger_ids(&QueryWithFilter<FindActiveTriggerIds>),
         visit_find_triggers(&QueryWithFilter<FindTriggers>),
         visit_find_transactions(&QueryWithFilter<FindTransactions>),
         visit_find_blocks(&QueryWithFilter<FindBlocks>),
         visit_find_block_headers(&QueryWithFilter<FindBlockHeaders>),
+        visit_find_all_parameters(&QueryWithFilter<FindAllParameters>),
 
         // Visit RegisterBox
         visit_register_peer(&Register<Peer>),
@@ -92,6 +129,18 @@ pub trait Visit {
         visit_unregister_role(&Unregister<Role>),
         visit_unregister_trigger(&Unregister<Trigger>),
 
+        // Visit PurgeBox
+        visit_purge_domain(&Purge<Domain>),
+        visit_purge_account(&Purge<Account>),
+
+        // Visit FreezeBox
+        visit_freeze_asset(&Freeze<Asset>),
+        visit_freeze_asset_definition(&Freeze<AssetDefinition>),
+
+        // Visit UnfreezeBox
+        visit_unfreeze_asset(&Unfreeze<Asset>),
+        visit_unfreeze_asset_definition(&Unfreeze<AssetDefinition>),
+
         // Visit MintBox
         visit_mint_asset_numeric(&Mint<Numeric, Asset>),
         visit_mint_trigger_repetitions(&Mint<u32, Trigger>),
@@ -110,6 +159,7 @@ pub trait Visit {
         visit_set_domain_key_value(&SetKeyValue<Domain>),
         visit_set_account_key_value(&SetKeyValue<Account>),
         visit_set_asset_definition_key_value(&SetKeyValue<AssetDefinition>),
+        visit_set_asset_key_value(&SetKeyValue<Asset>),
         visit_set_nft_key_value(&SetKeyValue<Nft>),
         visit_set_trigger_key_value(&SetKeyValue<Trigger>),
 
@@ -117,6 +167,7 @@ pub trait Visit {
         visit_remove_domain_key_value(&RemoveKeyValue<Domain>),
         visit_remove_account_key_value(&RemoveKeyValue<Account>),
         visit_remove_asset_definition_key_value(&RemoveKeyValue<AssetDefinition>),
+        visit_remove_asset_key_value(&RemoveKeyValue<Asset>),
         visit_remove_nft_key_value(&RemoveKeyValue<Nft>),
         visit_remove_trigger_key_value(&RemoveKeyValue<Trigger>),
 
@@ -155,6 +206,15 @@ pub fn visit_singular_query<V: Visit + ?Sized>(visitor: &mut V, query: &Singular
     singular_query_visitors! {
         visit_find_executor_data_model(FindExecutorDataModel),
         visit_find_parameters(FindParameters),
+        visit_find_tombstone(FindTombstone),
+        visit_find_asset_quantity_at(FindAssetQuantityAt),
+        visit_find_subscription(FindSubscription),
+        visit_find_dead_letters(FindDeadLetters),
+        visit_find_delegations(FindDelegations),
+        visit_find_allowances(FindAllowances),
+        visit_find_inbox(FindInbox),
+        visit_find_escrows(FindEscrows),
+        visit_find_fuel_schedule(FindFuelSchedule),
     }
 }
 
@@ -178,12 +238,16 @@ pub fn visit_iter_query<V: Visit + ?Sized>(visitor: &mut V, query: &QueryWithPar
         visit_find_permissions_by_account_id(FindPermissionsByAccountId),
         visit_find_roles_by_account_id(FindRolesByAccountId),
         visit_find_accounts_with_asset(FindAccountsWithAsset),
+        visit_find_accounts_with_role(FindAccountsWithRole),
+        visit_find_accounts_with_tag(FindAccountsWithTag),
+        visit_find_assets_with_definition(FindAssetsWithDefinition),
         visit_find_peers(FindPeers),
         visit_find_active_trigger_ids(FindActiveTriggerIds),
         visit_find_triggers(FindTriggers),
         visit_find_transactions(FindTransactions),
         visit_find_block_headers(FindBlockHeaders),
         visit_find_blocks(FindBlocks),
+        visit_find_all_parameters(FindAllParameters),
     }
 }
 
@@ -218,12 +282,47 @@ pub fn visit_instruction<V: Visit + ?Sized>(visitor: &mut V, isi: &InstructionBo
         InstructionBox::Revoke(variant_value) => visitor.visit_revoke(variant_value),
         InstructionBox::SetKeyValue(variant_value) => visitor.visit_set_key_value(variant_value),
         InstructionBox::Transfer(variant_value) => visitor.visit_transfer(variant_value),
+        InstructionBox::Split(variant_value) => visitor.visit_split(variant_value),
+        InstructionBox::Merge(variant_value) => visitor.visit_merge(variant_value),
+        InstructionBox::Subscribe(variant_value) => visitor.visit_subscribe(variant_value),
+        InstructionBox::Unsubscribe(variant_value) => visitor.visit_unsubscribe(variant_value),
+        InstructionBox::RetryDeadLetter(variant_value) => {
+            visitor.visit_retry_dead_letter(variant_value)
+        }
+        InstructionBox::DiscardDeadLetter(variant_value) => {
+            visitor.visit_discard_dead_letter(variant_value)
+        }
+        InstructionBox::Deprecate(variant_value) => {
+            visitor.visit_deprecate_asset_definition(variant_value)
+        }
+        InstructionBox::Delegate(variant_value) => visitor.visit_delegate(variant_value),
+        InstructionBox::Tagged(variant_value) => visitor.visit_tagged(variant_value),
+        InstructionBox::Onboard(variant_value) => visitor.visit_onboard(variant_value),
+        InstructionBox::Payout(variant_value) => visitor.visit_payout(variant_value),
+        InstructionBox::AddTag(variant_value) => visitor.visit_add_tag(variant_value),
+        InstructionBox::RemoveTag(variant_value) => visitor.visit_remove_tag(variant_value),
+        InstructionBox::Approve(variant_value) => visitor.visit_approve(variant_value),
+        InstructionBox::TransferFrom(variant_value) => visitor.visit_transfer_from(variant_value),
+        InstructionBox::Message(variant_value) => visitor.visit_message(variant_value),
+        InstructionBox::Convert(variant_value) => visitor.visit_convert(variant_value),
+        InstructionBox::CreateEscrow(variant_value) => visitor.visit_create_escrow(variant_value),
+        InstructionBox::ClaimEscrow(variant_value) => visitor.visit_claim_escrow(variant_value),
+        InstructionBox::RefundEscrow(variant_value) => visitor.visit_refund_escrow(variant_value),
+        InstructionBox::TransferMany(variant_value) => visitor.visit_transfer_many(variant_value),
         InstructionBox::Unregister(variant_value) => visitor.visit_unregister(variant_value),
+        InstructionBox::Purge(variant_value) => visitor.visit_purge(variant_value),
+        InstructionBox::Freeze(variant_value) => visitor.visit_freeze(variant_value),
+        InstructionBox::Unfreeze(variant_value) => visitor.visit_unfreeze(variant_value),
         InstructionBox::Upgrade(variant_value) => visitor.visit_upgrade(variant_value),
         InstructionBox::Custom(custom) => visitor.visit_custom_instruction(custom),
     }
 }
 
+/// Tagged instruction is validated as the instruction it wraps
+pub fn visit_tagged<V: Visit + ?Sized>(visitor: &mut V, isi: &Tagged) {
+    visitor.visit_instruction(isi.instruction());
+}
+
 pub fn visit_register<V: Visit + ?Sized>(visitor: &mut V, isi: &RegisterBox) {
     match isi {
         RegisterBox::Peer(obj) => visitor.visit_register_peer(obj),
@@ -248,6 +347,27 @@ pub fn visit_unregister<V: Visit + ?Sized>(visitor: &mut V, isi: &UnregisterBox)
     }
 }
 
+pub fn visit_purge<V: Visit + ?Sized>(visitor: &mut V, isi: &PurgeBox) {
+    match isi {
+        PurgeBox::Domain(obj) => visitor.visit_purge_domain(obj),
+        PurgeBox::Account(obj) => visitor.visit_purge_account(obj),
+    }
+}
+
+pub fn visit_freeze<V: Visit + ?Sized>(visitor: &mut V, isi: &FreezeBox) {
+    match isi {
+        FreezeBox::Asset(obj) => visitor.visit_freeze_asset(obj),
+        FreezeBox::AssetDefinition(obj) => visitor.visit_freeze_asset_definition(obj),
+    }
+}
+
+pub fn visit_unfreeze<V: Visit + ?Sized>(visitor: &mut V, isi: &UnfreezeBox) {
+    match isi {
+        UnfreezeBox::Asset(obj) => visitor.visit_unfreeze_asset(obj),
+        UnfreezeBox::AssetDefinition(obj) => visitor.visit_unfreeze_asset_definition(obj),
+    }
+}
+
 pub fn visit_mint<V: Visit + ?Sized>(visitor: &mut V, isi: &MintBox) {
     match isi {
         MintBox::Asset(obj) => visitor.visit_mint_asset_numeric(obj),
@@ -278,6 +398,7 @@ pub fn visit_set_key_value<V: Visit + ?Sized>(visitor: &mut V, isi: &SetKeyValue
         SetKeyValueBox::AssetDefinition(obj) => visitor.visit_set_asset_definition_key_value(obj),
         SetKeyValueBox::Nft(obj) => visitor.visit_set_nft_key_value(obj),
         SetKeyValueBox::Trigger(obj) => visitor.visit_set_trigger_key_value(obj),
+        SetKeyValueBox::Asset(obj) => visitor.visit_set_asset_key_value(obj),
     }
 }
 
@@ -290,6 +411,7 @@ pub fn visit_remove_key_value<V: Visit + ?Sized>(visitor: &mut V, isi: &RemoveKe
         }
         RemoveKeyValueBox::Nft(obj) => visitor.visit_remove_nft_key_value(obj),
         RemoveKeyValueBox::Trigger(obj) => visitor.visit_remove_trigger_key_value(obj),
+        RemoveKeyValueBox::Asset(obj) => visitor.visit_remove_asset_key_value(obj),
     }
 }
 
@@ -321,6 +443,7 @@ leaf_visitors! {
     // Instruction visitors
     visit_register_account(&Register<Account>),
     visit_unregister_account(&Unregister<Account>),
+    visit_purge_account(&Purge<Account>),
     visit_set_account_key_value(&SetKeyValue<Account>),
     visit_remove_account_key_value(&RemoveKeyValue<Account>),
     visit_register_nft(&Register<Nft>),
@@ -328,6 +451,30 @@ leaf_visitors! {
     visit_mint_asset_numeric(&Mint<Numeric, Asset>),
     visit_burn_asset_numeric(&Burn<Numeric, Asset>),
     visit_transfer_asset_numeric(&Transfer<Asset, Numeric, Account>),
+    visit_freeze_asset(&Freeze<Asset>),
+    visit_unfreeze_asset(&Unfreeze<Asset>),
+    visit_split(&Split),
+    visit_merge(&Merge),
+    visit_subscribe(&Subscribe),
+    visit_unsubscribe(&Unsubscribe),
+    visit_retry_dead_letter(&RetryDeadLetter),
+    visit_discard_dead_letter(&DiscardDeadLetter),
+    visit_deprecate_asset_definition(&Deprecate<AssetDefinition>),
+    visit_delegate(&Delegate),
+    visit_onboard(&Onboard),
+    visit_payout(&Payout),
+    visit_add_tag(&AddTag),
+    visit_remove_tag(&RemoveTag),
+    visit_approve(&Approve),
+    visit_transfer_from(&TransferFrom),
+    visit_message(&Message),
+    visit_convert(&Convert),
+    visit_create_escrow(&CreateEscrow),
+    visit_claim_escrow(&ClaimEscrow),
+    visit_refund_escrow(&RefundEscrow),
+    visit_transfer_many(&TransferMany),
+    visit_set_asset_key_value(&SetKeyValue<Asset>),
+    visit_remove_asset_key_value(&RemoveKeyValue<Asset>),
     visit_transfer_nft(&Transfer<Account, NftId, Account>),
     visit_set_nft_key_value(&SetKeyValue<Nft>),
     visit_remove_nft_key_value(&RemoveKeyValue<Nft>),
@@ -338,8 +485,11 @@ leaf_visitors! {
     visit_transfer_asset_definition(&Transfer<Account, AssetDefinitionId, Account>),
     visit_set_asset_definition_key_value(&SetKeyValue<AssetDefinition>),
     visit_remove_asset_definition_key_value(&RemoveKeyValue<AssetDefinition>),
+    visit_freeze_asset_definition(&Freeze<AssetDefinition>),
+    visit_unfreeze_asset_definition(&Unfreeze<AssetDefinition>),
     visit_register_domain(&Register<Domain>),
     visit_unregister_domain(&Unregister<Domain>),
+    visit_purge_domain(&Purge<Domain>),
     visit_transfer_domain(&Transfer<Account, DomainId, Account>),
     visit_set_domain_key_value(&SetKeyValue<Domain>),
     visit_remove_domain_key_value(&RemoveKeyValue<Domain>),
@@ -366,6 +516,15 @@ leaf_visitors! {
     // Singular Query visitors
     visit_find_executor_data_model(&FindExecutorDataModel),
     visit_find_parameters(&FindParameters),
+    visit_find_tombstone(&FindTombstone),
+    visit_find_asset_quantity_at(&FindAssetQuantityAt),
+    visit_find_subscription(&FindSubscription),
+    visit_find_dead_letters(&FindDeadLetters),
+    visit_find_delegations(&FindDelegations),
+    visit_find_allowances(&FindAllowances),
+    visit_find_inbox(&FindInbox),
+    visit_find_escrows(&FindEscrows),
+    visit_find_fuel_schedule(&FindFuelSchedule),
 
     // Iterable Query visitors
     visit_find_domains(&QueryWithFilter<FindDomains>),
@@ -378,10 +537,14 @@ leaf_visitors! {
     visit_find_permissions_by_account_id(&QueryWithFilter<FindPermissionsByAccountId>),
     visit_find_roles_by_account_id(&QueryWithFilter<FindRolesByAccountId>),
     visit_find_accounts_with_asset(&QueryWithFilter<FindAccountsWithAsset>),
+    visit_find_accounts_with_role(&QueryWithFilter<FindAccountsWithRole>),
+    visit_find_accounts_with_tag(&QueryWithFilter<FindAccountsWithTag>),
+    visit_find_assets_with_definition(&QueryWithFilter<FindAssetsWithDefinition>),
     visit_find_peers(&QueryWithFilter<FindPeers>),
     visit_find_active_trigger_ids(&QueryWithFilter<FindActiveTriggerIds>),
     visit_find_triggers(&QueryWithFilter<FindTriggers>),
     visit_find_transactions(&QueryWithFilter<FindTransactions>),
     visit_find_blocks(&QueryWithFilter<FindBlocks>),
     visit_find_block_headers(&QueryWithFilter<FindBlockHeaders>),
+    visit_find_all_parameters(&QueryWithFilter<FindAllParameters>),
 }
@@ -15,3 +15,21 @@ fn transfer_isi_should_be_valid() {
             .unwrap(),
     );
 }
+
+#[test]
+fn nested_tagged_isi_should_be_rejected() {
+    use parity_scale_codec::{DecodeAll, Encode};
+
+    let log = Log::new(Level::INFO, "tagged".to_owned());
+    let tagged = InstructionBox::from(Tagged::new("outer".parse().unwrap(), log));
+    let decoded = InstructionBox::decode_all(&mut tagged.encode().as_slice()).unwrap();
+    assert_eq!(decoded, tagged);
+
+    let nested = InstructionBox::from(Tagged {
+        tag: "inner".parse().unwrap(),
+        instruction: Box::new(tagged),
+    });
+    assert!(InstructionBox::decode_all(&mut nested.encode().as_slice()).is_err());
+    let json = serde_json::to_string(&nested).unwrap();
+    assert!(serde_json::from_str::<InstructionBox>(&json).is_err());
+}
@@ -3,6 +3,7 @@ mod enum_ref;
 mod event_set;
 mod has_origin;
 mod id;
+mod metadata_value;
 mod model;
 mod partially_tagged;
 
@@ -488,6 +489,43 @@ pub fn has_origin_derive(input: TokenStream) -> TokenStream {
     emitter.finish_token_stream_with(result)
 }
 
+/// Derive `iroha_data_model::metadata::MetadataValue`, storing the value in `Metadata`
+/// under the name of the type, or under the key set with `#[metadata_value(key = "...")]`.
+///
+/// The key is checked to be a valid `Name` at compile time.
+///
+/// # Example
+///
+/// ```
+/// use iroha_data_model::prelude::*;
+/// use iroha_data_model_derive::MetadataValue;
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Debug, PartialEq, Serialize, Deserialize, MetadataValue)]
+/// #[metadata_value(key = "kyc")]
+/// struct Kyc {
+///     level: u8,
+/// }
+///
+/// let mut metadata = Metadata::default();
+/// metadata.set_typed(&Kyc { level: 2 });
+///
+/// assert_eq!(metadata.get_typed::<Kyc>().unwrap(), Some(Kyc { level: 2 }));
+/// ```
+#[manyhow]
+#[proc_macro_derive(MetadataValue, attributes(metadata_value))]
+pub fn metadata_value_derive(input: TokenStream) -> TokenStream {
+    let mut emitter = Emitter::new();
+
+    let Some(input) = emitter.handle(syn::parse2(input)) else {
+        return emitter.finish_token_stream();
+    };
+
+    let result = metadata_value::impl_metadata_value(&mut emitter, &input);
+
+    emitter.finish_token_stream_with(result)
+}
+
 /// Create an event set structure from an event enum.
 ///
 /// Event set is a set of multiple event types, allowing to efficiently test whether an event is in a set.
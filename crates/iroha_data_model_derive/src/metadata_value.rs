@@ -0,0 +1,61 @@
+use iroha_macro_utils::{attr_struct, parse_single_list_attr_opt, Emitter};
+use manyhow::emit;
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{LitStr, Token};
+
+mod kw {
+    syn::custom_keyword!(key);
+}
+
+const METADATA_VALUE_ATTR: &str = "metadata_value";
+
+attr_struct! {
+    pub struct KeyAttr {
+        _kw: kw::key,
+        _eq: Token![=],
+        key: LitStr,
+    }
+}
+
+/// Same rules as the ones of `Name`, so that an invalid key is reported at compile time
+fn validate_key(key: &str) -> Result<(), &'static str> {
+    if key.is_empty() {
+        return Err("Metadata key should not be empty");
+    }
+    if key.chars().any(char::is_whitespace) {
+        return Err("White space is not allowed in metadata keys");
+    }
+    if key.chars().any(|ch| ['@', '#', '$'].contains(&ch)) {
+        return Err("`@`, `#` and `$` characters are not allowed in metadata keys");
+    }
+    Ok(())
+}
+
+pub fn impl_metadata_value(emitter: &mut Emitter, input: &syn::DeriveInput) -> TokenStream {
+    let Some(attr) = emitter.handle(parse_single_list_attr_opt::<KeyAttr>(
+        METADATA_VALUE_ATTR,
+        &input.attrs,
+    )) else {
+        return quote!();
+    };
+    let key = attr.map_or_else(
+        || LitStr::new(&input.ident.to_string(), input.ident.span()),
+        |attr| attr.key,
+    );
+    if let Err(reason) = validate_key(&key.value()) {
+        emit!(emitter, key, "{}", reason);
+        return quote!();
+    }
+
+    let ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    quote! {
+        impl #impl_generics ::iroha_data_model::metadata::MetadataValue for #ident #ty_generics #where_clause {
+            fn key() -> ::iroha_data_model::name::Name {
+                #key.parse().expect("INTERNAL BUG: metadata key is validated by the derive macro")
+            }
+        }
+    }
+}
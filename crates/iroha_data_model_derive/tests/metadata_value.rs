@@ -0,0 +1,40 @@
+#![allow(missing_docs)]
+
+use iroha_data_model::prelude::*;
+use iroha_data_model_derive::MetadataValue;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, PartialEq, Serialize, Deserialize, MetadataValue)]
+#[metadata_value(key = "kyc")]
+struct Kyc {
+    level: u8,
+    country: String,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize, MetadataValue)]
+struct Nickname(String);
+
+#[test]
+fn key_defaults_to_type_name() {
+    assert_eq!(Kyc::key(), "kyc".parse::<Name>().unwrap());
+    assert_eq!(Nickname::key(), "Nickname".parse::<Name>().unwrap());
+}
+
+#[test]
+fn typed_value_round_trips() {
+    let kyc = Kyc {
+        level: 2,
+        country: "JP".to_owned(),
+    };
+    let mut metadata = Metadata::default();
+    assert_eq!(metadata.get_typed::<Kyc>().unwrap(), None);
+
+    metadata.set_typed(&kyc);
+    assert_eq!(metadata.get(&Kyc::key()), Some(&Json::new(&kyc)));
+    assert_eq!(metadata.get_typed::<Kyc>().unwrap(), Some(kyc));
+
+    metadata.set_serde(Kyc::key(), "unverified");
+    let _err = metadata
+        .get_typed::<Kyc>()
+        .expect_err("value is not a valid `Kyc`");
+}
@@ -0,0 +1,226 @@
+//! Validation and execution logic of governance instructions
+
+use alloc::{collections::btree_set::BTreeSet, format, string::String};
+
+use iroha_executor_data_model::{
+    isi::governance::*,
+    parameter::{
+        governance::{GovernanceParameters, MIN_QUORUM},
+        Parameter as _,
+    },
+};
+
+use super::*;
+use crate::{data_model::Level, smart_contract::DebugExpectExt as _};
+
+impl VisitExecute for GovernanceInstructionBox {
+    fn visit_execute<V: Execute + Visit + ?Sized>(self, executor: &mut V) {
+        match self {
+            GovernanceInstructionBox::Propose(instruction) => instruction.visit_execute(executor),
+            GovernanceInstructionBox::Vote(instruction) => instruction.visit_execute(executor),
+        }
+    }
+}
+
+impl VisitExecute for GovernancePropose {
+    fn visit<V: Execute + Visit + ?Sized>(&self, executor: &mut V) {
+        let proposer = &executor.context().authority;
+        let parameters = governance_parameters(executor);
+
+        if let Err(reason) = quorum(&parameters) {
+            deny!(executor, ValidationFail::NotPermitted(reason.into()));
+        }
+        if !is_council_member(proposer, &parameters.council, executor) {
+            deny!(executor, "not qualified to propose governance action");
+        }
+
+        if proposal_value(&HashOf::new(&self.instructions), executor).is_some() {
+            deny!(executor, "governance proposal duplicates");
+        }
+    }
+
+    fn execute<V: Execute + Visit + ?Sized>(self, executor: &mut V) -> Result<(), ValidationFail> {
+        let proposer = executor.context().authority.clone();
+        let instructions_hash = HashOf::new(&self.instructions);
+        let now_ms = now_ms(executor);
+        let proposal_value = GovernanceProposalValue::new(
+            self.instructions,
+            proposer.clone(),
+            now_ms,
+            now_ms.saturating_add(self.voting_period_ms.get()),
+            BTreeSet::new(),
+            BTreeSet::new(),
+        );
+
+        executor.host().submit(&SetKeyValue::domain(
+            proposals_domain(),
+            proposal_key(&instructions_hash),
+            proposal_value,
+        ))
+    }
+}
+
+impl VisitExecute for GovernanceVote {
+    fn visit<V: Execute + Visit + ?Sized>(&self, executor: &mut V) {
+        let voter = &executor.context().authority;
+        let parameters = governance_parameters(executor);
+
+        if let Err(reason) = quorum(&parameters) {
+            deny!(executor, ValidationFail::NotPermitted(reason.into()));
+        }
+        if !is_council_member(voter, &parameters.council, executor) {
+            deny!(executor, "not qualified to vote for governance proposal");
+        }
+
+        if proposal_value(&self.instructions_hash, executor).is_none() {
+            deny!(executor, "governance proposal not found");
+        }
+    }
+
+    fn execute<V: Execute + Visit + ?Sized>(self, executor: &mut V) -> Result<(), ValidationFail> {
+        let voter = executor.context().authority.clone();
+        let instructions_hash = self.instructions_hash;
+        let mut proposal_value = proposal_value(&instructions_hash, executor)
+            .dbg_expect("proposal should be found as it passed validation");
+
+        if proposal_value.expires_at_ms <= now_ms(executor) {
+            // Notify that the proposal has expired, while returning Ok for the entry deletion to take effect
+            remove_proposal(&instructions_hash, executor)?;
+            log(
+                format!("governance proposal expired:\ninstructions hash: {instructions_hash}"),
+                executor,
+            )?;
+            return Ok(());
+        }
+
+        if self.approve {
+            proposal_value.rejections.remove(&voter);
+            proposal_value.approvals.insert(voter);
+        } else {
+            proposal_value.approvals.remove(&voter);
+            proposal_value.rejections.insert(voter);
+        }
+
+        tally(instructions_hash, proposal_value, executor)
+    }
+}
+
+/// Execute or dismiss the proposal once votes of the current council members reach the quorum, otherwise record it
+fn tally<V: Execute + Visit + ?Sized>(
+    instructions_hash: HashOf<Vec<InstructionBox>>,
+    proposal_value: GovernanceProposalValue,
+    executor: &mut V,
+) -> Result<(), ValidationFail> {
+    let parameters = governance_parameters(executor);
+    let quorum =
+        quorum(&parameters).map_err(|reason| ValidationFail::NotPermitted(reason.into()))?;
+    let count = |votes: &BTreeSet<AccountId>| {
+        votes
+            .iter()
+            .filter(|member| is_council_member(member, &parameters.council, executor))
+            .count()
+    };
+    let approvals = count(&proposal_value.approvals);
+    let rejections = count(&proposal_value.rejections);
+
+    if quorum <= approvals {
+        remove_proposal(&instructions_hash, executor)?;
+        // Approved instructions are validated like any other, on behalf of the governance authority
+        executor.context_mut().authority = parameters.authority.unwrap_or(proposal_value.proposer);
+        for instruction in &proposal_value.instructions {
+            visit_seq!(executor.visit_instruction(instruction));
+        }
+        return Ok(());
+    }
+
+    if quorum <= rejections {
+        remove_proposal(&instructions_hash, executor)?;
+        return log(
+            format!("governance proposal rejected:\ninstructions hash: {instructions_hash}"),
+            executor,
+        );
+    }
+
+    executor.host().submit(&SetKeyValue::domain(
+        proposals_domain(),
+        proposal_key(&instructions_hash),
+        proposal_value,
+    ))
+}
+
+fn governance_parameters<V: Execute + Visit + ?Sized>(executor: &V) -> GovernanceParameters {
+    executor
+        .host()
+        .query_single(FindParameters)
+        .ok()
+        .and_then(|parameters| {
+            parameters
+                .custom()
+                .get(&GovernanceParameters::id())
+                .and_then(|parameter| GovernanceParameters::try_from(parameter).ok())
+        })
+        .unwrap_or_default()
+}
+
+/// Number of the votes deciding a proposal, as long as the governance is enabled
+fn quorum(parameters: &GovernanceParameters) -> Result<usize, &'static str> {
+    match parameters.quorum {
+        None => Err("governance quorum is not set"),
+        Some(quorum) if quorum.get() < MIN_QUORUM => {
+            Err("governance quorum should require more than one vote")
+        }
+        Some(quorum) => Ok(usize::from(quorum.get())),
+    }
+}
+
+fn is_council_member<V: Execute + Visit + ?Sized>(
+    account: &AccountId,
+    council: &RoleId,
+    executor: &V,
+) -> bool {
+    executor
+        .host()
+        .query(FindRolesByAccountId::new(account.clone()))
+        .filter_with(|role_id| role_id.eq(council.clone()))
+        .execute_single()
+        .is_ok()
+}
+
+fn proposal_value<V: Execute + Visit + ?Sized>(
+    instructions_hash: &HashOf<Vec<InstructionBox>>,
+    executor: &V,
+) -> Option<GovernanceProposalValue> {
+    executor
+        .host()
+        .query(FindDomains)
+        .filter_with(|domain| domain.id.eq(proposals_domain()))
+        .select_with(|domain| domain.metadata.key(proposal_key(instructions_hash)))
+        .execute_single()
+        .ok()?
+        .try_into_any()
+        .ok()
+}
+
+fn remove_proposal<V: Execute + Visit + ?Sized>(
+    instructions_hash: &HashOf<Vec<InstructionBox>>,
+    executor: &V,
+) -> Result<(), ValidationFail> {
+    executor.host().submit(&RemoveKeyValue::domain(
+        proposals_domain(),
+        proposal_key(instructions_hash),
+    ))
+}
+
+fn log<V: Execute + Visit + ?Sized>(msg: String, executor: &V) -> Result<(), ValidationFail> {
+    executor.host().submit(&Log::new(Level::INFO, msg))
+}
+
+fn now_ms<V: Execute + Visit + ?Sized>(executor: &V) -> u64 {
+    executor
+        .context()
+        .curr_block
+        .creation_time()
+        .as_millis()
+        .try_into()
+        .dbg_expect("shouldn't overflow within 584942417 years")
+}
@@ -1,4 +1,7 @@
-use iroha_executor_data_model::isi::multisig::MultisigInstructionBox;
+use iroha_executor_data_model::isi::{
+    governance::GovernanceInstructionBox, multisig::MultisigInstructionBox,
+    preset::RolePresetInstructionBox,
+};
 
 use super::*;
 use crate::prelude::{Execute, Vec, Visit};
@@ -10,6 +13,12 @@ pub fn visit_custom_instruction<V: Execute + Visit + ?Sized>(
     if let Ok(instruction) = MultisigInstructionBox::try_from(instruction.payload()) {
         return instruction.visit_execute(executor);
     }
+    if let Ok(instruction) = RolePresetInstructionBox::try_from(instruction.payload()) {
+        return instruction.visit_execute(executor);
+    }
+    if let Ok(instruction) = GovernanceInstructionBox::try_from(instruction.payload()) {
+        return instruction.visit_execute(executor);
+    }
 
     deny!(executor, "unexpected custom instruction");
 }
@@ -46,4 +55,6 @@ macro_rules! visit_seq {
     };
 }
 
+mod governance;
 mod multisig;
+mod preset;
@@ -0,0 +1,20 @@
+//! Validation and execution logic of role preset instructions
+
+use iroha_executor_data_model::isi::preset::*;
+
+use super::*;
+
+impl VisitExecute for RolePresetInstructionBox {
+    fn visit_execute<V: Execute + Visit + ?Sized>(self, executor: &mut V) {
+        match self {
+            RolePresetInstructionBox::Register(instruction) => instruction.visit_execute(executor),
+        }
+    }
+}
+
+impl VisitExecute for RegisterRolePreset {
+    fn visit_execute<V: Execute + Visit + ?Sized>(self, executor: &mut V) {
+        // Permissions of the preset are subject to the same checks as of any other role
+        executor.visit_register_role(&Register::role(self.into()));
+    }
+}
@@ -4,34 +4,49 @@
 use alloc::format;
 
 pub use account::{
-    visit_register_account, visit_remove_account_key_value, visit_set_account_key_value,
-    visit_unregister_account,
+    visit_add_tag, visit_purge_account, visit_register_account, visit_remove_account_key_value,
+    visit_remove_tag, visit_set_account_key_value, visit_unregister_account,
+};
+pub use asset::{
+    visit_approve, visit_burn_asset_numeric, visit_claim_escrow, visit_convert,
+    visit_create_escrow, visit_freeze_asset, visit_freeze_asset_definition, visit_merge,
+    visit_mint_asset_numeric, visit_payout, visit_refund_escrow, visit_remove_asset_key_value,
+    visit_set_asset_key_value, visit_split, visit_transfer_asset_numeric, visit_transfer_from,
+    visit_transfer_many, visit_unfreeze_asset, visit_unfreeze_asset_definition,
 };
-pub use asset::{visit_burn_asset_numeric, visit_mint_asset_numeric, visit_transfer_asset_numeric};
 pub use asset_definition::{
-    visit_register_asset_definition, visit_remove_asset_definition_key_value,
-    visit_set_asset_definition_key_value, visit_transfer_asset_definition,
-    visit_unregister_asset_definition,
+    visit_deprecate_asset_definition, visit_register_asset_definition,
+    visit_remove_asset_definition_key_value, visit_set_asset_definition_key_value,
+    visit_transfer_asset_definition, visit_unregister_asset_definition,
+};
+pub use dead_letter::{
+    visit_discard_dead_letter, visit_find_dead_letters, visit_retry_dead_letter,
 };
 pub use domain::{
-    visit_register_domain, visit_remove_domain_key_value, visit_set_domain_key_value,
-    visit_transfer_domain, visit_unregister_domain,
+    visit_purge_domain, visit_register_domain, visit_remove_domain_key_value,
+    visit_set_domain_key_value, visit_transfer_domain, visit_unregister_domain,
 };
 pub use executor::visit_upgrade;
 use iroha_smart_contract::data_model::{prelude::*, visit::Visit};
 pub use isi::visit_custom_instruction;
 pub use log::visit_log;
+pub use message::visit_message;
 pub use nft::{
     visit_register_nft, visit_remove_nft_key_value, visit_set_nft_key_value, visit_transfer_nft,
     visit_unregister_nft,
 };
+pub use onboard::visit_onboard;
 pub use parameter::visit_set_parameter;
 pub use peer::{visit_register_peer, visit_unregister_peer};
-pub use permission::{visit_grant_account_permission, visit_revoke_account_permission};
+pub use permission::{
+    visit_delegate, visit_grant_account_permission, visit_revoke_account_permission,
+};
 pub use role::{
     visit_grant_account_role, visit_grant_role_permission, visit_register_role,
     visit_revoke_account_role, visit_revoke_role_permission, visit_unregister_role,
 };
+pub use subscription::{visit_subscribe, visit_unsubscribe};
+pub use tagged::visit_tagged;
 pub use trigger::{
     visit_burn_trigger_repetitions, visit_execute_trigger, visit_mint_trigger_repetitions,
     visit_register_trigger, visit_remove_trigger_key_value, visit_set_trigger_key_value,
@@ -112,9 +127,81 @@ pub fn visit_instruction<V: Execute + Visit + ?Sized>(executor: &mut V, isi: &In
         InstructionBox::Transfer(isi) => {
             executor.visit_transfer(isi);
         }
+        InstructionBox::Split(isi) => {
+            executor.visit_split(isi);
+        }
+        InstructionBox::Merge(isi) => {
+            executor.visit_merge(isi);
+        }
+        InstructionBox::Subscribe(isi) => {
+            executor.visit_subscribe(isi);
+        }
+        InstructionBox::Unsubscribe(isi) => {
+            executor.visit_unsubscribe(isi);
+        }
+        InstructionBox::RetryDeadLetter(isi) => {
+            executor.visit_retry_dead_letter(isi);
+        }
+        InstructionBox::DiscardDeadLetter(isi) => {
+            executor.visit_discard_dead_letter(isi);
+        }
+        InstructionBox::Deprecate(isi) => {
+            executor.visit_deprecate_asset_definition(isi);
+        }
+        InstructionBox::Delegate(isi) => {
+            executor.visit_delegate(isi);
+        }
+        InstructionBox::Tagged(isi) => {
+            executor.visit_tagged(isi);
+        }
+        InstructionBox::Onboard(isi) => {
+            executor.visit_onboard(isi);
+        }
+        InstructionBox::Payout(isi) => {
+            executor.visit_payout(isi);
+        }
+        InstructionBox::AddTag(isi) => {
+            executor.visit_add_tag(isi);
+        }
+        InstructionBox::RemoveTag(isi) => {
+            executor.visit_remove_tag(isi);
+        }
         InstructionBox::Unregister(isi) => {
             executor.visit_unregister(isi);
         }
+        InstructionBox::Purge(isi) => {
+            executor.visit_purge(isi);
+        }
+        InstructionBox::Freeze(isi) => {
+            executor.visit_freeze(isi);
+        }
+        InstructionBox::Unfreeze(isi) => {
+            executor.visit_unfreeze(isi);
+        }
+        InstructionBox::Approve(isi) => {
+            executor.visit_approve(isi);
+        }
+        InstructionBox::TransferFrom(isi) => {
+            executor.visit_transfer_from(isi);
+        }
+        InstructionBox::Message(isi) => {
+            executor.visit_message(isi);
+        }
+        InstructionBox::Convert(isi) => {
+            executor.visit_convert(isi);
+        }
+        InstructionBox::CreateEscrow(isi) => {
+            executor.visit_create_escrow(isi);
+        }
+        InstructionBox::ClaimEscrow(isi) => {
+            executor.visit_claim_escrow(isi);
+        }
+        InstructionBox::RefundEscrow(isi) => {
+            executor.visit_refund_escrow(isi);
+        }
+        InstructionBox::TransferMany(isi) => {
+            executor.visit_transfer_many(isi);
+        }
         InstructionBox::Upgrade(isi) => {
             executor.visit_upgrade(isi);
         }
@@ -124,6 +211,11 @@ pub fn visit_instruction<V: Execute + Visit + ?Sized>(executor: &mut V, isi: &In
     }
 }
 
+/// Changes of the trusted peer set outside of genesis need [`CanManagePeers`].
+///
+/// To route them through the governance council, grant the permission only to
+/// the [`GovernanceParameters::authority`](iroha_executor_data_model::parameter::governance::GovernanceParameters::authority)
+/// which enacts the approved proposals.
 pub mod peer {
     use iroha_executor_data_model::permission::peer::CanManagePeers;
 
@@ -160,7 +252,7 @@ pub mod peer {
 
 pub mod domain {
     use iroha_executor_data_model::permission::domain::{
-        CanModifyDomainMetadata, CanRegisterDomain, CanUnregisterDomain,
+        CanModifyDomainMetadata, CanRegisterDomain, CanTransferDomain, CanUnregisterDomain,
     };
     use iroha_smart_contract::data_model::domain::DomainId;
 
@@ -214,6 +306,29 @@ pub mod domain {
         deny!(executor, "Can't unregister domain");
     }
 
+    pub fn visit_purge_domain<V: Execute + Visit + ?Sized>(executor: &mut V, isi: &Purge<Domain>) {
+        let domain_id = isi.object();
+
+        if executor.context().curr_block.is_genesis() {
+            execute!(executor, isi);
+        }
+        match executor
+            .host()
+            .query_single(FindTombstone::new(IdBox::DomainId(domain_id.clone())))
+        {
+            Err(err) => deny!(executor, err),
+            Ok(tombstone) if *tombstone.removed_by() == executor.context().authority => {
+                execute!(executor, isi)
+            }
+            Ok(_) => {}
+        }
+
+        deny!(
+            executor,
+            "Can't purge domain unregistered by another account"
+        );
+    }
+
     pub fn visit_transfer_domain<V: Execute + Visit + ?Sized>(
         executor: &mut V,
         isi: &Transfer<Account, DomainId, Account>,
@@ -226,18 +341,41 @@ pub mod domain {
         }
         match is_account_owner(source_id, &executor.context().authority, executor.host()) {
             Err(err) => deny!(executor, err),
-            Ok(true) => execute!(executor, isi),
+            Ok(true) => return transfer_domain(executor, isi),
             Ok(false) => {}
         }
         match is_domain_owner(domain_id, &executor.context().authority, executor.host()) {
             Err(err) => deny!(executor, err),
-            Ok(true) => execute!(executor, isi),
+            Ok(true) => return transfer_domain(executor, isi),
             Ok(false) => {}
         }
+        let can_transfer_domain_token = CanTransferDomain {
+            domain: domain_id.clone(),
+        };
+        if can_transfer_domain_token.is_owned_by(&executor.context().authority, executor.host()) {
+            return transfer_domain(executor, isi);
+        }
 
         deny!(executor, "Can't transfer domain of another account");
     }
 
+    /// Transfer the domain, revoking the tokens granted by its current owner.
+    fn transfer_domain<V: Execute + Visit + ?Sized>(
+        executor: &mut V,
+        isi: &Transfer<Account, DomainId, Account>,
+    ) {
+        let domain_id = isi.object();
+
+        let err = revoke_permissions(executor, |permission| {
+            is_permission_granted_by_domain_owner(permission, domain_id)
+        });
+        if let Err(err) = err {
+            deny!(executor, err);
+        }
+
+        execute!(executor, isi);
+    }
+
     pub fn visit_set_domain_key_value<V: Execute + Visit + ?Sized>(
         executor: &mut V,
         isi: &SetKeyValue<Domain>,
@@ -290,6 +428,28 @@ pub mod domain {
         deny!(executor, "Can't remove key value in domain metadata");
     }
 
+    /// Whether the `permission` is scoped to the domain and thus granted by its owner,
+    /// see [`Owner`](crate::permission::domain::Owner).
+    fn is_permission_granted_by_domain_owner(
+        permission: &Permission,
+        domain_id: &DomainId,
+    ) -> bool {
+        let Ok(permission) = AnyPermission::try_from(permission) else {
+            return false;
+        };
+        match permission {
+            AnyPermission::CanUnregisterDomain(permission) => &permission.domain == domain_id,
+            AnyPermission::CanModifyDomainMetadata(permission) => &permission.domain == domain_id,
+            AnyPermission::CanTransferDomain(permission) => &permission.domain == domain_id,
+            AnyPermission::CanRegisterAccount(permission) => &permission.domain == domain_id,
+            AnyPermission::CanRegisterAssetDefinition(permission) => {
+                &permission.domain == domain_id
+            }
+            AnyPermission::CanRegisterNft(permission) => &permission.domain == domain_id,
+            _ => false,
+        }
+    }
+
     #[allow(clippy::too_many_lines)]
     fn is_permission_domain_associated(permission: &Permission, domain_id: &DomainId) -> bool {
         let Ok(permission) = AnyPermission::try_from(permission) else {
@@ -298,6 +458,7 @@ pub mod domain {
         match permission {
             AnyPermission::CanUnregisterDomain(permission) => &permission.domain == domain_id,
             AnyPermission::CanModifyDomainMetadata(permission) => &permission.domain == domain_id,
+            AnyPermission::CanTransferDomain(permission) => &permission.domain == domain_id,
             AnyPermission::CanRegisterAccount(permission) => &permission.domain == domain_id,
             AnyPermission::CanRegisterAssetDefinition(permission) => {
                 &permission.domain == domain_id
@@ -317,6 +478,9 @@ pub mod domain {
             AnyPermission::CanTransferAssetWithDefinition(permission) => {
                 permission.asset_definition.domain() == domain_id
             }
+            AnyPermission::CanFreezeAssetWithDefinition(permission) => {
+                permission.asset_definition.domain() == domain_id
+            }
             AnyPermission::CanMintAsset(permission) => {
                 permission.asset.definition().domain() == domain_id
                     || permission.asset.account().domain() == domain_id
@@ -329,6 +493,10 @@ pub mod domain {
                 permission.asset.definition().domain() == domain_id
                     || permission.asset.account().domain() == domain_id
             }
+            AnyPermission::CanModifyAssetMetadata(permission) => {
+                permission.asset.definition().domain() == domain_id
+                    || permission.asset.account().domain() == domain_id
+            }
             AnyPermission::CanRegisterNft(permission) => &permission.domain == domain_id,
             AnyPermission::CanUnregisterNft(permission) => permission.nft.domain() == domain_id,
             AnyPermission::CanTransferNft(permission) => permission.nft.domain() == domain_id,
@@ -339,6 +507,9 @@ pub mod domain {
             AnyPermission::CanModifyAccountMetadata(permission) => {
                 permission.account.domain() == domain_id
             }
+            AnyPermission::CanMessageAccount(permission) => {
+                permission.account.domain() == domain_id
+            }
             AnyPermission::CanRegisterTrigger(permission) => {
                 permission.authority.domain() == domain_id
             }
@@ -360,7 +531,7 @@ pub mod account {
         CanModifyAccountMetadata, CanRegisterAccount, CanUnregisterAccount,
     };
 
-    use super::*;
+    use super::{parameter::domain_policy, *};
     use crate::permission::{account::is_account_owner, revoke_permissions};
 
     pub fn visit_register_account<V: Execute + Visit + ?Sized>(
@@ -388,6 +559,12 @@ pub mod account {
             execute!(executor, isi);
         }
 
+        if executor.context().authority.domain() == domain_id
+            && domain_policy(domain_id, executor.host()).members_can_register_accounts
+        {
+            execute!(executor, isi);
+        }
+
         deny!(
             executor,
             "Can't register account in a domain owned by another account"
@@ -425,6 +602,32 @@ pub mod account {
         deny!(executor, "Can't unregister another account");
     }
 
+    pub fn visit_purge_account<V: Execute + Visit + ?Sized>(
+        executor: &mut V,
+        isi: &Purge<Account>,
+    ) {
+        let account_id = isi.object();
+
+        if executor.context().curr_block.is_genesis() {
+            execute!(executor, isi);
+        }
+        match executor
+            .host()
+            .query_single(FindTombstone::new(IdBox::AccountId(account_id.clone())))
+        {
+            Err(err) => deny!(executor, err),
+            Ok(tombstone) if *tombstone.removed_by() == executor.context().authority => {
+                execute!(executor, isi)
+            }
+            Ok(_) => {}
+        }
+
+        deny!(
+            executor,
+            "Can't purge account unregistered by another account"
+        );
+    }
+
     pub fn visit_set_account_key_value<V: Execute + Visit + ?Sized>(
         executor: &mut V,
         isi: &SetKeyValue<Account>,
@@ -483,6 +686,44 @@ pub mod account {
         );
     }
 
+    // Accounts are tagged by the owner of their domain, so that they can't label themselves
+    pub fn visit_add_tag<V: Execute + Visit + ?Sized>(executor: &mut V, isi: &AddTag) {
+        if executor.context().curr_block.is_genesis() {
+            execute!(executor, isi);
+        }
+        match crate::permission::domain::is_domain_owner(
+            isi.account.domain(),
+            &executor.context().authority,
+            executor.host(),
+        ) {
+            Err(err) => deny!(executor, err),
+            Ok(true) => execute!(executor, isi),
+            Ok(false) => {}
+        }
+
+        deny!(executor, "Can't tag an account outside of the owned domain");
+    }
+
+    pub fn visit_remove_tag<V: Execute + Visit + ?Sized>(executor: &mut V, isi: &RemoveTag) {
+        if executor.context().curr_block.is_genesis() {
+            execute!(executor, isi);
+        }
+        match crate::permission::domain::is_domain_owner(
+            isi.account.domain(),
+            &executor.context().authority,
+            executor.host(),
+        ) {
+            Err(err) => deny!(executor, err),
+            Ok(true) => execute!(executor, isi),
+            Ok(false) => {}
+        }
+
+        deny!(
+            executor,
+            "Can't remove a tag from an account outside of the owned domain"
+        );
+    }
+
     fn is_permission_account_associated(permission: &Permission, account_id: &AccountId) -> bool {
         let Ok(permission) = AnyPermission::try_from(permission) else {
             return false;
@@ -492,9 +733,13 @@ pub mod account {
             AnyPermission::CanModifyAccountMetadata(permission) => {
                 permission.account == *account_id
             }
+            AnyPermission::CanMessageAccount(permission) => permission.account == *account_id,
             AnyPermission::CanMintAsset(permission) => permission.asset.account() == account_id,
             AnyPermission::CanBurnAsset(permission) => permission.asset.account() == account_id,
             AnyPermission::CanTransferAsset(permission) => permission.asset.account() == account_id,
+            AnyPermission::CanModifyAssetMetadata(permission) => {
+                permission.asset.account() == account_id
+            }
             AnyPermission::CanRegisterTrigger(permission) => permission.authority == *account_id,
             AnyPermission::CanUnregisterTrigger(_)
             | AnyPermission::CanExecuteTrigger(_)
@@ -504,6 +749,7 @@ pub mod account {
             | AnyPermission::CanRegisterDomain(_)
             | AnyPermission::CanUnregisterDomain(_)
             | AnyPermission::CanModifyDomainMetadata(_)
+            | AnyPermission::CanTransferDomain(_)
             | AnyPermission::CanRegisterAccount(_)
             | AnyPermission::CanRegisterAssetDefinition(_)
             | AnyPermission::CanUnregisterAssetDefinition(_)
@@ -511,6 +757,7 @@ pub mod account {
             | AnyPermission::CanMintAssetWithDefinition(_)
             | AnyPermission::CanBurnAssetWithDefinition(_)
             | AnyPermission::CanTransferAssetWithDefinition(_)
+            | AnyPermission::CanFreezeAssetWithDefinition(_)
             | AnyPermission::CanRegisterNft(_)
             | AnyPermission::CanUnregisterNft(_)
             | AnyPermission::CanTransferNft(_)
@@ -528,7 +775,7 @@ pub mod asset_definition {
     };
     use iroha_smart_contract::data_model::asset::AssetDefinitionId;
 
-    use super::*;
+    use super::{parameter::domain_policy, *};
     use crate::permission::{
         account::is_account_owner, asset_definition::is_asset_definition_owner, revoke_permissions,
     };
@@ -558,6 +805,12 @@ pub mod asset_definition {
             execute!(executor, isi);
         }
 
+        if executor.context().authority.domain() == domain_id
+            && domain_policy(domain_id, executor.host()).members_can_register_asset_definitions
+        {
+            execute!(executor, isi);
+        }
+
         deny!(
             executor,
             "Can't register asset definition in a domain owned by another account"
@@ -699,6 +952,31 @@ pub mod asset_definition {
         );
     }
 
+    pub fn visit_deprecate_asset_definition<V: Execute + Visit + ?Sized>(
+        executor: &mut V,
+        isi: &Deprecate<AssetDefinition>,
+    ) {
+        let asset_definition_id = isi.object();
+
+        if executor.context().curr_block.is_genesis() {
+            execute!(executor, isi);
+        }
+        match is_asset_definition_owner(
+            asset_definition_id,
+            &executor.context().authority,
+            executor.host(),
+        ) {
+            Err(err) => deny!(executor, err),
+            Ok(true) => execute!(executor, isi),
+            Ok(false) => {}
+        }
+
+        deny!(
+            executor,
+            "Can't deprecate asset definition owned by another account"
+        );
+    }
+
     fn is_permission_asset_definition_associated(
         permission: &Permission,
         asset_definition_id: &AssetDefinitionId,
@@ -722,6 +1000,9 @@ pub mod asset_definition {
             AnyPermission::CanTransferAssetWithDefinition(permission) => {
                 &permission.asset_definition == asset_definition_id
             }
+            AnyPermission::CanFreezeAssetWithDefinition(permission) => {
+                &permission.asset_definition == asset_definition_id
+            }
             AnyPermission::CanMintAsset(permission) => {
                 permission.asset.definition() == asset_definition_id
             }
@@ -731,8 +1012,12 @@ pub mod asset_definition {
             AnyPermission::CanTransferAsset(permission) => {
                 permission.asset.definition() == asset_definition_id
             }
+            AnyPermission::CanModifyAssetMetadata(permission) => {
+                permission.asset.definition() == asset_definition_id
+            }
             AnyPermission::CanUnregisterAccount(_)
             | AnyPermission::CanModifyAccountMetadata(_)
+            | AnyPermission::CanMessageAccount(_)
             | AnyPermission::CanRegisterTrigger(_)
             | AnyPermission::CanUnregisterTrigger(_)
             | AnyPermission::CanExecuteTrigger(_)
@@ -742,6 +1027,7 @@ pub mod asset_definition {
             | AnyPermission::CanRegisterDomain(_)
             | AnyPermission::CanUnregisterDomain(_)
             | AnyPermission::CanModifyDomainMetadata(_)
+            | AnyPermission::CanTransferDomain(_)
             | AnyPermission::CanRegisterAccount(_)
             | AnyPermission::CanRegisterAssetDefinition(_)
             | AnyPermission::CanRegisterNft(_)
@@ -757,8 +1043,9 @@ pub mod asset_definition {
 
 pub mod asset {
     use iroha_executor_data_model::permission::asset::{
-        CanBurnAsset, CanBurnAssetWithDefinition, CanMintAsset, CanMintAssetWithDefinition,
-        CanTransferAsset, CanTransferAssetWithDefinition,
+        CanBurnAsset, CanBurnAssetWithDefinition, CanFreezeAssetWithDefinition, CanMintAsset,
+        CanMintAssetWithDefinition, CanModifyAssetMetadata, CanTransferAsset,
+        CanTransferAssetWithDefinition,
     };
     use iroha_smart_contract::data_model::isi::BuiltInInstruction;
     use iroha_smart_contract_utils::Encode;
@@ -870,13 +1157,220 @@ pub mod asset {
         if executor.context().curr_block.is_genesis() {
             execute!(executor, isi);
         }
-        match is_asset_owner(asset_id, &executor.context().authority, executor.host()) {
+        match can_transfer_asset(asset_id, executor) {
             Err(err) => deny!(executor, err),
             Ok(true) => execute!(executor, isi),
             Ok(false) => {}
         }
+
+        deny!(executor, "Can't transfer assets of another account");
+    }
+
+    pub fn visit_split<V: Execute + Visit + ?Sized>(executor: &mut V, isi: &Split) {
+        let asset_id = isi.asset();
+        if executor.context().curr_block.is_genesis() {
+            execute!(executor, isi);
+        }
+        match can_transfer_asset(asset_id, executor) {
+            Err(err) => deny!(executor, err),
+            Ok(true) => execute!(executor, isi),
+            Ok(false) => {}
+        }
+
+        deny!(executor, "Can't split assets of another account");
+    }
+
+    pub fn visit_merge<V: Execute + Visit + ?Sized>(executor: &mut V, isi: &Merge) {
+        if executor.context().curr_block.is_genesis() {
+            execute!(executor, isi);
+        }
+        for asset_id in isi.assets() {
+            match can_transfer_asset(asset_id, executor) {
+                Err(err) => deny!(executor, err),
+                Ok(true) => {}
+                Ok(false) => deny!(executor, "Can't merge assets of another account"),
+            }
+        }
+
+        execute!(executor, isi);
+    }
+
+    pub fn visit_payout<V: Execute + Visit + ?Sized>(executor: &mut V, isi: &Payout) {
+        let asset_id = isi.source();
+        if executor.context().curr_block.is_genesis() {
+            execute!(executor, isi);
+        }
+        match can_transfer_asset(asset_id, executor) {
+            Err(err) => deny!(executor, err),
+            Ok(true) => execute!(executor, isi),
+            Ok(false) => {}
+        }
+
+        deny!(executor, "Can't pay out assets of another account");
+    }
+
+    pub fn visit_transfer_many<V: Execute + Visit + ?Sized>(executor: &mut V, isi: &TransferMany) {
+        let asset_id = isi.source_asset();
+        if executor.context().curr_block.is_genesis() {
+            execute!(executor, isi);
+        }
+        match can_transfer_asset(asset_id, executor) {
+            Err(err) => deny!(executor, err),
+            Ok(true) => execute!(executor, isi),
+            Ok(false) => {}
+        }
+
+        deny!(executor, "Can't transfer assets of another account");
+    }
+
+    pub fn visit_approve<V: Execute + Visit + ?Sized>(executor: &mut V, isi: &Approve) {
+        if executor.context().curr_block.is_genesis() {
+            execute!(executor, isi);
+        }
+        if isi.owner() == &executor.context().authority {
+            execute!(executor, isi);
+        }
+
+        deny!(
+            executor,
+            "Can't approve spending of assets of another account"
+        );
+    }
+
+    /// The allowance given by the owner with [`Approve`] is checked by the peer when the transfer is executed
+    pub fn visit_transfer_from<V: Execute + Visit + ?Sized>(executor: &mut V, isi: &TransferFrom) {
+        execute!(executor, isi);
+    }
+
+    /// Conversion burns one asset and mints another, so the authority must own both definitions
+    pub fn visit_convert<V: Execute + Visit + ?Sized>(executor: &mut V, isi: &Convert) {
+        if executor.context().curr_block.is_genesis() {
+            execute!(executor, isi);
+        }
+        let authority = &executor.context().authority;
+        match (
+            is_asset_definition_owner(isi.from_definition(), authority, executor.host()),
+            is_asset_definition_owner(isi.to_definition(), authority, executor.host()),
+        ) {
+            (Err(err), _) | (_, Err(err)) => deny!(executor, err),
+            (Ok(true), Ok(true)) => execute!(executor, isi),
+            _ => {}
+        }
+
+        deny!(
+            executor,
+            "Can't convert assets without owning both asset definitions"
+        );
+    }
+
+    pub fn visit_create_escrow<V: Execute + Visit + ?Sized>(executor: &mut V, isi: &CreateEscrow) {
+        let escrow = isi.escrow();
+        let asset_id = AssetId::new(escrow.asset_definition.clone(), escrow.id.sender.clone());
+        if executor.context().curr_block.is_genesis() {
+            execute!(executor, isi);
+        }
+        match can_transfer_asset(&asset_id, executor) {
+            Err(err) => deny!(executor, err),
+            Ok(true) => execute!(executor, isi),
+            Ok(false) => {}
+        }
+
+        deny!(executor, "Can't escrow assets of another account");
+    }
+
+    /// The release and expiry times are checked by the peer when the escrow is claimed or refunded
+    pub fn visit_claim_escrow<V: Execute + Visit + ?Sized>(executor: &mut V, isi: &ClaimEscrow) {
+        let escrow_id = isi.escrow();
+        match executor
+            .host()
+            .query_single(FindEscrows::new(escrow_id.sender.clone()))
+        {
+            Err(err) => deny!(executor, err),
+            Ok(escrows)
+                if escrows.iter().any(|escrow| {
+                    escrow.id == *escrow_id && escrow.recipient == executor.context().authority
+                }) =>
+            {
+                execute!(executor, isi)
+            }
+            Ok(_) => {}
+        }
+
+        deny!(
+            executor,
+            "Can't claim an escrow destined to another account"
+        );
+    }
+
+    pub fn visit_refund_escrow<V: Execute + Visit + ?Sized>(executor: &mut V, isi: &RefundEscrow) {
+        if isi.escrow().sender == executor.context().authority {
+            execute!(executor, isi);
+        }
+
+        deny!(executor, "Can't refund an escrow of another account");
+    }
+
+    /// Whether the authority may move the quantity out of the given holding
+    fn can_transfer_asset<V: Execute + Visit + ?Sized>(
+        asset_id: &AssetId,
+        executor: &V,
+    ) -> Result<bool, ValidationFail> {
+        let authority = &executor.context().authority;
+        if is_asset_owner(asset_id, authority, executor.host())?
+            || is_asset_definition_owner(asset_id.definition(), authority, executor.host())?
+        {
+            return Ok(true);
+        }
+        let can_transfer_assets_with_definition_token = CanTransferAssetWithDefinition {
+            asset_definition: asset_id.definition().clone(),
+        };
+        let can_transfer_user_asset_token = CanTransferAsset {
+            asset: asset_id.clone(),
+        };
+
+        Ok(
+            can_transfer_assets_with_definition_token.is_owned_by(authority, executor.host())
+                || can_transfer_user_asset_token.is_owned_by(authority, executor.host()),
+        )
+    }
+
+    pub fn visit_freeze_asset<V: Execute + Visit + ?Sized>(executor: &mut V, isi: &Freeze<Asset>) {
+        execute_freeze(executor, isi.object().definition(), isi);
+    }
+
+    pub fn visit_unfreeze_asset<V: Execute + Visit + ?Sized>(
+        executor: &mut V,
+        isi: &Unfreeze<Asset>,
+    ) {
+        execute_freeze(executor, isi.object().definition(), isi);
+    }
+
+    pub fn visit_freeze_asset_definition<V: Execute + Visit + ?Sized>(
+        executor: &mut V,
+        isi: &Freeze<AssetDefinition>,
+    ) {
+        execute_freeze(executor, isi.object(), isi);
+    }
+
+    pub fn visit_unfreeze_asset_definition<V: Execute + Visit + ?Sized>(
+        executor: &mut V,
+        isi: &Unfreeze<AssetDefinition>,
+    ) {
+        execute_freeze(executor, isi.object(), isi);
+    }
+
+    /// Holdings are frozen and unfrozen by the issuer of the asset or with [`CanFreezeAssetWithDefinition`],
+    /// never by their holders
+    fn execute_freeze<V, I>(executor: &mut V, asset_definition_id: &AssetDefinitionId, isi: &I)
+    where
+        V: Execute + Visit + ?Sized,
+        I: BuiltInInstruction + Encode,
+    {
+        if executor.context().curr_block.is_genesis() {
+            execute!(executor, isi);
+        }
         match is_asset_definition_owner(
-            asset_id.definition(),
+            asset_definition_id,
             &executor.context().authority,
             executor.host(),
         ) {
@@ -884,23 +1378,72 @@ pub mod asset {
             Ok(true) => execute!(executor, isi),
             Ok(false) => {}
         }
-        let can_transfer_assets_with_definition_token = CanTransferAssetWithDefinition {
-            asset_definition: asset_id.definition().clone(),
+        let can_freeze_assets_with_definition_token = CanFreezeAssetWithDefinition {
+            asset_definition: asset_definition_id.clone(),
         };
-        if can_transfer_assets_with_definition_token
+        if can_freeze_assets_with_definition_token
             .is_owned_by(&executor.context().authority, executor.host())
         {
             execute!(executor, isi);
         }
-        let can_transfer_user_asset_token = CanTransferAsset {
+
+        deny!(
+            executor,
+            "Can't freeze assets with definitions registered by other accounts"
+        );
+    }
+
+    pub fn visit_set_asset_key_value<V: Execute + Visit + ?Sized>(
+        executor: &mut V,
+        isi: &SetKeyValue<Asset>,
+    ) {
+        execute_modify_asset_metadata(executor, isi.object(), isi);
+    }
+
+    pub fn visit_remove_asset_key_value<V: Execute + Visit + ?Sized>(
+        executor: &mut V,
+        isi: &RemoveKeyValue<Asset>,
+    ) {
+        execute_modify_asset_metadata(executor, isi.object(), isi);
+    }
+
+    /// Metadata of a holding may be modified by its holder, by the issuer of the asset,
+    /// or on behalf of the holder with [`CanModifyAssetMetadata`]
+    fn execute_modify_asset_metadata<V, I>(executor: &mut V, asset_id: &AssetId, isi: &I)
+    where
+        V: Execute + Visit + ?Sized,
+        I: BuiltInInstruction + Encode,
+    {
+        if executor.context().curr_block.is_genesis() {
+            execute!(executor, isi);
+        }
+        match is_asset_owner(asset_id, &executor.context().authority, executor.host()) {
+            Err(err) => deny!(executor, err),
+            Ok(true) => execute!(executor, isi),
+            Ok(false) => {}
+        }
+        match is_asset_definition_owner(
+            asset_id.definition(),
+            &executor.context().authority,
+            executor.host(),
+        ) {
+            Err(err) => deny!(executor, err),
+            Ok(true) => execute!(executor, isi),
+            Ok(false) => {}
+        }
+        let can_modify_asset_metadata_token = CanModifyAssetMetadata {
             asset: asset_id.clone(),
         };
-        if can_transfer_user_asset_token.is_owned_by(&executor.context().authority, executor.host())
+        if can_modify_asset_metadata_token
+            .is_owned_by(&executor.context().authority, executor.host())
         {
             execute!(executor, isi);
         }
 
-        deny!(executor, "Can't transfer assets of another account");
+        deny!(
+            executor,
+            "Can't modify metadata of assets of another account"
+        );
     }
 }
 
@@ -910,7 +1453,7 @@ pub mod nft {
     };
     use iroha_smart_contract_utils::Encode;
 
-    use super::*;
+    use super::{parameter::domain_policy, *};
     use crate::{
         data_model::isi::BuiltInInstruction,
         permission::{
@@ -942,6 +1485,12 @@ pub mod nft {
             execute!(executor, isi);
         }
 
+        if executor.context().authority.domain() == domain_id
+            && domain_policy(domain_id, executor.host()).members_can_register_nfts
+        {
+            execute!(executor, isi);
+        }
+
         deny!(
             executor,
             "Can't register NFT in a domain owned by another account"
@@ -1068,11 +1617,39 @@ pub mod nft {
 }
 
 pub mod parameter {
-    use iroha_executor_data_model::permission::parameter::CanSetParameters;
+    use iroha_executor_data_model::{
+        parameter::{
+            domain::{DomainPolicies, DomainPolicy},
+            Parameter as _,
+        },
+        permission::parameter::CanSetParameters,
+    };
+    use iroha_smart_contract::Iroha;
 
     use super::*;
+    use crate::permission::domain::is_domain_owner;
 
     pub fn visit_set_parameter<V: Execute + Visit + ?Sized>(executor: &mut V, isi: &SetParameter) {
+        if let Parameter::Custom(parameter) = isi.parameter() {
+            if *parameter.id() == DomainPolicies::id() {
+                let policies = match DomainPolicies::try_from(parameter) {
+                    Ok(policies) => policies,
+                    Err(err) => deny!(
+                        executor,
+                        ValidationFail::NotPermitted(format!("Invalid domain policies: {err:?}"))
+                    ),
+                };
+                for domain_id in policies.domains.keys() {
+                    // Ensure policies are set only for existing domains
+                    if let Err(err) =
+                        is_domain_owner(domain_id, &executor.context().authority, executor.host())
+                    {
+                        deny!(executor, err);
+                    }
+                }
+            }
+        }
+
         if executor.context().curr_block.is_genesis() {
             execute!(executor, isi);
         }
@@ -1085,9 +1662,26 @@ pub mod parameter {
             "Can't set executor configuration parameters without permission"
         );
     }
+
+    /// Policy of the domain set by [`DomainPolicies`] parameter.
+    /// Falls back to the default policy if the parameter is not defined by the executor
+    pub(crate) fn domain_policy(domain_id: &DomainId, host: &Iroha) -> DomainPolicy {
+        host.query_single(FindParameters)
+            .ok()
+            .and_then(|parameters| {
+                parameters
+                    .custom()
+                    .get(&DomainPolicies::id())
+                    .and_then(|parameter| DomainPolicies::try_from(parameter).ok())
+            })
+            .map(|policies| policies.get(domain_id))
+            .unwrap_or_default()
+    }
 }
 
 pub mod role {
+    use alloc::vec::Vec;
+
     use iroha_executor_data_model::permission::role::CanManageRoles;
     use iroha_smart_contract::{data_model::role::Role, Iroha};
 
@@ -1223,6 +1817,37 @@ pub mod role {
             }
             new_role = new_role.add_permission(any_permission);
         }
+        // The inherited permissions are validated as if the role included them directly
+        if !executor.context().curr_block.is_genesis() {
+            let mut inherited = Vec::new();
+            crate::permission::any_role(
+                executor.host(),
+                role.inner().extends().cloned().collect(),
+                |extended| {
+                    inherited.extend(extended.permissions().cloned());
+                    false
+                },
+            );
+            for permission in &inherited {
+                let Ok(any_permission) = AnyPermission::try_from(permission) else {
+                    deny!(
+                        executor,
+                        ValidationFail::NotPermitted(format!("{permission:?}: Unknown permission"))
+                    );
+                };
+                if let Err(error) = crate::permission::ValidateGrantRevoke::validate_grant(
+                    &any_permission,
+                    role.grant_to(),
+                    executor.context(),
+                    executor.host(),
+                ) {
+                    deny!(executor, error);
+                }
+            }
+        }
+        for extended in role.inner().extends() {
+            new_role = new_role.extend(extended.clone());
+        }
 
         if executor.context().curr_block.is_genesis()
             || CanManageRoles.is_owned_by(&executor.context().authority, executor.host())
@@ -1511,18 +2136,22 @@ pub mod trigger {
             | AnyPermission::CanRegisterDomain(_)
             | AnyPermission::CanUnregisterDomain(_)
             | AnyPermission::CanModifyDomainMetadata(_)
+            | AnyPermission::CanTransferDomain(_)
             | AnyPermission::CanRegisterAccount(_)
             | AnyPermission::CanRegisterAssetDefinition(_)
             | AnyPermission::CanUnregisterAccount(_)
             | AnyPermission::CanModifyAccountMetadata(_)
+            | AnyPermission::CanMessageAccount(_)
             | AnyPermission::CanUnregisterAssetDefinition(_)
             | AnyPermission::CanModifyAssetDefinitionMetadata(_)
             | AnyPermission::CanMintAssetWithDefinition(_)
             | AnyPermission::CanBurnAssetWithDefinition(_)
             | AnyPermission::CanTransferAssetWithDefinition(_)
+            | AnyPermission::CanFreezeAssetWithDefinition(_)
             | AnyPermission::CanMintAsset(_)
             | AnyPermission::CanBurnAsset(_)
             | AnyPermission::CanTransferAsset(_)
+            | AnyPermission::CanModifyAssetMetadata(_)
             | AnyPermission::CanSetParameters(_)
             | AnyPermission::CanManageRoles(_)
             | AnyPermission::CanRegisterNft(_)
@@ -1578,6 +2207,21 @@ pub mod permission {
     ) {
         impl_execute!(executor, isi, validate_revoke, Revoke<Permission, Account>);
     }
+
+    /// Delegation doesn't need a permission of its own:
+    /// only the permissions granted to the authority directly can be delegated, which is checked on execution.
+    pub fn visit_delegate<V: Execute + Visit + ?Sized>(executor: &mut V, isi: &Delegate) {
+        let permission = isi.permission();
+
+        if AnyPermission::try_from(permission).is_ok() {
+            execute!(executor, isi);
+        }
+
+        deny!(
+            executor,
+            ValidationFail::NotPermitted(format!("{permission:?}: Unknown permission"))
+        );
+    }
 }
 
 pub mod executor {
@@ -1604,3 +2248,137 @@ pub mod log {
         execute!(executor, isi)
     }
 }
+
+/// An account accepts messages from the accounts it granted [`CanMessageAccount`] to,
+/// the sender is recorded by the peer.
+pub mod message {
+    use iroha_executor_data_model::permission::account::CanMessageAccount;
+
+    use super::*;
+
+    pub fn visit_message<V: Execute + Visit + ?Sized>(executor: &mut V, isi: &Message) {
+        let recipient = &isi.recipient;
+
+        if executor.context().curr_block.is_genesis() || *recipient == executor.context().authority
+        {
+            execute!(executor, isi);
+        }
+        let can_message_account = CanMessageAccount {
+            account: recipient.clone(),
+        };
+        if can_message_account.is_owned_by(&executor.context().authority, executor.host()) {
+            execute!(executor, isi);
+        }
+
+        deny!(
+            executor,
+            "Can't leave a message to an account which doesn't accept messages from the authority"
+        );
+    }
+}
+
+/// The instruction nested into [`Tagged`] is validated by the executor
+/// when the peer executes the outer one.
+pub mod tagged {
+    use super::*;
+
+    pub fn visit_tagged<V: Execute + Visit + ?Sized>(executor: &mut V, isi: &Tagged) {
+        execute!(executor, isi)
+    }
+}
+
+/// The instructions [`Onboard`] consists of are validated by the executor
+/// when the peer executes it.
+pub mod onboard {
+    use super::*;
+
+    pub fn visit_onboard<V: Execute + Visit + ?Sized>(executor: &mut V, isi: &Onboard) {
+        execute!(executor, isi)
+    }
+}
+
+/// Accounts manage their own notification subscriptions only.
+pub mod subscription {
+    use super::*;
+
+    pub fn visit_subscribe<V: Execute + Visit + ?Sized>(executor: &mut V, isi: &Subscribe) {
+        if executor.context().curr_block.is_genesis()
+            || isi.subscription().id().account() == &executor.context().authority
+        {
+            execute!(executor, isi);
+        }
+
+        deny!(executor, "Can't subscribe on behalf of another account");
+    }
+
+    pub fn visit_unsubscribe<V: Execute + Visit + ?Sized>(executor: &mut V, isi: &Unsubscribe) {
+        if executor.context().curr_block.is_genesis()
+            || isi.subscription().account() == &executor.context().authority
+        {
+            execute!(executor, isi);
+        }
+
+        deny!(executor, "Can't unsubscribe another account");
+    }
+}
+
+/// Dead letters are handled by the owner of the failed trigger.
+pub mod dead_letter {
+    use super::*;
+    use crate::permission::trigger::is_trigger_owner;
+
+    pub fn visit_retry_dead_letter<V: Execute + Visit + ?Sized>(
+        executor: &mut V,
+        isi: &RetryDeadLetter,
+    ) {
+        if executor.context().curr_block.is_genesis() {
+            execute!(executor, isi);
+        }
+        let authority = &executor.context().authority;
+        match is_trigger_owner(isi.dead_letter().trigger(), authority, executor.host()) {
+            Err(err) => deny!(executor, err),
+            Ok(true) => execute!(executor, isi),
+            Ok(false) => {}
+        }
+
+        deny!(
+            executor,
+            "Can't retry dead letter of trigger owned by another account"
+        );
+    }
+
+    pub fn visit_discard_dead_letter<V: Execute + Visit + ?Sized>(
+        executor: &mut V,
+        isi: &DiscardDeadLetter,
+    ) {
+        if executor.context().curr_block.is_genesis() {
+            execute!(executor, isi);
+        }
+        let authority = &executor.context().authority;
+        match is_trigger_owner(isi.dead_letter().trigger(), authority, executor.host()) {
+            Err(err) => deny!(executor, err),
+            Ok(true) => execute!(executor, isi),
+            Ok(false) => {}
+        }
+
+        deny!(
+            executor,
+            "Can't discard dead letter of trigger owned by another account"
+        );
+    }
+
+    pub fn visit_find_dead_letters<V: Execute + Visit + ?Sized>(
+        executor: &mut V,
+        query: &FindDeadLetters,
+    ) {
+        let authority = &executor.context().authority;
+        match is_trigger_owner(&query.trigger, authority, executor.host()) {
+            Err(err) => deny!(executor, err),
+            Ok(true) => {}
+            Ok(false) => deny!(
+                executor,
+                "Can't find dead letters of trigger owned by another account"
+            ),
+        }
+    }
+}
@@ -200,6 +200,18 @@ impl DataModelBuilder {
         builder
     }
 
+    /// Define parameters of the default executor (defined in [`iroha_executor_data_model::parameter`])
+    /// with their default values
+    #[must_use]
+    pub fn add_default_parameters(self) -> Self {
+        use iroha_executor_data_model::parameter::{
+            domain::DomainPolicies, governance::GovernanceParameters,
+        };
+
+        self.add_parameter(DomainPolicies::default())
+            .add_parameter(GovernanceParameters::default())
+    }
+
     /// Define a permission in the data model
     #[must_use]
     pub fn add_parameter<T: Parameter + Into<data_model::parameter::CustomParameter>>(
@@ -1,6 +1,6 @@
 //! Module with permission related functionality.
 
-use alloc::{borrow::ToOwned as _, vec::Vec};
+use alloc::{borrow::ToOwned as _, collections::BTreeSet, vec::Vec};
 
 use iroha_executor_data_model::permission::Permission;
 
@@ -93,10 +93,12 @@ declare_permissions! {
     iroha_executor_data_model::permission::domain::{CanRegisterDomain},
     iroha_executor_data_model::permission::domain::{CanUnregisterDomain},
     iroha_executor_data_model::permission::domain::{CanModifyDomainMetadata},
+    iroha_executor_data_model::permission::domain::{CanTransferDomain},
 
     iroha_executor_data_model::permission::account::{CanRegisterAccount},
     iroha_executor_data_model::permission::account::{CanUnregisterAccount},
     iroha_executor_data_model::permission::account::{CanModifyAccountMetadata},
+    iroha_executor_data_model::permission::account::{CanMessageAccount},
 
     iroha_executor_data_model::permission::asset_definition::{CanRegisterAssetDefinition},
     iroha_executor_data_model::permission::asset_definition::{CanUnregisterAssetDefinition},
@@ -105,9 +107,11 @@ declare_permissions! {
     iroha_executor_data_model::permission::asset::{CanMintAssetWithDefinition},
     iroha_executor_data_model::permission::asset::{CanBurnAssetWithDefinition},
     iroha_executor_data_model::permission::asset::{CanTransferAssetWithDefinition},
+    iroha_executor_data_model::permission::asset::{CanFreezeAssetWithDefinition},
     iroha_executor_data_model::permission::asset::{CanMintAsset},
     iroha_executor_data_model::permission::asset::{CanBurnAsset},
     iroha_executor_data_model::permission::asset::{CanTransferAsset},
+    iroha_executor_data_model::permission::asset::{CanModifyAssetMetadata},
 
     iroha_executor_data_model::permission::nft::{CanRegisterNft},
     iroha_executor_data_model::permission::nft::{CanUnregisterNft},
@@ -144,27 +148,58 @@ pub trait ExecutorPermission: Permission + PartialEq {
             return true;
         }
 
-        // build a big OR predicate over all roles we are interested in
-        let role_predicate = host
+        let role_ids = host
             .query(FindRolesByAccountId::new(authority.clone()))
             .execute()
             .expect("INTERNAL BUG: `FindRolesByAccountId` must never fail")
             .map(|role_id| role_id.dbg_expect("Failed to get role from cursor"))
-            .fold(CompoundPredicate::Or(Vec::new()), |predicate, role_id| {
-                predicate.or(CompoundPredicate::<Role>::build(|role| role.id.eq(role_id)))
-            });
+            .collect::<Vec<_>>();
 
         // check if any of the roles have the permission we need
-        host.query(FindRoles)
+        any_role(host, role_ids, |role| {
+            role.permissions()
+                .filter_map(|permission| Self::try_from(permission).ok())
+                .any(|permission| *self == permission)
+        })
+    }
+}
+
+/// Check if `predicate` holds for any of the roles with `role_ids`
+/// or the roles they extend, directly or through other roles.
+pub(crate) fn any_role(
+    host: &Iroha,
+    mut role_ids: Vec<RoleId>,
+    mut predicate: impl FnMut(&Role) -> bool,
+) -> bool {
+    let mut visited = BTreeSet::new();
+
+    // walk the roles level by level, following the roles they extend
+    loop {
+        role_ids.retain(|role_id| visited.insert(role_id.clone()));
+        if role_ids.is_empty() {
+            return false;
+        }
+
+        // build a big OR predicate over all roles we are interested in
+        let role_predicate =
+            role_ids
+                .drain(..)
+                .fold(CompoundPredicate::Or(Vec::new()), |filter, role_id| {
+                    filter.or(CompoundPredicate::<Role>::build(|role| role.id.eq(role_id)))
+                });
+
+        for role in host
+            .query(FindRoles)
             .filter(role_predicate)
             .execute()
             .expect("INTERNAL BUG: `FindRoles` must never fail")
             .map(|role| role.dbg_expect("Failed to get role from cursor"))
-            .any(|role| {
-                role.permissions()
-                    .filter_map(|permission| Self::try_from(permission).ok())
-                    .any(|permission| *self == permission)
-            })
+        {
+            if predicate(&role) {
+                return true;
+            }
+            role_ids.extend(role.extends().cloned());
+        }
     }
 }
 
@@ -290,8 +325,9 @@ pub mod asset {
     //! Module with pass conditions for asset related tokens
 
     use iroha_executor_data_model::permission::asset::{
-        CanBurnAsset, CanBurnAssetWithDefinition, CanMintAsset, CanMintAssetWithDefinition,
-        CanTransferAsset, CanTransferAssetWithDefinition,
+        CanBurnAsset, CanBurnAssetWithDefinition, CanFreezeAssetWithDefinition, CanMintAsset,
+        CanMintAssetWithDefinition, CanModifyAssetMetadata, CanTransferAsset,
+        CanTransferAssetWithDefinition,
     };
 
     use super::*;
@@ -370,6 +406,20 @@ pub mod asset {
         }
     }
 
+    impl ValidateGrantRevoke for CanFreezeAssetWithDefinition {
+        fn validate_grant(&self, authority: &AccountId, context: &Context, host: &Iroha) -> Result {
+            super::asset_definition::Owner::from(self).validate(authority, host, context)
+        }
+        fn validate_revoke(
+            &self,
+            authority: &AccountId,
+            context: &Context,
+            host: &Iroha,
+        ) -> Result {
+            super::asset_definition::Owner::from(self).validate(authority, host, context)
+        }
+    }
+
     impl ValidateGrantRevoke for CanMintAsset {
         fn validate_grant(&self, authority: &AccountId, context: &Context, host: &Iroha) -> Result {
             Owner::from(self).validate(authority, host, context)
@@ -422,7 +472,26 @@ pub mod asset {
         };
     }
 
-    impl_froms!(CanMintAsset, CanBurnAsset, CanTransferAsset);
+    impl ValidateGrantRevoke for CanModifyAssetMetadata {
+        fn validate_grant(&self, authority: &AccountId, context: &Context, host: &Iroha) -> Result {
+            Owner::from(self).validate(authority, host, context)
+        }
+        fn validate_revoke(
+            &self,
+            authority: &AccountId,
+            context: &Context,
+            host: &Iroha,
+        ) -> Result {
+            Owner::from(self).validate(authority, host, context)
+        }
+    }
+
+    impl_froms!(
+        CanMintAsset,
+        CanBurnAsset,
+        CanTransferAsset,
+        CanModifyAssetMetadata
+    );
 }
 
 pub mod asset_definition {
@@ -557,6 +626,7 @@ pub mod asset_definition {
         iroha_executor_data_model::permission::asset::CanMintAssetWithDefinition,
         iroha_executor_data_model::permission::asset::CanBurnAssetWithDefinition,
         iroha_executor_data_model::permission::asset::CanTransferAssetWithDefinition,
+        iroha_executor_data_model::permission::asset::CanFreezeAssetWithDefinition,
     );
 }
 
@@ -712,7 +782,7 @@ pub mod account {
     //! Module with pass conditions for asset related tokens
 
     use iroha_executor_data_model::permission::account::{
-        CanModifyAccountMetadata, CanRegisterAccount, CanUnregisterAccount,
+        CanMessageAccount, CanModifyAccountMetadata, CanRegisterAccount, CanUnregisterAccount,
     };
 
     use super::*;
@@ -799,6 +869,20 @@ pub mod account {
         }
     }
 
+    impl ValidateGrantRevoke for CanMessageAccount {
+        fn validate_grant(&self, authority: &AccountId, context: &Context, host: &Iroha) -> Result {
+            Owner::from(self).validate(authority, host, context)
+        }
+        fn validate_revoke(
+            &self,
+            authority: &AccountId,
+            context: &Context,
+            host: &Iroha,
+        ) -> Result {
+            Owner::from(self).validate(authority, host, context)
+        }
+    }
+
     macro_rules! impl_froms {
         ($($name:ty),+ $(,)?) => {$(
             impl<'t> From<&'t $name> for Owner<'t> {
@@ -809,7 +893,11 @@ pub mod account {
         };
     }
 
-    impl_froms!(CanUnregisterAccount, CanModifyAccountMetadata,);
+    impl_froms!(
+        CanUnregisterAccount,
+        CanModifyAccountMetadata,
+        CanMessageAccount,
+    );
 }
 
 pub mod trigger {
@@ -979,7 +1067,9 @@ pub mod trigger {
 pub mod domain {
     //! Module with pass conditions for domain related tokens
     use iroha_executor_data_model::permission::{
-        domain::{CanModifyDomainMetadata, CanRegisterDomain, CanUnregisterDomain},
+        domain::{
+            CanModifyDomainMetadata, CanRegisterDomain, CanTransferDomain, CanUnregisterDomain,
+        },
         nft::CanRegisterNft,
     };
     use iroha_smart_contract::data_model::{
@@ -1074,6 +1164,20 @@ pub mod domain {
         }
     }
 
+    impl ValidateGrantRevoke for CanTransferDomain {
+        fn validate_grant(&self, authority: &AccountId, context: &Context, host: &Iroha) -> Result {
+            Owner::from(self).validate(authority, host, context)
+        }
+        fn validate_revoke(
+            &self,
+            authority: &AccountId,
+            context: &Context,
+            host: &Iroha,
+        ) -> Result {
+            Owner::from(self).validate(authority, host, context)
+        }
+    }
+
     macro_rules! impl_froms {
         ($($name:ty),+ $(,)?) => {$(
             impl<'t> From<&'t $name> for Owner<'t> {
@@ -1087,6 +1191,7 @@ pub mod domain {
     impl_froms!(
         CanUnregisterDomain,
         CanModifyDomainMetadata,
+        CanTransferDomain,
         iroha_executor_data_model::permission::account::CanRegisterAccount,
         iroha_executor_data_model::permission::asset_definition::CanRegisterAssetDefinition,
         CanRegisterNft,
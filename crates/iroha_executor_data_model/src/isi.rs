@@ -175,3 +175,200 @@ pub mod multisig {
         }
     }
 }
+
+/// Types for role presets
+pub mod preset {
+    use super::*;
+    use crate::permission::{
+        account::CanRegisterAccount,
+        asset::{CanBurnAssetWithDefinition, CanMintAssetWithDefinition},
+        asset_definition::{CanModifyAssetDefinitionMetadata, CanRegisterAssetDefinition},
+        domain::CanModifyDomainMetadata,
+        nft::CanRegisterNft,
+    };
+
+    /// Role preset instructions
+    #[derive(Debug, Clone, Serialize, Deserialize, IntoSchema, From)]
+    pub enum RolePresetInstructionBox {
+        /// Register a role from a preset
+        Register(RegisterRolePreset),
+    }
+
+    /// Register a role with the permissions of the given [`RolePreset`] and grant it to the account
+    #[derive(Debug, Clone, Serialize, Deserialize, IntoSchema, Constructor)]
+    pub struct RegisterRolePreset {
+        /// Role to be registered
+        pub role: RoleId,
+        /// Account to grant the role to
+        pub grant_to: AccountId,
+        /// Set of permissions of the role
+        pub preset: RolePreset,
+    }
+
+    impl_custom_instruction!(RolePresetInstructionBox, RegisterRolePreset);
+
+    /// Predefined sets of permissions of the default executor
+    #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, IntoSchema)]
+    pub enum RolePreset {
+        /// Read-only access.
+        /// Queries are not restricted by the default executor, so the role holds no permissions
+        /// and only marks the holder as an auditor
+        Auditor,
+        /// Mint and burn assets of the definition and manage its metadata
+        Issuer(AssetDefinitionId),
+        /// Register accounts, asset definitions and NFTs in the domain and manage its metadata
+        Operator(DomainId),
+    }
+
+    impl RolePreset {
+        /// Permissions granted by the preset
+        pub fn permissions(&self) -> Vec<Permission> {
+            match self {
+                Self::Auditor => Vec::new(),
+                Self::Issuer(asset_definition) => [
+                    CanMintAssetWithDefinition {
+                        asset_definition: asset_definition.clone(),
+                    }
+                    .into(),
+                    CanBurnAssetWithDefinition {
+                        asset_definition: asset_definition.clone(),
+                    }
+                    .into(),
+                    CanModifyAssetDefinitionMetadata {
+                        asset_definition: asset_definition.clone(),
+                    }
+                    .into(),
+                ]
+                .into(),
+                Self::Operator(domain) => [
+                    CanRegisterAccount {
+                        domain: domain.clone(),
+                    }
+                    .into(),
+                    CanRegisterAssetDefinition {
+                        domain: domain.clone(),
+                    }
+                    .into(),
+                    CanRegisterNft {
+                        domain: domain.clone(),
+                    }
+                    .into(),
+                    CanModifyDomainMetadata {
+                        domain: domain.clone(),
+                    }
+                    .into(),
+                ]
+                .into(),
+            }
+        }
+    }
+
+    impl From<RegisterRolePreset> for NewRole {
+        fn from(value: RegisterRolePreset) -> Self {
+            value
+                .preset
+                .permissions()
+                .into_iter()
+                .fold(Role::new(value.role, value.grant_to), |role, permission| {
+                    role.add_permission(permission)
+                })
+        }
+    }
+}
+
+/// Types for governance instructions
+pub mod governance {
+    use alloc::collections::btree_set::BTreeSet;
+    use core::num::NonZeroU64;
+
+    use super::*;
+
+    /// Governance-related instructions
+    #[derive(Debug, Clone, Serialize, Deserialize, IntoSchema, From)]
+    pub enum GovernanceInstructionBox {
+        /// Propose instructions to be executed on behalf of the governance council
+        Propose(GovernancePropose),
+        /// Vote for or against a certain proposal
+        Vote(GovernanceVote),
+    }
+
+    /// Propose instructions to be executed on behalf of the governance council.
+    /// The proposer votes separately, like the other council members
+    #[derive(Debug, Clone, Serialize, Deserialize, IntoSchema, Constructor)]
+    pub struct GovernancePropose {
+        /// Proposal contents
+        pub instructions: Vec<InstructionBox>,
+        /// Time in milliseconds based on block timestamps during which the proposal accepts votes
+        pub voting_period_ms: NonZeroU64,
+    }
+
+    /// Vote for or against a certain proposal
+    #[derive(Debug, Clone, Serialize, Deserialize, IntoSchema, Constructor)]
+    pub struct GovernanceVote {
+        /// Proposal to vote for
+        pub instructions_hash: HashOf<Vec<InstructionBox>>,
+        /// `true` to approve the proposal, `false` to reject it
+        pub approve: bool,
+    }
+
+    impl_custom_instruction!(GovernanceInstructionBox, GovernancePropose | GovernanceVote);
+
+    const DELIMITER: char = '/';
+    const PROPOSALS: &str = "governance/proposals";
+
+    /// Domain whose metadata holds the proposals, i.e. the genesis domain
+    pub fn proposals_domain() -> DomainId {
+        "genesis"
+            .parse()
+            .expect("INTERNAL BUG: genesis domain id should be valid")
+    }
+
+    /// Key of the genesis domain metadata entry holding the proposal
+    pub fn proposal_key(instructions_hash: &HashOf<Vec<InstructionBox>>) -> Name {
+        format!("{PROPOSALS}{DELIMITER}{instructions_hash}")
+            .parse()
+            .expect("INTERNAL BUG: proposal key should be a valid name")
+    }
+
+    /// Proposals recorded in the `metadata` of the genesis domain.
+    /// Expired proposals are pruned lazily, so they should be filtered by [`GovernanceProposalValue::expires_at_ms`]
+    pub fn proposals(
+        metadata: &Metadata,
+    ) -> impl Iterator<Item = (&Name, GovernanceProposalValue)> + '_ {
+        metadata
+            .iter()
+            .filter(|(key, _)| key.as_ref().starts_with(PROPOSALS))
+            .filter_map(|(key, value)| Some((key, value.try_into().ok()?)))
+    }
+
+    /// Metadata value for a governance proposal
+    #[derive(Debug, Clone, Serialize, Deserialize, IntoSchema, Constructor)]
+    pub struct GovernanceProposalValue {
+        /// Proposal contents
+        pub instructions: Vec<InstructionBox>,
+        /// Account which made the proposal
+        pub proposer: AccountId,
+        /// Time in milliseconds at which the proposal was made
+        pub proposed_at_ms: u64,
+        /// Time in milliseconds at which the proposal will expire
+        pub expires_at_ms: u64,
+        /// Council members who approved the proposal so far
+        pub approvals: BTreeSet<AccountId>,
+        /// Council members who rejected the proposal so far
+        pub rejections: BTreeSet<AccountId>,
+    }
+
+    impl From<GovernanceProposalValue> for Json {
+        fn from(details: GovernanceProposalValue) -> Self {
+            Json::new(details)
+        }
+    }
+
+    impl TryFrom<&Json> for GovernanceProposalValue {
+        type Error = serde_json::Error;
+
+        fn try_from(payload: &Json) -> serde_json::Result<Self> {
+            serde_json::from_str::<Self>(payload.as_ref())
+        }
+    }
+}
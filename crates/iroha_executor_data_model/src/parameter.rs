@@ -17,3 +17,83 @@ pub trait Parameter: Default + DeserializeOwned + Serialize + IntoSchema {
         )
     }
 }
+
+/// Parameters of the default executor related to domains
+pub mod domain {
+    use alloc::{collections::btree_map::BTreeMap, format, string::String, vec::Vec};
+
+    use iroha_data_model::prelude::DomainId;
+    use serde::Deserialize;
+
+    use super::*;
+
+    /// Per-domain policies enforced by the default executor.
+    /// Domains without an entry follow [`DomainPolicy::default`]
+    #[derive(
+        Debug, Clone, Default, PartialEq, Eq, Parameter, Serialize, Deserialize, IntoSchema,
+    )]
+    pub struct DomainPolicies {
+        /// Policies of individual domains
+        pub domains: BTreeMap<DomainId, DomainPolicy>,
+    }
+
+    /// Policy knobs of a single domain.
+    /// Domain owner and holders of the corresponding permissions are not affected by the policy
+    #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, IntoSchema)]
+    #[serde(default)]
+    pub struct DomainPolicy {
+        /// Accounts of the domain can register other accounts in it
+        pub members_can_register_accounts: bool,
+        /// Accounts of the domain can register asset definitions in it
+        pub members_can_register_asset_definitions: bool,
+        /// Accounts of the domain can register NFTs in it
+        pub members_can_register_nfts: bool,
+    }
+
+    impl DomainPolicies {
+        /// Policy of the given domain
+        pub fn get(&self, domain: &DomainId) -> DomainPolicy {
+            self.domains.get(domain).copied().unwrap_or_default()
+        }
+    }
+}
+
+/// Parameters of the default executor related to governance
+pub mod governance {
+    use alloc::{format, string::String, vec::Vec};
+    use core::num::NonZeroU16;
+
+    use iroha_data_model::prelude::{AccountId, RoleId};
+    use serde::Deserialize;
+
+    use super::*;
+
+    /// Smallest [`GovernanceParameters::quorum`], so that no council member decides alone
+    pub const MIN_QUORUM: u16 = 2;
+
+    /// Governance council of the default executor
+    #[derive(Debug, Clone, PartialEq, Eq, Parameter, Serialize, Deserialize, IntoSchema)]
+    pub struct GovernanceParameters {
+        /// Role whose holders can make proposals and vote
+        pub council: RoleId,
+        /// Number of approvals at which a proposal is executed, at least [`MIN_QUORUM`].
+        /// The same number of rejections dismisses the proposal.
+        /// Governance is disabled until the quorum is set
+        pub quorum: Option<NonZeroU16>,
+        /// Account on behalf of which the approved instructions are validated and executed,
+        /// the proposer if not set
+        pub authority: Option<AccountId>,
+    }
+
+    impl Default for GovernanceParameters {
+        fn default() -> Self {
+            Self {
+                council: "GOVERNANCE_COUNCIL"
+                    .parse()
+                    .expect("INTERNAL BUG: council role should be a valid id"),
+                quorum: None,
+                authority: None,
+            }
+        }
+    }
+}
@@ -60,6 +60,12 @@ pub mod domain {
             pub domain: DomainId,
         }
     }
+
+    permission! {
+        pub struct CanTransferDomain {
+            pub domain: DomainId,
+        }
+    }
 }
 
 pub mod asset_definition {
@@ -103,6 +109,12 @@ pub mod account {
             pub account: AccountId,
         }
     }
+
+    permission! {
+        pub struct CanMessageAccount {
+            pub account: AccountId,
+        }
+    }
 }
 
 pub mod asset {
@@ -126,6 +138,12 @@ pub mod asset {
         }
     }
 
+    permission! {
+        pub struct CanFreezeAssetWithDefinition {
+            pub asset_definition: AssetDefinitionId,
+        }
+    }
+
     permission! {
         pub struct CanMintAsset {
             pub asset: AssetId,
@@ -138,6 +156,12 @@ pub mod asset {
         }
     }
 
+    permission! {
+        pub struct CanModifyAssetMetadata {
+            pub asset: AssetId,
+        }
+    }
+
     permission! {
         pub struct CanTransferAsset {
             pub asset: AssetId,
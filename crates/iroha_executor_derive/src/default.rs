@@ -117,11 +117,13 @@ pub fn impl_derive_visit(emitter: &mut Emitter, input: &syn::DeriveInput) -> Tok
         "fn visit_unregister_peer(operation: &Unregister<Peer>)",
         "fn visit_register_domain(operation: &Register<Domain>)",
         "fn visit_unregister_domain(operation: &Unregister<Domain>)",
+        "fn visit_purge_domain(operation: &Purge<Domain>)",
         "fn visit_transfer_domain(operation: &Transfer<Account, DomainId, Account>)",
         "fn visit_set_domain_key_value(operation: &SetKeyValue<Domain>)",
         "fn visit_remove_domain_key_value(operation: &RemoveKeyValue<Domain>)",
         "fn visit_register_account(operation: &Register<Account>)",
         "fn visit_unregister_account(operation: &Unregister<Account>)",
+        "fn visit_purge_account(operation: &Purge<Account>)",
         "fn visit_set_account_key_value(operation: &SetKeyValue<Account>)",
         "fn visit_remove_account_key_value(operation: &RemoveKeyValue<Account>)",
         "fn visit_register_nft(operation: &Register<Nft>)",
@@ -129,6 +131,10 @@ pub fn impl_derive_visit(emitter: &mut Emitter, input: &syn::DeriveInput) -> Tok
         "fn visit_mint_asset_numeric(operation: &Mint<Numeric, Asset>)",
         "fn visit_burn_asset_numeric(operation: &Burn<Numeric, Asset>)",
         "fn visit_transfer_asset_numeric(operation: &Transfer<Asset, Numeric, Account>)",
+        "fn visit_split(operation: &Split)",
+        "fn visit_merge(operation: &Merge)",
+        "fn visit_set_asset_key_value(operation: &SetKeyValue<Asset>)",
+        "fn visit_remove_asset_key_value(operation: &RemoveKeyValue<Asset>)",
         "fn visit_transfer_nft(operation: &Transfer<Account, NftId, Account>)",
         "fn visit_set_nft_key_value(operation: &SetKeyValue<Nft>)",
         "fn visit_remove_nft_key_value(operation: &RemoveKeyValue<Nft>)",
@@ -155,7 +161,31 @@ pub fn impl_derive_visit(emitter: &mut Emitter, input: &syn::DeriveInput) -> Tok
         "fn visit_set_parameter(operation: &SetParameter)",
         "fn visit_upgrade(operation: &Upgrade)",
         "fn visit_log(operation: &Log)",
+        "fn visit_subscribe(operation: &Subscribe)",
+        "fn visit_unsubscribe(operation: &Unsubscribe)",
+        "fn visit_retry_dead_letter(operation: &RetryDeadLetter)",
+        "fn visit_discard_dead_letter(operation: &DiscardDeadLetter)",
+        "fn visit_deprecate_asset_definition(operation: &Deprecate<AssetDefinition>)",
+        "fn visit_delegate(operation: &Delegate)",
+        "fn visit_tagged(operation: &Tagged)",
+        "fn visit_onboard(operation: &Onboard)",
+        "fn visit_payout(operation: &Payout)",
+        "fn visit_add_tag(operation: &AddTag)",
+        "fn visit_remove_tag(operation: &RemoveTag)",
+        "fn visit_freeze_asset(operation: &Freeze<Asset>)",
+        "fn visit_unfreeze_asset(operation: &Unfreeze<Asset>)",
+        "fn visit_freeze_asset_definition(operation: &Freeze<AssetDefinition>)",
+        "fn visit_unfreeze_asset_definition(operation: &Unfreeze<AssetDefinition>)",
+        "fn visit_approve(operation: &Approve)",
+        "fn visit_transfer_from(operation: &TransferFrom)",
+        "fn visit_message(operation: &Message)",
+        "fn visit_convert(operation: &Convert)",
+        "fn visit_create_escrow(operation: &CreateEscrow)",
+        "fn visit_claim_escrow(operation: &ClaimEscrow)",
+        "fn visit_refund_escrow(operation: &RefundEscrow)",
+        "fn visit_transfer_many(operation: &TransferMany)",
         "fn visit_custom_instruction(operation: &CustomInstruction)",
+        "fn visit_find_dead_letters(operation: &FindDeadLetters)",
     ]
     .into_iter()
     .map(|item| {
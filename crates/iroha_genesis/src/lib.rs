@@ -4,14 +4,19 @@ use std::{
     fmt::Debug,
     fs::{self, File},
     io::BufReader,
+    num::NonZeroU64,
     path::{Path, PathBuf},
     sync::LazyLock,
 };
 
 use derive_more::Constructor;
 use eyre::{eyre, Result, WrapErr};
-use iroha_crypto::KeyPair;
-use iroha_data_model::{block::SignedBlock, parameter::Parameter, prelude::*};
+use iroha_crypto::{Hash, HashOf, KeyPair};
+use iroha_data_model::{
+    block::{BlockHeader, SignedBlock},
+    parameter::Parameter,
+    prelude::*,
+};
 use iroha_schema::IntoSchema;
 use parity_scale_codec::{Decode, Encode};
 use serde::{Deserialize, Serialize};
@@ -19,6 +24,9 @@ use serde::{Deserialize, Serialize};
 /// Domain of the genesis account, technically required for the pre-genesis state
 pub static GENESIS_DOMAIN_ID: LazyLock<DomainId> = LazyLock::new(|| "genesis".parse().unwrap());
 
+/// Key of the metadata of the first genesis transaction under which the [`GenesisAnchor`] is recorded
+pub static GENESIS_ANCHOR_KEY: LazyLock<Name> = LazyLock::new(|| "genesis_anchor".parse().unwrap());
+
 /// Genesis block.
 ///
 /// First transaction must contain single [`Upgrade`] instruction to set executor.
@@ -48,6 +56,34 @@ pub struct RawGenesisTransaction {
     wasm_triggers: Vec<GenesisWasmTrigger>,
     /// Initial topology
     topology: Vec<PeerId>,
+    /// State of another chain imported by the genesis, see [`GenesisAnchor`]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    anchor: Option<GenesisAnchor>,
+}
+
+/// Reference to the state of an existing chain which the genesis imports.
+///
+/// Used to migrate to a new chain when the parameters must change incompatibly:
+/// the instructions of the genesis recreate the state of the source chain at `block_height`,
+/// and `state_hash` commits to them, so that the peers of both chains can check what was imported.
+/// See `kagami genesis migrate`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, IntoSchema, Encode, Decode)]
+pub struct GenesisAnchor {
+    /// Id of the chain whose state is imported
+    pub source_chain: ChainId,
+    /// Height of the last block of the source chain reflected in the imported state
+    pub block_height: NonZeroU64,
+    /// Hash of the block at `block_height`
+    pub block_hash: HashOf<BlockHeader>,
+    /// Hash of the instructions importing the state, see [`GenesisAnchor::state_hash_of`]
+    pub state_hash: Hash,
+}
+
+impl GenesisAnchor {
+    /// Hash of the state snapshot represented by `instructions`
+    pub fn state_hash_of(instructions: &[InstructionBox]) -> Hash {
+        Hash::new(instructions.encode())
+    }
 }
 
 /// Path to `*.wasm` file or their directory
@@ -114,24 +150,44 @@ impl RawGenesisTransaction {
             wasm_dir: self.wasm_dir.0,
             wasm_triggers: self.wasm_triggers,
             topology: self.topology,
+            anchor: self.anchor,
         }
     }
 
+    /// State of another chain imported by the genesis, if any
+    pub fn anchor(&self) -> Option<&GenesisAnchor> {
+        self.anchor.as_ref()
+    }
+
     /// Build and sign genesis block.
     ///
     /// # Errors
     ///
-    /// Fails if `RawGenesisTransaction::parse` fails.
+    /// - `RawGenesisTransaction::parse` fails
+    /// - instructions don't match the state hash of the [`GenesisAnchor`]
     pub fn build_and_sign(self, genesis_key_pair: &KeyPair) -> Result<GenesisBlock> {
         let chain = self.chain.clone();
         let genesis_account = AccountId::new(
             GENESIS_DOMAIN_ID.clone(),
             genesis_key_pair.public_key().clone(),
         );
+        let mut metadata = Metadata::default();
+        if let Some(anchor) = &self.anchor {
+            let state_hash = GenesisAnchor::state_hash_of(&self.instructions);
+            if state_hash != anchor.state_hash {
+                return Err(eyre!(
+                    "genesis instructions don't match the anchored state: expected hash {}, got {state_hash}",
+                    anchor.state_hash
+                ));
+            }
+            metadata.insert(GENESIS_ANCHOR_KEY.clone(), Json::new(anchor));
+        }
         let mut transactions = vec![];
         for instructions in self.parse()? {
+            // The anchor is recorded only once, in the first transaction
             let transaction = TransactionBuilder::new(chain.clone(), genesis_account.clone())
                 .with_instructions(instructions)
+                .with_metadata(core::mem::take(&mut metadata))
                 .sign(genesis_key_pair.private_key());
             transactions.push(transaction);
         }
@@ -202,6 +258,7 @@ pub struct GenesisBuilder {
     wasm_dir: PathBuf,
     wasm_triggers: Vec<GenesisWasmTrigger>,
     topology: Vec<PeerId>,
+    anchor: Option<GenesisAnchor>,
 }
 
 /// Domain editing mode of the [`GenesisBuilder`] to register accounts and assets under the domain.
@@ -214,6 +271,7 @@ pub struct GenesisDomainBuilder {
     wasm_dir: PathBuf,
     wasm_triggers: Vec<GenesisWasmTrigger>,
     topology: Vec<PeerId>,
+    anchor: Option<GenesisAnchor>,
     domain_id: DomainId,
 }
 
@@ -228,6 +286,7 @@ impl GenesisBuilder {
             wasm_dir: wasm_dir.into(),
             wasm_triggers: Vec::new(),
             topology: Vec::new(),
+            anchor: None,
         }
     }
 
@@ -255,6 +314,7 @@ impl GenesisBuilder {
             wasm_dir: self.wasm_dir,
             wasm_triggers: self.wasm_triggers,
             topology: self.topology,
+            anchor: self.anchor,
             domain_id,
         }
     }
@@ -283,6 +343,12 @@ impl GenesisBuilder {
         self
     }
 
+    /// Record that the instructions import the state of another chain.
+    pub fn set_anchor(mut self, anchor: GenesisAnchor) -> Self {
+        self.anchor = Some(anchor);
+        self
+    }
+
     /// Finish building, sign, and produce a [`GenesisBlock`].
     ///
     /// # Errors
@@ -305,6 +371,7 @@ impl GenesisBuilder {
             wasm_dir: self.wasm_dir.into(),
             wasm_triggers: self.wasm_triggers,
             topology: self.topology,
+            anchor: self.anchor,
         }
     }
 }
@@ -320,6 +387,7 @@ impl GenesisDomainBuilder {
             wasm_dir: self.wasm_dir,
             wasm_triggers: self.wasm_triggers,
             topology: self.topology,
+            anchor: self.anchor,
         }
     }
 
@@ -477,6 +545,58 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn anchor_is_recorded_in_first_transaction() -> Result<()> {
+        let (_tmp_dir, builder) = test_builder();
+        let builder = builder
+            .domain("wonderland".parse()?)
+            .account(ALICE_KEYPAIR.public_key().clone())
+            .finish_domain();
+        let anchor = GenesisAnchor {
+            source_chain: ChainId::from("old"),
+            block_height: NonZeroU64::new(42).unwrap(),
+            block_hash: HashOf::from_untyped_unchecked(Hash::prehashed([1; Hash::LENGTH])),
+            state_hash: GenesisAnchor::state_hash_of(&builder.instructions),
+        };
+
+        let genesis = builder
+            .set_anchor(anchor.clone())
+            .build_and_sign(&KeyPair::random())?;
+
+        let transactions = genesis.0.external_transactions().collect::<Vec<_>>();
+        let recorded = transactions[0]
+            .metadata()
+            .get(&*GENESIS_ANCHOR_KEY)
+            .expect("anchor should be recorded")
+            .try_into_any::<GenesisAnchor>()?;
+        assert_eq!(recorded, anchor);
+        assert!(transactions[1..]
+            .iter()
+            .all(|tx| tx.metadata().get(&*GENESIS_ANCHOR_KEY).is_none()));
+
+        Ok(())
+    }
+
+    #[test]
+    fn anchor_rejects_modified_instructions() -> Result<()> {
+        let (_tmp_dir, builder) = test_builder();
+        let anchor = GenesisAnchor {
+            source_chain: ChainId::from("old"),
+            block_height: NonZeroU64::new(42).unwrap(),
+            block_hash: HashOf::from_untyped_unchecked(Hash::prehashed([1; Hash::LENGTH])),
+            state_hash: GenesisAnchor::state_hash_of(&[]),
+        };
+
+        let result = builder
+            .set_anchor(anchor)
+            .domain("wonderland".parse()?)
+            .finish_domain()
+            .build_and_sign(&KeyPair::random());
+        assert!(result.is_err());
+
+        Ok(())
+    }
+
     #[test]
     #[allow(clippy::too_many_lines)]
     fn genesis_block_builder_example() -> Result<()> {
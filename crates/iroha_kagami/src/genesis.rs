@@ -5,12 +5,14 @@ use clap::Subcommand;
 use crate::{Outcome, RunArgs};
 
 mod generate;
+mod migrate;
 mod sign;
 
 #[derive(Debug, Clone, Subcommand)]
 pub enum Args {
     Sign(sign::Args),
     Generate(generate::Args),
+    Migrate(migrate::Args),
 }
 
 impl<T: Write> RunArgs<T> for Args {
@@ -18,6 +20,7 @@ impl<T: Write> RunArgs<T> for Args {
         match self {
             Args::Sign(args) => args.run(writer),
             Args::Generate(args) => args.run(writer),
+            Args::Migrate(args) => args.run(writer),
         }
     }
 }
@@ -0,0 +1,122 @@
+use std::{
+    io::{BufWriter, Write},
+    num::{NonZeroU64, NonZeroUsize},
+    path::PathBuf,
+    sync::Arc,
+};
+
+use clap::Parser;
+use color_eyre::eyre::{eyre, WrapErr as _};
+use iroha_config::{
+    base::WithOrigin,
+    kura::{FsyncMode, InitMode, StoreMode},
+    parameters::{actual, defaults},
+};
+use iroha_core::{
+    kura::Kura,
+    migration::export_world,
+    query::store::{LiveQueryStore, LiveQueryStoreHandle},
+    replay::rebuild_state,
+    state::{StateReadOnly, WorldReadOnly},
+};
+use iroha_data_model::prelude::*;
+use iroha_futures::supervisor::ShutdownSignal;
+use iroha_genesis::{GenesisAnchor, GenesisBuilder, GENESIS_DOMAIN_ID};
+
+use crate::{Outcome, RunArgs};
+
+/// Generate a genesis of a new chain importing the state of an existing one, in JSON format
+///
+/// The state is rebuilt from the blocks of the existing chain and recreated by the genesis instructions,
+/// whose hash is recorded in the genesis along with the last imported block.
+/// Parameters are copied as well and can be edited before signing.
+/// Triggers are not imported.
+#[derive(Parser, Debug, Clone)]
+pub struct Args {
+    /// Path to the block store of the existing chain
+    store_dir: PathBuf,
+    /// Height of the last block to import (the latest one by default)
+    #[clap(long)]
+    height: Option<NonZeroUsize>,
+    /// Unique id of the new chain
+    #[clap(long)]
+    chain: ChainId,
+    /// Relative path from the directory of output file to the executor.wasm file
+    #[clap(long, value_name = "PATH")]
+    executor: PathBuf,
+    /// Relative path from the directory of output file to the directory that contains *.wasm libraries
+    #[clap(long, value_name = "PATH")]
+    wasm_dir: PathBuf,
+    #[clap(long, value_name = "MULTI_HASH")]
+    genesis_public_key: PublicKey,
+}
+
+impl<T: Write> RunArgs<T> for Args {
+    fn run(self, writer: &mut BufWriter<T>) -> Outcome {
+        let (kura, block_count) = Kura::new(&actual::Kura {
+            init_mode: InitMode::Strict,
+            store_mode: StoreMode::Disk,
+            store_dir: WithOrigin::inline(self.store_dir.clone()),
+            blocks_in_memory: defaults::kura::BLOCKS_IN_MEMORY,
+            // The block store is only read
+            fsync: FsyncMode::Never,
+            fsync_period: defaults::kura::FSYNC_PERIOD,
+            write_buffer_size: defaults::kura::WRITE_BUFFER_SIZE,
+            debug_output_new_blocks: false,
+        })
+        .wrap_err_with(|| format!("failed to open block store at {}", self.store_dir.display()))?;
+        let height = self
+            .height
+            .or_else(|| NonZeroUsize::new(block_count.0))
+            .ok_or_else(|| eyre!("block store is empty"))?;
+        if height.get() > block_count.0 {
+            return Err(eyre!(
+                "block store has only {} blocks, can't import up to {height}",
+                block_count.0
+            ));
+        }
+
+        let query_handle = LiveQueryStoreHandle::new(Arc::new(LiveQueryStore::from_config(
+            actual::LiveQueryStore::default(),
+            ShutdownSignal::new(),
+        )));
+        let state = rebuild_state(&kura, query_handle, height.get())?;
+        let view = state.view();
+
+        let source_chain = view
+            .all_blocks(NonZeroUsize::MIN)
+            .next()
+            .and_then(|genesis| {
+                genesis
+                    .external_transactions()
+                    .next()
+                    .map(|tx| tx.chain().clone())
+            })
+            .ok_or_else(|| eyre!("genesis block has no transactions"))?;
+        let block_hash = view
+            .latest_block_hash()
+            .expect("at least one block is imported");
+
+        let genesis_account = AccountId::new(GENESIS_DOMAIN_ID.clone(), self.genesis_public_key);
+        let instructions = export_world(&view.world, &genesis_account);
+        let anchor = GenesisAnchor {
+            source_chain,
+            block_height: NonZeroU64::try_from(height).expect("height fits into u64"),
+            block_hash,
+            state_hash: GenesisAnchor::state_hash_of(&instructions),
+        };
+
+        let builder = view.world.parameters().parameters().fold(
+            GenesisBuilder::new(self.chain, self.executor, self.wasm_dir),
+            GenesisBuilder::append_parameter,
+        );
+        let genesis = instructions
+            .into_iter()
+            .fold(builder, GenesisBuilder::append_instruction)
+            .set_anchor(anchor)
+            .build_raw();
+
+        writeln!(writer, "{}", serde_json::to_string_pretty(&genesis)?)
+            .wrap_err("failed to write serialized genesis to the buffer")
+    }
+}
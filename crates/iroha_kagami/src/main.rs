@@ -11,8 +11,11 @@ mod codec;
 mod crypto;
 mod genesis;
 mod kura;
+mod query;
+mod repl;
 mod schema;
 mod swarm;
+mod tx;
 mod wasm;
 
 /// Outcome shorthand used throughout this crate
@@ -55,6 +58,14 @@ enum Args {
     /// Commands related to building wasm smartcontracts
     #[clap(subcommand)]
     Wasm(wasm::Args),
+    /// Commands related to transaction inspection
+    #[clap(subcommand)]
+    Tx(tx::Args),
+    /// Commands related to querying a peer
+    #[clap(subcommand)]
+    Query(query::Args),
+    /// Interactive shell exploring the ledger of a peer
+    Repl(repl::Args),
     /// Output CLI documentation in Markdown format
     MarkdownHelp(MarkdownHelp),
 }
@@ -71,6 +82,9 @@ impl<T: Write> RunArgs<T> for Args {
             Kura(args) => args.run(writer),
             Swarm(args) => args.run(writer),
             Wasm(args) => args.run(writer),
+            Tx(args) => args.run(writer),
+            Query(args) => args.run(writer),
+            Repl(args) => args.run(writer),
             MarkdownHelp(args) => args.run(writer),
         }
     }
@@ -165,10 +179,25 @@ mod tests {
         .is_err())
     }
 
+    #[test]
+    fn query_command() {
+        parse("kagami query run --file query.json").unwrap();
+        parse("kagami query run --file query.scale --input scale --output json").unwrap();
+        assert!(parse("kagami query run --file query.json --output yaml").is_err());
+    }
+
+    #[test]
+    fn repl_command() {
+        parse("kagami repl").unwrap();
+        parse("kagami repl --config client.toml").unwrap();
+    }
+
     #[test]
     fn wasm_command() {
         parse("kagami wasm build ./test --out-file 1").unwrap();
         parse("kagami wasm check ./test").unwrap();
+        parse("kagami wasm build ./test --out-file 1 --format json").unwrap();
+        assert!(parse("kagami wasm check ./test --format yaml").is_err());
 
         let Args::Wasm(wasm::Args::Check { common, .. }) = Args::try_parse_from([
             "kagami",
@@ -192,4 +221,10 @@ mod tests {
         ])
         .unwrap();
     }
+
+    #[test]
+    fn tx_command() {
+        parse("kagami tx inspect ./tx.scale").unwrap();
+        parse("kagami tx inspect ./tx.scale --json").unwrap();
+    }
 }
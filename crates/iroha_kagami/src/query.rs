@@ -0,0 +1,121 @@
+use std::{
+    fs,
+    io::{BufWriter, Write},
+    path::PathBuf,
+};
+
+use clap::{Args as ClapArgs, Subcommand, ValueEnum};
+use color_eyre::eyre::{eyre, WrapErr as _};
+use iroha::{
+    client::Client,
+    config::{Config, LoadPath},
+    data_model::query::{builder::QueryExecutor, AnyQueryBox},
+};
+use parity_scale_codec::{DecodeAll, Encode};
+use serde::Serialize;
+
+use crate::{Outcome, RunArgs};
+
+#[derive(Debug, Clone, Subcommand)]
+pub enum Args {
+    /// Execute a query read from a file against a peer.
+    /// Prints `SingularQueryOutputBox` for singular queries
+    /// and `QueryOutputBatchBoxTuple` with all the fetched batches for iterable ones
+    Run(RunQueryArgs),
+}
+
+#[derive(Debug, Clone, ClapArgs)]
+pub struct RunQueryArgs {
+    /// Path to the query, `AnyQueryBox` as described by the schema
+    #[clap(long)]
+    file: PathBuf,
+    /// Format of the query file
+    #[clap(long, value_enum, default_value_t = Format::Json)]
+    input: Format,
+    /// Format of the query output
+    #[clap(long, value_enum, default_value_t = Format::Json)]
+    output: Format,
+    /// Path to the configuration of the client to query the peer with
+    #[clap(short, long, default_value = "client.toml")]
+    config: PathBuf,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub(crate) enum Format {
+    Json,
+    Scale,
+}
+
+impl<T: Write> RunArgs<T> for Args {
+    fn run(self, writer: &mut BufWriter<T>) -> Outcome {
+        match self {
+            Args::Run(args) => args.run(writer),
+        }
+    }
+}
+
+impl<T: Write> RunArgs<T> for RunQueryArgs {
+    fn run(self, writer: &mut BufWriter<T>) -> Outcome {
+        let bytes = fs::read(&self.file)
+            .wrap_err_with(|| format!("failed to read {}", self.file.display()))?;
+        let query: AnyQueryBox = match self.input {
+            Format::Json => {
+                serde_json::from_slice(&bytes).wrap_err("failed to decode the query from JSON")?
+            }
+            Format::Scale => AnyQueryBox::decode_all(&mut bytes.as_slice())
+                .wrap_err("failed to decode the query from SCALE")?,
+        };
+        let config = Config::load(LoadPath::Explicit(&self.config))
+            .map_err(|report| eyre!("{report:?}"))
+            .wrap_err("failed to load the client configuration")?;
+        let client = Client::new(config);
+
+        execute(&client, query, writer, self.output)
+    }
+}
+
+/// Execute the `query` against the peer, fetching all the batches of iterable ones,
+/// and write its output in the `format`
+pub(crate) fn execute<T: Write>(
+    client: &Client,
+    query: AnyQueryBox,
+    writer: &mut BufWriter<T>,
+    format: Format,
+) -> Outcome {
+    match query {
+        AnyQueryBox::Singular(query) => {
+            let output = client
+                .query_single(query)
+                .wrap_err("failed to execute the query")?;
+            write_output(writer, format, &output)
+        }
+        AnyQueryBox::Iterable(query) => {
+            let (mut output, _remaining_items, mut continue_cursor) = client
+                .start_query(query)
+                .wrap_err("failed to execute the query")?;
+            while let Some(cursor) = continue_cursor {
+                let (batch, _remaining_items, next_cursor) =
+                    <Client as QueryExecutor>::continue_query(cursor)
+                        .wrap_err("failed to fetch the next batch")?;
+                output.extend(batch);
+                continue_cursor = next_cursor;
+            }
+            write_output(writer, format, &output)
+        }
+    }
+}
+
+pub(crate) fn write_output<T: Write>(
+    writer: &mut BufWriter<T>,
+    format: Format,
+    output: &(impl Serialize + Encode),
+) -> Outcome {
+    match format {
+        Format::Json => {
+            serde_json::to_writer_pretty(&mut *writer, output)?;
+            writeln!(writer)?;
+        }
+        Format::Scale => writer.write_all(&output.encode())?,
+    }
+    Ok(())
+}
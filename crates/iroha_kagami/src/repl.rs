@@ -0,0 +1,417 @@
+//! Interactive shell exploring the ledger of a peer.
+//!
+//! Every line is a command, see [`HELP`]. Listing commands take an optional filter,
+//! a `CompoundPredicate` of the query DSL as JSON, the same way `iroha <entity> list filter` does.
+//! Ids of the domains, accounts and asset definitions are fetched from the peer and completed with `Tab`.
+
+use std::{
+    io::{BufWriter, Write},
+    path::PathBuf,
+    str::FromStr,
+    sync::Arc,
+};
+
+use clap::Args as ClapArgs;
+use color_eyre::eyre::{eyre, WrapErr as _};
+use inquire::{
+    autocompletion::{Autocomplete, Replacement},
+    CustomUserError, InquireError, Text,
+};
+use iroha::{
+    client::Client,
+    config::{Config, LoadPath},
+    data_model::{
+        prelude::*,
+        query::{dsl::CompoundPredicate, AnyQueryBox},
+    },
+};
+use owo_colors::OwoColorize as _;
+use serde::de::DeserializeOwned;
+
+use crate::{
+    query::{self, Format},
+    Outcome, RunArgs,
+};
+
+#[derive(Debug, Clone, ClapArgs)]
+pub struct Args {
+    /// Path to the configuration of the client to connect to the peer with.
+    /// Instructions are submitted on behalf of its account, signed with its key
+    #[clap(short, long, default_value = "client.toml")]
+    config: PathBuf,
+}
+
+const HELP: &str = "\
+domains [FILTER]        list the domains
+accounts [FILTER]       list the accounts
+definitions [FILTER]    list the asset definitions
+assets [FILTER]         list the assets
+nfts [FILTER]           list the NFTs
+peers                   list the peers
+parameters              show the parameters of the chain
+query QUERY             execute a query, `AnyQueryBox` as JSON
+submit INSTRUCTIONS     submit an instruction or an array of them as JSON and wait for the commit
+refresh                 fetch the ids completed with <Tab> again
+help                    show this message
+exit                    leave the shell
+
+FILTER is a `CompoundPredicate` on the listed entities as JSON";
+
+/// Names of the commands, completed at the start of the line
+const COMMANDS: [&str; 12] = [
+    "domains",
+    "accounts",
+    "definitions",
+    "assets",
+    "nfts",
+    "peers",
+    "parameters",
+    "query",
+    "submit",
+    "refresh",
+    "help",
+    "exit",
+];
+
+/// Single line entered into the shell
+#[derive(Debug)]
+enum Command {
+    Domains(CompoundPredicate<Domain>),
+    Accounts(CompoundPredicate<Account>),
+    AssetDefinitions(CompoundPredicate<AssetDefinition>),
+    Assets(CompoundPredicate<Asset>),
+    Nfts(CompoundPredicate<Nft>),
+    Peers,
+    Parameters,
+    Query(Box<AnyQueryBox>),
+    Submit(Vec<InstructionBox>),
+    Refresh,
+    Help,
+    Exit,
+}
+
+impl FromStr for Command {
+    type Err = color_eyre::Report;
+
+    fn from_str(line: &str) -> Result<Self, Self::Err> {
+        let line = line.trim();
+        let (command, argument) = line
+            .split_once(char::is_whitespace)
+            .map_or((line, ""), |(command, argument)| (command, argument.trim()));
+        let no_argument = |command: Self| {
+            if argument.is_empty() {
+                Ok(command)
+            } else {
+                Err(eyre!("`{line}` doesn't take an argument"))
+            }
+        };
+
+        match command {
+            "domains" => Ok(Self::Domains(filter(argument)?)),
+            "accounts" => Ok(Self::Accounts(filter(argument)?)),
+            "definitions" => Ok(Self::AssetDefinitions(filter(argument)?)),
+            "assets" => Ok(Self::Assets(filter(argument)?)),
+            "nfts" => Ok(Self::Nfts(filter(argument)?)),
+            "peers" => no_argument(Self::Peers),
+            "parameters" => no_argument(Self::Parameters),
+            "query" => Ok(Self::Query(Box::new(
+                serde_json::from_str(argument).wrap_err("failed to parse the query")?,
+            ))),
+            "submit" => {
+                let instructions = if argument.starts_with('[') {
+                    serde_json::from_str(argument)
+                } else {
+                    serde_json::from_str(argument).map(|instruction| vec![instruction])
+                };
+                Ok(Self::Submit(
+                    instructions.wrap_err("failed to parse the instructions")?,
+                ))
+            }
+            "refresh" => no_argument(Self::Refresh),
+            "help" => no_argument(Self::Help),
+            "exit" => no_argument(Self::Exit),
+            _ => Err(eyre!("unknown command `{command}`, see `help`")),
+        }
+    }
+}
+
+/// Filter of a listing command, which lists everything without one
+fn filter<T>(argument: &str) -> color_eyre::Result<CompoundPredicate<T>>
+where
+    CompoundPredicate<T>: DeserializeOwned,
+{
+    if argument.is_empty() {
+        return Ok(CompoundPredicate::PASS);
+    }
+    serde_json::from_str(argument).wrap_err("failed to parse the filter")
+}
+
+/// Completion of the commands and of the ids fetched from the peer.
+///
+/// The word under the cursor is completed: a command at the start of the line and an id after it,
+/// also inside of the JSON strings.
+#[derive(Debug, Clone, Default)]
+struct Completer {
+    ids: Arc<Vec<String>>,
+}
+
+impl Completer {
+    /// Fetch the ids of the domains, accounts and asset definitions from the peer
+    fn fetch(client: &Client) -> color_eyre::Result<Self> {
+        let mut ids = Vec::new();
+        ids.extend(
+            client
+                .query(FindDomains)
+                .select_with(|domain| domain.id)
+                .execute_all()?
+                .iter()
+                .map(ToString::to_string),
+        );
+        ids.extend(
+            client
+                .query(FindAccounts)
+                .select_with(|account| account.id)
+                .execute_all()?
+                .iter()
+                .map(ToString::to_string),
+        );
+        ids.extend(
+            client
+                .query(FindAssetsDefinitions)
+                .select_with(|definition| definition.id)
+                .execute_all()?
+                .iter()
+                .map(ToString::to_string),
+        );
+        ids.sort();
+
+        Ok(Self { ids: Arc::new(ids) })
+    }
+
+    /// Split the `input` into the completed line and the word under the cursor
+    fn split(input: &str) -> (&str, &str) {
+        let start = input
+            .rfind(|c: char| c.is_whitespace() || c == '"')
+            .map_or(0, |separator| separator + 1);
+        input.split_at(start)
+    }
+
+    fn candidates<'a>(&'a self, input: &'a str) -> impl Iterator<Item = &'a str> {
+        let (head, word) = Self::split(input);
+        let candidates: Box<dyn Iterator<Item = &'a str> + 'a> = if head.trim().is_empty() {
+            Box::new(COMMANDS.into_iter())
+        } else {
+            Box::new(self.ids.iter().map(String::as_str))
+        };
+        candidates.filter(move |candidate| candidate.starts_with(word))
+    }
+}
+
+impl Autocomplete for Completer {
+    fn get_suggestions(&mut self, input: &str) -> Result<Vec<String>, CustomUserError> {
+        Ok(self.candidates(input).map(ToOwned::to_owned).collect())
+    }
+
+    fn get_completion(
+        &mut self,
+        input: &str,
+        highlighted_suggestion: Option<String>,
+    ) -> Result<Replacement, CustomUserError> {
+        let (head, _) = Self::split(input);
+        if let Some(suggestion) = highlighted_suggestion {
+            return Ok(Some(format!("{head}{suggestion}")));
+        }
+        // Without a highlighted suggestion, the common prefix of the candidates is completed
+        let mut candidates = self.candidates(input);
+        let Some(first) = candidates.next() else {
+            return Ok(None);
+        };
+        let prefix = candidates.fold(first, |prefix, candidate| {
+            let common = prefix
+                .char_indices()
+                .zip(candidate.chars())
+                .find(|((_, a), b)| a != b)
+                .map_or(prefix.len().min(candidate.len()), |((index, _), _)| index);
+            &prefix[..common]
+        });
+
+        Ok(Some(format!("{head}{prefix}")))
+    }
+}
+
+impl<T: Write> RunArgs<T> for Args {
+    fn run(self, writer: &mut BufWriter<T>) -> Outcome {
+        let config = Config::load(LoadPath::Explicit(&self.config))
+            .map_err(|report| eyre!("{report:?}"))
+            .wrap_err("failed to load the client configuration")?;
+        let client = Client::new(config);
+
+        writeln!(
+            writer,
+            "Connected to {} as {}, type `help` for the commands",
+            client.torii_url.bold(),
+            client.account.bold()
+        )?;
+        let mut completer = refresh(&client, Completer::default(), writer)?;
+        writer.flush()?;
+
+        loop {
+            let line = match Text::new(">").with_autocomplete(completer.clone()).prompt() {
+                Ok(line) => line,
+                Err(InquireError::OperationCanceled | InquireError::OperationInterrupted) => break,
+                Err(error) => return Err(error.into()),
+            };
+            if line.trim().is_empty() {
+                continue;
+            }
+
+            match line.parse::<Command>() {
+                Ok(Command::Exit) => break,
+                Ok(Command::Refresh) => completer = refresh(&client, completer, writer)?,
+                Ok(Command::Help) => writeln!(writer, "{HELP}")?,
+                Ok(Command::Submit(instructions)) => {
+                    match client.submit_all_blocking(instructions) {
+                        Ok(hash) => {
+                            writeln!(writer, "Committed transaction {}", hash.green())?;
+                            // Entities registered by the transaction are completed right away
+                            completer = refresh(&client, completer, writer)?;
+                        }
+                        Err(error) => writeln!(writer, "{}", format!("{error:?}").red())?,
+                    }
+                }
+                Ok(command) => {
+                    if let Err(error) = execute(&client, command, writer) {
+                        writeln!(writer, "{}", format!("{error:?}").red())?;
+                    }
+                }
+                Err(error) => writeln!(writer, "{}", format!("{error:?}").red())?,
+            }
+            writer.flush()?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Fetch the ids completed in the shell again, keeping the `previous` ones if the peer can't be reached
+fn refresh<T: Write>(
+    client: &Client,
+    previous: Completer,
+    writer: &mut BufWriter<T>,
+) -> color_eyre::Result<Completer> {
+    match Completer::fetch(client) {
+        Ok(completer) => Ok(completer),
+        Err(error) => {
+            writeln!(
+                writer,
+                "{}",
+                format!("failed to fetch the ids to complete: {error}").yellow()
+            )?;
+            Ok(previous)
+        }
+    }
+}
+
+/// Execute a query `command`, pretty-printing its output
+fn execute<T: Write>(client: &Client, command: Command, writer: &mut BufWriter<T>) -> Outcome {
+    match command {
+        Command::Domains(filter) => {
+            let output = client.query(FindDomains).filter(filter).execute_all()?;
+            query::write_output(writer, Format::Json, &output)
+        }
+        Command::Accounts(filter) => {
+            let output = client.query(FindAccounts).filter(filter).execute_all()?;
+            query::write_output(writer, Format::Json, &output)
+        }
+        Command::AssetDefinitions(filter) => {
+            let output = client
+                .query(FindAssetsDefinitions)
+                .filter(filter)
+                .execute_all()?;
+            query::write_output(writer, Format::Json, &output)
+        }
+        Command::Assets(filter) => {
+            let output = client.query(FindAssets).filter(filter).execute_all()?;
+            query::write_output(writer, Format::Json, &output)
+        }
+        Command::Nfts(filter) => {
+            let output = client.query(FindNfts).filter(filter).execute_all()?;
+            query::write_output(writer, Format::Json, &output)
+        }
+        Command::Peers => {
+            let output = client.query(FindPeers).execute_all()?;
+            query::write_output(writer, Format::Json, &output)
+        }
+        Command::Parameters => {
+            let output = client.query_single(FindParameters)?;
+            query::write_output(writer, Format::Json, &output)
+        }
+        Command::Query(query) => query::execute(client, *query, writer, Format::Json),
+        Command::Submit(_) | Command::Refresh | Command::Help | Command::Exit => {
+            unreachable!("handled by the shell")
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_commands() {
+        let Command::Domains(filter) = "domains".parse().unwrap() else {
+            panic!("should be parsed as `domains`")
+        };
+        assert_eq!(filter, CompoundPredicate::PASS);
+        assert!(matches!(
+            "  peers ".parse::<Command>().unwrap(),
+            Command::Peers
+        ));
+        "peers wonderland"
+            .parse::<Command>()
+            .expect_err("peers take no argument");
+        "accounts {".parse::<Command>().expect_err("invalid filter");
+        "frobnicate"
+            .parse::<Command>()
+            .expect_err("unknown command");
+    }
+
+    #[test]
+    fn completes_commands_and_ids() {
+        let mut completer = Completer {
+            ids: Arc::new(vec![
+                "rose#wonderland".to_owned(),
+                "wonderland".to_owned(),
+                "wood".to_owned(),
+            ]),
+        };
+
+        assert_eq!(
+            completer.get_suggestions("a").unwrap(),
+            ["accounts", "assets"]
+        );
+        assert_eq!(
+            completer.get_completion("a", None).unwrap().as_deref(),
+            Some("a")
+        );
+        assert_eq!(
+            completer.get_completion("as", None).unwrap().as_deref(),
+            Some("assets")
+        );
+        assert_eq!(
+            completer
+                .get_completion("accounts {\"w", None)
+                .unwrap()
+                .as_deref(),
+            Some("accounts {\"wo")
+        );
+        assert_eq!(
+            completer
+                .get_completion("accounts wo", Some("wonderland".to_owned()))
+                .unwrap()
+                .as_deref(),
+            Some("accounts wonderland")
+        );
+        assert!(completer.get_suggestions("exit x").unwrap().is_empty());
+    }
+}
@@ -0,0 +1,56 @@
+use std::{
+    fs,
+    io::{BufWriter, Write},
+    path::PathBuf,
+};
+
+use clap::{Args as ClapArgs, Subcommand};
+use color_eyre::eyre::WrapErr as _;
+use iroha_data_model::transaction::{inspection::TransactionInspection, SignedTransaction};
+use iroha_version::scale::DecodeVersioned;
+use parity_scale_codec::DecodeAll;
+
+use crate::{Outcome, RunArgs};
+
+#[derive(Debug, Clone, Subcommand)]
+pub enum Args {
+    /// Render a SCALE-encoded signed transaction in a human-readable form:
+    /// authority, chain, instructions and validity of the signature
+    Inspect(InspectArgs),
+}
+
+#[derive(Debug, Clone, ClapArgs)]
+pub struct InspectArgs {
+    /// Path to the SCALE-encoded transaction, either versioned (as submitted to Torii) or bare
+    path: PathBuf,
+    /// Output structured JSON instead of text
+    #[clap(long)]
+    json: bool,
+}
+
+impl<T: Write> RunArgs<T> for Args {
+    fn run(self, writer: &mut BufWriter<T>) -> Outcome {
+        match self {
+            Args::Inspect(args) => args.run(writer),
+        }
+    }
+}
+
+impl<T: Write> RunArgs<T> for InspectArgs {
+    fn run(self, writer: &mut BufWriter<T>) -> Outcome {
+        let bytes = fs::read(&self.path)
+            .wrap_err_with(|| format!("failed to read {}", self.path.display()))?;
+        let transaction = SignedTransaction::decode_all_versioned(&bytes)
+            .or_else(|_| SignedTransaction::decode_all(&mut bytes.as_slice()))
+            .wrap_err("failed to decode signed transaction")?;
+        let inspection = TransactionInspection::from(&transaction);
+
+        if self.json {
+            serde_json::to_writer_pretty(&mut *writer, &inspection)?;
+            writeln!(writer)?;
+        } else {
+            writeln!(writer, "{inspection}")?;
+        }
+        Ok(())
+    }
+}
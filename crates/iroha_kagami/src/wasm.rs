@@ -4,10 +4,11 @@ use std::{
     str::FromStr,
 };
 
-use clap::{Args as ClapArgs, Subcommand};
+use clap::{Args as ClapArgs, Subcommand, ValueEnum};
 use color_eyre::eyre::{eyre, Context};
 use iroha_wasm_builder::{Builder, Profile};
 use owo_colors::OwoColorize;
+use serde::Serialize;
 
 use crate::{Outcome, RunArgs};
 
@@ -40,6 +41,36 @@ pub struct CommonArgs {
     /// Extra arguments to pass to `cargo`, e.g. `--locked`
     #[arg(long, require_equals(true), default_value = "")]
     pub(crate) cargo_args: CargoArgs,
+    /// Format of the result written to stdout. Progress and diagnostics go to stderr either way
+    #[arg(long, value_enum, default_value_t = Format::Human)]
+    pub(crate) format: Format,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum Format {
+    Human,
+    Json,
+}
+
+/// Result of `kagami wasm check --format json`
+#[derive(Debug, Serialize)]
+struct CheckReport {
+    warnings: Vec<String>,
+}
+
+/// Result of `kagami wasm build --format json`
+#[derive(Debug, Serialize)]
+struct BuildReport {
+    /// Where the output WASM is stored
+    artifact: PathBuf,
+    /// Hex-encoded sha256 digest of the artifact
+    sha256: String,
+    /// Size in bytes as built by `cargo`
+    unoptimized_size: u64,
+    /// Size in bytes after `wasm-opt`, if the profile is optimized
+    optimized_size: Option<u64>,
+    /// Rendered compiler warnings
+    warnings: Vec<String>,
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -57,22 +88,43 @@ impl<T: Write> RunArgs<T> for Args {
     fn run(self, writer: &mut BufWriter<T>) -> Outcome {
         match self {
             Args::Check {
-                common: CommonArgs { path, cargo_args },
+                common:
+                    CommonArgs {
+                        path,
+                        cargo_args,
+                        format,
+                    },
                 profile,
             } => {
-                let builder = Builder::new(&path, profile)
+                let mut builder = Builder::new(&path, profile)
                     .cargo_args(cargo_args.0)
                     .show_output();
-                builder.check()?;
+                if format == Format::Json {
+                    builder = builder.collect_warnings();
+                }
+                let warnings = builder.check()?;
+
+                if format == Format::Json {
+                    serde_json::to_writer_pretty(&mut *writer, &CheckReport { warnings })?;
+                    writeln!(writer)?;
+                }
             }
             Args::Build {
-                common: CommonArgs { path, cargo_args },
+                common:
+                    CommonArgs {
+                        path,
+                        cargo_args,
+                        format,
+                    },
                 out_file,
                 profile,
             } => {
-                let builder = Builder::new(&path, profile)
+                let mut builder = Builder::new(&path, profile)
                     .cargo_args(cargo_args.0)
                     .show_output();
+                if format == Format::Json {
+                    builder = builder.collect_warnings();
+                }
 
                 let output = {
                     // not showing the spinner here, cargo does a progress bar for us
@@ -81,6 +133,7 @@ impl<T: Write> RunArgs<T> for Args {
                         err => err?,
                     }
                 };
+                let unoptimized_size = output.size()?;
 
                 let output = if profile.is_optimized() {
                     let sp = if std::env::var("CI").is_err() {
@@ -119,11 +172,28 @@ impl<T: Write> RunArgs<T> for Args {
                     )
                 })?;
 
-                writeln!(
-                    writer,
-                    "✓ File is written into {}",
-                    out_file.display().green().bold()
-                )?;
+                match format {
+                    Format::Human => writeln!(
+                        writer,
+                        "✓ File is written into {}",
+                        out_file.display().green().bold()
+                    )?,
+                    Format::Json => {
+                        let report = BuildReport {
+                            sha256: output.sha256()?,
+                            unoptimized_size,
+                            optimized_size: if profile.is_optimized() {
+                                Some(output.size()?)
+                            } else {
+                                None
+                            },
+                            warnings: output.warnings().to_vec(),
+                            artifact: out_file,
+                        };
+                        serde_json::to_writer_pretty(&mut *writer, &report)?;
+                        writeln!(writer)?;
+                    }
+                }
             }
         }
 
@@ -1,6 +1,7 @@
 //! Iroha's logging utilities.
 pub mod actor;
 pub mod layer;
+pub mod slow_span;
 pub mod telemetry;
 
 use std::{
@@ -17,6 +18,7 @@ pub use iroha_config::{
     logger::{Format, Level},
     parameters::actual::{DevTelemetry as DevTelemetryConfig, Logger as Config},
 };
+use slow_span::SlowSpanLayer;
 use tracing::subscriber::set_global_default;
 pub use tracing::{
     debug, debug_span, error, error_span, info, info_span, instrument as log, trace, trace_span,
@@ -109,6 +111,7 @@ pub fn test_logger() -> LoggerHandle {
                     .ok()
                     .and_then(|raw| raw.parse().ok()),
                 format: Format::Pretty,
+                slow_span_threshold_ms: None,
             };
 
             init_global(InitConfig::new(config, true)).expect(
@@ -138,9 +141,14 @@ where
         tracing_subscriber::filter::EnvFilter::try_new(config.base.resolve_filter().to_string())
             .expect("INTERNAL BUG: Directives not valid");
     let (level_filter, level_filter_handle) = reload::Layer::new(level_filter);
+    let slow_span_layer = config
+        .base
+        .slow_span_threshold_ms
+        .map(|threshold| SlowSpanLayer::new(threshold.get()));
     let subscriber = Registry::default()
         .with(layer)
         .with(level_filter)
+        .with(slow_span_layer)
         .with(tracing_error::ErrorLayer::default());
 
     #[cfg(all(feature = "tokio-console", not(feature = "no-tokio-console")))]
@@ -0,0 +1,93 @@
+//! Layer reporting spans which stayed open for longer than a threshold.
+//!
+//! Spans are not logged by themselves, so the layer allows to find out what is slow in production
+//! without lowering the log level: only the spans exceeding the threshold are reported.
+use std::{
+    fmt::{Debug, Write as _},
+    time::{Duration, Instant},
+};
+
+use tracing::{
+    field::{Field, Visit},
+    span::{Attributes, Id, Record},
+    Subscriber,
+};
+use tracing_subscriber::{layer::Context, registry::LookupSpan, Layer};
+
+/// Target of the events reporting slow spans
+pub const TARGET: &str = "slow_span";
+
+/// Layer emitting a `WARN` event with the fields and the duration of each span open for longer than the threshold
+#[derive(Debug, Clone, Copy)]
+pub struct SlowSpanLayer {
+    threshold: Duration,
+}
+
+impl SlowSpanLayer {
+    /// Report the spans which stayed open for at least `threshold`
+    pub fn new(threshold: Duration) -> Self {
+        Self { threshold }
+    }
+}
+
+/// Stored in the extensions of each span
+struct Timing {
+    opened_at: Instant,
+    fields: String,
+}
+
+#[derive(Default)]
+struct FieldsVisitor(String);
+
+impl Visit for FieldsVisitor {
+    fn record_debug(&mut self, field: &Field, value: &dyn Debug) {
+        if !self.0.is_empty() {
+            self.0.push(' ');
+        }
+        let _ = write!(self.0, "{}={value:?}", field.name());
+    }
+}
+
+impl<S> Layer<S> for SlowSpanLayer
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+{
+    fn on_new_span(&self, attrs: &Attributes<'_>, id: &Id, ctx: Context<'_, S>) {
+        let Some(span) = ctx.span(id) else { return };
+        let mut visitor = FieldsVisitor::default();
+        attrs.record(&mut visitor);
+        span.extensions_mut().insert(Timing {
+            opened_at: Instant::now(),
+            fields: visitor.0,
+        });
+    }
+
+    fn on_record(&self, id: &Id, values: &Record<'_>, ctx: Context<'_, S>) {
+        let Some(span) = ctx.span(id) else { return };
+        let mut extensions = span.extensions_mut();
+        if let Some(timing) = extensions.get_mut::<Timing>() {
+            let mut visitor = FieldsVisitor(core::mem::take(&mut timing.fields));
+            values.record(&mut visitor);
+            timing.fields = visitor.0;
+        }
+    }
+
+    fn on_close(&self, id: Id, ctx: Context<'_, S>) {
+        let Some(span) = ctx.span(&id) else { return };
+        // NOTE: extensions must not be locked while the event is dispatched
+        let Some(Timing { opened_at, fields }) = span.extensions_mut().remove::<Timing>() else {
+            return;
+        };
+
+        let elapsed = opened_at.elapsed();
+        if elapsed >= self.threshold {
+            tracing::warn!(
+                target: TARGET,
+                span = span.name(),
+                %fields,
+                elapsed_ms = u64::try_from(elapsed.as_millis()).unwrap_or(u64::MAX),
+                "Slow span"
+            );
+        }
+    }
+}
@@ -0,0 +1,57 @@
+#![allow(missing_docs)]
+
+use std::{
+    sync::{Arc, Mutex},
+    thread,
+    time::Duration,
+};
+
+use iroha_logger::{info_span, slow_span};
+use tracing::{Event, Subscriber};
+use tracing_subscriber::{layer::Context, prelude::*, Layer, Registry};
+
+/// Collects the names of the spans reported as slow
+#[derive(Clone, Default)]
+struct Reported(Arc<Mutex<Vec<String>>>);
+
+impl<S: Subscriber> Layer<S> for Reported {
+    fn on_event(&self, event: &Event<'_>, _ctx: Context<'_, S>) {
+        struct SpanName(Option<String>);
+
+        impl tracing::field::Visit for SpanName {
+            fn record_str(&mut self, field: &tracing::field::Field, value: &str) {
+                if field.name() == "span" {
+                    self.0 = Some(value.to_owned());
+                }
+            }
+
+            fn record_debug(&mut self, _: &tracing::field::Field, _: &dyn std::fmt::Debug) {}
+        }
+
+        if event.metadata().target() == slow_span::TARGET {
+            let mut name = SpanName(None);
+            event.record(&mut name);
+            self.0.lock().unwrap().extend(name.0);
+        }
+    }
+}
+
+#[test]
+fn only_slow_spans_are_reported() {
+    let reported = Reported::default();
+    let subscriber = Registry::default()
+        .with(slow_span::SlowSpanLayer::new(Duration::from_millis(50)))
+        .with(reported.clone());
+
+    tracing::subscriber::with_default(subscriber, || {
+        {
+            let _span = info_span!("fast", tx = "fast_tx").entered();
+        }
+        {
+            let _span = info_span!("slow", tx = "slow_tx").entered();
+            thread::sleep(Duration::from_millis(60));
+        }
+    });
+
+    assert_eq!(*reported.0.lock().unwrap(), vec!["slow".to_owned()]);
+}
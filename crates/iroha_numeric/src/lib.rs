@@ -11,7 +11,7 @@ use core::str::FromStr;
 
 use derive_more::{Display, From};
 use parity_scale_codec::{Decode, Encode};
-use rust_decimal::{prelude::ToPrimitive, Decimal};
+use rust_decimal::{prelude::ToPrimitive, Decimal, RoundingStrategy};
 use serde::{Deserialize, Serialize};
 
 /// Decimal number with arbitrary precision and scale.
@@ -244,6 +244,21 @@ impl Numeric {
         Self { inner: self.inner }
     }
 
+    /// Returns a new `Decimal` number truncated to the given spec, i.e. rounded towards zero.
+    /// e.g. 6.59 -> 6.5 for the scale of 1
+    #[must_use]
+    pub fn trunc(&self, spec: NumericSpec) -> Self {
+        if let Some(scale) = spec.scale {
+            return Self {
+                inner: self
+                    .inner
+                    .round_dp_with_strategy(scale, RoundingStrategy::ToZero),
+            };
+        }
+
+        Self { inner: self.inner }
+    }
+
     /// Convert [`Numeric`] to [`f64`] with possible loss in precision
     pub fn to_f64(self) -> f64 {
         self.inner.to_f64().expect("never fails")
@@ -511,6 +526,15 @@ mod tests {
         assert_eq!(a.checked_add(b), Some(Numeric::new(1000, 2)));
     }
 
+    #[test]
+    fn check_trunc() {
+        let a = Numeric::new(659, 2);
+
+        assert_eq!(a.trunc(NumericSpec::fractional(1)), Numeric::new(65, 1));
+        assert_eq!(a.trunc(NumericSpec::integer()), Numeric::new(6, 0));
+        assert_eq!(a.trunc(NumericSpec::unconstrained()), a);
+    }
+
     #[test]
     fn check_serde() {
         let num1 = Numeric::new(1002, 2);
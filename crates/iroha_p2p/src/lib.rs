@@ -17,6 +17,7 @@ use thiserror::Error;
 
 pub mod network;
 pub mod peer;
+pub mod transport;
 
 /// The main type to use for secure communication.
 pub type NetworkHandle<T> = network::NetworkBaseHandle<T, X25519Sha256, ChaCha20Poly1305>;
@@ -61,6 +62,15 @@ pub enum Error {
     Addr(#[from] AddrParseError),
     /// Connection reset by peer in the middle of message transfer
     ConnectionResetByPeer,
+    /// QUIC transport failure
+    Quic(#[source] Box<dyn std::error::Error + Send + Sync>),
+}
+
+impl Error {
+    /// Wrap the error of the QUIC transport
+    pub(crate) fn quic(e: impl std::error::Error + Send + Sync + 'static) -> Self {
+        Self::Quic(Box::new(e))
+    }
 }
 
 impl From<io::Error> for Error {
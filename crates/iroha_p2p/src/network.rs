@@ -2,7 +2,6 @@
 use std::{
     collections::{HashMap, HashSet},
     fmt::Debug,
-    net::ToSocketAddrs,
     time::Duration,
 };
 
@@ -11,20 +10,18 @@ use iroha_config::parameters::actual::Network as Config;
 use iroha_crypto::KeyPair;
 use iroha_data_model::prelude::{Peer, PeerId};
 use iroha_futures::supervisor::{Child, OnShutdown, ShutdownSignal};
-use iroha_logger::prelude::*;
+use iroha_logger::{prelude::*, Instrument};
 use iroha_primitives::addr::SocketAddr;
-use tokio::{
-    net::{TcpListener, TcpStream},
-    sync::{mpsc, watch},
-};
+use tokio::sync::{mpsc, watch};
 
 use crate::{
     boilerplate::*,
     peer::{
         handles::{connected_from, connecting, PeerHandle},
         message::*,
-        Connection, ConnectionId,
+        ConnectionId,
     },
+    transport::{Dialer, Incoming, Listener},
     unbounded_with_len, Broadcast, Error, NetworkMessage, OnlinePeers, Post, UpdatePeers,
     UpdateTopology,
 };
@@ -40,6 +37,8 @@ pub struct NetworkBaseHandle<T: Pload, K: Kex, E: Enc> {
     subscribe_to_peers_messages_sender: mpsc::UnboundedSender<mpsc::Sender<PeerMessage<T>>>,
     /// Receiver of `OnlinePeer` message
     online_peers_receiver: watch::Receiver<OnlinePeers>,
+    /// Receiver of the number of rejected peer connections
+    rejected_peers_receiver: watch::Receiver<u64>,
     /// [`UpdateTopology`] message sender
     update_topology_sender: mpsc::UnboundedSender<UpdateTopology>,
     /// [`UpdatePeers`] message sender
@@ -57,6 +56,7 @@ impl<T: Pload, K: Kex, E: Enc> Clone for NetworkBaseHandle<T, K, E> {
         Self {
             subscribe_to_peers_messages_sender: self.subscribe_to_peers_messages_sender.clone(),
             online_peers_receiver: self.online_peers_receiver.clone(),
+            rejected_peers_receiver: self.rejected_peers_receiver.clone(),
             update_topology_sender: self.update_topology_sender.clone(),
             update_peers_sender: self.update_peers_sender.clone(),
             network_message_sender: self.network_message_sender.clone(),
@@ -78,13 +78,18 @@ impl<T: Pload, K: Kex + Sync, E: Enc + Sync> NetworkBaseHandle<T, K, E> {
             address: listen_addr,
             public_address,
             idle_timeout,
+            transport,
+            observers,
+            log_rejected_peers,
         }: Config,
         shutdown_signal: ShutdownSignal,
     ) -> Result<(Self, Child), Error> {
         // TODO: enhance the error by reporting the origin of `listen_addr`
-        let listener = TcpListener::bind(listen_addr.value().to_socket_addrs()?.as_slice()).await?;
-        iroha_logger::info!("Network bound to listener");
+        let (listener, dialer) =
+            Listener::bind(transport, listen_addr.value(), idle_timeout).await?;
+        iroha_logger::info!(%transport, "Network bound to listener");
         let (online_peers_sender, online_peers_receiver) = watch::channel(HashSet::new());
+        let (rejected_peers_sender, rejected_peers_receiver) = watch::channel(0);
         let (subscribe_to_peers_messages_sender, subscribe_to_peers_messages_receiver) =
             mpsc::unbounded_channel();
         let (update_topology_sender, update_topology_receiver) = mpsc::unbounded_channel();
@@ -97,12 +102,14 @@ impl<T: Pload, K: Kex + Sync, E: Enc + Sync> NetworkBaseHandle<T, K, E> {
             listen_addr: listen_addr.into_value(),
             public_address: public_address.into_value(),
             listener,
+            dialer,
             peers: HashMap::new(),
             connecting_peers: HashMap::new(),
             key_pair,
             subscribers_to_peers_messages: Vec::new(),
             subscribe_to_peers_messages_receiver,
             online_peers_sender,
+            rejected_peers_sender,
             update_topology_receiver,
             update_peers_receiver,
             network_message_receiver,
@@ -112,6 +119,8 @@ impl<T: Pload, K: Kex + Sync, E: Enc + Sync> NetworkBaseHandle<T, K, E> {
             service_message_sender,
             current_conn_id: 0,
             current_topology: HashSet::new(),
+            observers: observers.into_iter().collect(),
+            log_rejected_peers,
             current_peers_addresses: Vec::new(),
             idle_timeout,
             _key_exchange: core::marker::PhantomData::<K>,
@@ -125,6 +134,7 @@ impl<T: Pload, K: Kex + Sync, E: Enc + Sync> NetworkBaseHandle<T, K, E> {
             Self {
                 subscribe_to_peers_messages_sender,
                 online_peers_receiver,
+                rejected_peers_receiver,
                 update_topology_sender,
                 update_peers_sender,
                 network_message_sender,
@@ -182,6 +192,12 @@ impl<T: Pload, K: Kex + Sync, E: Enc + Sync> NetworkBaseHandle<T, K, E> {
         self.online_peers_receiver.clone()
     }
 
+    /// Get a receiver of the number of connections rejected since the start,
+    /// because the peers are neither in the topology nor observers
+    pub fn rejected_peers_receiver(&self) -> watch::Receiver<u64> {
+        self.rejected_peers_receiver.clone()
+    }
+
     /// Wait for update of [`OnlinePeers`].
     pub async fn wait_online_peers_update<P>(
         &mut self,
@@ -205,8 +221,10 @@ struct NetworkBase<T: Pload, K: Kex, E: Enc> {
     peers: HashMap<PeerId, RefPeer<T>>,
     /// [`Peer`]s in process of being connected.
     connecting_peers: HashMap<ConnectionId, Peer>,
-    /// [`TcpListener`] that is accepting [`Peer`]s' connections
-    listener: TcpListener,
+    /// [`Listener`] that is accepting [`Peer`]s' connections
+    listener: Listener,
+    /// [`Dialer`] to connect to [`Peer`]s over the preferred transport
+    dialer: Dialer,
     /// Our app-level key pair
     key_pair: KeyPair,
     /// Recipients of messages received from other peers in the network.
@@ -215,6 +233,8 @@ struct NetworkBase<T: Pload, K: Kex, E: Enc> {
     subscribe_to_peers_messages_receiver: mpsc::UnboundedReceiver<mpsc::Sender<PeerMessage<T>>>,
     /// Sender of `OnlinePeer` message
     online_peers_sender: watch::Sender<OnlinePeers>,
+    /// Sender of the number of rejected peer connections
+    rejected_peers_sender: watch::Sender<u64>,
     /// [`UpdateTopology`] message receiver
     update_topology_receiver: mpsc::UnboundedReceiver<UpdateTopology>,
     /// [`UpdatePeers`] message receiver
@@ -233,6 +253,10 @@ struct NetworkBase<T: Pload, K: Kex, E: Enc> {
     current_conn_id: ConnectionId,
     /// Current topology
     current_topology: HashSet<PeerId>,
+    /// Peers allowed to stay connected despite not being in the topology
+    observers: HashSet<PeerId>,
+    /// Whether to log the rejected connections
+    log_rejected_peers: bool,
     /// Peers which are not yet connected, but should.
     ///
     /// Can have two addresses for same `PeerId`.
@@ -304,10 +328,10 @@ impl<T: Pload, K: Kex, E: Enc> NetworkBase<T, K, E> {
                 // Accept incoming peer connections
                 accept = self.listener.accept() => {
                     match accept {
-                        Ok((stream, addr)) => {
+                        Ok((incoming, addr)) => {
                             iroha_logger::debug!(from_addr = %addr, "Accepted connection");
                             // Handle creation of new peer
-                            self.accept_new_peer(stream);
+                            self.accept_new_peer(incoming);
                         },
                         Err(error) => {
                             iroha_logger::warn!(%error, "Error accepting connection");
@@ -331,15 +355,29 @@ impl<T: Pload, K: Kex, E: Enc> NetworkBase<T, K, E> {
         }
     }
 
-    fn accept_new_peer(&mut self, stream: TcpStream) {
+    fn accept_new_peer(&mut self, incoming: Incoming) {
         let conn_id = self.get_conn_id();
         let service_message_sender = self.service_message_sender.clone();
-        connected_from::<T, K, E>(
-            self.public_address.clone(),
-            self.key_pair.clone(),
-            Connection::new(conn_id, stream),
-            service_message_sender,
-            self.idle_timeout,
+        let our_public_address = self.public_address.clone();
+        let key_pair = self.key_pair.clone();
+        let idle_timeout = self.idle_timeout;
+        // Handshake of the transport (if any) shouldn't block the network
+        tokio::task::spawn(
+            async move {
+                match incoming.establish(conn_id).await {
+                    Ok(connection) => connected_from::<T, K, E>(
+                        our_public_address,
+                        key_pair,
+                        connection,
+                        service_message_sender,
+                        idle_timeout,
+                    ),
+                    Err(error) => {
+                        iroha_logger::warn!(%error, "Failed to establish incoming connection");
+                    }
+                }
+            }
+            .in_current_span(),
         );
     }
 
@@ -378,7 +416,7 @@ impl<T: Pload, K: Kex, E: Enc> NetworkBase<T, K, E> {
             .peers
             .keys()
             // Peer is connected but shouldn't
-            .filter(|&peer_id| !self.current_topology.contains(peer_id))
+            .filter(|&peer_id| !self.is_allowed(peer_id))
             .cloned()
             .collect::<Vec<_>>();
 
@@ -406,6 +444,7 @@ impl<T: Pload, K: Kex, E: Enc> NetworkBase<T, K, E> {
             self.public_address.clone(),
             self.key_pair.clone(),
             conn_id,
+            self.dialer.clone(),
             service_message_sender,
             self.idle_timeout,
         );
@@ -434,8 +473,12 @@ impl<T: Pload, K: Kex, E: Enc> NetworkBase<T, K, E> {
     ) {
         self.connecting_peers.remove(&connection_id);
 
-        if !self.current_topology.contains(peer.id()) {
-            iroha_logger::warn!(peer=%peer.id(), topology=?self.current_topology, "Peer not present in topology is trying to connect");
+        if !self.is_allowed(peer.id()) {
+            if self.log_rejected_peers {
+                iroha_logger::warn!(peer=%peer.id(), topology=?self.current_topology, "Peer not present in topology is trying to connect");
+            }
+            self.rejected_peers_sender
+                .send_modify(|rejected| *rejected = rejected.saturating_add(1));
             return;
         }
 
@@ -466,6 +509,11 @@ impl<T: Pload, K: Kex, E: Enc> NetworkBase<T, K, E> {
         Self::add_online_peer(&self.online_peers_sender, peer);
     }
 
+    /// Whether the peer is allowed to be connected to
+    fn is_allowed(&self, peer_id: &PeerId) -> bool {
+        self.current_topology.contains(peer_id) || self.observers.contains(peer_id)
+    }
+
     fn peer_terminated(&mut self, Terminated { peer, conn_id }: Terminated) {
         self.connecting_peers.remove(&conn_id);
         if let Some(peer) = peer {
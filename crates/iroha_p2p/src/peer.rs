@@ -6,16 +6,13 @@ use bytes::{Buf, BufMut, BytesMut};
 use message::*;
 use parity_scale_codec::{DecodeAll, Encode};
 use tokio::{
-    io::{AsyncReadExt, AsyncWriteExt},
-    net::{
-        tcp::{OwnedReadHalf, OwnedWriteHalf},
-        TcpStream,
-    },
+    io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt},
+    net::TcpStream,
     sync::{mpsc, oneshot},
     time::Duration,
 };
 
-use crate::{boilerplate::*, Error};
+use crate::{boilerplate::*, transport::Dialer, Error};
 
 /// Max length of message handshake in bytes excluding first message length byte.
 pub const MAX_HANDSHAKE_LENGTH: u8 = 255;
@@ -34,11 +31,13 @@ pub mod handles {
     use crate::unbounded_with_len;
 
     /// Start Peer in `state::Connecting` state
+    #[allow(clippy::too_many_arguments)]
     pub fn connecting<T: Pload, K: Kex, E: Enc>(
         peer_addr: SocketAddr,
         our_public_address: SocketAddr,
         key_pair: KeyPair,
         connection_id: ConnectionId,
+        dialer: Dialer,
         service_message_sender: mpsc::Sender<ServiceMessage<T>>,
         idle_timeout: Duration,
     ) {
@@ -47,6 +46,7 @@ pub mod handles {
             our_public_address,
             key_pair,
             connection_id,
+            dialer,
         };
         let peer = RunPeerArgs {
             peer,
@@ -335,9 +335,9 @@ mod run {
         }
     }
 
-    /// Cancellation-safe way to read messages from tcp stream
+    /// Cancellation-safe way to read messages from the connection
     struct MessageReader<E: Enc> {
-        read: OwnedReadHalf,
+        read: ReadHalf,
         buffer: bytes::BytesMut,
         cryptographer: Cryptographer<E>,
     }
@@ -345,7 +345,7 @@ mod run {
     impl<E: Enc> MessageReader<E> {
         const U32_SIZE: usize = core::mem::size_of::<u32>();
 
-        fn new(read: OwnedReadHalf, cryptographer: Cryptographer<E>) -> Self {
+        fn new(read: ReadHalf, cryptographer: Cryptographer<E>) -> Self {
             Self {
                 read,
                 cryptographer,
@@ -404,7 +404,7 @@ mod run {
     }
 
     struct MessageSender<E: Enc> {
-        write: OwnedWriteHalf,
+        write: WriteHalf,
         cryptographer: Cryptographer<E>,
         /// Reusable buffer to encode messages
         buffer: Vec<u8>,
@@ -415,7 +415,7 @@ mod run {
     impl<E: Enc> MessageSender<E> {
         const U32_SIZE: usize = core::mem::size_of::<u32>();
 
-        fn new(write: OwnedWriteHalf, cryptographer: Cryptographer<E>) -> Self {
+        fn new(write: WriteHalf, cryptographer: Cryptographer<E>) -> Self {
             Self {
                 write,
                 cryptographer,
@@ -491,6 +491,7 @@ mod state {
         pub our_public_address: SocketAddr,
         pub key_pair: KeyPair,
         pub connection_id: ConnectionId,
+        pub dialer: Dialer,
     }
 
     impl Connecting {
@@ -500,10 +501,10 @@ mod state {
                 our_public_address,
                 key_pair,
                 connection_id,
+                dialer,
             }: Self,
         ) -> Result<ConnectedTo, crate::Error> {
-            let stream = TcpStream::connect(peer_addr.to_string()).await?;
-            let connection = Connection::new(connection_id, stream);
+            let connection = dialer.connect(connection_id, &peer_addr).await?;
             Ok(ConnectedTo {
                 our_public_address,
                 key_pair,
@@ -873,19 +874,33 @@ mod cryptographer {
 /// An identification for peer connections.
 pub type ConnectionId = u64;
 
+/// Reading half of the connection, whichever transport it's established over
+pub type ReadHalf = Box<dyn AsyncRead + Send + Unpin>;
+
+/// Writing half of the connection, whichever transport it's established over
+pub type WriteHalf = Box<dyn AsyncWrite + Send + Unpin>;
+
 /// P2P connection
-#[derive(Debug)]
 pub struct Connection {
     /// A unique connection id
     pub id: ConnectionId,
-    /// Reading half of `TcpStream`
-    pub read: OwnedReadHalf,
-    /// Writing half of `TcpStream`
-    pub write: OwnedWriteHalf,
+    /// Reading half of the connection
+    pub read: ReadHalf,
+    /// Writing half of the connection
+    pub write: WriteHalf,
     /// Remote addr, for logging purpose.
     pub remote_addr: Option<SocketAddr>,
 }
 
+impl core::fmt::Debug for Connection {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Connection")
+            .field("id", &self.id)
+            .field("remote_addr", &self.remote_addr)
+            .finish_non_exhaustive()
+    }
+}
+
 impl Connection {
     /// Instantiate new connection from `connection_id` and `stream`.
     pub fn new(id: ConnectionId, stream: TcpStream) -> Self {
@@ -893,9 +908,23 @@ impl Connection {
         let (read, write) = stream.into_split();
         Connection {
             id,
-            read,
-            write,
+            read: Box::new(read),
+            write: Box::new(write),
             remote_addr,
         }
     }
+
+    /// Instantiate new connection from `connection_id` and the bidirectional QUIC stream.
+    pub fn from_quic(
+        id: ConnectionId,
+        (write, read): (quinn::SendStream, quinn::RecvStream),
+        remote_addr: SocketAddr,
+    ) -> Self {
+        Connection {
+            id,
+            read: Box::new(read),
+            write: Box::new(write),
+            remote_addr: Some(remote_addr),
+        }
+    }
 }
@@ -0,0 +1,272 @@
+//! Transports the connections between the peers are established over.
+//!
+//! The transport is negotiated per connection: every peer accepts the connections over TCP,
+//! and the peers preferring QUIC also accept them over QUIC on the same port and try to connect
+//! over QUIC first, falling back to TCP for the peers which don't accept QUIC connections.
+//! So the peers preferring different transports can be part of the same network.
+//!
+//! Over QUIC each connection is a single bidirectional stream of a QUIC connection, so the messages
+//! are framed, encrypted and authenticated the same way as over TCP. The TLS layer required by QUIC
+//! uses a throwaway self-signed certificate which isn't verified by the remote peer: the peers are
+//! authenticated by the handshake which follows, as over TCP.
+
+use std::{
+    io,
+    net::{SocketAddr as StdSocketAddr, ToSocketAddrs},
+    sync::Arc,
+    time::Duration,
+};
+
+use iroha_config::network::Transport;
+use iroha_primitives::addr::SocketAddr;
+use rustls::{
+    client::danger::{HandshakeSignatureValid, ServerCertVerified, ServerCertVerifier},
+    crypto::CryptoProvider,
+    pki_types::{CertificateDer, PrivatePkcs8KeyDer, ServerName, UnixTime},
+    DigitallySignedStruct, SignatureScheme,
+};
+use tokio::net::{TcpListener, TcpStream};
+
+use crate::{
+    peer::{Connection, ConnectionId},
+    Error,
+};
+
+/// Name the certificates of the peers are issued for, it isn't verified anyway
+const SERVER_NAME: &str = "iroha";
+
+/// Time to wait for the QUIC handshake before falling back to TCP
+const QUIC_HANDSHAKE_TIMEOUT: Duration = Duration::from_secs(3);
+
+/// Listener of the connections from the other peers
+pub(crate) struct Listener {
+    tcp: TcpListener,
+    /// Present if QUIC is the preferred transport
+    quic: Option<quinn::Endpoint>,
+}
+
+impl Listener {
+    /// Bind to `address` and get the [`Dialer`] to connect to the other peers
+    /// over the preferred `transport`, if they accept it
+    pub(crate) async fn bind(
+        transport: Transport,
+        address: &SocketAddr,
+        idle_timeout: Duration,
+    ) -> Result<(Self, Dialer), Error> {
+        let addresses = address.to_socket_addrs()?;
+        let tcp = TcpListener::bind(addresses.as_slice()).await?;
+        let quic = match transport {
+            Transport::Tcp => None,
+            Transport::Quic => {
+                // Bound to the same port as the TCP listener, which might have been picked by the OS
+                let address = tcp.local_addr()?;
+                Some(quic_endpoint(address, idle_timeout)?)
+            }
+        };
+        let dialer = Dialer { quic: quic.clone() };
+
+        Ok((Self { tcp, quic }, dialer))
+    }
+
+    /// Accept the next connection over either transport. Cancellation-safe.
+    pub(crate) async fn accept(&self) -> Result<(Incoming, StdSocketAddr), Error> {
+        let accept_quic = async {
+            // The endpoint is never closed while the network is running
+            match &self.quic {
+                Some(endpoint) => match endpoint.accept().await {
+                    Some(incoming) => incoming,
+                    None => std::future::pending().await,
+                },
+                None => std::future::pending().await,
+            }
+        };
+
+        tokio::select! {
+            accepted = self.tcp.accept() => {
+                let (stream, address) = accepted?;
+                Ok((Incoming::Tcp(stream), address))
+            }
+            incoming = accept_quic => {
+                let address = incoming.remote_address();
+                Ok((Incoming::Quic(incoming), address))
+            }
+        }
+    }
+}
+
+/// Connection accepted by the [`Listener`]
+pub(crate) enum Incoming {
+    Tcp(TcpStream),
+    /// The QUIC handshake is yet to be completed
+    Quic(quinn::Incoming),
+}
+
+impl Incoming {
+    /// Complete the handshake of the transport, if any
+    pub(crate) async fn establish(self, id: ConnectionId) -> Result<Connection, Error> {
+        match self {
+            Self::Tcp(stream) => Ok(Connection::new(id, stream)),
+            Self::Quic(incoming) => {
+                let connection = incoming.await.map_err(Error::quic)?;
+                let streams = connection.accept_bi().await.map_err(Error::quic)?;
+                Ok(Connection::from_quic(
+                    id,
+                    streams,
+                    connection.remote_address(),
+                ))
+            }
+        }
+    }
+}
+
+/// Means of connecting to the other peers over the preferred transport of the peer
+#[derive(Debug, Clone)]
+pub struct Dialer {
+    /// Endpoint the network listens on, if QUIC is the preferred transport
+    quic: Option<quinn::Endpoint>,
+}
+
+impl Dialer {
+    /// Connect to the peer listening on `address`.
+    ///
+    /// If QUIC is preferred, but the peer doesn't accept QUIC connections, connect over TCP.
+    ///
+    /// # Errors
+    /// Fails if the peer is unreachable or the handshake of the transport fails
+    pub async fn connect(
+        &self,
+        id: ConnectionId,
+        address: &SocketAddr,
+    ) -> Result<Connection, Error> {
+        if let Some(endpoint) = &self.quic {
+            match tokio::time::timeout(
+                QUIC_HANDSHAKE_TIMEOUT,
+                Self::connect_quic(endpoint, id, address),
+            )
+            .await
+            {
+                Ok(Ok(connection)) => return Ok(connection),
+                Ok(Err(error)) => {
+                    iroha_logger::debug!(%address, %error, "Failed to connect over QUIC, falling back to TCP");
+                }
+                Err(_) => {
+                    iroha_logger::debug!(%address, "QUIC handshake timed out, falling back to TCP");
+                }
+            }
+        }
+
+        let stream = TcpStream::connect(address.to_string()).await?;
+        Ok(Connection::new(id, stream))
+    }
+
+    async fn connect_quic(
+        endpoint: &quinn::Endpoint,
+        id: ConnectionId,
+        address: &SocketAddr,
+    ) -> Result<Connection, Error> {
+        let address = tokio::net::lookup_host(address.to_string())
+            .await?
+            .next()
+            .ok_or_else(|| {
+                io::Error::new(io::ErrorKind::NotFound, "address resolved to nothing")
+            })?;
+        let connection = endpoint
+            .connect(address, SERVER_NAME)
+            .map_err(Error::quic)?
+            .await
+            .map_err(Error::quic)?;
+        // The stream is announced to the peer once the handshake of the peers is written into it
+        let streams = connection.open_bi().await.map_err(Error::quic)?;
+        Ok(Connection::from_quic(id, streams, address))
+    }
+}
+
+/// Endpoint both accepting the connections of the other peers and connecting to them
+fn quic_endpoint(address: StdSocketAddr, idle_timeout: Duration) -> Result<quinn::Endpoint, Error> {
+    let provider = Arc::new(rustls::crypto::ring::default_provider());
+    let certificate =
+        rcgen::generate_simple_self_signed([SERVER_NAME.to_owned()]).map_err(Error::quic)?;
+    let private_key = PrivatePkcs8KeyDer::from(certificate.key_pair.serialize_der());
+
+    let server_crypto = rustls::ServerConfig::builder_with_provider(Arc::clone(&provider))
+        .with_protocol_versions(&[&rustls::version::TLS13])
+        .map_err(Error::quic)?
+        .with_no_client_auth()
+        .with_single_cert(vec![certificate.cert.der().clone()], private_key.into())
+        .map_err(Error::quic)?;
+    let client_crypto = rustls::ClientConfig::builder_with_provider(Arc::clone(&provider))
+        .with_protocol_versions(&[&rustls::version::TLS13])
+        .map_err(Error::quic)?
+        .dangerous()
+        .with_custom_certificate_verifier(Arc::new(SkipCertificateVerification(provider)))
+        .with_no_client_auth();
+
+    let mut transport = quinn::TransportConfig::default();
+    transport.max_idle_timeout(Some(idle_timeout.try_into().map_err(Error::quic)?));
+    let transport = Arc::new(transport);
+
+    let mut server_config = quinn::ServerConfig::with_crypto(Arc::new(
+        quinn::crypto::rustls::QuicServerConfig::try_from(server_crypto).map_err(Error::quic)?,
+    ));
+    server_config
+        .transport_config(Arc::clone(&transport))
+        // Keep the connection once the address of the remote peer changes, e.g. behind a NAT
+        .migration(true);
+    let mut client_config = quinn::ClientConfig::new(Arc::new(
+        quinn::crypto::rustls::QuicClientConfig::try_from(client_crypto).map_err(Error::quic)?,
+    ));
+    client_config.transport_config(transport);
+
+    let mut endpoint = quinn::Endpoint::server(server_config, address)?;
+    endpoint.set_default_client_config(client_config);
+    Ok(endpoint)
+}
+
+/// Accepts any certificate of the remote peer, but checks that the TLS handshake is signed by its key
+#[derive(Debug)]
+struct SkipCertificateVerification(Arc<CryptoProvider>);
+
+impl ServerCertVerifier for SkipCertificateVerification {
+    fn verify_server_cert(
+        &self,
+        _end_entity: &CertificateDer<'_>,
+        _intermediates: &[CertificateDer<'_>],
+        _server_name: &ServerName<'_>,
+        _ocsp_response: &[u8],
+        _now: UnixTime,
+    ) -> Result<ServerCertVerified, rustls::Error> {
+        Ok(ServerCertVerified::assertion())
+    }
+
+    fn verify_tls12_signature(
+        &self,
+        message: &[u8],
+        cert: &CertificateDer<'_>,
+        dss: &DigitallySignedStruct,
+    ) -> Result<HandshakeSignatureValid, rustls::Error> {
+        rustls::crypto::verify_tls12_signature(
+            message,
+            cert,
+            dss,
+            &self.0.signature_verification_algorithms,
+        )
+    }
+
+    fn verify_tls13_signature(
+        &self,
+        message: &[u8],
+        cert: &CertificateDer<'_>,
+        dss: &DigitallySignedStruct,
+    ) -> Result<HandshakeSignatureValid, rustls::Error> {
+        rustls::crypto::verify_tls13_signature(
+            message,
+            cert,
+            dss,
+            &self.0.signature_verification_algorithms,
+        )
+    }
+
+    fn supported_verify_schemes(&self) -> Vec<SignatureScheme> {
+        self.0.signature_verification_algorithms.supported_schemes()
+    }
+}
@@ -1,5 +1,5 @@
 use std::{
-    collections::HashSet,
+    collections::{BTreeSet, HashSet},
     fmt::Debug,
     sync::{
         atomic::{AtomicU32, Ordering},
@@ -8,14 +8,17 @@ use std::{
 };
 
 use futures::{prelude::*, stream::FuturesUnordered, task::AtomicWaker};
-use iroha_config::parameters::actual::Network as Config;
+use iroha_config::{network::Transport, parameters::actual::Network as Config};
 use iroha_config_base::WithOrigin;
 use iroha_crypto::KeyPair;
-use iroha_data_model::{prelude::Peer, Identifiable};
+use iroha_data_model::{
+    prelude::{Peer, PeerId},
+    Identifiable,
+};
 use iroha_futures::supervisor::ShutdownSignal;
 use iroha_logger::{prelude::*, test_logger};
 use iroha_p2p::{network::message::*, peer::message::PeerMessage, NetworkHandle};
-use iroha_primitives::addr::socket_addr;
+use iroha_primitives::addr::{socket_addr, SocketAddr};
 use parity_scale_codec::{Decode, Encode};
 use tokio::{
     sync::{mpsc, Barrier},
@@ -46,6 +49,9 @@ async fn network_create() {
         address: WithOrigin::inline(address.clone()),
         public_address: WithOrigin::inline(address.clone()),
         idle_timeout,
+        transport: Transport::Tcp,
+        observers: BTreeSet::new(),
+        log_rejected_peers: true,
     };
     let (network, _) = NetworkHandle::start(key_pair, config, ShutdownSignal::new())
         .await
@@ -146,6 +152,37 @@ impl TestActor {
 /// This peer connects to our second network, emulating some distant peer.
 #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
 async fn two_networks() {
+    exchange_between_two_networks(
+        (Transport::Tcp, socket_addr!(127.0.0.1:12_005)),
+        (Transport::Tcp, socket_addr!(127.0.0.1:12_010)),
+    )
+    .await;
+}
+
+/// Same as [`two_networks`], but the peers are connected over QUIC.
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn two_networks_over_quic() {
+    exchange_between_two_networks(
+        (Transport::Quic, socket_addr!(127.0.0.1:12_300)),
+        (Transport::Quic, socket_addr!(127.0.0.1:12_305)),
+    )
+    .await;
+}
+
+/// Same as [`two_networks`], but only one of the peers prefers QUIC, so they are connected over TCP.
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn two_networks_preferring_different_transports() {
+    exchange_between_two_networks(
+        (Transport::Quic, socket_addr!(127.0.0.1:12_310)),
+        (Transport::Tcp, socket_addr!(127.0.0.1:12_315)),
+    )
+    .await;
+}
+
+async fn exchange_between_two_networks(
+    (transport1, address1): (Transport, SocketAddr),
+    (transport2, address2): (Transport, SocketAddr),
+) {
     let delay = Duration::from_millis(300);
     let idle_timeout = Duration::from_secs(60);
     setup_logger();
@@ -154,22 +191,26 @@ async fn two_networks() {
     let key_pair2 = KeyPair::random().clone();
     let public_key2 = key_pair2.public_key().clone();
     info!("Starting first network...");
-    let address1 = socket_addr!(127.0.0.1:12_005);
     let config1 = Config {
         address: WithOrigin::inline(address1.clone()),
         public_address: WithOrigin::inline(address1.clone()),
         idle_timeout,
+        transport: transport1,
+        observers: BTreeSet::new(),
+        log_rejected_peers: true,
     };
     let (mut network1, _) = NetworkHandle::start(key_pair1, config1, ShutdownSignal::new())
         .await
         .unwrap();
 
     info!("Starting second network...");
-    let address2 = socket_addr!(127.0.0.1:12_010);
     let config2 = Config {
         address: WithOrigin::inline(address2.clone()),
         public_address: WithOrigin::inline(address2.clone()),
         idle_timeout,
+        transport: transport2,
+        observers: BTreeSet::new(),
+        log_rejected_peers: true,
     };
     let (network2, _) = NetworkHandle::start(key_pair2, config2, ShutdownSignal::new())
         .await
@@ -217,6 +258,75 @@ async fn two_networks() {
     assert_eq!(connected_peers2, 1);
 }
 
+/// The peer outside of the topology is allowed to connect only if it's an observer.
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn peers_outside_of_topology_are_rejected_unless_observers() {
+    setup_logger();
+    let config = |address: &SocketAddr, observers: BTreeSet<PeerId>| Config {
+        address: WithOrigin::inline(address.clone()),
+        public_address: WithOrigin::inline(address.clone()),
+        idle_timeout: Duration::from_secs(60),
+        transport: Transport::Tcp,
+        observers,
+        log_rejected_peers: true,
+    };
+    let [validator_key_pair, observer_key_pair, stranger_key_pair] =
+        [KeyPair::random(), KeyPair::random(), KeyPair::random()];
+    let validator = Peer::new(
+        socket_addr!(127.0.0.1:12_400),
+        validator_key_pair.public_key().clone(),
+    );
+    let observer = Peer::new(
+        socket_addr!(127.0.0.1:12_405),
+        observer_key_pair.public_key().clone(),
+    );
+    let stranger = Peer::new(
+        socket_addr!(127.0.0.1:12_410),
+        stranger_key_pair.public_key().clone(),
+    );
+
+    let (mut validator_network, _) = NetworkHandle::<TestMessage>::start(
+        validator_key_pair,
+        config(validator.address(), [observer.id().clone()].into()),
+        ShutdownSignal::new(),
+    )
+    .await
+    .unwrap();
+    update_topology_and_peers_addresses(&validator_network, &[]);
+    let mut rejected = validator_network.rejected_peers_receiver();
+
+    let mut networks = Vec::new();
+    for (key_pair, peer) in [
+        (observer_key_pair, &observer),
+        (stranger_key_pair, &stranger),
+    ] {
+        let (network, _) = NetworkHandle::<TestMessage>::start(
+            key_pair,
+            config(peer.address(), BTreeSet::new()),
+            ShutdownSignal::new(),
+        )
+        .await
+        .unwrap();
+        update_topology_and_peers_addresses(&network, &[validator.clone()]);
+        networks.push(network);
+    }
+
+    tokio::time::timeout(Duration::from_millis(5_000), async {
+        while !validator_network
+            .online_peers(|peers| peers.iter().any(|peer| peer.id() == observer.id()))
+        {
+            validator_network.wait_online_peers_update(|_| ()).await;
+        }
+        rejected.wait_for(|rejected| *rejected > 0).await.unwrap();
+    })
+    .await
+    .expect("Observer should be connected and stranger rejected");
+
+    let connected = validator_network.online_peers(Clone::clone);
+    assert_eq!(connected.len(), 1);
+    assert!(connected.iter().all(|peer| peer.id() != stranger.id()));
+}
+
 #[tokio::test(flavor = "multi_thread", worker_threads = 8)]
 async fn multiple_networks() {
     setup_logger();
@@ -305,6 +415,9 @@ async fn start_network(
         address: WithOrigin::inline(address.clone()),
         public_address: WithOrigin::inline(address.clone()),
         idle_timeout,
+        transport: Transport::Tcp,
+        observers: BTreeSet::new(),
+        log_rejected_peers: true,
     };
     let (mut network, _) = NetworkHandle::start(key_pair, config, shutdown_signal)
         .await
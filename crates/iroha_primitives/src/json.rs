@@ -55,6 +55,39 @@ impl Json {
     pub fn get(&self) -> &String {
         &self.0
     }
+
+    /// Nesting depth of the value: `0` for a scalar, `1` for an array or an object of scalars and so on.
+    ///
+    /// # Errors
+    /// - if the string isn't valid JSON, which is possible for a decoded value
+    pub fn depth(&self) -> Result<usize, serde_json::Error> {
+        let value: Value = serde_json::from_str(&self.0)?;
+
+        let mut depth = 0;
+        let mut level = Vec::from([&value]);
+        loop {
+            let mut is_nested = false;
+            let mut next_level = Vec::new();
+            for value in level {
+                match value {
+                    Value::Array(values) => {
+                        is_nested = true;
+                        next_level.extend(values);
+                    }
+                    Value::Object(values) => {
+                        is_nested = true;
+                        next_level.extend(values.values());
+                    }
+                    _ => {}
+                }
+            }
+            if !is_nested {
+                return Ok(depth);
+            }
+            depth += 1;
+            level = next_level;
+        }
+    }
 }
 
 impl<'de> serde::de::Deserialize<'de> for Json {
@@ -2,11 +2,14 @@
 //! `build_schemas` `fn`, which is the function which decides which
 //! types are included in the schema.
 use iroha_data_model::{
-    block::stream::{BlockMessage, BlockSubscriptionRequest},
+    block::stream::{BlockHeaderMessage, BlockMessage, BlockSubscriptionRequest},
     query::{QueryResponse, SignedQuery},
 };
 use iroha_schema::prelude::*;
-use iroha_telemetry::metrics::Status;
+use iroha_telemetry::{
+    metrics::{InstructionStats, Status},
+    stats::NetworkStats,
+};
 
 macro_rules! types {
     ($($t:ty),+ $(,)?) => {
@@ -34,7 +37,10 @@ macro_rules! types {
 /// shall be included recursively.
 pub fn build_schemas() -> MetaMap {
     use iroha_data_model::prelude::*;
-    use iroha_executor_data_model::{isi::multisig, permission};
+    use iroha_executor_data_model::{
+        isi::{governance, multisig, preset},
+        parameter, permission,
+    };
 
     macro_rules! schemas {
         ($($t:ty),* $(,)?) => {{
@@ -54,9 +60,12 @@ pub fn build_schemas() -> MetaMap {
         // Event stream
         EventMessage,
         EventSubscriptionRequest,
+        SequencedEventMessage,
+        SequencedEventSubscriptionRequest,
 
         // Block stream
         BlockMessage,
+        BlockHeaderMessage,
         BlockSubscriptionRequest,
 
         // Never referenced, but present in type signature. Like `PhantomData<X>`
@@ -68,10 +77,12 @@ pub fn build_schemas() -> MetaMap {
         permission::domain::CanRegisterDomain,
         permission::domain::CanUnregisterDomain,
         permission::domain::CanModifyDomainMetadata,
+        permission::domain::CanTransferDomain,
 
         permission::account::CanRegisterAccount,
         permission::account::CanUnregisterAccount,
         permission::account::CanModifyAccountMetadata,
+        permission::account::CanMessageAccount,
 
         permission::asset_definition::CanRegisterAssetDefinition,
         permission::asset_definition::CanUnregisterAssetDefinition,
@@ -80,9 +91,11 @@ pub fn build_schemas() -> MetaMap {
         permission::asset::CanMintAssetWithDefinition,
         permission::asset::CanBurnAssetWithDefinition,
         permission::asset::CanTransferAssetWithDefinition,
+        permission::asset::CanFreezeAssetWithDefinition,
         permission::asset::CanMintAsset,
         permission::asset::CanBurnAsset,
         permission::asset::CanTransferAsset,
+        permission::asset::CanModifyAssetMetadata,
 
         permission::nft::CanRegisterNft,
         permission::nft::CanUnregisterNft,
@@ -100,18 +113,37 @@ pub fn build_schemas() -> MetaMap {
 
         permission::executor::CanUpgradeExecutor,
 
+        // Default parameters
+        parameter::domain::DomainPolicies,
+        parameter::governance::GovernanceParameters,
+
         // Multi-signature operations
         multisig::MultisigInstructionBox,
         // Multi-signature account metadata
         multisig::MultisigSpec,
         multisig::MultisigProposalValue,
 
+        // Role presets of the default executor
+        preset::RolePresetInstructionBox,
+
+        // Governance operations
+        governance::GovernanceInstructionBox,
+        // Governance proposal metadata
+        governance::GovernanceProposalValue,
+
+        // Encrypted metadata values
+        EncryptedValue,
+
         // Genesis file - used by SDKs to generate the genesis block
         // TODO: IMO it could/should be removed from the schema
         iroha_genesis::RawGenesisTransaction,
 
         // It is exposed via Torii
-        Status
+        Status,
+        InstructionStats,
+
+        // Reported to the collector of the opt-in network statistics
+        NetworkStats
     }
 }
 
@@ -129,14 +161,22 @@ types!(
     AccountProjection<PredicateMarker>,
     AccountProjection<SelectorMarker>,
     AccountRoleChanged,
+    AccountTagChanged,
     Action,
     ActionPredicateAtom,
     ActionProjection<PredicateMarker>,
     ActionProjection<SelectorMarker>,
+    AddTag,
+    Aggregation,
     Algorithm,
+    Allowance,
+    Approve,
     Asset,
     AssetChanged,
+    AssetConverted,
     AssetDefinition,
+    AssetDefinitionDeprecated,
+    AssetDefinitionDeprecation,
     AssetDefinitionEvent,
     AssetDefinitionEventFilter,
     AssetDefinitionEventSet,
@@ -156,14 +196,28 @@ types!(
     AssetIdPredicateAtom,
     AssetIdProjection<PredicateMarker>,
     AssetIdProjection<SelectorMarker>,
+    AssetKind,
+    AssetMerged,
     AssetPredicateAtom,
     AssetProjection<PredicateMarker>,
     AssetProjection<SelectorMarker>,
+    AssetSplit,
+    AssetWithDefinition,
+    AssetWithDefinitionPredicateAtom,
+    AssetWithDefinitionProjection<PredicateMarker>,
+    AssetWithDefinitionProjection<SelectorMarker>,
+    BTreeMap<AccountId, Numeric>,
     BTreeMap<AccountId, u8>,
+    BTreeMap<AssetDefinitionId, Numeric>,
     BTreeMap<CustomParameterId, CustomParameter>,
+    BTreeMap<DomainId, BTreeMap<InstructionType, u64>>,
+    BTreeMap<InstructionType, u64>,
     BTreeMap<Name, Json>,
     BTreeSet<AccountId>,
+    BTreeSet<AssetId>,
+    BTreeSet<Name>,
     BTreeSet<Permission>,
+    BTreeSet<RoleId>,
     BTreeSet<BlockSignature>,
     BTreeSet<String>,
     BlockEvent,
@@ -172,6 +226,7 @@ types!(
     BlockHeaderHashProjection<PredicateMarker>,
     BlockHeaderHashProjection<SelectorMarker>,
     BlockHeader,
+    BlockHeaderMessage,
     BlockHeaderPredicateAtom,
     BlockHeaderProjection<PredicateMarker>,
     BlockHeaderProjection<SelectorMarker>,
@@ -188,10 +243,12 @@ types!(
     Box<CompoundPredicate<Account>>,
     Box<CompoundPredicate<AssetDefinition>>,
     Box<CompoundPredicate<Asset>>,
+    Box<CompoundPredicate<AssetWithDefinition>>,
     Box<CompoundPredicate<BlockHeader>>,
     Box<CompoundPredicate<CommittedTransaction>>,
     Box<CompoundPredicate<Domain>>,
     Box<CompoundPredicate<Nft>>,
+    Box<CompoundPredicate<Parameter>>,
     Box<CompoundPredicate<PeerId>>,
     Box<CompoundPredicate<Permission>>,
     Box<CompoundPredicate<RoleId>>,
@@ -199,6 +256,9 @@ types!(
     Box<CompoundPredicate<SignedBlock>>,
     Box<CompoundPredicate<TriggerId>>,
     Box<CompoundPredicate<Trigger>>,
+    Box<DataEvent>,
+    Box<IdBox>,
+    Box<InstructionBox>,
     Box<InstructionExecutionFail>,
     Box<Permission>,
     Box<RepetitionError>,
@@ -207,6 +267,7 @@ types!(
     Burn<u32, Trigger>,
     BurnBox,
     ChainId,
+    ClaimEscrow,
     CommittedTransaction,
     CommittedTransactionPredicateAtom,
     CommittedTransactionProjection<PredicateMarker>,
@@ -214,10 +275,12 @@ types!(
     CompoundPredicate<Account>,
     CompoundPredicate<AssetDefinition>,
     CompoundPredicate<Asset>,
+    CompoundPredicate<AssetWithDefinition>,
     CompoundPredicate<BlockHeader>,
     CompoundPredicate<CommittedTransaction>,
     CompoundPredicate<Domain>,
     CompoundPredicate<Nft>,
+    CompoundPredicate<Parameter>,
     CompoundPredicate<PeerId>,
     CompoundPredicate<Permission>,
     CompoundPredicate<RoleId>,
@@ -231,12 +294,22 @@ types!(
     ConstString,
     ConstVec<InstructionBox>,
     ConstVec<u8>,
+    Convert,
+    CreateEscrow,
     CustomInstruction,
     CustomParameter,
     CustomParameterId,
     DataEvent,
     DataEventFilter,
     DataTriggerStep,
+    DeadLetter,
+    DeadLetterId,
+    Delegate,
+    Delegation,
+    Deprecate<AssetDefinition>,
+    DirectMessage,
+    DirectMessageId,
+    DiscardDeadLetter,
     Domain,
     DomainEvent,
     DomainEventFilter,
@@ -249,9 +322,14 @@ types!(
     DomainPredicateAtom,
     DomainProjection<PredicateMarker>,
     DomainProjection<SelectorMarker>,
+    EncryptedValue,
+    EncryptionAlgorithm,
+    Escrow,
+    EscrowId,
     EventBox,
     EventFilterBox,
     EventMessage,
+    EventSequence,
     EventSubscriptionRequest,
     Executable,
     ExecuteTrigger,
@@ -269,14 +347,25 @@ types!(
     FetchSize,
     FindAccounts,
     FindAccountsWithAsset,
+    FindAccountsWithRole,
+    FindAccountsWithTag,
+    FindAssetQuantityAt,
     FindActiveTriggerIds,
+    FindAllParameters,
+    FindAllowances,
     FindAssets,
     FindAssetsDefinitions,
+    FindAssetsWithDefinition,
     FindBlockHeaders,
     FindBlocks,
+    FindDeadLetters,
+    FindDelegations,
     FindDomains,
     FindError,
+    FindEscrows,
     FindExecutorDataModel,
+    FindFuelSchedule,
+    FindInbox,
     FindNfts,
     FindParameters,
     FindPeers,
@@ -284,9 +373,16 @@ types!(
     FindRoleIds,
     FindRoles,
     FindRolesByAccountId,
+    FindSubscription,
+    FindTombstone,
     FindTransactions,
     FindTriggers,
     ForwardCursor,
+    Freeze<Asset>,
+    Freeze<AssetDefinition>,
+    FreezeBox,
+    FuelSchedule,
+    GenesisAnchor,
     GenesisWasmAction,
     GenesisWasmTrigger,
     Grant<Permission, Account>,
@@ -306,6 +402,7 @@ types!(
     InstructionEvaluationError,
     InstructionExecutionError,
     InstructionExecutionFail,
+    InstructionStats,
     InstructionType,
     InvalidParameterError,
     IpfsPath,
@@ -318,6 +415,8 @@ types!(
     Level,
     Log,
     MathError,
+    Merge,
+    Message,
     MerkleProof<TransactionEntrypoint>,
     MerkleProof<TransactionResult>,
     MerkleTree<SignedTransaction>,
@@ -325,6 +424,7 @@ types!(
     MerkleTree<TransactionResult>,
     Metadata,
     MetadataChanged<AccountId>,
+    MetadataChanged<AssetId>,
     MetadataChanged<AssetDefinitionId>,
     MetadataChanged<DomainId>,
     MetadataChanged<NftId>,
@@ -339,10 +439,14 @@ types!(
     MintBox,
     MintabilityError,
     Mintable,
+    MintedInPeriod,
+    MintingRate,
+    Mismatch<AssetKind>,
     Mismatch<NumericSpec>,
     Name,
     NameProjection<PredicateMarker>,
     NameProjection<SelectorMarker>,
+    NetworkStats,
     NewAccount,
     NewAssetDefinition,
     NewDomain,
@@ -368,12 +472,17 @@ types!(
     NumericProjection<PredicateMarker>,
     NumericProjection<SelectorMarker>,
     NumericSpec,
+    Onboard,
     Option<AccountId>,
+    Option<Aggregation>,
+    Option<AssetDefinitionDeprecation>,
     Option<AssetDefinitionId>,
     Option<AssetId>,
     Option<BlockStatus>,
     Option<DomainId>,
+    Option<EventSequence>,
     Option<ForwardCursor>,
+    Option<GenesisAnchor>,
     Option<HashOf<BlockHeader>>,
     Option<HashOf<MerkleTree<TransactionEntrypoint>>>,
     Option<HashOf<MerkleTree<TransactionResult>>>,
@@ -381,24 +490,33 @@ types!(
     Option<HashOf<TransactionEntrypoint>>,
     Option<HashOf<TransactionResult>>,
     Option<IpfsPath>,
+    Option<MintedInPeriod>,
     Option<Name>,
     Option<NftId>,
+    Option<NonZeroU16>,
     Option<NonZeroU32>,
     Option<NonZeroU64>,
+    Option<Numeric>,
     Option<Option<NonZeroU64>>,
     Option<Parameters>,
     Option<PeerId>,
     Option<RoleId>,
+    Option<SortKey>,
     Option<TransactionStatus>,
     Option<TriggerCompletedOutcomeType>,
     Option<TriggerId>,
+    Option<Vec<InstructionType>>,
     Option<bool>,
     Option<u32>,
     Option<u64>,
     Pagination,
     Parameter,
     ParameterChanged,
+    ParameterPredicateAtom,
+    ParameterProjection<PredicateMarker>,
+    ParameterProjection<SelectorMarker>,
     Parameters,
+    Payout,
     PeerEvent,
     PeerEventFilter,
     PeerEventSet,
@@ -417,6 +535,9 @@ types!(
     PublicKeyPredicateAtom,
     PublicKeyProjection<PredicateMarker>,
     PublicKeyProjection<SelectorMarker>,
+    Purge<Account>,
+    Purge<Domain>,
+    PurgeBox,
     QueryBox,
     QueryExecutionFail,
     QueryOutput,
@@ -429,9 +550,13 @@ types!(
     QuerySignature,
     QueryWithFilter<FindAccounts>,
     QueryWithFilter<FindAccountsWithAsset>,
+    QueryWithFilter<FindAccountsWithRole>,
+    QueryWithFilter<FindAccountsWithTag>,
     QueryWithFilter<FindActiveTriggerIds>,
+    QueryWithFilter<FindAllParameters>,
     QueryWithFilter<FindAssets>,
     QueryWithFilter<FindAssetsDefinitions>,
+    QueryWithFilter<FindAssetsWithDefinition>,
     QueryWithFilter<FindBlockHeaders>,
     QueryWithFilter<FindBlocks>,
     QueryWithFilter<FindDomains>,
@@ -444,6 +569,7 @@ types!(
     QueryWithFilter<FindTransactions>,
     QueryWithFilter<FindTriggers>,
     QueryWithParams,
+    RefundEscrow,
     Register<Account>,
     Register<AssetDefinition>,
     Register<Domain>,
@@ -453,14 +579,17 @@ types!(
     Register<Trigger>,
     RegisterBox,
     RemoveKeyValue<Account>,
+    RemoveKeyValue<Asset>,
     RemoveKeyValue<AssetDefinition>,
     RemoveKeyValue<Domain>,
     RemoveKeyValue<Nft>,
     RemoveKeyValue<Trigger>,
     RemoveKeyValueBox,
+    RemoveTag,
     Repeats,
     RepetitionError,
     Result<DataTriggerSequence, TransactionRejectionReason>,
+    RetryDeadLetter,
     Revoke<Permission, Account>,
     Revoke<Permission, Role>,
     Revoke<RoleId, Account>,
@@ -480,10 +609,12 @@ types!(
     SelectorTuple<Account>,
     SelectorTuple<AssetDefinition>,
     SelectorTuple<Asset>,
+    SelectorTuple<AssetWithDefinition>,
     SelectorTuple<BlockHeader>,
     SelectorTuple<CommittedTransaction>,
     SelectorTuple<Domain>,
     SelectorTuple<Nft>,
+    SelectorTuple<Parameter>,
     SelectorTuple<PeerId>,
     SelectorTuple<Permission>,
     SelectorTuple<RoleId>,
@@ -491,7 +622,10 @@ types!(
     SelectorTuple<SignedBlock>,
     SelectorTuple<TriggerId>,
     SelectorTuple<Trigger>,
+    SequencedEventMessage,
+    SequencedEventSubscriptionRequest,
     SetKeyValue<Account>,
+    SetKeyValue<Asset>,
     SetKeyValue<AssetDefinition>,
     SetKeyValue<Domain>,
     SetKeyValue<Nft>,
@@ -513,23 +647,33 @@ types!(
     SignedTransactionV1,
     SingularQueryBox,
     SingularQueryOutputBox,
+    Split,
     SmartContractParameter,
     SmartContractParameters,
     SocketAddr,
     SocketAddrHost,
     SocketAddrV4,
     SocketAddrV6,
+    SortKey,
+    SortOrder,
     Sorting,
     Status,
     String,
     StringPredicateAtom,
+    Subscribe,
+    Subscription,
+    SubscriptionDelivery,
+    SubscriptionId,
     SumeragiParameter,
     SumeragiParameters,
+    Tagged,
+    TaggedEvent,
     TimeEvent,
     TimeEventFilter,
     TimeInterval,
     TimeSchedule,
     TimeTriggerEntrypoint,
+    Tombstone,
     TransactionEntrypoint,
     TransactionEntrypointHashPredicateAtom,
     TransactionEntrypointHashProjection<PredicateMarker>,
@@ -558,6 +702,9 @@ types!(
     Transfer<Account, NftId, Account>,
     Transfer<Asset, Numeric, Account>,
     TransferBox,
+    TransferFrom,
+    TransferLeg,
+    TransferMany,
     Trigger,
     TriggerCompletedEvent,
     TriggerCompletedEventFilter,
@@ -577,6 +724,9 @@ types!(
     TriggerProjection<SelectorMarker>,
     DataTriggerSequence,
     TypeError,
+    Unfreeze<Asset>,
+    Unfreeze<AssetDefinition>,
+    UnfreezeBox,
     Unregister<Account>,
     Unregister<AssetDefinition>,
     Unregister<Domain>,
@@ -585,25 +735,32 @@ types!(
     Unregister<Role>,
     Unregister<Trigger>,
     UnregisterBox,
+    Unsubscribe,
     Upgrade,
     Uptime,
     ValidationFail,
     Vec<Account>,
     Vec<AccountId>,
     Vec<Action>,
+    Vec<Allowance>,
     Vec<Asset>,
+    Vec<AssetChanged>,
     Vec<AssetId>,
     Vec<AssetDefinition>,
     Vec<AssetDefinitionId>,
+    Vec<AssetWithDefinition>,
     Vec<BlockHeader>,
+    Vec<BlockSignature>,
     Vec<CommittedTransaction>,
     Vec<CompoundPredicate<Account>>,
     Vec<CompoundPredicate<AssetDefinition>>,
     Vec<CompoundPredicate<Asset>>,
+    Vec<CompoundPredicate<AssetWithDefinition>>,
     Vec<CompoundPredicate<BlockHeader>>,
     Vec<CompoundPredicate<CommittedTransaction>>,
     Vec<CompoundPredicate<Domain>>,
     Vec<CompoundPredicate<Nft>>,
+    Vec<CompoundPredicate<Parameter>>,
     Vec<CompoundPredicate<PeerId>>,
     Vec<CompoundPredicate<Permission>>,
     Vec<CompoundPredicate<RoleId>>,
@@ -611,11 +768,17 @@ types!(
     Vec<CompoundPredicate<SignedBlock>>,
     Vec<CompoundPredicate<TriggerId>>,
     Vec<CompoundPredicate<Trigger>>,
+    Vec<DeadLetter>,
+    Vec<Delegation>,
+    Vec<DirectMessage>,
     Vec<Domain>,
     Vec<DomainId>,
+    Vec<Escrow>,
     Vec<EventFilterBox>,
+    Vec<FuelSchedule>,
     Vec<GenesisWasmTrigger>,
     Vec<InstructionBox>,
+    Vec<InstructionType>,
     Vec<Json>,
     Vec<Nft>,
     Vec<NftId>,
@@ -631,6 +794,7 @@ types!(
     Vec<AccountProjection<SelectorMarker>>,
     Vec<AssetDefinitionProjection<SelectorMarker>>,
     Vec<AssetProjection<SelectorMarker>>,
+    Vec<AssetWithDefinitionProjection<SelectorMarker>>,
     Vec<BlockHeaderProjection<SelectorMarker>>,
     Vec<CommittedTransactionProjection<SelectorMarker>>,
     Vec<DomainProjection<SelectorMarker>>,
@@ -642,6 +806,7 @@ types!(
     Vec<Numeric>,
     Vec<Option<HashOf<TransactionEntrypoint>>>,
     Vec<Option<HashOf<TransactionResult>>>,
+    Vec<ParameterProjection<SelectorMarker>>,
     Vec<PeerIdProjection<SelectorMarker>>,
     Vec<PermissionProjection<SelectorMarker>>,
     Vec<PublicKey>,
@@ -656,6 +821,7 @@ types!(
     Vec<TriggerProjection<SelectorMarker>>,
     Vec<Trigger>,
     Vec<TriggerId>,
+    Vec<TransferLeg>,
     Vec<u8>,
     WasmExecutionFail,
     WasmSmartContract,
@@ -685,7 +851,7 @@ pub mod complete_data_model {
         asset::NewAssetDefinition,
         block::{
             error::BlockRejectionReason,
-            stream::{BlockMessage, BlockSubscriptionRequest},
+            stream::{BlockHeaderMessage, BlockMessage, BlockSubscriptionRequest},
             BlockHeader, BlockPayload, BlockResult, BlockSignature, SignedBlock, SignedBlockV1,
         },
         domain::NewDomain,
@@ -709,9 +875,9 @@ pub mod complete_data_model {
             dsl::{CompoundPredicate, PredicateMarker, SelectorMarker},
             error::{FindError, QueryExecutionFail},
             parameters::{ForwardCursor, QueryParams},
-            CommittedTransaction, QueryOutput, QueryOutputBatchBox, QueryOutputBatchBoxTuple,
-            QueryRequestWithAuthority, QueryResponse, QuerySignature, QueryWithFilter,
-            QueryWithParams, SignedQuery, SignedQueryV1, SingularQueryOutputBox,
+            AssetWithDefinition, CommittedTransaction, QueryOutput, QueryOutputBatchBox,
+            QueryOutputBatchBoxTuple, QueryRequestWithAuthority, QueryResponse, QuerySignature,
+            QueryWithFilter, QueryWithParams, SignedQuery, SignedQueryV1, SingularQueryOutputBox,
         },
         transaction::{
             error::TransactionLimitError, SignedTransactionV1, TransactionPayload,
@@ -719,7 +885,7 @@ pub mod complete_data_model {
         },
         Level,
     };
-    pub use iroha_genesis::{GenesisWasmAction, GenesisWasmTrigger, WasmPath};
+    pub use iroha_genesis::{GenesisAnchor, GenesisWasmAction, GenesisWasmTrigger, WasmPath};
     pub use iroha_primitives::{
         addr::{Ipv4Addr, Ipv6Addr, SocketAddr, SocketAddrHost, SocketAddrV4, SocketAddrV6},
         const_vec::ConstVec,
@@ -727,7 +893,10 @@ pub mod complete_data_model {
         json::Json,
     };
     pub use iroha_schema::Compact;
-    pub use iroha_telemetry::metrics::{Status, Uptime};
+    pub use iroha_telemetry::{
+        metrics::{InstructionStats, Status, Uptime},
+        stats::NetworkStats,
+    };
 }
 
 #[cfg(test)]
@@ -768,11 +937,13 @@ mod tests {
         insert_into_test_map!(
             iroha_executor_data_model::permission::domain::CanModifyDomainMetadata
         );
+        insert_into_test_map!(iroha_executor_data_model::permission::domain::CanTransferDomain);
         insert_into_test_map!(iroha_executor_data_model::permission::account::CanRegisterAccount);
         insert_into_test_map!(iroha_executor_data_model::permission::account::CanUnregisterAccount);
         insert_into_test_map!(
             iroha_executor_data_model::permission::account::CanModifyAccountMetadata
         );
+        insert_into_test_map!(iroha_executor_data_model::permission::account::CanMessageAccount);
         insert_into_test_map!(
             iroha_executor_data_model::permission::asset_definition::CanRegisterAssetDefinition
         );
@@ -789,9 +960,13 @@ mod tests {
         insert_into_test_map!(
             iroha_executor_data_model::permission::asset::CanTransferAssetWithDefinition
         );
+        insert_into_test_map!(
+            iroha_executor_data_model::permission::asset::CanFreezeAssetWithDefinition
+        );
         insert_into_test_map!(iroha_executor_data_model::permission::asset::CanMintAsset);
         insert_into_test_map!(iroha_executor_data_model::permission::asset::CanBurnAsset);
         insert_into_test_map!(iroha_executor_data_model::permission::asset::CanTransferAsset);
+        insert_into_test_map!(iroha_executor_data_model::permission::asset::CanModifyAssetMetadata);
 
         insert_into_test_map!(iroha_executor_data_model::permission::nft::CanRegisterNft);
         insert_into_test_map!(iroha_executor_data_model::permission::nft::CanUnregisterNft);
@@ -810,6 +985,15 @@ mod tests {
         );
         insert_into_test_map!(iroha_executor_data_model::permission::executor::CanUpgradeExecutor);
 
+        insert_into_test_map!(iroha_executor_data_model::parameter::domain::DomainPolicies);
+        insert_into_test_map!(iroha_executor_data_model::parameter::domain::DomainPolicy);
+        insert_into_test_map!(
+            BTreeMap<DomainId, iroha_executor_data_model::parameter::domain::DomainPolicy>
+        );
+        insert_into_test_map!(
+            iroha_executor_data_model::parameter::governance::GovernanceParameters
+        );
+
         insert_into_test_map!(iroha_executor_data_model::isi::multisig::MultisigInstructionBox);
         insert_into_test_map!(iroha_executor_data_model::isi::multisig::MultisigRegister);
         insert_into_test_map!(iroha_executor_data_model::isi::multisig::MultisigPropose);
@@ -817,6 +1001,15 @@ mod tests {
         insert_into_test_map!(iroha_executor_data_model::isi::multisig::MultisigSpec);
         insert_into_test_map!(iroha_executor_data_model::isi::multisig::MultisigProposalValue);
 
+        insert_into_test_map!(iroha_executor_data_model::isi::preset::RolePresetInstructionBox);
+        insert_into_test_map!(iroha_executor_data_model::isi::preset::RegisterRolePreset);
+        insert_into_test_map!(iroha_executor_data_model::isi::preset::RolePreset);
+
+        insert_into_test_map!(iroha_executor_data_model::isi::governance::GovernanceInstructionBox);
+        insert_into_test_map!(iroha_executor_data_model::isi::governance::GovernancePropose);
+        insert_into_test_map!(iroha_executor_data_model::isi::governance::GovernanceVote);
+        insert_into_test_map!(iroha_executor_data_model::isi::governance::GovernanceProposalValue);
+
         map
     }
 
@@ -97,6 +97,11 @@ impl Iroha {
     }
 
     /// Build an iterable query for execution in a smart contract.
+    ///
+    /// Results are fetched from the host lazily, [`QueryBuilder::with_fetch_size`] at a time.
+    /// Large scans should select only the needed fields with [`QueryBuilder::select_with`]
+    /// and process the results with [`QueryIterator::next_batch`](iroha_data_model::query::builder::QueryIterator::next_batch),
+    /// so that neither the whole output is kept in memory nor the fuel is spent on decoding unneeded data.
     pub fn query<Q>(&self, query: Q) -> QueryBuilder<Self, Q, Q::Item>
     where
         Q: Query,
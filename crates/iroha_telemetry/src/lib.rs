@@ -5,10 +5,12 @@ pub mod dev;
 pub mod futures;
 pub mod metrics;
 mod retry_period;
+pub mod stats;
 pub mod ws;
 
 pub use iroha_config::parameters::actual::{
-    DevTelemetry as DevTelemetryConfig, Telemetry as TelemetryConfig,
+    DevTelemetry as DevTelemetryConfig, NetworkStats as NetworkStatsConfig,
+    Telemetry as TelemetryConfig,
 };
 pub use iroha_telemetry_derive::metrics;
 
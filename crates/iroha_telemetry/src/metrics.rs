@@ -1,7 +1,8 @@
 //! [`Metrics`] and [`Status`]-related logic and functions.
 
-use std::{ops::Deref, time::Duration};
+use std::{collections::BTreeMap, ops::Deref, time::Duration};
 
+use iroha_data_model::{domain::DomainId, isi::InstructionType};
 use iroha_schema::{Ident, IntoSchema, MetaMap, Metadata, TypeId, UnnamedFieldsMeta};
 use parity_scale_codec::{Compact, Decode, Encode};
 use prometheus::{
@@ -73,7 +74,7 @@ impl IntoSchema for Uptime {
 }
 
 /// Response body for GET status request
-#[derive(Clone, Copy, Debug, Default, Deserialize, Serialize, Encode, Decode, IntoSchema)]
+#[derive(Clone, Debug, Default, Deserialize, Serialize, Encode, Decode, IntoSchema)]
 pub struct Status {
     /// Number of currently connected peers excluding the reporting peer
     #[codec(compact)]
@@ -101,6 +102,10 @@ pub struct Status {
     /// Number of the transactions in the queue
     #[codec(compact)]
     pub queue_size: u64,
+    /// If set, Torii of the peer only accepts the instructions of these types
+    pub instructions_allowed: Option<Vec<InstructionType>>,
+    /// Torii of the peer refuses the instructions of these types
+    pub instructions_denied: Vec<InstructionType>,
 }
 
 impl<T: Deref<Target = Metrics>> From<&T> for Status {
@@ -120,10 +125,29 @@ impl<T: Deref<Target = Metrics>> From<&T> for Status {
                 .try_into()
                 .expect("INTERNAL BUG: Number of view changes exceeds u32::MAX"),
             queue_size: val.queue_size.get(),
+            instructions_allowed: None,
+            instructions_denied: Vec::new(),
         }
     }
 }
 
+/// Response body for GET instruction statistics request
+#[derive(
+    Clone, Debug, Default, PartialEq, Eq, Deserialize, Serialize, Encode, Decode, IntoSchema,
+)]
+pub struct InstructionStats {
+    /// Time window the instructions are counted over, ending now
+    #[codec(compact)]
+    pub window_ms: u64,
+    /// Number of blocks created within the window
+    #[codec(compact)]
+    pub blocks: u64,
+    /// Number of the executed instructions of each type
+    pub by_type: BTreeMap<InstructionType, u64>,
+    /// Number of the executed instructions of each type, by the domain of the transaction authority
+    pub by_domain: BTreeMap<DomainId, BTreeMap<InstructionType, u64>>,
+}
+
 /// A strict superset of [`Status`].
 #[derive(Debug, Clone)]
 pub struct Metrics {
@@ -139,6 +163,8 @@ pub struct Metrics {
     pub commit_time_ms: Histogram,
     /// Number of currently connected peers excluding the reporting peer
     pub connected_peers: GenericGauge<AtomicU64>,
+    /// Number of the connections of the peers outside of the topology rejected since the start
+    pub rejected_peer_connections: GenericGauge<AtomicU64>,
     /// Uptime of the network, starting from commit of the genesis block
     pub uptime_since_genesis_ms: GenericGauge<AtomicU64>,
     /// Number of domains.
@@ -157,6 +183,12 @@ pub struct Metrics {
     pub queue_size: GenericGauge<AtomicU64>,
     /// Number of sumeragi dropped messages
     pub dropped_messages: DroppedMessagesCounter,
+    /// Number of the changes of the asset quantities kept in the history
+    pub asset_history_entries: GenericGauge<AtomicU64>,
+    /// Number of the changes of the asset quantities dropped from the history by the retention
+    pub asset_history_pruned: IntCounter,
+    /// Number of the expired transactions dropped from the index by the retention
+    pub transactions_pruned: IntCounter,
     /// Internal use only. Needed for generating the response.
     registry: Registry,
 }
@@ -224,6 +256,11 @@ impl Default for Metrics {
             "Total number of currently connected peers",
         )
         .expect("Infallible");
+        let rejected_peer_connections = GenericGauge::new(
+            "rejected_peer_connections",
+            "Total number of rejected connections of peers outside of the topology",
+        )
+        .expect("Infallible");
         let uptime_since_genesis_ms = GenericGauge::new(
             "uptime_since_genesis_ms",
             "Network up-time, from creation of the genesis block",
@@ -244,6 +281,21 @@ impl Default for Metrics {
             .expect("Infallible");
         let dropped_messages =
             IntCounter::new("dropped_messages", "Sumeragi dropped messages").expect("Infallible");
+        let asset_history_entries = GenericGauge::new(
+            "asset_history_entries",
+            "Number of the changes of the asset quantities kept in the history",
+        )
+        .expect("Infallible");
+        let asset_history_pruned = IntCounter::new(
+            "asset_history_pruned",
+            "Changes of the asset quantities dropped from the history by the retention",
+        )
+        .expect("Infallible");
+        let transactions_pruned = IntCounter::new(
+            "transactions_pruned",
+            "Expired transactions dropped from the index by the retention",
+        )
+        .expect("Infallible");
         let registry = Registry::new();
 
         macro_rules! register {
@@ -264,6 +316,7 @@ impl Default for Metrics {
             last_commit_time_ms,
             commit_time_ms,
             connected_peers,
+            rejected_peer_connections,
             uptime_since_genesis_ms,
             domains,
             accounts,
@@ -271,7 +324,10 @@ impl Default for Metrics {
             isi_times,
             view_changes,
             queue_size,
-            dropped_messages
+            dropped_messages,
+            asset_history_entries,
+            asset_history_pruned,
+            transactions_pruned
         );
 
         Self {
@@ -281,6 +337,7 @@ impl Default for Metrics {
             last_commit_time_ms,
             commit_time_ms,
             connected_peers,
+            rejected_peer_connections,
             uptime_since_genesis_ms,
             domains,
             accounts,
@@ -290,6 +347,9 @@ impl Default for Metrics {
             view_changes,
             queue_size,
             dropped_messages,
+            asset_history_entries,
+            asset_history_pruned,
+            transactions_pruned,
             registry,
         }
     }
@@ -340,6 +400,8 @@ mod test {
             uptime: Uptime(Duration::new(5, 937_000_000)),
             view_changes: 2,
             queue_size: 18,
+            instructions_allowed: None,
+            instructions_denied: vec![InstructionType::Upgrade],
         }
     }
 
@@ -363,7 +425,11 @@ mod test {
                 "nanos": 937000000
               },
               "view_changes": 2,
-              "queue_size": 18
+              "queue_size": 18,
+              "instructions_allowed": null,
+              "instructions_denied": [
+                "Upgrade"
+              ]
             }"#]];
         expected.assert_eq(&actual);
     }
@@ -376,7 +442,7 @@ mod test {
         let actual = hex::encode_upper(bytes);
         // CAUTION: if this is outdated, make sure to update the documentation:
         // https://docs.iroha.tech/reference/torii-endpoints.html#status
-        let expected = expect_test::expect!["10140C09027C0C14407CD9370848"];
+        let expected = expect_test::expect!["10140C09027C0C14407CD937084800040E"];
         expected.assert_eq(&actual);
     }
 }
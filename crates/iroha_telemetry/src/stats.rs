@@ -0,0 +1,138 @@
+//! Opt-in reporting of anonymized aggregate statistics about the network.
+//!
+//! The peer periodically `POST`s [`NetworkStats`] as JSON to the configured collector,
+//! so that the ecosystem can publish dashboards of the network health.
+//! Nothing identifying the peer, its accounts or its transactions is reported.
+
+use std::{future::Future, time::Duration};
+
+use iroha_config::parameters::actual::NetworkStats as Config;
+use iroha_schema::IntoSchema;
+use parity_scale_codec::{Decode, Encode};
+use serde::{Deserialize, Serialize};
+use tokio::task::JoinHandle;
+
+use crate::metrics::Status;
+
+/// Timeout of a single report
+const TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Payload reported to the collector.
+///
+/// Its schema is published along with the rest of the Iroha schema.
+#[derive(
+    Clone, Debug, Default, PartialEq, Eq, Deserialize, Serialize, Encode, Decode, IntoSchema,
+)]
+pub struct NetworkStats {
+    /// Version of the reporting peer
+    pub version: String,
+    /// Number of peers connected to the reporting peer
+    #[codec(compact)]
+    pub peers: u64,
+    /// Number of committed blocks (blockchain height)
+    #[codec(compact)]
+    pub blocks: u64,
+    /// Transactions approved per second over the reporting period, multiplied by 1000
+    #[codec(compact)]
+    pub tps_milli: u64,
+    /// Average time between the blocks committed over the reporting period, 0 if there were none
+    #[codec(compact)]
+    pub block_time_ms: u64,
+}
+
+impl NetworkStats {
+    /// Aggregate the statistics over the `period` between the `previous` and the `current` observations.
+    pub fn aggregate(previous: &Status, current: &Status, period: Duration) -> Self {
+        let period_ms = u64::try_from(period.as_millis()).unwrap_or(u64::MAX).max(1);
+        let txs = current.txs_approved.saturating_sub(previous.txs_approved);
+        let blocks = current.blocks.saturating_sub(previous.blocks);
+
+        Self {
+            version: env!("CARGO_PKG_VERSION").to_owned(),
+            peers: current.peers,
+            blocks: current.blocks,
+            tps_milli: txs.saturating_mul(1_000_000) / period_ms,
+            block_time_ms: period_ms.checked_div(blocks).unwrap_or(0),
+        }
+    }
+}
+
+/// Start reporting the statistics aggregated from the [`Status`] observed with `status` every `period`.
+///
+/// Reporting is best-effort: failures are logged and the next report is attempted on schedule.
+pub fn start<F, Fut>(Config { url, period }: Config, status: F) -> JoinHandle<()>
+where
+    F: Fn() -> Fut + Send + 'static,
+    Fut: Future<Output = Status> + Send,
+{
+    iroha_logger::info!(%url, ?period, "Starting network statistics reporting");
+    tokio::task::spawn(async move {
+        let mut interval = tokio::time::interval(period);
+        interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+        interval.tick().await;
+        let mut previous = (status().await, tokio::time::Instant::now());
+
+        loop {
+            interval.tick().await;
+            let current = (status().await, tokio::time::Instant::now());
+            let stats = NetworkStats::aggregate(&previous.0, &current.0, current.1 - previous.1);
+            previous = current;
+
+            let body = serde_json::to_vec(&stats).expect("serialization is infallible");
+            let url = url.clone();
+            let result = tokio::task::spawn_blocking(move || {
+                attohttpc::post(url.as_str())
+                    .header("content-type", "application/json")
+                    .timeout(TIMEOUT)
+                    .bytes(body)
+                    .send()
+                    .and_then(attohttpc::Response::error_for_status)
+            })
+            .await
+            .expect("reporting task must not panic");
+            match result {
+                Ok(_) => iroha_logger::debug!(?stats, "Network statistics reported"),
+                Err(error) => iroha_logger::warn!(%error, "Failed to report network statistics"),
+            }
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn aggregates_over_period() {
+        let previous = Status {
+            peers: 3,
+            blocks: 10,
+            txs_approved: 100,
+            ..Status::default()
+        };
+        let current = Status {
+            peers: 4,
+            blocks: 15,
+            txs_approved: 250,
+            txs_rejected: 7,
+            ..Status::default()
+        };
+
+        let stats = NetworkStats::aggregate(&previous, &current, Duration::from_secs(10));
+
+        assert_eq!(stats.peers, 4);
+        assert_eq!(stats.blocks, 15);
+        assert_eq!(stats.tps_milli, 15_000);
+        assert_eq!(stats.block_time_ms, 2_000);
+    }
+
+    #[test]
+    fn no_blocks_no_block_time() {
+        let status = Status::default();
+
+        let stats = NetworkStats::aggregate(&status, &status, Duration::from_secs(10));
+
+        assert_eq!(stats.tps_milli, 0);
+        assert_eq!(stats.block_time_ms, 0);
+    }
+}
@@ -6,6 +6,7 @@ use iroha_config::base::toml::WriteExt;
 use iroha_data_model::{
     asset::AssetDefinitionId,
     isi::{Grant, Instruction},
+    parameter::{Parameter, SumeragiParameter},
     peer::PeerId,
     ChainId,
 };
@@ -48,7 +49,10 @@ pub fn genesis<T: Instruction>(
         .unwrap();
     let genesis = RawGenesisTransaction::from_path(&json_path)
         .unwrap_or_else(|err| panic!("failed to parse {}\n{err}", json_path.display()));
-    let mut builder = genesis.into_builder();
+    // Tests observe the peer set changes right after they are committed
+    let mut builder = genesis.into_builder().append_parameter(Parameter::Sumeragi(
+        SumeragiParameter::PeerActivationDelayBlocks(0),
+    ));
 
     let rose_definition_id = "rose#wonderland".parse::<AssetDefinitionId>().unwrap();
     let grant_modify_rose_permission = Grant::account_permission(
@@ -469,6 +469,16 @@ impl NetworkBuilder {
         self
     }
 
+    /// Keep the blocks of the peers in memory only, so that the network doesn't touch the disk.
+    ///
+    /// Snapshots are already disabled in the base configuration. The blocks are lost once a peer
+    /// stops, so a restarted peer has to fetch the whole chain from the others.
+    pub fn with_in_memory_storage(self) -> Self {
+        self.with_config_layer(|t| {
+            t.write(["kura", "store_mode"], "memory");
+        })
+    }
+
     /// Set [`WasmFuelConfig`].
     ///
     /// [`WasmFuelConfig::Auto`] by default.
@@ -792,7 +802,7 @@ impl NetworkPeer {
                     )
                     .await
                     .expect("there is no max elapsed time");
-                    let mut block_height = BlockHeight::from(status);
+                    let mut block_height = BlockHeight::from(&status);
                     let _ = events_tx.send(PeerLifecycleEvent::ServerStarted);
                     let _ = block_height_tx.send_replace(Some(block_height));
                     info!(?status, "server started");
@@ -1233,8 +1243,8 @@ pub struct BlockHeight {
     pub non_empty: u64,
 }
 
-impl From<Status> for BlockHeight {
-    fn from(value: Status) -> Self {
+impl From<&Status> for BlockHeight {
+    fn from(value: &Status) -> Self {
         Self {
             total: value.blocks,
             non_empty: value.blocks_non_empty,
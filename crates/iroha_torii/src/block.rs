@@ -2,7 +2,7 @@ use std::{num::NonZeroU64, sync::Arc};
 
 use iroha_core::kura::Kura;
 use iroha_data_model::block::{
-    stream::{BlockMessage, BlockSubscriptionRequest},
+    stream::{BlockHeaderMessage, BlockMessage, BlockSubscriptionRequest},
     SignedBlock,
 };
 
@@ -32,20 +32,27 @@ pub struct Consumer<'ws> {
     pub stream: &'ws mut WebSocketScale,
     height: NonZeroU64,
     kura: Arc<Kura>,
+    headers_only: bool,
 }
 
 impl<'ws> Consumer<'ws> {
     /// Constructs [`Consumer`], which forwards blocks through the `stream`.
+    /// If `headers_only` is set, only [`BlockHeaderMessage`]s are forwarded.
     ///
     /// # Errors
     /// Can fail due to timeout or without message at websocket or during decoding request
     #[iroha_futures::telemetry_future]
-    pub async fn new(stream: &'ws mut WebSocketScale, kura: Arc<Kura>) -> Result<Self> {
+    pub async fn new(
+        stream: &'ws mut WebSocketScale,
+        kura: Arc<Kura>,
+        headers_only: bool,
+    ) -> Result<Self> {
         let BlockSubscriptionRequest(height) = stream.recv().await?;
         Ok(Consumer {
             stream,
             height,
             kura,
+            headers_only,
         })
     }
 
@@ -60,10 +67,14 @@ impl<'ws> Consumer<'ws> {
                 .try_into()
                 .expect("INTERNAL BUG: Number of blocks exceeds usize::MAX"),
         ) {
-            // TODO: to avoid clone `BlockMessage` could be split into sending and receiving parts
-            self.stream
-                .send(BlockMessage(SignedBlock::clone(&block)))
-                .await?;
+            if self.headers_only {
+                self.stream.send(BlockHeaderMessage::from(&*block)).await?;
+            } else {
+                // TODO: to avoid clone `BlockMessage` could be split into sending and receiving parts
+                self.stream
+                    .send(BlockMessage(SignedBlock::clone(&block)))
+                    .await?;
+            }
             self.height = self
                 .height
                 .checked_add(1)
@@ -2,7 +2,26 @@
 //! This module contains descriptions of such an events and
 //! utility Iroha Special Instructions to work with them.
 
-use iroha_data_model::events::prelude::*;
+use std::{
+    collections::{BTreeSet, HashMap, VecDeque},
+    num::NonZeroUsize,
+    sync::{Arc, Mutex, MutexGuard},
+    time::Duration,
+};
+
+use iroha_core::{
+    query::scope::DomainScope,
+    state::{State, StateReadOnly, WorldReadOnly},
+};
+use iroha_data_model::{
+    domain::DomainId,
+    events::prelude::*,
+    peer::PeerId,
+    prelude::Hash,
+    subscription::{SubscriptionDelivery, SubscriptionId},
+};
+use parity_scale_codec::Encode;
+use tokio::sync::{broadcast, Semaphore};
 
 use crate::stream::{self, WebSocketScale};
 
@@ -29,6 +48,10 @@ pub type Result<T> = core::result::Result<T, Error>;
 pub struct Consumer<'ws> {
     pub stream: &'ws mut WebSocketScale,
     filters: Vec<EventFilterBox>,
+    scope: Option<DomainScope>,
+    sequenced: bool,
+    /// Sequence of the last replayed event, the ones up to it are skipped once they arrive anew.
+    replayed: Option<EventSequence>,
 }
 
 impl<'ws> Consumer<'ws> {
@@ -39,7 +62,44 @@ impl<'ws> Consumer<'ws> {
     #[iroha_futures::telemetry_future]
     pub async fn new(stream: &'ws mut WebSocketScale) -> Result<Self> {
         let EventSubscriptionRequest(filters) = stream.recv::<EventSubscriptionRequest>().await?;
-        Ok(Consumer { stream, filters })
+        Ok(Self::with_filters(stream, filters))
+    }
+
+    /// Constructs [`Consumer`] of the sequenced events, returning the sequence of the event
+    /// after which the client resumes the stream, if any.
+    ///
+    /// # Errors
+    /// Can fail due to timeout or without message at websocket or during decoding request
+    #[iroha_futures::telemetry_future]
+    pub async fn resumed(stream: &'ws mut WebSocketScale) -> Result<(Self, Option<EventSequence>)> {
+        let SequencedEventSubscriptionRequest { filters, after } =
+            stream.recv::<SequencedEventSubscriptionRequest>().await?;
+        Ok((Self::with_filters(stream, filters), after))
+    }
+
+    /// Constructs [`Consumer`] forwarding the events matching any of the `filters` through the `stream`.
+    pub fn with_filters(stream: &'ws mut WebSocketScale, filters: Vec<EventFilterBox>) -> Self {
+        Consumer {
+            stream,
+            filters,
+            scope: None,
+            sequenced: true,
+            replayed: None,
+        }
+    }
+
+    /// Forward the bare events without their sequences, as `/events` did before the sequences were introduced.
+    #[must_use]
+    pub fn unsequenced(mut self) -> Self {
+        self.sequenced = false;
+        self
+    }
+
+    /// Forward only the events visible in the `scope`, if any.
+    #[must_use]
+    pub fn in_scope(mut self, scope: Option<DomainScope>) -> Self {
+        self.scope = scope;
+        self
     }
 
     /// Forwards the `event` over the `stream` if it matches the `filter`.
@@ -47,14 +107,646 @@ impl<'ws> Consumer<'ws> {
     /// # Errors
     /// Can fail due to timeout or sending event. Also receiving might fail
     #[iroha_futures::telemetry_future]
-    pub async fn consume(&mut self, event: EventBox) -> Result<()> {
-        if !self.filters.iter().any(|filter| filter.matches(&event)) {
+    pub async fn consume(&mut self, message: EventMessage) -> Result<()> {
+        if message.sequence.is_some() && message.sequence <= self.replayed
+            || !self
+                .filters
+                .iter()
+                .any(|filter| filter.matches(&message.event))
+            || !self
+                .scope
+                .as_ref()
+                .is_none_or(|scope| scope.allows_event(&message.event))
+        {
             return Ok(());
         }
 
+        if self.sequenced {
+            self.stream
+                .send(SequencedEventMessage::Event(message))
+                .await
+        } else {
+            self.stream.send(message.event).await
+        }
+        .map_err(Into::into)
+    }
+
+    /// Forward the `events` kept by the peer, skipping them once they arrive anew.
+    ///
+    /// # Errors
+    /// Can fail due to timeout or sending event
+    pub async fn replay(&mut self, events: Vec<EventMessage>) -> Result<()> {
+        for message in events {
+            let sequence = message.sequence;
+            self.consume(message).await?;
+            self.replayed = self.replayed.max(sequence);
+        }
+        Ok(())
+    }
+
+    /// Tell the client that some of the events it resumes the stream after are lost.
+    ///
+    /// # Errors
+    /// Can fail due to timeout or sending the message
+    pub async fn lost(&mut self) -> Result<()> {
         self.stream
-            .send(EventMessage(event))
+            .send(SequencedEventMessage::Lost)
             .await
             .map_err(Into::into)
     }
 }
+
+/// Assigns [`EventSequence`]s to the events emitted by the committed blocks.
+///
+/// The events of a block are emitted on its commit, ending with its [`BlockStatus::Applied`]
+/// event, so every peer assigns them the same sequences. The events emitted by the peer
+/// on its own, such as the statuses of the transactions in its queue, aren't sequenced.
+#[derive(Debug, Clone, Copy)]
+pub struct Sequencer {
+    /// Height of the block emitting the current events.
+    block_height: u64,
+    /// Index of the next event of the block, `None` if some of its events were lost.
+    next_index: Option<u64>,
+}
+
+impl Sequencer {
+    /// Construct [`Sequencer`] continuing after the block at `block_height`.
+    pub fn new(block_height: u64) -> Self {
+        Self {
+            block_height: block_height + 1,
+            next_index: Some(0),
+        }
+    }
+
+    /// Assign the next sequence number to the `event`.
+    pub fn sequence(&mut self, event: EventBox) -> EventMessage {
+        let sequence = match &event {
+            EventBox::Pipeline(PipelineEventBox::Block(block))
+                if matches!(block.status(), BlockStatus::Applied) =>
+            {
+                let block_height = block.header().height().get();
+                let sequence = self
+                    .next_index
+                    .map(|index| EventSequence::new(block_height, index));
+                *self = Self::new(block_height);
+                sequence
+            }
+            EventBox::Pipeline(_) => None,
+            _ => {
+                let sequence = self
+                    .next_index
+                    .map(|index| EventSequence::new(self.block_height, index));
+                self.next_index = self.next_index.map(|index| index + 1);
+                sequence
+            }
+        };
+
+        EventMessage::new(sequence, event)
+    }
+
+    /// Stop sequencing the events of the current block, as some of them were lost.
+    pub fn lost(&mut self) {
+        self.next_index = None;
+    }
+
+    /// Sequence every event received from `events` and forward it to `sequenced`.
+    ///
+    /// Runs until `events` channel is closed.
+    #[iroha_futures::telemetry_future]
+    pub async fn run(
+        mut self,
+        mut events: broadcast::Receiver<EventBox>,
+        sequenced: broadcast::Sender<EventMessage>,
+    ) {
+        loop {
+            match events.recv().await {
+                Ok(event) => {
+                    // NOTE: error means there are no subscribers at the moment
+                    let _ = sequenced.send(self.sequence(event));
+                }
+                Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                    iroha_logger::warn!(skipped, "Event sequencer lagged behind, events are lost");
+                    self.lost();
+                }
+                Err(broadcast::error::RecvError::Closed) => break,
+            }
+        }
+    }
+}
+
+/// Recent sequenced events kept to be replayed to the consumers resuming their streams.
+///
+/// Whole blocks are kept: a stream resumed after an event of a block which isn't kept
+/// anymore, or whose events were partly lost by the peer, misses some of the events.
+/// Cloning is cheap: the clones share the events.
+#[derive(Debug, Clone)]
+pub struct Replay(Arc<Mutex<Kept>>);
+
+#[derive(Debug)]
+struct Kept {
+    events: VecDeque<EventMessage>,
+    /// Number of the events kept, see [`Retention::events_max_entries`](iroha_config::parameters::actual::Retention::events_max_entries)
+    capacity: NonZeroUsize,
+    /// All events of the blocks from this height on are kept, `None` until the current block is over
+    /// if some of its events were lost.
+    from_height: Option<u64>,
+}
+
+impl Replay {
+    /// Construct [`Replay`] keeping up to `capacity` events of the blocks after the one at `block_height`.
+    pub fn new(block_height: u64, capacity: NonZeroUsize) -> Self {
+        Self(Arc::new(Mutex::new(Kept {
+            events: VecDeque::new(),
+            capacity,
+            from_height: Some(block_height + 1),
+        })))
+    }
+
+    fn lock(&self) -> MutexGuard<'_, Kept> {
+        self.0.lock().expect("replay lock shouldn't be poisoned")
+    }
+
+    /// Kept events following the one with sequence `after`, and whether none of them is missing.
+    pub fn after(&self, after: EventSequence) -> (Vec<EventMessage>, bool) {
+        let kept = self.lock();
+        let complete = kept
+            .from_height
+            .is_some_and(|from_height| after.block_height >= from_height);
+        let events = kept
+            .events
+            .iter()
+            .filter(|message| message.sequence > Some(after))
+            .cloned()
+            .collect();
+        (events, complete)
+    }
+
+    /// Keep the `message`, dropping the events of the oldest block once over the capacity.
+    fn keep(&self, message: EventMessage) {
+        let mut kept = self.lock();
+        let applied = match &message.event {
+            EventBox::Pipeline(PipelineEventBox::Block(block))
+                if matches!(block.status(), BlockStatus::Applied) =>
+            {
+                Some(block.header().height().get())
+            }
+            _ => None,
+        };
+
+        match message.sequence {
+            Some(_) if kept.from_height.is_some() => {
+                kept.events.push_back(message);
+                if kept.events.len() > kept.capacity.get() {
+                    kept.drop_oldest_block();
+                }
+            }
+            // NOTE: the sequencer lost some of the events of the block
+            None if !matches!(message.event, EventBox::Pipeline(_)) => kept.lost(),
+            _ => {}
+        }
+        if let Some(block_height) = applied {
+            kept.from_height.get_or_insert(block_height + 1);
+        }
+    }
+
+    /// Keep the sequenced events received from `events`.
+    ///
+    /// Runs until `events` channel is closed.
+    #[iroha_futures::telemetry_future]
+    pub async fn run(self, mut events: broadcast::Receiver<EventMessage>) {
+        loop {
+            match events.recv().await {
+                Ok(message) => self.keep(message),
+                Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                    iroha_logger::warn!(skipped, "Event replay lagged behind, events are lost");
+                    self.lock().lost();
+                }
+                Err(broadcast::error::RecvError::Closed) => break,
+            }
+        }
+    }
+}
+
+impl Kept {
+    /// Drop the events, as some of the ones of the current block were lost.
+    fn lost(&mut self) {
+        self.events.clear();
+        self.from_height = None;
+    }
+
+    fn drop_oldest_block(&mut self) {
+        let Some(oldest) = self.events.front().and_then(|message| message.sequence) else {
+            return;
+        };
+        self.from_height = Some(oldest.block_height + 1);
+        self.events.retain(|message| {
+            message
+                .sequence
+                .is_some_and(|sequence| sequence.block_height > oldest.block_height)
+        });
+    }
+}
+
+/// Per-domain broadcast channels of the sequenced events.
+///
+/// Consumers of a single domain, e.g. a tenant of a multi-tenant chain, receive only the events of the domain
+/// instead of filtering the events of the whole chain.
+/// The channel of a domain is opened by its first subscriber and closed once the last one is gone.
+/// Cloning is cheap: the clones share the channels.
+#[derive(Debug, Clone, Default)]
+pub struct DomainShards(Arc<Mutex<HashMap<DomainId, broadcast::Sender<EventMessage>>>>);
+
+impl DomainShards {
+    const BUFFER_CAPACITY: usize = 1_000;
+
+    /// Subscribe to the events of the `domain`.
+    pub fn subscribe(&self, domain: DomainId) -> broadcast::Receiver<EventMessage> {
+        self.0
+            .lock()
+            .expect("domain shards lock shouldn't be poisoned")
+            .entry(domain)
+            .or_insert_with(|| broadcast::channel(Self::BUFFER_CAPACITY).0)
+            .subscribe()
+    }
+
+    /// Domain the `event` belongs to, if it belongs to a single one
+    fn domain_of(event: &EventBox) -> Option<&DomainId> {
+        match event {
+            EventBox::Data(event) => event.domain(),
+            EventBox::ExecuteTrigger(event) => Some(event.authority().domain()),
+            _ => None,
+        }
+    }
+
+    /// Forward the `message` to the subscribers of its domain, if any.
+    fn dispatch(&self, message: EventMessage) {
+        let Some(domain) = Self::domain_of(&message.event).cloned() else {
+            return;
+        };
+        let mut shards = self
+            .0
+            .lock()
+            .expect("domain shards lock shouldn't be poisoned");
+        if let Some(sender) = shards.get(&domain) {
+            // NOTE: error means the last subscriber of the domain is gone
+            if sender.send(message).is_err() {
+                shards.remove(&domain);
+            }
+        }
+    }
+
+    /// Dispatch every sequenced event received from `events` to the channel of its domain.
+    ///
+    /// Runs until `events` channel is closed.
+    #[iroha_futures::telemetry_future]
+    pub async fn run(self, mut events: broadcast::Receiver<EventMessage>) {
+        loop {
+            match events.recv().await {
+                Ok(message) => self.dispatch(message),
+                Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                    iroha_logger::warn!(skipped, "Domain shards lagged behind, events are lost");
+                }
+                Err(broadcast::error::RecvError::Closed) => break,
+            }
+        }
+    }
+}
+
+/// Delivers events to the webhooks of on-chain subscriptions.
+///
+/// Every subscription is delivered by a single peer designated by the hash of its id,
+/// so the webhooks don't receive each event once per peer. Only the hosts allowed in
+/// the configuration of the peer are delivered to.
+#[derive(Debug)]
+pub struct WebhookDelivery {
+    peer_id: PeerId,
+    state: Arc<State>,
+    allowed_hosts: BTreeSet<String>,
+    agent: ureq::Agent,
+    in_flight: Arc<Semaphore>,
+}
+
+impl WebhookDelivery {
+    const TIMEOUT: Duration = Duration::from_secs(5);
+    const MAX_IN_FLIGHT: usize = 16;
+
+    /// Construct [`WebhookDelivery`] on behalf of the peer with `peer_id`,
+    /// delivering only to the `allowed_hosts`.
+    pub fn new(peer_id: PeerId, state: Arc<State>, allowed_hosts: BTreeSet<String>) -> Self {
+        Self {
+            peer_id,
+            state,
+            allowed_hosts,
+            agent: ureq::AgentBuilder::new().timeout(Self::TIMEOUT).build(),
+            in_flight: Arc::new(Semaphore::new(Self::MAX_IN_FLIGHT)),
+        }
+    }
+
+    /// Peer among `peers` designated to deliver the subscription with `id`.
+    fn designated<'peers>(id: &SubscriptionId, peers: &'peers [PeerId]) -> Option<&'peers PeerId> {
+        let hash = Hash::new(id.encode());
+        let bytes: [u8; 8] = hash.as_ref()[..8]
+            .try_into()
+            .expect("INTERNAL BUG: hash is longer than 8 bytes");
+        let index = u64::from_le_bytes(bytes) % u64::try_from(peers.len()).ok()?.max(1);
+        peers.get(usize::try_from(index).expect("INTERNAL BUG: index is below the length"))
+    }
+
+    /// Whether the host of `url` is among the `allowed_hosts`.
+    fn is_allowed(allowed_hosts: &BTreeSet<String>, url: &str) -> bool {
+        url::Url::parse(url).is_ok_and(|url| {
+            url.host_str()
+                .is_some_and(|host| allowed_hosts.contains(host))
+        })
+    }
+
+    /// Webhooks subscribed to the `message` which this peer is designated to deliver.
+    fn recipients(&self, message: &EventMessage) -> Vec<String> {
+        if self.allowed_hosts.is_empty() {
+            return Vec::new();
+        }
+        let state_view = self.state.view();
+        let peers = state_view.world().peers();
+
+        state_view
+            .world()
+            .subscriptions_iter()
+            .filter(|subscription| {
+                Self::designated(subscription.id(), peers) == Some(&self.peer_id)
+            })
+            .filter(|subscription| subscription.filter().matches(&message.event))
+            .filter_map(|subscription| match subscription.delivery() {
+                SubscriptionDelivery::Webhook(url)
+                    if Self::is_allowed(&self.allowed_hosts, url) =>
+                {
+                    Some(url.clone())
+                }
+                SubscriptionDelivery::Webhook(url) => {
+                    iroha_logger::debug!(%url, "Webhook host isn't allowed, skipping");
+                    None
+                }
+                SubscriptionDelivery::Inbox => None,
+            })
+            .collect()
+    }
+
+    /// `POST` every sequenced event received from `events` to the subscribed webhooks.
+    ///
+    /// Runs until `events` channel is closed.
+    #[iroha_futures::telemetry_future]
+    pub async fn run(self, mut events: broadcast::Receiver<EventMessage>) {
+        loop {
+            match events.recv().await {
+                Ok(message) => {
+                    let recipients = self.recipients(&message);
+                    if recipients.is_empty() {
+                        continue;
+                    }
+                    let body = message.encode();
+                    // NOTE: waiting here makes the delivery lag behind rather than pile up requests
+                    let Ok(permit) = Arc::clone(&self.in_flight).acquire_owned().await else {
+                        break;
+                    };
+                    let agent = self.agent.clone();
+                    // NOTE: delivery is best-effort, subscribers resynchronize using event sequences
+                    tokio::task::spawn_blocking(move || {
+                        for url in recipients {
+                            if let Err(error) = agent
+                                .post(&url)
+                                .set("content-type", "application/x-parity-scale")
+                                .send_bytes(&body)
+                            {
+                                iroha_logger::warn!(%url, %error, "Failed to deliver event to webhook");
+                            }
+                        }
+                        drop(permit);
+                    });
+                }
+                Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                    iroha_logger::warn!(skipped, "Webhook delivery lagged behind, events are lost");
+                }
+                Err(broadcast::error::RecvError::Closed) => break,
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::num::NonZeroU64;
+
+    use iroha_data_model::{account::AccountId, block::BlockHeader, prelude::KeyPair};
+
+    use super::*;
+
+    fn block_event(height: u64, status: BlockStatus) -> EventBox {
+        let header = BlockHeader {
+            height: NonZeroU64::new(height).unwrap(),
+            prev_block_hash: None,
+            merkle_root: None,
+            result_merkle_root: None,
+            creation_time_ms: 0,
+            view_change_index: 0,
+        };
+        BlockEvent { header, status }.into()
+    }
+
+    #[test]
+    fn events_are_sequenced_by_their_block() {
+        let domain_deleted = || {
+            EventBox::from(DataEvent::from(DomainEvent::Deleted(
+                "wonderland".parse().unwrap(),
+            )))
+        };
+        let events = || {
+            [
+                block_event(2, BlockStatus::Created),
+                domain_deleted(),
+                domain_deleted(),
+                block_event(2, BlockStatus::Committed),
+                block_event(2, BlockStatus::Applied),
+                domain_deleted(),
+                block_event(3, BlockStatus::Applied),
+            ]
+        };
+        let sequences = |mut sequencer: Sequencer| {
+            events()
+                .into_iter()
+                .map(|event| sequencer.sequence(event).sequence)
+                .collect::<Vec<_>>()
+        };
+
+        let expected = [
+            None,
+            Some(EventSequence::new(2, 0)),
+            Some(EventSequence::new(2, 1)),
+            None,
+            Some(EventSequence::new(2, 2)),
+            Some(EventSequence::new(3, 0)),
+            Some(EventSequence::new(3, 1)),
+        ];
+        // NOTE: the same sequences are assigned by a restarted peer
+        assert_eq!(sequences(Sequencer::new(1)), expected);
+        assert_eq!(sequences(Sequencer::new(1)), expected);
+
+        let mut sequencer = Sequencer::new(1);
+        sequencer.lost();
+        assert_eq!(
+            sequences(sequencer),
+            [None, None, None, None, None, expected[5], expected[6]]
+        );
+    }
+
+    #[test]
+    fn kept_events_are_replayed_after_resume_point() {
+        let domain_deleted = || {
+            EventBox::from(DataEvent::from(DomainEvent::Deleted(
+                "wonderland".parse().unwrap(),
+            )))
+        };
+        let mut sequencer = Sequencer::new(1);
+        let replay = Replay::new(1, nonzero_ext::nonzero!(10_usize));
+        for event in [
+            domain_deleted(),
+            block_event(2, BlockStatus::Applied),
+            domain_deleted(),
+            domain_deleted(),
+            block_event(3, BlockStatus::Applied),
+        ] {
+            replay.keep(sequencer.sequence(event));
+        }
+        let sequences = |after| {
+            let (events, complete) = replay.after(after);
+            let sequences = events
+                .into_iter()
+                .map(|message| message.sequence.unwrap())
+                .collect::<Vec<_>>();
+            (sequences, complete)
+        };
+
+        assert_eq!(
+            sequences(EventSequence::new(2, 1)),
+            (
+                vec![
+                    EventSequence::new(3, 0),
+                    EventSequence::new(3, 1),
+                    EventSequence::new(3, 2)
+                ],
+                true
+            )
+        );
+        assert_eq!(
+            sequences(EventSequence::new(3, 1)),
+            (vec![EventSequence::new(3, 2)], true)
+        );
+        // NOTE: the events of the block before the peer started aren't kept
+        assert!(!sequences(EventSequence::new(1, 0)).1);
+
+        replay.keep(sequencer.sequence(domain_deleted()));
+        replay.lock().lost();
+        replay.keep(sequencer.sequence(block_event(4, BlockStatus::Applied)));
+        assert_eq!(sequences(EventSequence::new(3, 2)), (vec![], false));
+        assert_eq!(sequences(EventSequence::new(5, 0)), (vec![], true));
+    }
+
+    #[test]
+    fn oldest_block_is_dropped_over_capacity() {
+        let domain_deleted = || {
+            EventBox::from(DataEvent::from(DomainEvent::Deleted(
+                "wonderland".parse().unwrap(),
+            )))
+        };
+        let mut sequencer = Sequencer::new(1);
+        let replay = Replay::new(1, nonzero_ext::nonzero!(3_usize));
+        for event in [
+            domain_deleted(),
+            domain_deleted(),
+            block_event(2, BlockStatus::Applied),
+            domain_deleted(),
+        ] {
+            replay.keep(sequencer.sequence(event));
+        }
+
+        let (events, complete) = replay.after(EventSequence::new(2, 0));
+        assert_eq!(events.len(), 1);
+        assert!(!complete);
+        assert!(replay.after(EventSequence::new(3, 0)).1);
+    }
+
+    #[test]
+    fn events_are_dispatched_to_subscribers_of_their_domain() {
+        let shards = DomainShards::default();
+        let wonderland: DomainId = "wonderland".parse().unwrap();
+        let mut wonderland_events = shards.subscribe(wonderland.clone());
+        let mut garden_events = shards.subscribe("garden_of_live_flowers".parse().unwrap());
+        let message = |event: EventBox| EventMessage::new(Some(EventSequence::new(1, 0)), event);
+        let domain_deleted = || DataEvent::from(DomainEvent::Deleted(wonderland.clone())).into();
+
+        shards.dispatch(message(domain_deleted()));
+        shards.dispatch(message(block_event(2, BlockStatus::Committed)));
+
+        assert!(matches!(
+            wonderland_events.try_recv().unwrap().event,
+            EventBox::Data(_)
+        ));
+        assert!(wonderland_events.try_recv().is_err());
+        assert!(garden_events.try_recv().is_err());
+
+        drop(wonderland_events);
+        shards.dispatch(message(domain_deleted()));
+        assert!(!shards.0.lock().unwrap().contains_key(&wonderland));
+    }
+
+    #[test]
+    fn every_subscription_is_delivered_by_one_peer() {
+        let peers: Vec<PeerId> = (0..4)
+            .map(|_| PeerId::new(KeyPair::random().into_parts().0))
+            .collect();
+        let account = AccountId::new(
+            "wonderland".parse().unwrap(),
+            KeyPair::random().into_parts().0,
+        );
+        let designated: BTreeSet<_> = (0..32)
+            .map(|index| {
+                let id =
+                    SubscriptionId::new(account.clone(), format!("hook_{index}").parse().unwrap());
+                let peer = WebhookDelivery::designated(&id, &peers).unwrap();
+                assert_eq!(WebhookDelivery::designated(&id, &peers), Some(peer));
+                peer
+            })
+            .collect();
+
+        assert!(
+            designated.len() > 1,
+            "subscriptions are spread over the peers"
+        );
+        assert!(WebhookDelivery::designated(
+            &SubscriptionId::new(account, "hook".parse().unwrap()),
+            &[]
+        )
+        .is_none());
+    }
+
+    #[test]
+    fn only_allowed_hosts_are_delivered_to() {
+        let allowed_hosts = BTreeSet::from(["hooks.example.com".to_owned()]);
+
+        assert!(WebhookDelivery::is_allowed(
+            &allowed_hosts,
+            "https://hooks.example.com/events"
+        ));
+        assert!(WebhookDelivery::is_allowed(
+            &allowed_hosts,
+            "http://hooks.example.com:8080"
+        ));
+        assert!(!WebhookDelivery::is_allowed(
+            &allowed_hosts,
+            "http://169.254.169.254/latest"
+        ));
+        assert!(!WebhookDelivery::is_allowed(
+            &allowed_hosts,
+            "https://hooks.example.com.evil.org"
+        ));
+        assert!(!WebhookDelivery::is_allowed(&allowed_hosts, "not a url"));
+    }
+}
@@ -3,13 +3,20 @@
 //! Crate provides the following features that are not enabled by default:
 //!
 //! - `telemetry`: enables Status, Metrics, and API Version endpoints
-//! - `schema`: enables Data Model Schema endpoint
+//! - `schema`: enables Data Model Schema and Schema Bundle endpoints
+//! - `profiling`: enables CPU profiling endpoint
+//! - `replay`: enables transaction replay endpoint
 
-use std::{collections::HashSet, fmt::Debug, sync::Arc, time::Duration};
+use std::{
+    collections::{BTreeSet, HashSet},
+    fmt::Debug,
+    sync::Arc,
+    time::Duration,
+};
 
 use axum::{
     extract::{DefaultBodyLimit, WebSocketUpgrade},
-    http::StatusCode,
+    http::{Extensions, HeaderMap, StatusCode, Version},
     response::{IntoResponse, Json, Response},
     routing::{get, post},
     Router,
@@ -17,7 +24,7 @@ use axum::{
 use error_stack::ResultExt;
 use iroha_config::{
     base::{util::Bytes, WithOrigin},
-    parameters::actual::Torii as Config,
+    parameters::actual::{InstructionFilter, Torii as Config, ToriiVirtualHost},
 };
 #[cfg(feature = "telemetry")]
 use iroha_core::telemetry::Telemetry;
@@ -25,17 +32,27 @@ use iroha_core::{
     kiso::{Error as KisoError, KisoHandle},
     kura::Kura,
     prelude::*,
-    query::store::LiveQueryStoreHandle,
+    query::{scope::DomainScope, store::LiveQueryStoreHandle},
     queue::{self, Queue},
     state::State,
     EventsSender,
 };
-use iroha_data_model::{peer::Peer, ChainId};
+use iroha_data_model::{
+    domain::DomainId,
+    events::stream::EventMessage,
+    peer::{Peer, PeerId},
+    prelude::KeyPair,
+    ChainId,
+};
 use iroha_futures::supervisor::ShutdownSignal;
 use iroha_primitives::addr::SocketAddr;
 use iroha_torii_shared::uri;
-use tokio::{net::TcpListener, sync::watch};
+use tokio::{
+    net::TcpListener,
+    sync::{broadcast, watch, Semaphore},
+};
 use tower_http::{
+    compression::{predicate::DefaultPredicate, CompressionLayer, Predicate},
     timeout::TimeoutLayer,
     trace::{DefaultMakeSpan, TraceLayer},
 };
@@ -45,10 +62,14 @@ use utils::{extractors::ScaleVersioned, Scale};
 pub(crate) mod utils;
 mod block;
 mod event;
+mod multisig;
 mod routing;
 mod stream;
 
 const SERVER_SHUTDOWN_TIMEOUT: Duration = Duration::from_secs(60);
+const SEQUENCED_EVENTS_BUFFER_CAPACITY: usize = 10_000;
+/// Transactions simulated or estimated at once, each of them holds a blocking thread while executed
+const MAX_SIMULATIONS_IN_FLIGHT: usize = 2;
 
 /// Main network handler and the only entrypoint of the Iroha.
 pub struct Torii {
@@ -56,11 +77,22 @@ pub struct Torii {
     kiso: KisoHandle,
     queue: Arc<Queue>,
     events: EventsSender,
+    sequenced_events: broadcast::Sender<EventMessage>,
+    domain_shards: event::DomainShards,
+    replay: event::Replay,
     query_service: LiveQueryStoreHandle,
     kura: Arc<Kura>,
     transaction_max_content_len: Bytes<u64>,
+    compression: bool,
     address: WithOrigin<SocketAddr>,
+    virtual_hosts: Vec<ToriiVirtualHost>,
+    instructions: Arc<InstructionFilter>,
+    webhook_allowed_hosts: BTreeSet<String>,
+    simulations: Arc<Semaphore>,
     state: Arc<State>,
+    peer_id: PeerId,
+    /// Signs the receipts of the submitted transactions
+    key_pair: Arc<KeyPair>,
     #[cfg(feature = "telemetry")]
     telemetry: Telemetry,
     online_peers: OnlinePeersProvider,
@@ -78,22 +110,41 @@ impl Torii {
         query_service: LiveQueryStoreHandle,
         kura: Arc<Kura>,
         state: Arc<State>,
+        key_pair: KeyPair,
         online_peers: OnlinePeersProvider,
         #[cfg(feature = "telemetry")] telemetry: Telemetry,
     ) -> Self {
+        let (sequenced_events, _) = broadcast::channel(SEQUENCED_EVENTS_BUFFER_CAPACITY);
+
         Self {
             chain_id: Arc::new(chain_id),
             kiso,
             queue,
             events,
+            sequenced_events,
+            domain_shards: event::DomainShards::default(),
+            replay: event::Replay::new(
+                state.view().height() as u64,
+                state.retention().map_or(
+                    iroha_config::parameters::defaults::retention::EVENTS_MAX_ENTRIES,
+                    |retention| retention.events_max_entries,
+                ),
+            ),
             query_service,
             kura,
             state,
+            peer_id: PeerId::new(key_pair.public_key().clone()),
+            key_pair: Arc::new(key_pair),
             online_peers,
             #[cfg(feature = "telemetry")]
             telemetry,
             address: config.address,
+            virtual_hosts: config.virtual_hosts,
+            instructions: Arc::new(config.instructions),
+            webhook_allowed_hosts: config.webhook_allowed_hosts,
+            simulations: Arc::new(Semaphore::new(MAX_SIMULATIONS_IN_FLIGHT)),
             transaction_max_content_len: config.max_content_len,
+            compression: config.compression,
         }
     }
 
@@ -130,9 +181,11 @@ impl Torii {
                 &format!("{}/*tail", uri::STATUS),
                 get({
                     let tel = self.telemetry.clone();
+                    let instructions = self.instructions.clone();
                     move |accept: Option<utils::extractors::ExtractAccept>, axum::extract::Path(tail): axum::extract::Path<String>| async move {
                         routing::handle_status(
                             &tel,
+                            &instructions,
                             accept.map(|extract| extract.0),
                             Some(&tail),
                         ).await
@@ -143,8 +196,30 @@ impl Torii {
                 uri::STATUS,
                 get({
                     let tel = self.telemetry.clone();
+                    let instructions = self.instructions.clone();
                     move |accept: Option<utils::extractors::ExtractAccept>| async move {
-                        routing::handle_status(&tel, accept.map(|extract| extract.0), None).await
+                        routing::handle_status(
+                            &tel,
+                            &instructions,
+                            accept.map(|extract| extract.0),
+                            None,
+                        )
+                        .await
+                    }
+                }),
+            )
+            .route(
+                uri::INSTRUCTION_STATS,
+                get({
+                    let tel = self.telemetry.clone();
+                    move |accept: Option<utils::extractors::ExtractAccept>,
+                          axum::extract::Query(params): axum::extract::Query<_>| async move {
+                        routing::handle_instruction_stats(
+                            &tel,
+                            params,
+                            accept.map(|extract| extract.0),
+                        )
+                        .await
                     }
                 }),
             )
@@ -158,6 +233,10 @@ impl Torii {
         #[cfg(not(feature = "telemetry"))]
         let router = router
             .route(uri::STATUS, get(routing::telemetry_not_implemented))
+            .route(
+                uri::INSTRUCTION_STATS,
+                get(routing::telemetry_not_implemented),
+            )
             .route(
                 &format!("{}/*rest", uri::STATUS),
                 get(routing::telemetry_not_implemented),
@@ -165,9 +244,21 @@ impl Torii {
             .route(uri::METRICS, get(routing::telemetry_not_implemented));
 
         #[cfg(feature = "schema")]
-        let router = router.route(uri::SCHEMA, get(routing::handle_schema));
+        let router = router
+            .route(uri::SCHEMA, get(routing::handle_schema))
+            .route(
+                uri::SCHEMA_BUNDLE,
+                get({
+                    let state = self.state.clone();
+                    move |headers: axum::http::HeaderMap| async move {
+                        routing::handle_schema_bundle(&state, &headers)
+                    }
+                }),
+            );
         #[cfg(not(feature = "schema"))]
-        let router = router.route(uri::SCHEMA, get(routing::schema_not_implemented));
+        let router = router
+            .route(uri::SCHEMA, get(routing::schema_not_implemented))
+            .route(uri::SCHEMA_BUNDLE, get(routing::schema_not_implemented));
 
         #[cfg(feature = "profiling")]
         let router = router.route(
@@ -183,6 +274,30 @@ impl Torii {
         #[cfg(not(feature = "profiling"))]
         let router = router.route(uri::PROFILE, get(routing::profiling_not_implemented));
 
+        #[cfg(feature = "replay")]
+        let router = router.route(
+            uri::REPLAY_TRANSACTION,
+            get({
+                let state = self.state.clone();
+                let replayer = Arc::new(iroha_core::replay::Replayer::new(
+                    self.kura.clone(),
+                    self.query_service.clone(),
+                ));
+                move |axum::extract::Query(params): axum::extract::Query<_>| {
+                    routing::replay::handle_replay_transaction(
+                        params,
+                        state.clone(),
+                        replayer.clone(),
+                    )
+                }
+            }),
+        );
+        #[cfg(not(feature = "replay"))]
+        let router = router.route(
+            uri::REPLAY_TRANSACTION,
+            get(routing::replay_not_implemented),
+        );
+
         let router = router
             .route(
                 uri::TRANSACTION,
@@ -190,8 +305,132 @@ impl Torii {
                     let chain_id = self.chain_id.clone();
                     let queue = self.queue.clone();
                     let state = self.state.clone();
+                    let instructions = self.instructions.clone();
+                    let key_pair = self.key_pair.clone();
+                    move |ScaleVersioned(transaction): ScaleVersioned<_>| {
+                        routing::handle_transaction(
+                            chain_id,
+                            queue,
+                            state,
+                            None,
+                            instructions,
+                            key_pair,
+                            transaction,
+                        )
+                    }
+                })
+                .layer(DefaultBodyLimit::max(
+                    self.transaction_max_content_len
+                        .get()
+                        .try_into()
+                        .expect("should't exceed usize"),
+                )),
+            )
+            .route(
+                uri::TRANSACTION_SIMULATION,
+                post({
+                    let chain_id = self.chain_id.clone();
+                    let state = self.state.clone();
+                    let simulations = self.simulations.clone();
                     move |ScaleVersioned(transaction): ScaleVersioned<_>| {
-                        routing::handle_transaction(chain_id, queue, state, transaction)
+                        routing::handle_simulate_transaction(
+                            chain_id,
+                            state,
+                            simulations,
+                            transaction,
+                        )
+                    }
+                })
+                .layer(DefaultBodyLimit::max(
+                    self.transaction_max_content_len
+                        .get()
+                        .try_into()
+                        .expect("should't exceed usize"),
+                )),
+            )
+            .route(
+                uri::TRANSACTION_ESTIMATE,
+                post({
+                    let chain_id = self.chain_id.clone();
+                    let state = self.state.clone();
+                    let simulations = self.simulations.clone();
+                    move |ScaleVersioned(transaction): ScaleVersioned<_>| {
+                        routing::handle_estimate_transaction(
+                            chain_id,
+                            state,
+                            simulations,
+                            transaction,
+                        )
+                    }
+                })
+                .layer(DefaultBodyLimit::max(
+                    self.transaction_max_content_len
+                        .get()
+                        .try_into()
+                        .expect("should't exceed usize"),
+                )),
+            )
+            .route(
+                uri::PENDING_TRANSACTIONS,
+                get({
+                    let queue = self.queue.clone();
+                    let state = self.state.clone();
+                    move |axum::extract::Query(params): axum::extract::Query<_>| {
+                        routing::handle_pending_transactions(queue, state, params)
+                    }
+                }),
+            )
+            .route(
+                uri::MULTISIG_PENDING,
+                post({
+                    let chain_id = self.chain_id.clone();
+                    let queue = self.queue.clone();
+                    let state = self.state.clone();
+                    let instructions = self.instructions.clone();
+                    move |ScaleVersioned(transaction): ScaleVersioned<_>| {
+                        routing::multisig::handle_propose(
+                            chain_id,
+                            queue,
+                            state,
+                            instructions,
+                            transaction,
+                        )
+                    }
+                })
+                .layer(DefaultBodyLimit::max(
+                    self.transaction_max_content_len
+                        .get()
+                        .try_into()
+                        .expect("should't exceed usize"),
+                )),
+            )
+            .route(
+                &format!("{}/:account/:hash", uri::MULTISIG_PENDING),
+                get({
+                    let state = self.state.clone();
+                    move |axum::extract::Path((account, hash)): axum::extract::Path<_>| {
+                        routing::multisig::handle_get_proposal(state, account, hash)
+                    }
+                }),
+            )
+            .route(
+                &format!("{}/:account/:hash/approvals", uri::MULTISIG_PENDING),
+                post({
+                    let chain_id = self.chain_id.clone();
+                    let queue = self.queue.clone();
+                    let state = self.state.clone();
+                    let instructions = self.instructions.clone();
+                    move |axum::extract::Path((account, hash)): axum::extract::Path<_>,
+                          ScaleVersioned(transaction): ScaleVersioned<_>| {
+                        routing::multisig::handle_approve(
+                            chain_id,
+                            queue,
+                            state,
+                            instructions,
+                            account,
+                            hash,
+                            transaction,
+                        )
                     }
                 })
                 .layer(DefaultBodyLimit::max(
@@ -207,7 +446,17 @@ impl Torii {
                     let query_service = self.query_service.clone();
                     let state = self.state.clone();
                     move |ScaleVersioned(query_request): ScaleVersioned<_>| {
-                        routing::handle_queries(query_service, state, query_request)
+                        routing::handle_queries(query_service, state, None, query_request)
+                    }
+                }),
+            )
+            .route(
+                uri::QUERY_BATCH,
+                post({
+                    let query_service = self.query_service.clone();
+                    let state = self.state.clone();
+                    move |Scale(queries): Scale<_>| {
+                        routing::handle_query_batch(query_service, state, queries)
                     }
                 }),
             )
@@ -223,11 +472,29 @@ impl Torii {
             .route(
                 uri::SUBSCRIPTION,
                 get({
-                    let events = self.events.clone();
+                    let events = self.sequenced_events.clone();
                     move |ws: WebSocketUpgrade| {
                         core::future::ready(ws.on_upgrade(|ws| async move {
                             if let Err(error) =
-                                routing::event::handle_events_stream(events, ws).await
+                                routing::event::handle_events_stream(events, None, ws).await
+                            {
+                                iroha_logger::error!(%error, "Failure during event streaming");
+                            }
+                        }))
+                    }
+                }),
+            )
+            .route(
+                uri::SUBSCRIPTION_SEQUENCED,
+                get({
+                    let events = self.sequenced_events.clone();
+                    let replay = self.replay.clone();
+                    move |ws: WebSocketUpgrade| {
+                        core::future::ready(ws.on_upgrade(|ws| async move {
+                            if let Err(error) = routing::event::handle_sequenced_events_stream(
+                                events, replay, None, ws,
+                            )
+                            .await
                             {
                                 iroha_logger::error!(%error, "Failure during event streaming");
                             }
@@ -235,6 +502,39 @@ impl Torii {
                     }
                 }),
             )
+            .route(
+                &format!("{}/:domain", uri::SUBSCRIPTION_DOMAIN),
+                get({
+                    let shards = self.domain_shards.clone();
+                    move |axum::extract::Path(domain): axum::extract::Path<DomainId>,
+                          ws: WebSocketUpgrade| {
+                        core::future::ready(ws.on_upgrade(|ws| async move {
+                            if let Err(error) =
+                                routing::event::handle_domain_events_stream(shards, domain, ws)
+                                    .await
+                            {
+                                iroha_logger::error!(%error, "Failure during domain event streaming");
+                            }
+                        }))
+                    }
+                }),
+            )
+            .route(
+                uri::SUBSCRIPTION_INBOX,
+                get({
+                    let events = self.sequenced_events.clone();
+                    let state = self.state.clone();
+                    move |ws: WebSocketUpgrade| {
+                        core::future::ready(ws.on_upgrade(|ws| async move {
+                            if let Err(error) =
+                                routing::event::handle_inbox_stream(events, state, ws).await
+                            {
+                                iroha_logger::error!(%error, "Failure during inbox streaming");
+                            }
+                        }))
+                    }
+                }),
+            )
             .route(
                 uri::BLOCKS_STREAM,
                 get({
@@ -248,6 +548,21 @@ impl Torii {
                         }))
                     }
                 }),
+            )
+            .route(
+                uri::BLOCK_HEADERS_STREAM,
+                get({
+                    let kura = self.kura.clone();
+                    move |ws: WebSocketUpgrade| {
+                        core::future::ready(ws.on_upgrade(|ws| async move {
+                            if let Err(error) =
+                                routing::block::handle_block_headers_stream(kura, ws).await
+                            {
+                                iroha_logger::error!(%error, "Failure during block header streaming");
+                            }
+                        }))
+                    }
+                }),
             );
 
         let router = router.route(
@@ -261,31 +576,191 @@ impl Torii {
             // Graceful shutdown will wait for outstanding requests to complete.
             // Add a timeout so requests don't hang forever.
             TimeoutLayer::new(SERVER_SHUTDOWN_TIMEOUT),
+            compression_layer(self.compression),
         ))
     }
 
+    /// Create the router of a virtual host exposing only the data of the `scope`.
+    ///
+    /// Only the endpoints for the clients are exposed: the transactions and the queries
+    /// are accepted from the accounts of the `scope`, and the events outside of it are not streamed.
+    fn create_virtual_host_router(&self, scope: &DomainScope) -> axum::Router {
+        Router::new()
+            .route(uri::HEALTH, get(routing::handle_health))
+            .route(
+                uri::SERVER_VERSION,
+                get(move || async move { routing::handle_server_version() }),
+            )
+            .route(
+                uri::TRANSACTION,
+                post({
+                    let chain_id = self.chain_id.clone();
+                    let queue = self.queue.clone();
+                    let state = self.state.clone();
+                    let scope = scope.clone();
+                    let instructions = self.instructions.clone();
+                    let key_pair = self.key_pair.clone();
+                    move |ScaleVersioned(transaction): ScaleVersioned<_>| {
+                        routing::handle_transaction(
+                            chain_id,
+                            queue,
+                            state,
+                            Some(scope),
+                            instructions,
+                            key_pair,
+                            transaction,
+                        )
+                    }
+                })
+                .layer(DefaultBodyLimit::max(
+                    self.transaction_max_content_len
+                        .get()
+                        .try_into()
+                        .expect("should't exceed usize"),
+                )),
+            )
+            .route(
+                uri::QUERY,
+                post({
+                    let query_service = self.query_service.clone();
+                    let state = self.state.clone();
+                    let scope = scope.clone();
+                    move |ScaleVersioned(query_request): ScaleVersioned<_>| {
+                        routing::handle_queries(query_service, state, Some(scope), query_request)
+                    }
+                }),
+            )
+            .route(
+                uri::SUBSCRIPTION,
+                get({
+                    let events = self.sequenced_events.clone();
+                    let scope = scope.clone();
+                    move |ws: WebSocketUpgrade| {
+                        core::future::ready(ws.on_upgrade(|ws| async move {
+                            if let Err(error) =
+                                routing::event::handle_events_stream(events, Some(scope), ws).await
+                            {
+                                iroha_logger::error!(%error, "Failure during event streaming");
+                            }
+                        }))
+                    }
+                }),
+            )
+            .route(
+                uri::SUBSCRIPTION_SEQUENCED,
+                get({
+                    let events = self.sequenced_events.clone();
+                    let replay = self.replay.clone();
+                    let scope = scope.clone();
+                    move |ws: WebSocketUpgrade| {
+                        core::future::ready(ws.on_upgrade(|ws| async move {
+                            if let Err(error) = routing::event::handle_sequenced_events_stream(
+                                events,
+                                replay,
+                                Some(scope),
+                                ws,
+                            )
+                            .await
+                            {
+                                iroha_logger::error!(%error, "Failure during event streaming");
+                            }
+                        }))
+                    }
+                }),
+            )
+            .layer((
+                TraceLayer::new_for_http()
+                    .make_span_with(DefaultMakeSpan::default().include_headers(true)),
+                TimeoutLayer::new(SERVER_SHUTDOWN_TIMEOUT),
+                compression_layer(self.compression),
+            ))
+    }
+
     /// To handle incoming requests `Torii` should be started first.
     ///
     /// # Errors
     /// Can fail due to listening to network or if http server fails
     // #[iroha_futures::telemetry_future]
     pub async fn start(self, shutdown_signal: ShutdownSignal) -> error_stack::Result<(), Error> {
-        let torii_address = self.address.value().clone();
+        let listener = bind(self.address.value().clone())
+            .await
+            .change_context(Error::StartServer)
+            .attach_printable("failed to bind to the specified address")
+            .attach_printable_lazy(|| self.address.clone().into_attachment())?;
+        let api_router = self.create_api_router();
 
-        let listener = match torii_address {
-            SocketAddr::Ipv4(v) => TcpListener::bind(std::net::SocketAddr::V4(v.into())).await,
-            SocketAddr::Ipv6(v) => TcpListener::bind(std::net::SocketAddr::V6(v.into())).await,
-            SocketAddr::Host(v) => TcpListener::bind((v.host.as_ref(), v.port)).await,
+        let mut virtual_hosts = Vec::with_capacity(self.virtual_hosts.len());
+        for virtual_host in &self.virtual_hosts {
+            let listener = bind(virtual_host.address.clone())
+                .await
+                .change_context(Error::StartServer)
+                .attach_printable_lazy(|| {
+                    format!(
+                        "failed to bind virtual host to the address {}",
+                        virtual_host.address
+                    )
+                })?;
+            let router =
+                self.create_virtual_host_router(&DomainScope::new(virtual_host.domains.clone()));
+            let shutdown_signal = shutdown_signal.clone();
+            virtual_hosts.push(tokio::spawn(async move {
+                if let Err(error) = axum::serve(listener, router)
+                    .with_graceful_shutdown(async move { shutdown_signal.receive().await })
+                    .await
+                {
+                    iroha_logger::error!(%error, "Virtual host terminated with an error");
+                }
+            }));
         }
-        .change_context(Error::StartServer)
-        .attach_printable("failed to bind to the specified address")
-        .attach_printable_lazy(|| self.address.clone().into_attachment())?;
-        let api_router = self.create_api_router();
 
-        axum::serve(listener, api_router)
+        let sequencer = event::Sequencer::new(self.state.view().height() as u64);
+        let sequencer =
+            tokio::spawn(sequencer.run(self.events.subscribe(), self.sequenced_events.clone()));
+        let webhooks = event::WebhookDelivery::new(
+            self.peer_id.clone(),
+            self.state.clone(),
+            self.webhook_allowed_hosts.clone(),
+        );
+        let webhooks = tokio::spawn(webhooks.run(self.sequenced_events.subscribe()));
+        let replay = tokio::spawn(self.replay.clone().run(self.sequenced_events.subscribe()));
+        let domain_shards = tokio::spawn(
+            self.domain_shards
+                .clone()
+                .run(self.sequenced_events.subscribe()),
+        );
+
+        let result = axum::serve(listener, api_router)
             .with_graceful_shutdown(async move { shutdown_signal.receive().await })
             .await
-            .change_context(Error::FailedExit)
+            .change_context(Error::FailedExit);
+        sequencer.abort();
+        webhooks.abort();
+        replay.abort();
+        domain_shards.abort();
+        for virtual_host in virtual_hosts {
+            let _ = virtual_host.await;
+        }
+        result
+    }
+}
+
+/// Compress the responses with `gzip` or `zstd`, whichever the client accepts, unless disabled
+fn compression_layer(enabled: bool) -> CompressionLayer<impl Predicate + Send + Sync> {
+    // NOTE: upgraded `WebSocket` connections have no body to compress
+    let not_upgraded = |status: StatusCode, _: Version, _: &HeaderMap, _: &Extensions| {
+        status != StatusCode::SWITCHING_PROTOCOLS
+    };
+    CompressionLayer::new()
+        .gzip(enabled)
+        .zstd(enabled)
+        .compress_when(DefaultPredicate::new().and(not_upgraded))
+}
+
+async fn bind(address: SocketAddr) -> std::io::Result<TcpListener> {
+    match address {
+        SocketAddr::Ipv4(v) => TcpListener::bind(std::net::SocketAddr::V4(v.into())).await,
+        SocketAddr::Ipv6(v) => TcpListener::bind(std::net::SocketAddr::V6(v.into())).await,
+        SocketAddr::Host(v) => TcpListener::bind((v.host.as_ref(), v.port)).await,
     }
 }
 
@@ -300,12 +775,31 @@ pub enum Error {
     Config(#[source] eyre::Report),
     /// Failed to push into queue
     PushIntoQueue(#[from] Box<queue::Error>),
+    /// Account `{0}` is outside of the domains served by this endpoint
+    OutOfScope(iroha_data_model::account::AccountId),
+    /// Instructions of type `{0}` are not accepted by this peer
+    InstructionNotAccepted(iroha_data_model::isi::InstructionType),
+    /// Smart contracts are not accepted by this peer, since the types of their instructions can't be checked
+    SmartContractNotAccepted,
+    /// Transaction queue is saturated, the client should retry later
+    QueueFull(iroha_torii_shared::QueueFull),
+    /// Account `{0}` isn't registered, only the registered accounts may simulate transactions
+    UnknownAuthority(iroha_data_model::account::AccountId),
+    /// Too many transactions are being simulated, the client should retry later
+    TooManySimulations,
+    /// Failed to simulate transaction
+    Simulation(#[from] iroha_core::simulation::SimulationError),
+    /// Multisig proposal or approval doesn't match the multisig account
+    Multisig(#[from] multisig::Error),
     #[cfg(feature = "telemetry")]
     /// Failed to get Prometheus metrics
     Prometheus(#[source] eyre::Report),
     #[cfg(feature = "profiling")]
     /// Failed to get pprof profile
     Pprof(#[source] eyre::Report),
+    #[cfg(feature = "replay")]
+    /// Failed to replay transaction
+    Replay(#[from] iroha_core::replay::Error),
     #[cfg(feature = "telemetry")]
     /// Failed to get status
     StatusFailure(#[source] eyre::Report),
@@ -323,6 +817,18 @@ impl IntoResponse for Error {
     fn into_response(self) -> Response {
         match self {
             Self::Query(err) => (Self::query_status_code(&err), utils::Scale(err)).into_response(),
+            Self::QueueFull(hint) => {
+                let retry_after_secs = hint.retry_after_ms.div_ceil(1000).max(1);
+                (
+                    StatusCode::SERVICE_UNAVAILABLE,
+                    [(
+                        axum::http::header::RETRY_AFTER,
+                        retry_after_secs.to_string(),
+                    )],
+                    Json(hint),
+                )
+                    .into_response()
+            }
             _ => (self.status_code(), format!("{self:?}")).into_response(),
         }
     }
@@ -335,15 +841,42 @@ impl Error {
         match self {
             Query(e) => Self::query_status_code(e),
             AcceptTransaction(_) => StatusCode::BAD_REQUEST,
+            OutOfScope(_) | InstructionNotAccepted(_) | SmartContractNotAccepted => {
+                StatusCode::FORBIDDEN
+            }
             Config(_) | StatusSegmentNotFound(_) => StatusCode::NOT_FOUND,
             PushIntoQueue(err) => match **err {
-                queue::Error::Full => StatusCode::INTERNAL_SERVER_ERROR,
+                queue::Error::Full => StatusCode::SERVICE_UNAVAILABLE,
+                // Lets the clients recognize the resubmissions of an accepted transaction
+                queue::Error::IsInQueue | queue::Error::InBlockchain => StatusCode::CONFLICT,
                 _ => StatusCode::BAD_REQUEST,
             },
+            QueueFull(_) => StatusCode::SERVICE_UNAVAILABLE,
+            UnknownAuthority(_) => StatusCode::FORBIDDEN,
+            TooManySimulations => StatusCode::TOO_MANY_REQUESTS,
+            Simulation(err) => match err {
+                iroha_core::simulation::SimulationError::WasmNotSupported => {
+                    StatusCode::UNPROCESSABLE_ENTITY
+                }
+            },
+            Multisig(err) => match err {
+                multisig::Error::NotSignatory(_) => StatusCode::FORBIDDEN,
+                multisig::Error::NotPending(_) => StatusCode::NOT_FOUND,
+                multisig::Error::AlreadyPending(_) => StatusCode::CONFLICT,
+                multisig::Error::UnexpectedInstructions(_) | multisig::Error::NotMultisig(_) => {
+                    StatusCode::BAD_REQUEST
+                }
+            },
             #[cfg(feature = "telemetry")]
             Prometheus(_) | StatusFailure(_) => StatusCode::INTERNAL_SERVER_ERROR,
             #[cfg(feature = "profiling")]
             Pprof(_) => StatusCode::INTERNAL_SERVER_ERROR,
+            #[cfg(feature = "replay")]
+            Replay(err) => match err {
+                iroha_core::replay::Error::NotCommitted(_) => StatusCode::NOT_FOUND,
+                iroha_core::replay::Error::MissingBlock(_) => StatusCode::INTERNAL_SERVER_ERROR,
+                iroha_core::replay::Error::Busy => StatusCode::TOO_MANY_REQUESTS,
+            },
             ConfigurationFailure(_) => StatusCode::INTERNAL_SERVER_ERROR,
             StartServer | FailedExit => unreachable!("these never occur during request handling"),
         }
@@ -365,9 +898,12 @@ impl Error {
                 | CursorDone
                 | NotFound
                 | FetchSizeTooBig
-                | InvalidSingularParameters => StatusCode::BAD_REQUEST,
-                Find(_) => StatusCode::NOT_FOUND,
+                | InvalidSingularParameters
+                | Aggregation(_) => StatusCode::BAD_REQUEST,
+                Find(_) | NotCommitted(_) => StatusCode::NOT_FOUND,
                 CapacityLimit => StatusCode::TOO_MANY_REQUESTS,
+                Pruned(_) => StatusCode::GONE,
+                Shed => StatusCode::SERVICE_UNAVAILABLE,
             },
             TooComplex => StatusCode::UNPROCESSABLE_ENTITY,
             InternalError(_) => StatusCode::INTERNAL_SERVER_ERROR,
@@ -404,8 +940,25 @@ impl OnlinePeersProvider {
 
 #[cfg(test)]
 mod tests {
+    use std::num::NonZeroU64;
+
+    use axum::{body::Body, http::Request};
+    use futures::{SinkExt as _, StreamExt as _};
     // for `collect`
     use http_body_util::BodyExt as _;
+    use iroha_data_model::{
+        account::AccountId,
+        block::{
+            stream::{BlockHeaderMessage, BlockSubscriptionRequest},
+            SignedBlock,
+        },
+        isi::InstructionBox,
+        transaction::TransactionBuilder,
+    };
+    use parity_scale_codec::{DecodeAll as _, Encode as _};
+    use tokio_tungstenite::tungstenite::Message;
+    // for `oneshot`
+    use tower::ServiceExt as _;
 
     use super::*;
 
@@ -424,4 +977,96 @@ mod tests {
             String::from_utf8(body.iter().copied().collect()).expect("to be a valid UTF8 string");
         assert_eq!(text, "Failed to accept transaction\n\nCaused by:\n    Chain id doesn't correspond to the id of current blockchain: Expected ChainId(\"123\"), actual ChainId(\"321\")");
     }
+
+    async fn listing_response(compression: bool, accept_encoding: &str) -> Response {
+        let listing = "wonderland#alice, ".repeat(1_000);
+        Router::new()
+            .route("/", get(move || core::future::ready(listing)))
+            .layer(compression_layer(compression))
+            .oneshot(
+                Request::get("/")
+                    .header("accept-encoding", accept_encoding)
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap()
+    }
+
+    async fn body_len(response: Response) -> usize {
+        response
+            .into_body()
+            .collect()
+            .await
+            .unwrap()
+            .to_bytes()
+            .len()
+    }
+
+    #[tokio::test]
+    async fn responses_are_compressed_if_accepted() {
+        let plain = listing_response(true, "identity").await;
+        assert!(!plain.headers().contains_key("content-encoding"));
+        let plain_len = body_len(plain).await;
+
+        for encoding in ["gzip", "zstd"] {
+            let compressed = listing_response(true, encoding).await;
+            assert_eq!(compressed.headers()["content-encoding"], encoding);
+            assert!(body_len(compressed).await * 10 < plain_len);
+        }
+
+        let disabled = listing_response(false, "gzip, zstd").await;
+        assert!(!disabled.headers().contains_key("content-encoding"));
+        assert_eq!(body_len(disabled).await, plain_len);
+    }
+
+    #[tokio::test]
+    async fn block_headers_stream_sends_headers_and_signatures() {
+        let key_pair = KeyPair::random();
+        let authority = AccountId::new("genesis".parse().unwrap(), key_pair.public_key().clone());
+        let tx = TransactionBuilder::new(
+            ChainId::from("00000000-0000-0000-0000-000000000000"),
+            authority,
+        )
+        .with_instructions::<InstructionBox>([])
+        .sign(key_pair.private_key());
+        let block = SignedBlock::genesis(vec![tx], key_pair.private_key());
+        let kura = Kura::blank_kura_for_testing();
+        kura.store_block(block.clone());
+
+        let router = Router::new().route(
+            uri::BLOCK_HEADERS_STREAM,
+            get(move |ws: WebSocketUpgrade| {
+                core::future::ready(ws.on_upgrade(|ws| async move {
+                    // Fails once the client disconnects
+                    let _ = routing::block::handle_block_headers_stream(kura, ws).await;
+                }))
+            }),
+        );
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        tokio::spawn(async move { axum::serve(listener, router).await });
+
+        let (mut stream, _) = tokio_tungstenite::connect_async(format!(
+            "ws://{address}{}",
+            uri::BLOCK_HEADERS_STREAM
+        ))
+        .await
+        .unwrap();
+        stream
+            .send(Message::Binary(
+                BlockSubscriptionRequest::new(NonZeroU64::MIN).encode(),
+            ))
+            .await
+            .unwrap();
+        let Some(Ok(Message::Binary(message))) = stream.next().await else {
+            panic!("the header of the stored block should be sent");
+        };
+        let message = BlockHeaderMessage::decode_all(&mut message.as_slice()).unwrap();
+        assert_eq!(message.header, block.header());
+        assert_eq!(
+            message.signatures,
+            block.signatures().cloned().collect::<Vec<_>>()
+        );
+    }
 }
@@ -0,0 +1,279 @@
+//! Exchange of the approvals of multisig proposals through the peer.
+//!
+//! The proposer uploads the transaction proposing a multisig transaction, the signatories fetch
+//! the proposal by the multisig account and the hash of its instructions and upload their approving transactions.
+//! Every upload is checked against the multisig account and submitted right away: the proposal and the approvals
+//! are recorded in the metadata of the account by the multisig instructions, so they outlive the peer they are uploaded to,
+//! and the proposal is executed within the transaction of the approval reaching the quorum.
+
+use std::{collections::BTreeSet, time::Duration};
+
+use iroha_core::state::{State, StateReadOnly, WorldReadOnly};
+use iroha_data_model::{
+    account::AccountId,
+    isi::InstructionBox,
+    name::Name,
+    prelude::{Executable, HashOf, SignedTransaction},
+};
+use iroha_executor_data_model::isi::multisig::{
+    MultisigApprove, MultisigInstructionBox, MultisigProposalValue, MultisigPropose, MultisigSpec,
+};
+use iroha_torii_shared::MultisigApprovals;
+
+/// Hash of the instructions of a multisig proposal, which identifies it within the multisig account
+pub type ProposalHash = HashOf<Vec<InstructionBox>>;
+
+/// Error of uploading a multisig proposal or approval
+#[derive(Debug, displaydoc::Display, thiserror::Error)]
+pub enum Error {
+    /// Transaction should consist of a single multisig `{0}` instruction
+    UnexpectedInstructions(&'static str),
+    /// Account `{0}` is not a multisig account
+    NotMultisig(AccountId),
+    /// Account `{0}` is not a signatory of the multisig account
+    NotSignatory(AccountId),
+    /// Proposal `{0}` is not pending
+    NotPending(ProposalHash),
+    /// Proposal `{0}` is already recorded
+    AlreadyPending(ProposalHash),
+}
+
+/// Check that `tx` proposes a multisig transaction of an account its authority is a signatory of
+///
+/// # Errors
+/// Fails if `tx` doesn't propose a new multisig transaction on behalf of a signatory
+pub fn propose(state: &State, tx: &SignedTransaction) -> Result<MultisigApprovals, Error> {
+    let Some(MultisigInstructionBox::Propose(MultisigPropose {
+        account,
+        instructions,
+        ..
+    })) = single_instruction(tx)
+    else {
+        return Err(Error::UnexpectedInstructions("propose"));
+    };
+    let hash = HashOf::new(&instructions);
+    let (spec, proposal) = lookup(state, &account, hash)?;
+
+    check_propose(&spec, proposal.as_ref(), hash, tx.authority())
+}
+
+/// Proposal with the given `hash` pending approvals of the signatories of the multisig `account`
+///
+/// # Errors
+/// Fails if the proposal is not pending
+pub fn proposal(
+    state: &State,
+    account: &AccountId,
+    hash: ProposalHash,
+) -> Result<MultisigProposalValue, Error> {
+    let (_, proposal) = lookup(state, account, hash)?;
+    proposal.filter(is_pending).ok_or(Error::NotPending(hash))
+}
+
+/// Check that `tx` approves the pending proposal with the given `hash` of the multisig `account` on behalf of a signatory
+///
+/// # Errors
+/// Fails if the proposal is not pending or `tx` doesn't approve it on behalf of a signatory
+pub fn approve(
+    state: &State,
+    account: &AccountId,
+    hash: ProposalHash,
+    tx: &SignedTransaction,
+) -> Result<MultisigApprovals, Error> {
+    match single_instruction(tx) {
+        Some(MultisigInstructionBox::Approve(MultisigApprove {
+            account: approved_account,
+            instructions_hash,
+        })) if approved_account == *account && instructions_hash == hash => {}
+        _ => return Err(Error::UnexpectedInstructions("approve")),
+    }
+    let (spec, proposal) = lookup(state, account, hash)?;
+
+    check_approve(&spec, proposal, hash, tx.authority())
+}
+
+fn check_propose(
+    spec: &MultisigSpec,
+    proposal: Option<&MultisigProposalValue>,
+    hash: ProposalHash,
+    proposer: &AccountId,
+) -> Result<MultisigApprovals, Error> {
+    if !spec.signatories.contains_key(proposer) {
+        return Err(Error::NotSignatory(proposer.clone()));
+    }
+    // NOTE: the expired proposal isn't replaced until it's pruned by an approval
+    if proposal.is_some() {
+        return Err(Error::AlreadyPending(hash));
+    }
+
+    Ok(tally(spec, [proposer]))
+}
+
+fn check_approve(
+    spec: &MultisigSpec,
+    proposal: Option<MultisigProposalValue>,
+    hash: ProposalHash,
+    approver: &AccountId,
+) -> Result<MultisigApprovals, Error> {
+    let proposal = proposal.filter(is_pending).ok_or(Error::NotPending(hash))?;
+    if !spec.signatories.contains_key(approver) {
+        return Err(Error::NotSignatory(approver.clone()));
+    }
+
+    Ok(tally(
+        spec,
+        proposal.approvals.iter().chain(core::iter::once(approver)),
+    ))
+}
+
+/// Approvals of the proposal once the transaction of the latest one is committed
+fn tally<'a>(
+    spec: &MultisigSpec,
+    approvers: impl IntoIterator<Item = &'a AccountId>,
+) -> MultisigApprovals {
+    let approvers: BTreeSet<_> = approvers.into_iter().collect();
+    let weight = spec
+        .signatories
+        .iter()
+        .filter(|(signatory, _)| approvers.contains(signatory))
+        .fold(0_u16, |weight, (_, &signatory_weight)| {
+            weight.saturating_add(signatory_weight.into())
+        });
+
+    MultisigApprovals {
+        weight,
+        quorum: spec.quorum.get(),
+    }
+}
+
+/// Specification of the multisig `account` and its proposal with the given `hash`, if any, as recorded in its metadata
+fn lookup(
+    state: &State,
+    account: &AccountId,
+    hash: ProposalHash,
+) -> Result<(MultisigSpec, Option<MultisigProposalValue>), Error> {
+    let state_view = state.view();
+    let metadata = &state_view
+        .world()
+        .account(account)
+        .map_err(|_| Error::NotMultisig(account.clone()))?
+        .metadata;
+    let spec = metadata
+        .get(&metadata_key("spec"))
+        .and_then(|spec| spec.try_into_any().ok())
+        .ok_or_else(|| Error::NotMultisig(account.clone()))?;
+    let proposal = metadata
+        .get(&metadata_key(&format!("proposals/{hash}")))
+        .and_then(|proposal| proposal.try_into_any().ok());
+
+    Ok((spec, proposal))
+}
+
+fn metadata_key(suffix: &str) -> Name {
+    format!("multisig/{suffix}")
+        .parse()
+        .expect("multisig metadata key should be a valid name")
+}
+
+fn single_instruction(tx: &SignedTransaction) -> Option<MultisigInstructionBox> {
+    let Executable::Instructions(instructions) = tx.instructions() else {
+        return None;
+    };
+    let [InstructionBox::Custom(instruction)] = instructions.as_ref() else {
+        return None;
+    };
+    MultisigInstructionBox::try_from(&instruction.payload).ok()
+}
+
+/// Expired proposals are pruned by the multisig instructions lazily
+fn is_pending(proposal: &MultisigProposalValue) -> bool {
+    let now = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .expect("system time is after the epoch");
+    now < Duration::from_millis(proposal.expires_at_ms)
+}
+
+#[cfg(test)]
+mod tests {
+    use core::num::{NonZeroU16, NonZeroU64};
+
+    use iroha_data_model::prelude::*;
+
+    use super::*;
+
+    fn signatory() -> AccountId {
+        AccountId::new(
+            "wonderland".parse().unwrap(),
+            KeyPair::random().public_key().clone(),
+        )
+    }
+
+    #[test]
+    fn approvals_are_tallied_from_the_proposal_on_chain() {
+        let [proposer, approver, late_approver, outsider] =
+            [signatory(), signatory(), signatory(), signatory()];
+        let instructions: Vec<InstructionBox> =
+            vec![Log::new(Level::INFO, "treasury payout".to_owned()).into()];
+        let hash = HashOf::new(&instructions);
+        let spec = MultisigSpec::new(
+            [&proposer, &approver, &late_approver]
+                .into_iter()
+                .map(|account| (account.clone(), 1))
+                .collect(),
+            NonZeroU16::new(2).unwrap(),
+            NonZeroU64::new(3_600_000).unwrap(),
+        );
+        let proposal = |expires_at_ms| {
+            MultisigProposalValue::new(
+                instructions.clone(),
+                0,
+                expires_at_ms,
+                BTreeSet::from([proposer.clone()]),
+                None,
+            )
+        };
+
+        assert_eq!(
+            check_propose(&spec, None, hash, &proposer).unwrap(),
+            MultisigApprovals {
+                weight: 1,
+                quorum: 2
+            }
+        );
+        assert!(matches!(
+            check_propose(&spec, None, hash, &outsider),
+            Err(Error::NotSignatory(_))
+        ));
+        assert!(matches!(
+            check_propose(&spec, Some(&proposal(u64::MAX)), hash, &proposer),
+            Err(Error::AlreadyPending(_))
+        ));
+
+        assert!(matches!(
+            check_approve(&spec, Some(proposal(u64::MAX)), hash, &outsider),
+            Err(Error::NotSignatory(_))
+        ));
+        assert_eq!(
+            check_approve(&spec, Some(proposal(u64::MAX)), hash, &approver).unwrap(),
+            MultisigApprovals {
+                weight: 2,
+                quorum: 2
+            }
+        );
+        // Approving twice doesn't add to the weight
+        assert_eq!(
+            check_approve(&spec, Some(proposal(u64::MAX)), hash, &proposer)
+                .unwrap()
+                .weight,
+            1
+        );
+        assert!(matches!(
+            check_approve(&spec, Some(proposal(0)), hash, &late_approver),
+            Err(Error::NotPending(_))
+        ));
+        assert!(matches!(
+            check_approve(&spec, None, hash, &late_approver),
+            Err(Error::NotPending(_))
+        ));
+    }
+}
@@ -2,39 +2,116 @@
 //! Iroha you should add it here by creating a `handle_*` function,
 //! and add it to impl Torii.
 
+use std::num::{NonZeroU64, NonZeroUsize};
+
 use axum::extract::ws::WebSocket;
 #[cfg(feature = "telemetry")]
 use eyre::{eyre, WrapErr};
 use iroha_config::client_api::{ConfigGetDTO, ConfigUpdateDTO};
 #[cfg(feature = "telemetry")]
 use iroha_core::telemetry::Telemetry;
-use iroha_core::{query::store::LiveQueryStoreHandle, smartcontracts::query::ValidQueryRequest};
+use iroha_core::{
+    query::{scope::DomainScope, store::LiveQueryStoreHandle},
+    simulation::{estimate_transaction, simulate_transaction},
+    smartcontracts::query::ValidQueryRequest,
+};
 use iroha_data_model::{
     self,
+    isi::InstructionType,
+    parameter::TransactionParameters,
     prelude::*,
     query::{QueryRequestWithAuthority, QueryResponse, SignedQuery},
+    transaction::{
+        receipt::{TransactionReceipt, TransactionReceiptPayload},
+        simulation::{TransactionEstimate, TransactionSimulation},
+    },
 };
 #[cfg(feature = "telemetry")]
-use iroha_telemetry::metrics::Status;
-use iroha_torii_shared::Version;
-use tokio::task;
+use iroha_telemetry::metrics::{InstructionStats, Status};
+use iroha_torii_shared::{QueueFull, Version};
+use tokio::{sync::OwnedSemaphorePermit, task};
 
 use super::*;
 
+/// Reject the `authority` outside of the `scope` of the virtual host, if any
+fn check_scope(scope: Option<&DomainScope>, authority: &AccountId) -> Result<()> {
+    match scope {
+        Some(scope) if !scope.contains(authority.domain()) => {
+            Err(Error::OutOfScope(authority.clone()))
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Reject the transaction containing the instructions not accepted by the peer
+fn check_instructions(filter: &InstructionFilter, tx: &SignedTransaction) -> Result<()> {
+    if !filter.is_restrictive() {
+        return Ok(());
+    }
+    match tx.instructions() {
+        Executable::Instructions(instructions) => instructions
+            .iter()
+            .map(InstructionType::from)
+            .find(|instruction| !filter.accepts(*instruction))
+            .map_or(Ok(()), |instruction| {
+                Err(Error::InstructionNotAccepted(instruction))
+            }),
+        Executable::Wasm(_) => Err(Error::SmartContractNotAccepted),
+    }
+}
+
+/// Push the transaction into the queue and acknowledge it with a receipt signed by the peer
 #[iroha_futures::telemetry_future]
 pub async fn handle_transaction(
     chain_id: Arc<ChainId>,
     queue: Arc<Queue>,
     state: Arc<State>,
+    scope: Option<DomainScope>,
+    instructions: Arc<InstructionFilter>,
+    key_pair: Arc<KeyPair>,
+    tx: SignedTransaction,
+) -> Result<Json<TransactionReceipt>> {
+    let received_at = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .expect("system time is after the epoch");
+    let tx_hash = tx.hash();
+    push_transaction(&chain_id, &queue, &state, scope.as_ref(), &instructions, tx)?;
+
+    let payload = TransactionReceiptPayload {
+        tx_hash,
+        received_at_ms: received_at.as_millis().try_into().unwrap_or(u64::MAX),
+        queue_len: queue.tx_len() as u64,
+        peer: PeerId::new(key_pair.public_key().clone()),
+    };
+    Ok(Json(TransactionReceipt::sign(payload, &key_pair)))
+}
+
+/// Accept the transaction and push it into the queue
+fn push_transaction(
+    chain_id: &ChainId,
+    queue: &Queue,
+    state: &State,
+    scope: Option<&DomainScope>,
+    instructions: &InstructionFilter,
     tx: SignedTransaction,
 ) -> Result<()> {
-    let (max_clock_drift, tx_limits) = {
+    check_scope(scope, tx.authority())?;
+    check_instructions(instructions, &tx)?;
+
+    let (max_clock_drift, tx_limits, round_time) = {
         let state_view = state.world.view();
         let params = state_view.parameters();
-        (params.sumeragi.max_clock_drift(), params.transaction)
+        (
+            params.sumeragi.max_clock_drift(),
+            params.transaction,
+            params
+                .sumeragi
+                .block_time()
+                .saturating_add(params.sumeragi.commit_time()),
+        )
     };
 
-    let accepted_tx = AcceptedTransaction::accept(tx, &chain_id, max_clock_drift, tx_limits)
+    let accepted_tx = AcceptedTransaction::accept(tx, chain_id, max_clock_drift, tx_limits)
         .map_err(Error::AcceptTransaction)?;
 
     queue
@@ -45,29 +122,144 @@ pub async fn handle_transaction(
                 "Failed to push into queue"
             );
 
-            Box::new(err)
+            match err {
+                // Space is freed once the next block is committed
+                queue::Error::Full => Error::QueueFull(QueueFull {
+                    queue_depth: queue.tx_len() as u64,
+                    capacity: queue.capacity().get() as u64,
+                    retry_after_ms: round_time.as_millis().try_into().unwrap_or(u64::MAX),
+                }),
+                err => Error::PushIntoQueue(Box::new(err)),
+            }
         })
-        .map_err(Error::PushIntoQueue)
+}
+
+/// Admit the transaction to be simulated: it must be signed by a registered account,
+/// and only a few transactions are simulated at once, since each of them holds a blocking thread
+fn admit_simulation(
+    state: &State,
+    simulations: Arc<Semaphore>,
+    tx: &SignedTransaction,
+) -> Result<OwnedSemaphorePermit> {
+    if state.view().world().account(tx.authority()).is_err() {
+        return Err(Error::UnknownAuthority(tx.authority().clone()));
+    }
+    simulations
+        .try_acquire_owned()
+        .map_err(|_| Error::TooManySimulations)
+}
+
+/// Execute the transaction against the current state without committing it
+#[iroha_futures::telemetry_future]
+pub async fn handle_simulate_transaction(
+    chain_id: Arc<ChainId>,
+    state: Arc<State>,
+    simulations: Arc<Semaphore>,
+    tx: SignedTransaction,
+) -> Result<Json<TransactionSimulation>> {
+    let (max_clock_drift, tx_limits) = {
+        let state_view = state.world.view();
+        let params = state_view.parameters();
+        (params.sumeragi.max_clock_drift(), params.transaction)
+    };
+
+    let accepted_tx = AcceptedTransaction::accept(tx, &chain_id, max_clock_drift, tx_limits)
+        .map_err(Error::AcceptTransaction)?;
+    let permit = admit_simulation(&state, simulations, accepted_tx.as_ref())?;
+
+    // Executing the transaction is CPU-bound
+    let simulation = task::spawn_blocking(move || {
+        let _permit = permit;
+        simulate_transaction(&state, accepted_tx)
+    })
+    .await
+    .expect("Failed to join transaction simulation task")?;
+    Ok(Json(simulation))
+}
+
+/// Estimate the resources the transaction consumes against the limits of the chain
+#[iroha_futures::telemetry_future]
+pub async fn handle_estimate_transaction(
+    chain_id: Arc<ChainId>,
+    state: Arc<State>,
+    simulations: Arc<Semaphore>,
+    tx: SignedTransaction,
+) -> Result<Json<TransactionEstimate>> {
+    let max_clock_drift = state.world.view().parameters().sumeragi.max_clock_drift();
+    // The limits are reported by the estimate rather than enforced
+    let unlimited = {
+        let max = NonZeroU64::try_from(NonZeroUsize::MAX).expect("usize fits into u64");
+        TransactionParameters::new(max, max, max, max)
+    };
+
+    let accepted_tx = AcceptedTransaction::accept(tx, &chain_id, max_clock_drift, unlimited)
+        .map_err(Error::AcceptTransaction)?;
+
+    let permit = admit_simulation(&state, simulations, accepted_tx.as_ref())?;
+
+    // Metering a smart contract executes it
+    let estimate = task::spawn_blocking(move || {
+        let _permit = permit;
+        estimate_transaction(&state, accepted_tx.as_ref())
+    })
+    .await
+    .expect("Failed to join transaction estimation task");
+    Ok(Json(estimate))
+}
+
+/// Parameters of the pending transactions request
+#[derive(Debug, serde::Deserialize)]
+pub struct PendingTransactionsParams {
+    /// Only the transactions of this authority, all if not set
+    authority: Option<AccountId>,
+    /// Only the transactions created at least this long ago, all if not set
+    min_age_ms: Option<u64>,
+}
+
+/// Transactions waiting in the queue to be included in a block, the oldest first
+#[iroha_futures::telemetry_future]
+pub async fn handle_pending_transactions(
+    queue: Arc<Queue>,
+    state: Arc<State>,
+    params: PendingTransactionsParams,
+) -> Scale<Vec<SignedTransaction>> {
+    let min_age = params
+        .min_age_ms
+        .map_or(Duration::ZERO, Duration::from_millis);
+    let now = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .expect("system time is after the epoch");
+
+    let state_view = state.view();
+    let mut transactions: Vec<SignedTransaction> = queue
+        .all_transactions(&state_view)
+        .map(SignedTransaction::from)
+        .filter(|tx| {
+            params
+                .authority
+                .as_ref()
+                .map_or(true, |authority| tx.authority() == authority)
+        })
+        .filter(|tx| now.saturating_sub(tx.creation_time()) >= min_age)
+        .collect();
+    transactions.sort_by_key(SignedTransaction::creation_time);
+
+    Scale(transactions)
 }
 
 #[iroha_futures::telemetry_future]
 pub async fn handle_queries(
     live_query_store: LiveQueryStoreHandle,
     state: Arc<State>,
+    scope: Option<DomainScope>,
     query: SignedQuery,
 ) -> Result<Scale<QueryResponse>> {
-    let handle = task::spawn_blocking(move || {
-        let state_view = state.view();
-
-        let SignedQuery::V1(query) = query;
-        let query: QueryRequestWithAuthority = query.payload;
-        let authority = query.authority.clone();
-
-        let valid_query = ValidQueryRequest::validate_for_client(query, &state_view)?;
-        let response = valid_query.execute(&live_query_store, &state_view, &authority)?;
+    let SignedQuery::V1(query) = query;
+    let query: QueryRequestWithAuthority = query.payload;
+    check_scope(scope.as_ref(), &query.authority)?;
 
-        Ok::<_, ValidationFail>(response)
-    });
+    let handle =
+        task::spawn_blocking(move || execute_query(&live_query_store, &state.view(), query, scope));
     handle
         .await
         .expect("Failed to join query handling task")
@@ -75,6 +267,43 @@ pub async fn handle_queries(
         .map_err(Into::into)
 }
 
+/// Execute the queries of the batch independently and in order, against the same state
+#[iroha_futures::telemetry_future]
+pub async fn handle_query_batch(
+    live_query_store: LiveQueryStoreHandle,
+    state: Arc<State>,
+    queries: Vec<SignedQuery>,
+) -> Result<Scale<Vec<Result<QueryResponse, ValidationFail>>>> {
+    let handle = task::spawn_blocking(move || {
+        let state_view = state.view();
+
+        queries
+            .into_iter()
+            .map(|SignedQuery::V1(query)| {
+                execute_query(&live_query_store, &state_view, query.payload, None)
+            })
+            .collect()
+    });
+    let responses = handle
+        .await
+        .expect("Failed to join query batch handling task");
+    Ok(Scale(responses))
+}
+
+fn execute_query(
+    live_query_store: &LiveQueryStoreHandle,
+    state_view: &impl StateReadOnly,
+    query: QueryRequestWithAuthority,
+    scope: Option<DomainScope>,
+) -> Result<QueryResponse, ValidationFail> {
+    let authority = query.authority.clone();
+
+    let valid_query = ValidQueryRequest::validate_for_client(query, state_view)?;
+    let response = valid_query.execute_in_scope(live_query_store, state_view, &authority, scope)?;
+
+    Ok(response)
+}
+
 pub async fn handle_health() -> &'static str {
     "Healthy"
 }
@@ -85,6 +314,51 @@ pub async fn handle_schema() -> axum::Json<iroha_schema::MetaMap> {
     axum::Json(iroha_schema_gen::build_schemas())
 }
 
+/// Response body for GET schema bundle request
+#[cfg(feature = "schema")]
+#[derive(serde::Serialize)]
+struct SchemaBundle {
+    /// Hash of the running executor, `None` for the initial one
+    executor_hash: Option<HashOf<iroha_data_model::executor::Executor>>,
+    /// Schema of the data model Iroha was built with
+    schema: iroha_schema::MetaMap,
+    /// Schema of the types declared by the running executor
+    executor_schema: iroha_primitives::json::Json,
+}
+
+/// Serve the schema bundle tagged with the executor hash,
+/// so that clients revalidate it with `If-None-Match` once the executor is upgraded
+#[cfg(feature = "schema")]
+pub fn handle_schema_bundle(state: &State, headers: &axum::http::HeaderMap) -> Response {
+    use axum::http::header::{ETAG, IF_NONE_MATCH};
+
+    let (executor_hash, executor_schema) = {
+        let world = state.world.view();
+        (
+            world.executor().hash(),
+            world.executor_data_model().schema().clone(),
+        )
+    };
+    let etag = executor_hash.map_or_else(
+        || "\"initial\"".to_owned(),
+        |executor_hash| format!("\"{executor_hash}\""),
+    );
+
+    if headers
+        .get(IF_NONE_MATCH)
+        .is_some_and(|tag| tag.as_bytes() == etag.as_bytes())
+    {
+        return (StatusCode::NOT_MODIFIED, [(ETAG, etag)]).into_response();
+    }
+
+    let bundle = SchemaBundle {
+        executor_hash,
+        schema: iroha_schema_gen::build_schemas(),
+        executor_schema,
+    };
+    ([(ETAG, etag)], axum::Json(bundle)).into_response()
+}
+
 #[iroha_futures::telemetry_future]
 pub async fn handle_get_configuration(kiso: KisoHandle) -> Result<axum::Json<ConfigGetDTO>> {
     let dto = kiso.get_dto().await?;
@@ -130,9 +404,26 @@ pub mod block {
 
     #[iroha_futures::telemetry_future]
     pub async fn handle_blocks_stream(kura: Arc<Kura>, stream: WebSocket) -> eyre::Result<()> {
+        handle_stream(kura, stream, false).await
+    }
+
+    /// Stream only headers and signatures of blocks, for watch-only clients
+    #[iroha_futures::telemetry_future]
+    pub async fn handle_block_headers_stream(
+        kura: Arc<Kura>,
+        stream: WebSocket,
+    ) -> eyre::Result<()> {
+        handle_stream(kura, stream, true).await
+    }
+
+    async fn handle_stream(
+        kura: Arc<Kura>,
+        stream: WebSocket,
+        headers_only: bool,
+    ) -> eyre::Result<()> {
         let mut stream = WebSocketScale(stream);
         let init_and_subscribe = async {
-            let mut consumer = block::Consumer::new(&mut stream, kura).await?;
+            let mut consumer = block::Consumer::new(&mut stream, kura, headers_only).await?;
             subscribe_forever(&mut consumer).await
         };
 
@@ -171,7 +462,12 @@ pub mod block {
 pub mod event {
     //! Events stream handler
 
+    use iroha_data_model::{
+        events::stream::EventMessage,
+        subscription::{SubscriptionDelivery, SubscriptionId},
+    };
     use stream::WebSocketScale;
+    use tokio::sync::broadcast;
 
     use super::*;
     use crate::event;
@@ -183,6 +479,10 @@ pub mod event {
         Consumer(#[from] Box<event::Error>),
         /// Event reception error
         Event(#[from] tokio::sync::broadcast::error::RecvError),
+        /// Inbox subscription `{_0}` not found
+        Inbox(SubscriptionId),
+        /// Challenge isn't signed by the account of subscription `{_0}`
+        Unauthorized(SubscriptionId),
         /// Connection is closed
         Close,
     }
@@ -201,10 +501,54 @@ pub mod event {
     /// Subscribes `stream` for `events` filtered by filter that is
     /// received through the `stream`
     #[iroha_futures::telemetry_future]
-    pub async fn handle_events_stream(events: EventsSender, stream: WebSocket) -> eyre::Result<()> {
+    pub async fn handle_events_stream(
+        events: broadcast::Sender<EventMessage>,
+        scope: Option<DomainScope>,
+        stream: WebSocket,
+    ) -> eyre::Result<()> {
+        let mut stream = WebSocketScale(stream);
+        let init_and_subscribe = async {
+            let mut consumer = event::Consumer::new(&mut stream)
+                .await?
+                .in_scope(scope)
+                .unsequenced();
+            subscribe_forever(events.subscribe(), &mut consumer).await
+        };
+
+        match init_and_subscribe.await {
+            Ok(()) => stream.close().await.map_err(Into::into),
+            Err(Error::Close) => Ok(()),
+            Err(err) => {
+                // NOTE: try close websocket and return initial error
+                let _ = stream.close().await;
+                Err(err.into())
+            }
+        }
+    }
+
+    /// Subscribes `stream` for `events` along with their sequences filtered by filter that is
+    /// received through the `stream`. The events following the one the client resumes after
+    /// are replayed first, as far as the peer keeps them.
+    #[iroha_futures::telemetry_future]
+    pub async fn handle_sequenced_events_stream(
+        events: broadcast::Sender<EventMessage>,
+        replay: event::Replay,
+        scope: Option<DomainScope>,
+        stream: WebSocket,
+    ) -> eyre::Result<()> {
         let mut stream = WebSocketScale(stream);
         let init_and_subscribe = async {
-            let mut consumer = event::Consumer::new(&mut stream).await?;
+            let (consumer, after) = event::Consumer::resumed(&mut stream).await?;
+            let mut consumer = consumer.in_scope(scope);
+            // NOTE: subscribed before the replay, so that no event is missed in between
+            let events = events.subscribe();
+            if let Some(after) = after {
+                let (replayed, complete) = replay.after(after);
+                if !complete {
+                    consumer.lost().await?;
+                }
+                consumer.replay(replayed).await?;
+            }
             subscribe_forever(events, &mut consumer).await
         };
 
@@ -219,15 +563,124 @@ pub mod event {
         }
     }
 
+    /// Subscribes `stream` for the events of the `domain` filtered by filter that is
+    /// received through the `stream`
+    #[iroha_futures::telemetry_future]
+    pub async fn handle_domain_events_stream(
+        shards: event::DomainShards,
+        domain: DomainId,
+        stream: WebSocket,
+    ) -> eyre::Result<()> {
+        let mut stream = WebSocketScale(stream);
+        let init_and_subscribe = async {
+            let mut consumer = event::Consumer::new(&mut stream).await?.unsequenced();
+            subscribe_forever(shards.subscribe(domain), &mut consumer).await
+        };
+
+        match init_and_subscribe.await {
+            Ok(()) => stream.close().await.map_err(Into::into),
+            Err(Error::Close) => Ok(()),
+            Err(err) => {
+                // NOTE: try close websocket and return initial error
+                let _ = stream.close().await;
+                Err(err.into())
+            }
+        }
+    }
+
+    /// Subscribes `stream` for `events` filtered by the on-chain inbox subscription
+    /// which id is received through the `stream`. The stream is closed once the
+    /// subscription is removed.
+    ///
+    /// The client proves the control of the subscribed account by signing a random
+    /// challenge sent in response to the id.
+    #[iroha_futures::telemetry_future]
+    pub async fn handle_inbox_stream(
+        events: broadcast::Sender<EventMessage>,
+        state: Arc<State>,
+        stream: WebSocket,
+    ) -> eyre::Result<()> {
+        let mut stream = WebSocketScale(stream);
+        let init_and_subscribe = async {
+            let id = stream
+                .recv::<SubscriptionId>()
+                .await
+                .map_err(event::Error::from)?;
+            let mut challenge = [0_u8; 32];
+            rand::RngCore::fill_bytes(&mut rand::rngs::OsRng, &mut challenge);
+            stream
+                .send(challenge.to_vec())
+                .await
+                .map_err(event::Error::from)?;
+            let signature = stream
+                .recv::<Signature>()
+                .await
+                .map_err(event::Error::from)?;
+            signature
+                .verify(id.account.signatory(), &challenge)
+                .map_err(|_| Error::Unauthorized(id.clone()))?;
+            let filter = inbox_filter(&state, &id).ok_or_else(|| Error::Inbox(id.clone()))?;
+            let mut consumer = event::Consumer::with_filters(&mut stream, vec![filter]);
+            subscribe_to_inbox(events, &state, &id, &mut consumer).await
+        };
+
+        match init_and_subscribe.await {
+            Ok(()) => stream.close().await.map_err(Into::into),
+            Err(Error::Close) => Ok(()),
+            Err(err) => {
+                // NOTE: try close websocket and return initial error
+                let _ = stream.close().await;
+                Err(err.into())
+            }
+        }
+    }
+
+    /// Filter of the subscription with `id` if it exists and is delivered to the inbox
+    fn inbox_filter(state: &State, id: &SubscriptionId) -> Option<EventFilterBox> {
+        let state_view = state.view();
+        let subscription = state_view.world().subscription(id).ok()?;
+        matches!(subscription.delivery(), SubscriptionDelivery::Inbox)
+            .then(|| subscription.filter().clone())
+    }
+
+    /// Make `consumer` subscription for `events` lasting until subscription with `id` is removed
+    async fn subscribe_to_inbox(
+        events: broadcast::Sender<EventMessage>,
+        state: &State,
+        id: &SubscriptionId,
+        consumer: &mut event::Consumer<'_>,
+    ) -> Result<()> {
+        let mut events = events.subscribe();
+
+        loop {
+            tokio::select! {
+                // Wait for stream to be closed by client
+                closed = consumer.stream.closed() => {
+                    match closed {
+                        Ok(()) => return Err(Error::Close),
+                        Err(err) => return Err(event::Error::from(err).into())
+                    }
+                }
+                // This branch catches and sends events
+                event = events.recv() => {
+                    let event = event?;
+                    if inbox_filter(state, id).is_none() {
+                        return Ok(());
+                    }
+                    iroha_logger::trace!(?event);
+                    consumer.consume(event).await?;
+                }
+            }
+        }
+    }
+
     /// Make endless `consumer` subscription for `events`
     ///
     /// Ideally should return `Result<!>` cause it either runs forever or returns error
     async fn subscribe_forever(
-        events: EventsSender,
+        mut events: broadcast::Receiver<EventMessage>,
         consumer: &mut event::Consumer<'_>,
     ) -> Result<()> {
-        let mut events = events.subscribe();
-
         loop {
             tokio::select! {
                 // Wait for stream to be closed by client
@@ -248,6 +701,56 @@ pub mod event {
     }
 }
 
+pub mod multisig {
+    //! Handlers exchanging the approvals of multisig proposals
+
+    use iroha_executor_data_model::isi::multisig::MultisigProposalValue;
+    use iroha_torii_shared::MultisigApprovals;
+
+    use super::*;
+    use crate::multisig::{self, ProposalHash};
+
+    /// Submit the multisig proposal made by `tx`
+    #[iroha_futures::telemetry_future]
+    pub async fn handle_propose(
+        chain_id: Arc<ChainId>,
+        queue: Arc<Queue>,
+        state: Arc<State>,
+        instructions: Arc<InstructionFilter>,
+        tx: SignedTransaction,
+    ) -> Result<Json<MultisigApprovals>> {
+        let approvals = multisig::propose(&state, &tx)?;
+        push_transaction(&chain_id, &queue, &state, None, &instructions, tx)?;
+        Ok(Json(approvals))
+    }
+
+    /// Proposal with the given `hash` pending approvals of the signatories of the multisig `account`
+    #[iroha_futures::telemetry_future]
+    pub async fn handle_get_proposal(
+        state: Arc<State>,
+        account: AccountId,
+        hash: ProposalHash,
+    ) -> Result<Json<MultisigProposalValue>> {
+        Ok(Json(multisig::proposal(&state, &account, hash)?))
+    }
+
+    /// Submit the approval made by `tx` of the pending proposal with the given `hash` of the multisig `account`
+    #[iroha_futures::telemetry_future]
+    pub async fn handle_approve(
+        chain_id: Arc<ChainId>,
+        queue: Arc<Queue>,
+        state: Arc<State>,
+        instructions: Arc<InstructionFilter>,
+        account: AccountId,
+        hash: ProposalHash,
+        tx: SignedTransaction,
+    ) -> Result<Json<MultisigApprovals>> {
+        let approvals = multisig::approve(&state, &account, hash, &tx)?;
+        push_transaction(&chain_id, &queue, &state, None, &instructions, tx)?;
+        Ok(Json(approvals))
+    }
+}
+
 #[iroha_futures::telemetry_future]
 pub async fn handle_version(state: Arc<State>) -> String {
     use iroha_version::Version;
@@ -287,6 +790,15 @@ pub async fn profiling_not_implemented() -> impl IntoResponse {
     )
 }
 
+#[cfg(not(feature = "replay"))]
+pub async fn replay_not_implemented() -> impl IntoResponse {
+    (
+        StatusCode::NOT_IMPLEMENTED,
+        "This endpoint is not available on this version of \"irohad\", \
+          as it was compiled without the \"replay-endpoint\" feature flag",
+    )
+}
+
 #[cfg(feature = "telemetry")]
 pub async fn handle_metrics(telemetry: &Telemetry) -> Result<String> {
     telemetry
@@ -296,6 +808,34 @@ pub async fn handle_metrics(telemetry: &Telemetry) -> Result<String> {
         .map_err(Error::Prometheus)
 }
 
+/// Parameters of the instruction statistics request
+#[cfg(feature = "telemetry")]
+#[derive(Debug, serde::Deserialize)]
+pub struct InstructionStatsParams {
+    /// Time window ending now the instructions are counted over, an hour if not set
+    window_ms: Option<u64>,
+}
+
+#[cfg(feature = "telemetry")]
+pub async fn handle_instruction_stats(
+    telemetry: &Telemetry,
+    params: InstructionStatsParams,
+    accept: Option<axum::http::HeaderValue>,
+) -> Response {
+    const DEFAULT_WINDOW: Duration = Duration::from_secs(60 * 60);
+
+    let window = params
+        .window_ms
+        .map_or(DEFAULT_WINDOW, Duration::from_millis);
+    let stats: InstructionStats = telemetry.instruction_stats(window).await;
+
+    if accept.is_some_and(|x| x.as_ref() == utils::PARITY_SCALE_MIME_TYPE.as_bytes()) {
+        Scale(stats).into_response()
+    } else {
+        axum::Json(stats).into_response()
+    }
+}
+
 pub fn handle_peers(online_peers: &OnlinePeersProvider) -> Response {
     // Caution: this short `.get` done frequently might cause the writer part to block, thus
     // blocking the P2P module
@@ -307,12 +847,20 @@ pub fn handle_peers(online_peers: &OnlinePeersProvider) -> Response {
 #[allow(clippy::unnecessary_wraps)]
 pub async fn handle_status(
     telemetry: &Telemetry,
+    instructions: &InstructionFilter,
     accept: Option<axum::http::HeaderValue>,
     tail: Option<&str>,
 ) -> Result<Response> {
     use eyre::ContextCompat;
 
-    let status = Status::from(&telemetry.metrics().await);
+    let status = Status {
+        instructions_allowed: instructions
+            .allowed
+            .as_ref()
+            .map(|allowed| allowed.iter().copied().collect()),
+        instructions_denied: instructions.denied.iter().copied().collect(),
+        ..Status::from(&telemetry.metrics().await)
+    };
 
     if let Some(tail) = tail {
         // TODO: This probably can be optimised to elide the full
@@ -426,3 +974,33 @@ pub fn handle_server_version() -> axum::http::Response<axum::body::Body> {
     };
     axum::Json(version).into_response()
 }
+
+#[cfg(feature = "replay")]
+pub mod replay {
+    use iroha_core::replay::{Replayer, TransactionReplay};
+    use serde::{Deserialize, Serialize};
+
+    use super::*;
+
+    /// Query params used to select the transaction to replay
+    #[derive(Serialize, Deserialize, Clone, Copy)]
+    pub struct ReplayParams {
+        /// Hash of the committed transaction
+        hash: HashOf<SignedTransaction>,
+    }
+
+    /// Re-execute a committed transaction against the state preceding its block
+    #[iroha_futures::telemetry_future]
+    pub async fn handle_replay_transaction(
+        ReplayParams { hash }: ReplayParams,
+        state: Arc<State>,
+        replayer: Arc<Replayer>,
+    ) -> Result<Json<TransactionReplay>> {
+        // Rebuilding the state is CPU-bound and can take a long time
+        task::spawn_blocking(move || replayer.replay(&state, hash))
+            .await
+            .expect("Failed to join transaction replay task")
+            .map(Json)
+            .map_err(Into::into)
+    }
+}
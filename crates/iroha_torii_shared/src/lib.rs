@@ -6,33 +6,81 @@ pub mod uri {
 
     /// Query URI is used to handle incoming Query requests.
     pub const QUERY: &str = "/query";
+    /// URI for executing several independent queries in a single request.
+    pub const QUERY_BATCH: &str = "/query/batch";
     /// Transaction URI is used to handle incoming ISI requests.
     pub const TRANSACTION: &str = "/transaction";
+    /// URI for executing a transaction against the current state without committing it.
+    pub const TRANSACTION_SIMULATION: &str = "/transaction/simulate";
+    /// URI for estimating the resources a transaction consumes against the limits of the chain.
+    pub const TRANSACTION_ESTIMATE: &str = "/transaction/estimate";
+    /// URI for listing the transactions waiting in the queue of the peer.
+    pub const PENDING_TRANSACTIONS: &str = "/transaction/pending";
+    /// URI for uploading a multisig proposal to be approved by the signatories.
+    /// Followed by the multisig account and the hash of the proposal instructions to fetch the proposal,
+    /// and by the account, the hash and `/approvals` to upload an approval.
+    pub const MULTISIG_PENDING: &str = "/multisig/pending";
     /// Health URI is used to handle incoming Healthcheck requests.
     pub const HEALTH: &str = "/health";
     /// Peers URI is used to find all peers in the network
     pub const PEERS: &str = "/peers";
     /// The web socket uri used to subscribe to block and transactions statuses.
     pub const SUBSCRIPTION: &str = "/events";
+    /// The web socket uri used to subscribe to the events along with their sequences.
+    pub const SUBSCRIPTION_SEQUENCED: &str = "/events/sequenced";
+    /// The web socket uri used to receive the events of an on-chain inbox subscription.
+    pub const SUBSCRIPTION_INBOX: &str = "/events/inbox";
+    /// The web socket uri used to subscribe to the events of a single domain, followed by the domain id.
+    pub const SUBSCRIPTION_DOMAIN: &str = "/events/domain";
     /// The web socket uri used to subscribe to blocks stream.
     pub const BLOCKS_STREAM: &str = "/block/stream";
+    /// The web socket uri used to subscribe to the stream of block headers only.
+    pub const BLOCK_HEADERS_STREAM: &str = "/block/headers/stream";
     /// The URI for local config changing inspecting
     pub const CONFIGURATION: &str = "/configuration";
     /// URI to report status for administration
     pub const STATUS: &str = "/status";
+    /// URI to report the usage of the instructions over a time window
+    pub const INSTRUCTION_STATS: &str = "/stats/instructions";
     ///  Metrics URI is used to export metrics according to [Prometheus
     ///  Guidance](https://prometheus.io/docs/instrumenting/writing_exporters/).
     pub const METRICS: &str = "/metrics";
     /// URI for retrieving the schema with which Iroha was built.
     pub const SCHEMA: &str = "/schema";
+    /// URI for retrieving the schema along with the types declared by the running executor.
+    pub const SCHEMA_BUNDLE: &str = "/schema/bundle";
     /// URI for getting the API version currently used
     pub const API_VERSION: &str = "/api_version";
     /// URI for getting cpu profile
     pub const PROFILE: &str = "/debug/pprof/profile";
+    /// URI for re-executing a committed transaction against the state preceding its block
+    pub const REPLAY_TRANSACTION: &str = "/debug/transaction/replay";
     /// URI for getting the server version
     pub const SERVER_VERSION: &str = "/server_version";
 }
 
+/// Response body for a transaction rejected because the queue is saturated
+///
+/// Sent along with `503 Service Unavailable` and a `Retry-After` header.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+pub struct QueueFull {
+    /// Number of transactions currently waiting in the queue
+    pub queue_depth: u64,
+    /// Maximum number of transactions the queue can hold
+    pub capacity: u64,
+    /// Suggested delay (in milliseconds) before resubmitting the transaction
+    pub retry_after_ms: u64,
+}
+
+/// Response body for an uploaded multisig proposal or approval, which is submitted right away
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+pub struct MultisigApprovals {
+    /// Total weight of the signatories who approved the proposal, including the proposer and the uploaded approval
+    pub weight: u16,
+    /// Weight the proposal is executed at, within the transaction of the approval reaching it
+    pub quorum: u16,
+}
+
 /// Response body for GET server version request
 #[derive(Deserialize, Serialize)]
 pub struct Version {
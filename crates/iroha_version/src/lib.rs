@@ -127,6 +127,27 @@ pub trait Version {
     }
 }
 
+/// Versioned container able to convert the content of the previous versions into the latest one.
+///
+/// During a rolling upgrade the upgraded peers keep receiving the data produced by the peers
+/// running the previous version of the data model. Keeping the previous version among the supported ones
+/// and upcasting it right after decoding lets the rest of the code deal with the latest version only,
+/// so that the network doesn't split as soon as one of the peers is upgraded.
+///
+/// Meant for the containers declaring more than one version, decoded with
+/// [`decode_all_upcast`](scale::DecodeVersioned::decode_all_upcast) in place of
+/// [`decode_all_versioned`](scale::DecodeVersioned::decode_all_versioned).
+/// The containers declaring a single version have nothing to upcast.
+pub trait Upcast: Version + Sized {
+    /// Convert the content into the latest supported version.
+    /// The content of the latest version is returned as is.
+    ///
+    /// The hashes of the signed parts of the content must stay the same,
+    /// otherwise the signatures made by the peers running the previous version no longer verify.
+    #[must_use]
+    fn upcast(self) -> Self;
+}
+
 /// Structure describing a container content which version is not supported.
 #[derive(Debug, Clone)]
 #[cfg_attr(feature = "std", derive(thiserror::Error))]
@@ -179,7 +200,7 @@ pub mod scale {
 
     use parity_scale_codec::{DecodeAll, Encode};
 
-    use super::{error::Result, Version};
+    use super::{error::Result, Upcast, Version};
 
     /// [`parity_scale_codec::Decode`] versioned analog.
     pub trait DecodeVersioned: DecodeAll + Version {
@@ -190,6 +211,17 @@ pub mod scale {
         /// - Input won't have enough bytes for decoding
         /// - Input has extra bytes
         fn decode_all_versioned(input: &[u8]) -> Result<Self>;
+
+        /// Same as [`Self::decode_all_versioned`], but [upcasts](Upcast) the content of the previous versions.
+        ///
+        /// # Errors
+        /// Same as [`Self::decode_all_versioned`]
+        fn decode_all_upcast(input: &[u8]) -> Result<Self>
+        where
+            Self: Upcast,
+        {
+            Self::decode_all_versioned(input).map(Upcast::upcast)
+        }
     }
 
     /// [`parity_scale_codec::Encode`] versioned analog.
@@ -207,7 +239,7 @@ pub mod json {
 
     use serde::{Deserialize, Serialize};
 
-    use super::{error::Result, Version};
+    use super::{error::Result, Upcast, Version};
 
     /// [`Serialize`] versioned analog, specifically for JSON.
     pub trait DeserializeVersioned<'de>: Deserialize<'de> + Version {
@@ -219,6 +251,17 @@ pub mod json {
         /// * if json is not an object
         /// * if json is has no version field
         fn from_versioned_json_str(input: &str) -> Result<Self>;
+
+        /// Same as [`Self::from_versioned_json_str`], but [upcasts](Upcast) the content of the previous versions.
+        ///
+        /// # Errors
+        /// Same as [`Self::from_versioned_json_str`]
+        fn from_versioned_json_str_upcast(input: &str) -> Result<Self>
+        where
+            Self: Upcast,
+        {
+            Self::from_versioned_json_str(input).map(Upcast::upcast)
+        }
     }
 
     /// [`Deserialize`] versioned analog, specifically for JSON.
@@ -4,7 +4,7 @@ mod tests {
     use iroha_version::{
         error::{Error, Result},
         scale::*,
-        RawVersioned,
+        RawVersioned, Upcast,
     };
     use iroha_version_derive::{declare_versioned, version};
     use parity_scale_codec::{Decode, Encode};
@@ -40,6 +40,15 @@ mod tests {
         #[version(version = 3, versioned_alias = "VersionedMessage")]
         #[derive(Debug, Clone, Decode, Encode, Serialize, Deserialize)]
         pub struct Message3(pub String);
+
+        impl Upcast for VersionedMessage {
+            fn upcast(self) -> Self {
+                match self {
+                    Self::V1(_) | Self::V2(_) => Message3(String::new()).into(),
+                    Self::V3(message) => message.into(),
+                }
+            }
+        }
     }
 
     #[test]
@@ -62,6 +71,26 @@ mod tests {
         }
     }
 
+    #[test]
+    fn previous_version_is_upcast() -> Result<(), String> {
+        let bytes = {
+            use model_1::*;
+
+            let versioned_message: VersionedMessage = Message.into();
+            versioned_message.encode_versioned()
+        };
+
+        let decoded_message =
+            model_2::VersionedMessage::decode_all_upcast(&bytes).map_err(|e| e.to_string())?;
+        match decoded_message {
+            model_2::VersionedMessage::V3(message) => {
+                assert!(message.0.is_empty());
+                Ok(())
+            }
+            _ => Err("Should have been upcast to message v3.".to_owned()),
+        }
+    }
+
     #[test]
     fn unsupported_version() -> Result<(), String> {
         use model_1::*;
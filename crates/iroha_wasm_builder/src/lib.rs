@@ -75,6 +75,8 @@ pub struct Builder<'path, 'out_dir> {
     out_dir: Option<&'out_dir Path>,
     /// Flag controlling whether to show output of the build process
     show_output: bool,
+    /// Flag controlling whether to collect the compiler warnings
+    collect_warnings: bool,
     /// Build profile
     profile: Profile,
     cargo_args: Vec<String>,
@@ -92,6 +94,7 @@ impl<'path, 'out_dir> Builder<'path, 'out_dir> {
             path: relative_path.as_ref(),
             out_dir: None,
             show_output: false,
+            collect_warnings: false,
             profile,
             cargo_args: <_>::default(),
         }
@@ -124,12 +127,23 @@ impl<'path, 'out_dir> Builder<'path, 'out_dir> {
         self
     }
 
+    /// Collect the compiler warnings, see [`Output::warnings`].
+    ///
+    /// `cargo` is run with `--message-format=json` for that, the diagnostics are still printed to stderr.
+    /// Disabled by default.
+    pub fn collect_warnings(mut self) -> Self {
+        self.collect_warnings = true;
+        self
+    }
+
     /// Apply `cargo check` to the smartcontract.
     ///
+    /// Returns the compiler warnings if [`Self::collect_warnings`] is enabled.
+    ///
     /// # Errors
     ///
     /// Can fail due to multiple reasons like invalid path, failed build, etc.
-    pub fn check(self) -> Result<()> {
+    pub fn check(self) -> Result<Vec<String>> {
         self.into_internal()?.check()
     }
 
@@ -166,6 +180,7 @@ impl<'path, 'out_dir> Builder<'path, 'out_dir> {
                 |out_dir| Ok(Cow::Borrowed(out_dir)),
             )?,
             show_output: self.show_output,
+            collect_warnings: self.collect_warnings,
             profile: self.profile,
             cargo_args: self.cargo_args,
         })
@@ -221,12 +236,13 @@ mod internal {
         pub absolute_path: PathBuf,
         pub out_dir: Cow<'out_dir, Path>,
         pub show_output: bool,
+        pub collect_warnings: bool,
         pub profile: Profile,
         pub cargo_args: Vec<String>,
     }
 
     impl Builder<'_> {
-        pub fn check(self) -> Result<()> {
+        pub fn check(self) -> Result<Vec<String>> {
             self.check_smartcontract().wrap_err_with(|| {
                 format!(
                     "Failed to check the smartcontract at path: {}",
@@ -276,10 +292,19 @@ mod internal {
             command
         }
 
-        fn check_smartcontract(&self) -> Result<()> {
+        fn check_smartcontract(&self) -> Result<Vec<String>> {
             let command = &mut self.get_base_command("check");
 
-            check_command(self.show_output, command, "cargo check")
+            self.run_command(command, "cargo check")
+        }
+
+        fn run_command(&self, command: &mut Command, command_name: &str) -> Result<Vec<String>> {
+            if self.collect_warnings {
+                check_command_collecting_warnings(command, command_name)
+            } else {
+                check_command(self.show_output, command, command_name)?;
+                Ok(Vec::new())
+            }
         }
 
         fn build_smartcontract(self) -> Result<Output> {
@@ -305,8 +330,7 @@ mod internal {
                 None
             };
 
-            check_command(
-                self.show_output,
+            let warnings = self.run_command(
                 self.get_base_command("build")
                     .env("CARGO_TARGET_DIR", self.out_dir.as_ref()),
                 "cargo build",
@@ -315,6 +339,7 @@ mod internal {
             Ok(Output {
                 wasm_file,
                 previous_hash,
+                warnings,
             })
         }
 
@@ -350,6 +375,8 @@ pub struct Output {
     wasm_file: PathBuf,
     /// Hash of the `self.wasm_file` on previous iteration if there is some.
     previous_hash: Option<String>,
+    /// Compiler warnings, if they were collected.
+    warnings: Vec<String>,
 }
 
 impl Output {
@@ -397,6 +424,7 @@ impl Output {
         Ok(Self {
             wasm_file: optimized_file,
             previous_hash: Some(current_hash),
+            warnings: self.warnings,
         })
     }
 
@@ -422,6 +450,37 @@ impl Output {
     pub fn wasm_file_path(&self) -> &PathBuf {
         &self.wasm_file
     }
+
+    /// Get the compiler warnings collected with [`Builder::collect_warnings`]
+    #[must_use]
+    pub fn warnings(&self) -> &[String] {
+        &self.warnings
+    }
+
+    /// Get the size of the underlying WASM in bytes.
+    ///
+    /// # Errors
+    ///
+    /// Fails if the metadata of the output file cannot be read.
+    pub fn size(&self) -> Result<u64> {
+        std::fs::metadata(&self.wasm_file)
+            .map(|metadata| metadata.len())
+            .wrap_err_with(|| format!("Failed to read metadata of {}", self.wasm_file.display()))
+    }
+
+    /// Get the hex-encoded sha256 digest of the underlying WASM.
+    ///
+    /// # Errors
+    ///
+    /// Fails if the output file cannot be read.
+    pub fn sha256(&self) -> Result<String> {
+        sha256::try_digest(self.wasm_file.as_path()).wrap_err_with(|| {
+            format!(
+                "Failed to compute sha256 digest of wasm file: {}",
+                self.wasm_file.display()
+            )
+        })
+    }
 }
 
 // TODO: Remove cargo invocation (#2152)
@@ -456,6 +515,52 @@ fn check_command_output(output: &std::process::Output, command_name: &str) -> Re
     }
 }
 
+/// Run the `command` with `--message-format=json` and collect the rendered compiler warnings.
+///
+/// The diagnostics are printed to stderr as `cargo` would print them itself.
+fn check_command_collecting_warnings(
+    command: &mut Command,
+    command_name: &str,
+) -> Result<Vec<String>> {
+    use std::io::{BufRead as _, BufReader};
+
+    let mut child = command
+        .arg("--message-format=json")
+        .stdout(std::process::Stdio::piped())
+        .spawn()
+        .wrap_err(format!("Failed to run `{command_name}`"))?;
+    let stdout = child.stdout.take().expect("stdout is piped");
+
+    let mut warnings = Vec::new();
+    for line in BufReader::new(stdout).lines() {
+        let line = line.wrap_err(format!("Failed to read the output of `{command_name}`"))?;
+        let Ok(message) = line.parse::<serde_json::Value>() else {
+            continue;
+        };
+        if message["reason"] != "compiler-message" {
+            continue;
+        }
+        let Some(rendered) = message["message"]["rendered"].as_str() else {
+            continue;
+        };
+        eprint!("{rendered}");
+        if message["message"]["level"] == "warning" {
+            warnings.push(rendered.to_owned());
+        }
+    }
+
+    let status = child
+        .wait()
+        .wrap_err(format!("Failed to run `{command_name}`"))?;
+    if status.success() {
+        Ok(warnings)
+    } else {
+        bail!(
+            "`{command_name}` returned non zero exit code ({status}). See messages above for the probable error",
+        );
+    }
+}
+
 fn check_command(show_output: bool, command: &mut Command, command_name: &str) -> Result<()> {
     if show_output {
         let status = command
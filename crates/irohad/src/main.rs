@@ -17,7 +17,9 @@ use iroha_config::{
 #[cfg(feature = "telemetry")]
 use iroha_core::telemetry::StateTelemetry;
 use iroha_core::{
+    archive::Archiver,
     block_sync::{BlockSynchronizer, BlockSynchronizerHandle},
+    compaction::Compactor,
     gossiper::{TransactionGossiper, TransactionGossiperHandle},
     kiso::KisoHandle,
     kura::Kura,
@@ -27,7 +29,8 @@ use iroha_core::{
     smartcontracts::isi::Registrable as _,
     snapshot::{try_read_snapshot, SnapshotMaker, TryReadError as TryReadSnapshotError},
     state::{State, StateReadOnly, World},
-    sumeragi::{GenesisWithPubKey, SumeragiHandle, SumeragiStartArgs},
+    sumeragi::{proposal_check, GenesisWithPubKey, SumeragiHandle, SumeragiStartArgs},
+    tiering::ColdStore,
     IrohaNetwork,
 };
 use iroha_data_model::{block::SignedBlock, prelude::*};
@@ -85,6 +88,18 @@ pub struct Args {
         num_args(0..=1),
     )]
     pub terminal_colors: bool,
+    /// Rebuild the state and its derived indices from the blocks stored in Kura
+    ///
+    /// The state snapshot is ignored, so all blocks are replayed.
+    /// Use it to recover from a corrupted snapshot or to populate indices
+    /// introduced by a newer version of Iroha.
+    #[arg(long, env)]
+    pub reindex: bool,
+    /// Restore the blocks and the state snapshot from the archive configured in `[archive]`
+    ///
+    /// The block store has to be empty. Iroha starts once the archive is restored.
+    #[arg(long, env)]
+    pub restore_from_archive: bool,
 }
 
 #[derive(thiserror::Error, Debug)]
@@ -95,6 +110,8 @@ enum MainError {
     Config,
     #[error("Could not initialize logger")]
     Logger,
+    #[error("Failed to restore from the archive")]
+    Restore,
     #[error("Failed to start Iroha")]
     IrohaStart,
     #[error("Error occured while running Iroha")]
@@ -121,6 +138,8 @@ pub enum StartError {
     StartP2p,
     #[error("Unable to initialize Kura (block storage)")]
     InitKura,
+    #[error("Unable to prepare the store of the dormant accounts")]
+    InitColdStore,
     #[error("Unable to start dev telemetry service")]
     StartDevTelemetry,
     #[error("Unable to start telemetry service")]
@@ -182,6 +201,7 @@ impl Iroha {
         config: Config,
         genesis: Option<GenesisBlock>,
         logger: LoggerHandle,
+        reindex: bool,
         shutdown_signal: ShutdownSignal,
     ) -> Result<
         (
@@ -207,14 +227,23 @@ impl Iroha {
             (metrics.clone(), StateTelemetry::new(metrics))
         };
 
-        let state = match try_read_snapshot(
-            config.snapshot.store_dir.resolve_relative_path(),
-            &kura,
-            || live_query_store.clone(),
-            block_count,
-            #[cfg(feature = "telemetry")]
-            state_telemetry.clone()
-        ) {
+        let snapshot = if reindex {
+            iroha_logger::info!(
+                blocks = block_count.0,
+                "Reindexing requested; ignoring the state snapshot and replaying all blocks"
+            );
+            Err(TryReadSnapshotError::NotFound)
+        } else {
+            try_read_snapshot(
+                config.snapshot.store_dir.resolve_relative_path(),
+                &kura,
+                || live_query_store.clone(),
+                block_count,
+                #[cfg(feature = "telemetry")]
+                state_telemetry.clone(),
+            )
+        };
+        let mut state = match snapshot {
             Ok(state) => {
                 iroha_logger::info!(
                     at_height = state.view().height(),
@@ -223,7 +252,9 @@ impl Iroha {
                 Some(state)
             }
             Err(TryReadSnapshotError::NotFound) => {
-                iroha_logger::info!("Didn't find a state snapshot; creating an empty state");
+                if !reindex {
+                    iroha_logger::info!("Didn't find a state snapshot; creating an empty state");
+                }
                 None
             }
             Err(error) => {
@@ -245,6 +276,12 @@ impl Iroha {
                 state_telemetry
             )
         });
+        state.set_retention(config.retention);
+        if let Some(cold_store) =
+            ColdStore::from_config(&config.tiering).change_context(StartError::InitColdStore)?
+        {
+            state.set_cold_store(cold_store);
+        }
         let state = Arc::new(state);
 
         let (events_sender, _) = broadcast::channel(EVENTS_BUFFER_CAPACITY);
@@ -271,10 +308,19 @@ impl Iroha {
                 kura.clone(),
                 queue.clone(),
                 network.online_peers_receiver(),
+                network.rejected_peers_receiver(),
                 TimeSource::new_system(),
             );
             supervisor.monitor(child);
 
+            if let Some(config) = &config.network_stats {
+                let telemetry = metrics_reporter.clone();
+                supervisor.monitor(iroha_telemetry::stats::start(config.clone(), move || {
+                    let telemetry = telemetry.clone();
+                    async move { iroha_telemetry::metrics::Status::from(&telemetry.metrics().await) }
+                }));
+            }
+
             metrics_reporter
         };
 
@@ -300,6 +346,7 @@ impl Iroha {
                 public_key: config.genesis.public_key.clone(),
             },
             block_count,
+            proposal_checks: proposal_check::from_config(&config.sumeragi),
             #[cfg(feature = "telemetry")]
             telemetry: telemetry.clone(),
         }
@@ -333,6 +380,17 @@ impl Iroha {
             supervisor.monitor(snapshot_maker.start(supervisor.shutdown_signal()));
         }
 
+        if let Some(compactor) =
+            Compactor::from_config(config.retention, Arc::clone(&state), Arc::clone(&kura))
+        {
+            supervisor.monitor(compactor.start(supervisor.shutdown_signal()));
+        }
+
+        if let Some(archive) = config.archive.clone() {
+            let archiver = Archiver::new(archive, &config.snapshot, kura.clone());
+            supervisor.monitor(archiver.start(supervisor.shutdown_signal()));
+        }
+
         let (kiso, child) = KisoHandle::start(config.clone());
         supervisor.monitor(child);
 
@@ -342,9 +400,11 @@ impl Iroha {
             config.torii,
             queue,
             events_sender,
-            live_query_store,
+            // The queries of the clients yield under load, unlike the ones of the smart contracts
+            live_query_store.with_load_shedding(),
             kura.clone(),
             state.clone(),
+            config.common.key_pair.clone(),
             iroha_torii::OnlinePeersProvider::new(network.online_peers_receiver()),
             #[cfg(feature = "telemetry")]
             telemetry,
@@ -569,6 +629,9 @@ fn validate_config(config: &Config) -> Result<(), ConfigError> {
     validate_directory_path(&mut emitter, &config.kura.store_dir);
     // maybe validate only if snapshot mode is enabled
     validate_directory_path(&mut emitter, &config.snapshot.store_dir);
+    if config.tiering.dormant_after_blocks.is_some() {
+        validate_directory_path(&mut emitter, &config.tiering.store_dir);
+    }
 
     if config.genesis.file.is_none()
         && !config
@@ -679,6 +742,24 @@ fn configure_reports(args: &Args) {
     Report::install_debug_hook::<Location>(|_, _| {});
 }
 
+async fn restore_from_archive(config: &Config) -> error_stack::Result<(), MainError> {
+    let Some(archive) = config.archive.clone() else {
+        return Err(Report::new(MainError::Restore)
+            .attach_printable("`--restore-from-archive` requires the `[archive]` configuration"));
+    };
+    let store_dir = config.kura.store_dir.resolve_relative_path();
+    let snapshot_dir = config.snapshot.store_dir.resolve_relative_path();
+    let blocks = tokio::task::spawn_blocking(move || {
+        iroha_core::archive::restore(archive, &store_dir, &snapshot_dir)
+    })
+    .await
+    .change_context(MainError::Restore)?
+    .change_context(MainError::Restore)?;
+    iroha_logger::info!(blocks, "Restored the blocks from the archive");
+
+    Ok(())
+}
+
 #[tokio::main]
 async fn main() -> error_stack::Result<(), MainError> {
     let args = Args::parse();
@@ -716,6 +797,10 @@ async fn main() -> error_stack::Result<(), MainError> {
         iroha_logger::debug!("Submitting genesis.");
     }
 
+    if args.restore_from_archive {
+        restore_from_archive(&config).await?;
+    }
+
     let shutdown_on_panic = ShutdownSignal::new();
     let default_hook = std::panic::take_hook();
     let signal_clone = shutdown_on_panic.clone();
@@ -725,9 +810,10 @@ async fn main() -> error_stack::Result<(), MainError> {
         default_hook(info);
     }));
 
-    let (_iroha, supervisor_fut) = Iroha::start(config, genesis, logger, shutdown_on_panic)
-        .await
-        .change_context(MainError::IrohaStart)?;
+    let (_iroha, supervisor_fut) =
+        Iroha::start(config, genesis, logger, args.reindex, shutdown_on_panic)
+            .await
+            .change_context(MainError::IrohaStart)?;
     supervisor_fut.await.change_context(MainError::IrohaRun)
 }
 
@@ -807,6 +893,8 @@ mod tests {
                 config: Some(config_path),
                 terminal_colors: false,
                 trace_config: false,
+                reindex: false,
+                restore_from_archive: false,
             })
             .map_err(|report| eyre::eyre!("{report:?}"))?;
 
@@ -859,6 +947,8 @@ mod tests {
                 config: Some(config_path),
                 terminal_colors: false,
                 trace_config: false,
+                reindex: false,
+                restore_from_archive: false,
             })
             .unwrap_err();
 
@@ -898,6 +988,14 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn reindex_is_opt_in() -> eyre::Result<()> {
+        assert!(!Args::try_parse_from(["test"])?.reindex);
+        assert!(Args::try_parse_from(["test", "--reindex"])?.reindex);
+
+        Ok(())
+    }
+
     #[test]
     fn user_provided_config_path_works() {
         let args = Args::try_parse_from(["test", "--config", "/home/custom/file.json"]).unwrap();
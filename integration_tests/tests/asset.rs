@@ -1,10 +1,14 @@
 #![allow(missing_docs)]
 
+use std::num::NonZeroU64;
+
 use eyre::Result;
 use iroha::{
     crypto::KeyPair,
     data_model::{
-        isi::error::{InstructionEvaluationError, InstructionExecutionError, TypeError},
+        isi::error::{
+            InstructionEvaluationError, InstructionExecutionError, MintabilityError, TypeError,
+        },
         prelude::*,
         transaction::error::TransactionRejectionReason,
     },
@@ -35,7 +39,7 @@ fn client_add_asset_quantity_to_existing_asset_should_increase_asset_amount() ->
         AssetId::new(asset_definition_id.clone(), account_id.clone()),
     );
     let instructions: [InstructionBox; 2] = [create_asset.into(), mint.into()];
-    let tx = test_client.build_transaction(instructions, metadata);
+    let tx = test_client.build_transaction(instructions, metadata)?;
     test_client.submit_transaction_blocking(&tx)?;
 
     let asset = test_client
@@ -69,7 +73,7 @@ fn client_add_big_asset_quantity_to_existing_asset_should_increase_asset_amount(
         AssetId::new(asset_definition_id.clone(), account_id.clone()),
     );
     let instructions: [InstructionBox; 2] = [create_asset.into(), mint.into()];
-    let tx = test_client.build_transaction(instructions, metadata);
+    let tx = test_client.build_transaction(instructions, metadata)?;
     test_client.submit_transaction_blocking(&tx)?;
 
     let asset = test_client
@@ -104,7 +108,7 @@ fn client_add_asset_with_decimal_should_increase_asset_amount() -> Result<()> {
         AssetId::new(asset_definition_id.clone(), account_id.clone()),
     );
     let instructions: [InstructionBox; 2] = [create_asset.into(), mint.into()];
-    let tx = test_client.build_transaction(instructions, metadata);
+    let tx = test_client.build_transaction(instructions, metadata)?;
     test_client.submit_transaction_blocking(&tx)?;
 
     let asset = test_client
@@ -348,6 +352,74 @@ fn fail_if_dont_satisfy_spec() {
     }
 }
 
+#[test]
+fn holding_metadata_is_set_and_removed() -> Result<()> {
+    let (network, _rt) = NetworkBuilder::new().start_blocking()?;
+    let test_client = network.client();
+
+    let asset_definition_id: AssetDefinitionId = "medicine#wonderland".parse()?;
+    let asset_id = AssetId::new(asset_definition_id.clone(), ALICE_ID.clone());
+    let batch: Name = "batch".parse()?;
+
+    test_client.submit_all_blocking::<InstructionBox>([
+        Register::asset_definition(AssetDefinition::numeric(asset_definition_id)).into(),
+        Mint::asset_numeric(numeric!(10), asset_id.clone()).into(),
+        SetKeyValue::asset(asset_id.clone(), batch.clone(), "B-42").into(),
+    ])?;
+
+    let asset = test_client
+        .query(FindAssets::new())
+        .filter_with(|asset| asset.id.eq(asset_id.clone()))
+        .execute_single()?;
+    assert_eq!(asset.metadata().get(&batch), Some(&"B-42".into()));
+
+    test_client.submit_blocking(RemoveKeyValue::asset(asset_id.clone(), batch.clone()))?;
+
+    let asset = test_client
+        .query(FindAssets::new())
+        .filter_with(|asset| asset.id.eq(asset_id))
+        .execute_single()?;
+    assert!(asset.metadata().get(&batch).is_none());
+
+    Ok(())
+}
+
+#[test]
+fn quantity_is_found_at_past_heights() -> Result<()> {
+    let (network, _rt) = NetworkBuilder::new().start_blocking()?;
+    let test_client = network.client();
+
+    let asset_definition_id: AssetDefinitionId = "gold#wonderland".parse()?;
+    let asset_id = AssetId::new(asset_definition_id.clone(), ALICE_ID.clone());
+    let quantity_at = |height: u64| {
+        test_client.query_single(FindAssetQuantityAt::new(
+            asset_id.clone(),
+            NonZeroU64::new(height).expect("height is positive"),
+        ))
+    };
+
+    let before_registration = test_client.get_status()?.blocks;
+    test_client.submit_all_blocking::<InstructionBox>([
+        Register::asset_definition(AssetDefinition::numeric(asset_definition_id)).into(),
+        Mint::asset_numeric(numeric!(10), asset_id.clone()).into(),
+    ])?;
+    let after_mint = test_client.get_status()?.blocks;
+    test_client.submit_blocking(Transfer::asset_numeric(
+        asset_id.clone(),
+        numeric!(4),
+        BOB_ID.clone(),
+    ))?;
+    let after_transfer = test_client.get_status()?.blocks;
+    test_client.submit_blocking(Burn::asset_numeric(numeric!(6), asset_id.clone()))?;
+
+    assert_eq!(quantity_at(before_registration)?, numeric!(0));
+    assert_eq!(quantity_at(after_mint)?, numeric!(10));
+    assert_eq!(quantity_at(after_transfer)?, numeric!(6));
+    assert_eq!(quantity_at(test_client.get_status()?.blocks)?, numeric!(0));
+
+    Ok(())
+}
+
 mod register {
     use super::*;
 
@@ -365,3 +437,59 @@ mod register {
         ))
     }
 }
+
+#[test]
+fn deprecated_asset_is_not_minted_but_transferred() -> Result<()> {
+    let (network, _rt) = NetworkBuilder::new().start_blocking()?;
+    let test_client = network.client();
+
+    let asset_definition_id: AssetDefinitionId = "coupon#wonderland".parse()?;
+    let asset_id = AssetId::new(asset_definition_id.clone(), ALICE_ID.clone());
+
+    test_client.submit_all_blocking::<InstructionBox>([
+        Register::asset_definition(AssetDefinition::numeric(asset_definition_id.clone())).into(),
+        Mint::asset_numeric(numeric!(10), asset_id.clone()).into(),
+        Deprecate::asset_definition(asset_definition_id.clone(), 3_600_000).into(),
+    ])?;
+
+    let deprecated = test_client
+        .query(FindAssetsDefinitions::new())
+        .filter_with(|asset_definition| asset_definition.is_deprecated())
+        .execute_single()?;
+    let deprecation = deprecated.deprecation().expect("asset is deprecated");
+    assert_eq!(
+        deprecation.sunset_end_ms - deprecation.deprecated_at_ms,
+        3_600_000
+    );
+
+    let err = test_client
+        .submit_blocking(Mint::asset_numeric(numeric!(1), asset_id.clone()))
+        .expect_err("deprecated asset must not be minted");
+    let rejection_reason = err
+        .downcast_ref::<TransactionRejectionReason>()
+        .unwrap_or_else(|| panic!("Error {err} is not TransactionRejectionReason"));
+    assert!(matches!(
+        rejection_reason,
+        TransactionRejectionReason::Validation(ValidationFail::InstructionFailed(
+            InstructionExecutionError::Mintability(MintabilityError::MintDeprecated)
+        ))
+    ));
+
+    test_client.submit_blocking(Transfer::asset_numeric(
+        asset_id,
+        numeric!(4),
+        BOB_ID.clone(),
+    ))?;
+    let bob_quantity = test_client
+        .query(FindAssets::new())
+        .filter_with(|asset| {
+            asset
+                .id
+                .eq(AssetId::new(asset_definition_id, BOB_ID.clone()))
+        })
+        .execute_single()?
+        .value;
+    assert_eq!(bob_quantity, numeric!(4));
+
+    Ok(())
+}
@@ -1,9 +1,12 @@
-use std::fmt::Write as _;
+use std::{fmt::Write as _, time::Duration};
 
 use assert_matches::assert_matches;
 use eyre::Result;
 use futures_util::StreamExt;
-use iroha::data_model::{prelude::*, transaction::WasmSmartContract};
+use iroha::{
+    client::EventTimeoutError,
+    data_model::{prelude::*, transaction::WasmSmartContract},
+};
 use iroha_executor_data_model::permission::{
     account::CanModifyAccountMetadata, domain::CanModifyDomainMetadata,
 };
@@ -142,7 +145,7 @@ async fn transaction_execution_should_produce_events(
 
     {
         let client = network.client();
-        let tx = client.build_transaction(executable, <_>::default());
+        let tx = client.build_transaction(executable, <_>::default())?;
         spawn_blocking(move || client.submit_transaction_blocking(&tx)).await??;
     }
 
@@ -265,3 +268,47 @@ async fn produce_multiple_events() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn wait_for_event_returns_matching_event_or_times_out() -> Result<()> {
+    let network = NetworkBuilder::new().start().await?;
+    let client = network.client();
+
+    let error = client
+        .wait_for_event_async(DataEventFilter::Any, |_| true, Duration::from_millis(500))
+        .await
+        .expect_err("nothing happens in the idle network");
+    assert!(error.downcast_ref::<EventTimeoutError>().is_some());
+
+    let waiter = {
+        let client = client.clone();
+        tokio::spawn(async move {
+            client
+                .wait_for_event_async(
+                    DataEventFilter::Any,
+                    |event| {
+                        matches!(
+                            event,
+                            EventBox::Data(DataEvent::Domain(DomainEvent::Created(domain)))
+                                if domain.id().name().as_ref().starts_with("looking_glass")
+                        )
+                    },
+                    Duration::from_secs(30),
+                )
+                .await
+        })
+    };
+    // The waiter may connect after the first domains are registered
+    for i in 0.. {
+        if waiter.is_finished() {
+            break;
+        }
+        let client = client.clone();
+        let domain: DomainId = format!("looking_glass_{i}").parse()?;
+        spawn_blocking(move || client.submit_blocking(Register::domain(Domain::new(domain))))
+            .await??;
+    }
+    waiter.await??;
+
+    Ok(())
+}
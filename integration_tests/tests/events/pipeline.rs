@@ -1,14 +1,17 @@
-use std::time::Duration;
+use std::{num::NonZeroU64, time::Duration};
 
 use eyre::Result;
 use futures_util::StreamExt;
-use iroha::data_model::{
-    events::pipeline::{TransactionEventFilter, TransactionStatus},
-    isi::error::InstructionExecutionError,
-    prelude::*,
-    query::error::FindError,
-    transaction::error::TransactionRejectionReason,
-    ValidationFail,
+use iroha::{
+    client::TransactionProgress,
+    data_model::{
+        events::pipeline::{TransactionEventFilter, TransactionStatus},
+        isi::error::InstructionExecutionError,
+        prelude::*,
+        query::error::FindError,
+        transaction::error::TransactionRejectionReason,
+        ValidationFail,
+    },
 };
 use iroha_test_network::*;
 use tokio::{task::spawn_blocking, time::timeout};
@@ -44,7 +47,7 @@ async fn test_with_instruction_and_status(
     let client = network.client();
 
     // When
-    let transaction = client.build_transaction(exec, Metadata::default());
+    let transaction = client.build_transaction(exec, Metadata::default())?;
     let hash = transaction.hash();
     let mut events = client
         .listen_for_events_async([TransactionEventFilter::default().for_hash(hash)])
@@ -73,6 +76,60 @@ async fn test_with_instruction_and_status(
     Ok(())
 }
 
+#[tokio::test]
+async fn block_headers_stream_follows_committed_blocks() -> Result<()> {
+    let network = NetworkBuilder::new().start().await?;
+    let client = network.client();
+
+    let mut headers = client
+        .listen_for_block_headers_async(NonZeroU64::MIN)
+        .await?;
+    let genesis = timeout(Duration::from_secs(5), headers.next())
+        .await?
+        .unwrap()?;
+    assert_eq!(genesis.header.height(), NonZeroU64::MIN);
+    assert!(genesis.header.prev_block_hash().is_none());
+
+    let register = Register::domain(Domain::new("looking_glass".parse()?));
+    spawn_blocking(move || client.submit_blocking(register)).await??;
+
+    let next = timeout(Duration::from_secs(5), headers.next())
+        .await?
+        .unwrap()?;
+    assert_eq!(next.header.height().get(), 2);
+    assert_eq!(next.header.prev_block_hash(), Some(genesis.header.hash()));
+    assert!(next.header.merkle_root().is_some());
+    assert!(!next.signatures.is_empty());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn transaction_progress_is_watched_until_committed() -> Result<()> {
+    let network = NetworkBuilder::new().start().await?;
+    let client = network.client();
+
+    let register = Register::domain(Domain::new("looking_glass".parse()?));
+    let transaction = client.build_transaction([register], Metadata::default())?;
+    let progress = client.submit_and_watch(&transaction).await?;
+    let progress = timeout(Duration::from_secs(5), progress.collect::<Vec<_>>())
+        .await?
+        .into_iter()
+        .collect::<Result<Vec<_>>>()?;
+
+    let block_height = NonZeroU64::new(2).unwrap();
+    assert_eq!(
+        progress,
+        [
+            TransactionProgress::Queued,
+            TransactionProgress::Validating { block_height },
+            TransactionProgress::Committed { block_height },
+        ]
+    );
+
+    Ok(())
+}
+
 #[test]
 #[ignore = "TODO: implement with the help of Kura Inspector, "]
 fn applied_block_must_be_available_in_kura() {
@@ -76,7 +76,7 @@ async fn multiple_blocks_created() -> Result<()> {
                     AssetId::new(asset_definition_id.clone(), account_id.clone()),
                 )],
                 <_>::default(),
-            );
+            )?;
             spawn_blocking(move || client.submit_transaction(&tx)).await??;
         }
 
@@ -0,0 +1,143 @@
+#![allow(missing_docs)]
+
+use std::num::{NonZeroU16, NonZeroU64};
+
+use eyre::Result;
+use iroha::{
+    data_model::{
+        parameter::{BlockParameter, Parameter},
+        prelude::*,
+        Level,
+    },
+    executor_data_model::{
+        isi::governance::*, parameter::governance::GovernanceParameters,
+        permission::parameter::CanSetParameters,
+    },
+};
+use iroha_test_network::*;
+use iroha_test_samples::{ALICE_ID, BOB_ID, BOB_KEYPAIR};
+use nonzero_ext::nonzero;
+
+#[test]
+fn proposal_is_executed_once_quorum_approves() -> Result<()> {
+    let council = GovernanceParameters {
+        quorum: Some(NonZeroU16::new(2).unwrap()),
+        ..GovernanceParameters::default()
+    };
+    let (network, _rt) = NetworkBuilder::new()
+        .with_genesis_instruction(Register::role(Role::new(
+            council.council.clone(),
+            ALICE_ID.clone(),
+        )))
+        .with_genesis_instruction(Grant::account_role(council.council.clone(), BOB_ID.clone()))
+        .with_genesis_instruction(SetParameter::new(council.clone().into()))
+        .with_genesis_instruction(Grant::account_permission(
+            CanSetParameters,
+            ALICE_ID.clone(),
+        ))
+        .start_blocking()?;
+    let test_client = network.client();
+
+    let new_max_transactions = nonzero!(42_u64);
+    let instructions: Vec<InstructionBox> =
+        vec![
+            SetParameter::new(Parameter::Block(BlockParameter::MaxTransactions(
+                new_max_transactions,
+            )))
+            .into(),
+        ];
+    let instructions_hash = HashOf::new(&instructions);
+
+    // Alice proposes and approves, the approved instructions are executed with her permissions
+    test_client.submit_blocking(GovernancePropose::new(
+        instructions,
+        NonZeroU64::new(60 * 60 * 1_000).unwrap(),
+    ))?;
+    test_client.submit_blocking(GovernanceVote::new(instructions_hash, true))?;
+
+    let genesis_metadata = test_client
+        .query(FindDomains)
+        .filter_with(|domain| domain.id.eq(proposals_domain()))
+        .select_with(|domain| domain.metadata)
+        .execute_single()?;
+    let open_proposals = proposals(&genesis_metadata).collect::<Vec<_>>();
+    assert_eq!(open_proposals.len(), 1);
+    assert!(open_proposals[0].1.approvals.contains(&ALICE_ID));
+    assert_ne!(
+        test_client
+            .query_single(FindParameters::new())?
+            .block()
+            .max_transactions(),
+        new_max_transactions
+    );
+
+    // Bob's approval reaches the quorum
+    let vote = TransactionBuilder::new(network.chain_id(), BOB_ID.clone())
+        .with_instructions([GovernanceVote::new(instructions_hash, true)])
+        .sign(BOB_KEYPAIR.private_key());
+    test_client.submit_transaction_blocking(&vote)?;
+
+    assert_eq!(
+        test_client
+            .query_single(FindParameters::new())?
+            .block()
+            .max_transactions(),
+        new_max_transactions
+    );
+    let genesis_metadata = test_client
+        .query(FindDomains)
+        .filter_with(|domain| domain.id.eq(proposals_domain()))
+        .select_with(|domain| domain.metadata)
+        .execute_single()?;
+    assert_eq!(proposals(&genesis_metadata).count(), 0);
+
+    Ok(())
+}
+
+#[test]
+fn non_council_member_cannot_propose() -> Result<()> {
+    let council = GovernanceParameters {
+        quorum: Some(NonZeroU16::new(2).unwrap()),
+        ..GovernanceParameters::default()
+    };
+    let (network, _rt) = NetworkBuilder::new()
+        .with_genesis_instruction(SetParameter::new(council.into()))
+        .start_blocking()?;
+    let test_client = network.client();
+
+    let instructions: Vec<InstructionBox> = vec![Log::new(Level::INFO, "hello".to_owned()).into()];
+    let _err = test_client
+        .submit_blocking(GovernancePropose::new(
+            instructions,
+            NonZeroU64::new(1_000).unwrap(),
+        ))
+        .expect_err("alice is not a council member");
+
+    Ok(())
+}
+
+#[test]
+fn proposal_cannot_be_decided_by_a_single_vote() -> Result<()> {
+    let council = GovernanceParameters {
+        quorum: Some(NonZeroU16::MIN),
+        ..GovernanceParameters::default()
+    };
+    let (network, _rt) = NetworkBuilder::new()
+        .with_genesis_instruction(Register::role(Role::new(
+            council.council.clone(),
+            ALICE_ID.clone(),
+        )))
+        .with_genesis_instruction(SetParameter::new(council.into()))
+        .start_blocking()?;
+    let test_client = network.client();
+
+    let instructions: Vec<InstructionBox> = vec![Log::new(Level::INFO, "hello".to_owned()).into()];
+    let _err = test_client
+        .submit_blocking(GovernancePropose::new(
+            instructions,
+            NonZeroU64::new(1_000).unwrap(),
+        ))
+        .expect_err("quorum of one is rejected");
+
+    Ok(())
+}
@@ -2,14 +2,11 @@
 
 use std::path::PathBuf;
 
-use iroha::{
-    client::Client,
-    crypto::{ExposedPrivateKey, KeyPair},
-};
+use iroha::crypto::{ExposedPrivateKey, KeyPair};
 use iroha_config_base::toml::WriteExt;
 use iroha_data_model::prelude::AccountId;
-use iroha_test_network::NetworkBuilder;
-use iroha_test_samples::sample_wasm_path;
+use iroha_test_network::{Network, NetworkBuilder};
+use iroha_test_samples::{sample_wasm_path, ALICE_KEYPAIR};
 use reqwest::Url;
 
 fn program() -> PathBuf {
@@ -22,11 +19,13 @@ struct ProgramConfig {
     key: KeyPair,
 }
 
-impl From<&Client> for ProgramConfig {
-    fn from(value: &Client) -> Self {
-        let torii_url = value.torii_url.clone();
-        let account = value.account.clone();
-        let key = value.key_pair.clone();
+impl From<&Network> for ProgramConfig {
+    fn from(value: &Network) -> Self {
+        let client = value.client();
+        let torii_url = client.torii_url;
+        let account = client.account;
+        // The client signs with Alice's key, which can't be taken out of its signer
+        let key = ALICE_KEYPAIR.clone();
         Self {
             torii_url,
             account,
@@ -70,7 +69,7 @@ async fn can_upgrade_executor() -> eyre::Result<()> {
         .start()
         .await?;
 
-    let config = ProgramConfig::from(&network.client());
+    let config = ProgramConfig::from(&network);
     let mut child = tokio::process::Command::new(program())
         .current_dir(iroha_test_network::repo_root())
         .envs(config.envs())
@@ -89,7 +88,7 @@ async fn can_upgrade_executor() -> eyre::Result<()> {
 #[tokio::test]
 async fn reads_client_toml_by_default() -> eyre::Result<()> {
     let network = NetworkBuilder::new().start().await?;
-    let config = ProgramConfig::from(&network.client());
+    let config = ProgramConfig::from(&network);
 
     let dir = tempfile::tempdir()?;
     tokio::fs::write(
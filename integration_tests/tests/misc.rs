@@ -66,3 +66,29 @@ async fn get_server_version() -> eyre::Result<()> {
     assert!(!response.git_sha.is_empty());
     Ok(())
 }
+
+#[tokio::test]
+async fn schema_bundle_is_tagged_with_executor_hash() -> Result<()> {
+    let network = NetworkBuilder::new().start().await?;
+    let url = network.client().torii_url.join("/schema/bundle").unwrap();
+    let http = reqwest::Client::new();
+
+    let response = http.get(url.clone()).send().await?;
+    assert_eq!(response.status(), reqwest::StatusCode::OK);
+    let etag = response.headers()[reqwest::header::ETAG].clone();
+    let bundle: serde_json::Value = response.json().await?;
+    assert!(bundle["schema"].get("SignedTransaction").is_some());
+    assert_eq!(
+        etag.to_str()?,
+        format!("\"{}\"", bundle["executor_hash"].as_str().unwrap())
+    );
+
+    let response = http
+        .get(url)
+        .header(reqwest::header::IF_NONE_MATCH, etag)
+        .send()
+        .await?;
+    assert_eq!(response.status(), reqwest::StatusCode::NOT_MODIFIED);
+
+    Ok(())
+}
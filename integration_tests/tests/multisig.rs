@@ -3,6 +3,7 @@
 use std::{
     collections::BTreeMap,
     num::{NonZeroU16, NonZeroU64},
+    sync::Arc,
     time::Duration,
 };
 
@@ -560,7 +561,7 @@ fn reserved_roles() {
 fn alt_client(signatory: (AccountId, KeyPair), base_client: &Client) -> Client {
     Client {
         account: signatory.0,
-        key_pair: signatory.1,
+        signer: Arc::new(signatory.1),
         ..base_client.clone()
     }
 }
@@ -5,6 +5,38 @@ use iroha::{
 };
 use iroha_test_network::*;
 use iroha_test_samples::{gen_account_in, ALICE_ID};
+use nonzero_ext::nonzero;
+
+#[test]
+fn find_assets_with_definition() -> Result<()> {
+    let (network, _rt) = NetworkBuilder::new().start_blocking()?;
+    let test_client = network.client();
+
+    let definition_id: AssetDefinitionId = "tulip#wonderland".parse()?;
+    let logo: IpfsPath = "QmQqzMTavQgT4f4T5v6PWBp7XNKtoPmC9jvn12WPT3gkSE".parse()?;
+    let asset_id = AssetId::new(definition_id.clone(), ALICE_ID.clone());
+    test_client.submit_all_blocking::<InstructionBox>([
+        Register::asset_definition(
+            AssetDefinition::numeric(definition_id.clone())
+                .mintable_once()
+                .with_logo(logo.clone()),
+        )
+        .into(),
+        Mint::asset_numeric(7_u32, asset_id.clone()).into(),
+    ])?;
+
+    let entry = test_client
+        .query(FindAssetsWithDefinition)
+        .filter_with(|entry| entry.asset.id.eq(asset_id.clone()))
+        .execute_single()?;
+    assert_eq!(entry.asset.value, numeric!(7));
+    assert_eq!(entry.definition.id, definition_id);
+    assert_eq!(entry.definition.logo, Some(logo));
+    // Minting once has already forbidden further minting
+    assert_eq!(entry.definition.mintable, Mintable::Not);
+
+    Ok(())
+}
 
 #[test]
 #[allow(clippy::too_many_lines)]
@@ -142,3 +174,59 @@ fn test_total_quantity(
 
     Ok(())
 }
+
+#[test]
+fn aggregate_asset_quantities() -> Result<()> {
+    let (network, _rt) = NetworkBuilder::new().start_blocking()?;
+    let test_client = network.client();
+
+    let definition_id: AssetDefinitionId = "tulip#wonderland".parse()?;
+    let (mad_hatter_id, _) = gen_account_in("wonderland");
+    test_client.submit_all_blocking::<InstructionBox>([
+        Register::asset_definition(AssetDefinition::numeric(definition_id.clone())).into(),
+        Register::account(Account::new(mad_hatter_id.clone())).into(),
+        Mint::asset_numeric(7_u32, AssetId::new(definition_id.clone(), ALICE_ID.clone())).into(),
+        Mint::asset_numeric(5_u32, AssetId::new(definition_id.clone(), mad_hatter_id)).into(),
+    ])?;
+
+    let tulips = || {
+        test_client
+            .query(FindAssets)
+            .filter_with(|asset| asset.id.definition.eq(definition_id.clone()))
+    };
+    assert_eq!(tulips().count()?, 2);
+    // The whole output is aggregated, not a page of it
+    assert_eq!(
+        tulips()
+            .with_pagination(Pagination::new(Some(nonzero!(1_u64)), 1))
+            .count()?,
+        2
+    );
+    assert_eq!(
+        tulips()
+            .with_pagination(Pagination::new(Some(nonzero!(1_u64)), 0))
+            .select_with(|asset| asset.value)
+            .sum()?,
+        numeric!(12)
+    );
+    assert_eq!(
+        tulips().select_with(|asset| asset.value).sum()?,
+        numeric!(12)
+    );
+    assert_eq!(
+        tulips().select_with(|asset| asset.value).min()?,
+        Some(numeric!(5))
+    );
+    assert_eq!(
+        tulips()
+            .filter_with(|asset| asset
+                .id
+                .definition
+                .eq("tulip#looking_glass".parse().unwrap()))
+            .select_with(|asset| asset.value)
+            .max()?,
+        None
+    );
+
+    Ok(())
+}
@@ -33,6 +33,36 @@ fn too_big_fetch_size_is_not_allowed() {
     ));
 }
 
+#[test]
+fn batched_queries_return_results_in_order() -> eyre::Result<()> {
+    let (network, _rt) = NetworkBuilder::new().start_blocking()?;
+    let client = network.client();
+
+    let (accounts, domains, too_big) = client.query_batch((
+        client
+            .query(FindAccounts::new())
+            .with_fetch_size(FetchSize::new(Some(nonzero_ext::nonzero!(1_u64)))),
+        client.query(FindDomains::new()),
+        client
+            .query(FindAssets::new())
+            .with_fetch_size(FetchSize::new(Some(MAX_FETCH_SIZE.checked_add(1).unwrap()))),
+    ))?;
+
+    assert_eq!(
+        accounts?.len(),
+        client.query(FindAccounts::new()).execute_all()?.len()
+    );
+    assert_eq!(domains?, client.query(FindDomains::new()).execute_all()?);
+    assert!(matches!(
+        too_big,
+        Err(QueryError::Validation(ValidationFail::QueryFailed(
+            QueryExecutionFail::FetchSizeTooBig
+        )))
+    ));
+
+    Ok(())
+}
+
 #[test]
 fn find_blocks_reversed() -> eyre::Result<()> {
     let (network, rt) = NetworkBuilder::new().start_blocking()?;
@@ -4,7 +4,7 @@ use eyre::Result;
 use iroha::data_model::{prelude::*, query::builder::SingleQueryError};
 use iroha_executor_data_model::permission::account::CanModifyAccountMetadata;
 use iroha_test_network::*;
-use iroha_test_samples::ALICE_ID;
+use iroha_test_samples::{ALICE_ID, BOB_ID};
 
 fn create_role_ids() -> [RoleId; 5] {
     [
@@ -151,3 +151,35 @@ fn find_roles_by_account_id() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn find_accounts_with_role() -> Result<()> {
+    let (network, _rt) = NetworkBuilder::new().start_blocking().unwrap();
+    let test_client = network.client();
+
+    let role_id: RoleId = "auditor".parse()?;
+    test_client.submit_all_blocking([
+        InstructionBox::from(Register::role(Role::new(role_id.clone(), ALICE_ID.clone()))),
+        Grant::account_role(role_id.clone(), BOB_ID.clone()).into(),
+    ])?;
+
+    let holders = test_client
+        .query(FindAccountsWithRole::new(role_id.clone()))
+        .execute_all()?
+        .into_iter()
+        .map(|account| account.id().clone())
+        .collect::<HashSet<_>>();
+    assert_eq!(holders, HashSet::from([ALICE_ID.clone(), BOB_ID.clone()]));
+
+    test_client.submit_blocking(Revoke::account_role(role_id.clone(), ALICE_ID.clone()))?;
+
+    let holders = test_client
+        .query(FindAccountsWithRole::new(role_id))
+        .execute_all()?
+        .into_iter()
+        .map(|account| account.id().clone())
+        .collect::<Vec<_>>();
+    assert_eq!(holders, [BOB_ID.clone()]);
+
+    Ok(())
+}
@@ -14,7 +14,7 @@ fn live_query_is_dropped_after_smart_contract_end() -> Result<()> {
     let transaction = client.build_transaction(
         load_sample_wasm("query_assets_and_save_cursor"),
         Metadata::default(),
-    );
+    )?;
     client.submit_transaction_blocking(&transaction)?;
 
     let metadata_value = client
@@ -46,7 +46,7 @@ fn smart_contract_can_filter_queries() -> Result<()> {
     let transaction = client.build_transaction(
         load_sample_wasm("smart_contract_can_filter_queries"),
         Metadata::default(),
-    );
+    )?;
     client.submit_transaction_blocking(&transaction)?;
 
     Ok(())
@@ -4,9 +4,12 @@ use std::time::Duration;
 use executor_custom_data_model::permissions::CanControlDomainLives;
 use eyre::Result;
 use iroha::data_model::{prelude::*, transaction::error::TransactionRejectionReason};
-use iroha_executor_data_model::permission::account::CanModifyAccountMetadata;
+use iroha_executor_data_model::{
+    isi::preset::{RegisterRolePreset, RolePreset},
+    permission::account::CanModifyAccountMetadata,
+};
 use iroha_test_network::*;
-use iroha_test_samples::{gen_account_in, ALICE_ID};
+use iroha_test_samples::{gen_account_in, ALICE_ID, BOB_ID, BOB_KEYPAIR};
 use serde_json::json;
 use tokio::time::timeout;
 
@@ -289,3 +292,32 @@ async fn grant_unexisting_role_in_genesis_fail() {
         .await
         .expect("must terminate immediately");
 }
+
+#[test]
+fn issuer_preset_registered_in_genesis_allows_minting() -> Result<()> {
+    let role_id = "ROSE_ISSUER".parse::<RoleId>()?;
+    let rose_definition_id = "rose#wonderland".parse::<AssetDefinitionId>()?;
+    let register_preset = RegisterRolePreset::new(
+        role_id.clone(),
+        BOB_ID.clone(),
+        RolePreset::Issuer(rose_definition_id.clone()),
+    );
+
+    let (network, _rt) = NetworkBuilder::new()
+        .with_genesis_instruction(register_preset)
+        .start_blocking()?;
+    let test_client = network.client();
+
+    assert!(test_client
+        .query(FindRolesByAccountId::new(BOB_ID.clone()))
+        .execute_all()?
+        .contains(&role_id));
+
+    let rose_id = AssetId::new(rose_definition_id, BOB_ID.clone());
+    let mint_rose = TransactionBuilder::new(network.chain_id(), BOB_ID.clone())
+        .with_instructions([Mint::asset_numeric(1_u32, rose_id)])
+        .sign(BOB_KEYPAIR.private_key());
+    test_client.submit_transaction_blocking(&mint_rose)?;
+
+    Ok(())
+}
@@ -2,10 +2,12 @@
 
 use eyre::Result;
 use iroha::data_model::{
-    parameter::{BlockParameter, Parameter, Parameters},
+    parameter::{BlockParameter, CustomParameter, Parameter, Parameters},
     prelude::*,
 };
+use iroha_executor_data_model::parameter::domain::{DomainPolicies, DomainPolicy};
 use iroha_test_network::*;
+use iroha_test_samples::gen_account_in;
 use nonzero_ext::nonzero;
 
 #[test]
@@ -30,3 +32,73 @@ fn can_change_parameter_value() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn can_filter_parameters() -> Result<()> {
+    let policies = CustomParameter::from(DomainPolicies::default());
+    let (network, _rt) = NetworkBuilder::new()
+        .with_genesis_instruction(SetParameter::new(Parameter::Custom(policies.clone())))
+        .start_blocking()?;
+    let test_client = network.client();
+
+    let custom = test_client
+        .query(FindAllParameters::new())
+        .filter_with(|parameter| parameter.is_custom())
+        .execute_all()?;
+    assert!(custom
+        .iter()
+        .all(|parameter| matches!(parameter, Parameter::Custom(_))));
+    assert!(custom.contains(&Parameter::Custom(policies.clone())));
+
+    let found = test_client
+        .query(FindAllParameters::new())
+        .filter_with(|parameter| parameter.custom_id_eq(policies.id().clone()))
+        .execute_single()?;
+    assert_eq!(found, Parameter::Custom(policies));
+
+    Ok(())
+}
+
+#[test]
+fn domain_policy_allows_members_to_register_asset_definitions() -> Result<()> {
+    let wonderland: DomainId = "wonderland".parse()?;
+    let policies = DomainPolicies {
+        domains: [(
+            wonderland.clone(),
+            DomainPolicy {
+                members_can_register_asset_definitions: true,
+                ..DomainPolicy::default()
+            },
+        )]
+        .into(),
+    };
+    let (network, _rt) = NetworkBuilder::new()
+        .with_genesis_instruction(SetParameter::new(policies.into()))
+        .start_blocking()?;
+    let test_client = network.client();
+
+    let (mouse_id, mouse_keypair) = gen_account_in("wonderland");
+    test_client.submit_blocking(Register::account(Account::new(mouse_id.clone())))?;
+
+    let register_tulip =
+        Register::asset_definition(AssetDefinition::numeric("tulip#wonderland".parse()?));
+    let register_tulip_tx = TransactionBuilder::new(network.chain_id(), mouse_id.clone())
+        .with_instructions([register_tulip])
+        .sign(mouse_keypair.private_key());
+    test_client.submit_transaction_blocking(&register_tulip_tx)?;
+
+    // Policy of wonderland doesn't extend to other domains
+    let (rabbit_id, rabbit_keypair) = gen_account_in("wonderland");
+    test_client.submit_blocking(Register::account(Account::new(rabbit_id.clone())))?;
+    let register_daisy = Register::asset_definition(AssetDefinition::numeric(
+        "daisy#garden_of_live_flowers".parse()?,
+    ));
+    let register_daisy_tx = TransactionBuilder::new(network.chain_id(), rabbit_id)
+        .with_instructions([register_daisy])
+        .sign(rabbit_keypair.private_key());
+    let _err = test_client
+        .submit_transaction_blocking(&register_daisy_tx)
+        .expect_err("policy is set only for wonderland");
+
+    Ok(())
+}
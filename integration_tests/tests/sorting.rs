@@ -342,3 +342,52 @@ fn sort_only_elements_which_have_sorting_key() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn correct_sorting_of_assets_by_value() -> Result<()> {
+    let (network, _rt) = NetworkBuilder::new().start_blocking()?;
+    let test_client = network.client();
+
+    let n = 10u32;
+    let mut register_asset_definitions = vec![];
+    let mut mint_assets = vec![];
+    for i in 0..n {
+        let asset_definition_id = format!("xor{i}#wonderland").parse::<AssetDefinitionId>()?;
+        register_asset_definitions.push(Register::asset_definition(AssetDefinition::numeric(
+            asset_definition_id.clone(),
+        )));
+        mint_assets.push(Mint::asset_numeric(
+            i + 1,
+            AssetId::new(asset_definition_id, ALICE_ID.clone()),
+        ));
+    }
+    register_asset_definitions.shuffle(&mut thread_rng());
+    mint_assets.shuffle(&mut thread_rng());
+
+    test_client
+        .submit_all_blocking(register_asset_definitions)
+        .wrap_err("Failed to register asset definitions")?;
+    test_client
+        .submit_all_blocking(mint_assets)
+        .wrap_err("Failed to mint assets")?;
+
+    let res = test_client
+        .query(FindAssets::new())
+        .filter_with(|asset| asset.id.definition.name.starts_with("xor"))
+        .sort_by(SortKey::Value)
+        .order(SortOrder::Descending)
+        .with_pagination(Pagination::new(Some(nonzero!(3_u64)), 1))
+        .execute_all()
+        .wrap_err("Failed to submit request")?;
+
+    let quantities = res.iter().map(|asset| *asset.value()).collect::<Vec<_>>();
+    assert_eq!(
+        quantities,
+        [n - 1, n - 2, n - 3]
+            .into_iter()
+            .map(Numeric::from)
+            .collect::<Vec<_>>()
+    );
+
+    Ok(())
+}
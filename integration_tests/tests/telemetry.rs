@@ -3,6 +3,7 @@
 use std::collections::{HashMap, HashSet};
 
 use expect_test::expect;
+use iroha::client::PeerStatus;
 use iroha_data_model::{isi::Log, peer::Peer, Level};
 use iroha_test_network::NetworkBuilder;
 
@@ -85,6 +86,8 @@ async fn misc_measurements() -> eyre::Result<()> {
             "accounts{domain=\"garden_of_live_flowers\"}",
             "accounts{domain=\"genesis\"}",
             "accounts{domain=\"wonderland\"}",
+            "asset_history_entries",
+            "asset_history_pruned",
             "block_height",
             "block_height_non_empty",
             "commit_time_ms_bucket{le=\"+Inf\"}",
@@ -100,6 +103,7 @@ async fn misc_measurements() -> eyre::Result<()> {
             "dropped_messages",
             "last_commit_time_ms",
             "queue_size",
+            "transactions_pruned",
             "tx_amount_bucket{le=\"+Inf\"}",
             "tx_amount_bucket{le=\"-10\"}",
             "tx_amount_bucket{le=\"-1000\"}",
@@ -165,3 +169,32 @@ async fn fetch_online_peers() -> eyre::Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn network_status_reports_every_peer() -> eyre::Result<()> {
+    let (network, rt) = NetworkBuilder::new().with_peers(4).start_blocking()?;
+    rt.block_on(network.ensure_blocks_with(|x| x.total == 1))?;
+    let (unknown, known) = network.peers().split_last().expect("network has peers");
+    let torii_urls = known
+        .iter()
+        .map(|peer| (peer.id(), peer.client().torii_url))
+        .collect();
+
+    let status = network.client().network_status(&torii_urls)?;
+
+    assert_eq!(status.peers.len(), 4);
+    assert_eq!(status.height(), Some(1));
+    for peer in known {
+        let Some(PeerStatus::Online(peer_status)) = status.peers.get(&peer.id()) else {
+            panic!("peer {} should report its status", peer.id());
+        };
+        assert_eq!(peer_status.height, 1);
+        assert_eq!(peer_status.sync_lag, 0);
+    }
+    assert!(matches!(
+        status.peers.get(&unknown.id()),
+        Some(PeerStatus::UnknownUrl)
+    ));
+
+    Ok(())
+}
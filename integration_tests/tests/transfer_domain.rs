@@ -6,10 +6,10 @@ use iroha::{
     data_model::{prelude::*, transaction::error::TransactionRejectionReason},
 };
 use iroha_executor_data_model::permission::{
-    account::CanUnregisterAccount,
+    account::{CanRegisterAccount, CanUnregisterAccount},
     asset::CanTransferAsset,
     asset_definition::{CanRegisterAssetDefinition, CanUnregisterAssetDefinition},
-    domain::CanUnregisterDomain,
+    domain::{CanTransferDomain, CanUnregisterDomain},
     nft::{CanRegisterNft, CanUnregisterNft},
     trigger::CanUnregisterTrigger,
 };
@@ -386,6 +386,114 @@ fn domain_owner_transfer() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn delegated_domain_transfer_revokes_domain_owner_tokens() -> Result<()> {
+    let (network, _rt) = NetworkBuilder::new().start_blocking()?;
+    let test_client = network.client();
+
+    let alice_id = ALICE_ID.clone();
+    let kingdom_id: DomainId = "kingdom".parse()?;
+    let (bob_id, bob_keypair) = gen_account_in("kingdom");
+    let (mad_hatter_id, _mad_hatter_keypair) = gen_account_in("kingdom");
+
+    // "alice@wonderland" is owner of "kingdom" domain
+    let kingdom = Domain::new(kingdom_id.clone());
+    test_client.submit_blocking(Register::domain(kingdom))?;
+    test_client.submit_blocking(Register::account(Account::new(bob_id.clone())))?;
+    test_client.submit_blocking(Register::account(Account::new(mad_hatter_id.clone())))?;
+
+    // "alice@wonderland" delegates the transfer of "kingdom" to "bob@kingdom"
+    let can_transfer_domain = CanTransferDomain {
+        domain: kingdom_id.clone(),
+    };
+    let can_register_account = CanRegisterAccount {
+        domain: kingdom_id.clone(),
+    };
+    test_client.submit_blocking(Grant::account_permission(
+        can_transfer_domain.clone(),
+        bob_id.clone(),
+    ))?;
+    test_client.submit_blocking(Grant::account_permission(
+        can_register_account.clone(),
+        mad_hatter_id.clone(),
+    ))?;
+
+    let transaction = TransactionBuilder::new(network.chain_id(), bob_id.clone())
+        .with_instructions([Transfer::domain(
+            alice_id,
+            kingdom_id.clone(),
+            mad_hatter_id.clone(),
+        )])
+        .sign(bob_keypair.private_key());
+    test_client.submit_transaction_blocking(&transaction)?;
+
+    let domain = test_client
+        .query(FindDomains::new())
+        .filter_with(|domain| domain.id.eq(kingdom_id.clone()))
+        .execute_single()?;
+    assert_eq!(domain.owned_by(), &mad_hatter_id);
+
+    // Tokens granted by the previous owner don't survive the transfer
+    let permissions = test_client
+        .query(FindPermissionsByAccountId::new(bob_id))
+        .execute_all()?;
+    assert!(!permissions.iter().any(|permission| {
+        CanTransferDomain::try_from(permission)
+            .is_ok_and(|permission| permission == can_transfer_domain)
+    }));
+    let permissions = test_client
+        .query(FindPermissionsByAccountId::new(mad_hatter_id))
+        .execute_all()?;
+    assert!(!permissions.iter().any(|permission| {
+        CanRegisterAccount::try_from(permission)
+            .is_ok_and(|permission| permission == can_register_account)
+    }));
+
+    Ok(())
+}
+
+#[test]
+fn not_allowed_to_transfer_domain_without_token() -> Result<()> {
+    let (network, _rt) = NetworkBuilder::new().start_blocking()?;
+    let test_client = network.client();
+
+    let alice_id = ALICE_ID.clone();
+    let kingdom_id: DomainId = "kingdom".parse()?;
+    let (bob_id, bob_keypair) = gen_account_in("kingdom");
+
+    // "alice@wonderland" is owner of "kingdom" domain
+    let kingdom = Domain::new(kingdom_id.clone());
+    test_client.submit_blocking(Register::domain(kingdom))?;
+    test_client.submit_blocking(Register::account(Account::new(bob_id.clone())))?;
+
+    // "bob@kingdom" can't transfer "kingdom" to himself without the token
+    let transaction = TransactionBuilder::new(network.chain_id(), bob_id.clone())
+        .with_instructions([Transfer::domain(
+            alice_id.clone(),
+            kingdom_id.clone(),
+            bob_id,
+        )])
+        .sign(bob_keypair.private_key());
+    let err = test_client
+        .submit_transaction_blocking(&transaction)
+        .expect_err("Tx should fail due to permissions");
+    let rejection_reason = err
+        .downcast_ref::<TransactionRejectionReason>()
+        .unwrap_or_else(|| panic!("Error {err} is not TransactionRejectionReason"));
+    assert!(matches!(
+        rejection_reason,
+        &TransactionRejectionReason::Validation(ValidationFail::NotPermitted(_))
+    ));
+
+    let domain = test_client
+        .query(FindDomains::new())
+        .filter_with(|domain| domain.id.eq(kingdom_id.clone()))
+        .execute_single()?;
+    assert_eq!(domain.owned_by(), &alice_id);
+
+    Ok(())
+}
+
 #[test]
 fn not_allowed_to_transfer_other_user_domain() -> Result<()> {
     let users_domain: DomainId = "users".parse()?;
@@ -1,4 +1,8 @@
-use std::{sync::mpsc, thread, time::Duration};
+use std::{
+    sync::{mpsc, Arc},
+    thread,
+    time::Duration,
+};
 
 use eyre::{eyre, Result, WrapErr};
 use iroha::{
@@ -273,7 +277,7 @@ fn only_account_with_permission_can_register_trigger() -> Result<()> {
 
     let mut rabbit_client = test_client.clone();
     rabbit_client.account = rabbit_account_id.clone();
-    rabbit_client.key_pair = rabbit_keys;
+    rabbit_client.signer = Arc::new(rabbit_keys);
 
     // Permission for the trigger registration on behalf of alice
     let permission_on_registration = CanRegisterTrigger {
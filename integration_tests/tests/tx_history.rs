@@ -38,7 +38,7 @@ fn client_has_rejected_and_accepted_txs_should_return_tx_history() -> Result<()>
             &mint_not_existed_asset
         };
         let instructions: Vec<InstructionBox> = vec![mint_asset.clone().into()];
-        let transaction = client.build_transaction(instructions, Metadata::default());
+        let transaction = client.build_transaction(instructions, Metadata::default())?;
         let _ = client.submit_transaction_blocking(&transaction);
     }
 
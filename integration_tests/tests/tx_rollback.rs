@@ -36,3 +36,35 @@ fn client_sends_transaction_with_invalid_instruction_should_not_see_any_changes(
         .all(|asset| *asset.id() != wrong_asset_definition_id));
     Ok(())
 }
+
+#[test]
+fn simulated_transaction_reports_failed_instruction_without_committing() -> Result<()> {
+    let (network, _rt) = NetworkBuilder::new().start_blocking()?;
+    let client = network.client();
+
+    let asset_definition_id: AssetDefinitionId = "xor#wonderland".parse()?;
+    let wrong_asset_definition_id: AssetDefinitionId = "ksor#wonderland".parse()?;
+    let create_asset =
+        Register::asset_definition(AssetDefinition::numeric(asset_definition_id.clone()));
+    let mint_asset = Mint::asset_numeric(
+        200u32,
+        AssetId::new(wrong_asset_definition_id, ALICE_ID.clone()),
+    );
+    let transaction = client.build_transaction(
+        [InstructionBox::from(create_asset), mint_asset.into()],
+        Metadata::default(),
+    )?;
+
+    let simulation = client.simulate(&transaction)?;
+
+    assert!(!simulation.is_ok());
+    assert_eq!(simulation.instructions.len(), 2);
+    assert!(simulation.instructions[0].result.is_ok());
+    assert!(simulation.instructions[1].result.is_err());
+    assert!(client
+        .query(FindAssetsDefinitions::new())
+        .execute_all()?
+        .iter()
+        .all(|definition| *definition.id() != asset_definition_id));
+    Ok(())
+}
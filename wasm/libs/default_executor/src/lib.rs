@@ -48,5 +48,7 @@ impl Executor {
 #[iroha_executor::migrate]
 fn migrate(host: Iroha, context: Context) {
     Executor::ensure_genesis(context.curr_block);
-    DataModelBuilder::with_default_permissions().build_and_set(&host);
+    DataModelBuilder::with_default_permissions()
+        .add_default_parameters()
+        .build_and_set(&host);
 }
@@ -42,6 +42,7 @@ fn main(host: Iroha, context: Context) {
                 Default::default(),
                 Default::default(),
                 FetchSize::new(Some(nonzero!(1_u64))),
+                None,
             ),
         ))
         .dbg_unwrap();